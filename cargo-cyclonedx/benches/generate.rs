@@ -0,0 +1,103 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use assert_fs::prelude::*;
+use cargo::core::Workspace;
+use cargo::Config;
+use cargo_cyclonedx::config::SbomConfig;
+use cargo_cyclonedx::generator::SbomGenerator;
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use std::time::Duration;
+
+/// Number of crates the benchmarked package depends on
+const DEPENDENCIES: usize = 5_000;
+
+/// Creates a package depending on `dependencies` path crates, each of which depends on the next
+/// few crates, so that the dependency graph is wider and deeper than a chain
+fn create_workspace(dependencies: usize) -> assert_fs::TempDir {
+    let dir = assert_fs::TempDir::new().expect("Failed to create the workspace");
+
+    let mut manifest = String::from(
+        "[package]\nname = \"app\"\nversion = \"1.0.0\"\nlicense = \"MIT\"\n\n[dependencies]\n",
+    );
+    for index in 0..dependencies {
+        manifest.push_str(&format!(
+            "crate-{0} = {{ path = \"crates/crate-{0}\" }}\n",
+            index
+        ));
+    }
+    dir.child("Cargo.toml")
+        .write_str(&manifest)
+        .expect("Failed to write the manifest");
+    dir.child("src/main.rs")
+        .write_str("fn main() {}\n")
+        .expect("Failed to write the sources");
+
+    for index in 0..dependencies {
+        let mut manifest = format!(
+            "[package]\nname = \"crate-{0}\"\nversion = \"1.0.{1}\"\nlicense = \"MIT OR Apache-2.0\"\n\
+             repository = \"https://github.com/example/crate-{0}\"\n\n[dependencies]\n",
+            index,
+            index % 100
+        );
+        for dependency in index + 1..dependencies.min(index + 4) {
+            manifest.push_str(&format!(
+                "crate-{0} = {{ path = \"../crate-{0}\" }}\n",
+                dependency
+            ));
+        }
+
+        let crate_dir = dir.child(format!("crates/crate-{}", index));
+        crate_dir
+            .child("Cargo.toml")
+            .write_str(&manifest)
+            .expect("Failed to write the manifest");
+        crate_dir
+            .child("src/lib.rs")
+            .write_str("")
+            .expect("Failed to write the sources");
+    }
+
+    dir
+}
+
+fn benchmarks(c: &mut Criterion) {
+    let dir = create_workspace(DEPENDENCIES);
+    let manifest_path = dir.path().join("Cargo.toml");
+    let config = Config::default().expect("Failed to create cargo config");
+
+    let mut group = c.benchmark_group("generate");
+    group.throughput(Throughput::Elements(DEPENDENCIES as u64));
+    group.bench_function("workspace", |b| {
+        b.iter(|| {
+            let ws = Workspace::new(&manifest_path, &config).expect("Failed to load the workspace");
+            SbomGenerator::create_sboms(ws, &SbomConfig::empty_config())
+                .expect("Failed to generate the SBOM")
+        })
+    });
+    group.finish();
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default()
+        .sample_size(10)
+        .measurement_time(Duration::from_secs(15));
+    targets = benchmarks
+}
+criterion_main!(benches);
@@ -0,0 +1,75 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Records the git commit cargo-cyclonedx is built from as `CARGO_CYCLONEDX_COMMIT`
+
+use std::path::Path;
+use std::process::Command;
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    if let Some(commit) = packaged_commit().or_else(git_commit) {
+        println!("cargo:rustc-env=CARGO_CYCLONEDX_COMMIT={}", commit);
+    }
+}
+
+/// The commit checked out in the repository, when building from a clone
+fn git_commit() -> Option<String> {
+    let git_dir = git(&["rev-parse", "--absolute-git-dir"])?;
+    // a new commit or checkout moves HEAD or the branch it points to
+    println!(
+        "cargo:rerun-if-changed={}",
+        Path::new(&git_dir).join("HEAD").display()
+    );
+    println!(
+        "cargo:rerun-if-changed={}",
+        Path::new(&git_dir).join("refs").display()
+    );
+
+    git(&["rev-parse", "HEAD"])
+}
+
+fn git(args: &[&str]) -> Option<String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    String::from_utf8(output.stdout)
+        .ok()
+        .map(|stdout| stdout.trim().to_string())
+}
+
+/// The commit `cargo package` recorded in `.cargo_vcs_info.json`, when building the published crate
+fn packaged_commit() -> Option<String> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(".cargo_vcs_info.json");
+    let info = std::fs::read_to_string(path).ok()?;
+
+    // { "git": { "sha1": "<commit>" }, "path_in_vcs": "cargo-cyclonedx" }
+    let sha1 = &info[info.find("\"sha1\"")? + "\"sha1\"".len()..];
+    let sha1 = sha1
+        .trim_start()
+        .strip_prefix(':')?
+        .trim_start()
+        .strip_prefix('"')?;
+    Some(sha1[..sha1.find('"')?].to_string())
+}
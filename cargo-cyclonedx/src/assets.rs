@@ -0,0 +1,304 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+use crate::scan::FileScanner;
+
+use crate::config::OnError;
+use anyhow::Context;
+use cargo::core::Package;
+use cargo::sources::PathSource;
+use cargo::Config;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Component, Path, PathBuf};
+
+/// A file that is compiled into a crate with `include_bytes!` or `include_str!`
+///
+/// * `path` - Location of the file relative to the package root, using `/` as separator
+/// * `included_from` - Source file that includes it, relative to the package root
+/// * `size` - Size of the file in bytes
+/// * `sha256` - Hex encoded SHA-256 hash of the file contents
+#[derive(Debug, PartialEq, Eq)]
+pub struct EmbeddedAsset {
+    pub path: String,
+    pub included_from: String,
+    pub size: u64,
+    pub sha256: String,
+}
+
+/// Finds the files of at least `min_size` bytes that the library and binary targets of
+/// `package` embed
+///
+/// Sources and embedded files skipped by `scanner` are not considered. This is a textual scan of
+/// the sources of those targets: only string literal paths are found, so files included through
+/// `concat!` or `env!("OUT_DIR")` are skipped, and includes in comments or disabled code are
+/// reported as well. Sources that cannot be read are logged and skipped, embedded files that
+/// cannot be read are handled as `on_error` says.
+pub fn embedded_assets(
+    package: &Package,
+    min_size: u64,
+    scanner: &FileScanner,
+    on_error: OnError,
+) -> anyhow::Result<Vec<EmbeddedAsset>> {
+    let root = package.root();
+    let source_dirs: BTreeSet<PathBuf> = package
+        .targets()
+        .iter()
+        .filter(|target| target.is_lib() || target.is_bin())
+        .filter_map(|target| target.src_path().path())
+        .filter_map(|path| path.parent().map(Path::to_path_buf))
+        .collect();
+
+    let sources: BTreeSet<PathBuf> = source_dirs
+        .iter()
+        .flat_map(|dir| scanner.files(dir))
+        .filter(|path| {
+            path.extension()
+                .map_or(false, |extension| extension == "rs")
+        })
+        .collect();
+
+    let mut assets = BTreeMap::new();
+    for source in sources {
+        let contents = match std::fs::read_to_string(&source) {
+            Ok(contents) => contents,
+            Err(e) => {
+                log::warn!(
+                    "Could not scan {} for embedded assets: {}",
+                    source.display(),
+                    e
+                );
+                continue;
+            }
+        };
+        let included_from = relative_path(root, &source);
+
+        for literal in included_paths(&contents) {
+            let path = normalize(&source.parent().unwrap_or(root).join(literal));
+            let relative = relative_path(root, &path);
+            if assets.contains_key(&relative) || scanner.is_excluded(&path) {
+                continue;
+            }
+
+            let hashed = hash_or_report(&path, on_error, || {
+                format!(
+                    "Could not read the asset {} embedded by {}",
+                    path.display(),
+                    included_from
+                )
+            })?;
+            match hashed {
+                Some((size, _)) if size < min_size => {}
+                Some((size, sha256)) => {
+                    assets.insert(
+                        relative.clone(),
+                        EmbeddedAsset {
+                            path: relative,
+                            included_from: included_from.clone(),
+                            size,
+                            sha256,
+                        },
+                    );
+                }
+                None => {}
+            }
+        }
+    }
+
+    Ok(assets.into_values().collect())
+}
+
+/// A file of a package, as it would be included in the `.crate` file by `cargo package`
+///
+/// * `path` - Location of the file relative to the package root, using `/` as separator
+/// * `sha256` - Hex encoded SHA-256 hash of the file contents
+#[derive(Debug, PartialEq, Eq)]
+pub struct SourceFile {
+    pub path: String,
+    pub sha256: String,
+}
+
+/// Lists and hashes the files of `package`, honoring its `include` and `exclude` settings as
+/// well as the files skipped by `scanner`
+///
+/// Files that cannot be read are handled as `on_error` says.
+pub fn source_files(
+    package: &Package,
+    config: &Config,
+    scanner: &FileScanner,
+    on_error: OnError,
+) -> anyhow::Result<Vec<SourceFile>> {
+    let root = package.root();
+    let source = PathSource::new(root, package.package_id().source_id(), config);
+    let scanned: BTreeSet<PathBuf> = scanner.files(root).into_iter().collect();
+
+    let mut files = Vec::new();
+    for path in source.list_files(package)? {
+        if !scanned.contains(&path) {
+            continue;
+        }
+        let hashed = hash_or_report(&path, on_error, || {
+            format!("Could not read {}", path.display())
+        })?;
+        if let Some((_, sha256)) = hashed {
+            files.push(SourceFile {
+                path: relative_path(root, &path),
+                sha256,
+            });
+        }
+    }
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+
+    Ok(files)
+}
+
+/// The string literal paths passed to `include_bytes!` and `include_str!`
+fn included_paths(source: &str) -> Vec<&str> {
+    static INCLUDE_REGEX: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(r#"include_(?:bytes|str)!\s*[(\[{]\s*(?:r#*)?"([^"]+)""#)
+            .expect("Invalid include regex")
+    });
+
+    INCLUDE_REGEX
+        .captures_iter(source)
+        .filter_map(|captures| captures.get(1))
+        .map(|path| path.as_str())
+        .collect()
+}
+
+/// Hashes the file at `path` like [`hash_file`], or reports the failure with the message
+/// `context` as `on_error` says
+fn hash_or_report(
+    path: &Path,
+    on_error: OnError,
+    context: impl FnOnce() -> String,
+) -> anyhow::Result<Option<(u64, String)>> {
+    match hash_file(path).with_context(context) {
+        Ok(hashed) => Ok(Some(hashed)),
+        Err(error) => on_error.handle(error).map(|_| None),
+    }
+}
+
+fn hash_file(path: &Path) -> std::io::Result<(u64, String)> {
+    let mut file = std::fs::File::open(path)?;
+    let mut hasher = Sha256::new();
+    let size = std::io::copy(&mut file, &mut hasher)?;
+    Ok((size, format!("{:x}", hasher.finalize())))
+}
+
+/// Resolves `.` and `..` without touching the file system, which keeps paths that leave the
+/// package root readable
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir
+                if matches!(
+                    normalized.components().next_back(),
+                    Some(Component::Normal(_))
+                ) =>
+            {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+/// `path` relative to `root` with `/` separators, or the full path if it is outside of `root`
+fn relative_path(root: &Path, path: &Path) -> String {
+    let relative = path.strip_prefix(root).unwrap_or(path);
+    if relative.is_absolute() {
+        return relative.to_string_lossy().into_owned();
+    }
+
+    let parts: Vec<_> = relative
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect();
+    parts.join("/")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_should_find_literal_include_paths() {
+        let source = r####"
+            static LOGO: &[u8] = include_bytes!("../assets/logo.png");
+            const SCHEMA: &str = include_str!(
+                "schema.json"
+            );
+            let raw = include_bytes! [r#"data/raw.bin"#];
+            let generated = include_bytes!(concat!(env!("OUT_DIR"), "/generated.bin"));
+        "####;
+
+        assert_eq!(
+            included_paths(source),
+            vec!["../assets/logo.png", "schema.json", "data/raw.bin"]
+        );
+    }
+
+    #[test]
+    fn it_should_normalize_paths_lexically() {
+        assert_eq!(
+            normalize(Path::new("pkg/src/../assets/./logo.png")),
+            PathBuf::from("pkg/assets/logo.png")
+        );
+        assert_eq!(
+            normalize(Path::new("src/../../shared/logo.png")),
+            PathBuf::from("../shared/logo.png")
+        );
+    }
+
+    #[test]
+    fn it_should_hash_and_measure_files() {
+        let tmp_dir = assert_fs::TempDir::new().expect("Failed to create temporary directory");
+        let path = tmp_dir.path().join("asset.txt");
+        std::fs::write(&path, "tacos").expect("Failed to write asset");
+
+        let (size, sha256) = hash_file(&path).expect("Failed to hash file");
+
+        assert_eq!(size, 5);
+        assert_eq!(
+            sha256,
+            "1734c8d3b028f603483d60ab3ab8a61cf6a41f93709cf47fa01d30a90ee2282c"
+        );
+    }
+
+    #[test]
+    fn it_should_report_unreadable_files_as_configured() {
+        let tmp_dir = assert_fs::TempDir::new().expect("Failed to create temporary directory");
+        let path = tmp_dir.path().join("missing.txt");
+        let context = || "Could not read missing.txt".to_string();
+
+        assert!(hash_or_report(&path, OnError::Skip, context)
+            .expect("Should skip the file")
+            .is_none());
+        assert!(hash_or_report(&path, OnError::Warn, context)
+            .expect("Should skip the file")
+            .is_none());
+
+        let error = hash_or_report(&path, OnError::Fail, context).expect_err("Should fail");
+        assert_eq!(error.to_string(), "Could not read missing.txt");
+    }
+}
@@ -0,0 +1,158 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Generation of SBOMs from async code, e.g. a service that generates them on push events, with
+//! the `async` feature
+//!
+//! cargo resolves workspaces synchronously, and neither its workspaces nor its packages can be
+//! sent to another thread. The generation therefore runs as a blocking job, which opens the
+//! workspace itself, on a thread the caller provides with a `spawn_blocking` hook, e.g. that of
+//! the runtime, or on a thread of its own with [`spawn_thread`]. The returned [`Generation`]
+//! completes when the job is done, without blocking the executor that polls it.
+//!
+//! The enrichment pipeline runs inside the job as well, as the enrichers are synchronous: an
+//! enricher that reads files or the registry cache blocks the thread of the job, not the executor,
+//! but there are no async enrichers that would await I/O instead.
+//!
+//! ```no_run
+//! use cargo_cyclonedx::asynchronous::{create_sboms, spawn_thread};
+//! use cargo_cyclonedx::config::SbomConfig;
+//!
+//! # async fn generate() -> Result<(), Box<dyn std::error::Error>> {
+//! // e.g. `|job| drop(tokio::task::spawn_blocking(job))` with tokio
+//! let boms = create_sboms("Cargo.toml", SbomConfig::empty_config(), spawn_thread).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::config::SbomConfig;
+use crate::generator::{GeneratedSbom, GeneratorError, SbomGenerator};
+use futures_channel::oneshot;
+use std::future::Future;
+use std::path::PathBuf;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use thiserror::Error;
+
+/// A job that blocks the thread it runs on until it is done
+pub type BlockingJob = Box<dyn FnOnce() + Send + 'static>;
+
+/// Generates the SBOMs of the workspace of the manifest at `manifest_path`, like
+/// [`SbomGenerator::create_sboms`], in a blocking job that `spawn_blocking` runs
+pub fn create_sboms<S>(
+    manifest_path: impl Into<PathBuf>,
+    config_override: SbomConfig,
+    spawn_blocking: S,
+) -> Generation
+where
+    S: FnOnce(BlockingJob),
+{
+    let manifest_path = manifest_path.into();
+    let (sender, receiver) = oneshot::channel();
+    spawn_blocking(Box::new(move || {
+        let result = SbomGenerator::create_sboms_for_manifest(&manifest_path, &config_override)
+            .map_err(AsyncGeneratorError::from);
+        // the receiver is gone if the generation is no longer awaited
+        let _ = sender.send(result);
+    }));
+
+    Generation { receiver }
+}
+
+/// Runs `job` on a thread of its own, for callers whose runtime has no `spawn_blocking`
+pub fn spawn_thread(job: BlockingJob) {
+    let spawned = std::thread::Builder::new()
+        .name("cargo-cyclonedx".to_string())
+        .spawn(job);
+    if let Err(error) = spawned {
+        // the job was dropped, so the generation fails as aborted
+        log::error!("Could not start the generation thread: {}", error);
+    }
+}
+
+/// An SBOM generation running in a blocking job, which completes with the SBOMs of the workspace,
+/// or fails as aborted if the job ends without a result, e.g. because it panicked or never ran
+pub struct Generation {
+    receiver: oneshot::Receiver<Result<Vec<GeneratedSbom>, AsyncGeneratorError>>,
+}
+
+impl Future for Generation {
+    type Output = Result<Vec<GeneratedSbom>, AsyncGeneratorError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.receiver)
+            .poll(cx)
+            .map(|result| result.unwrap_or(Err(AsyncGeneratorError::Aborted)))
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum AsyncGeneratorError {
+    #[error(transparent)]
+    GeneratorError(#[from] GeneratorError),
+
+    #[error("The generation ended without a result")]
+    Aborted,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use assert_fs::prelude::*;
+    use futures_executor::block_on;
+
+    #[test]
+    fn it_should_generate_the_sboms_in_a_blocking_job() {
+        let tmp_dir = assert_fs::TempDir::new().expect("Failed to create a temporary directory");
+        tmp_dir
+            .child("Cargo.toml")
+            .write_str(r#"package = { name = "pkg", version = "0.0.0" }"#)
+            .expect("Failed to write the manifest");
+        tmp_dir
+            .child("src/lib.rs")
+            .touch()
+            .expect("Failed to write the library");
+
+        let boms = block_on(create_sboms(
+            tmp_dir.child("Cargo.toml").path(),
+            SbomConfig::empty_config(),
+            spawn_thread,
+        ))
+        .expect("Failed to generate the SBOMs");
+        assert_eq!(boms.len(), 1);
+        assert_eq!(boms[0].package_name, "pkg");
+
+        let result = block_on(create_sboms(
+            tmp_dir.child("missing/Cargo.toml").path(),
+            SbomConfig::empty_config(),
+            spawn_thread,
+        ));
+        assert!(matches!(
+            result,
+            Err(AsyncGeneratorError::GeneratorError(
+                GeneratorError::CargoConfigError { .. }
+            ))
+        ));
+    }
+
+    #[test]
+    fn it_should_fail_as_aborted_if_the_job_does_not_run() {
+        let result = block_on(create_sboms("Cargo.toml", SbomConfig::empty_config(), drop));
+        assert!(matches!(result, Err(AsyncGeneratorError::Aborted)));
+    }
+}
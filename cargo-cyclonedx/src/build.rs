@@ -0,0 +1,299 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+use cargo::core::Package;
+use serde::Deserialize;
+use std::collections::BTreeSet;
+use std::ffi::OsString;
+use std::io::{self, BufRead, BufReader};
+use std::path::Path;
+use std::process::{Child, ChildStdout, Command, ExitStatus, Stdio};
+use thiserror::Error;
+
+/// Packages that had at least one unit compiled (or found fresh) by `cargo build`, or that
+/// have a unit in its unit graph, identified by name and version
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct BuiltPackages(BTreeSet<(String, String)>);
+
+impl BuiltPackages {
+    pub fn contains(&self, package: &Package) -> bool {
+        self.0
+            .contains(&(package.name().to_string(), package.version().to_string()))
+    }
+
+    /// Collects the packages of every `compiler-artifact` message in cargo's JSON output
+    pub fn from_messages(reader: impl BufRead) -> Result<Self, BuildError> {
+        let mut packages = BTreeSet::new();
+
+        for line in reader.lines() {
+            let line = line.map_err(BuildError::OutputError)?;
+            // cargo passes through anything build scripts print, which need not be JSON
+            let message: Message = match serde_json::from_str(&line) {
+                Ok(message) => message,
+                Err(_) => continue,
+            };
+
+            if message.reason != "compiler-artifact" {
+                continue;
+            }
+
+            if let Some(package_id) = message.package_id {
+                match parse_package_id(&package_id) {
+                    Some(package) => {
+                        packages.insert(package);
+                    }
+                    None => log::warn!("Could not parse package id {} from cargo", package_id),
+                }
+            }
+        }
+
+        Ok(Self(packages))
+    }
+
+    /// Collects the packages of every unit in the output of `cargo build --unit-graph`
+    pub fn from_unit_graph(reader: impl io::Read) -> Result<Self, BuildError> {
+        let graph: UnitGraph =
+            serde_json::from_reader(reader).map_err(BuildError::UnitGraphError)?;
+
+        if graph.version != 1 {
+            log::warn!(
+                "Unit graph version {} is not known, the SBOM might be incomplete",
+                graph.version
+            );
+        }
+
+        let packages = graph
+            .units
+            .iter()
+            .filter_map(|unit| {
+                let package = parse_package_id(&unit.pkg_id);
+                if package.is_none() {
+                    log::warn!("Could not parse package id {} from cargo", unit.pkg_id);
+                }
+                package
+            })
+            .collect();
+
+        Ok(Self(packages))
+    }
+}
+
+#[derive(Deserialize)]
+struct UnitGraph {
+    version: u32,
+    units: Vec<Unit>,
+}
+
+#[derive(Deserialize)]
+struct Unit {
+    pkg_id: String,
+}
+
+#[derive(Deserialize)]
+struct Message {
+    reason: String,
+    package_id: Option<String>,
+}
+
+/// Extracts the name and version from both package id formats cargo has used in its messages:
+/// `name version (source)` and the package id spec `source#name@version` (or `source#version`
+/// when the name is the last segment of the source path)
+fn parse_package_id(package_id: &str) -> Option<(String, String)> {
+    if let Some((url, fragment)) = package_id.rsplit_once('#') {
+        return match fragment.split_once('@') {
+            Some((name, version)) => Some((name.to_string(), version.to_string())),
+            None => {
+                let path = url.split('?').next()?;
+                let name = path.trim_end_matches('/').rsplit('/').next()?;
+                Some((name.to_string(), fragment.to_string()))
+            }
+        };
+    }
+
+    let mut parts = package_id.split_whitespace();
+    match (parts.next(), parts.next()) {
+        (Some(name), Some(version)) => Some((name.to_string(), version.to_string())),
+        _ => None,
+    }
+}
+
+/// Runs `cargo build` with `args` for the manifest at `manifest_path` and returns the packages
+/// that were built
+///
+/// Diagnostics are still rendered on stderr, while the JSON messages on stdout are consumed.
+pub fn run_build(manifest_path: &Path, args: &[String]) -> Result<BuiltPackages, BuildError> {
+    let mut child = spawn_cargo_build(
+        manifest_path,
+        &["--message-format=json-render-diagnostics"],
+        args,
+    )?;
+
+    let built = BuiltPackages::from_messages(BufReader::new(child_stdout(&mut child)?))?;
+    wait(child)?;
+
+    Ok(built)
+}
+
+/// Runs `cargo build --unit-graph` with `args` for the manifest at `manifest_path` and returns
+/// the packages that would be compiled, without compiling anything
+///
+/// The unit graph is unstable and requires a nightly toolchain.
+pub fn run_unit_graph(manifest_path: &Path, args: &[String]) -> Result<BuiltPackages, BuildError> {
+    let mut child = spawn_cargo_build(
+        manifest_path,
+        &["--unit-graph", "-Z", "unstable-options"],
+        args,
+    )?;
+
+    let stdout = child_stdout(&mut child)?;
+    let built = BuiltPackages::from_unit_graph(stdout);
+    // a failed build does not print a unit graph, so its exit status is the more useful error
+    wait(child)?;
+
+    built
+}
+
+fn spawn_cargo_build(
+    manifest_path: &Path,
+    options: &[&str],
+    args: &[String],
+) -> Result<Child, BuildError> {
+    // cargo sets CARGO when running `cargo cyclonedx`
+    let cargo = std::env::var_os("CARGO").unwrap_or_else(|| OsString::from("cargo"));
+
+    log::info!(
+        "Running cargo build {} {}",
+        options.join(" "),
+        args.join(" ")
+    );
+    Command::new(cargo)
+        .arg("build")
+        .args(options)
+        .arg("--manifest-path")
+        .arg(manifest_path)
+        .args(args)
+        .stdout(Stdio::piped())
+        .spawn()
+        .map_err(BuildError::SpawnError)
+}
+
+fn child_stdout(child: &mut Child) -> Result<ChildStdout, BuildError> {
+    child.stdout.take().ok_or_else(|| {
+        BuildError::OutputError(io::Error::new(
+            io::ErrorKind::BrokenPipe,
+            "cargo build stdout is not captured",
+        ))
+    })
+}
+
+fn wait(mut child: Child) -> Result<(), BuildError> {
+    let status = child.wait().map_err(BuildError::OutputError)?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(BuildError::BuildFailed(status))
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum BuildError {
+    #[error("Could not run cargo build")]
+    SpawnError(#[source] io::Error),
+
+    #[error("Could not read the output of cargo build")]
+    OutputError(#[source] io::Error),
+
+    #[error("cargo build failed with {0}")]
+    BuildFailed(ExitStatus),
+
+    #[error("Could not parse the unit graph")]
+    UnitGraphError(#[source] serde_json::Error),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_should_parse_both_package_id_formats() {
+        let expected = Some(("serde".to_string(), "1.0.164".to_string()));
+
+        assert_eq!(
+            parse_package_id(
+                "serde 1.0.164 (registry+https://github.com/rust-lang/crates.io-index)"
+            ),
+            expected
+        );
+        assert_eq!(
+            parse_package_id("registry+https://github.com/rust-lang/crates.io-index#serde@1.0.164"),
+            expected
+        );
+        assert_eq!(
+            parse_package_id("path+file:///home/tacos/serde#1.0.164"),
+            expected
+        );
+        assert_eq!(
+            parse_package_id("git+https://github.com/serde-rs/serde?branch=main#serde@1.0.164"),
+            expected
+        );
+    }
+
+    #[test]
+    fn it_should_collect_only_compiler_artifacts() {
+        let output = r#"{"reason":"compiler-artifact","package_id":"path+file:///tmp/pkg#0.1.0","fresh":true}
+{"reason":"build-script-executed","package_id":"libc 0.2.146 (registry+https://github.com/rust-lang/crates.io-index)"}
+not json printed by a build script
+{"reason":"compiler-artifact","package_id":"registry+https://github.com/rust-lang/crates.io-index#log@0.4.19","fresh":false}
+{"reason":"build-finished","success":true}
+"#;
+
+        let actual = BuiltPackages::from_messages(output.as_bytes()).expect("Failed to parse");
+
+        let expected = BuiltPackages(
+            vec![
+                ("log".to_string(), "0.4.19".to_string()),
+                ("pkg".to_string(), "0.1.0".to_string()),
+            ]
+            .into_iter()
+            .collect(),
+        );
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn it_should_collect_the_packages_of_all_units() {
+        let output = r#"{"version":1,"units":[
+{"pkg_id":"path+file:///tmp/pkg#0.1.0","mode":"build","dependencies":[{"index":1}]},
+{"pkg_id":"registry+https://github.com/rust-lang/crates.io-index#log@0.4.19","mode":"build","dependencies":[]},
+{"pkg_id":"registry+https://github.com/rust-lang/crates.io-index#log@0.4.19","mode":"run-custom-build","dependencies":[]}
+],"roots":[0]}"#;
+
+        let actual = BuiltPackages::from_unit_graph(output.as_bytes()).expect("Failed to parse");
+
+        let expected = BuiltPackages(
+            vec![
+                ("log".to_string(), "0.4.19".to_string()),
+                ("pkg".to_string(), "0.1.0".to_string()),
+            ]
+            .into_iter()
+            .collect(),
+        );
+
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,204 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+use crate::format::Format;
+use cyclonedx_bom::external_models::normalized_string::NormalizedString;
+use cyclonedx_bom::models::component::{Classification, Component};
+use cyclonedx_bom::models::hash::{Hash, HashAlgorithm, Hashes};
+use cyclonedx_bom::models::metadata::Metadata;
+use cyclonedx_bom::models::property::{Properties, Property};
+use cyclonedx_bom::models::tool::{Tool, Tools};
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::fs::File;
+
+/// SHA-256 of the running executable, read once as it can be large
+static EXECUTABLE_SHA256: Lazy<Option<String>> = Lazy::new(|| {
+    let hash = std::env::current_exe()
+        .and_then(File::open)
+        .and_then(|mut file| {
+            let mut hasher = Sha256::new();
+            std::io::copy(&mut file, &mut hasher)?;
+            Ok(hasher.finalize())
+        });
+
+    match hash {
+        Ok(hash) => Some(format!("{:x}", hash)),
+        Err(error) => {
+            log::debug!("Failed to hash the cargo-cyclonedx executable: {}", error);
+            None
+        }
+    }
+});
+
+/// What the running cargo-cyclonedx was built from
+///
+/// * `version` - Version of the package
+/// * `commit` - Git commit of the sources, if they were built from a clone or a published package
+/// * `features` - Cargo features it was compiled with
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuildInfo {
+    pub version: &'static str,
+    pub commit: Option<&'static str>,
+    pub features: Vec<&'static str>,
+}
+
+impl BuildInfo {
+    pub fn current() -> Self {
+        let mut features = Vec::new();
+        if cfg!(feature = "memory-stats") {
+            features.push("memory-stats");
+        }
+
+        Self {
+            version: env!("CARGO_PKG_VERSION"),
+            commit: option_env!("CARGO_CYCLONEDX_COMMIT"),
+            features,
+        }
+    }
+
+    /// The entry for `metadata.tools`, with the SHA-256 hash of the executable that wrote the SBOM
+    pub fn tool(&self) -> Tool {
+        let tool = Tool::new("CycloneDX", "cargo-cyclonedx", self.version);
+        match EXECUTABLE_SHA256.as_ref() {
+            Some(sha256) => {
+                tool.with_hashes(Hashes(vec![Hash::new(HashAlgorithm::SHA256, sha256)]))
+            }
+            None => tool,
+        }
+    }
+
+    /// cargo-cyclonedx as an application component, for the annotations it writes
+    pub fn component(&self) -> Component {
+        let mut component = Component::new(
+            Classification::Application,
+            "cargo-cyclonedx",
+            self.version,
+            None,
+        );
+        component.group = Some(NormalizedString::new("CycloneDX"));
+        if let Some(sha256) = EXECUTABLE_SHA256.as_ref() {
+            component.add_hash(Hash::new(HashAlgorithm::SHA256, sha256));
+        }
+        component
+    }
+
+    /// The commit and features as `cargo-cyclonedx:*` properties, as version 1.3 of the
+    /// specification has no place for them in the tool itself
+    pub fn properties(&self) -> Vec<Property> {
+        let mut properties = Vec::new();
+        if let Some(commit) = self.commit {
+            properties.push(Property::new("cargo-cyclonedx:commit", commit));
+        }
+        if !self.features.is_empty() {
+            properties.push(Property::new(
+                "cargo-cyclonedx:features",
+                &self.features.join(","),
+            ));
+        }
+        properties
+    }
+
+    /// Records cargo-cyclonedx as the tool of `metadata`, and its commit and features after the
+    /// properties of `metadata`
+    pub fn record(&self, metadata: &mut Metadata) {
+        metadata.tools = Some(Tools::List(vec![self.tool()]));
+
+        let properties = self.properties();
+        if !properties.is_empty() {
+            metadata
+                .properties
+                .get_or_insert_with(|| Properties(Vec::new()))
+                .0
+                .extend(properties);
+        }
+    }
+
+    /// What the running cargo-cyclonedx can generate
+    pub fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            name: "cargo-cyclonedx",
+            version: self.version,
+            features: self.features.clone(),
+            formats: vec![Format::Json, Format::Xml],
+            documents: vec!["sbom", "saasbom", "vex"],
+            library: cyclonedx_bom::capabilities(),
+        }
+    }
+}
+
+/// What the running cargo-cyclonedx can generate, printed by `--capabilities` so that
+/// orchestration systems can detect features instead of assuming them from the version
+///
+/// * `features` - Cargo features it was compiled with
+/// * `formats` - Formats the documents can be written as
+/// * `documents` - Kinds of documents it writes, see
+///   [`Document`](crate::generator::Document)
+/// * `library` - What the `cyclonedx-bom` library supports, including the versions of the
+///   specification
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Capabilities {
+    pub name: &'static str,
+    pub version: &'static str,
+    pub features: Vec<&'static str>,
+    pub formats: Vec<Format>,
+    pub documents: Vec<&'static str>,
+    pub library: cyclonedx_bom::capabilities::Capabilities,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use cyclonedx_bom::models::bom::SpecVersion;
+
+    #[test]
+    fn it_should_describe_the_build() {
+        let build_info = BuildInfo {
+            version: "1.0.0",
+            commit: Some("0123456789abcdef0123456789abcdef01234567"),
+            features: vec!["memory-stats"],
+        };
+
+        let tool = build_info.tool();
+        assert_eq!(
+            tool.version.map(|v| v.to_string()),
+            Some("1.0.0".to_string())
+        );
+        let hashes = tool.hashes.expect("Missing the executable hash").0;
+        assert_eq!(hashes[0].alg, HashAlgorithm::SHA256);
+
+        assert_eq!(
+            build_info.properties(),
+            vec![
+                Property::new(
+                    "cargo-cyclonedx:commit",
+                    "0123456789abcdef0123456789abcdef01234567"
+                ),
+                Property::new("cargo-cyclonedx:features", "memory-stats"),
+            ]
+        );
+
+        let capabilities = build_info.capabilities();
+        assert_eq!(capabilities.version, "1.0.0");
+        assert_eq!(capabilities.features, vec!["memory-stats"]);
+        assert_eq!(
+            capabilities.library.spec_versions.len(),
+            SpecVersion::SUPPORTED.len()
+        );
+    }
+}
@@ -1,11 +1,24 @@
 use cargo_cyclonedx::{
     config::{
-        CdxExtension, CustomPrefix, IncludedDependencies, OutputOptions, Pattern, Prefix,
-        PrefixError, SbomConfig,
+        BuildProfile, CdxExtension, CustomPrefix, DocumentReference, DocumentReferences,
+        Duplicates, EmbeddedAssets, ErrorPolicy, ExternalBoms, FeatureMatrix, GitPurl,
+        IncludedDependencies, LicensePreference, LicenseTexts, OutputOptions, OverridesFile,
+        Pattern, Prefix, PrefixError, RegistryLinks, SbomConfig, ScanExclude, SourceFiles,
+        SpecVersion,
     },
+    container::ContainerImage,
+    convert::{ConversionFormat, ConversionTarget},
+    crosscheck::CrosscheckFormat,
     format::Format,
+    lockdiff::DiffFormat,
+    stats::{Percentage, StatsFormat},
+    validate::ValidationFormat,
 };
-use clap::{ArgGroup, Parser};
+use clap::{ArgGroup, CommandFactory, Parser, Subcommand};
+use clap_complete::Shell;
+use cyclonedx_bom::query::Filter;
+use cyclonedx_bom::validation::Severity;
+use std::io;
 use std::path;
 use thiserror::Error;
 
@@ -18,7 +31,7 @@ pub enum Opts {
 }
 
 #[derive(Parser, Debug)]
-#[clap(group(ArgGroup::new("dependencies-group").required(false).args(&["all", "top-level"])))]
+#[clap(group(ArgGroup::new("dependencies-group").required(false).args(&["all", "top-level", "library"])))]
 #[clap(group(ArgGroup::new("prefix-or-pattern-group").required(false).args(&["output-prefix", "output-pattern"])))]
 pub struct Args {
     /// Path to Cargo.toml
@@ -29,6 +42,10 @@ pub struct Args {
     #[clap(long = "format", short = 'f', value_name = "FORMAT")]
     pub format: Option<Format>,
 
+    /// Version of the CycloneDX specification the BOM conforms to: 1.3, 1.4, 1.5
+    #[clap(long = "spec-version", value_name = "VERSION")]
+    pub spec_version: Option<SpecVersion>,
+
     /// Use verbose output (-vv very verbose/build.rs output)
     #[clap(long = "verbose", short = 'v', action = clap::ArgAction::Count)]
     pub verbose: u8,
@@ -37,6 +54,18 @@ pub struct Args {
     #[clap(long = "quiet", short = 'q')]
     pub quiet: bool,
 
+    /// Require Cargo.lock and cache are up to date
+    #[clap(long = "frozen")]
+    pub frozen: bool,
+
+    /// Require Cargo.lock is up to date
+    #[clap(long = "locked")]
+    pub locked: bool,
+
+    /// Run without accessing the network
+    #[clap(long = "offline")]
+    pub offline: bool,
+
     /// List all dependencies instead of only top-level ones
     #[clap(long = "all", short = 'a')]
     pub all: bool,
@@ -45,6 +74,11 @@ pub struct Args {
     #[clap(name = "top-level", long = "top-level")]
     pub top_level: bool,
 
+    /// List only the dependencies a consumer of the library inherits: the normal dependencies
+    /// of the package that are not optional or are enabled by its default features
+    #[clap(long = "library")]
+    pub library: bool,
+
     /// Prepend file extension with .cdx
     #[clap(long = "output-cdx")]
     pub output_cdx: bool,
@@ -64,13 +98,403 @@ pub struct Args {
         value_name = "FILENAME_PREFIX"
     )]
     pub output_prefix: Option<String>,
+
+    /// Write the SBOMs to DIR instead of next to each Cargo.toml, creating it if needed
+    #[clap(long = "output-dir", value_name = "DIR")]
+    pub output_dir: Option<path::PathBuf>,
+
+    /// Write a single SBOM of the whole workspace next to the root manifest, with the other
+    /// workspace members nested in its metadata component, instead of one SBOM per member
+    #[clap(long = "merged")]
+    pub merged: bool,
+
+    /// Write a VEX document of the vulnerabilities of the dependencies next to each SBOM, from
+    /// the RustSec advisory database
+    #[clap(long = "vex")]
+    pub vex: bool,
+
+    /// Checkout of the RustSec advisory database (default: $CARGO_HOME/advisory-db)
+    #[clap(long = "advisory-db", value_name = "PATH", requires = "vex")]
+    pub advisory_db: Option<path::PathBuf>,
+
+    /// Known Exploited Vulnerabilities catalog of CISA, in JSON, to annotate the vulnerabilities
+    /// with
+    #[clap(long = "kev", value_name = "PATH", requires = "vex")]
+    pub kev: Option<path::PathBuf>,
+
+    /// EPSS scores of FIRST, in CSV, to rate the vulnerabilities with
+    #[clap(long = "epss", value_name = "PATH", requires = "vex")]
+    pub epss: Option<path::PathBuf>,
+
+    /// TOML file of the advisories to report as not affecting the packages, with the
+    /// justification and expiry date of each
+    #[clap(long = "suppressions", value_name = "PATH", requires = "vex")]
+    pub suppressions: Option<path::PathBuf>,
+
+    /// Keep the previous SBOM files with a .bak suffix when overwriting them
+    #[clap(long = "backup")]
+    pub backup: bool,
+
+    /// Write the SBOM files without waiting for other runs in the same workspace
+    #[clap(long = "no-lock")]
+    pub no_lock: bool,
+
+    /// Cargo profile whose settings are recorded in the SBOM metadata (default: release)
+    #[clap(long = "profile", value_name = "PROFILE")]
+    pub profile: Option<BuildProfile>,
+
+    /// List files embedded with include_bytes! or include_str! that are at least MIN_SIZE bytes
+    /// (default: 4096) as components
+    #[clap(
+        long = "embedded-assets",
+        value_name = "MIN_SIZE",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "true"
+    )]
+    pub embedded_assets: Option<EmbeddedAssets>,
+
+    /// List the files of each package, with their hashes, as components of the package
+    #[clap(long = "source-files")]
+    pub source_files: bool,
+
+    /// Link every crates.io package to its documentation on docs.rs and its dependency status on
+    /// deps.rs
+    #[clap(long = "registry-links")]
+    pub registry_links: bool,
+
+    /// Purl of the packages taken from a git repository: cargo, as for crates.io packages, or
+    /// repository, e.g. pkg:github/org/repo@<commit>, to tell patched forks from the crates.io
+    /// package they descend from (default: cargo)
+    #[clap(long = "git-purl", value_name = "TYPE")]
+    pub git_purl: Option<GitPurl>,
+
+    /// Embed the license files of every package, such as LICENSE-MIT, in its component, for
+    /// license reviews without access to the sources
+    #[clap(long = "license-texts")]
+    pub license_texts: bool,
+
+    /// Override the supplier, author, group or license of the components matching the rules of
+    /// the TOML or CSV mapping file at PATH, relative to the package root
+    #[clap(long = "overrides", value_name = "PATH")]
+    pub overrides: Option<path::PathBuf>,
+
+    /// Warn about the packages in use in semver-incompatible versions, with the shortest path to
+    /// each version, and annotate their components (annotations need --spec-version 1.5)
+    #[clap(long = "duplicates")]
+    pub duplicates: bool,
+
+    /// Create an SBOM for each feature set of a package: the default features, all features
+    /// and the named sets of the feature_sets option
+    #[clap(long = "feature-matrix")]
+    pub feature_matrix: bool,
+
+    /// How failures are handled: skip, warn or fail for all subsystems, or per subsystem, e.g.
+    /// hashing=fail,registry=warn (default: hashing=warn,registry=fail)
+    #[clap(long = "on-error", value_name = "POLICY")]
+    pub on_error: Option<ErrorPolicy>,
+
+    /// Skip the files matching GLOB, relative to the package root, when scanning the files of a
+    /// package. Can be passed multiple times
+    #[clap(long = "scan-exclude", value_name = "GLOB")]
+    pub scan_exclude: Vec<String>,
+
+    /// Comma separated SPDX licenses, most preferred first, used to choose between the
+    /// alternatives of OR license expressions, e.g. MIT,Apache-2.0
+    #[clap(long = "license-preference", value_name = "LICENSES")]
+    pub license_preference: Option<LicensePreference>,
+
+    /// Merge the CycloneDX BOM at PATH, generated for a non-Rust part of the package, into the
+    /// SBOM as an assembly. Can be passed multiple times
+    #[clap(long = "external-bom", value_name = "PATH")]
+    pub external_boms: Vec<path::PathBuf>,
+
+    /// Link the SBOM to e.g. the CI run that generates it, as an external reference of the given
+    /// type, e.g. build-system=https://ci.example.com/runs/42. Can be passed multiple times
+    #[clap(long = "external-reference", value_name = "TYPE=URL")]
+    pub external_references: Vec<DocumentReference>,
+
+    /// Record the container image the application ships in, e.g.
+    /// ghcr.io/org/app:1.0.0@sha256:<hex>, as the subject of the SBOM, with the package nested in it
+    #[clap(long = "container-image", value_name = "REF")]
+    pub container_image: Option<ContainerImage>,
+
+    /// Only keep the components matching EXPR, e.g. 'license ~ GPL || name = openssl-sys'
+    #[clap(long = "filter", value_name = "EXPR")]
+    pub filter: Option<Filter>,
+
+    /// Replace the names, Purls, references and URLs of internal components with pseudonyms
+    /// derived from SALT, e.g. to share the SBOM in a bug report
+    #[clap(long = "anonymize", value_name = "SALT")]
+    pub anonymize: Option<String>,
+
+    /// Fail if less than PERCENT of the components of an SBOM have a license, e.g. 95
+    #[clap(long = "require-license-coverage", value_name = "PERCENT")]
+    pub require_license_coverage: Option<Percentage>,
+
+    /// Fail if less than PERCENT of the components of an SBOM have a hash, e.g. 100
+    #[clap(long = "require-hash-coverage", value_name = "PERCENT")]
+    pub require_hash_coverage: Option<Percentage>,
+
+    /// Sign the SBOMs with the P-384 or Ed25519 private key in the PEM file at PATH
+    #[clap(long = "sign-key", value_name = "PATH")]
+    pub sign_key: Option<path::PathBuf>,
+
+    /// Write a JSON summary of the run to PATH, or to stdout if PATH is -
+    #[clap(long = "summary", value_name = "PATH")]
+    pub summary: Option<path::PathBuf>,
+
+    /// Report the peak memory usage and the memory allocated in each phase, also in the --summary
+    #[cfg(feature = "memory-stats")]
+    #[clap(long = "memory-stats")]
+    pub memory_stats: bool,
+
+    /// Print the supported specification versions, formats and documents as JSON and exit
+    #[clap(long = "capabilities")]
+    pub capabilities: bool,
+
+    /// Print the SBOM files that would be written without generating or writing them, or what
+    /// rescan and init would write
+    #[clap(long = "dry-run")]
+    pub dry_run: bool,
+
+    #[clap(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Inspect the configuration used to generate SBOMs
+    #[clap(subcommand)]
+    Config(ConfigCommand),
+
+    /// Add a [package.metadata.cyclonedx] section to the manifest, asking for each option
+    Init {
+        /// Write the default configuration without asking
+        #[clap(long = "defaults")]
+        defaults: bool,
+
+        /// Write to [workspace.metadata.cyclonedx] instead
+        #[clap(long = "workspace")]
+        workspace: bool,
+    },
+
+    /// Print the recommended [package.metadata.cyclonedx] block for a new project, e.g. from a
+    /// cargo-generate hook
+    Template {
+        /// Override the recommended values with the TOML block at URL, an http(s) URL or a path
+        #[clap(long = "org-defaults", value_name = "URL")]
+        org_defaults: Option<String>,
+
+        /// Print a [workspace.metadata.cyclonedx] block instead
+        #[clap(long = "workspace")]
+        workspace: bool,
+    },
+
+    /// Run cargo build and list only the dependencies that were compiled
+    Build {
+        /// Experimental: take the crates from `cargo build --unit-graph` instead of building,
+        /// which requires a nightly toolchain
+        #[clap(long = "unit-graph")]
+        unit_graph: bool,
+
+        /// Arguments passed to cargo build, e.g. `-- --release --features tacos`
+        #[clap(last = true, value_name = "CARGO_ARGS")]
+        cargo_args: Vec<String>,
+    },
+
+    /// Create the SBOM of the files packaged for cargo publish, next to the .crate files in
+    /// target/package
+    Prepublish {
+        /// Write the SBOM into the package root instead, so that it is published inside the
+        /// .crate, and fail if the package does not include it
+        #[clap(long = "include")]
+        include: bool,
+    },
+
+    /// Create a SaaSBOM of the services declared for each package, next to its SBOM
+    Saasbom,
+
+    /// Check the environment for problems that would prevent generating SBOMs
+    Doctor,
+
+    /// Classify the licenses of the dependencies of each package and report the dependency
+    /// paths that bring copyleft licenses into it
+    Copyleft,
+
+    /// Report the packages whose license files name other licenses than the license declared
+    /// in their manifest
+    LicenseDiscrepancies,
+
+    /// List the dependencies that were added, removed or updated in Cargo.lock since a git
+    /// revision
+    Lockdiff {
+        /// Git revision to compare from, e.g. a release tag
+        #[clap(long = "from", value_name = "GIT_REF")]
+        from: String,
+
+        /// Git revision to compare to (default: the Cargo.lock in the working tree)
+        #[clap(long = "to", value_name = "GIT_REF")]
+        to: Option<String>,
+
+        /// Output format: markdown, json
+        #[clap(long = "format", value_name = "FORMAT", default_value = "markdown")]
+        format: DiffFormat,
+    },
+
+    /// Print statistics of a BOM: component counts by type and scope, licenses, hash coverage,
+    /// dependency graph depth and duplicated crate versions
+    Stats {
+        /// BOM to read, in any supported format
+        #[clap(value_name = "BOM")]
+        path: path::PathBuf,
+
+        /// Output format: text, json
+        #[clap(long = "format", value_name = "FORMAT", default_value = "text")]
+        format: StatsFormat,
+    },
+
+    /// Validate an existing BOM, in any supported format, and exit with an error if it is invalid
+    Validate {
+        /// BOM to validate
+        #[clap(value_name = "BOM")]
+        path: path::PathBuf,
+
+        /// Output format: text, json
+        #[clap(long = "format", value_name = "FORMAT", default_value = "text")]
+        format: ValidationFormat,
+
+        /// Minimum severity of the reported failure reasons: error, warning, info
+        #[clap(long = "severity", value_name = "SEVERITY", default_value = "error")]
+        severity: Severity,
+    },
+
+    /// Replace the vulnerabilities of an existing JSON or XML BOM with those of its crates.io
+    /// components in the RustSec advisory database, and increment its version
+    Rescan {
+        /// BOM to update in place
+        #[clap(value_name = "BOM")]
+        path: path::PathBuf,
+
+        /// Checkout of the RustSec advisory database (default: $CARGO_HOME/advisory-db)
+        #[clap(long = "advisory-db", value_name = "PATH")]
+        advisory_db: Option<path::PathBuf>,
+
+        /// Known Exploited Vulnerabilities catalog of CISA, in JSON, to annotate the
+        /// vulnerabilities with
+        #[clap(long = "kev", value_name = "PATH")]
+        kev: Option<path::PathBuf>,
+
+        /// EPSS scores of FIRST, in CSV, to rate the vulnerabilities with
+        #[clap(long = "epss", value_name = "PATH")]
+        epss: Option<path::PathBuf>,
+
+        /// TOML file of the advisories to report as not affecting the BOM, with the
+        /// justification and expiry date of each
+        #[clap(long = "suppressions", value_name = "PATH")]
+        suppressions: Option<path::PathBuf>,
+    },
+
+    /// Cross-check a BOM against the output of `cargo audit --json` and of
+    /// `cargo deny --format json check`, and exit with an error if the reports mention crates
+    /// the BOM does not list
+    Crosscheck {
+        /// BOM to check, in any supported format
+        #[clap(value_name = "BOM")]
+        path: path::PathBuf,
+
+        /// Output of cargo-audit or cargo-deny to check the BOM against, recognized by its
+        /// content
+        #[clap(long = "report", value_name = "PATH", required = true)]
+        reports: Vec<path::PathBuf>,
+
+        /// Output format: text, json
+        #[clap(long = "format", value_name = "FORMAT", default_value = "text")]
+        format: CrosscheckFormat,
+    },
+
+    /// Convert a BOM, in any supported format, into an SPDX 2.3 document, or an SPDX document
+    /// into a BOM, and print it to stdout
+    Convert {
+        /// BOM or SPDX document to convert
+        #[clap(value_name = "INPUT")]
+        path: path::PathBuf,
+
+        /// Format to convert into: spdx, cyclonedx
+        #[clap(long = "to", value_name = "TARGET")]
+        to: ConversionTarget,
+
+        /// Output format: json, xml (cyclonedx only), tag-value (spdx only)
+        #[clap(long = "format", value_name = "FORMAT", default_value = "json")]
+        format: ConversionFormat,
+
+        /// Version of the CycloneDX specification a converted BOM conforms to: 1.3, 1.4, 1.5
+        #[clap(long = "spec-version", value_name = "VERSION", default_value = "1.5")]
+        spec_version: SpecVersion,
+
+        /// Write the converted document to a file instead of stdout
+        #[clap(long = "output", value_name = "PATH")]
+        output: Option<path::PathBuf>,
+    },
+
+    /// Create an SBOM of the binaries installed with cargo install, in the current directory
+    Installed,
+
+    /// Create an SBOM of the toolchains and components installed with rustup, in the current
+    /// directory
+    Toolchains,
+
+    /// Print a shell completion script to stdout
+    Completions {
+        /// Shell to generate the completion script for: bash, elvish, fish, powershell, zsh
+        #[clap(value_name = "SHELL")]
+        shell: Shell,
+    },
+
+    /// Print a man page in roff format to stdout
+    Manpage,
+
+    /// Print the description of an error code, such as CDX0201, and the steps to resolve it, or
+    /// list all error codes
+    Explain {
+        /// Error code to explain
+        #[clap(value_name = "CODE")]
+        code: Option<String>,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ConfigCommand {
+    /// Print the effective configuration of every package and the source of each value
+    Show,
+}
+
+impl Opts {
+    /// Writes a completion script for `shell` covering `cargo cyclonedx` and its subcommands
+    pub fn write_completions(shell: Shell, out: &mut dyn io::Write) {
+        let mut command = Opts::command();
+        clap_complete::generate(shell, &mut command, "cargo", out);
+    }
+
+    /// Writes the `cargo-cyclonedx(1)` man page
+    pub fn write_manpage(out: &mut dyn io::Write) -> io::Result<()> {
+        let command = Opts::command();
+        let command = command
+            .find_subcommand("cyclonedx")
+            .expect("cyclonedx subcommand is always defined")
+            .clone()
+            .name("cargo-cyclonedx")
+            .version(env!("CARGO_PKG_VERSION"));
+        clap_mangen::Man::new(command).render(out)
+    }
 }
 
 impl Args {
     pub fn as_config(&self) -> Result<SbomConfig, ArgsError> {
-        let included_dependencies = match (self.all, self.top_level) {
-            (true, _) => Some(IncludedDependencies::AllDependencies),
-            (_, true) => Some(IncludedDependencies::TopLevelDependencies),
+        let included_dependencies = match (self.all, self.top_level, self.library) {
+            (true, _, _) => Some(IncludedDependencies::AllDependencies),
+            (_, true, _) => Some(IncludedDependencies::TopLevelDependencies),
+            (_, _, true) => Some(IncludedDependencies::Library),
             _ => None,
         };
 
@@ -104,10 +528,52 @@ impl Args {
             (_, _) => None,
         };
 
+        let scan_exclude = match self.scan_exclude.as_slice() {
+            [] => None,
+            globs => Some(ScanExclude::new(globs.to_vec()).map_err(ArgsError::ScanExcludeError)?),
+        };
+
+        // relative to the working directory rather than to the root of each package
+        let external_boms = match self.external_boms.as_slice() {
+            [] => None,
+            paths => Some(ExternalBoms::new(
+                paths
+                    .iter()
+                    .map(|path| match std::env::current_dir() {
+                        Ok(dir) => dir.join(path),
+                        Err(_) => path.clone(),
+                    })
+                    .collect(),
+            )),
+        };
+
         Ok(SbomConfig {
             format: self.format,
+            spec_version: self.spec_version,
             included_dependencies,
             output_options,
+            supplier: None,
+            profile: self.profile.clone(),
+            embedded_assets: self.embedded_assets,
+            source_files: self.source_files.then_some(SourceFiles::Included),
+            scan_exclude,
+            license_preference: self.license_preference.clone(),
+            external_boms,
+            registry_links: self.registry_links.then_some(RegistryLinks::Included),
+            duplicates: self.duplicates.then_some(Duplicates::Annotated),
+            feature_matrix: self.feature_matrix.then_some(FeatureMatrix::Enabled),
+            feature_sets: None,
+            on_error: self.on_error,
+            services: None,
+            external_references: match self.external_references.as_slice() {
+                [] => None,
+                references => Some(DocumentReferences::new(references.to_vec())),
+            },
+            enrichers: None,
+            document_license: None,
+            git_purl: self.git_purl,
+            license_texts: self.license_texts.then_some(LicenseTexts::Included),
+            overrides: self.overrides.clone().map(OverridesFile::new),
         })
     }
 }
@@ -116,4 +582,7 @@ impl Args {
 pub enum ArgsError {
     #[error("Invalid prefix from CLI")]
     CustomPrefixError(#[from] PrefixError),
+
+    #[error("Invalid --scan-exclude: {0}")]
+    ScanExcludeError(String),
 }
@@ -1,4 +1,9 @@
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::time::Duration;
 
 use thiserror::Error;
 
@@ -20,31 +25,113 @@ use thiserror::Error;
  * SPDX-License-Identifier: Apache-2.0
  */
 use crate::format::Format;
+use cyclonedx_bom::external_models::spdx::SpdxExpression;
+use cyclonedx_bom::external_models::uri::Uri;
+pub use cyclonedx_bom::models::bom::SpecVersion;
+use std::convert::TryFrom;
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SbomConfig {
     pub format: Option<Format>,
+    pub spec_version: Option<SpecVersion>,
     pub included_dependencies: Option<IncludedDependencies>,
     pub output_options: Option<OutputOptions>,
+    pub supplier: Option<Supplier>,
+    pub profile: Option<BuildProfile>,
+    pub embedded_assets: Option<EmbeddedAssets>,
+    pub source_files: Option<SourceFiles>,
+    pub scan_exclude: Option<ScanExclude>,
+    pub license_preference: Option<LicensePreference>,
+    pub external_boms: Option<ExternalBoms>,
+    pub registry_links: Option<RegistryLinks>,
+    pub duplicates: Option<Duplicates>,
+    pub feature_matrix: Option<FeatureMatrix>,
+    pub feature_sets: Option<FeatureSets>,
+    pub on_error: Option<ErrorPolicy>,
+    pub services: Option<DeclaredServices>,
+    pub external_references: Option<DocumentReferences>,
+    pub enrichers: Option<Enrichers>,
+    pub document_license: Option<DocumentLicense>,
+    pub git_purl: Option<GitPurl>,
+    pub license_texts: Option<LicenseTexts>,
+    pub overrides: Option<OverridesFile>,
 }
 
 impl SbomConfig {
     pub fn empty_config() -> Self {
         Self {
             format: None,
+            spec_version: None,
             included_dependencies: None,
             output_options: None,
+            supplier: None,
+            profile: None,
+            embedded_assets: None,
+            source_files: None,
+            scan_exclude: None,
+            license_preference: None,
+            external_boms: None,
+            registry_links: None,
+            duplicates: None,
+            feature_matrix: None,
+            feature_sets: None,
+            on_error: None,
+            services: None,
+            external_references: None,
+            enrichers: None,
+            document_license: None,
+            git_purl: None,
+            license_texts: None,
+            overrides: None,
         }
     }
 
     pub fn merge(&self, other: &SbomConfig) -> SbomConfig {
         SbomConfig {
             format: other.format.or(self.format),
+            spec_version: other.spec_version.or(self.spec_version),
             included_dependencies: other.included_dependencies.or(self.included_dependencies),
             output_options: other
                 .output_options
                 .clone()
                 .or_else(|| self.output_options.clone()),
+            supplier: other.supplier.clone().or_else(|| self.supplier.clone()),
+            profile: other.profile.clone().or_else(|| self.profile.clone()),
+            embedded_assets: other.embedded_assets.or(self.embedded_assets),
+            source_files: other.source_files.or(self.source_files),
+            scan_exclude: other
+                .scan_exclude
+                .clone()
+                .or_else(|| self.scan_exclude.clone()),
+            license_preference: other
+                .license_preference
+                .clone()
+                .or_else(|| self.license_preference.clone()),
+            external_boms: other
+                .external_boms
+                .clone()
+                .or_else(|| self.external_boms.clone()),
+            registry_links: other.registry_links.or(self.registry_links),
+            duplicates: other.duplicates.or(self.duplicates),
+            feature_matrix: other.feature_matrix.or(self.feature_matrix),
+            feature_sets: other
+                .feature_sets
+                .clone()
+                .or_else(|| self.feature_sets.clone()),
+            on_error: other.on_error.or(self.on_error),
+            services: other.services.clone().or_else(|| self.services.clone()),
+            external_references: other
+                .external_references
+                .clone()
+                .or_else(|| self.external_references.clone()),
+            enrichers: other.enrichers.clone().or_else(|| self.enrichers.clone()),
+            document_license: other
+                .document_license
+                .clone()
+                .or_else(|| self.document_license.clone()),
+            git_purl: other.git_purl.or(self.git_purl),
+            license_texts: other.license_texts.or(self.license_texts),
+            overrides: other.overrides.clone().or_else(|| self.overrides.clone()),
         }
     }
 
@@ -52,6 +139,10 @@ impl SbomConfig {
         self.format.unwrap_or_default()
     }
 
+    pub fn spec_version(&self) -> SpecVersion {
+        self.spec_version.unwrap_or_default()
+    }
+
     pub fn included_dependencies(&self) -> IncludedDependencies {
         self.included_dependencies.unwrap_or_default()
     }
@@ -59,20 +150,188 @@ impl SbomConfig {
     pub fn output_options(&self) -> OutputOptions {
         self.output_options.clone().unwrap_or_default()
     }
+
+    pub fn profile(&self) -> BuildProfile {
+        self.profile.clone().unwrap_or_default()
+    }
+
+    pub fn embedded_assets(&self) -> EmbeddedAssets {
+        self.embedded_assets.unwrap_or_default()
+    }
+
+    pub fn source_files(&self) -> SourceFiles {
+        self.source_files.unwrap_or_default()
+    }
+
+    pub fn scan_exclude(&self) -> ScanExclude {
+        self.scan_exclude.clone().unwrap_or_default()
+    }
+
+    pub fn license_preference(&self) -> LicensePreference {
+        self.license_preference.clone().unwrap_or_default()
+    }
+
+    pub fn external_boms(&self) -> ExternalBoms {
+        self.external_boms.clone().unwrap_or_default()
+    }
+
+    pub fn registry_links(&self) -> RegistryLinks {
+        self.registry_links.unwrap_or_default()
+    }
+
+    pub fn duplicates(&self) -> Duplicates {
+        self.duplicates.unwrap_or_default()
+    }
+
+    pub fn feature_matrix(&self) -> FeatureMatrix {
+        self.feature_matrix.unwrap_or_default()
+    }
+
+    pub fn feature_sets(&self) -> FeatureSets {
+        self.feature_sets.clone().unwrap_or_default()
+    }
+
+    pub fn on_error(&self) -> ErrorPolicy {
+        self.on_error.unwrap_or_default()
+    }
+
+    pub fn services(&self) -> DeclaredServices {
+        self.services.clone().unwrap_or_default()
+    }
+
+    pub fn external_references(&self) -> DocumentReferences {
+        self.external_references.clone().unwrap_or_default()
+    }
+
+    pub fn enrichers(&self) -> Enrichers {
+        self.enrichers.clone().unwrap_or_default()
+    }
+
+    pub fn git_purl(&self) -> GitPurl {
+        self.git_purl.unwrap_or_default()
+    }
+
+    pub fn license_texts(&self) -> LicenseTexts {
+        self.license_texts.unwrap_or_default()
+    }
 }
 
+/// Where the value of a configuration option was taken from
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum IncludedDependencies {
-    TopLevelDependencies,
-    AllDependencies,
+pub enum ConfigSource {
+    Default,
+    WorkspaceMetadata,
+    PackageMetadata,
+    Environment,
+    CommandLine,
 }
 
-impl Default for IncludedDependencies {
-    fn default() -> Self {
-        Self::TopLevelDependencies
+impl fmt::Display for ConfigSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigSource::Default => "default".fmt(f),
+            ConfigSource::WorkspaceMetadata => "workspace metadata".fmt(f),
+            ConfigSource::PackageMetadata => "package metadata".fmt(f),
+            ConfigSource::Environment => "environment".fmt(f),
+            ConfigSource::CommandLine => "command line".fmt(f),
+        }
+    }
+}
+
+/// A configuration value together with the source that provided it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sourced<T> {
+    pub value: T,
+    pub source: ConfigSource,
+}
+
+/// The fully resolved configuration, recording which source each value came from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EffectiveConfig {
+    pub format: Sourced<Format>,
+    pub spec_version: Sourced<SpecVersion>,
+    pub included_dependencies: Sourced<IncludedDependencies>,
+    pub output_options: Sourced<OutputOptions>,
+    pub supplier: Sourced<Option<Supplier>>,
+    pub profile: Sourced<BuildProfile>,
+    pub embedded_assets: Sourced<EmbeddedAssets>,
+    pub source_files: Sourced<SourceFiles>,
+    pub scan_exclude: Sourced<ScanExclude>,
+    pub license_preference: Sourced<LicensePreference>,
+    pub external_boms: Sourced<ExternalBoms>,
+    pub registry_links: Sourced<RegistryLinks>,
+    pub duplicates: Sourced<Duplicates>,
+    pub feature_matrix: Sourced<FeatureMatrix>,
+    pub feature_sets: Sourced<FeatureSets>,
+    pub on_error: Sourced<ErrorPolicy>,
+    pub services: Sourced<DeclaredServices>,
+    pub external_references: Sourced<DocumentReferences>,
+    pub enrichers: Sourced<Enrichers>,
+    pub document_license: Sourced<Option<DocumentLicense>>,
+    pub git_purl: Sourced<GitPurl>,
+    pub license_texts: Sourced<LicenseTexts>,
+    pub overrides: Sourced<Option<OverridesFile>>,
+}
+
+impl EffectiveConfig {
+    /// Resolves every option from `layers`, which are ordered from lowest to highest precedence
+    pub fn resolve(layers: &[(ConfigSource, &SbomConfig)]) -> Self {
+        Self {
+            format: pick(layers, |c| c.format),
+            spec_version: pick(layers, |c| c.spec_version),
+            included_dependencies: pick(layers, |c| c.included_dependencies),
+            output_options: pick(layers, |c| c.output_options.clone()),
+            supplier: pick(layers, |c| c.supplier.clone().map(Some)),
+            profile: pick(layers, |c| c.profile.clone()),
+            embedded_assets: pick(layers, |c| c.embedded_assets),
+            source_files: pick(layers, |c| c.source_files),
+            scan_exclude: pick(layers, |c| c.scan_exclude.clone()),
+            license_preference: pick(layers, |c| c.license_preference.clone()),
+            external_boms: pick(layers, |c| c.external_boms.clone()),
+            registry_links: pick(layers, |c| c.registry_links),
+            duplicates: pick(layers, |c| c.duplicates),
+            feature_matrix: pick(layers, |c| c.feature_matrix),
+            feature_sets: pick(layers, |c| c.feature_sets.clone()),
+            on_error: pick(layers, |c| c.on_error),
+            services: pick(layers, |c| c.services.clone()),
+            external_references: pick(layers, |c| c.external_references.clone()),
+            enrichers: pick(layers, |c| c.enrichers.clone()),
+            document_license: pick(layers, |c| c.document_license.clone().map(Some)),
+            git_purl: pick(layers, |c| c.git_purl),
+            license_texts: pick(layers, |c| c.license_texts),
+            overrides: pick(layers, |c| c.overrides.clone().map(Some)),
+        }
     }
 }
 
+fn pick<T: Default>(
+    layers: &[(ConfigSource, &SbomConfig)],
+    get: impl Fn(&SbomConfig) -> Option<T>,
+) -> Sourced<T> {
+    layers
+        .iter()
+        .rev()
+        .find_map(|(source, config)| {
+            get(config).map(|value| Sourced {
+                value,
+                source: *source,
+            })
+        })
+        .unwrap_or_else(|| Sourced {
+            value: T::default(),
+            source: ConfigSource::Default,
+        })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IncludedDependencies {
+    #[default]
+    TopLevelDependencies,
+    AllDependencies,
+    /// The direct dependencies a consumer of the library inherits
+    Library,
+}
+
 impl FromStr for IncludedDependencies {
     type Err = String;
 
@@ -80,7 +339,18 @@ impl FromStr for IncludedDependencies {
         match s {
             "all" => Ok(Self::AllDependencies),
             "top-level" => Ok(Self::TopLevelDependencies),
-            _ => Err(format!("Expected all or top-level, got `{}`", s)),
+            "library" => Ok(Self::Library),
+            _ => Err(format!("Expected all, top-level or library, got `{}`", s)),
+        }
+    }
+}
+
+impl fmt::Display for IncludedDependencies {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IncludedDependencies::TopLevelDependencies => "top-level".fmt(f),
+            IncludedDependencies::AllDependencies => "all".fmt(f),
+            IncludedDependencies::Library => "library".fmt(f),
         }
     }
 }
@@ -100,106 +370,1726 @@ impl Default for OutputOptions {
     }
 }
 
+/// Formats the options the same way they are written in the `Cargo.toml` metadata
+impl fmt::Display for OutputOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cdx = self.cdx_extension == CdxExtension::Included;
+        match &self.prefix {
+            Prefix::Pattern(pattern) => {
+                write!(f, "{{ cdx = {}, pattern = \"{}\" }}", cdx, pattern)
+            }
+            Prefix::Custom(prefix) => write!(f, "{{ cdx = {}, prefix = \"{}\" }}", cdx, prefix),
+        }
+    }
+}
+
+/// The cargo profile whose settings are recorded in the SBOM metadata
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub enum CdxExtension {
+pub struct BuildProfile(String);
+
+impl BuildProfile {
+    pub fn new(name: impl Into<String>) -> Result<Self, String> {
+        let name = name.into();
+        if name.is_empty() {
+            Err("Expected a profile name, got an empty string".to_string())
+        } else {
+            Ok(Self(name))
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Default for BuildProfile {
+    fn default() -> Self {
+        Self("release".to_string())
+    }
+}
+
+impl FromStr for BuildProfile {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl fmt::Display for BuildProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Files smaller than this are not considered large enough to list as embedded assets
+pub const DEFAULT_MIN_ASSET_SIZE: u64 = 4096;
+
+/// Whether files embedded with `include_bytes!` and `include_str!` are listed as components
+///
+/// Written in the `Cargo.toml` metadata as `false`, `true`, or the minimum size in bytes of the
+/// files to list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EmbeddedAssets {
+    #[default]
+    Disabled,
+    Enabled {
+        min_size: u64,
+    },
+}
+
+impl FromStr for EmbeddedAssets {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "false" => Ok(Self::Disabled),
+            "true" => Ok(Self::Enabled {
+                min_size: DEFAULT_MIN_ASSET_SIZE,
+            }),
+            _ => s
+                .parse()
+                .map(|min_size| Self::Enabled { min_size })
+                .map_err(|_| format!("Expected true, false, or a size in bytes, got `{}`", s)),
+        }
+    }
+}
+
+impl fmt::Display for EmbeddedAssets {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EmbeddedAssets::Disabled => "false".fmt(f),
+            EmbeddedAssets::Enabled { min_size } => min_size.fmt(f),
+        }
+    }
+}
+
+/// Whether the files of the package are listed as components nested in the metadata component
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SourceFiles {
     Included,
+    #[default]
     NotIncluded,
 }
 
-impl CdxExtension {
-    pub fn extension(&self) -> String {
-        match &self {
-            CdxExtension::Included => ".cdx".to_string(),
-            CdxExtension::NotIncluded => "".to_string(),
+impl FromStr for SourceFiles {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "true" => Ok(Self::Included),
+            "false" => Ok(Self::NotIncluded),
+            _ => Err(format!("Expected true or false, got `{}`", s)),
         }
     }
 }
 
-impl Default for CdxExtension {
-    fn default() -> Self {
-        Self::NotIncluded
+impl fmt::Display for SourceFiles {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SourceFiles::Included => "true".fmt(f),
+            SourceFiles::NotIncluded => "false".fmt(f),
+        }
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub enum Prefix {
-    Pattern(Pattern),
-    Custom(CustomPrefix),
+/// Whether crates.io packages get `documentation` and `other` external references to their
+/// pages on docs.rs and deps.rs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RegistryLinks {
+    Included,
+    #[default]
+    NotIncluded,
 }
 
-impl Default for Prefix {
-    fn default() -> Self {
-        Self::Pattern(Pattern::default())
+impl FromStr for RegistryLinks {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "true" => Ok(Self::Included),
+            "false" => Ok(Self::NotIncluded),
+            _ => Err(format!("Expected true or false, got `{}`", s)),
+        }
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum Pattern {
-    Bom,
-    Package,
+impl fmt::Display for RegistryLinks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RegistryLinks::Included => "true".fmt(f),
+            RegistryLinks::NotIncluded => "false".fmt(f),
+        }
+    }
 }
 
-impl Default for Pattern {
-    fn default() -> Self {
-        Self::Bom
+/// Whether the license files of packages are embedded in their components, so the licenses can
+/// be reviewed from the SBOM alone
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LicenseTexts {
+    Included,
+    #[default]
+    NotIncluded,
+}
+
+impl FromStr for LicenseTexts {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "true" => Ok(Self::Included),
+            "false" => Ok(Self::NotIncluded),
+            _ => Err(format!("Expected true or false, got `{}`", s)),
+        }
     }
 }
 
-impl FromStr for Pattern {
+impl fmt::Display for LicenseTexts {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LicenseTexts::Included => "true".fmt(f),
+            LicenseTexts::NotIncluded => "false".fmt(f),
+        }
+    }
+}
+
+/// Whether packages in use in semver-incompatible versions are reported as warnings and
+/// annotations of their components
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Duplicates {
+    Annotated,
+    #[default]
+    NotAnnotated,
+}
+
+impl FromStr for Duplicates {
     type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "bom" => Ok(Self::Bom),
-            "package" => Ok(Self::Package),
-            _ => Err(format!("Expected bom or package, got `{}`", s)),
+            "true" => Ok(Self::Annotated),
+            "false" => Ok(Self::NotAnnotated),
+            _ => Err(format!("Expected true or false, got `{}`", s)),
         }
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct CustomPrefix(String);
+impl fmt::Display for Duplicates {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Duplicates::Annotated => "true".fmt(f),
+            Duplicates::NotAnnotated => "false".fmt(f),
+        }
+    }
+}
 
-impl CustomPrefix {
-    pub fn new(custom_prefix: impl Into<String>) -> Result<Self, PrefixError> {
-        let prefix = custom_prefix.into();
+/// The Purl of packages taken from a git repository instead of a registry, which are often patched
+/// forks of the crates.io package of the same name
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GitPurl {
+    /// `pkg:cargo`, as for crates.io packages
+    #[default]
+    Cargo,
+    /// `pkg:github` for GitHub repositories and `pkg:generic` with a `vcs_url` qualifier for other
+    /// repositories, with the crates.io package recorded as ancestor in the pedigree
+    Repository,
+}
+
+impl FromStr for GitPurl {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "cargo" => Ok(Self::Cargo),
+            "repository" => Ok(Self::Repository),
+            _ => Err(format!("Expected cargo or repository, got `{}`", s)),
+        }
+    }
+}
+
+impl fmt::Display for GitPurl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GitPurl::Cargo => "cargo".fmt(f),
+            GitPurl::Repository => "repository".fmt(f),
+        }
+    }
+}
+
+/// Whether an SBOM is generated for each feature set of a package instead of a single one
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FeatureMatrix {
+    Enabled,
+    #[default]
+    Disabled,
+}
+
+impl FromStr for FeatureMatrix {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "true" => Ok(Self::Enabled),
+            "false" => Ok(Self::Disabled),
+            _ => Err(format!("Expected true or false, got `{}`", s)),
+        }
+    }
+}
+
+impl fmt::Display for FeatureMatrix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FeatureMatrix::Enabled => "true".fmt(f),
+            FeatureMatrix::Disabled => "false".fmt(f),
+        }
+    }
+}
+
+/// Named sets of features that the feature matrix generates SBOMs for, in addition to the
+/// `default` features and `all` features
+///
+/// A set activates the features it lists, without the default features unless it lists
+/// `default`. Its name becomes part of the file name of the SBOM.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct FeatureSets(BTreeMap<String, Vec<String>>);
+
+impl FeatureSets {
+    pub fn new(sets: BTreeMap<String, Vec<String>>) -> Result<Self, String> {
+        for name in sets.keys() {
+            if name == "default" || name == "all" {
+                return Err(format!("The feature set `{}` cannot be redefined", name));
+            }
+            let valid = |c: char| c.is_ascii_alphanumeric() || c == '-' || c == '_';
+            if name.is_empty() || !name.chars().all(valid) {
+                return Err(format!(
+                    "Expected a feature set name of letters, digits, `-` and `_`, got `{}`",
+                    name
+                ));
+            }
+        }
+        Ok(Self(sets))
+    }
+
+    pub fn sets(&self) -> &BTreeMap<String, Vec<String>> {
+        &self.0
+    }
+}
 
-        if prefix.contains(std::path::MAIN_SEPARATOR) {
-            Err(PrefixError::CustomPrefixError(
-                std::path::MAIN_SEPARATOR.to_string(),
-            ))
+/// Formats the sets as a TOML inline table
+impl fmt::Display for FeatureSets {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sets: Vec<_> = self
+            .0
+            .iter()
+            .map(|(name, features)| {
+                let features: Vec<_> = features
+                    .iter()
+                    .map(|feature| format!("{:?}", feature))
+                    .collect();
+                format!("{} = [{}]", name, features.join(", "))
+            })
+            .collect();
+        if sets.is_empty() {
+            write!(f, "{{}}")
         } else {
-            Ok(Self(prefix))
+            write!(f, "{{ {} }}", sets.join(", "))
         }
     }
 }
 
-impl ToString for CustomPrefix {
-    fn to_string(&self) -> String {
-        self.0.clone()
+/// What happens when part of the information about a package cannot be retrieved
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OnError {
+    /// Leave the information out without a message, except in the debug log
+    Skip,
+    /// Leave the information out with a warning
+    Warn,
+    /// Stop generating the SBOM with an error
+    Fail,
+}
+
+impl FromStr for OnError {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "skip" => Ok(Self::Skip),
+            "warn" => Ok(Self::Warn),
+            "fail" => Ok(Self::Fail),
+            _ => Err(format!("Expected skip, warn or fail, got `{}`", s)),
+        }
     }
 }
 
-#[derive(Error, Debug, PartialEq, Eq)]
-pub enum PrefixError {
-    #[error("Illegal characters in custom prefix string: {0}")]
-    CustomPrefixError(String),
+impl fmt::Display for OnError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OnError::Skip => "skip".fmt(f),
+            OnError::Warn => "warn".fmt(f),
+            OnError::Fail => "fail".fmt(f),
+        }
+    }
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
+impl OnError {
+    /// Hands `error` back to fail with, or logs it as a warning or debug message
+    pub fn handle(self, error: anyhow::Error) -> anyhow::Result<()> {
+        match self {
+            OnError::Fail => return Err(error),
+            OnError::Warn => log::warn!("{:#}", error),
+            OnError::Skip => log::debug!("{:#}", error),
+        }
+        Ok(())
+    }
+}
 
-    #[test]
-    fn it_should_error_for_a_prefix_with_a_path_separator() {
-        let prefix = format!("directory{}prefix", std::path::MAIN_SEPARATOR);
+/// How failures are handled by each subsystem
+///
+/// * `hashing` - Reading and hashing the `.crate` files of registry packages and the files
+///   listed with `source_files` and `embedded_assets`, a warning by default
+/// * `registry` - Retrieving the packages of the dependencies from their registry, an error by
+///   default
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ErrorPolicy {
+    pub hashing: OnError,
+    pub registry: OnError,
+}
 
-        let actual = CustomPrefix::new(prefix)
-            .expect_err("Should not have been able to create CustomPrefix with path separator");
+impl Default for ErrorPolicy {
+    fn default() -> Self {
+        Self {
+            hashing: OnError::Warn,
+            registry: OnError::Fail,
+        }
+    }
+}
 
-        let expected = PrefixError::CustomPrefixError(std::path::MAIN_SEPARATOR.to_string());
+impl ErrorPolicy {
+    /// Handles the failures of every subsystem the same way
+    pub fn all(on_error: OnError) -> Self {
+        Self {
+            hashing: on_error,
+            registry: on_error,
+        }
+    }
 
-        assert_eq!(actual, expected);
+    /// Changes how the failures of `subsystem` are handled
+    pub fn set(&mut self, subsystem: &str, on_error: OnError) -> Result<(), String> {
+        match subsystem {
+            "hashing" => self.hashing = on_error,
+            "registry" => self.registry = on_error,
+            _ => {
+                return Err(format!(
+                    "Expected the subsystem hashing or registry, got `{}`",
+                    subsystem
+                ))
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parses either the handling of all subsystems, e.g. `warn`, or comma separated subsystems with
+/// their handling, e.g. `hashing=fail,registry=warn`, where the other subsystems keep their default
+impl FromStr for ErrorPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if !s.contains('=') {
+            return Ok(Self::all(s.trim().parse()?));
+        }
+
+        let mut policy = Self::default();
+        for entry in s
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+        {
+            let (subsystem, on_error) = entry
+                .split_once('=')
+                .ok_or_else(|| format!("Expected <subsystem>=<skip|warn|fail>, got `{}`", entry))?;
+            policy.set(subsystem.trim(), on_error.trim().parse()?)?;
+        }
+        Ok(policy)
+    }
+}
+
+/// Formats the policy as a TOML inline table
+impl fmt::Display for ErrorPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{{ hashing = \"{}\", registry = \"{}\" }}",
+            self.hashing, self.registry
+        )
+    }
+}
+
+/// Direction in which data flows between a service and its callers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Flow {
+    Inbound,
+    Outbound,
+    BiDirectional,
+    Unknown,
+}
+
+impl FromStr for Flow {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "inbound" => Ok(Self::Inbound),
+            "outbound" => Ok(Self::Outbound),
+            "bi-directional" => Ok(Self::BiDirectional),
+            "unknown" => Ok(Self::Unknown),
+            _ => Err(format!(
+                "Expected inbound, outbound, bi-directional or unknown, got `{}`",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Flow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Flow::Inbound => "inbound".fmt(f),
+            Flow::Outbound => "outbound".fmt(f),
+            Flow::BiDirectional => "bi-directional".fmt(f),
+            Flow::Unknown => "unknown".fmt(f),
+        }
+    }
+}
+
+/// Data a service exchanges, with the direction it flows in and its classification, e.g. `PII`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataFlow {
+    pub flow: Flow,
+    pub classification: String,
+}
+
+/// A service that the package provides or calls, which cargo does not know about
+///
+/// * `endpoints` - URLs of the service
+/// * `authenticated` - Whether the service requires authentication
+/// * `x_trust_boundary` - Whether calling the service crosses a trust boundary
+/// * `data` - Data the service exchanges
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeclaredService {
+    pub name: String,
+    pub description: Option<String>,
+    pub endpoints: Vec<String>,
+    pub authenticated: Option<bool>,
+    pub x_trust_boundary: Option<bool>,
+    pub data: Vec<DataFlow>,
+}
+
+/// Formats the service as a TOML inline table
+impl fmt::Display for DeclaredService {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{{ name = {}", toml::Value::from(self.name.as_str()))?;
+        if let Some(description) = &self.description {
+            write!(
+                f,
+                ", description = {}",
+                toml::Value::from(description.as_str())
+            )?;
+        }
+        if !self.endpoints.is_empty() {
+            let endpoints: Vec<_> = self
+                .endpoints
+                .iter()
+                .map(|endpoint| toml::Value::from(endpoint.as_str()).to_string())
+                .collect();
+            write!(f, ", endpoints = [{}]", endpoints.join(", "))?;
+        }
+        if let Some(authenticated) = self.authenticated {
+            write!(f, ", authenticated = {}", authenticated)?;
+        }
+        if let Some(x_trust_boundary) = self.x_trust_boundary {
+            write!(f, ", x_trust_boundary = {}", x_trust_boundary)?;
+        }
+        if !self.data.is_empty() {
+            let data: Vec<_> = self
+                .data
+                .iter()
+                .map(|data| {
+                    format!(
+                        "{{ flow = \"{}\", classification = {} }}",
+                        data.flow,
+                        toml::Value::from(data.classification.as_str())
+                    )
+                })
+                .collect();
+            write!(f, ", data = [{}]", data.join(", "))?;
+        }
+        write!(f, " }}")
+    }
+}
+
+/// The services declared for a package, which are listed in the `services` of its SBOM
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct DeclaredServices(Vec<DeclaredService>);
+
+impl DeclaredServices {
+    pub fn new(services: Vec<DeclaredService>) -> Result<Self, String> {
+        let mut names = std::collections::HashSet::new();
+        for service in &services {
+            if service.name.trim().is_empty() {
+                return Err("Expected a service name, got an empty string".to_string());
+            }
+            if !names.insert(service.name.as_str()) {
+                return Err(format!("The service `{}` is declared twice", service.name));
+            }
+            for endpoint in &service.endpoints {
+                if Uri::try_from(endpoint.clone()).is_err() {
+                    return Err(format!(
+                        "Expected the endpoints of the service `{}` to be URLs, got `{}`",
+                        service.name, endpoint
+                    ));
+                }
+            }
+            if service
+                .data
+                .iter()
+                .any(|data| data.classification.trim().is_empty())
+            {
+                return Err(format!(
+                    "Expected a classification of the data of the service `{}`, got an empty string",
+                    service.name
+                ));
+            }
+        }
+        Ok(Self(services))
+    }
+
+    pub fn services(&self) -> &[DeclaredService] {
+        &self.0
+    }
+}
+
+/// Formats the services as a TOML array
+impl fmt::Display for DeclaredServices {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let services: Vec<_> = self.0.iter().map(ToString::to_string).collect();
+        write!(f, "[{}]", services.join(", "))
+    }
+}
+
+/// A link attached to the SBOM as an external reference of the BOM itself, e.g. to the CI run
+/// that generated it, its build logs or a provenance document
+///
+/// * `reference_type` - Type of the external reference, e.g. `build-system` or `build-meta`
+/// * `comment` - What the link points to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DocumentReference {
+    pub reference_type: String,
+    pub url: String,
+    pub comment: Option<String>,
+}
+
+impl DocumentReference {
+    pub fn new(reference_type: &str, url: &str, comment: Option<&str>) -> Result<Self, String> {
+        cyclonedx_bom::models::external_reference::ExternalReferenceType::from_str(reference_type)
+            .map_err(|error| error.to_string())?;
+        if Uri::try_from(url.to_string()).is_err() {
+            return Err(format!(
+                "Expected the external reference to be a URL, got `{}`",
+                url
+            ));
+        }
+        Ok(Self {
+            reference_type: reference_type.to_string(),
+            url: url.to_string(),
+            comment: comment.map(ToString::to_string),
+        })
+    }
+}
+
+/// Parses a reference written as `TYPE=URL`, e.g. `build-system=https://ci.example.com/runs/42`
+impl FromStr for DocumentReference {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (reference_type, url) = s
+            .split_once('=')
+            .ok_or_else(|| format!("Expected TYPE=URL, got `{}`", s))?;
+        Self::new(reference_type, url, None)
+    }
+}
+
+/// Formats the reference as a TOML inline table
+impl fmt::Display for DocumentReference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{{ type = {}, url = {}",
+            toml::Value::from(self.reference_type.as_str()),
+            toml::Value::from(self.url.as_str())
+        )?;
+        if let Some(comment) = &self.comment {
+            write!(f, ", comment = {}", toml::Value::from(comment.as_str()))?;
+        }
+        write!(f, " }}")
+    }
+}
+
+/// The links attached to the SBOM as its `externalReferences`
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct DocumentReferences(Vec<DocumentReference>);
+
+impl DocumentReferences {
+    pub fn new(references: Vec<DocumentReference>) -> Self {
+        Self(references)
+    }
+
+    pub fn references(&self) -> &[DocumentReference] {
+        &self.0
+    }
+}
+
+/// Parses references written as `TYPE=URL` and separated by whitespace
+impl FromStr for DocumentReferences {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split_whitespace()
+            .map(DocumentReference::from_str)
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+}
+
+/// Formats the references as a TOML array
+impl fmt::Display for DocumentReferences {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let references: Vec<_> = self.0.iter().map(ToString::to_string).collect();
+        write!(f, "[{}]", references.join(", "))
+    }
+}
+
+/// A source of data that the enrichment pipeline adds to the components of an SBOM
+///
+/// * `Hashes` - Hashes of the `.crate` files of registry packages
+/// * `Licenses` - Effective licenses, computed from the declared licenses and the license files
+/// * `RegistryLinks` - Links to docs.rs and deps.rs, if `registry_links` is enabled
+/// * `LicenseTexts` - The texts of the license files, if `license_texts` is enabled
+/// * `Provenance` - The commits published packages were packaged from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnricherKind {
+    Hashes,
+    Licenses,
+    RegistryLinks,
+    LicenseTexts,
+    Provenance,
+}
+
+impl FromStr for EnricherKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "hashes" => Ok(Self::Hashes),
+            "licenses" => Ok(Self::Licenses),
+            "registry-links" => Ok(Self::RegistryLinks),
+            "license-texts" => Ok(Self::LicenseTexts),
+            "provenance" => Ok(Self::Provenance),
+            _ => Err(format!(
+                "Expected hashes, licenses, registry-links, license-texts or provenance, got `{}`",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for EnricherKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EnricherKind::Hashes => "hashes".fmt(f),
+            EnricherKind::Licenses => "licenses".fmt(f),
+            EnricherKind::RegistryLinks => "registry-links".fmt(f),
+            EnricherKind::LicenseTexts => "license-texts".fmt(f),
+            EnricherKind::Provenance => "provenance".fmt(f),
+        }
+    }
+}
+
+/// A stage of the enrichment pipeline
+///
+/// * `timeout` - Time the enricher may spend on the components of an SBOM, after which the
+///   remaining components are not enriched by it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EnricherConfig {
+    pub kind: EnricherKind,
+    pub timeout: Option<Duration>,
+}
+
+impl EnricherConfig {
+    pub fn new(kind: EnricherKind) -> Self {
+        Self {
+            kind,
+            timeout: None,
+        }
+    }
+}
+
+/// Formats the stage as a TOML inline table
+impl fmt::Display for EnricherConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{{ name = \"{}\"", self.kind)?;
+        if let Some(timeout) = self.timeout {
+            write!(f, ", timeout = {}", timeout.as_secs())?;
+        }
+        write!(f, " }}")
+    }
+}
+
+/// The stages of the enrichment pipeline, in the order they run in
+///
+/// Enrichers that are not listed do not run. By default, every enricher runs without a timeout.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Enrichers(Vec<EnricherConfig>);
+
+impl Enrichers {
+    pub fn new(enrichers: Vec<EnricherConfig>) -> Result<Self, String> {
+        for (index, enricher) in enrichers.iter().enumerate() {
+            if enrichers[..index]
+                .iter()
+                .any(|other| other.kind == enricher.kind)
+            {
+                return Err(format!("The enricher `{}` is listed twice", enricher.kind));
+            }
+        }
+        Ok(Self(enrichers))
+    }
+
+    pub fn enrichers(&self) -> &[EnricherConfig] {
+        &self.0
+    }
+}
+
+impl Default for Enrichers {
+    fn default() -> Self {
+        Self(vec![
+            EnricherConfig::new(EnricherKind::Hashes),
+            EnricherConfig::new(EnricherKind::Licenses),
+            EnricherConfig::new(EnricherKind::RegistryLinks),
+            EnricherConfig::new(EnricherKind::LicenseTexts),
+            EnricherConfig::new(EnricherKind::Provenance),
+        ])
+    }
+}
+
+/// Formats the stages as a TOML array
+impl fmt::Display for Enrichers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let enrichers: Vec<_> = self.0.iter().map(ToString::to_string).collect();
+        write!(f, "[{}]", enrichers.join(", "))
+    }
+}
+
+/// Globs of the paths, relative to the package root, that are skipped when scanning the files of
+/// a package, in addition to the paths ignored by `.gitignore` and `.ignore` files
+///
+/// `*` does not match `/`, while `**` matches any number of directories. A glob that matches a
+/// directory excludes everything below it.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ScanExclude(Vec<String>);
+
+impl ScanExclude {
+    pub fn new(globs: Vec<String>) -> Result<Self, String> {
+        for glob in &globs {
+            build_glob(glob)?;
+        }
+        Ok(Self(globs))
+    }
+
+    pub fn globs(&self) -> &[String] {
+        &self.0
+    }
+
+    /// Matcher for all globs at once
+    pub fn glob_set(&self) -> GlobSet {
+        let mut builder = GlobSetBuilder::new();
+        for glob in &self.0 {
+            builder.add(build_glob(glob).expect("Globs are validated on construction"));
+        }
+        builder
+            .build()
+            .expect("Globs are validated on construction")
+    }
+}
+
+fn build_glob(glob: &str) -> Result<globset::Glob, String> {
+    GlobBuilder::new(glob)
+        .literal_separator(true)
+        .build()
+        .map_err(|e| format!("Invalid glob `{}`: {}", glob, e.kind()))
+}
+
+/// Parses globs separated by whitespace
+impl FromStr for ScanExclude {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s.split_whitespace().map(String::from).collect())
+    }
+}
+
+/// Formats the globs as a TOML array
+impl fmt::Display for ScanExclude {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let globs: Vec<_> = self.0.iter().map(|glob| format!("{:?}", glob)).collect();
+        write!(f, "[{}]", globs.join(", "))
+    }
+}
+
+/// Licenses in order of preference, used to choose between the alternatives of SPDX `OR`
+/// expressions when computing the effective license of a package
+///
+/// Licenses that are not listed rank below all listed ones, and ties are resolved in favor of
+/// the alternative written first.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct LicensePreference(Vec<String>);
+
+impl LicensePreference {
+    pub fn new(licenses: Vec<String>) -> Result<Self, String> {
+        for license in &licenses {
+            if spdx::license_id(license).is_none() && !license.starts_with("LicenseRef-") {
+                return Err(format!(
+                    "Expected an SPDX license identifier or LicenseRef, got `{}`",
+                    license
+                ));
+            }
+        }
+        Ok(Self(licenses))
+    }
+
+    pub fn licenses(&self) -> &[String] {
+        &self.0
+    }
+
+    /// Position of `license` in the preference order, or the number of preferred licenses if it
+    /// is not listed
+    pub fn rank(&self, license: &str) -> usize {
+        self.0
+            .iter()
+            .position(|preferred| preferred == license)
+            .unwrap_or(self.0.len())
+    }
+}
+
+/// Parses licenses separated by commas
+impl FromStr for LicensePreference {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(
+            s.split(',')
+                .map(str::trim)
+                .filter(|license| !license.is_empty())
+                .map(String::from)
+                .collect(),
+        )
+    }
+}
+
+/// Formats the licenses as a TOML array
+impl fmt::Display for LicensePreference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let licenses: Vec<_> = self
+            .0
+            .iter()
+            .map(|license| format!("{:?}", license))
+            .collect();
+        write!(f, "[{}]", licenses.join(", "))
+    }
+}
+
+/// CycloneDX BOMs generated for the non-Rust parts of a package, e.g. a bundled frontend, that
+/// are merged into the SBOM of the package as assemblies
+///
+/// Relative paths are relative to the package root.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ExternalBoms(Vec<PathBuf>);
+
+impl ExternalBoms {
+    pub fn new(paths: Vec<PathBuf>) -> Self {
+        Self(paths)
+    }
+
+    pub fn paths(&self) -> &[PathBuf] {
+        &self.0
+    }
+}
+
+/// Parses paths separated like in the `PATH` environment variable, i.e. by `:` or by `;` on
+/// Windows
+impl FromStr for ExternalBoms {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::new(
+            std::env::split_paths(s)
+                .filter(|path| !path.as_os_str().is_empty())
+                .collect(),
+        ))
+    }
+}
+
+/// Formats the paths as a TOML array
+impl fmt::Display for ExternalBoms {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let paths: Vec<_> = self
+            .0
+            .iter()
+            .map(|path| format!("{:?}", path.display().to_string()))
+            .collect();
+        write!(f, "[{}]", paths.join(", "))
+    }
+}
+
+/// A mapping file, in TOML or CSV, that overrides the supplier, author, group or license of
+/// components, see [`crate::overrides`]
+///
+/// A relative path is relative to the package root.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OverridesFile(PathBuf);
+
+impl OverridesFile {
+    pub fn new(path: PathBuf) -> Self {
+        Self(path)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl FromStr for OverridesFile {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err("Expected the path of a mapping file".to_string());
+        }
+        Ok(Self::new(PathBuf::from(s)))
+    }
+}
+
+/// Formats the path as a TOML string
+impl fmt::Display for OverridesFile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.0.display().to_string())
+    }
+}
+
+/// The organization that supplies the package, recorded as the supplier in the SBOM metadata
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Supplier {
+    pub name: String,
+    pub url: Option<String>,
+}
+
+/// Formats the supplier the same way it is written in the `Cargo.toml` metadata
+impl fmt::Display for Supplier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{{ name = {}", toml::Value::from(self.name.as_str()))?;
+        if let Some(url) = &self.url {
+            write!(f, ", url = {}", toml::Value::from(url.as_str()))?;
+        }
+        write!(f, " }}")
+    }
+}
+
+/// The license of the SBOM document itself, as opposed to the licenses of the components it
+/// describes, recorded as the licenses of the SBOM metadata
+///
+/// * `Expression` - An SPDX license expression, e.g. `CC0-1.0`
+/// * `Named` - Other terms, such as a proprietary license, with the URL of their text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DocumentLicense {
+    Expression(String),
+    Named { name: String, url: Option<String> },
+}
+
+impl DocumentLicense {
+    pub fn expression(expression: &str) -> Result<Self, String> {
+        SpdxExpression::try_from(expression.to_string()).map_err(|error| {
+            format!(
+                "Expected the document license to be an SPDX expression, got `{}`: {}",
+                expression, error
+            )
+        })?;
+        Ok(Self::Expression(expression.to_string()))
+    }
+
+    pub fn named(name: &str, url: Option<&str>) -> Result<Self, String> {
+        if let Some(url) = url {
+            if Uri::try_from(url.to_string()).is_err() {
+                return Err(format!(
+                    "Expected the URL of the document license to be a URL, got `{}`",
+                    url
+                ));
+            }
+        }
+        Ok(Self::Named {
+            name: name.to_string(),
+            url: url.map(ToString::to_string),
+        })
+    }
+}
+
+/// Formats the license the same way it is written in the `Cargo.toml` metadata
+impl fmt::Display for DocumentLicense {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DocumentLicense::Expression(expression) => {
+                write!(f, "{}", toml::Value::from(expression.as_str()))
+            }
+            DocumentLicense::Named { name, url } => {
+                write!(f, "{{ name = {}", toml::Value::from(name.as_str()))?;
+                if let Some(url) = url {
+                    write!(f, ", url = {}", toml::Value::from(url.as_str()))?;
+                }
+                write!(f, " }}")
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum CdxExtension {
+    Included,
+    #[default]
+    NotIncluded,
+}
+
+impl CdxExtension {
+    pub fn extension(&self) -> String {
+        match &self {
+            CdxExtension::Included => ".cdx".to_string(),
+            CdxExtension::NotIncluded => "".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Prefix {
+    Pattern(Pattern),
+    Custom(CustomPrefix),
+}
+
+impl Default for Prefix {
+    fn default() -> Self {
+        Self::Pattern(Pattern::default())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Pattern {
+    #[default]
+    Bom,
+    Package,
+}
+
+impl FromStr for Pattern {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "bom" => Ok(Self::Bom),
+            "package" => Ok(Self::Package),
+            _ => Err(format!("Expected bom or package, got `{}`", s)),
+        }
+    }
+}
+
+impl fmt::Display for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Pattern::Bom => "bom".fmt(f),
+            Pattern::Package => "package".fmt(f),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CustomPrefix(String);
+
+/// Longest custom prefix accepted, leaving room for the `.cdx.json` suffix within the 255 byte
+/// file name limit shared by common file systems
+pub const MAX_PREFIX_LEN: usize = 240;
+
+/// Characters that cannot appear in a file name on at least one supported platform
+const RESERVED_CHARACTERS: &[char] = &['/', '\\', '<', '>', ':', '"', '|', '?', '*'];
+
+/// Device names that Windows reserves regardless of extension
+const RESERVED_NAMES: &[&str] = &[
+    "CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8",
+    "COM9", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+];
+
+impl CustomPrefix {
+    /// Creates a prefix that is safe to use as the start of a file name on every platform
+    ///
+    /// The checks do not depend on the current platform or locale, so a configuration that is
+    /// accepted on one machine is accepted on all of them.
+    pub fn new(custom_prefix: impl Into<String>) -> Result<Self, PrefixError> {
+        let prefix = custom_prefix.into();
+
+        if prefix.is_empty() {
+            return Err(PrefixError::Empty);
+        }
+
+        if prefix == "." || prefix == ".." {
+            return Err(PrefixError::RelativePath(prefix));
+        }
+
+        if let Some(c) = prefix.chars().find(|c| is_reserved_char(*c)) {
+            return Err(PrefixError::IllegalCharacter(c));
+        }
+
+        if is_reserved_name(&prefix) {
+            return Err(PrefixError::ReservedName(prefix));
+        }
+
+        if prefix.len() > MAX_PREFIX_LEN {
+            return Err(PrefixError::TooLong {
+                length: prefix.len(),
+                max: MAX_PREFIX_LEN,
+            });
+        }
+
+        Ok(Self(prefix))
+    }
+}
+
+/// Turns an arbitrary string, such as a package name, into a file name prefix that
+/// [`CustomPrefix::new`] would accept
+///
+/// Reserved characters are replaced with `_`, reserved names are suffixed with `_`, and the
+/// result is truncated to [`MAX_PREFIX_LEN`] bytes on a character boundary.
+pub fn sanitize_prefix(name: &str) -> String {
+    let mut sanitized: String = name
+        .chars()
+        .map(|c| if is_reserved_char(c) { '_' } else { c })
+        .collect();
+
+    if sanitized.is_empty() || sanitized == "." || sanitized == ".." || is_reserved_name(&sanitized)
+    {
+        sanitized.push('_');
+    }
+
+    if sanitized.len() > MAX_PREFIX_LEN {
+        let mut end = MAX_PREFIX_LEN;
+        while !sanitized.is_char_boundary(end) {
+            end -= 1;
+        }
+        sanitized.truncate(end);
+    }
+
+    sanitized
+}
+
+fn is_reserved_char(c: char) -> bool {
+    c.is_ascii_control() || RESERVED_CHARACTERS.contains(&c)
+}
+
+fn is_reserved_name(prefix: &str) -> bool {
+    let stem = prefix.split('.').next().unwrap_or_default().trim_end();
+    RESERVED_NAMES
+        .iter()
+        .any(|reserved| stem.eq_ignore_ascii_case(reserved))
+}
+
+impl std::fmt::Display for CustomPrefix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum PrefixError {
+    #[error("Custom prefix cannot be empty")]
+    Empty,
+
+    #[error("Illegal character in custom prefix string: {0:?}")]
+    IllegalCharacter(char),
+
+    #[error("Custom prefix cannot be the relative path {0}")]
+    RelativePath(String),
+
+    #[error("Custom prefix {0} is a reserved file name on Windows")]
+    ReservedName(String),
+
+    #[error("Custom prefix is {length} bytes long, the maximum is {max}")]
+    TooLong { length: usize, max: usize },
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_should_error_for_a_prefix_with_a_path_separator() {
+        let prefix = format!("directory{}prefix", std::path::MAIN_SEPARATOR);
+
+        let actual = CustomPrefix::new(prefix)
+            .expect_err("Should not have been able to create CustomPrefix with path separator");
+
+        let expected = PrefixError::IllegalCharacter(std::path::MAIN_SEPARATOR);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn it_should_reject_prefixes_that_are_unsafe_on_any_platform() {
+        for (prefix, expected) in [
+            ("", PrefixError::Empty),
+            ("..", PrefixError::RelativePath("..".to_string())),
+            ("../bom", PrefixError::IllegalCharacter('/')),
+            ("..\\bom", PrefixError::IllegalCharacter('\\')),
+            ("C:bom", PrefixError::IllegalCharacter(':')),
+            ("bom?", PrefixError::IllegalCharacter('?')),
+            ("bom\n", PrefixError::IllegalCharacter('\n')),
+            ("con", PrefixError::ReservedName("con".to_string())),
+            (
+                "Lpt1.sbom",
+                PrefixError::ReservedName("Lpt1.sbom".to_string()),
+            ),
+        ] {
+            assert_eq!(CustomPrefix::new(prefix), Err(expected), "{:?}", prefix);
+        }
+
+        assert_eq!(
+            CustomPrefix::new("a".repeat(MAX_PREFIX_LEN + 1)),
+            Err(PrefixError::TooLong {
+                length: MAX_PREFIX_LEN + 1,
+                max: MAX_PREFIX_LEN
+            })
+        );
+        assert!(CustomPrefix::new("console").is_ok());
+    }
+
+    #[test]
+    fn it_should_sanitize_any_string_into_an_accepted_prefix() {
+        // Every ASCII character alone, doubled, and around a reserved name, plus multi-byte
+        // characters near the length limit
+        let mut inputs: Vec<String> = (0u8..=127)
+            .map(char::from)
+            .flat_map(|c| {
+                vec![
+                    c.to_string(),
+                    format!("{}{}", c, c),
+                    format!("{}nul{}", c, c),
+                ]
+            })
+            .collect();
+        inputs.push("é".repeat(MAX_PREFIX_LEN));
+        inputs.push(format!("{}💾", "a".repeat(MAX_PREFIX_LEN - 2)));
+
+        for input in inputs {
+            let sanitized = sanitize_prefix(&input);
+
+            assert!(
+                CustomPrefix::new(sanitized.clone()).is_ok(),
+                "{:?} was sanitized to the rejected prefix {:?}",
+                input,
+                sanitized
+            );
+        }
+    }
+
+    #[test]
+    fn it_should_keep_accepted_prefixes_in_the_output_directory() {
+        use std::path::{Component, Path};
+
+        for prefix in ["bom", "my-package", ".hidden", "...", "tacos.v1"] {
+            let prefix = CustomPrefix::new(prefix).expect("Prefix should be accepted");
+            let file_name = format!("{}.cdx.xml", prefix);
+            let components: Vec<_> = Path::new(&file_name).components().collect();
+
+            assert!(
+                matches!(components.as_slice(), [Component::Normal(_)]),
+                "{} is not a plain file name",
+                file_name
+            );
+        }
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn it_should_reject_prefixes_windows_would_treat_as_a_path() {
+        for prefix in ["C:bom", "\\\\server\\share", "NUL"] {
+            assert!(CustomPrefix::new(prefix).is_err(), "{:?}", prefix);
+        }
+    }
+
+    #[test]
+    fn it_should_resolve_each_option_from_the_highest_precedence_source() {
+        let workspace = SbomConfig {
+            format: Some(Format::Json),
+            spec_version: None,
+            included_dependencies: Some(IncludedDependencies::AllDependencies),
+            output_options: None,
+            supplier: None,
+            profile: None,
+            embedded_assets: None,
+            source_files: None,
+            scan_exclude: None,
+            license_preference: None,
+            external_boms: None,
+            registry_links: None,
+            duplicates: None,
+            feature_matrix: None,
+            feature_sets: None,
+            on_error: None,
+            services: None,
+            external_references: None,
+            enrichers: None,
+            document_license: None,
+            git_purl: Some(GitPurl::Repository),
+            license_texts: Some(LicenseTexts::Included),
+            overrides: None,
+        };
+        let package = SbomConfig {
+            format: Some(Format::Xml),
+            supplier: Some(Supplier {
+                name: "Tacos Inc".to_string(),
+                url: None,
+            }),
+            ..SbomConfig::empty_config()
+        };
+        let cli = SbomConfig::empty_config();
+
+        let actual = EffectiveConfig::resolve(&[
+            (ConfigSource::WorkspaceMetadata, &workspace),
+            (ConfigSource::PackageMetadata, &package),
+            (ConfigSource::CommandLine, &cli),
+        ]);
+
+        let expected = EffectiveConfig {
+            format: Sourced {
+                value: Format::Xml,
+                source: ConfigSource::PackageMetadata,
+            },
+            spec_version: Sourced {
+                value: SpecVersion::V1_3,
+                source: ConfigSource::Default,
+            },
+            included_dependencies: Sourced {
+                value: IncludedDependencies::AllDependencies,
+                source: ConfigSource::WorkspaceMetadata,
+            },
+            output_options: Sourced {
+                value: OutputOptions::default(),
+                source: ConfigSource::Default,
+            },
+            supplier: Sourced {
+                value: Some(Supplier {
+                    name: "Tacos Inc".to_string(),
+                    url: None,
+                }),
+                source: ConfigSource::PackageMetadata,
+            },
+            profile: Sourced {
+                value: BuildProfile::default(),
+                source: ConfigSource::Default,
+            },
+            embedded_assets: Sourced {
+                value: EmbeddedAssets::Disabled,
+                source: ConfigSource::Default,
+            },
+            source_files: Sourced {
+                value: SourceFiles::NotIncluded,
+                source: ConfigSource::Default,
+            },
+            scan_exclude: Sourced {
+                value: ScanExclude::default(),
+                source: ConfigSource::Default,
+            },
+            license_preference: Sourced {
+                value: LicensePreference::default(),
+                source: ConfigSource::Default,
+            },
+            external_boms: Sourced {
+                value: ExternalBoms::default(),
+                source: ConfigSource::Default,
+            },
+            registry_links: Sourced {
+                value: RegistryLinks::NotIncluded,
+                source: ConfigSource::Default,
+            },
+            duplicates: Sourced {
+                value: Duplicates::NotAnnotated,
+                source: ConfigSource::Default,
+            },
+            feature_matrix: Sourced {
+                value: FeatureMatrix::Disabled,
+                source: ConfigSource::Default,
+            },
+            feature_sets: Sourced {
+                value: FeatureSets::default(),
+                source: ConfigSource::Default,
+            },
+            on_error: Sourced {
+                value: ErrorPolicy::default(),
+                source: ConfigSource::Default,
+            },
+            services: Sourced {
+                value: DeclaredServices::default(),
+                source: ConfigSource::Default,
+            },
+            external_references: Sourced {
+                value: DocumentReferences::default(),
+                source: ConfigSource::Default,
+            },
+            enrichers: Sourced {
+                value: Enrichers::default(),
+                source: ConfigSource::Default,
+            },
+            document_license: Sourced {
+                value: None,
+                source: ConfigSource::Default,
+            },
+            git_purl: Sourced {
+                value: GitPurl::Repository,
+                source: ConfigSource::WorkspaceMetadata,
+            },
+            license_texts: Sourced {
+                value: LicenseTexts::Included,
+                source: ConfigSource::WorkspaceMetadata,
+            },
+            overrides: Sourced {
+                value: None,
+                source: ConfigSource::Default,
+            },
+        };
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn it_should_validate_enrichers() {
+        assert_eq!(
+            Enrichers::default().to_string(),
+            r#"[{ name = "hashes" }, { name = "licenses" }, { name = "registry-links" }, { name = "license-texts" }, { name = "provenance" }]"#
+        );
+
+        let enrichers = Enrichers::new(vec![
+            EnricherConfig {
+                kind: EnricherKind::RegistryLinks,
+                timeout: Some(Duration::from_secs(30)),
+            },
+            EnricherConfig::new(EnricherKind::Hashes),
+        ])
+        .unwrap();
+        assert_eq!(
+            enrichers.to_string(),
+            r#"[{ name = "registry-links", timeout = 30 }, { name = "hashes" }]"#
+        );
+
+        assert_eq!(
+            Enrichers::new(vec![
+                EnricherConfig::new(EnricherKind::Hashes),
+                EnricherConfig::new(EnricherKind::Hashes),
+            ]),
+            Err("The enricher `hashes` is listed twice".to_string())
+        );
+        assert_eq!(
+            "advisories".parse::<EnricherKind>(),
+            Err(
+                "Expected hashes, licenses, registry-links, license-texts or provenance, got `advisories`"
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn it_should_validate_scan_exclude_globs() {
+        let exclude: ScanExclude = "vendor/** tests/{fixtures,data}".parse().unwrap();
+
+        assert_eq!(exclude.globs(), ["vendor/**", "tests/{fixtures,data}"]);
+        assert_eq!(
+            exclude.to_string(),
+            r#"["vendor/**", "tests/{fixtures,data}"]"#
+        );
+        assert!(exclude.glob_set().is_match("tests/data"));
+        assert!(!exclude.glob_set().is_match("src/tests/data"));
+
+        let actual = ScanExclude::new(vec!["tests/[fixtures".to_string()])
+            .expect_err("Should not accept an unclosed character class");
+        assert!(
+            actual.starts_with("Invalid glob `tests/[fixtures`"),
+            "{}",
+            actual
+        );
+    }
+
+    #[test]
+    fn it_should_rank_preferred_licenses() {
+        let preference: LicensePreference = "MIT, Apache-2.0".parse().unwrap();
+
+        assert_eq!(preference.rank("MIT"), 0);
+        assert_eq!(preference.rank("Apache-2.0"), 1);
+        assert_eq!(preference.rank("GPL-3.0-only"), 2);
+        assert_eq!(preference.to_string(), r#"["MIT", "Apache-2.0"]"#);
+
+        assert!("MIT, LicenseRef-Tacos".parse::<LicensePreference>().is_ok());
+        assert_eq!(
+            "MIT, Tacos".parse::<LicensePreference>(),
+            Err("Expected an SPDX license identifier or LicenseRef, got `Tacos`".to_string())
+        );
+    }
+
+    #[test]
+    fn it_should_validate_feature_set_names() {
+        let sets = |name: &str| {
+            FeatureSets::new(BTreeMap::from([(
+                name.to_string(),
+                vec!["tls".to_string()],
+            )]))
+        };
+
+        assert_eq!(
+            sets("with-tls").unwrap().to_string(),
+            r#"{ with-tls = ["tls"] }"#
+        );
+        assert_eq!(FeatureSets::default().to_string(), "{}");
+        assert_eq!(
+            sets("all"),
+            Err("The feature set `all` cannot be redefined".to_string())
+        );
+        assert!(sets("../tls").is_err());
+        assert!(sets("").is_err());
+    }
+
+    #[test]
+    fn it_should_parse_the_error_policy_of_each_subsystem() {
+        assert_eq!(
+            "skip".parse::<ErrorPolicy>(),
+            Ok(ErrorPolicy::all(OnError::Skip))
+        );
+        assert_eq!(
+            "registry=warn".parse::<ErrorPolicy>(),
+            Ok(ErrorPolicy {
+                hashing: OnError::Warn,
+                registry: OnError::Warn,
+            })
+        );
+
+        let policy: ErrorPolicy = "hashing=fail, registry=skip".parse().unwrap();
+        assert_eq!(
+            policy.to_string(),
+            r#"{ hashing = "fail", registry = "skip" }"#
+        );
+
+        assert_eq!(
+            "ignore".parse::<ErrorPolicy>(),
+            Err("Expected skip, warn or fail, got `ignore`".to_string())
+        );
+        assert_eq!(
+            "network=fail".parse::<ErrorPolicy>(),
+            Err("Expected the subsystem hashing or registry, got `network`".to_string())
+        );
+    }
+
+    #[test]
+    fn it_should_validate_declared_services() {
+        let service = DeclaredService {
+            name: "payments".to_string(),
+            description: None,
+            endpoints: vec!["https://payments.example.com/v1".to_string()],
+            authenticated: Some(true),
+            x_trust_boundary: None,
+            data: vec![DataFlow {
+                flow: Flow::Outbound,
+                classification: "PII".to_string(),
+            }],
+        };
+
+        let services = DeclaredServices::new(vec![service.clone()]).unwrap();
+        assert_eq!(
+            services.to_string(),
+            r#"[{ name = "payments", endpoints = ["https://payments.example.com/v1"], authenticated = true, data = [{ flow = "outbound", classification = "PII" }] }]"#
+        );
+
+        assert_eq!(
+            DeclaredServices::new(vec![service.clone(), service.clone()]),
+            Err("The service `payments` is declared twice".to_string())
+        );
+        assert!(DeclaredServices::new(vec![DeclaredService {
+            endpoints: vec!["not a url".to_string()],
+            ..service.clone()
+        }])
+        .is_err());
+        assert!(DeclaredServices::new(vec![DeclaredService {
+            data: vec![DataFlow {
+                flow: Flow::Inbound,
+                classification: " ".to_string(),
+            }],
+            ..service
+        }])
+        .is_err());
+        assert_eq!(
+            "sideways".parse::<Flow>(),
+            Err(
+                "Expected inbound, outbound, bi-directional or unknown, got `sideways`".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn it_should_parse_document_references() {
+        let references: DocumentReferences =
+            "build-system=https://ci.example.com/runs/42 build-meta=https://ci.example.com/runs/42/log"
+                .parse()
+                .unwrap();
+        assert_eq!(
+            references.to_string(),
+            r#"[{ type = "build-system", url = "https://ci.example.com/runs/42" }, { type = "build-meta", url = "https://ci.example.com/runs/42/log" }]"#
+        );
+
+        assert_eq!(
+            "https://ci.example.com".parse::<DocumentReference>(),
+            Err("Expected TYPE=URL, got `https://ci.example.com`".to_string())
+        );
+        assert_eq!(
+            "ci=https://ci.example.com".parse::<DocumentReference>(),
+            Err("Unknown external reference type `ci`".to_string())
+        );
+        assert!(DocumentReference::new("build-meta", "not a url", None).is_err());
+    }
+
+    #[test]
+    fn it_should_validate_document_licenses() {
+        assert_eq!(
+            DocumentLicense::expression("CC0-1.0").unwrap().to_string(),
+            r#""CC0-1.0""#
+        );
+        assert_eq!(
+            DocumentLicense::named(
+                "Tacos Inc SBOM terms",
+                Some("https://tacos.example.com/terms")
+            )
+            .unwrap()
+            .to_string(),
+            r#"{ name = "Tacos Inc SBOM terms", url = "https://tacos.example.com/terms" }"#
+        );
+        assert!(DocumentLicense::expression("Tacos Inc SBOM terms").is_err());
+        assert!(DocumentLicense::named("Tacos Inc SBOM terms", Some("not a url")).is_err());
+    }
+
+    #[test]
+    fn it_should_display_output_options_as_toml() {
+        let options = OutputOptions {
+            cdx_extension: CdxExtension::Included,
+            prefix: Prefix::Custom(CustomPrefix("tacos".to_string())),
+        };
+
+        assert_eq!(options.to_string(), r#"{ cdx = true, prefix = "tacos" }"#);
     }
 
     #[test]
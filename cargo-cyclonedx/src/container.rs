@@ -0,0 +1,287 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+use cyclonedx_bom::external_models::purl::Purl;
+use cyclonedx_bom::models::bom::Bom;
+use cyclonedx_bom::models::component::{Classification, Component, Components};
+use cyclonedx_bom::models::hash::{Hash, HashAlgorithm};
+use cyclonedx_bom::models::metadata::Metadata;
+use std::fmt;
+use std::str::FromStr;
+
+/// Reference to the container image an application ships in, e.g.
+/// `ghcr.io/tacos/app:1.0.0@sha256:<hex>`
+///
+/// * `repository` - Registry and path of the image, e.g. `ghcr.io/tacos/app`
+/// * `tag` - Tag of the image, e.g. `1.0.0`
+/// * `digest` - Content digest identifying the exact image
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContainerImage {
+    pub repository: String,
+    pub tag: Option<String>,
+    pub digest: Option<ImageDigest>,
+}
+
+/// Digest of a container image manifest, e.g. `sha256:<hex>`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageDigest {
+    pub algorithm: DigestAlgorithm,
+    pub hex: String,
+}
+
+/// Algorithms registries use to digest image manifests
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DigestAlgorithm {
+    Sha256,
+    Sha512,
+}
+
+impl From<DigestAlgorithm> for HashAlgorithm {
+    fn from(algorithm: DigestAlgorithm) -> Self {
+        match algorithm {
+            DigestAlgorithm::Sha256 => HashAlgorithm::SHA256,
+            DigestAlgorithm::Sha512 => HashAlgorithm::SHA512,
+        }
+    }
+}
+
+impl fmt::Display for ImageDigest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let algorithm = match self.algorithm {
+            DigestAlgorithm::Sha256 => "sha256",
+            DigestAlgorithm::Sha512 => "sha512",
+        };
+        write!(f, "{}:{}", algorithm, self.hex)
+    }
+}
+
+impl FromStr for ImageDigest {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (algorithm, length, hex) = match s.split_once(':') {
+            Some(("sha256", hex)) => (DigestAlgorithm::Sha256, 64, hex),
+            Some(("sha512", hex)) => (DigestAlgorithm::Sha512, 128, hex),
+            _ => {
+                return Err(format!(
+                    "Expected a sha256 or sha512 digest like sha256:<hex>, got `{}`",
+                    s
+                ))
+            }
+        };
+
+        if hex.len() != length || !hex.chars().all(|c| matches!(c, '0'..='9' | 'a'..='f')) {
+            return Err(format!(
+                "Expected {} lowercase hexadecimal digits in the digest `{}`",
+                length, s
+            ));
+        }
+
+        Ok(Self {
+            algorithm,
+            hex: hex.to_string(),
+        })
+    }
+}
+
+impl ContainerImage {
+    /// Last path segment of the repository, e.g. `app` for `ghcr.io/tacos/app`
+    pub fn name(&self) -> &str {
+        self.repository
+            .rsplit('/')
+            .next()
+            .unwrap_or(&self.repository)
+    }
+
+    /// Package URL of the image, which is only defined for images referenced by digest
+    pub fn purl(&self) -> Option<Purl> {
+        let digest = self.digest.as_ref()?;
+        // The colon of the digest is escaped as in the examples of the purl specification, which
+        // the builder leaves to the caller
+        let mut purl = Purl::builder("oci", self.name())
+            .version(&digest.to_string().replace(':', "%3A"))
+            .repository_url(&self.repository);
+        if let Some(tag) = &self.tag {
+            purl = purl.qualifier("tag", tag);
+        }
+
+        match purl.build() {
+            Ok(purl) => Some(purl),
+            Err(e) => {
+                log::error!("Container image {} has an invalid Purl: {} ", self, e);
+                None
+            }
+        }
+    }
+
+    /// Describes the image as a container component, with the tag as version, or the digest if
+    /// the image is not tagged
+    pub fn create_component(&self) -> Component {
+        let version = match (&self.tag, &self.digest) {
+            (Some(tag), _) => tag.clone(),
+            (None, Some(digest)) => digest.to_string(),
+            (None, None) => "latest".to_string(),
+        };
+        let purl = self.purl();
+
+        let mut component = Component::new(
+            Classification::Container,
+            &self.repository,
+            &version,
+            Some(
+                purl.as_ref()
+                    .map_or_else(|| self.to_string(), ToString::to_string),
+            ),
+        );
+        component.purl = purl;
+        if let Some(digest) = &self.digest {
+            component.add_hash(Hash::new(digest.algorithm.into(), &digest.hex));
+        }
+
+        component
+    }
+
+    /// Makes the image the subject of `bom`, nesting the previous metadata component in it
+    pub fn set_as_parent(&self, bom: &mut Bom) {
+        let metadata = bom.metadata.get_or_insert_with(Metadata::default);
+
+        let mut image = self.create_component();
+        if let Some(application) = metadata.component.take() {
+            image.components = Some(Components(vec![application]));
+        }
+        metadata.component = Some(image);
+    }
+}
+
+/// Parses `[registry/]path[:tag][@digest]`
+impl FromStr for ContainerImage {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, digest) = match s.split_once('@') {
+            Some((name, digest)) => (name, Some(digest.parse::<ImageDigest>()?)),
+            None => (s, None),
+        };
+
+        // a colon before the last slash separates the port of the registry
+        let (repository, tag) = match name.rsplit_once(':') {
+            Some((repository, tag)) if !tag.contains('/') => (repository, Some(tag.to_string())),
+            _ => (name, None),
+        };
+
+        if repository.is_empty() || repository.ends_with('/') || tag.as_deref() == Some("") {
+            return Err(format!(
+                "Expected an image reference like registry/name:tag@sha256:<hex>, got `{}`",
+                s
+            ));
+        }
+
+        Ok(Self {
+            repository: repository.to_string(),
+            tag,
+            digest,
+        })
+    }
+}
+
+impl fmt::Display for ContainerImage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.repository)?;
+        if let Some(tag) = &self.tag {
+            write!(f, ":{}", tag)?;
+        }
+        if let Some(digest) = &self.digest {
+            write!(f, "@{}", digest)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const DIGEST: &str = "sha256:0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef";
+
+    #[test]
+    fn it_should_parse_image_references() {
+        let image: ContainerImage = format!("localhost:5000/tacos/app:1.0.0@{}", DIGEST)
+            .parse()
+            .expect("Failed to parse the reference");
+
+        assert_eq!(image.repository, "localhost:5000/tacos/app");
+        assert_eq!(image.tag.as_deref(), Some("1.0.0"));
+        assert_eq!(image.name(), "app");
+        assert_eq!(
+            image.to_string(),
+            format!("localhost:5000/tacos/app:1.0.0@{}", DIGEST)
+        );
+
+        let image: ContainerImage = "localhost:5000/app".parse().expect("Failed to parse");
+        assert_eq!(image.repository, "localhost:5000/app");
+        assert_eq!(image.tag, None);
+        assert_eq!(image.digest, None);
+    }
+
+    #[test]
+    fn it_should_reject_invalid_references() {
+        for reference in ["", "app:", "registry/", "app@sha256:0123", "app@md5:0123"] {
+            assert!(
+                reference.parse::<ContainerImage>().is_err(),
+                "{} should be invalid",
+                reference
+            );
+        }
+    }
+
+    #[test]
+    fn it_should_nest_the_application_in_the_image() {
+        let image: ContainerImage = format!("ghcr.io/tacos/app:1.0.0@{}", DIGEST)
+            .parse()
+            .expect("Failed to parse the reference");
+        let mut bom = Bom::default();
+        let mut metadata = Metadata::default();
+        metadata.component = Some(Component::new(
+            Classification::Application,
+            "app",
+            "1.0.0",
+            None,
+        ));
+        bom.metadata = Some(metadata);
+
+        image.set_as_parent(&mut bom);
+
+        let parent = bom
+            .metadata
+            .and_then(|metadata| metadata.component)
+            .expect("Missing metadata component");
+        assert_eq!(parent.component_type, Classification::Container);
+        assert_eq!(
+            parent.purl.map(|purl| purl.to_string()),
+            Some(format!(
+                "pkg:oci/app@{}?repository_url=ghcr.io/tacos/app&tag=1.0.0",
+                DIGEST.replace(':', "%3A")
+            ))
+        );
+        assert_eq!(
+            parent
+                .components
+                .map(|components| components.0[0].name.to_string()),
+            Some("app".to_string())
+        );
+    }
+}
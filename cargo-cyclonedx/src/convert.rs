@@ -0,0 +1,175 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+//! Conversion of BOMs into SPDX documents and back, for tooling that only accepts SPDX
+//!
+//! See [`cyclonedx_bom::spdx_document`] for how the content maps between both formats.
+
+use cyclonedx_bom::errors::{BomReadError, JsonWriteError, SpdxError, XmlWriteError};
+use cyclonedx_bom::models::bom::{Bom, SpecVersion};
+use cyclonedx_bom::spdx_document::SpdxDocument;
+use std::convert::TryFrom;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use thiserror::Error;
+
+/// The format to convert into, from the other one
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConversionTarget {
+    Spdx,
+    Cyclonedx,
+}
+
+impl FromStr for ConversionTarget {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "spdx" => Ok(Self::Spdx),
+            "cyclonedx" => Ok(Self::Cyclonedx),
+            _ => Err(format!("Expected spdx or cyclonedx, got `{}`", s)),
+        }
+    }
+}
+
+impl fmt::Display for ConversionTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Spdx => "spdx".fmt(f),
+            Self::Cyclonedx => "cyclonedx".fmt(f),
+        }
+    }
+}
+
+/// The encoding of the converted document: JSON for both formats, XML for CycloneDX only and
+/// tag-value for SPDX only
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ConversionFormat {
+    #[default]
+    Json,
+    Xml,
+    TagValue,
+}
+
+impl FromStr for ConversionFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(Self::Json),
+            "xml" => Ok(Self::Xml),
+            "tag-value" => Ok(Self::TagValue),
+            _ => Err(format!("Expected json, xml or tag-value, got `{}`", s)),
+        }
+    }
+}
+
+impl fmt::Display for ConversionFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Json => "json".fmt(f),
+            Self::Xml => "xml".fmt(f),
+            Self::TagValue => "tag-value".fmt(f),
+        }
+    }
+}
+
+/// Reads the document at `path` and converts it into `to` in `format`. A BOM is read in any
+/// supported format, an SPDX document in the JSON or the tag-value format, and a BOM is written in
+/// `spec_version` of the specification.
+pub fn convert(
+    path: &Path,
+    to: ConversionTarget,
+    format: ConversionFormat,
+    spec_version: SpecVersion,
+) -> Result<Vec<u8>, ConvertError> {
+    let input = std::fs::read(path).map_err(|error| ConvertError::ReadError {
+        path: path.to_path_buf(),
+        error,
+    })?;
+    let mut output = Vec::new();
+
+    match (to, format) {
+        (ConversionTarget::Spdx, ConversionFormat::Json | ConversionFormat::TagValue) => {
+            let (bom, _) = Bom::parse(&input).map_err(|error| ConvertError::ParseError {
+                path: path.to_path_buf(),
+                error,
+            })?;
+            let document = SpdxDocument::from(&bom);
+            if format == ConversionFormat::Json {
+                serde_json::to_writer_pretty(&mut output, &document)
+                    .map_err(JsonWriteError::from)?;
+            } else {
+                output = document.to_tag_value().into_bytes();
+            }
+        }
+        (ConversionTarget::Cyclonedx, ConversionFormat::Json | ConversionFormat::Xml) => {
+            let spdx_error = |error| ConvertError::SpdxError {
+                path: path.to_path_buf(),
+                error,
+            };
+            let input = String::from_utf8_lossy(&input);
+            let document = SpdxDocument::parse(&input).map_err(spdx_error)?;
+            let bom = Bom::try_from(document).map_err(spdx_error)?;
+            if format == ConversionFormat::Json {
+                bom.output_as_json(&mut output, spec_version)?;
+            } else {
+                bom.output_as_xml(&mut output, spec_version)?;
+            }
+        }
+        (to, format) => return Err(ConvertError::UnsupportedFormat { to, format }),
+    }
+
+    Ok(output)
+}
+
+#[derive(Debug, Error)]
+pub enum ConvertError {
+    #[error("Failed to read {}", .path.display())]
+    ReadError {
+        path: PathBuf,
+        #[source]
+        error: std::io::Error,
+    },
+
+    #[error("Failed to parse {}", .path.display())]
+    ParseError {
+        path: PathBuf,
+        #[source]
+        error: BomReadError,
+    },
+
+    #[error("Failed to convert the SPDX document {}", .path.display())]
+    SpdxError {
+        path: PathBuf,
+        #[source]
+        error: SpdxError,
+    },
+
+    #[error("Cannot convert to {to} in the {format} format")]
+    UnsupportedFormat {
+        to: ConversionTarget,
+        format: ConversionFormat,
+    },
+
+    #[error("Failed to write the JSON document")]
+    JsonWriteError(#[from] JsonWriteError),
+
+    #[error("Failed to write the XML document")]
+    XmlWriteError(#[from] XmlWriteError),
+}
@@ -0,0 +1,396 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+use crate::config::{LicensePreference, SbomConfig};
+use crate::generator::GeneratorError;
+use crate::license::effective_license;
+use crate::toml::config_from_toml;
+
+use cargo::core::dependency::DepKind;
+use cargo::core::{Package, PackageId, PackageSet, Resolve, Workspace};
+use cargo::ops;
+use spdx::expression::ExprNode;
+use spdx::{Expression, LicenseItem, LicenseReq, ParseMode};
+use std::collections::hash_map::Entry;
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::fmt;
+
+/// Strong copyleft licenses, matched by prefix of the SPDX identifier
+const STRONG_COPYLEFT: &[&str] = &[
+    "AGPL-",
+    "CC-BY-SA-",
+    "GPL-",
+    "OSL-",
+    "QPL-",
+    "RPL-",
+    "Sleepycat",
+    "SSPL-",
+];
+
+/// Weak copyleft licenses, matched by prefix of the SPDX identifier
+const WEAK_COPYLEFT: &[&str] = &["CDDL-", "CPL-", "EPL-", "EUPL-", "LGPL-", "MPL-", "MS-RL"];
+
+/// Exceptions that allow linking against strong copyleft code without the copyleft applying to
+/// the rest of the binary
+const LINKING_EXCEPTIONS: &[&str] = &[
+    "Classpath-exception-2.0",
+    "GCC-exception-2.0",
+    "GCC-exception-3.1",
+    "GPL-3.0-linking-exception",
+    "LGPL-3.0-linking-exception",
+];
+
+/// How restrictive the obligations of a license are, ordered from least to most restrictive
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LicenseCategory {
+    Permissive,
+    Unknown,
+    Proprietary,
+    WeakCopyleft,
+    StrongCopyleft,
+}
+
+impl LicenseCategory {
+    pub fn is_copyleft(&self) -> bool {
+        matches!(
+            self,
+            LicenseCategory::WeakCopyleft | LicenseCategory::StrongCopyleft
+        )
+    }
+}
+
+impl fmt::Display for LicenseCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LicenseCategory::Permissive => "permissive".fmt(f),
+            LicenseCategory::Unknown => "unknown".fmt(f),
+            LicenseCategory::Proprietary => "proprietary".fmt(f),
+            LicenseCategory::WeakCopyleft => "weak copyleft".fmt(f),
+            LicenseCategory::StrongCopyleft => "strong copyleft".fmt(f),
+        }
+    }
+}
+
+/// Classifies an effective license expression, in which all licenses apply at once, as its most
+/// restrictive license
+///
+/// Expressions that cannot be parsed are [`LicenseCategory::Unknown`].
+pub fn classify(expression: &str) -> LicenseCategory {
+    let parsed = match Expression::parse_mode(expression, ParseMode::LAX) {
+        Ok(parsed) => parsed,
+        Err(_) => return LicenseCategory::Unknown,
+    };
+
+    parsed
+        .iter()
+        .filter_map(|node| match node {
+            ExprNode::Req(req) => Some(classify_requirement(&req.req)),
+            ExprNode::Op(_) => None,
+        })
+        .max()
+        .unwrap_or(LicenseCategory::Unknown)
+}
+
+fn classify_requirement(req: &LicenseReq) -> LicenseCategory {
+    let id = match &req.license {
+        LicenseItem::Spdx { id, .. } => id,
+        LicenseItem::Other { .. } => return LicenseCategory::Proprietary,
+    };
+
+    let matches = |prefixes: &[&str]| prefixes.iter().any(|prefix| id.name.starts_with(prefix));
+    if matches(STRONG_COPYLEFT) {
+        match req.exception {
+            Some(exception) if LINKING_EXCEPTIONS.contains(&exception.name) => {
+                LicenseCategory::WeakCopyleft
+            }
+            _ => LicenseCategory::StrongCopyleft,
+        }
+    } else if matches(WEAK_COPYLEFT) {
+        LicenseCategory::WeakCopyleft
+    } else if id.is_copyleft() {
+        // copyleft licenses that are not known to be weak are assumed to be strong
+        LicenseCategory::StrongCopyleft
+    } else if id.is_osi_approved() || id.is_fsf_free_libre() {
+        LicenseCategory::Permissive
+    } else {
+        LicenseCategory::Unknown
+    }
+}
+
+/// A dependency with a copyleft license and the shortest chain of dependencies through which
+/// a workspace member uses it
+///
+/// * `package` - Name and version of the dependency
+/// * `license` - Effective license of the dependency
+/// * `path` - Name and version of every package from the workspace member to the dependency
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CopyleftDependency {
+    pub package: String,
+    pub license: String,
+    pub category: LicenseCategory,
+    pub path: Vec<String>,
+}
+
+/// Copyleft exposure of a workspace member through its normal dependencies, which are the ones
+/// that end up in its binaries
+///
+/// * `package` - Name and version of the workspace member
+/// * `binaries` - Names of the binary targets of the member
+/// * `categories` - Number of dependencies in each license category
+/// * `copyleft` - Dependencies with copyleft licenses, most restrictive first
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CopyleftReport {
+    pub package: String,
+    pub binaries: Vec<String>,
+    pub categories: BTreeMap<LicenseCategory, usize>,
+    pub copyleft: Vec<CopyleftDependency>,
+}
+
+impl fmt::Display for CopyleftReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.binaries.is_empty() {
+            writeln!(f, "{} (library)", self.package)?;
+        } else {
+            writeln!(
+                f,
+                "{} (binaries: {})",
+                self.package,
+                self.binaries.join(", ")
+            )?;
+        }
+
+        let categories: Vec<_> = self
+            .categories
+            .iter()
+            .map(|(category, count)| format!("{} {}", count, category))
+            .collect();
+        if categories.is_empty() {
+            writeln!(f, "  no dependencies")?;
+        } else {
+            writeln!(f, "  dependencies: {}", categories.join(", "))?;
+        }
+
+        if self.copyleft.is_empty() {
+            write!(f, "  no copyleft dependencies")
+        } else {
+            let lines: Vec<_> = self
+                .copyleft
+                .iter()
+                .map(|dependency| {
+                    format!(
+                        "  {}: {} ({}) via {}",
+                        dependency.category,
+                        dependency.package,
+                        dependency.license,
+                        dependency.path.join(" > ")
+                    )
+                })
+                .collect();
+            write!(f, "{}", lines.join("\n"))
+        }
+    }
+}
+
+/// Analyzes the copyleft exposure of every workspace member, using the license preference of its
+/// configuration to compute the effective licenses
+pub fn analyze(
+    ws: &Workspace,
+    config_override: &SbomConfig,
+) -> Result<Vec<CopyleftReport>, GeneratorError> {
+    let workspace_config = config_from_toml(ws.custom_metadata())?;
+    let (package_set, resolve) =
+        ops::resolve_ws(ws).map_err(|error| GeneratorError::CargoConfigError {
+            config_filepath: ws.root_manifest().to_string_lossy().to_string(),
+            error,
+        })?;
+
+    let mut reports = Vec::new();
+    for member in ws.members() {
+        let package_config = config_from_toml(member.manifest().custom_metadata())?;
+        let config = workspace_config
+            .merge(&package_config)
+            .merge(config_override);
+
+        reports.push(analyze_member(
+            member,
+            &package_set,
+            &resolve,
+            &config.license_preference(),
+        )?);
+    }
+
+    Ok(reports)
+}
+
+fn analyze_member(
+    member: &Package,
+    package_set: &PackageSet<'_>,
+    resolve: &Resolve,
+    preference: &LicensePreference,
+) -> Result<CopyleftReport, GeneratorError> {
+    let mut categories = BTreeMap::new();
+    let mut copyleft = Vec::new();
+
+    let root = member.package_id();
+    let parents = normal_dependency_tree(root, resolve);
+    for (&package_id, _) in parents.iter().filter(|(&id, _)| id != root) {
+        let package = package_set
+            .get_one(package_id)
+            .map_err(|error| GeneratorError::PackageError { package_id, error })?;
+
+        let license = effective_license(package, preference).map(|license| license.expression);
+        let category = license
+            .as_deref()
+            .map_or(LicenseCategory::Unknown, classify);
+        *categories.entry(category).or_insert(0) += 1;
+
+        if category.is_copyleft() {
+            let mut path = vec![package_id];
+            let mut current = package_id;
+            while let Some(&Some(parent)) = parents.get(&current) {
+                path.push(parent);
+                current = parent;
+            }
+            path.reverse();
+
+            copyleft.push(CopyleftDependency {
+                package: display_id(package_id),
+                license: license.unwrap_or_default(),
+                category,
+                path: path.into_iter().map(display_id).collect(),
+            });
+        }
+    }
+
+    copyleft.sort_by(|a, b| {
+        b.category
+            .cmp(&a.category)
+            .then_with(|| a.package.cmp(&b.package))
+    });
+
+    Ok(CopyleftReport {
+        package: display_id(root),
+        binaries: member
+            .targets()
+            .iter()
+            .filter(|target| target.is_bin())
+            .map(|target| target.name().to_string())
+            .collect(),
+        categories,
+        copyleft,
+    })
+}
+
+/// Breadth-first search over the normal dependencies of `root`, mapping every reachable package
+/// to the package that first reached it, so that following the parents gives a shortest path
+pub fn normal_dependency_tree(
+    root: PackageId,
+    resolve: &Resolve,
+) -> HashMap<PackageId, Option<PackageId>> {
+    let mut parents = HashMap::new();
+    parents.insert(root, None);
+
+    let mut queue = VecDeque::from([root]);
+    while let Some(package_id) = queue.pop_front() {
+        let mut dependencies: Vec<_> = resolve
+            .deps(package_id)
+            .filter(|(_, deps)| deps.iter().any(|dep| dep.kind() == DepKind::Normal))
+            .map(|(dependency, _)| dependency)
+            .collect();
+        dependencies.sort();
+
+        for dependency in dependencies {
+            if let Entry::Vacant(entry) = parents.entry(dependency) {
+                entry.insert(Some(package_id));
+                queue.push_back(dependency);
+            }
+        }
+    }
+
+    parents
+}
+
+pub fn display_id(package_id: PackageId) -> String {
+    format!("{} {}", package_id.name(), package_id.version())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_should_classify_single_licenses() {
+        for (expression, expected) in [
+            ("MIT", LicenseCategory::Permissive),
+            (
+                "Apache-2.0 WITH LLVM-exception",
+                LicenseCategory::Permissive,
+            ),
+            ("MPL-2.0", LicenseCategory::WeakCopyleft),
+            ("LGPL-2.1-or-later", LicenseCategory::WeakCopyleft),
+            ("GPL-3.0-only", LicenseCategory::StrongCopyleft),
+            ("AGPL-3.0-or-later", LicenseCategory::StrongCopyleft),
+            (
+                "GPL-2.0-only WITH Classpath-exception-2.0",
+                LicenseCategory::WeakCopyleft,
+            ),
+            ("LicenseRef-Tacos", LicenseCategory::Proprietary),
+            ("CC-BY-NC-4.0", LicenseCategory::Unknown),
+            ("not a license", LicenseCategory::Unknown),
+        ] {
+            assert_eq!(classify(expression), expected, "{}", expression);
+        }
+    }
+
+    #[test]
+    fn it_should_classify_combined_licenses_as_the_most_restrictive() {
+        assert_eq!(classify("MIT AND MPL-2.0"), LicenseCategory::WeakCopyleft);
+        assert_eq!(
+            classify("LicenseRef-Tacos AND GPL-3.0-only"),
+            LicenseCategory::StrongCopyleft
+        );
+    }
+
+    #[test]
+    fn it_should_display_the_report() {
+        let report = CopyleftReport {
+            package: "app 1.0.0".to_string(),
+            binaries: vec!["app".to_string()],
+            categories: BTreeMap::from([
+                (LicenseCategory::Permissive, 2),
+                (LicenseCategory::StrongCopyleft, 1),
+            ]),
+            copyleft: vec![CopyleftDependency {
+                package: "readline 8.0.0".to_string(),
+                license: "GPL-3.0-only".to_string(),
+                category: LicenseCategory::StrongCopyleft,
+                path: vec![
+                    "app 1.0.0".to_string(),
+                    "cli 0.1.0".to_string(),
+                    "readline 8.0.0".to_string(),
+                ],
+            }],
+        };
+
+        assert_eq!(
+            report.to_string(),
+            "app 1.0.0 (binaries: app)\n\
+             \x20 dependencies: 2 permissive, 1 strong copyleft\n\
+             \x20 strong copyleft: readline 8.0.0 (GPL-3.0-only) via app 1.0.0 > cli 0.1.0 > readline 8.0.0"
+        );
+    }
+}
@@ -0,0 +1,156 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Hashes of the `.crate` files that registry packages are downloaded as
+
+use crate::config::OnError;
+use cargo::core::Package;
+use cyclonedx_bom::models::hash::{Hash, HashAlgorithm};
+use sha2::{Digest, Sha256, Sha512};
+use std::path::{Path, PathBuf};
+
+/// The SHA-256 and SHA-512 hashes of the `.crate` file of the registry package `package`
+///
+/// The file is read from the download cache `registry_cache`, usually
+/// `~/.cargo/registry/cache`. If it is not cached, e.g. for vendored sources, only the SHA-256
+/// checksum that the registry index and `Cargo.lock` record for the package is used. Files that
+/// cannot be read are handled as `on_error` says. Packages from other sources have no hashes.
+pub fn crate_hashes(
+    package: &Package,
+    registry_cache: &Path,
+    on_error: OnError,
+) -> anyhow::Result<Vec<Hash>> {
+    if !package.package_id().source_id().is_registry() {
+        return Ok(Vec::new());
+    }
+
+    let checksum = || {
+        package
+            .summary()
+            .checksum()
+            .map(|checksum| vec![Hash::new(HashAlgorithm::SHA256, checksum)])
+            .unwrap_or_default()
+    };
+    let path = match cached_crate(package.root(), registry_cache) {
+        Some(path) => path,
+        None => return Ok(checksum()),
+    };
+
+    match std::fs::read(&path) {
+        Ok(contents) => Ok(vec![
+            Hash::new(
+                HashAlgorithm::SHA256,
+                &format!("{:x}", Sha256::digest(&contents)),
+            ),
+            Hash::new(
+                HashAlgorithm::SHA512,
+                &format!("{:x}", Sha512::digest(&contents)),
+            ),
+        ]),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            log::debug!("{} is not cached", path.display());
+            Ok(checksum())
+        }
+        Err(e) => {
+            let error = anyhow::Error::new(e).context(format!("Could not read {}", path.display()));
+            on_error.handle(error)?;
+            Ok(checksum())
+        }
+    }
+}
+
+/// The cached `.crate` file of the package unpacked at `root`, which cargo unpacks to
+/// `registry/src/<index>/<name>-<version>` and caches as
+/// `registry/cache/<index>/<name>-<version>.crate`
+fn cached_crate(root: &Path, registry_cache: &Path) -> Option<PathBuf> {
+    let index = root.parent()?.file_name()?;
+    let file_name = format!("{}.crate", root.file_name()?.to_string_lossy());
+    Some(registry_cache.join(index).join(file_name))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use cargo::core::{EitherManifest, SourceId};
+    use cargo::util::toml::read_manifest_from_str;
+    use cargo::Config;
+
+    fn registry_package(root: &Path) -> Package {
+        let config = Config::default().expect("Failed to create cargo config");
+        let source_id = SourceId::crates_io(&config).expect("Failed to get crates.io source");
+        let manifest_path = root.join("Cargo.toml");
+        let manifest = read_manifest_from_str(
+            "[package]\nname = \"tacos\"\nversion = \"1.2.3\"\n[lib]\npath = \"lib.rs\"\n",
+            &manifest_path,
+            source_id,
+            &config,
+        )
+        .expect("Failed to read manifest");
+        match manifest {
+            (EitherManifest::Real(manifest), _) => Package::new(manifest, &manifest_path),
+            _ => panic!("Expected a package manifest"),
+        }
+    }
+
+    #[test]
+    fn it_should_hash_the_cached_crate_file() {
+        let tmp_dir = assert_fs::TempDir::new().expect("Failed to create temporary directory");
+        let registry = tmp_dir.path().join("registry");
+        let package = registry_package(&registry.join("src/index.example-1/tacos-1.2.3"));
+        let cache = registry.join("cache");
+        std::fs::create_dir_all(cache.join("index.example-1")).expect("Failed to create cache");
+        std::fs::write(cache.join("index.example-1/tacos-1.2.3.crate"), "tacos")
+            .expect("Failed to write crate");
+
+        let hashes = crate_hashes(&package, &cache, OnError::Fail).expect("Failed to hash");
+
+        assert_eq!(
+            hashes,
+            vec![
+                Hash::new(
+                    HashAlgorithm::SHA256,
+                    "1734c8d3b028f603483d60ab3ab8a61cf6a41f93709cf47fa01d30a90ee2282c"
+                ),
+                Hash::new(
+                    HashAlgorithm::SHA512,
+                    &format!("{:x}", Sha512::digest("tacos"))
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_fall_back_to_the_checksum_of_an_uncached_crate() {
+        let tmp_dir = assert_fs::TempDir::new().expect("Failed to create temporary directory");
+        let registry = tmp_dir.path().join("registry");
+        let mut package = registry_package(&registry.join("src/index.example-1/tacos-1.2.3"));
+        let cache = registry.join("cache");
+
+        assert!(crate_hashes(&package, &cache, OnError::Fail)
+            .expect("Failed to hash")
+            .is_empty());
+
+        package
+            .manifest_mut()
+            .summary_mut()
+            .set_checksum("0123abcd".to_string());
+        let hashes = crate_hashes(&package, &cache, OnError::Fail).expect("Failed to hash");
+
+        assert_eq!(hashes, vec![Hash::new(HashAlgorithm::SHA256, "0123abcd")]);
+    }
+}
@@ -0,0 +1,556 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+//! Cross-check of a BOM against the crates that cargo-audit and cargo-deny report on
+//!
+//! Both tools read `Cargo.lock` themselves, so a crate one of them has findings for that the BOM
+//! does not list points at a gap in either toolchain. The reports are recognized by their
+//! content: the output of `cargo audit --json` is a single JSON object, that of
+//! `cargo deny --format json check` one JSON object per line.
+
+use cyclonedx_bom::errors::BomReadError;
+use cyclonedx_bom::models::bom::Bom;
+use cyclonedx_bom::models::component::Component;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use thiserror::Error;
+
+/// The tool that wrote a report
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ReportTool {
+    CargoAudit,
+    CargoDeny,
+}
+
+impl fmt::Display for ReportTool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReportTool::CargoAudit => "cargo-audit".fmt(f),
+            ReportTool::CargoDeny => "cargo-deny".fmt(f),
+        }
+    }
+}
+
+/// A crate the reports mention that the BOM does not list
+///
+/// * `tools` - The tools that mention this version of the crate
+/// * `findings` - Advisory IDs, warning kinds or diagnostic codes reported for it
+/// * `bom_versions` - Other versions of the crate the BOM lists
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MissingCrate {
+    pub name: String,
+    pub version: String,
+    pub tools: BTreeSet<ReportTool>,
+    pub findings: BTreeSet<String>,
+    pub bom_versions: BTreeSet<String>,
+}
+
+impl fmt::Display for MissingCrate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let tools: Vec<_> = self.tools.iter().map(ToString::to_string).collect();
+        write!(f, "{} {} ({}", self.name, self.version, tools.join(", "))?;
+        if !self.findings.is_empty() {
+            let findings: Vec<_> = self.findings.iter().map(String::as_str).collect();
+            write!(f, ": {}", findings.join(", "))?;
+        }
+        write!(f, ")")?;
+        if !self.bom_versions.is_empty() {
+            let versions: Vec<_> = self.bom_versions.iter().map(String::as_str).collect();
+            write!(f, ", the BOM lists {}", versions.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// The result of cross-checking a BOM against cargo-audit and cargo-deny reports
+///
+/// * `bom_crates` - Number of distinct crates the BOM lists, nested components included
+/// * `reported_crates` - Number of distinct crates the reports mention
+/// * `lockfile_crates` - Number of crates cargo-audit scanned in `Cargo.lock`, if a cargo-audit
+///   report was given. Unlike the crates missing from the BOM this is not a mismatch by itself:
+///   the BOM may leave out dependencies of other targets or of other workspace members.
+/// * `missing` - Crates the reports mention that the BOM does not list
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Crosscheck {
+    pub bom: PathBuf,
+    pub bom_crates: usize,
+    pub reported_crates: usize,
+    pub lockfile_crates: Option<usize>,
+    pub missing: Vec<MissingCrate>,
+}
+
+impl Crosscheck {
+    /// Compares the crates the BOM lists with those the reports mention, each a tool and the
+    /// crates it mentions with their findings
+    pub fn new(bom_path: &Path, bom: &Bom, reports: &[(ReportTool, Report)]) -> Self {
+        let mut bom_crates: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+        let mut components = Vec::new();
+        if let Some(component) = bom.metadata.as_ref().and_then(|m| m.component.as_ref()) {
+            components.push(component);
+        }
+        if let Some(list) = &bom.components {
+            collect_components(&list.0, &mut components);
+        }
+        for component in components {
+            if let Some((name, version)) = crate_of(component) {
+                bom_crates.entry(name).or_default().insert(version);
+            }
+        }
+
+        let mut reported: BTreeMap<(String, String), MissingCrate> = BTreeMap::new();
+        let mut lockfile_crates = None;
+        for (tool, report) in reports {
+            if let Some(count) = report.lockfile_crates {
+                lockfile_crates = Some(lockfile_crates.map_or(count, |max: usize| max.max(count)));
+            }
+            for ((name, version), findings) in &report.crates {
+                let entry = reported
+                    .entry((name.clone(), version.clone()))
+                    .or_insert_with(|| MissingCrate {
+                        name: name.clone(),
+                        version: version.clone(),
+                        ..MissingCrate::default()
+                    });
+                entry.tools.insert(*tool);
+                entry.findings.extend(findings.iter().cloned());
+            }
+        }
+
+        let reported_crates = reported.len();
+        let missing = reported
+            .into_values()
+            .filter_map(|mut missing| {
+                let versions = bom_crates.get(&missing.name);
+                if versions.map_or(false, |versions| versions.contains(&missing.version)) {
+                    return None;
+                }
+                missing.bom_versions = versions.cloned().unwrap_or_default();
+                Some(missing)
+            })
+            .collect();
+
+        Self {
+            bom: bom_path.to_path_buf(),
+            bom_crates: bom_crates.values().map(BTreeSet::len).sum(),
+            reported_crates,
+            lockfile_crates,
+            missing,
+        }
+    }
+
+    /// Whether the BOM lists every crate the reports mention
+    pub fn is_consistent(&self) -> bool {
+        self.missing.is_empty()
+    }
+}
+
+impl fmt::Display for Crosscheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {} crate(s)", self.bom.display(), self.bom_crates)?;
+        if let Some(lockfile_crates) = self.lockfile_crates {
+            write!(f, ", cargo-audit scanned {} in Cargo.lock", lockfile_crates)?;
+        }
+        writeln!(f)?;
+
+        if self.is_consistent() {
+            return writeln!(
+                f,
+                "Consistent, the BOM lists all {} crate(s) the reports mention",
+                self.reported_crates
+            );
+        }
+        writeln!(f, "Mentioned by the reports but missing from the BOM:")?;
+        for missing in &self.missing {
+            writeln!(f, "  {}", missing)?;
+        }
+        writeln!(
+            f,
+            "Inconsistent, {} of {} crate(s) the reports mention are missing",
+            self.missing.len(),
+            self.reported_crates
+        )
+    }
+}
+
+/// The crates a report mentions, by name and version, with their findings
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Report {
+    pub crates: BTreeMap<(String, String), BTreeSet<String>>,
+    pub lockfile_crates: Option<usize>,
+}
+
+impl Report {
+    fn mention(&mut self, name: &str, version: &str, finding: Option<&str>) {
+        let findings = self
+            .crates
+            .entry((name.to_string(), version.to_string()))
+            .or_default();
+        findings.extend(finding.map(ToString::to_string));
+    }
+}
+
+/// Parses the output of `cargo audit --json` or of `cargo deny --format json check`, telling
+/// them apart by their content
+pub fn parse_report(contents: &str) -> Result<(ReportTool, Report), String> {
+    match serde_json::from_str::<serde_json::Value>(contents) {
+        Ok(value) if value.get("vulnerabilities").is_some() || value.get("lockfile").is_some() => {
+            let report = serde_json::from_value(value).map_err(|e| e.to_string())?;
+            Ok((ReportTool::CargoAudit, audit_report(report)))
+        }
+        _ => deny_report(contents).map(|report| (ReportTool::CargoDeny, report)),
+    }
+}
+
+#[derive(Deserialize)]
+struct AuditReport {
+    lockfile: Option<AuditLockfile>,
+    vulnerabilities: Option<AuditVulnerabilities>,
+    #[serde(default)]
+    warnings: BTreeMap<String, Vec<AuditFinding>>,
+}
+
+#[derive(Deserialize)]
+struct AuditLockfile {
+    #[serde(rename = "dependency-count")]
+    dependency_count: usize,
+}
+
+#[derive(Deserialize)]
+struct AuditVulnerabilities {
+    list: Vec<AuditFinding>,
+}
+
+#[derive(Deserialize)]
+struct AuditFinding {
+    advisory: Option<AuditAdvisory>,
+    package: Krate,
+}
+
+#[derive(Deserialize)]
+struct AuditAdvisory {
+    id: String,
+}
+
+#[derive(Deserialize)]
+struct Krate {
+    name: String,
+    version: String,
+}
+
+fn audit_report(audit: AuditReport) -> Report {
+    let mut report = Report {
+        lockfile_crates: audit.lockfile.map(|lockfile| lockfile.dependency_count),
+        ..Report::default()
+    };
+    for finding in audit.vulnerabilities.into_iter().flat_map(|v| v.list) {
+        let id = finding
+            .advisory
+            .as_ref()
+            .map(|advisory| advisory.id.as_str());
+        report.mention(&finding.package.name, &finding.package.version, id);
+    }
+    for (kind, findings) in &audit.warnings {
+        for finding in findings {
+            // yanked crates are reported without an advisory
+            let id = finding
+                .advisory
+                .as_ref()
+                .map_or(kind.as_str(), |advisory| advisory.id.as_str());
+            report.mention(&finding.package.name, &finding.package.version, Some(id));
+        }
+    }
+    report
+}
+
+#[derive(Deserialize)]
+struct DenyLine {
+    #[serde(rename = "type")]
+    line_type: String,
+    #[serde(default)]
+    fields: Option<DenyFields>,
+}
+
+#[derive(Deserialize)]
+struct DenyFields {
+    code: Option<String>,
+    advisory: Option<AuditAdvisory>,
+    #[serde(default)]
+    graphs: Vec<DenyNode>,
+}
+
+/// A node of the inverted dependency graph of a diagnostic, the crate it is about or one of the
+/// crates depending on it, or a feature in newer versions of cargo-deny
+#[derive(Deserialize)]
+struct DenyNode {
+    #[serde(rename = "Krate")]
+    krate: Option<Krate>,
+    #[serde(default)]
+    parents: Vec<DenyNode>,
+}
+
+fn deny_report(contents: &str) -> Result<Report, String> {
+    let mut report = Report::default();
+    for (number, line) in contents.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let line: DenyLine = serde_json::from_str(line).map_err(|e| {
+            format!(
+                "Line {} is neither cargo-audit nor cargo-deny output: {}",
+                number + 1,
+                e
+            )
+        })?;
+        let fields = match (line.line_type.as_str(), line.fields) {
+            ("diagnostic", Some(fields)) => fields,
+            _ => continue,
+        };
+        let finding = fields.advisory.map(|advisory| advisory.id).or(fields.code);
+        for graph in &fields.graphs {
+            mention_graph(&mut report, graph, finding.as_deref());
+        }
+    }
+    Ok(report)
+}
+
+fn mention_graph(report: &mut Report, node: &DenyNode, finding: Option<&str>) {
+    if let Some(krate) = &node.krate {
+        report.mention(&krate.name, &krate.version, finding);
+    }
+    for parent in &node.parents {
+        // the finding is about the crate at the root of the graph only
+        mention_graph(report, parent, None);
+    }
+}
+
+fn collect_components<'a>(components: &'a [Component], collected: &mut Vec<&'a Component>) {
+    for component in components {
+        collected.push(component);
+        if let Some(nested) = &component.components {
+            collect_components(&nested.0, collected);
+        }
+    }
+}
+
+fn crate_of(component: &Component) -> Option<(String, String)> {
+    let parts = component.purl.as_ref()?.parts().ok()?;
+    if parts.package_type != "cargo" {
+        return None;
+    }
+    Some((parts.name, parts.version?))
+}
+
+/// Reads the BOM at `bom_path` in any supported format and cross-checks it against the
+/// cargo-audit and cargo-deny reports at `report_paths`
+pub fn crosscheck(
+    bom_path: &Path,
+    report_paths: &[PathBuf],
+) -> Result<Crosscheck, CrosscheckError> {
+    let input = std::fs::read(bom_path).map_err(|error| CrosscheckError::ReadError {
+        path: bom_path.to_path_buf(),
+        error,
+    })?;
+    let (bom, _) = Bom::parse(&input).map_err(|error| CrosscheckError::ParseError {
+        path: bom_path.to_path_buf(),
+        error,
+    })?;
+
+    let mut reports = Vec::new();
+    for path in report_paths {
+        let contents =
+            std::fs::read_to_string(path).map_err(|error| CrosscheckError::ReadError {
+                path: path.to_path_buf(),
+                error,
+            })?;
+        let report = parse_report(&contents).map_err(|error| CrosscheckError::ReportError {
+            path: path.to_path_buf(),
+            error,
+        })?;
+        reports.push(report);
+    }
+
+    Ok(Crosscheck::new(bom_path, &bom, &reports))
+}
+
+#[derive(Debug, Error)]
+pub enum CrosscheckError {
+    #[error("Failed to read {}", .path.display())]
+    ReadError {
+        path: PathBuf,
+        #[source]
+        error: std::io::Error,
+    },
+
+    #[error("Failed to parse {}", .path.display())]
+    ParseError {
+        path: PathBuf,
+        #[source]
+        error: BomReadError,
+    },
+
+    #[error("Failed to parse the report {}: {}", .path.display(), .error)]
+    ReportError { path: PathBuf, error: String },
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CrosscheckFormat {
+    #[default]
+    Text,
+    Json,
+}
+
+impl FromStr for CrosscheckFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(CrosscheckFormat::Text),
+            "json" => Ok(CrosscheckFormat::Json),
+            _ => Err(format!("Expected text or json, got `{}`", s)),
+        }
+    }
+}
+
+impl fmt::Display for CrosscheckFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CrosscheckFormat::Text => "text".fmt(f),
+            CrosscheckFormat::Json => "json".fmt(f),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const BOM: &str = r#"{
+  "bomFormat": "CycloneDX",
+  "specVersion": "1.3",
+  "version": 1,
+  "metadata": {
+    "component": { "type": "application", "name": "app", "version": "1.0.0", "purl": "pkg:cargo/app@1.0.0" }
+  },
+  "components": [
+    { "type": "library", "name": "tacos", "version": "1.1.0", "purl": "pkg:cargo/tacos@1.1.0" },
+    { "type": "library", "name": "salsa", "version": "0.2.0", "purl": "pkg:cargo/salsa@0.2.0" }
+  ]
+}"#;
+
+    const AUDIT: &str = r#"{
+  "database": { "advisory-count": 500 },
+  "lockfile": { "dependency-count": 4 },
+  "vulnerabilities": {
+    "found": true,
+    "count": 1,
+    "list": [
+      {
+        "advisory": { "id": "RUSTSEC-2020-0001", "package": "tacos" },
+        "package": { "name": "tacos", "version": "1.0.0", "source": "registry+https://github.com/rust-lang/crates.io-index" }
+      }
+    ]
+  },
+  "warnings": {
+    "yanked": [
+      { "kind": "yanked", "advisory": null, "package": { "name": "salsa", "version": "0.2.0" } }
+    ]
+  }
+}"#;
+
+    const DENY: &str = r#"{"type":"diagnostic","fields":{"severity":"error","code":"banned","message":"crate 'guac = 0.3.0' is explicitly banned","graphs":[{"Krate":{"name":"guac","version":"0.3.0"},"parents":[{"Krate":{"name":"salsa","version":"0.2.0"},"parents":[{"Krate":{"name":"app","version":"1.0.0"}}]}]}]}}
+{"type":"diagnostic","fields":{"severity":"error","code":"vulnerability","advisory":{"id":"RUSTSEC-2020-0001"},"graphs":[{"Krate":{"name":"tacos","version":"1.0.0"},"parents":[{"Feature":{"crate_name":"app","name":"default"}}]}]}}
+{"type":"summary","fields":{"bans":{"errors":1}}}
+"#;
+
+    fn bom() -> Bom {
+        Bom::parse_from_json(BOM.as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn it_should_tell_the_reports_apart() {
+        let (tool, audit) = parse_report(AUDIT).unwrap();
+        assert_eq!(tool, ReportTool::CargoAudit);
+        assert_eq!(audit.lockfile_crates, Some(4));
+        assert_eq!(audit.crates.len(), 2);
+
+        let (tool, deny) = parse_report(DENY).unwrap();
+        assert_eq!(tool, ReportTool::CargoDeny);
+        assert_eq!(deny.lockfile_crates, None);
+        let crates: Vec<_> = deny.crates.keys().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(crates, ["app", "guac", "salsa", "tacos"]);
+
+        assert!(parse_report("not json").is_err());
+    }
+
+    #[test]
+    fn it_should_report_the_crates_missing_from_the_bom() {
+        let reports = [parse_report(AUDIT).unwrap(), parse_report(DENY).unwrap()];
+        let crosscheck = Crosscheck::new(Path::new("bom.json"), &bom(), &reports);
+
+        assert_eq!(crosscheck.bom_crates, 3);
+        assert_eq!(crosscheck.reported_crates, 4);
+        assert_eq!(crosscheck.lockfile_crates, Some(4));
+        assert!(!crosscheck.is_consistent());
+        assert_eq!(
+            crosscheck.missing,
+            [
+                MissingCrate {
+                    name: "guac".to_string(),
+                    version: "0.3.0".to_string(),
+                    tools: BTreeSet::from([ReportTool::CargoDeny]),
+                    findings: BTreeSet::from(["banned".to_string()]),
+                    bom_versions: BTreeSet::new(),
+                },
+                MissingCrate {
+                    name: "tacos".to_string(),
+                    version: "1.0.0".to_string(),
+                    tools: BTreeSet::from([ReportTool::CargoAudit, ReportTool::CargoDeny]),
+                    findings: BTreeSet::from(["RUSTSEC-2020-0001".to_string()]),
+                    bom_versions: BTreeSet::from(["1.1.0".to_string()]),
+                },
+            ]
+        );
+        assert_eq!(
+            crosscheck.to_string(),
+            "bom.json: 3 crate(s), cargo-audit scanned 4 in Cargo.lock
+Mentioned by the reports but missing from the BOM:
+  guac 0.3.0 (cargo-deny: banned)
+  tacos 1.0.0 (cargo-audit, cargo-deny: RUSTSEC-2020-0001), the BOM lists 1.1.0
+Inconsistent, 2 of 4 crate(s) the reports mention are missing
+"
+        );
+    }
+
+    #[test]
+    fn it_should_accept_a_bom_listing_every_reported_crate() {
+        let deny = r#"{"type":"diagnostic","fields":{"code":"duplicate","graphs":[{"Krate":{"name":"salsa","version":"0.2.0"}}]}}"#;
+        let reports = [parse_report(deny).unwrap()];
+        let crosscheck = Crosscheck::new(Path::new("bom.json"), &bom(), &reports);
+
+        assert!(crosscheck.is_consistent());
+        assert_eq!(
+            crosscheck.to_string(),
+            "bom.json: 3 crate(s)\nConsistent, the BOM lists all 1 crate(s) the reports mention\n"
+        );
+    }
+}
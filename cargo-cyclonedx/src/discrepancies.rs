@@ -0,0 +1,174 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Mismatches between the licenses that packages declare in their manifest and the licenses
+//! that their license files name, which legal review has to resolve before relying on either
+
+use crate::copyleft::{display_id, normal_dependency_tree};
+use crate::generator::GeneratorError;
+use crate::license::{declared_licenses, detected_licenses};
+
+use cargo::core::Workspace;
+use cargo::ops;
+use std::collections::BTreeSet;
+use std::fmt;
+
+/// A package whose declared licenses differ from the licenses its license files name
+///
+/// * `package` - Name and version of the package
+/// * `declared` - License expression of the manifest
+/// * `detected` - Licenses named by the license files
+/// * `undeclared` - Detected licenses that the declared expression does not name
+/// * `without_file` - Declared licenses that no license file names
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LicenseDiscrepancy {
+    pub package: String,
+    pub declared: String,
+    pub detected: Vec<String>,
+    pub undeclared: Vec<String>,
+    pub without_file: Vec<String>,
+}
+
+impl LicenseDiscrepancy {
+    /// Compares the `declared` license expression of `package` with the `detected` licenses of
+    /// its license files, returning `None` if they agree or if no license file names a license
+    pub fn compare(package: String, declared: &str, detected: Vec<String>) -> Option<Self> {
+        if detected.is_empty() {
+            return None;
+        }
+
+        let declared_licenses = declared_licenses(declared).unwrap_or_default();
+        let undeclared: Vec<_> = detected
+            .iter()
+            .filter(|license| !declared_licenses.contains(license))
+            .cloned()
+            .collect();
+        let without_file: Vec<_> = declared_licenses
+            .into_iter()
+            .filter(|license| !detected.contains(license))
+            .collect();
+
+        if undeclared.is_empty() && without_file.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            package,
+            declared: declared.to_string(),
+            detected,
+            undeclared,
+            without_file,
+        })
+    }
+}
+
+impl fmt::Display for LicenseDiscrepancy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: declares {}, license files name {}",
+            self.package,
+            self.declared,
+            self.detected.join(", ")
+        )?;
+        if !self.undeclared.is_empty() {
+            write!(f, "\n  not declared: {}", self.undeclared.join(", "))?;
+        }
+        if !self.without_file.is_empty() {
+            write!(
+                f,
+                "\n  without a license file: {}",
+                self.without_file.join(", ")
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Finds the license discrepancies of the workspace members and of their normal dependencies,
+/// which are the packages that end up in their binaries, ordered by package
+///
+/// Packages that declare no license, or whose license files name no license, are skipped.
+pub fn analyze(ws: &Workspace) -> Result<Vec<LicenseDiscrepancy>, GeneratorError> {
+    let (package_set, resolve) =
+        ops::resolve_ws(ws).map_err(|error| GeneratorError::CargoConfigError {
+            config_filepath: ws.root_manifest().to_string_lossy().to_string(),
+            error,
+        })?;
+
+    let package_ids: BTreeSet<_> = ws
+        .members()
+        .flat_map(|member| normal_dependency_tree(member.package_id(), &resolve).into_keys())
+        .collect();
+
+    let mut discrepancies = Vec::new();
+    for package_id in package_ids {
+        let package = package_set
+            .get_one(package_id)
+            .map_err(|error| GeneratorError::PackageError { package_id, error })?;
+
+        if let Some(declared) = package.manifest().metadata().license.as_deref() {
+            discrepancies.extend(LicenseDiscrepancy::compare(
+                display_id(package_id),
+                declared,
+                detected_licenses(package),
+            ));
+        }
+    }
+
+    Ok(discrepancies)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn compare(declared: &str, detected: &[&str]) -> Option<LicenseDiscrepancy> {
+        LicenseDiscrepancy::compare(
+            "tacos 1.0.0".to_string(),
+            declared,
+            detected.iter().map(ToString::to_string).collect(),
+        )
+    }
+
+    #[test]
+    fn it_should_accept_license_files_that_match_the_declared_licenses() {
+        assert_eq!(compare("MIT OR Apache-2.0", &["Apache-2.0", "MIT"]), None);
+        assert_eq!(compare("MIT/Apache", &["Apache-2.0", "MIT"]), None);
+        assert_eq!(
+            compare("Apache-2.0 WITH LLVM-exception", &["Apache-2.0"]),
+            None
+        );
+        assert_eq!(compare("MIT", &[]), None);
+    }
+
+    #[test]
+    fn it_should_report_licenses_that_are_not_declared_or_have_no_file() {
+        let discrepancy =
+            compare("MIT OR Apache-2.0", &["GPL-3.0-only", "MIT"]).expect("Expected a discrepancy");
+
+        assert_eq!(discrepancy.undeclared, vec!["GPL-3.0-only".to_string()]);
+        assert_eq!(discrepancy.without_file, vec!["Apache-2.0".to_string()]);
+        assert_eq!(
+            discrepancy.to_string(),
+            "tacos 1.0.0: declares MIT OR Apache-2.0, license files name GPL-3.0-only, MIT\n  \
+             not declared: GPL-3.0-only\n  \
+             without a license file: Apache-2.0"
+        );
+    }
+}
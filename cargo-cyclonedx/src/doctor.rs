@@ -0,0 +1,267 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+use crate::toml::config_from_toml;
+
+use cargo::core::Workspace;
+use cargo::Config;
+use std::fmt;
+use std::path::Path;
+use std::process::Command;
+use std::time::Duration;
+
+/// Endpoints that have to be reachable to generate an SBOM without `--offline`
+const ENDPOINTS: &[(&str, &str)] = &[("crates.io index", "https://index.crates.io/config.json")];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Ok,
+    Warning,
+    Error,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Ok => "ok".fmt(f),
+            Status::Warning => "warning".fmt(f),
+            Status::Error => "error".fmt(f),
+        }
+    }
+}
+
+/// The outcome of a single `cargo cyclonedx doctor` check
+///
+/// * `check` - What was checked
+/// * `message` - What was found
+/// * `hint` - How to fix the problem, if there is one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub check: String,
+    pub status: Status,
+    pub message: String,
+    pub hint: Option<String>,
+}
+
+impl Diagnostic {
+    fn ok(check: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            check: check.into(),
+            status: Status::Ok,
+            message: message.into(),
+            hint: None,
+        }
+    }
+
+    fn problem(
+        check: impl Into<String>,
+        status: Status,
+        message: impl Into<String>,
+        hint: impl Into<String>,
+    ) -> Self {
+        Self {
+            check: check.into(),
+            status,
+            message: message.into(),
+            hint: Some(hint.into()),
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}] {}: {}", self.status, self.check, self.message)?;
+        if let Some(hint) = &self.hint {
+            write!(f, "\n  hint: {}", hint)?;
+        }
+        Ok(())
+    }
+}
+
+/// Runs every check against the environment `cargo cyclonedx` would run in
+pub fn run_checks(config: &Config, manifest_path: &Path) -> Vec<Diagnostic> {
+    let mut diagnostics = vec![check_cargo(), check_registry_cache(config)];
+    diagnostics.extend(check_manifest(config, manifest_path));
+    diagnostics.extend(check_endpoints(config));
+    diagnostics
+}
+
+fn check_cargo() -> Diagnostic {
+    const CHECK: &str = "cargo";
+    let hint = "Install cargo with rustup or set the CARGO environment variable to its path";
+
+    // cargo sets CARGO when running `cargo cyclonedx`
+    let cargo = std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let cargo = Path::new(&cargo);
+
+    match Command::new(cargo).arg("--version").output() {
+        Ok(output) if output.status.success() => Diagnostic::ok(
+            CHECK,
+            String::from_utf8_lossy(&output.stdout).trim().to_string(),
+        ),
+        Ok(output) => Diagnostic::problem(
+            CHECK,
+            Status::Error,
+            format!(
+                "`{} --version` failed with {}",
+                cargo.display(),
+                output.status
+            ),
+            hint,
+        ),
+        Err(e) => Diagnostic::problem(
+            CHECK,
+            Status::Error,
+            format!("Could not run {}: {}", cargo.display(), e),
+            hint,
+        ),
+    }
+}
+
+fn check_registry_cache(config: &Config) -> Diagnostic {
+    const CHECK: &str = "registry cache";
+    let cache = config
+        .home()
+        .as_path_unlocked()
+        .join("registry")
+        .join("cache");
+
+    let has_entries = std::fs::read_dir(&cache)
+        .map(|mut entries| entries.next().is_some())
+        .unwrap_or(false);
+
+    if has_entries {
+        Diagnostic::ok(CHECK, cache.display().to_string())
+    } else {
+        Diagnostic::problem(
+            CHECK,
+            Status::Warning,
+            format!("{} is missing or empty", cache.display()),
+            "Run `cargo fetch` before generating SBOMs without network access",
+        )
+    }
+}
+
+fn check_manifest(config: &Config, manifest_path: &Path) -> Vec<Diagnostic> {
+    const CHECK: &str = "manifest";
+
+    let ws = match Workspace::new(manifest_path, config) {
+        Ok(ws) => ws,
+        Err(e) => {
+            return vec![Diagnostic::problem(
+                CHECK,
+                Status::Error,
+                format!("{:#}", e),
+                "Pass the path to a valid Cargo.toml with --manifest-path",
+            )]
+        }
+    };
+
+    let mut diagnostics = vec![Diagnostic::ok(
+        CHECK,
+        ws.root_manifest().display().to_string(),
+    )];
+
+    let hint = "Fix the [package.metadata.cyclonedx] or [workspace.metadata.cyclonedx] section";
+    if let Err(e) = config_from_toml(ws.custom_metadata()) {
+        diagnostics.push(Diagnostic::problem(
+            "workspace configuration",
+            Status::Error,
+            e.to_string(),
+            hint,
+        ));
+    }
+    for member in ws.members() {
+        if let Err(e) = config_from_toml(member.manifest().custom_metadata()) {
+            diagnostics.push(Diagnostic::problem(
+                format!("{} configuration", member.name()),
+                Status::Error,
+                e.to_string(),
+                hint,
+            ));
+        }
+    }
+
+    diagnostics
+}
+
+fn check_endpoints(config: &Config) -> Vec<Diagnostic> {
+    ENDPOINTS
+        .iter()
+        .map(|(name, url)| {
+            if config.offline() {
+                Diagnostic::ok(*name, "skipped in offline mode")
+            } else {
+                check_endpoint(config, name, url)
+            }
+        })
+        .collect()
+}
+
+fn check_endpoint(config: &Config, name: &str, url: &str) -> Diagnostic {
+    let hint = "Check the network and proxy settings (`http.proxy` in the cargo configuration)";
+
+    let result = cargo::ops::http_handle(config)
+        .map_err(|e| format!("{:#}", e))
+        .and_then(|mut handle| {
+            handle.url(url).map_err(|e| e.to_string())?;
+            handle.nobody(true).map_err(|e| e.to_string())?;
+            handle
+                .timeout(Duration::from_secs(10))
+                .map_err(|e| e.to_string())?;
+            handle.perform().map_err(|e| e.to_string())?;
+            handle.response_code().map_err(|e| e.to_string())
+        });
+
+    match result {
+        Ok(code) if (200..400).contains(&code) => {
+            Diagnostic::ok(name, format!("{} is reachable", url))
+        }
+        Ok(code) => Diagnostic::problem(
+            name,
+            Status::Error,
+            format!("{} responded with HTTP {}", url, code),
+            hint,
+        ),
+        Err(e) => Diagnostic::problem(
+            name,
+            Status::Error,
+            format!("{} is unreachable: {}", url, e),
+            hint,
+        ),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_should_display_the_hint_below_the_problem() {
+        let diagnostic = Diagnostic::problem(
+            "registry cache",
+            Status::Warning,
+            "/tmp/cache is missing or empty",
+            "Run `cargo fetch`",
+        );
+
+        assert_eq!(
+            diagnostic.to_string(),
+            "[warning] registry cache: /tmp/cache is missing or empty\n  hint: Run `cargo fetch`"
+        );
+    }
+}
@@ -0,0 +1,309 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Analysis of the packages that are in use in more than one semver-incompatible version
+//!
+//! Like `cargo tree --duplicates`, but only the versions cargo cannot unify are flagged, as
+//! those are compiled and linked side by side. Each conflict is logged as a warning and recorded
+//! as an annotation of the components of its versions, with the shortest chain of dependencies
+//! through which the workspace uses each version.
+
+use crate::build_info::BuildInfo;
+use cargo::core::{Package, PackageId, Resolve};
+use cyclonedx_bom::external_models::date_time::DateTime;
+use cyclonedx_bom::external_models::purl::Purl;
+use cyclonedx_bom::models::annotation::{Annotation, Annotations, Annotator};
+use cyclonedx_bom::models::bom::Bom;
+use semver::Version;
+use std::collections::hash_map::Entry;
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
+use std::fmt;
+
+/// A version of a package in conflict with other versions of the same package
+///
+/// * `version` - Version of the package
+/// * `bom_ref` - The `bom-ref` of the component of this version
+/// * `path` - Name and version of every package from a workspace member to this version
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConflictingVersion {
+    pub version: Version,
+    pub bom_ref: String,
+    pub path: Vec<String>,
+}
+
+/// A package of which semver-incompatible versions are in use, ordered by version
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionConflict {
+    pub name: String,
+    pub versions: Vec<ConflictingVersion>,
+}
+
+impl fmt::Display for VersionConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let versions: Vec<_> = self
+            .versions
+            .iter()
+            .map(|version| format!("{} via {}", version.version, version.path.join(" > ")))
+            .collect();
+        write!(
+            f,
+            "Semver-incompatible versions of {} are in use: {}",
+            self.name,
+            versions.join("; ")
+        )
+    }
+}
+
+/// Whether cargo can unify both versions into one, following the rules of caret requirements:
+/// the versions have to share their major version, their minor version below 1.0.0 and their
+/// patch version below 0.1.0
+pub fn semver_compatible(a: &Version, b: &Version) -> bool {
+    compatibility(a) == compatibility(b)
+}
+
+fn compatibility(version: &Version) -> (u64, Option<u64>, Option<u64>) {
+    match (version.major, version.minor) {
+        (0, 0) => (0, Some(0), Some(version.patch)),
+        (0, minor) => (0, Some(minor), None),
+        (major, _) => (major, None, None),
+    }
+}
+
+/// Finds the packages of `listed` with semver-incompatible versions, where the paths are the
+/// shortest ones from any of the workspace `members` over every kind of dependency
+pub fn find_conflicts(
+    members: &[Package],
+    resolve: &Resolve,
+    listed: &BTreeSet<Package>,
+) -> Vec<VersionConflict> {
+    let mut by_name: BTreeMap<String, Vec<PackageId>> = BTreeMap::new();
+    for package in listed {
+        by_name
+            .entry(package.name().to_string())
+            .or_default()
+            .push(package.package_id());
+    }
+    by_name.retain(|_, ids| {
+        ids.iter()
+            .map(|id| compatibility(id.version()))
+            .collect::<BTreeSet<_>>()
+            .len()
+            > 1
+    });
+    if by_name.is_empty() {
+        return Vec::new();
+    }
+
+    let parents = dependency_tree(members, resolve);
+    by_name
+        .into_iter()
+        .map(|(name, mut ids)| {
+            ids.sort_by(|a, b| a.version().cmp(b.version()));
+            let versions = ids
+                .into_iter()
+                .map(|id| ConflictingVersion {
+                    version: id.version().clone(),
+                    bom_ref: bom_ref(id),
+                    path: path_to(id, &parents),
+                })
+                .collect();
+            VersionConflict { name, versions }
+        })
+        .collect()
+}
+
+/// Logs every conflict as a warning and annotates the components of its versions in `bom`, as
+/// cargo-cyclonedx at the time the metadata of `bom` was created
+pub fn record(conflicts: &[VersionConflict], bom: &mut Bom, build_info: &BuildInfo) {
+    if conflicts.is_empty() {
+        return;
+    }
+
+    let timestamp = bom
+        .metadata
+        .as_ref()
+        .and_then(|metadata| metadata.timestamp)
+        .or_else(|| DateTime::now().ok());
+    let annotations = bom
+        .annotations
+        .get_or_insert_with(|| Annotations(Vec::new()));
+    for conflict in conflicts {
+        log::warn!("{}", conflict);
+
+        if let Some(timestamp) = timestamp {
+            annotations.0.push(Annotation::new(
+                conflict
+                    .versions
+                    .iter()
+                    .map(|version| version.bom_ref.clone())
+                    .collect(),
+                Annotator::Component(Box::new(build_info.component())),
+                timestamp,
+                &conflict.to_string(),
+            ));
+        }
+    }
+}
+
+/// Breadth-first search from the workspace members over all their dependencies, mapping every
+/// reachable package to the package that first reached it
+fn dependency_tree(
+    members: &[Package],
+    resolve: &Resolve,
+) -> HashMap<PackageId, Option<PackageId>> {
+    let mut parents = HashMap::new();
+    let mut queue = VecDeque::new();
+    for member in members {
+        parents.insert(member.package_id(), None);
+        queue.push_back(member.package_id());
+    }
+
+    while let Some(package_id) = queue.pop_front() {
+        let mut dependencies: Vec<_> = resolve
+            .deps(package_id)
+            .map(|(dependency, _)| dependency)
+            .collect();
+        dependencies.sort();
+
+        for dependency in dependencies {
+            if let Entry::Vacant(entry) = parents.entry(dependency) {
+                entry.insert(Some(package_id));
+                queue.push_back(dependency);
+            }
+        }
+    }
+
+    parents
+}
+
+fn path_to(package_id: PackageId, parents: &HashMap<PackageId, Option<PackageId>>) -> Vec<String> {
+    let mut path = vec![package_id];
+    let mut current = package_id;
+    while let Some(&Some(parent)) = parents.get(&current) {
+        path.push(parent);
+        current = parent;
+    }
+    path.reverse();
+    path.into_iter()
+        .map(|id| format!("{} {}", id.name(), id.version()))
+        .collect()
+}
+
+/// The `bom-ref` of the component of a package, which is its purl
+fn bom_ref(package_id: PackageId) -> String {
+    let name = package_id.name().trim().to_string();
+    let version = package_id.version().to_string();
+    match Purl::new("cargo", &name, &version) {
+        Ok(purl) => purl.to_string(),
+        Err(_) => format!("pkg:cargo/{}@{}", name, version),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use cyclonedx_bom::models::metadata::Metadata;
+
+    fn version(version: &str) -> Version {
+        Version::parse(version).expect("Failed to parse the version")
+    }
+
+    fn conflict() -> VersionConflict {
+        VersionConflict {
+            name: "rand".to_string(),
+            versions: vec![
+                ConflictingVersion {
+                    version: version("0.7.3"),
+                    bom_ref: "pkg:cargo/rand@0.7.3".to_string(),
+                    path: vec![
+                        "app 1.0.0".to_string(),
+                        "tacos 0.2.1".to_string(),
+                        "rand 0.7.3".to_string(),
+                    ],
+                },
+                ConflictingVersion {
+                    version: version("0.8.5"),
+                    bom_ref: "pkg:cargo/rand@0.8.5".to_string(),
+                    path: vec!["app 1.0.0".to_string(), "rand 0.8.5".to_string()],
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn it_should_follow_the_rules_of_caret_requirements() {
+        for (a, b, expected) in [
+            ("1.2.3", "1.9.0", true),
+            ("1.2.3", "2.0.0", false),
+            ("0.7.3", "0.7.9", true),
+            ("0.7.3", "0.8.5", false),
+            ("0.0.1", "0.0.1", true),
+            ("0.0.1", "0.0.2", false),
+            ("1.0.0-alpha.1", "1.0.0", true),
+        ] {
+            assert_eq!(
+                semver_compatible(&version(a), &version(b)),
+                expected,
+                "{} and {}",
+                a,
+                b
+            );
+        }
+    }
+
+    #[test]
+    fn it_should_display_the_paths_to_each_version() {
+        assert_eq!(
+            conflict().to_string(),
+            "Semver-incompatible versions of rand are in use: \
+             0.7.3 via app 1.0.0 > tacos 0.2.1 > rand 0.7.3; 0.8.5 via app 1.0.0 > rand 0.8.5"
+        );
+    }
+
+    #[test]
+    fn it_should_annotate_the_components_of_each_version() {
+        let metadata = Metadata::new().expect("Failed to create the metadata");
+        let timestamp = metadata.timestamp.expect("Missing the timestamp");
+        let mut bom = Bom::default();
+        bom.metadata = Some(metadata);
+        let build_info = BuildInfo::current();
+
+        record(&[conflict()], &mut bom, &build_info);
+
+        assert_eq!(
+            bom.annotations,
+            Some(Annotations(vec![Annotation::new(
+                vec![
+                    "pkg:cargo/rand@0.7.3".to_string(),
+                    "pkg:cargo/rand@0.8.5".to_string()
+                ],
+                Annotator::Component(Box::new(build_info.component())),
+                timestamp,
+                &conflict().to_string(),
+            )]))
+        );
+    }
+
+    #[test]
+    fn it_should_leave_sboms_without_conflicts_alone() {
+        let mut bom = Bom::default();
+        record(&[], &mut bom, &BuildInfo::current());
+
+        assert_eq!(bom.annotations, None);
+    }
+}
@@ -0,0 +1,529 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! The enrichment pipeline, which adds data from sources other than the manifest, such as the
+//! registry cache and the license files, to the components of an SBOM
+//!
+//! Every source is an [`Enricher`]. The pipeline runs the enrichers listed in the `enrichers`
+//! option in order on every component and measures the time each of them takes, so a new source
+//! only needs an [`EnricherKind`] and an implementation of the trait.
+
+use crate::config::{
+    EnricherConfig, EnricherKind, LicensePreference, LicenseTexts, OnError, RegistryLinks,
+    SbomConfig,
+};
+use crate::crate_hashes::crate_hashes;
+use crate::license::{effective_license, license_files, license_of_file, LicenseSource};
+use crate::metrics::{metrics, Counter, Phase};
+use crate::provenance::provenance;
+use cargo::core::{Package, PackageId};
+use cyclonedx_bom::external_models::normalized_string::NormalizedString;
+use cyclonedx_bom::external_models::uri::Uri;
+use cyclonedx_bom::models::attached_text::AttachedText;
+use cyclonedx_bom::models::component::{Component, ComponentEvidence};
+use cyclonedx_bom::models::external_reference::{
+    ExternalReference, ExternalReferenceType, ExternalReferences,
+};
+use cyclonedx_bom::models::license::{License, LicenseChoice, Licenses};
+use cyclonedx_bom::taxonomy::CargoProperty;
+use std::convert::TryFrom;
+use std::fmt;
+use std::path::Path;
+use std::time::{Duration, Instant};
+use thiserror::Error;
+
+/// A source of data about packages that is added to their components
+pub trait Enricher {
+    /// Adds the data about `package` to its `component`
+    fn enrich(&self, package: &Package, component: &mut Component) -> anyhow::Result<()>;
+}
+
+/// Adds the hashes of the `.crate` files of registry packages, which are read from the download
+/// cache `registry_cache`
+pub struct CrateHashes<'a> {
+    pub registry_cache: &'a Path,
+    pub on_error: OnError,
+}
+
+impl Enricher for CrateHashes<'_> {
+    fn enrich(&self, package: &Package, component: &mut Component) -> anyhow::Result<()> {
+        for hash in crate_hashes(package, self.registry_cache, self.on_error)? {
+            component.add_hash(hash);
+        }
+        Ok(())
+    }
+}
+
+/// Records the effective license of packages in the `cargo:effective-license` property, unless
+/// it is the declared license expression itself
+pub struct EffectiveLicenses {
+    pub preference: LicensePreference,
+}
+
+impl Enricher for EffectiveLicenses {
+    fn enrich(&self, package: &Package, component: &mut Component) -> anyhow::Result<()> {
+        if let Some(effective) = effective_license(package, &self.preference) {
+            let declared = package.manifest().metadata().license.as_deref();
+            if effective.source != LicenseSource::Declared
+                || declared.map(str::trim) != Some(effective.expression.as_str())
+            {
+                component
+                    .set_cargo_property(CargoProperty::EffectiveLicense, &effective.expression);
+                component.set_cargo_property(
+                    CargoProperty::EffectiveLicenseSource,
+                    &effective.source.to_string(),
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Adds the license files of packages, with their texts, to the license evidence of their
+/// components
+///
+/// A file is identified by the SPDX license of [`license_of_file`], or else named after the file.
+pub struct LicenseFileTexts;
+
+impl Enricher for LicenseFileTexts {
+    fn enrich(&self, package: &Package, component: &mut Component) -> anyhow::Result<()> {
+        let mut licenses = Vec::new();
+        for path in license_files(package) {
+            let content = match std::fs::read(&path) {
+                Ok(content) => content,
+                Err(e) => {
+                    log::warn!(
+                        "Could not read the license file {} of {}: {}",
+                        path.display(),
+                        package.name(),
+                        e
+                    );
+                    continue;
+                }
+            };
+
+            let mut license = license_of_file(package, &path)
+                .and_then(|id| License::license_id(&id).ok())
+                .unwrap_or_else(|| {
+                    let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+                    License::named_license(&file_name)
+                });
+            license.text = Some(AttachedText::text_or_base64(
+                Some(NormalizedString::new("text/plain")),
+                content,
+            ));
+            licenses.push(LicenseChoice::License(license));
+        }
+
+        if !licenses.is_empty() {
+            let evidence = component.evidence.get_or_insert(ComponentEvidence {
+                licenses: None,
+                copyright: None,
+            });
+            match &mut evidence.licenses {
+                Some(existing) => existing.0.extend(licenses),
+                None => evidence.licenses = Some(Licenses(licenses)),
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Records the commit published packages were packaged from in the `cargo:provenance:commit`
+/// property, with the directory of the package in the repository and whether the working tree
+/// was dirty, see [`crate::provenance`]
+pub struct PublishProvenance;
+
+impl Enricher for PublishProvenance {
+    fn enrich(&self, package: &Package, component: &mut Component) -> anyhow::Result<()> {
+        if let Some(provenance) = provenance(package) {
+            component.set_cargo_property(CargoProperty::ProvenanceCommit, &provenance.commit);
+            if !provenance.path_in_vcs.is_empty() {
+                component
+                    .set_cargo_property(CargoProperty::ProvenancePath, &provenance.path_in_vcs);
+            }
+            component.set_cargo_provenance_dirty(provenance.dirty);
+        }
+        Ok(())
+    }
+}
+
+/// Links the packages published on crates.io to their documentation on docs.rs and the status
+/// of their dependencies on deps.rs
+pub struct RegistryReferences;
+
+impl Enricher for RegistryReferences {
+    fn enrich(&self, package: &Package, component: &mut Component) -> anyhow::Result<()> {
+        // docs.rs and deps.rs only know the packages published on crates.io
+        if !package.package_id().source_id().is_crates_io() {
+            return Ok(());
+        }
+
+        let references = registry_references(package);
+        if !references.is_empty() {
+            match &mut component.external_references {
+                Some(existing) => existing.0.extend(references),
+                None => component.external_references = Some(ExternalReferences(references)),
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The documentation of the package on docs.rs and the status of its dependencies on deps.rs
+fn registry_references(package: &Package) -> Vec<ExternalReference> {
+    let (name, version) = (package.name(), package.version());
+    let links = vec![
+        (
+            ExternalReferenceType::Documentation,
+            format!("https://docs.rs/crate/{}/{}", name, version),
+            "docs.rs",
+        ),
+        (
+            ExternalReferenceType::Other,
+            format!("https://deps.rs/crate/{}/{}", name, version),
+            "deps.rs dependency status",
+        ),
+    ];
+
+    links
+        .into_iter()
+        .filter_map(
+            |(reference_type, url, comment)| match Uri::try_from(url.clone()) {
+                Ok(uri) => {
+                    let mut reference = ExternalReference::new(reference_type, uri);
+                    reference.comment = Some(comment.to_string());
+                    Some(reference)
+                }
+                Err(e) => {
+                    log::error!(
+                        "Package {} has an invalid registry URI ({}): {} ",
+                        name,
+                        url,
+                        e
+                    );
+                    None
+                }
+            },
+        )
+        .collect()
+}
+
+/// What an enricher did for the components of an SBOM
+///
+/// * `enriched` - Number of components the enricher ran on
+/// * `skipped` - Number of components left out because the enricher exceeded its timeout
+/// * `elapsed` - Time the enricher took
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EnricherMetrics {
+    pub enriched: usize,
+    pub skipped: usize,
+    pub elapsed: Duration,
+}
+
+impl fmt::Display for EnricherMetrics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "enriched {} components in {} ms, skipped {}",
+            self.enriched,
+            self.elapsed.as_millis(),
+            self.skipped
+        )
+    }
+}
+
+struct Stage<'a> {
+    config: EnricherConfig,
+    enricher: Box<dyn Enricher + 'a>,
+    metrics: EnricherMetrics,
+}
+
+/// The enrichers that run on every component of an SBOM, in order
+pub struct Pipeline<'a> {
+    stages: Vec<Stage<'a>>,
+}
+
+impl<'a> Pipeline<'a> {
+    /// A pipeline without enrichers, which leaves the components as they are
+    pub fn empty() -> Self {
+        Self { stages: Vec::new() }
+    }
+
+    /// The pipeline of the enrichers configured in `config`, which reads the `.crate` files of
+    /// registry packages from the download cache `registry_cache`
+    pub fn new(config: &SbomConfig, registry_cache: &'a Path) -> Self {
+        let mut pipeline = Self { stages: Vec::new() };
+
+        for enricher in config.enrichers().enrichers() {
+            let source: Box<dyn Enricher + 'a> = match enricher.kind {
+                EnricherKind::Hashes => Box::new(CrateHashes {
+                    registry_cache,
+                    on_error: config.on_error().hashing,
+                }),
+                EnricherKind::Licenses => Box::new(EffectiveLicenses {
+                    preference: config.license_preference(),
+                }),
+                EnricherKind::RegistryLinks => match config.registry_links() {
+                    RegistryLinks::Included => Box::new(RegistryReferences),
+                    RegistryLinks::NotIncluded => continue,
+                },
+                EnricherKind::LicenseTexts => match config.license_texts() {
+                    LicenseTexts::Included => Box::new(LicenseFileTexts),
+                    LicenseTexts::NotIncluded => continue,
+                },
+                EnricherKind::Provenance => Box::new(PublishProvenance),
+            };
+            pipeline.push(*enricher, source);
+        }
+
+        pipeline
+    }
+
+    /// Adds `enricher` after the enrichers already in the pipeline
+    pub fn push(&mut self, config: EnricherConfig, enricher: Box<dyn Enricher + 'a>) {
+        self.stages.push(Stage {
+            config,
+            enricher,
+            metrics: EnricherMetrics::default(),
+        });
+    }
+
+    /// Runs every enricher that has not exceeded its timeout on the component of `package`
+    pub fn enrich(
+        &mut self,
+        package: &Package,
+        component: &mut Component,
+    ) -> Result<(), EnrichmentError> {
+        for stage in &mut self.stages {
+            if let Some(timeout) = stage.config.timeout {
+                if stage.metrics.elapsed >= timeout {
+                    if stage.metrics.skipped == 0 {
+                        log::warn!(
+                            "The {} enricher exceeded its timeout of {} s, so the remaining components are not enriched by it",
+                            stage.config.kind,
+                            timeout.as_secs()
+                        );
+                    }
+                    stage.metrics.skipped += 1;
+                    continue;
+                }
+            }
+
+            let start = Instant::now();
+            let result = stage.enricher.enrich(package, component);
+            stage.metrics.elapsed += start.elapsed();
+            result.map_err(|error| EnrichmentError {
+                enricher: stage.config.kind,
+                package_id: package.package_id(),
+                error,
+            })?;
+            stage.metrics.enriched += 1;
+        }
+
+        Ok(())
+    }
+
+    /// The metrics of every enricher, in the order they run in
+    pub fn metrics(&self) -> Vec<(EnricherKind, EnricherMetrics)> {
+        self.stages
+            .iter()
+            .map(|stage| (stage.config.kind, stage.metrics))
+            .collect()
+    }
+
+    /// Reports the time the enrichers took to the [metrics hook](crate::metrics), the hashes
+    /// enricher as the hashing phase and the others as the enrichment phase
+    pub fn report_metrics(&self) {
+        let mut hashing = None;
+        let mut enrichment = None;
+        let mut skipped = 0;
+        for stage in &self.stages {
+            let phase = match stage.config.kind {
+                EnricherKind::Hashes => &mut hashing,
+                _ => &mut enrichment,
+            };
+            *phase = Some(phase.unwrap_or_default() + stage.metrics.elapsed);
+            skipped += stage.metrics.skipped;
+        }
+
+        let metrics = metrics();
+        if let Some(hashing) = hashing {
+            metrics.phase(Phase::Hashing, hashing);
+        }
+        if let Some(enrichment) = enrichment {
+            metrics.phase(Phase::Enrichment, enrichment);
+        }
+        if skipped > 0 {
+            metrics.count(Counter::SkippedEnrichments, skipped as u64);
+        }
+    }
+}
+
+#[derive(Error, Debug)]
+#[error("The {enricher} enricher failed for the package {package_id}")]
+pub struct EnrichmentError {
+    pub enricher: EnricherKind,
+    pub package_id: PackageId,
+    #[source]
+    pub error: anyhow::Error,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::config::Enrichers;
+    use cargo::core::{EitherManifest, SourceId};
+    use cargo::util::toml::read_manifest_from_str;
+    use cargo::Config;
+    use cyclonedx_bom::models::component::Classification;
+
+    fn crates_io_package() -> Package {
+        let config = Config::default().expect("Failed to create cargo config");
+        let source_id = SourceId::crates_io(&config).expect("Failed to get crates.io source");
+        let manifest_path = Path::new("/registry/tacos-1.2.3/Cargo.toml");
+        let manifest = read_manifest_from_str(
+            "[package]\nname = \"tacos\"\nversion = \"1.2.3\"\n[lib]\npath = \"lib.rs\"\n",
+            manifest_path,
+            source_id,
+            &config,
+        )
+        .expect("Failed to read manifest");
+        match manifest {
+            (EitherManifest::Real(manifest), _) => Package::new(manifest, manifest_path),
+            _ => panic!("Expected a package manifest"),
+        }
+    }
+
+    fn component() -> Component {
+        Component::new(Classification::Library, "tacos", "1.2.3", None)
+    }
+
+    fn registry_links_config(registry_links: RegistryLinks) -> SbomConfig {
+        SbomConfig {
+            registry_links: Some(registry_links),
+            enrichers: Some(
+                Enrichers::new(vec![EnricherConfig::new(EnricherKind::RegistryLinks)]).unwrap(),
+            ),
+            ..SbomConfig::empty_config()
+        }
+    }
+
+    struct Failing;
+
+    impl Enricher for Failing {
+        fn enrich(&self, _: &Package, _: &mut Component) -> anyhow::Result<()> {
+            anyhow::bail!("The registry is down")
+        }
+    }
+
+    #[test]
+    fn it_should_link_crates_io_packages_to_docs_rs_and_deps_rs() {
+        let package = crates_io_package();
+        let config = registry_links_config(RegistryLinks::Included);
+        let mut pipeline = Pipeline::new(&config, Path::new("/registry/cache"));
+        let mut component = component();
+        pipeline
+            .enrich(&package, &mut component)
+            .expect("Failed to enrich");
+
+        let references: Vec<_> = component
+            .external_references
+            .expect("Missing external references")
+            .0
+            .into_iter()
+            .map(|reference| (reference.external_reference_type, reference.url.to_string()))
+            .collect();
+
+        assert_eq!(
+            references,
+            vec![
+                (
+                    ExternalReferenceType::Documentation,
+                    "https://docs.rs/crate/tacos/1.2.3".to_string()
+                ),
+                (
+                    ExternalReferenceType::Other,
+                    "https://deps.rs/crate/tacos/1.2.3".to_string()
+                ),
+            ]
+        );
+
+        let config = registry_links_config(RegistryLinks::NotIncluded);
+        let mut pipeline = Pipeline::new(&config, Path::new("/registry/cache"));
+        let mut component = self::component();
+        pipeline
+            .enrich(&package, &mut component)
+            .expect("Failed to enrich");
+
+        assert!(component.external_references.is_none());
+        assert!(pipeline.metrics().is_empty());
+    }
+
+    #[test]
+    fn it_should_skip_enrichers_that_exceeded_their_timeout() {
+        let package = crates_io_package();
+        let mut pipeline = Pipeline::new(
+            &registry_links_config(RegistryLinks::Included),
+            Path::new("/registry/cache"),
+        );
+        pipeline.push(
+            EnricherConfig {
+                kind: EnricherKind::Hashes,
+                timeout: Some(Duration::ZERO),
+            },
+            Box::new(Failing),
+        );
+
+        for _ in 0..2 {
+            pipeline
+                .enrich(&package, &mut component())
+                .expect("Failed to enrich");
+        }
+
+        let metrics = pipeline.metrics();
+        assert_eq!(metrics[0].0, EnricherKind::RegistryLinks);
+        assert_eq!(metrics[0].1.enriched, 2);
+        assert_eq!(metrics[1].0, EnricherKind::Hashes);
+        assert_eq!(metrics[1].1.enriched, 0);
+        assert_eq!(metrics[1].1.skipped, 2);
+    }
+
+    #[test]
+    fn it_should_name_the_enricher_that_failed() {
+        let package = crates_io_package();
+        let mut pipeline = Pipeline::new(
+            &registry_links_config(RegistryLinks::NotIncluded),
+            Path::new("/registry/cache"),
+        );
+        pipeline.push(
+            EnricherConfig::new(EnricherKind::Licenses),
+            Box::new(Failing),
+        );
+
+        let error = pipeline
+            .enrich(&package, &mut component())
+            .expect_err("Should have failed");
+
+        assert_eq!(
+            error.to_string(),
+            "The licenses enricher failed for the package tacos v1.2.3"
+        );
+        assert_eq!(error.error.to_string(), "The registry is down");
+    }
+}
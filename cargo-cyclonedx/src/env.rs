@@ -0,0 +1,271 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+use crate::config::{
+    BuildProfile, CdxExtension, CustomPrefix, DocumentReferences, Duplicates, EmbeddedAssets,
+    ErrorPolicy, ExternalBoms, FeatureMatrix, GitPurl, IncludedDependencies, LicensePreference,
+    LicenseTexts, OutputOptions, OverridesFile, Pattern, Prefix, PrefixError, RegistryLinks,
+    SbomConfig, ScanExclude, SourceFiles, SpecVersion,
+};
+use crate::format::Format;
+
+use std::str::FromStr;
+use thiserror::Error;
+
+pub const FORMAT_VAR: &str = "CARGO_CYCLONEDX_FORMAT";
+pub const SPEC_VERSION_VAR: &str = "CARGO_CYCLONEDX_SPEC_VERSION";
+pub const INCLUDED_DEPENDENCIES_VAR: &str = "CARGO_CYCLONEDX_INCLUDED_DEPENDENCIES";
+pub const OUTPUT_CDX_VAR: &str = "CARGO_CYCLONEDX_OUTPUT_CDX";
+pub const OUTPUT_PATTERN_VAR: &str = "CARGO_CYCLONEDX_OUTPUT_PATTERN";
+pub const OUTPUT_PREFIX_VAR: &str = "CARGO_CYCLONEDX_OUTPUT_PREFIX";
+pub const PROFILE_VAR: &str = "CARGO_CYCLONEDX_PROFILE";
+pub const EMBEDDED_ASSETS_VAR: &str = "CARGO_CYCLONEDX_EMBEDDED_ASSETS";
+pub const SOURCE_FILES_VAR: &str = "CARGO_CYCLONEDX_SOURCE_FILES";
+pub const SCAN_EXCLUDE_VAR: &str = "CARGO_CYCLONEDX_SCAN_EXCLUDE";
+pub const LICENSE_PREFERENCE_VAR: &str = "CARGO_CYCLONEDX_LICENSE_PREFERENCE";
+pub const EXTERNAL_BOMS_VAR: &str = "CARGO_CYCLONEDX_EXTERNAL_BOMS";
+pub const REGISTRY_LINKS_VAR: &str = "CARGO_CYCLONEDX_REGISTRY_LINKS";
+pub const DUPLICATES_VAR: &str = "CARGO_CYCLONEDX_DUPLICATES";
+pub const FEATURE_MATRIX_VAR: &str = "CARGO_CYCLONEDX_FEATURE_MATRIX";
+pub const ON_ERROR_VAR: &str = "CARGO_CYCLONEDX_ON_ERROR";
+pub const EXTERNAL_REFERENCES_VAR: &str = "CARGO_CYCLONEDX_EXTERNAL_REFERENCES";
+pub const GIT_PURL_VAR: &str = "CARGO_CYCLONEDX_GIT_PURL";
+pub const LICENSE_TEXTS_VAR: &str = "CARGO_CYCLONEDX_LICENSE_TEXTS";
+pub const OVERRIDES_VAR: &str = "CARGO_CYCLONEDX_OVERRIDES";
+
+/// Reads the configuration from `CARGO_CYCLONEDX_*` environment variables
+pub fn config_from_env() -> Result<SbomConfig, EnvConfigError> {
+    config_from_vars(|name| std::env::var(name).ok())
+}
+
+fn config_from_vars(lookup: impl Fn(&str) -> Option<String>) -> Result<SbomConfig, EnvConfigError> {
+    let format = parse_var::<Format>(&lookup, FORMAT_VAR)?;
+    let included_dependencies =
+        parse_var::<IncludedDependencies>(&lookup, INCLUDED_DEPENDENCIES_VAR)?;
+
+    let cdx_extension = match lookup(OUTPUT_CDX_VAR).as_deref() {
+        Some("true") | Some("1") => Some(CdxExtension::Included),
+        Some("false") | Some("0") => Some(CdxExtension::NotIncluded),
+        Some(other) => {
+            return Err(EnvConfigError::InvalidValue {
+                name: OUTPUT_CDX_VAR.to_string(),
+                message: format!("Expected true or false, got `{}`", other),
+            })
+        }
+        None => None,
+    };
+
+    let pattern = parse_var::<Pattern>(&lookup, OUTPUT_PATTERN_VAR)?;
+    let prefix = match (pattern, lookup(OUTPUT_PREFIX_VAR)) {
+        (Some(_), Some(_)) => {
+            return Err(EnvConfigError::MutuallyExclusive(
+                OUTPUT_PATTERN_VAR.to_string(),
+                OUTPUT_PREFIX_VAR.to_string(),
+            ))
+        }
+        (Some(pattern), None) => Some(Prefix::Pattern(pattern)),
+        (None, Some(prefix)) => Some(Prefix::Custom(CustomPrefix::new(prefix)?)),
+        (None, None) => None,
+    };
+
+    let output_options = match (cdx_extension, prefix) {
+        (None, None) => None,
+        (cdx_extension, prefix) => Some(OutputOptions {
+            cdx_extension: cdx_extension.unwrap_or_default(),
+            prefix: prefix.unwrap_or_default(),
+        }),
+    };
+
+    Ok(SbomConfig {
+        format,
+        spec_version: parse_var::<SpecVersion>(&lookup, SPEC_VERSION_VAR)?,
+        included_dependencies,
+        output_options,
+        supplier: None,
+        profile: parse_var::<BuildProfile>(&lookup, PROFILE_VAR)?,
+        embedded_assets: parse_var::<EmbeddedAssets>(&lookup, EMBEDDED_ASSETS_VAR)?,
+        source_files: parse_var::<SourceFiles>(&lookup, SOURCE_FILES_VAR)?,
+        scan_exclude: parse_var::<ScanExclude>(&lookup, SCAN_EXCLUDE_VAR)?,
+        license_preference: parse_var::<LicensePreference>(&lookup, LICENSE_PREFERENCE_VAR)?,
+        external_boms: parse_var::<ExternalBoms>(&lookup, EXTERNAL_BOMS_VAR)?,
+        registry_links: parse_var::<RegistryLinks>(&lookup, REGISTRY_LINKS_VAR)?,
+        duplicates: parse_var::<Duplicates>(&lookup, DUPLICATES_VAR)?,
+        feature_matrix: parse_var::<FeatureMatrix>(&lookup, FEATURE_MATRIX_VAR)?,
+        feature_sets: None,
+        on_error: parse_var::<ErrorPolicy>(&lookup, ON_ERROR_VAR)?,
+        services: None,
+        external_references: parse_var::<DocumentReferences>(&lookup, EXTERNAL_REFERENCES_VAR)?,
+        enrichers: None,
+        document_license: None,
+        git_purl: parse_var::<GitPurl>(&lookup, GIT_PURL_VAR)?,
+        license_texts: parse_var::<LicenseTexts>(&lookup, LICENSE_TEXTS_VAR)?,
+        overrides: parse_var::<OverridesFile>(&lookup, OVERRIDES_VAR)?,
+    })
+}
+
+fn parse_var<T>(
+    lookup: &impl Fn(&str) -> Option<String>,
+    name: &str,
+) -> Result<Option<T>, EnvConfigError>
+where
+    T: FromStr,
+    T::Err: ToString,
+{
+    lookup(name)
+        .map(|value| {
+            value
+                .parse()
+                .map_err(|message: T::Err| EnvConfigError::InvalidValue {
+                    name: name.to_string(),
+                    message: message.to_string(),
+                })
+        })
+        .transpose()
+}
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum EnvConfigError {
+    #[error("Invalid value for environment variable {name}: {message}")]
+    InvalidValue { name: String, message: String },
+
+    #[error("Environment variables {0} and {1} cannot be set at the same time")]
+    MutuallyExclusive(String, String),
+
+    #[error("Invalid prefix from environment")]
+    CustomPrefixError(#[from] PrefixError),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::config::OnError;
+    use std::collections::HashMap;
+
+    fn config_from(vars: &[(&str, &str)]) -> Result<SbomConfig, EnvConfigError> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        config_from_vars(|name| vars.get(name).cloned())
+    }
+
+    #[test]
+    fn it_should_return_an_empty_config_without_variables() {
+        let actual = config_from(&[]).expect("Failed to read config");
+
+        assert_eq!(actual, SbomConfig::empty_config());
+    }
+
+    #[test]
+    fn it_should_read_all_variables() {
+        let actual = config_from(&[
+            (FORMAT_VAR, "json"),
+            (SPEC_VERSION_VAR, "1.3"),
+            (INCLUDED_DEPENDENCIES_VAR, "all"),
+            (OUTPUT_CDX_VAR, "true"),
+            (OUTPUT_PATTERN_VAR, "package"),
+            (PROFILE_VAR, "dist"),
+            (EMBEDDED_ASSETS_VAR, "1024"),
+            (SOURCE_FILES_VAR, "true"),
+            (SCAN_EXCLUDE_VAR, "vendor/** tests/fixtures"),
+            (LICENSE_PREFERENCE_VAR, "MIT,Apache-2.0"),
+            (EXTERNAL_BOMS_VAR, "frontend/bom.json"),
+            (
+                EXTERNAL_REFERENCES_VAR,
+                "build-system=https://ci.example.com/runs/42",
+            ),
+            (REGISTRY_LINKS_VAR, "true"),
+            (DUPLICATES_VAR, "true"),
+            (FEATURE_MATRIX_VAR, "true"),
+            (ON_ERROR_VAR, "registry=warn"),
+            (GIT_PURL_VAR, "repository"),
+            (LICENSE_TEXTS_VAR, "true"),
+            (OVERRIDES_VAR, "overrides.csv"),
+        ])
+        .expect("Failed to read config");
+
+        let expected = SbomConfig {
+            format: Some(Format::Json),
+            spec_version: Some(SpecVersion::V1_3),
+            included_dependencies: Some(IncludedDependencies::AllDependencies),
+            output_options: Some(OutputOptions {
+                cdx_extension: CdxExtension::Included,
+                prefix: Prefix::Pattern(Pattern::Package),
+            }),
+            supplier: None,
+            profile: Some(BuildProfile::new("dist").unwrap()),
+            embedded_assets: Some(EmbeddedAssets::Enabled { min_size: 1024 }),
+            source_files: Some(SourceFiles::Included),
+            scan_exclude: Some(
+                ScanExclude::new(vec!["vendor/**".to_string(), "tests/fixtures".to_string()])
+                    .unwrap(),
+            ),
+            license_preference: Some(
+                LicensePreference::new(vec!["MIT".to_string(), "Apache-2.0".to_string()]).unwrap(),
+            ),
+            external_boms: Some(ExternalBoms::new(vec!["frontend/bom.json".into()])),
+            registry_links: Some(RegistryLinks::Included),
+            duplicates: Some(Duplicates::Annotated),
+            feature_matrix: Some(FeatureMatrix::Enabled),
+            feature_sets: None,
+            on_error: Some(ErrorPolicy {
+                hashing: OnError::Warn,
+                registry: OnError::Warn,
+            }),
+            services: None,
+            external_references: Some(
+                "build-system=https://ci.example.com/runs/42"
+                    .parse()
+                    .unwrap(),
+            ),
+            enrichers: None,
+            document_license: None,
+            git_purl: Some(GitPurl::Repository),
+            license_texts: Some(LicenseTexts::Included),
+            overrides: Some(OverridesFile::new("overrides.csv".into())),
+        };
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn it_should_reject_invalid_values() {
+        let actual = config_from(&[(FORMAT_VAR, "yaml")]).expect_err("Should have failed");
+
+        assert_eq!(
+            actual,
+            EnvConfigError::InvalidValue {
+                name: FORMAT_VAR.to_string(),
+                message: "Expected xml or json, got `yaml`".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn it_should_reject_both_pattern_and_prefix() {
+        let actual = config_from(&[(OUTPUT_PATTERN_VAR, "bom"), (OUTPUT_PREFIX_VAR, "tacos")])
+            .expect_err("Should have failed");
+
+        assert_eq!(
+            actual,
+            EnvConfigError::MutuallyExclusive(
+                OUTPUT_PATTERN_VAR.to_string(),
+                OUTPUT_PREFIX_VAR.to_string()
+            )
+        );
+    }
+}
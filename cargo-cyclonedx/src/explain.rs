@@ -0,0 +1,396 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+//! Stable codes of the errors of the generation, of reading and validating BOMs and of the checks
+//! asked for on the command line, with an extended description and the steps to resolve each
+//!
+//! Like the error codes of rustc, an error with a code is printed as `Error[CDX0003]: ...`, and
+//! `cargo cyclonedx explain CDX0003` prints its explanation. The codes never change meaning; an
+//! error that no longer occurs keeps its code unused.
+
+use crate::config::PrefixError;
+use crate::convert::ConvertError;
+use crate::crosscheck::CrosscheckError;
+use crate::env::EnvConfigError;
+use crate::generator::{GeneratorError, SbomWriterError};
+use crate::output::OutputCollisionError;
+use crate::rescan::RescanError;
+use crate::stats::StatsError;
+use crate::toml::ConfigError;
+use crate::validate::ValidateError;
+use cyclonedx_bom::errors::SigningError;
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// The code of an error, written as `CDX` and four digits: `CDX00xx` for the generation,
+/// `CDX01xx` for reading and validating BOMs and `CDX02xx` for the checks asked for on the command
+/// line
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ErrorCode {
+    Workspace,
+    PackageMetadata,
+    Configuration,
+    InvalidAuthor,
+    PackageFiles,
+    Enrichment,
+    ExternalBom,
+    DuplicateBomRef,
+    FeatureSet,
+    WriteFailed,
+    Signing,
+    UnreadableDocument,
+    InvalidBom,
+    InsufficientCoverage,
+    CrosscheckMismatch,
+    DoctorChecksFailed,
+}
+
+impl ErrorCode {
+    pub const ALL: &'static [ErrorCode] = &[
+        ErrorCode::Workspace,
+        ErrorCode::PackageMetadata,
+        ErrorCode::Configuration,
+        ErrorCode::InvalidAuthor,
+        ErrorCode::PackageFiles,
+        ErrorCode::Enrichment,
+        ErrorCode::ExternalBom,
+        ErrorCode::DuplicateBomRef,
+        ErrorCode::FeatureSet,
+        ErrorCode::WriteFailed,
+        ErrorCode::Signing,
+        ErrorCode::UnreadableDocument,
+        ErrorCode::InvalidBom,
+        ErrorCode::InsufficientCoverage,
+        ErrorCode::CrosscheckMismatch,
+        ErrorCode::DoctorChecksFailed,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ErrorCode::Workspace => "CDX0001",
+            ErrorCode::PackageMetadata => "CDX0002",
+            ErrorCode::Configuration => "CDX0003",
+            ErrorCode::InvalidAuthor => "CDX0004",
+            ErrorCode::PackageFiles => "CDX0005",
+            ErrorCode::Enrichment => "CDX0006",
+            ErrorCode::ExternalBom => "CDX0007",
+            ErrorCode::DuplicateBomRef => "CDX0008",
+            ErrorCode::FeatureSet => "CDX0009",
+            ErrorCode::WriteFailed => "CDX0010",
+            ErrorCode::Signing => "CDX0011",
+            ErrorCode::UnreadableDocument => "CDX0101",
+            ErrorCode::InvalidBom => "CDX0102",
+            ErrorCode::InsufficientCoverage => "CDX0201",
+            ErrorCode::CrosscheckMismatch => "CDX0202",
+            ErrorCode::DoctorChecksFailed => "CDX0203",
+        }
+    }
+
+    /// A one-line summary of the error
+    pub fn title(&self) -> &'static str {
+        match self {
+            ErrorCode::Workspace => "The cargo workspace could not be loaded",
+            ErrorCode::PackageMetadata => "The information of a package could not be retrieved",
+            ErrorCode::Configuration => "The configuration is invalid",
+            ErrorCode::InvalidAuthor => "An author of a package could not be parsed",
+            ErrorCode::PackageFiles => "The files of a package could not be read",
+            ErrorCode::Enrichment => "An enricher failed for a component",
+            ErrorCode::ExternalBom => "An external BOM could not be merged",
+            ErrorCode::DuplicateBomRef => "Two components share a bom-ref",
+            ErrorCode::FeatureSet => "A feature set of the feature matrix could not be resolved",
+            ErrorCode::WriteFailed => "An SBOM could not be written",
+            ErrorCode::Signing => "An SBOM could not be signed",
+            ErrorCode::UnreadableDocument => "A document could not be read or parsed",
+            ErrorCode::InvalidBom => "The BOM is invalid",
+            ErrorCode::InsufficientCoverage => "An SBOM does not reach the required coverage",
+            ErrorCode::CrosscheckMismatch => "The reports mention crates the BOM does not list",
+            ErrorCode::DoctorChecksFailed => "A check of the environment failed",
+        }
+    }
+
+    /// The extended description of the error and the steps to resolve it
+    pub fn explanation(&self) -> &'static str {
+        match self {
+            ErrorCode::Workspace => {
+                "cargo could not load the workspace of the manifest, or the workspace has no root \
+package, e.g. because the manifest is invalid, a path dependency is missing or a cargo \
+configuration file cannot be parsed.
+
+Run `cargo metadata` in the same directory, which loads the workspace in the same way and reports \
+the problem in detail. Pass `--manifest-path` if the manifest is not in the current directory, and \
+`cargo cyclonedx doctor` to check the cargo installation."
+            }
+            ErrorCode::PackageMetadata => {
+                "The package of a dependency could not be retrieved, usually because it is neither \
+in the registry cache nor downloadable, e.g. with `--offline` or `--frozen`, behind a proxy or from \
+a private registry without credentials.
+
+Run `cargo fetch` to populate the cache, then run again, with `--offline` if the network is not \
+available. `cargo cyclonedx doctor` checks that the registry cache is populated and the crates.io \
+index is reachable."
+            }
+            ErrorCode::Configuration => {
+                "A setting of the `[package.metadata.cyclonedx]` or `[workspace.metadata.cyclonedx]` \
+section of a manifest, of a `CARGO_CYCLONEDX_*` environment variable or of the command line has an \
+invalid value, or two settings cannot be combined, e.g. an output prefix with a path separator, an \
+invalid supplier URL or an `overrides` file that cannot be read or has an invalid rule.
+
+The error names the setting. `cargo cyclonedx config show` prints the effective configuration with \
+the source of every value, and `cargo cyclonedx template` prints a valid section to start from."
+            }
+            ErrorCode::InvalidAuthor => {
+                "An entry of the `authors` of a package is not of the form `Name` or \
+`Name <email>`, or its email address is invalid.
+
+Fix the `authors` of the package named in the error in its manifest."
+            }
+            ErrorCode::PackageFiles => {
+                "The files of a package, which `--source-files` lists and `--embedded-assets` scans, \
+could not be listed or read, e.g. because a file was removed while the SBOM was generated or is \
+not readable.
+
+Check the permissions of the files of the package named in the error, and run \
+`cargo package --list` in the package, which lists the files in the same way."
+            }
+            ErrorCode::Enrichment => {
+                "An enricher, which adds hashes, licenses, registry links, license texts or \
+provenance to the components, failed for a package and its failures are configured to fail the \
+generation.
+
+Resolve the cause reported with the error, e.g. run `cargo fetch` if a `.crate` file is missing \
+from the registry cache for hashing. Pass `--on-error hashing=warn` to write the SBOM with a \
+warning instead, or disable the enricher in the `enrichers` of the configuration."
+            }
+            ErrorCode::ExternalBom => {
+                "A BOM passed with `--external-bom` could not be read, parsed or merged into the \
+SBOM, e.g. because it does not exist relative to the current directory, is not a CycloneDX \
+document or lists a bom-ref the SBOM already uses.
+
+Check the path and run `cargo cyclonedx validate <BOM>` on the external BOM."
+            }
+            ErrorCode::DuplicateBomRef => {
+                "A component could not be added to the SBOM because another component, nested \
+ones and the metadata component included, already has the same bom-ref. A BOM with duplicate \
+bom-refs is invalid, as its dependencies would be ambiguous.
+
+This happens when external BOMs or assemblies list the same components. Remove the duplicate \
+from the external BOM, or give its components unique bom-refs."
+            }
+            ErrorCode::FeatureSet => {
+                "cargo could not resolve the dependencies of a feature set of the feature matrix, \
+e.g. because a feature combination enables incompatible dependencies.
+
+Run `cargo tree -e features` with the same features to see the problem, or leave out \
+`--feature-matrix`."
+            }
+            ErrorCode::WriteFailed => {
+                "An SBOM could not be written, e.g. because its directory does not exist or is \
+not writable, or the disk is full. The SBOM is written to a temporary file first, so an existing \
+SBOM is left as it was.
+
+Check the permissions and the free space of the output directory, and the `--output-dir`, \
+`--output-prefix` and `--output-pattern` settings."
+            }
+            ErrorCode::Signing => {
+                "The key passed with `--sign-key` could not be read or used, or the SBOM cannot be \
+signed: only SBOMs of version 1.4 or later of the specification carry a signature.
+
+Pass `--spec-version 1.5`, and a P-384 or Ed25519 private key in a PKCS #8 PEM file, or a P-384 \
+key in a SEC1 PEM file."
+            }
+            ErrorCode::UnreadableDocument => {
+                "A file passed to `stats`, `validate`, `crosscheck`, `convert` or `rescan` could \
+not be read, or is not a CycloneDX document in a supported format, or not an SPDX document or a \
+report of cargo-audit or cargo-deny where one is expected.
+
+Check the path and the content of the file named in the error. BOMs are read as JSON or XML, \
+optionally compressed with gzip, in version 1.3 to 1.5 of the specification."
+            }
+            ErrorCode::InvalidBom => {
+                "`cargo cyclonedx validate` found errors in the BOM, such as an invalid Package URL, \
+license expression or bom-ref, which are printed with their location.
+
+Fix the reported content in the tool that wrote the BOM. Pass `--severity warning` to also see \
+missing content that is recommended."
+            }
+            ErrorCode::InsufficientCoverage => {
+                "The share of the components of an SBOM that have a license, or a hash, is below \
+the percentage required with `--require-license-coverage` or `--require-hash-coverage`. No SBOM \
+is written.
+
+`cargo cyclonedx stats <BOM>` on an SBOM written without the requirement shows the coverage and \
+the unlicensed components. Licenses are added by the license enricher from the license files of \
+the packages, and hashes by the hash enricher from the registry cache, so run `cargo fetch` first."
+            }
+            ErrorCode::CrosscheckMismatch => {
+                "`cargo cyclonedx crosscheck` found crates that cargo-audit or cargo-deny report on \
+but the BOM does not list, so either the BOM is incomplete or the reports are of another \
+`Cargo.lock`.
+
+Generate the BOM with `--all` from the same `Cargo.lock` the reports were made from, and with the \
+same targets and features."
+            }
+            ErrorCode::DoctorChecksFailed => {
+                "`cargo cyclonedx doctor` found a problem with cargo, the registry cache, the \
+manifest or the network, which is printed with a hint.
+
+Follow the hints of the failed checks, then run `cargo cyclonedx doctor` again."
+            }
+        }
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl FromStr for ErrorCode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ErrorCode::ALL
+            .iter()
+            .copied()
+            .find(|code| code.as_str().eq_ignore_ascii_case(s))
+            .ok_or_else(|| format!("Unknown error code `{}`", s))
+    }
+}
+
+/// A failure of a check asked for on the command line, such as a coverage requirement
+#[derive(Debug, Error)]
+#[error("{message}")]
+pub struct CheckFailure {
+    pub code: ErrorCode,
+    pub message: String,
+}
+
+impl CheckFailure {
+    pub fn new(code: ErrorCode, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+/// The code of the outermost error of the chain of `error` that has one
+pub fn code_of(error: &anyhow::Error) -> Option<ErrorCode> {
+    error.chain().find_map(|error| {
+        if let Some(failure) = error.downcast_ref::<CheckFailure>() {
+            return Some(failure.code);
+        }
+        if let Some(error) = error.downcast_ref::<GeneratorError>() {
+            return generator_code(error);
+        }
+        if let Some(error) = error.downcast_ref::<SbomWriterError>() {
+            return Some(match error {
+                SbomWriterError::SignatureError(_) => ErrorCode::Signing,
+                _ => ErrorCode::WriteFailed,
+            });
+        }
+        if error.is::<SigningError>() {
+            return Some(ErrorCode::Signing);
+        }
+        if error.is::<ConfigError>()
+            || error.is::<EnvConfigError>()
+            || error.is::<PrefixError>()
+            || error.is::<OutputCollisionError>()
+        {
+            return Some(ErrorCode::Configuration);
+        }
+        if let Some(error) = error.downcast_ref::<RescanError>() {
+            return match error {
+                RescanError::ReadError { .. } | RescanError::ParseError { .. } => {
+                    Some(ErrorCode::UnreadableDocument)
+                }
+                _ => None,
+            };
+        }
+        if error.is::<StatsError>()
+            || error.is::<ValidateError>()
+            || error.is::<CrosscheckError>()
+            || error.is::<ConvertError>()
+        {
+            return Some(ErrorCode::UnreadableDocument);
+        }
+        None
+    })
+}
+
+fn generator_code(error: &GeneratorError) -> Option<ErrorCode> {
+    match error {
+        GeneratorError::RootPackageMissingError { .. }
+        | GeneratorError::CargoConfigError { .. }
+        | GeneratorError::ProfileError { .. } => Some(ErrorCode::Workspace),
+        GeneratorError::PackageError { .. } => Some(ErrorCode::PackageMetadata),
+        GeneratorError::CustomMetadataTomlError(_)
+        | GeneratorError::InvalidSupplierUrl(_)
+        | GeneratorError::OverridesError(_) => Some(ErrorCode::Configuration),
+        GeneratorError::AuthorParseError(_) => Some(ErrorCode::InvalidAuthor),
+        GeneratorError::SourceFilesError { .. } | GeneratorError::EmbeddedAssetsError { .. } => {
+            Some(ErrorCode::PackageFiles)
+        }
+        GeneratorError::EnrichmentError(_) => Some(ErrorCode::Enrichment),
+        GeneratorError::ExternalBomError { .. } => Some(ErrorCode::ExternalBom),
+        GeneratorError::BomEditError(_) => Some(ErrorCode::DuplicateBomRef),
+        GeneratorError::FeatureSetError { .. } => Some(ErrorCode::FeatureSet),
+        // errors of the tool itself, which no explanation helps with
+        GeneratorError::MetadataError(_) | GeneratorError::InvalidRegexError(_) => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_should_have_unique_codes() {
+        let mut codes: Vec<_> = ErrorCode::ALL.iter().map(ErrorCode::as_str).collect();
+        codes.sort_unstable();
+        codes.dedup();
+        assert_eq!(codes.len(), ErrorCode::ALL.len());
+
+        for code in ErrorCode::ALL {
+            assert_eq!(code.as_str().parse::<ErrorCode>(), Ok(*code));
+            assert!(!code.explanation().is_empty());
+        }
+        assert_eq!("cdx0201".parse(), Ok(ErrorCode::InsufficientCoverage));
+        assert!("E0308".parse::<ErrorCode>().is_err());
+    }
+
+    #[test]
+    fn it_should_find_the_code_in_the_chain() {
+        let error = anyhow::Error::new(GeneratorError::AuthorParseError("Invalid".to_string()))
+            .context("Failed to generate the SBOM");
+        assert_eq!(code_of(&error), Some(ErrorCode::InvalidAuthor));
+
+        let error = anyhow::Error::new(CheckFailure::new(
+            ErrorCode::InsufficientCoverage,
+            "1 SBOM(s) do not reach the required coverage",
+        ));
+        assert_eq!(code_of(&error), Some(ErrorCode::InsufficientCoverage));
+        assert_eq!(
+            error.to_string(),
+            "1 SBOM(s) do not reach the required coverage"
+        );
+
+        assert_eq!(code_of(&anyhow::anyhow!("Something else")), None);
+    }
+}
@@ -0,0 +1,313 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+//! Annotation of vulnerabilities with their membership in the
+//! [Known Exploited Vulnerabilities catalog](https://www.cisa.gov/known-exploited-vulnerabilities-catalog)
+//! of CISA and their [EPSS](https://www.first.org/epss) score, so that they can be prioritized
+//! from the BOM
+//!
+//! Both datasets are read from local files and never downloaded: the catalog in its JSON format,
+//! and the scores in the uncompressed CSV format of FIRST. Vulnerabilities are matched by the CVE
+//! IDs among their ID and references.
+//!
+//! * A vulnerability in the catalog gets the `cisa:kev:date-added` property, and
+//!   `cisa:kev:known-ransomware-campaign-use` if the catalog tells
+//! * A vulnerability with an EPSS score gets a rating of the `other` method with the score, from
+//!   the `EPSS` source, and the `first:epss:percentile` property
+
+use cyclonedx_bom::external_models::uri::Uri;
+use cyclonedx_bom::models::bom::Bom;
+use cyclonedx_bom::models::property::Properties;
+use cyclonedx_bom::models::vulnerability::{
+    Rating, Score, ScoreMethod, Vulnerability, VulnerabilitySource,
+};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+const EPSS_URL: &str = "https://www.first.org/epss";
+
+/// An entry of the Known Exploited Vulnerabilities catalog
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KevEntry {
+    #[serde(rename = "cveID")]
+    pub cve_id: String,
+    pub date_added: String,
+    pub known_ransomware_campaign_use: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct KevCatalog {
+    vulnerabilities: Vec<KevEntry>,
+}
+
+/// The EPSS score of a CVE: the probability of exploitation in the next 30 days, and the
+/// percentile of that probability among all scored CVEs
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EpssScore {
+    pub epss: f64,
+    pub percentile: f64,
+}
+
+/// The KEV entries and EPSS scores, by CVE ID
+#[derive(Debug, Default)]
+pub struct Exploitability {
+    kev: BTreeMap<String, KevEntry>,
+    epss: BTreeMap<String, EpssScore>,
+}
+
+impl Exploitability {
+    /// Reads the catalog at `kev` and the scores at `epss`, where given
+    pub fn open(kev: Option<&Path>, epss: Option<&Path>) -> Result<Self, ExploitabilityError> {
+        let mut exploitability = Self::default();
+        if let Some(kev) = kev {
+            exploitability.read_kev(kev)?;
+        }
+        if let Some(epss) = epss {
+            exploitability.read_epss(epss)?;
+        }
+        Ok(exploitability)
+    }
+
+    /// Reads the Known Exploited Vulnerabilities catalog at `path`, in its JSON format
+    pub fn read_kev(&mut self, path: &Path) -> Result<(), ExploitabilityError> {
+        let contents = read(path)?;
+        let catalog: KevCatalog =
+            serde_json::from_str(&contents).map_err(|error| ExploitabilityError::ParseError {
+                path: path.to_path_buf(),
+                message: error.to_string(),
+            })?;
+        for entry in catalog.vulnerabilities {
+            self.kev.insert(entry.cve_id.clone(), entry);
+        }
+        Ok(())
+    }
+
+    /// Reads the EPSS scores at `path`, in the CSV format of FIRST with the `cve`, `epss` and
+    /// `percentile` columns
+    pub fn read_epss(&mut self, path: &Path) -> Result<(), ExploitabilityError> {
+        let contents = read(path)?;
+        self.epss.extend(parse_epss(&contents).map_err(|message| {
+            ExploitabilityError::ParseError {
+                path: path.to_path_buf(),
+                message,
+            }
+        })?);
+        Ok(())
+    }
+
+    /// Annotates the vulnerabilities of `bom`
+    pub fn annotate(&self, bom: &mut Bom) {
+        for vulnerability in bom
+            .vulnerabilities
+            .iter_mut()
+            .flat_map(|vulnerabilities| vulnerabilities.0.iter_mut())
+        {
+            self.annotate_vulnerability(vulnerability);
+        }
+    }
+
+    fn annotate_vulnerability(&self, vulnerability: &mut Vulnerability) {
+        let cves = cve_ids(vulnerability);
+        let mut properties = vulnerability
+            .properties
+            .take()
+            .unwrap_or_else(|| Properties(Vec::new()));
+
+        if let Some(entry) = cves.iter().find_map(|cve| self.kev.get(cve)) {
+            properties.upsert("cisa:kev:date-added", &entry.date_added);
+            if let Some(ransomware) = &entry.known_ransomware_campaign_use {
+                properties.upsert("cisa:kev:known-ransomware-campaign-use", ransomware);
+            }
+        }
+
+        // the most likely exploitation of all aliases
+        let score = cves
+            .iter()
+            .filter_map(|cve| self.epss.get(cve))
+            .max_by(|left, right| left.epss.total_cmp(&right.epss));
+        if let Some(score) = score {
+            properties.upsert("first:epss:percentile", &score.percentile.to_string());
+            let rating = Rating::new(Some(Score::new(score.epss)), None, Some(ScoreMethod::Other))
+                .with_source(VulnerabilitySource::new(
+                    Some("EPSS"),
+                    Uri::try_from(EPSS_URL.to_string()).ok(),
+                ));
+            vulnerability
+                .ratings
+                .get_or_insert_with(Vec::new)
+                .push(rating);
+        }
+
+        if !properties.0.is_empty() {
+            vulnerability.properties = Some(properties);
+        }
+    }
+}
+
+fn read(path: &Path) -> Result<String, ExploitabilityError> {
+    std::fs::read_to_string(path).map_err(|error| ExploitabilityError::ReadError {
+        path: path.to_path_buf(),
+        error,
+    })
+}
+
+/// The CVE IDs of `vulnerability`, its own and the ones of its references
+fn cve_ids(vulnerability: &Vulnerability) -> Vec<String> {
+    vulnerability
+        .id
+        .iter()
+        .chain(
+            vulnerability
+                .references
+                .iter()
+                .flatten()
+                .map(|reference| &reference.id),
+        )
+        .map(ToString::to_string)
+        .filter(|id| id.starts_with("CVE-"))
+        .collect()
+}
+
+/// Parses the EPSS scores of the CSV format of FIRST, which starts with a comment line of the
+/// model version and the date of the scores, followed by a header line
+fn parse_epss(contents: &str) -> Result<Vec<(String, EpssScore)>, String> {
+    let mut scores = Vec::new();
+    for (index, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with("cve,") {
+            continue;
+        }
+        let invalid = || {
+            format!(
+                "Expected a CVE, a score and a percentile on line {}",
+                index + 1
+            )
+        };
+        let mut fields = line.split(',');
+        let (cve, epss, percentile) = match (fields.next(), fields.next(), fields.next()) {
+            (Some(cve), Some(epss), Some(percentile)) => (cve, epss, percentile),
+            _ => return Err(invalid()),
+        };
+        let score = EpssScore {
+            epss: epss.parse().map_err(|_| invalid())?,
+            percentile: percentile.parse().map_err(|_| invalid())?,
+        };
+        scores.push((cve.to_string(), score));
+    }
+    Ok(scores)
+}
+
+#[derive(Debug, Error)]
+pub enum ExploitabilityError {
+    #[error("Could not read {}", .path.display())]
+    ReadError {
+        path: PathBuf,
+        #[source]
+        error: std::io::Error,
+    },
+
+    #[error("Could not parse {}: {message}", .path.display())]
+    ParseError { path: PathBuf, message: String },
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use cyclonedx_bom::external_models::normalized_string::NormalizedString;
+    use cyclonedx_bom::models::property::Property;
+    use cyclonedx_bom::models::vulnerability::{Vulnerabilities, VulnerabilityReference};
+
+    const KEV: &str = r#"{
+      "title": "CISA Catalog of Known Exploited Vulnerabilities",
+      "vulnerabilities": [
+        {
+          "cveID": "CVE-2021-32715",
+          "vendorProject": "hyper",
+          "dateAdded": "2023-01-10",
+          "knownRansomwareCampaignUse": "Unknown"
+        }
+      ]
+    }"#;
+
+    const EPSS: &str = "#model_version:v2023.03.01,score_date:2023-06-01T00:00:00+0000
+cve,epss,percentile
+CVE-2021-32715,0.00215,0.58921
+CVE-2021-32714,0.00442,0.71234
+";
+
+    #[test]
+    fn it_should_parse_epss_scores() {
+        assert_eq!(
+            parse_epss(EPSS).unwrap()[1],
+            (
+                "CVE-2021-32714".to_string(),
+                EpssScore {
+                    epss: 0.00442,
+                    percentile: 0.71234,
+                }
+            )
+        );
+        assert!(parse_epss("CVE-2021-32715,high").is_err());
+    }
+
+    #[test]
+    fn it_should_annotate_the_vulnerabilities_of_their_cves() {
+        let mut exploitability = Exploitability::default();
+        let catalog: KevCatalog = serde_json::from_str(KEV).unwrap();
+        for entry in catalog.vulnerabilities {
+            exploitability.kev.insert(entry.cve_id.clone(), entry);
+        }
+        exploitability.epss.extend(parse_epss(EPSS).unwrap());
+
+        let mut vulnerability = Vulnerability::new(Some("RUSTSEC-2021-0078"));
+        vulnerability.references = Some(
+            ["CVE-2021-32715", "CVE-2021-32714"]
+                .iter()
+                .map(|id| VulnerabilityReference {
+                    id: NormalizedString::new(id),
+                    source: VulnerabilitySource::new(Some("NVD"), None),
+                })
+                .collect(),
+        );
+        let mut bom = Bom::default();
+        bom.vulnerabilities = Some(Vulnerabilities(vec![
+            vulnerability,
+            Vulnerability::new(Some("RUSTSEC-2020-0001")),
+        ]));
+
+        exploitability.annotate(&mut bom);
+
+        let vulnerabilities = bom.vulnerabilities.unwrap().0;
+        assert_eq!(
+            vulnerabilities[0].properties,
+            Some(Properties(vec![
+                Property::new("cisa:kev:date-added", "2023-01-10"),
+                Property::new("cisa:kev:known-ransomware-campaign-use", "Unknown"),
+                Property::new("first:epss:percentile", "0.71234"),
+            ]))
+        );
+        let rating = &vulnerabilities[0].ratings.as_ref().unwrap()[0];
+        assert_eq!(rating.score, Some(Score::new(0.00442)));
+        assert_eq!(rating.method, Some(ScoreMethod::Other));
+        assert_eq!(vulnerabilities[1].properties, None);
+        assert_eq!(vulnerabilities[1].ratings, None);
+    }
+}
@@ -0,0 +1,191 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Resolution of the feature sets of a package for the feature matrix
+//!
+//! Each feature set is resolved the way `cargo build -p <package>` would resolve it with these
+//! features, so the SBOM of a set lists only the packages that the set pulls in. The set and the
+//! features it activates are recorded as the `cargo:feature-set` and `cargo:features`
+//! metadata properties.
+
+use crate::config::FeatureSets;
+use cargo::core::compiler::{CompileKind, RustcTargetData};
+use cargo::core::resolver::features::{CliFeatures, FeaturesFor, ForceAllTargets, HasDevUnits};
+use cargo::core::{Package, PackageId, PackageIdSpec, Workspace};
+use cargo::ops;
+use cargo::CargoResult;
+use cyclonedx_bom::models::bom::Bom;
+use cyclonedx_bom::models::metadata::Metadata;
+use cyclonedx_bom::models::property::Properties;
+use cyclonedx_bom::taxonomy::CargoProperty;
+use std::collections::HashSet;
+
+/// A combination of features of a package that the feature matrix creates an SBOM for
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FeatureSet {
+    /// The default features
+    Default,
+    /// All features
+    All,
+    /// A set of the `feature_sets` option
+    Named { name: String, features: Vec<String> },
+}
+
+impl FeatureSet {
+    /// The `default` and `all` sets, followed by the named sets
+    pub fn matrix(named: &FeatureSets) -> Vec<FeatureSet> {
+        let mut matrix = vec![FeatureSet::Default, FeatureSet::All];
+        matrix.extend(
+            named
+                .sets()
+                .iter()
+                .map(|(name, features)| FeatureSet::Named {
+                    name: name.clone(),
+                    features: features.clone(),
+                }),
+        );
+        matrix
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            FeatureSet::Default => "default",
+            FeatureSet::All => "all",
+            FeatureSet::Named { name, .. } => name,
+        }
+    }
+
+    fn cli_features(&self) -> CargoResult<CliFeatures> {
+        match self {
+            FeatureSet::Default => Ok(CliFeatures::new_all(false)),
+            FeatureSet::All => Ok(CliFeatures::new_all(true)),
+            FeatureSet::Named { features, .. } => {
+                CliFeatures::from_command_line(features, false, false)
+            }
+        }
+    }
+}
+
+/// The features of a package and the packages activated by one of its feature sets
+#[derive(Debug)]
+pub struct ActivatedFeatures {
+    pub feature_set: String,
+    pub features: Vec<String>,
+    packages: HashSet<PackageId>,
+}
+
+impl ActivatedFeatures {
+    /// Resolves the dependencies of `package` with `feature_set`, leaving out its
+    /// dev-dependencies
+    pub fn resolve(
+        ws: &Workspace<'_>,
+        package: &Package,
+        feature_set: &FeatureSet,
+    ) -> CargoResult<Self> {
+        let requested_targets = [CompileKind::Host];
+        let target_data = RustcTargetData::new(ws, &requested_targets)?;
+        let specs = [PackageIdSpec::from_package_id(package.package_id())];
+        let resolve = ops::resolve_ws_with_opts(
+            ws,
+            &target_data,
+            &requested_targets,
+            &feature_set.cli_features()?,
+            &specs,
+            HasDevUnits::No,
+            // the SBOM covers every platform the package is built for
+            ForceAllTargets::Yes,
+        )?;
+
+        let mut features: Vec<_> = resolve
+            .resolved_features
+            .activated_features(package.package_id(), FeaturesFor::NormalOrDev)
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        features.sort();
+
+        Ok(Self {
+            feature_set: feature_set.name().to_string(),
+            features,
+            packages: resolve.targeted_resolve.iter().collect(),
+        })
+    }
+
+    pub fn contains(&self, package: &Package) -> bool {
+        self.packages.contains(&package.package_id())
+    }
+
+    /// Records the feature set and its activated features as metadata properties of `bom`
+    pub fn record(&self, bom: &mut Bom) {
+        let properties = bom
+            .metadata
+            .get_or_insert_with(Metadata::default)
+            .properties
+            .get_or_insert_with(|| Properties(Vec::new()));
+        properties.upsert_cargo(CargoProperty::FeatureSet, &self.feature_set);
+        properties.upsert_cargo(CargoProperty::Features, &self.features.join(","));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn it_should_list_the_default_and_all_sets_before_the_named_ones() {
+        let named = FeatureSets::new(BTreeMap::from([
+            ("tls".to_string(), vec!["rustls".to_string()]),
+            ("minimal".to_string(), vec![]),
+        ]))
+        .unwrap();
+
+        let names: Vec<_> = FeatureSet::matrix(&named)
+            .iter()
+            .map(|set| set.name().to_string())
+            .collect();
+
+        assert_eq!(names, ["default", "all", "minimal", "tls"]);
+    }
+
+    #[test]
+    fn it_should_record_the_activated_features() {
+        let activated = ActivatedFeatures {
+            feature_set: "tls".to_string(),
+            features: vec!["default".to_string(), "rustls".to_string()],
+            packages: HashSet::new(),
+        };
+        let mut bom = Bom::default();
+
+        activated.record(&mut bom);
+
+        let properties = bom.metadata.and_then(|metadata| metadata.properties);
+        let properties: Vec<_> = properties
+            .iter()
+            .flat_map(|properties| properties.0.iter())
+            .map(|property| (property.name.clone(), property.value.to_string()))
+            .collect();
+        assert_eq!(
+            properties,
+            [
+                ("cargo:feature-set".to_string(), "tls".to_string()),
+                ("cargo:features".to_string(), "default,rustls".to_string()),
+            ]
+        );
+    }
+}
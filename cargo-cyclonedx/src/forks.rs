@@ -0,0 +1,201 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Purls and pedigrees of the packages taken from git repositories, which are often patched forks
+//! of the crates.io package of the same name
+//!
+//! With the `pkg:cargo` Purl of the crates.io package, vulnerability scanners match such a fork
+//! against the advisories of the crates.io release, although the fork may have been patched.
+
+use cargo::core::Package;
+use cyclonedx_bom::external_models::normalized_string::NormalizedString;
+use cyclonedx_bom::external_models::purl::Purl;
+use cyclonedx_bom::external_models::uri::{Uri, UriError};
+use cyclonedx_bom::models::code::{Commit, Commits};
+use cyclonedx_bom::models::component::{Classification, Component, Components, Pedigree};
+use std::convert::TryFrom;
+use std::ffi::OsStr;
+use std::path::Path;
+
+/// Gives the component of `package`, if it is taken from a git repository, the Purl of the
+/// repository at the checked out commit, and records the crates.io package of the same name and
+/// version as its ancestor, and the commit, in its pedigree
+pub fn record_repository(package: &Package, component: &mut Component) {
+    let source_id = package.package_id().source_id();
+    if !source_id.is_git() {
+        return;
+    }
+    let name = package.name().to_string();
+    let version = package.version().to_string();
+    let repository = source_id.url().as_str();
+    let commit = source_id.precise();
+    let subpath = checkout_subpath(package.root());
+
+    match repository_purl(&name, &version, repository, commit, subpath.as_deref()) {
+        Ok(purl) => component.purl = Some(purl),
+        Err(e) => {
+            log::error!("Package {} has an invalid repository Purl: {} ", name, e);
+            return;
+        }
+    }
+
+    let mut ancestor = Component::new(Classification::Library, &name, &version, None);
+    ancestor.purl = Purl::new("cargo", &name, &version).ok();
+
+    component.pedigree = Some(Pedigree {
+        ancestors: Some(Components(vec![ancestor])),
+        descendants: None,
+        variants: None,
+        commits: commit.map(|commit| {
+            Commits(vec![Commit {
+                uid: Some(NormalizedString::new(commit)),
+                url: Uri::try_from(repository.to_string()).ok(),
+                author: None,
+                committer: None,
+                message: None,
+            }])
+        }),
+        patches: None,
+        notes: Some(format!("Taken from the git repository {}", repository)),
+    });
+}
+
+/// The Purl of the package `name` in the git repository `repository`, at `commit` if it is known
+/// and at `version` otherwise
+///
+/// GitHub repositories get a `pkg:github` Purl, other repositories a `pkg:generic` Purl with a
+/// `vcs_url` qualifier. `subpath` is the directory of the package in the repository.
+pub fn repository_purl(
+    name: &str,
+    version: &str,
+    repository: &str,
+    commit: Option<&str>,
+    subpath: Option<&str>,
+) -> Result<Purl, UriError> {
+    let revision = commit.unwrap_or(version);
+    let mut purl = match github_repository(repository) {
+        Some((owner, repo)) => Purl::builder("github", &repo)
+            .namespace(&owner)
+            .version(revision),
+        None => Purl::builder("generic", name)
+            .version(revision)
+            .qualifier("vcs_url", &format!("git+{}@{}", repository, revision)),
+    };
+    if let Some(subpath) = subpath {
+        purl = purl.subpath(subpath);
+    }
+    purl.build()
+}
+
+/// The owner and name of a GitHub repository, lowercased as in `pkg:github` Purls
+fn github_repository(repository: &str) -> Option<(String, String)> {
+    let path = vec![
+        "https://github.com/",
+        "http://github.com/",
+        "ssh://git@github.com/",
+    ]
+    .into_iter()
+    .find_map(|prefix| repository.strip_prefix(prefix))?
+    .trim_end_matches('/');
+    let path = path.strip_suffix(".git").unwrap_or(path);
+
+    match path.split_once('/') {
+        Some((owner, repo)) if !owner.is_empty() && !repo.is_empty() && !repo.contains('/') => {
+            Some((owner.to_lowercase(), repo.to_lowercase()))
+        }
+        _ => None,
+    }
+}
+
+/// The directory of a package in the git checkout cargo made of its repository, e.g.
+/// `crates/fork` for `~/.cargo/git/checkouts/repo-1a2b3c4d/5e6f7a8/crates/fork`
+fn checkout_subpath(root: &Path) -> Option<String> {
+    let checkout = root.ancestors().find(|dir| {
+        dir.parent()
+            .and_then(Path::parent)
+            .and_then(Path::file_name)
+            == Some(OsStr::new("checkouts"))
+    })?;
+    let subpath = root.strip_prefix(checkout).ok()?;
+    if subpath.as_os_str().is_empty() {
+        return None;
+    }
+    Some(subpath.to_string_lossy().replace('\\', "/"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_should_use_the_github_purl_of_github_repositories() {
+        for repository in [
+            "https://github.com/Tacos/Salsa",
+            "https://github.com/tacos/salsa.git",
+            "ssh://git@github.com/tacos/salsa/",
+        ] {
+            assert_eq!(
+                repository_purl("salsa", "1.0.0", repository, Some("0123abcd"), None)
+                    .unwrap()
+                    .to_string(),
+                "pkg:github/tacos/salsa@0123abcd"
+            );
+        }
+
+        assert_eq!(
+            repository_purl(
+                "salsa-macros",
+                "1.0.0",
+                "https://github.com/tacos/salsa",
+                None,
+                Some("crates/macros")
+            )
+            .unwrap()
+            .to_string(),
+            "pkg:github/tacos/salsa@1.0.0#crates/macros"
+        );
+    }
+
+    #[test]
+    fn it_should_use_a_generic_purl_for_other_repositories() {
+        assert_eq!(
+            repository_purl(
+                "salsa",
+                "1.0.0",
+                "https://git.example.com/tacos/salsa",
+                Some("0123abcd"),
+                None
+            )
+            .unwrap()
+            .to_string(),
+            "pkg:generic/salsa@0123abcd?vcs_url=git+https://git.example.com/tacos/salsa%400123abcd"
+        );
+    }
+
+    #[test]
+    fn it_should_find_the_directory_of_the_package_in_the_checkout() {
+        let checkouts = Path::new("/home/user/.cargo/git/checkouts/salsa-1a2b3c4d/5e6f7a8");
+
+        assert_eq!(
+            checkout_subpath(&checkouts.join("crates").join("macros")),
+            Some("crates/macros".to_string())
+        );
+        assert_eq!(checkout_subpath(checkouts), None);
+        assert_eq!(checkout_subpath(Path::new("/home/user/salsa")), None);
+    }
+}
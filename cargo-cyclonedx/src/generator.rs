@@ -15,49 +15,174 @@
  *
  * SPDX-License-Identifier: Apache-2.0
  */
+use crate::assets::{embedded_assets, source_files};
+use crate::build::BuiltPackages;
+use crate::build_info::BuildInfo;
+use crate::config::ConfigSource;
+use crate::config::DeclaredServices;
+use crate::config::DocumentLicense;
+use crate::config::DocumentReferences;
+use crate::config::Duplicates;
+use crate::config::EffectiveConfig;
+use crate::config::EmbeddedAssets;
+use crate::config::ExternalBoms;
+use crate::config::FeatureMatrix;
+use crate::config::Flow;
+use crate::config::GitPurl;
 use crate::config::IncludedDependencies;
+use crate::config::OnError;
 use crate::config::Pattern;
-use crate::config::Prefix;
 use crate::config::SbomConfig;
+use crate::config::SourceFiles;
+use crate::config::Supplier;
+use crate::config::{sanitize_prefix, Prefix};
+use crate::duplicates;
+use crate::enrich::{EnrichmentError, Pipeline};
+use crate::feature_matrix::{ActivatedFeatures, FeatureSet};
+use crate::forks;
 use crate::format::Format;
+use crate::metrics::{metrics, Counter, Phase};
+use crate::omissions::{OmissionReason, Omissions};
+use crate::output::AtomicFile;
+use crate::overrides::{Overrides, OverridesError};
+use crate::profile::profile_properties;
+use crate::scan::FileScanner;
+use crate::sources;
+use crate::stats::BomStats;
 use crate::toml::config_from_toml;
 use crate::toml::ConfigError;
 use cargo::core::dependency::DepKind;
 use cargo::core::Dependency;
+use cargo::core::FeatureValue;
 use cargo::core::Package;
+use cargo::core::PackageId;
 use cargo::core::PackageSet;
 use cargo::core::Resolve;
 use cargo::core::Workspace;
 use cargo::ops;
+use cargo::util::interning::InternedString;
 
 use cyclonedx_bom::external_models::normalized_string::NormalizedString;
+use cyclonedx_bom::external_models::purl::Purl;
 use cyclonedx_bom::external_models::spdx::SpdxExpression;
-use cyclonedx_bom::external_models::uri::{Purl, Uri};
-use cyclonedx_bom::models::bom::Bom;
+use cyclonedx_bom::external_models::uri::Uri;
+use cyclonedx_bom::models::bom::{Bom, BomEditError};
 use cyclonedx_bom::models::component::{Classification, Component, Components, Scope};
+use cyclonedx_bom::models::dependency::Dependency as BomDependency;
 use cyclonedx_bom::models::external_reference::{
     ExternalReference, ExternalReferenceType, ExternalReferences,
 };
+use cyclonedx_bom::models::hash::{Hash, HashAlgorithm};
 use cyclonedx_bom::models::license::{License, LicenseChoice, Licenses};
 use cyclonedx_bom::models::metadata::Metadata;
 use cyclonedx_bom::models::metadata::MetadataError;
-use cyclonedx_bom::models::organization::OrganizationalContact;
-use cyclonedx_bom::models::tool::{Tool, Tools};
+use cyclonedx_bom::models::organization::{OrganizationalContact, OrganizationalEntity};
+use cyclonedx_bom::models::property::{Properties, Property};
+use cyclonedx_bom::models::service::{DataClassification, DataFlowType, Service, Services};
+use cyclonedx_bom::signing::{sign_json, sign_xml, SigningKey};
+use cyclonedx_bom::taxonomy::CargoProperty;
 use cyclonedx_bom::validation::Validate;
 use once_cell::sync::Lazy;
 use regex::Regex;
+use serde::Serialize;
 
 use std::convert::TryFrom;
-use std::{collections::BTreeSet, fs::File, path::PathBuf};
+use std::fmt;
+use std::time::Instant;
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+};
 use thiserror::Error;
 use validator::validate_email;
 
+/// Generates the SBOMs of the workspace of the manifest at `manifest_path`, one per package or
+/// as configured, without writing them, for build scripts and other tools that embed the
+/// generation instead of running `cargo cyclonedx`
+///
+/// `config_override` takes precedence over the `cyclonedx` metadata of the manifests, like the
+/// options of the command line. [`SbomGenerator::create_sboms_for_manifest`] also returns where
+/// each SBOM would be written.
+///
+/// ```no_run
+/// use cargo_cyclonedx::config::SbomConfig;
+///
+/// let boms = cargo_cyclonedx::generate("Cargo.toml", &SbomConfig::empty_config())
+///     .expect("Failed to generate the SBOMs");
+/// for bom in &boms {
+///     println!("{} component(s)", bom.components.as_ref().map_or(0, |c| c.0.len()));
+/// }
+/// ```
+pub fn generate(
+    manifest_path: impl AsRef<Path>,
+    config_override: &SbomConfig,
+) -> Result<Vec<Bom>, GeneratorError> {
+    let sboms = SbomGenerator::create_sboms_for_manifest(manifest_path.as_ref(), config_override)?;
+    Ok(sboms.into_iter().map(|sbom| sbom.bom).collect())
+}
+
 pub struct SbomGenerator {}
 
 impl SbomGenerator {
     pub fn create_sboms(
         ws: Workspace,
         config_override: &SbomConfig,
+    ) -> Result<Vec<GeneratedSbom>, GeneratorError> {
+        Self::generate(ws, config_override, None, false)
+    }
+
+    /// Plans the SBOMs [`SbomGenerator::create_sboms`] would create, for a dry run
+    ///
+    /// The SBOMs list the dependencies that are selected, but no enricher runs on them and no
+    /// files are hashed, scanned for embedded assets or merged from external BOMs.
+    pub fn plan_sboms(
+        ws: Workspace,
+        config_override: &SbomConfig,
+    ) -> Result<Vec<GeneratedSbom>, GeneratorError> {
+        Self::generate(ws, config_override, None, true)
+    }
+
+    /// Creates the SBOMs of the workspace of the manifest at `manifest_path`, with the default
+    /// cargo configuration of the current user
+    pub fn create_sboms_for_manifest(
+        manifest_path: &Path,
+        config_override: &SbomConfig,
+    ) -> Result<Vec<GeneratedSbom>, GeneratorError> {
+        let cargo_config = |error| GeneratorError::CargoConfigError {
+            config_filepath: manifest_path.to_string_lossy().to_string(),
+            error,
+        };
+        let config = cargo::Config::default().map_err(cargo_config)?;
+        let ws = Workspace::new(manifest_path, &config).map_err(cargo_config)?;
+        Self::create_sboms(ws, config_override)
+    }
+
+    /// Creates SBOMs for the workspace members that were built, listing only the dependencies
+    /// that were compiled as part of that build
+    pub fn create_sboms_for_build(
+        ws: Workspace,
+        config_override: &SbomConfig,
+        built: &BuiltPackages,
+    ) -> Result<Vec<GeneratedSbom>, GeneratorError> {
+        Self::generate(ws, config_override, Some(built), false)
+    }
+
+    /// Plans the SBOMs [`SbomGenerator::create_sboms_for_build`] would create, like
+    /// [`SbomGenerator::plan_sboms`]
+    pub fn plan_sboms_for_build(
+        ws: Workspace,
+        config_override: &SbomConfig,
+        built: &BuiltPackages,
+    ) -> Result<Vec<GeneratedSbom>, GeneratorError> {
+        Self::generate(ws, config_override, Some(built), true)
+    }
+
+    fn generate(
+        ws: Workspace,
+        config_override: &SbomConfig,
+        built: Option<&BuiltPackages>,
+        dry_run: bool,
     ) -> Result<Vec<GeneratedSbom>, GeneratorError> {
         log::trace!(
             "Processing the workspace {} configuration",
@@ -66,14 +191,26 @@ impl SbomGenerator {
         let workspace_config = config_from_toml(ws.custom_metadata())?;
         let members: Vec<Package> = ws.members().cloned().collect();
 
+        let started = Instant::now();
         let (package_ids, resolve) =
             ops::resolve_ws(&ws).map_err(|error| GeneratorError::CargoConfigError {
                 config_filepath: ws.root_manifest().to_string_lossy().to_string(),
                 error,
             })?;
+        metrics().phase(Phase::Metadata, started.elapsed());
 
+        let context = WorkspaceContext {
+            ws: &ws,
+            members: &members,
+            resolve: &resolve,
+        };
         let mut result = Vec::with_capacity(members.len());
         for member in members.iter() {
+            if built.map_or(false, |built| !built.contains(member)) {
+                log::debug!("Skipping {} because it was not built", member.name());
+                continue;
+            }
+
             log::trace!(
                 "Processing the package {} configuration",
                 member.manifest_path().to_string_lossy()
@@ -88,48 +225,750 @@ impl SbomGenerator {
             log::trace!("Config from config override: {:?}", config_override);
             log::debug!("Config from merged config: {:?}", config);
 
-            let dependencies =
-                if config.included_dependencies() == IncludedDependencies::AllDependencies {
-                    all_dependencies(&members, &package_ids, &resolve)?
-                } else {
-                    top_level_dependencies(&members, &package_ids, &resolve)?
+            let mut omissions = Omissions::default();
+            let (mut dependencies, unavailable) =
+                all_dependencies(&members, &package_ids, &resolve, config.on_error().registry)?;
+            let selected = match config.included_dependencies() {
+                IncludedDependencies::AllDependencies => None,
+                IncludedDependencies::TopLevelDependencies => {
+                    Some(top_level_dependencies(&members, &package_ids, &resolve)?)
+                }
+                IncludedDependencies::Library => {
+                    Some(library_dependencies(member, &package_ids, &resolve)?)
+                }
+            };
+            if let Some(selected) = selected {
+                omissions = omitted_dependencies(&members, &resolve, &dependencies, &selected);
+                dependencies = selected;
+            }
+            omissions.add(OmissionReason::Unavailable, unavailable);
+
+            if let Some(built) = built {
+                let count = dependencies.len();
+                dependencies.retain(|package| built.contains(package));
+                omissions.add(OmissionReason::NotBuilt, count - dependencies.len());
+            }
+
+            let feature_sets = match config.feature_matrix() {
+                FeatureMatrix::Enabled => FeatureSet::matrix(&config.feature_sets())
+                    .into_iter()
+                    .map(Some)
+                    .collect(),
+                FeatureMatrix::Disabled => vec![None],
+            };
+            for feature_set in feature_sets {
+                let mut selection = Selection {
+                    dependencies: dependencies.clone(),
+                    omissions: omissions.clone(),
+                    activated: None,
                 };
+                if let Some(feature_set) = feature_set {
+                    let activated =
+                        ActivatedFeatures::resolve(&ws, member, &feature_set).map_err(|error| {
+                            GeneratorError::FeatureSetError {
+                                package: member.name().to_string(),
+                                feature_set: feature_set.name().to_string(),
+                                error,
+                            }
+                        })?;
+                    let count = selection.dependencies.len();
+                    selection
+                        .dependencies
+                        .retain(|package| activated.contains(package));
+                    selection.omissions.add(
+                        OmissionReason::DisabledFeature,
+                        count - selection.dependencies.len(),
+                    );
+                    selection.activated = Some(activated);
+                }
 
-            let bom = create_bom(member, dependencies)?;
+                result.push(create_sbom(
+                    &context,
+                    member,
+                    config.clone(),
+                    selection,
+                    dry_run,
+                )?);
+            }
+        }
 
-            log::debug!("Bom validation: {:?}", &bom.validate());
+        Ok(result)
+    }
 
-            let generated = GeneratedSbom {
+    /// Creates a SaaSBOM of every workspace member that declares `services`
+    ///
+    /// The services of the member are the subjects of the SaaSBOM, which lists no components
+    /// besides the member itself in the metadata and records that the member depends on each of
+    /// its services.
+    pub fn create_saasboms(
+        ws: Workspace,
+        config_override: &SbomConfig,
+    ) -> Result<Vec<GeneratedSbom>, GeneratorError> {
+        Self::generate_saasboms(ws, config_override, false)
+    }
+
+    /// Plans the SaaSBOMs [`SbomGenerator::create_saasboms`] would create, without running the
+    /// enrichers on the members
+    pub fn plan_saasboms(
+        ws: Workspace,
+        config_override: &SbomConfig,
+    ) -> Result<Vec<GeneratedSbom>, GeneratorError> {
+        Self::generate_saasboms(ws, config_override, true)
+    }
+
+    fn generate_saasboms(
+        ws: Workspace,
+        config_override: &SbomConfig,
+        dry_run: bool,
+    ) -> Result<Vec<GeneratedSbom>, GeneratorError> {
+        let workspace_config = config_from_toml(ws.custom_metadata())?;
+
+        let mut result = Vec::new();
+        for member in ws.members() {
+            let package_config = config_from_toml(member.manifest().custom_metadata())?;
+            let config = workspace_config
+                .merge(&package_config)
+                .merge(config_override);
+
+            let services = declared_services(member, &config.services());
+            if services.is_empty() {
+                log::warn!(
+                    "The package {} declares no services, so no SaaSBOM is written for it",
+                    member.name()
+                );
+                continue;
+            }
+
+            let registry_cache = ws.config().registry_cache_path();
+            let mut pipeline = if dry_run {
+                Pipeline::empty()
+            } else {
+                Pipeline::new(&config, registry_cache.as_path_unlocked())
+            };
+            let mut metadata = create_metadata(member, config.supplier.as_ref(), &mut pipeline)?;
+            pipeline.report_metrics();
+            metadata.licenses = config.document_license.as_ref().map(document_license);
+            BuildInfo::current().record(&mut metadata);
+
+            let mut bom = Bom::default().with_metadata(metadata);
+            bom.external_references = document_references(&config.external_references());
+            if let Some(member_ref) = bom_ref(member) {
+                let service_refs = services
+                    .iter()
+                    .filter_map(|service| service.bom_ref.clone())
+                    .collect();
+                bom.add_dependency(BomDependency::new(member_ref, service_refs));
+            }
+            bom.services = Some(Services(services));
+
+            result.push(GeneratedSbom {
                 bom,
                 manifest_path: member.manifest_path().to_path_buf(),
                 package_name: member.name().to_string(),
                 sbom_config: config,
-            };
-
-            result.push(generated);
+                output_dir: None,
+                published_refs: None,
+                feature_set: None,
+                document: Document::Saasbom,
+            });
         }
 
         Ok(result)
     }
+
+    /// Resolves the configuration of every workspace member without generating any SBOMs,
+    /// keeping track of which source each configuration value was taken from
+    pub fn effective_configs(
+        ws: &Workspace,
+        env_config: &SbomConfig,
+        cli_config: &SbomConfig,
+    ) -> Result<Vec<PackageConfig>, GeneratorError> {
+        let workspace_config = config_from_toml(ws.custom_metadata())?;
+
+        ws.members()
+            .map(|member| {
+                let package_config = config_from_toml(member.manifest().custom_metadata())?;
+                let config = EffectiveConfig::resolve(&[
+                    (ConfigSource::WorkspaceMetadata, &workspace_config),
+                    (ConfigSource::PackageMetadata, &package_config),
+                    (ConfigSource::Environment, env_config),
+                    (ConfigSource::CommandLine, cli_config),
+                ]);
+
+                Ok(PackageConfig {
+                    package_name: member.name().to_string(),
+                    manifest_path: member.manifest_path().to_path_buf(),
+                    config,
+                })
+            })
+            .collect()
+    }
+
+    /// Merges the SBOMs of the workspace members into a single SBOM of the workspace, written
+    /// next to the root manifest `root_manifest`
+    ///
+    /// The SBOM of the root package, or of the workspace directory for a virtual workspace,
+    /// nests the metadata components of the other members, see [`Bom::merge`].
+    pub fn merge_sboms(
+        root_manifest: &Path,
+        mut sboms: Vec<GeneratedSbom>,
+    ) -> Result<Vec<GeneratedSbom>, GeneratorError> {
+        if sboms.is_empty() {
+            return Ok(sboms);
+        }
+
+        let mut merged = match sboms
+            .iter()
+            .position(|sbom| sbom.manifest_path == root_manifest)
+        {
+            Some(root) => sboms.remove(root),
+            None => {
+                let name = root_manifest
+                    .parent()
+                    .and_then(Path::file_name)
+                    .map(|name| name.to_string_lossy().to_string())
+                    .unwrap_or_else(|| "workspace".to_string());
+                let mut metadata = Metadata::new()?;
+                metadata.component =
+                    Some(Component::new(Classification::Application, &name, "", None));
+                BuildInfo::current().record(&mut metadata);
+
+                GeneratedSbom {
+                    bom: Bom::default().with_metadata(metadata),
+                    manifest_path: root_manifest.to_path_buf(),
+                    package_name: name,
+                    sbom_config: sboms[0].sbom_config.clone(),
+                    output_dir: None,
+                    published_refs: Some(BTreeSet::new()),
+                    feature_set: None,
+                    document: Document::Sbom,
+                }
+            }
+        };
+
+        let mut others = Vec::with_capacity(sboms.len());
+        for sbom in sboms {
+            if let (Some(published), Some(other)) =
+                (&mut merged.published_refs, sbom.published_refs)
+            {
+                published.extend(other);
+            }
+            others.push(sbom.bom);
+        }
+        merged.bom.merge(others)?;
+
+        Ok(vec![merged])
+    }
+}
+
+/// The effective configuration of a single workspace member
+pub struct PackageConfig {
+    pub package_name: String,
+    pub manifest_path: PathBuf,
+    pub config: EffectiveConfig,
 }
 
-fn create_bom(package: &Package, dependencies: BTreeSet<Package>) -> Result<Bom, GeneratorError> {
+impl fmt::Display for PackageConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let config = &self.config;
+        writeln!(
+            f,
+            "# {} ({})",
+            self.package_name,
+            self.manifest_path.display()
+        )?;
+        writeln!(
+            f,
+            "format = \"{}\" # {}",
+            config.format.value, config.format.source
+        )?;
+        writeln!(
+            f,
+            "spec_version = \"{}\" # {}",
+            config.spec_version.value, config.spec_version.source
+        )?;
+        writeln!(
+            f,
+            "included_dependencies = \"{}\" # {}",
+            config.included_dependencies.value, config.included_dependencies.source
+        )?;
+        writeln!(
+            f,
+            "output_options = {} # {}",
+            config.output_options.value, config.output_options.source
+        )?;
+        writeln!(
+            f,
+            "profile = \"{}\" # {}",
+            config.profile.value, config.profile.source
+        )?;
+        writeln!(
+            f,
+            "embedded_assets = {} # {}",
+            config.embedded_assets.value, config.embedded_assets.source
+        )?;
+        writeln!(
+            f,
+            "source_files = {} # {}",
+            config.source_files.value, config.source_files.source
+        )?;
+        writeln!(
+            f,
+            "scan_exclude = {} # {}",
+            config.scan_exclude.value, config.scan_exclude.source
+        )?;
+        writeln!(
+            f,
+            "license_preference = {} # {}",
+            config.license_preference.value, config.license_preference.source
+        )?;
+        writeln!(
+            f,
+            "external_boms = {} # {}",
+            config.external_boms.value, config.external_boms.source
+        )?;
+        writeln!(
+            f,
+            "registry_links = {} # {}",
+            config.registry_links.value, config.registry_links.source
+        )?;
+        writeln!(
+            f,
+            "duplicates = {} # {}",
+            config.duplicates.value, config.duplicates.source
+        )?;
+        writeln!(
+            f,
+            "feature_matrix = {} # {}",
+            config.feature_matrix.value, config.feature_matrix.source
+        )?;
+        writeln!(
+            f,
+            "feature_sets = {} # {}",
+            config.feature_sets.value, config.feature_sets.source
+        )?;
+        writeln!(
+            f,
+            "on_error = {} # {}",
+            config.on_error.value, config.on_error.source
+        )?;
+        writeln!(
+            f,
+            "services = {} # {}",
+            config.services.value, config.services.source
+        )?;
+        writeln!(
+            f,
+            "external_references = {} # {}",
+            config.external_references.value, config.external_references.source
+        )?;
+        writeln!(
+            f,
+            "enrichers = {} # {}",
+            config.enrichers.value, config.enrichers.source
+        )?;
+        writeln!(
+            f,
+            "git_purl = \"{}\" # {}",
+            config.git_purl.value, config.git_purl.source
+        )?;
+        writeln!(
+            f,
+            "license_texts = {} # {}",
+            config.license_texts.value, config.license_texts.source
+        )?;
+        if let Some(supplier) = &config.supplier.value {
+            writeln!(f, "supplier = {} # {}", supplier, config.supplier.source)?;
+        }
+        if let Some(license) = &config.document_license.value {
+            writeln!(
+                f,
+                "document_license = {} # {}",
+                license, config.document_license.source
+            )?;
+        }
+        if let Some(overrides) = &config.overrides.value {
+            writeln!(f, "overrides = {} # {}", overrides, config.overrides.source)?;
+        }
+        Ok(())
+    }
+}
+
+/// The workspace the SBOMs are created for, with its resolved dependency graph
+struct WorkspaceContext<'a> {
+    ws: &'a Workspace<'a>,
+    members: &'a [Package],
+    resolve: &'a Resolve,
+}
+
+/// The packages listed in an SBOM, the number of packages left out for each reason and, for the
+/// feature matrix, the features the SBOM is created for
+struct Selection {
+    dependencies: BTreeSet<Package>,
+    omissions: Omissions,
+    activated: Option<ActivatedFeatures>,
+}
+
+/// Creates the SBOM of the workspace member `member`, or only plans it for a `dry_run`, which
+/// neither enriches the components nor reads any files of the member
+fn create_sbom(
+    context: &WorkspaceContext<'_>,
+    member: &Package,
+    config: SbomConfig,
+    selection: Selection,
+    dry_run: bool,
+) -> Result<GeneratedSbom, GeneratorError> {
+    let profile = config.profile();
+    let properties = profile_properties(context.ws, profile.name()).map_err(|error| {
+        GeneratorError::ProfileError {
+            profile: profile.to_string(),
+            error,
+        }
+    })?;
+
+    let Selection {
+        dependencies,
+        omissions,
+        activated,
+    } = selection;
+    let published_refs = published_refs(&dependencies);
+    let graph = dependency_graph(member, &dependencies, context.resolve);
+    let binaries = binary_components(member, &dependencies, context.resolve);
+    let conflicts = match config.duplicates() {
+        Duplicates::Annotated => {
+            duplicates::find_conflicts(context.members, context.resolve, &dependencies)
+        }
+        Duplicates::NotAnnotated => Vec::new(),
+    };
+    let registry_cache = context.ws.config().registry_cache_path();
+    let mut pipeline = if dry_run {
+        Pipeline::empty()
+    } else {
+        Pipeline::new(&config, registry_cache.as_path_unlocked())
+    };
+    let mut bom = create_bom(member, dependencies, &config, &mut pipeline, properties)?;
+    for (enricher, metrics) in pipeline.metrics() {
+        log::debug!("The {} enricher {}", enricher, metrics);
+    }
+    pipeline.report_metrics();
+    if let Some(overrides) = config.overrides.as_ref().filter(|_| !dry_run) {
+        let path = member.root().join(overrides.path());
+        let overridden = Overrides::read(&path)?.apply(&mut bom);
+        log::debug!(
+            "The overrides of {} changed {} component(s)",
+            path.display(),
+            overridden
+        );
+    }
+    for dependency in graph {
+        bom.add_dependency(dependency);
+    }
+    let services = declared_services(member, &config.services());
+    if !services.is_empty() {
+        bom.services = Some(Services(services));
+    }
+    omissions.record(&mut bom);
+    if let Some(activated) = &activated {
+        activated.record(&mut bom);
+    }
+    duplicates::record(&conflicts, &mut bom, &BuildInfo::current());
+
+    let scanner = FileScanner::new(member.root(), &config.scan_exclude());
+    let mut files = Vec::new();
+    if config.source_files() == SourceFiles::Included && !dry_run {
+        // an SBOM written by a previous run would describe an outdated version of itself
+        let own_file = sbom_filename(
+            &config,
+            member.name().as_str(),
+            activated
+                .as_ref()
+                .map(|activated| activated.feature_set.as_str()),
+        );
+        let listed = source_files(
+            member,
+            context.ws.config(),
+            &scanner,
+            config.on_error().hashing,
+        )
+        .map_err(|error| GeneratorError::SourceFilesError {
+            package: member.name().to_string(),
+            error,
+        })?;
+        for file in listed {
+            if file.path != own_file {
+                files.push(create_file_component(member, &file.path, &file.sha256));
+            }
+        }
+    }
+
+    let embedded = if dry_run {
+        EmbeddedAssets::Disabled
+    } else {
+        config.embedded_assets()
+    };
+    if let EmbeddedAssets::Enabled { min_size } = embedded {
+        let assets = embedded_assets(member, min_size, &scanner, config.on_error().hashing)
+            .map_err(|error| GeneratorError::EmbeddedAssetsError {
+                package: member.name().to_string(),
+                error,
+            })?;
+        for asset in assets {
+            // Files of the package are already listed with the package itself
+            match files
+                .iter_mut()
+                .find(|file| file.name.to_string() == asset.path)
+            {
+                Some(file) => {
+                    file.set_cargo_property(CargoProperty::EmbeddedBy, &asset.included_from)
+                }
+                None => {
+                    let mut component = create_file_component(member, &asset.path, &asset.sha256);
+                    component.scope = Some(Scope::Required);
+                    component.set_cargo_property(CargoProperty::EmbeddedBy, &asset.included_from);
+                    bom.add_component(component)?;
+                }
+            }
+        }
+    }
+
+    let mut nested = Vec::new();
+    for (component, dependency) in binaries {
+        nested.push(component);
+        if let Some(dependency) = dependency {
+            bom.add_dependency(dependency);
+        }
+    }
+    nested.extend(files);
+    if !nested.is_empty() {
+        if let Some(component) = bom.metadata.as_mut().and_then(|m| m.component.as_mut()) {
+            component.components = Some(Components(nested));
+        }
+    }
+
+    let external_boms = if dry_run {
+        ExternalBoms::default()
+    } else {
+        config.external_boms()
+    };
+    for path in external_boms.paths() {
+        let path = member.root().join(path);
+        read_external_bom(&path)
+            .and_then(|external| Ok(bom.add_assembly(external)?))
+            .map_err(|error| GeneratorError::ExternalBomError {
+                path: path.clone(),
+                error,
+            })?;
+        log::debug!("Merged {} into the SBOM", path.display());
+    }
+
+    log::debug!("Bom validation: {:?}", &bom.validate());
+
+    let metrics = metrics();
+    metrics.count(Counter::Sboms, 1);
+    let components = bom.components.as_ref().map_or(0, |c| c.0.len());
+    metrics.count(Counter::Components, components as u64);
+
+    Ok(GeneratedSbom {
+        bom,
+        manifest_path: member.manifest_path().to_path_buf(),
+        package_name: member.name().to_string(),
+        sbom_config: config,
+        output_dir: None,
+        published_refs: Some(published_refs),
+        feature_set: activated.map(|activated| activated.feature_set),
+        document: Document::Sbom,
+    })
+}
+
+/// Creates the BOM of `package` with its `dependencies` as components, which are enriched by
+/// `pipeline`
+///
+/// The Purls of dependencies that are not taken from crates.io tell where they are taken from.
+fn create_bom(
+    package: &Package,
+    dependencies: BTreeSet<Package>,
+    config: &SbomConfig,
+    pipeline: &mut Pipeline<'_>,
+    properties: Vec<Property>,
+) -> Result<Bom, GeneratorError> {
     let mut bom = Bom::default();
 
-    let components: Vec<_> = dependencies
+    let components = dependencies
         .into_iter()
-        .map(|package| create_component(&package))
-        .collect();
+        .map(|package| {
+            let mut component = create_component(&package);
+            match sources::source_purl(&package) {
+                Ok(purl) => component.purl = Some(purl),
+                Err(e) => log::error!("Package {} has an invalid Purl: {} ", package.name(), e),
+            }
+            if config.git_purl() == GitPurl::Repository {
+                forks::record_repository(&package, &mut component);
+            }
+            pipeline.enrich(&package, &mut component)?;
+            Ok(component)
+        })
+        .collect::<Result<Vec<_>, GeneratorError>>()?;
 
     bom.components = Some(Components(components));
 
-    let metadata = create_metadata(package)?;
+    let mut metadata = create_metadata(package, config.supplier.as_ref(), pipeline)?;
+    metadata.licenses = config.document_license.as_ref().map(document_license);
+
+    if !properties.is_empty() {
+        metadata.properties = Some(Properties(properties));
+    }
+    BuildInfo::current().record(&mut metadata);
 
     bom.metadata = Some(metadata);
+    bom.external_references = document_references(&config.external_references());
 
     Ok(bom)
 }
 
+/// The external references of the BOM itself, e.g. to the CI run that generates it
+fn document_references(references: &DocumentReferences) -> Option<ExternalReferences> {
+    let references: Vec<_> = references
+        .references()
+        .iter()
+        .map(|reference| {
+            let mut external_reference = ExternalReference::new(
+                reference
+                    .reference_type
+                    .parse()
+                    .expect("Types are validated on construction"),
+                Uri::try_from(reference.url.clone()).expect("URLs are validated on construction"),
+            );
+            external_reference.comment = reference.comment.clone();
+            external_reference
+        })
+        .collect();
+
+    if references.is_empty() {
+        None
+    } else {
+        Some(ExternalReferences(references))
+    }
+}
+
+/// The license of the BOM itself, e.g. `CC0-1.0`
+fn document_license(license: &DocumentLicense) -> Licenses {
+    let license = match license {
+        DocumentLicense::Expression(expression) => LicenseChoice::Expression(
+            SpdxExpression::try_from(expression.clone())
+                .expect("Expressions are validated on construction"),
+        ),
+        DocumentLicense::Named { name, url } => {
+            let mut license = License::named_license(name);
+            license.url = url
+                .clone()
+                .map(|url| Uri::try_from(url).expect("URLs are validated on construction"));
+            LicenseChoice::License(license)
+        }
+    };
+    Licenses(vec![license])
+}
+
+/// The `bom-ref`s of the components of the packages published on crates.io
+fn published_refs(packages: &BTreeSet<Package>) -> BTreeSet<String> {
+    packages
+        .iter()
+        .filter(|package| package.package_id().source_id().is_crates_io())
+        .filter_map(bom_ref)
+        .collect()
+}
+
+/// The `bom-ref` of the component of `package`, which is its Purl
+fn bom_ref(package: &Package) -> Option<String> {
+    let name = package.name().trim().to_string();
+    Purl::new("cargo", &name, &package.version().to_string())
+        .ok()
+        .map(|purl| purl.to_string())
+}
+
+/// The dependencies of `member` and of the packages in `dependencies` on each other, taken from
+/// the resolved dependency graph
+///
+/// A package gets no entry if all of its dependencies are left out of the SBOM, as an empty
+/// entry states that the package has no dependencies at all.
+fn dependency_graph(
+    member: &Package,
+    dependencies: &BTreeSet<Package>,
+    resolve: &Resolve,
+) -> Vec<BomDependency> {
+    let packages: Vec<&Package> = std::iter::once(member).chain(dependencies).collect();
+    let bom_refs: HashMap<PackageId, String> = packages
+        .iter()
+        .filter_map(|package| Some((package.package_id(), bom_ref(package)?)))
+        .collect();
+
+    packages
+        .iter()
+        .filter_map(|package| {
+            let bom_ref = bom_refs.get(&package.package_id())?;
+            let resolved: Vec<PackageId> = resolve
+                .deps(package.package_id())
+                .map(|(package_id, _)| package_id)
+                .collect();
+            let depends_on: BTreeSet<String> = resolved
+                .iter()
+                .filter_map(|package_id| bom_refs.get(package_id).cloned())
+                .collect();
+            if depends_on.is_empty() && !resolved.is_empty() {
+                return None;
+            }
+            Some(BomDependency::new(
+                bom_ref.clone(),
+                depends_on.into_iter().collect(),
+            ))
+        })
+        .collect()
+}
+
+/// The services declared for `member`, with `bom-ref`s derived from the Purl of its component
+fn declared_services(member: &Package, declared: &DeclaredServices) -> Vec<Service> {
+    let member_ref = bom_ref(member).unwrap_or_else(|| member.name().to_string());
+    declared
+        .services()
+        .iter()
+        .map(|declared| {
+            let mut service = Service::new(
+                &declared.name,
+                Some(format!("{}#service:{}", member_ref, declared.name)),
+            );
+            service.description = declared.description.as_deref().map(NormalizedString::new);
+            if !declared.endpoints.is_empty() {
+                service.endpoints = Some(
+                    declared
+                        .endpoints
+                        .iter()
+                        .filter_map(|endpoint| Uri::try_from(endpoint.clone()).ok())
+                        .collect(),
+                );
+            }
+            service.authenticated = declared.authenticated;
+            service.x_trust_boundary = declared.x_trust_boundary;
+            if !declared.data.is_empty() {
+                service.data = Some(
+                    declared
+                        .data
+                        .iter()
+                        .map(|data| DataClassification {
+                            flow: match data.flow {
+                                Flow::Inbound => DataFlowType::Inbound,
+                                Flow::Outbound => DataFlowType::Outbound,
+                                Flow::BiDirectional => DataFlowType::BiDirectional,
+                                Flow::Unknown => DataFlowType::Unknown,
+                            },
+                            classification: NormalizedString::new(&data.classification),
+                        })
+                        .collect(),
+                );
+            }
+            service
+        })
+        .collect()
+}
+
 fn create_component(package: &Package) -> Component {
     let name = package.name().to_owned().trim().to_string();
     let version = package.version().to_string();
@@ -164,6 +1003,27 @@ fn create_component(package: &Package) -> Component {
     component
 }
 
+/// Describes the file at `path`, relative to the root of `package`, as a file component of the
+/// package version
+///
+/// The BOM reference is the purl of the package with the path of the file as subpath.
+fn create_file_component(package: &Package, path: &str, sha256: &str) -> Component {
+    let version = package.version().to_string();
+    let bom_ref = format!("pkg:cargo/{}@{}#{}", package.name(), version, path);
+
+    let mut component = Component::new(Classification::File, path, &version, Some(bom_ref));
+    component.add_hash(Hash::new(HashAlgorithm::SHA256, sha256));
+    component
+}
+
+/// Reads a BOM generated by another tool, as XML if the file name ends with `.xml` and as JSON
+/// otherwise, in the version of the specification the document declares
+fn read_external_bom(path: &Path) -> anyhow::Result<Bom> {
+    let input = std::fs::read(path)?;
+    let (bom, _) = Bom::parse(&input)?;
+    Ok(bom)
+}
+
 fn get_classification(pkg: &Package) -> Classification {
     if pkg.targets().iter().any(|tgt| tgt.is_bin()) {
         return Classification::Application;
@@ -274,7 +1134,11 @@ fn get_licenses(package: &Package) -> Option<Licenses> {
     Some(Licenses(licenses))
 }
 
-fn create_metadata(package: &Package) -> Result<Metadata, GeneratorError> {
+fn create_metadata(
+    package: &Package,
+    supplier: Option<&Supplier>,
+    pipeline: &mut Pipeline<'_>,
+) -> Result<Metadata, GeneratorError> {
     let authors = create_authors(package);
 
     let mut metadata = Metadata::new()?;
@@ -283,18 +1147,31 @@ fn create_metadata(package: &Package) -> Result<Metadata, GeneratorError> {
     }
 
     let mut component = create_component(package);
+    pipeline.enrich(package, &mut component)?;
 
     component.component_type = get_classification(package);
 
     metadata.component = Some(component);
 
-    let tool = Tool::new("CycloneDX", "cargo-cyclonedx", env!("CARGO_PKG_VERSION"));
-
-    metadata.tools = Some(Tools(vec![tool]));
+    if let Some(supplier) = supplier {
+        metadata.supplier = Some(create_supplier(supplier)?);
+    }
 
     Ok(metadata)
 }
 
+fn create_supplier(supplier: &Supplier) -> Result<OrganizationalEntity, GeneratorError> {
+    let mut entity = OrganizationalEntity::new(&supplier.name);
+
+    if let Some(url) = &supplier.url {
+        let url = Uri::try_from(url.clone())
+            .map_err(|e| GeneratorError::InvalidSupplierUrl(e.to_string()))?;
+        entity = entity.with_url(vec![url]);
+    }
+
+    Ok(entity)
+}
+
 fn create_authors(package: &Package) -> Vec<OrganizationalContact> {
     let mut authors = vec![];
     let mut invalid_authors = vec![];
@@ -370,6 +1247,54 @@ pub enum GeneratorError {
 
     #[error("Invalid regular expression")]
     InvalidRegexError(#[source] regex::Error),
+
+    #[error("Could not read the settings of the `{profile}` profile")]
+    ProfileError {
+        profile: String,
+        #[source]
+        error: anyhow::Error,
+    },
+
+    #[error("Invalid supplier URL: {}", .0)]
+    InvalidSupplierUrl(String),
+
+    #[error("Could not list the files of the package {package}")]
+    SourceFilesError {
+        package: String,
+        #[source]
+        error: anyhow::Error,
+    },
+
+    #[error("Could not enrich a component")]
+    EnrichmentError(#[from] EnrichmentError),
+
+    #[error("Could not read the assets embedded by the package {package}")]
+    EmbeddedAssetsError {
+        package: String,
+        #[source]
+        error: anyhow::Error,
+    },
+
+    #[error("Could not merge the external BOM {}", .path.display())]
+    ExternalBomError {
+        path: PathBuf,
+        #[source]
+        error: anyhow::Error,
+    },
+
+    #[error("Could not add a component to the SBOM")]
+    BomEditError(#[from] BomEditError),
+
+    #[error("Could not read the component overrides")]
+    OverridesError(#[from] OverridesError),
+
+    #[error("Could not resolve the feature set `{feature_set}` of the package {package}")]
+    FeatureSetError {
+        package: String,
+        feature_set: String,
+        #[source]
+        error: anyhow::Error,
+    },
 }
 
 fn top_level_dependencies(
@@ -398,10 +1323,10 @@ fn top_level_dependencies(
             .find(|id| dependency.matches_id(*id))
         {
             Some(package_id) => {
-                let package = package_ids
-                    .get_one(package_id)
-                    .map_err(|error| GeneratorError::PackageError { package_id, error })?;
-                dependencies.insert(package.to_owned());
+                // failures were already handled when listing all dependencies
+                if let Some(package) = lookup_package(package_ids, package_id, OnError::Skip)? {
+                    dependencies.insert(package.to_owned());
+                }
             }
             None => {
                 log::warn!(
@@ -422,18 +1347,176 @@ fn top_level_dependencies(
     Ok(dependencies)
 }
 
+/// The direct dependencies a consumer of the library `package` inherits: its normal dependencies
+/// that are not optional or are enabled by its default features, including other workspace
+/// members
+fn library_dependencies(
+    package: &Package,
+    package_ids: &PackageSet<'_>,
+    resolve: &Resolve,
+) -> Result<BTreeSet<Package>, GeneratorError> {
+    log::trace!("Adding the dependencies of the library to SBOM");
+    let enabled = enabled_dependencies(package, &[InternedString::new("default")]);
+    let mut dependencies = BTreeSet::new();
+
+    for (package_id, package_dependencies) in resolve.deps(package.package_id()) {
+        let inherited = package_dependencies.iter().any(|dependency| {
+            dependency.kind() == DepKind::Normal
+                && (!dependency.is_optional() || enabled.contains(&dependency.name_in_toml()))
+        });
+        if inherited {
+            // failures were already handled when listing all dependencies
+            if let Some(dependency) = lookup_package(package_ids, package_id, OnError::Skip)? {
+                dependencies.insert(dependency.to_owned());
+            }
+        }
+    }
+
+    Ok(dependencies)
+}
+
+/// The names of the optional dependencies of `package` enabled by `features`, which are given
+/// as in `required-features`, e.g. `tls` or `serde/derive`
+fn enabled_dependencies(package: &Package, features: &[InternedString]) -> HashSet<InternedString> {
+    let definitions = package.summary().features();
+    let mut enabled = HashSet::new();
+    let mut visited = HashSet::new();
+    let mut pending: Vec<FeatureValue> = features
+        .iter()
+        .map(|feature| FeatureValue::new(*feature))
+        .collect();
+
+    while let Some(value) = pending.pop() {
+        match value {
+            FeatureValue::Feature(feature) => {
+                if visited.insert(feature) {
+                    pending.extend(definitions.get(&feature).into_iter().flatten().cloned());
+                }
+            }
+            FeatureValue::Dep { dep_name } => {
+                enabled.insert(dep_name);
+            }
+            // `dep?/feature` only enables the feature if the dependency is enabled otherwise
+            FeatureValue::DepFeature { dep_name, weak, .. } => {
+                if !weak {
+                    enabled.insert(dep_name);
+                }
+            }
+        }
+    }
+
+    enabled
+}
+
+/// The binaries of `package`, if it has more than one, as application components to nest in its
+/// metadata component, each with its direct dependencies among `dependencies`
+///
+/// The direct dependencies of a binary are the normal dependencies of the package that are not
+/// optional, or are enabled by the default features or the `required-features` of the binary.
+/// A binary gets no dependency entry if all of its dependencies are left out of the SBOM, as for
+/// packages.
+fn binary_components(
+    package: &Package,
+    dependencies: &BTreeSet<Package>,
+    resolve: &Resolve,
+) -> Vec<(Component, Option<BomDependency>)> {
+    let binaries: Vec<_> = package
+        .targets()
+        .iter()
+        .filter(|target| target.is_bin())
+        .collect();
+    if binaries.len() < 2 {
+        return Vec::new();
+    }
+
+    let bom_refs: HashMap<PackageId, String> = dependencies
+        .iter()
+        .filter_map(|package| Some((package.package_id(), bom_ref(package)?)))
+        .collect();
+    let version = package.version().to_string();
+
+    binaries
+        .into_iter()
+        .map(|target| {
+            let required_features: Vec<InternedString> = target
+                .required_features()
+                .into_iter()
+                .flatten()
+                .map(|feature| InternedString::new(feature))
+                .collect();
+            let mut features = vec![InternedString::new("default")];
+            features.extend(required_features.iter().copied());
+            let enabled = enabled_dependencies(package, &features);
+
+            let resolved: Vec<PackageId> = resolve
+                .deps(package.package_id())
+                .filter(|(_, package_dependencies)| {
+                    package_dependencies.iter().any(|dependency| {
+                        dependency.kind() == DepKind::Normal
+                            && (!dependency.is_optional()
+                                || enabled.contains(&dependency.name_in_toml()))
+                    })
+                })
+                .map(|(package_id, _)| package_id)
+                .collect();
+            let depends_on: BTreeSet<String> = resolved
+                .iter()
+                .filter_map(|package_id| bom_refs.get(package_id).cloned())
+                .collect();
+
+            let bom_ref = format!(
+                "pkg:cargo/{}@{}?bin={}",
+                package.name(),
+                version,
+                target.name()
+            );
+            let mut component = Component::new(
+                Classification::Application,
+                target.name(),
+                &version,
+                Some(bom_ref.clone()),
+            );
+            if !required_features.is_empty() {
+                let required_features: Vec<&str> = required_features
+                    .iter()
+                    .map(|feature| feature.as_str())
+                    .collect();
+                component.set_cargo_required_features(&required_features);
+            }
+
+            let dependency = if depends_on.is_empty() && !resolved.is_empty() {
+                None
+            } else {
+                Some(BomDependency::new(
+                    bom_ref,
+                    depends_on.into_iter().collect(),
+                ))
+            };
+            (component, dependency)
+        })
+        .collect()
+}
+
+/// All packages of the dependency graph except the workspace members, and the number of
+/// packages that could not be retrieved and were left out as `on_error` allows
 fn all_dependencies(
     members: &[Package],
     package_ids: &PackageSet<'_>,
     resolve: &Resolve,
-) -> Result<BTreeSet<Package>, GeneratorError> {
+    on_error: OnError,
+) -> Result<(BTreeSet<Package>, usize), GeneratorError> {
     log::trace!("Adding all dependencies to SBOM");
     let mut dependencies = BTreeSet::new();
+    let mut unavailable = 0;
 
     for package_id in resolve.iter() {
-        let package = package_ids
-            .get_one(package_id)
-            .map_err(|error| GeneratorError::PackageError { package_id, error })?;
+        let package = match lookup_package(package_ids, package_id, on_error)? {
+            Some(package) => package,
+            None => {
+                unavailable += 1;
+                continue;
+            }
+        };
         if members.contains(package) {
             // Skip listing our own packages in our workspace
             continue;
@@ -441,7 +1524,70 @@ fn all_dependencies(
         dependencies.insert(package.to_owned());
     }
 
-    Ok(dependencies)
+    Ok((dependencies, unavailable))
+}
+
+/// Retrieves the package `package_id`, downloading it from its registry if needed, or reports
+/// the failure as `on_error` says
+fn lookup_package<'a>(
+    package_ids: &'a PackageSet<'_>,
+    package_id: PackageId,
+    on_error: OnError,
+) -> Result<Option<&'a Package>, GeneratorError> {
+    match package_ids.get_one(package_id) {
+        Ok(package) => Ok(Some(package)),
+        Err(error) => match on_error {
+            OnError::Fail => Err(GeneratorError::PackageError { package_id, error }),
+            OnError::Warn => {
+                log::warn!("Leaving out the package {}: {:#}", package_id, error);
+                Ok(None)
+            }
+            OnError::Skip => {
+                log::debug!("Leaving out the package {}: {:#}", package_id, error);
+                Ok(None)
+            }
+        },
+    }
+}
+
+/// Counts the packages of `all` left out of `selected` by the reason they were left out, where
+/// the dev- and build-dependencies are those of the workspace members
+fn omitted_dependencies(
+    members: &[Package],
+    resolve: &Resolve,
+    all: &BTreeSet<Package>,
+    selected: &BTreeSet<Package>,
+) -> Omissions {
+    let mut reasons = HashMap::new();
+    for member in members {
+        for (package_id, dependencies) in resolve.deps(member.package_id()) {
+            let kinds: Vec<_> = dependencies.iter().map(Dependency::kind).collect();
+            let reason = if dependencies
+                .iter()
+                .any(|d| d.kind() == DepKind::Normal && !d.is_optional())
+            {
+                continue;
+            } else if kinds.contains(&DepKind::Normal) {
+                OmissionReason::OptionalDependency
+            } else if kinds.contains(&DepKind::Development) {
+                OmissionReason::DevDependency
+            } else {
+                OmissionReason::BuildDependency
+            };
+            // a dependency of one member may be a dev-dependency of another
+            reasons.entry(package_id).or_insert(reason);
+        }
+    }
+
+    let mut omissions = Omissions::default();
+    for package in all.difference(selected) {
+        let reason = reasons
+            .get(&package.package_id())
+            .copied()
+            .unwrap_or(OmissionReason::Transitive);
+        omissions.add(reason, 1);
+    }
+    omissions
 }
 
 /// Contains a generated SBOM and context used in its generation
@@ -450,48 +1596,190 @@ fn all_dependencies(
 /// * `manifest_path` - Folder containing the `Cargo.toml` manifest
 /// * `package_name` - Package from which this SBOM was generated
 /// * `sbom_config` - Configuration options used during generation
+/// * `output_dir` - Folder to write the SBOM to instead of the folder containing the manifest
+/// * `published_refs` - The `bom-ref`s of the components published on crates.io, where known
+/// * `feature_set` - The feature set the SBOM was created for by the feature matrix
+/// * `document` - Kind of the document
 pub struct GeneratedSbom {
     pub bom: Bom,
     pub manifest_path: PathBuf,
     pub package_name: String,
     pub sbom_config: SbomConfig,
+    pub output_dir: Option<PathBuf>,
+    pub published_refs: Option<BTreeSet<String>>,
+    pub feature_set: Option<String>,
+    pub document: Document,
+}
+
+/// Kind of a document written for a package
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Document {
+    Sbom,
+    /// SaaSBOM of the services of the package
+    Saasbom,
+    /// VEX document of the vulnerabilities of the components of the SBOM
+    Vex,
+}
+
+impl Document {
+    /// Infix of the file name after the prefix, which tells the document apart from the SBOM
+    fn infix(self) -> Option<&'static str> {
+        match self {
+            Document::Sbom => None,
+            Document::Saasbom => Some("saasbom"),
+            Document::Vex => Some("vex"),
+        }
+    }
 }
 
 impl GeneratedSbom {
-    /// Writes SBOM to either a JSON or XML file in the same folder as `Cargo.toml` manifest
-    pub fn write_to_file(self) -> Result<(), SbomWriterError> {
-        let path = self.manifest_path.with_file_name(self.filename());
+    /// Writes SBOM to either a JSON or XML file in the output folder
+    ///
+    /// The file is replaced atomically, so an interrupted run leaves the previous SBOM in place.
+    /// With `backup`, the previous SBOM is kept next to it with a `.bak` suffix. The SBOM is
+    /// written as it is serialized, through a buffer, so that large SBOMs are neither held in
+    /// memory a second time nor written in many small writes.
+    ///
+    /// With a `signing_key`, the document is serialized into memory first and then signed: a JSON
+    /// document with an embedded JSF `signature`, an XML document with an enveloped XML-DSig
+    /// `Signature` as the last child of its `bom` element.
+    pub fn write_to_file(
+        self,
+        backup: bool,
+        signing_key: Option<&SigningKey>,
+    ) -> Result<(), SbomWriterError> {
+        let path = self.output_path();
         log::info!("Outputting {}", path.display());
-        let mut file = File::create(path).map_err(SbomWriterError::FileCreateError)?;
-        match self.sbom_config.format() {
-            Format::Json => {
-                self.bom
-                    .output_as_json_v1_3(&mut file)
+        let mut file = AtomicFile::create(&path).map_err(SbomWriterError::FileCreateError)?;
+        let mut writer = BufWriter::new(file.file());
+        let started = Instant::now();
+        let warnings = match (self.sbom_config.format(), signing_key) {
+            (Format::Json, Some(key)) => {
+                let mut document = Vec::new();
+                let warnings = self
+                    .bom
+                    .output_as_json_with_warnings(&mut document, self.sbom_config.spec_version())
                     .map_err(SbomWriterError::JsonWriteError)?;
+                let signed = sign_json(&document, key).map_err(SbomWriterError::SignatureError)?;
+                writer
+                    .write_all(&signed)
+                    .map_err(SbomWriterError::FileWriteError)?;
+                warnings
             }
-            Format::Xml => {
-                self.bom
-                    .output_as_xml_v1_3(&mut file)
+            (Format::Json, None) => self
+                .bom
+                .output_as_json_with_warnings(&mut writer, self.sbom_config.spec_version())
+                .map_err(SbomWriterError::JsonWriteError)?,
+            (Format::Xml, Some(key)) => {
+                let mut document = Vec::new();
+                let warnings = self
+                    .bom
+                    .output_as_xml_with_warnings(&mut document, self.sbom_config.spec_version())
                     .map_err(SbomWriterError::XmlWriteError)?;
+                let signed = sign_xml(&document, key).map_err(SbomWriterError::SignatureError)?;
+                writer
+                    .write_all(&signed)
+                    .map_err(SbomWriterError::FileWriteError)?;
+                warnings
             }
+            (Format::Xml, None) => self
+                .bom
+                .output_as_xml_with_warnings(&mut writer, self.sbom_config.spec_version())
+                .map_err(SbomWriterError::XmlWriteError)?,
+        };
+        writer
+            .into_inner()
+            .map_err(|error| SbomWriterError::FileWriteError(error.into_error()))?;
+        metrics().phase(Phase::Serialize, started.elapsed());
+        for warning in warnings {
+            log::warn!("{}: {}", path.display(), warning);
         }
 
+        file.commit(backup)
+            .map_err(SbomWriterError::FileCommitError)?;
+
         Ok(())
     }
 
+    /// Location of the file the SBOM is written to
+    pub fn output_path(&self) -> PathBuf {
+        match &self.output_dir {
+            Some(dir) => dir.join(self.filename()),
+            None => self.manifest_path.with_file_name(self.filename()),
+        }
+    }
+
+    /// Describes the file that is written, without serializing the SBOM
+    pub fn output_summary(&self) -> OutputSummary {
+        let stats = BomStats::new(&self.bom);
+        OutputSummary {
+            path: self.output_path(),
+            format: self.sbom_config.format(),
+            spec_version: self.sbom_config.spec_version().as_str(),
+            component_count: self
+                .bom
+                .components
+                .as_ref()
+                .map_or(0, |components| components.0.len()),
+            license_coverage: stats.license_coverage,
+            hash_coverage: stats.hash_coverage,
+        }
+    }
+
     fn filename(&self) -> String {
-        let output_options = self.sbom_config.output_options();
-        let prefix = match output_options.prefix {
-            Prefix::Pattern(Pattern::Bom) => "bom".to_string(),
-            Prefix::Pattern(Pattern::Package) => self.package_name.clone(),
-            Prefix::Custom(c) => c.to_string(),
-        };
+        let infix = self.document.infix().or(self.feature_set.as_deref());
+        sbom_filename(&self.sbom_config, &self.package_name, infix)
+    }
+}
+
+/// Name of the file the SBOM of `package_name` is written to with `config`, with the `infix`
+/// after the prefix, i.e. the name of the feature set for the feature matrix or the infix of the
+/// [`Document`]
+fn sbom_filename(config: &SbomConfig, package_name: &str, infix: Option<&str>) -> String {
+    let output_options = config.output_options();
+    let prefix = match output_options.prefix {
+        Prefix::Pattern(Pattern::Bom) => "bom".to_string(),
+        Prefix::Pattern(Pattern::Package) => sanitize_prefix(package_name),
+        Prefix::Custom(c) => c.to_string(),
+    };
+    let infix = infix.map(|infix| format!(".{}", infix));
+
+    format!(
+        "{}{}{}.{}",
+        prefix,
+        infix.unwrap_or_default(),
+        output_options.cdx_extension.extension(),
+        config.format()
+    )
+}
 
-        format!(
-            "{}{}.{}",
-            prefix,
-            output_options.cdx_extension.extension(),
-            self.sbom_config.format()
+/// Summary of an SBOM file that is written
+///
+/// * `path` - Location of the file
+/// * `format` - Output format of the file
+/// * `spec_version` - CycloneDX specification version the file conforms to
+/// * `component_count` - Number of top-level components in the SBOM
+/// * `license_coverage` - Percentage of the components, nested ones included, with a license
+/// * `hash_coverage` - Percentage of the components, nested ones included, with a hash
+#[derive(Debug, PartialEq, Serialize)]
+pub struct OutputSummary {
+    pub path: PathBuf,
+    pub format: Format,
+    pub spec_version: &'static str,
+    pub component_count: usize,
+    pub license_coverage: f64,
+    pub hash_coverage: f64,
+}
+
+impl fmt::Display for OutputSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} ({}, CycloneDX {}, {} components)",
+            self.path.display(),
+            self.format,
+            self.spec_version,
+            self.component_count
         )
     }
 }
@@ -501,14 +1789,20 @@ pub enum SbomWriterError {
     #[error("Error creating file")]
     FileCreateError(#[source] std::io::Error),
 
+    #[error("Error writing file")]
+    FileWriteError(#[source] std::io::Error),
+
+    #[error("Error moving the written file into place")]
+    FileCommitError(#[source] std::io::Error),
+
     #[error("Error writing JSON file")]
     JsonWriteError(#[source] cyclonedx_bom::errors::JsonWriteError),
 
     #[error("Error writing XML file")]
     XmlWriteError(#[source] cyclonedx_bom::errors::XmlWriteError),
 
-    #[error("Error serializing to XML")]
-    SerializeXmlError(#[source] std::io::Error),
+    #[error("Error signing the SBOM")]
+    SignatureError(#[source] cyclonedx_bom::errors::SigningError),
 }
 
 #[cfg(test)]
@@ -551,4 +1845,41 @@ mod test {
 
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn it_should_generate_the_boms_of_a_manifest() {
+        use assert_fs::prelude::*;
+
+        let tmp_dir = assert_fs::TempDir::new().expect("Failed to create a temporary directory");
+        tmp_dir
+            .child("Cargo.toml")
+            .write_str(r#"package = { name = "pkg", version = "0.0.0" }"#)
+            .expect("Failed to write the manifest");
+        tmp_dir
+            .child("src/lib.rs")
+            .touch()
+            .expect("Failed to write the library");
+
+        let boms = generate(
+            tmp_dir.child("Cargo.toml").path(),
+            &SbomConfig::empty_config(),
+        )
+        .expect("Failed to generate the BOMs");
+        assert_eq!(boms.len(), 1);
+        let component = boms[0]
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.component.as_ref())
+            .expect("Missing the metadata component");
+        assert_eq!(component.name.to_string(), "pkg");
+
+        let result = generate(
+            tmp_dir.child("missing/Cargo.toml").path(),
+            &SbomConfig::empty_config(),
+        );
+        assert!(matches!(
+            result,
+            Err(GeneratorError::CargoConfigError { .. })
+        ));
+    }
 }
@@ -0,0 +1,553 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+use crate::config::{
+    CdxExtension, CustomPrefix, IncludedDependencies, OutputOptions, Pattern, Prefix, SbomConfig,
+    Supplier,
+};
+use crate::format::Format;
+use crate::toml::config_from_toml;
+
+use cargo::Config;
+use std::fmt;
+use std::io::{self, BufRead, Write};
+use std::str::FromStr;
+use thiserror::Error;
+use toml_edit::{value, Document, InlineTable, Item, Table};
+
+/// The manifest section that `cargo cyclonedx init` writes the configuration to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InitTarget {
+    Package,
+    Workspace,
+}
+
+impl fmt::Display for InitTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InitTarget::Package => "package".fmt(f),
+            InitTarget::Workspace => "workspace".fmt(f),
+        }
+    }
+}
+
+/// The configuration written by `cargo cyclonedx init --defaults`
+pub fn default_config() -> SbomConfig {
+    SbomConfig {
+        format: Some(Format::default()),
+        spec_version: None,
+        included_dependencies: Some(IncludedDependencies::default()),
+        output_options: Some(OutputOptions::default()),
+        supplier: None,
+        profile: None,
+        embedded_assets: None,
+        source_files: None,
+        scan_exclude: None,
+        license_preference: None,
+        external_boms: None,
+        registry_links: None,
+        duplicates: None,
+        feature_matrix: None,
+        feature_sets: None,
+        on_error: None,
+        services: None,
+        external_references: None,
+        enrichers: None,
+        document_license: None,
+        git_purl: None,
+        license_texts: None,
+        overrides: None,
+    }
+}
+
+/// The configuration `cargo cyclonedx template` recommends for new projects: a JSON SBOM named
+/// `bom.cdx.json` with all dependencies
+pub fn recommended_config() -> SbomConfig {
+    SbomConfig {
+        format: Some(Format::Json),
+        included_dependencies: Some(IncludedDependencies::AllDependencies),
+        output_options: Some(OutputOptions {
+            cdx_extension: CdxExtension::Included,
+            prefix: Prefix::Pattern(Pattern::Bom),
+        }),
+        ..default_config()
+    }
+}
+
+/// The `[<target>.metadata.cyclonedx]` block for a new project, e.g. to append to the manifest
+/// from a project template
+///
+/// `org_defaults` is a TOML document with the keys of the block, whose values replace the
+/// recommended ones, so that an organization can set its supplier or preferences for all its
+/// projects.
+pub fn baseline_block(target: InitTarget, org_defaults: Option<&str>) -> Result<String, InitError> {
+    let mut table = config_table(&recommended_config());
+
+    if let Some(org_defaults) = org_defaults {
+        let defaults: Document = org_defaults.parse()?;
+
+        // rejecting an invalid block here beats failing in every generated project
+        let value: toml::value::Value =
+            toml::from_str(&format!("[cyclonedx]\n{}", org_defaults))
+                .map_err(|error| InitError::InvalidOrgDefaults(error.to_string()))?;
+        config_from_toml(Some(&value))
+            .map_err(|error| InitError::InvalidOrgDefaults(error.to_string()))?;
+
+        for (key, item) in defaults.iter() {
+            table.insert(key, item.clone());
+        }
+    }
+
+    let mut metadata = Table::new();
+    metadata.set_implicit(true);
+    metadata.insert("cyclonedx", Item::Table(table));
+    let mut section = Table::new();
+    section.set_implicit(true);
+    section.insert("metadata", Item::Table(metadata));
+
+    let mut document = Document::new();
+    document.insert(&target.to_string(), Item::Table(section));
+    Ok(document.to_string())
+}
+
+/// Reads the organization defaults for [`baseline_block`] from an `http(s)://` URL, with the
+/// network configuration of cargo, or from a local path
+pub fn read_org_defaults(config: &Config, location: &str) -> Result<String, InitError> {
+    let error = |message: String| InitError::OrgDefaultsError {
+        location: location.to_string(),
+        message,
+    };
+
+    if !location.starts_with("http://") && !location.starts_with("https://") {
+        let path = location.strip_prefix("file://").unwrap_or(location);
+        return std::fs::read_to_string(path).map_err(|e| error(e.to_string()));
+    }
+
+    let mut handle = cargo::ops::http_handle(config).map_err(|e| error(e.to_string()))?;
+    handle.url(location).map_err(|e| error(e.to_string()))?;
+    handle
+        .follow_location(true)
+        .map_err(|e| error(e.to_string()))?;
+
+    let mut body = Vec::new();
+    {
+        let mut transfer = handle.transfer();
+        transfer
+            .write_function(|data| {
+                body.extend_from_slice(data);
+                Ok(data.len())
+            })
+            .map_err(|e| error(e.to_string()))?;
+        transfer.perform().map_err(|e| error(e.to_string()))?;
+    }
+
+    match handle.response_code().map_err(|e| error(e.to_string()))? {
+        200 => String::from_utf8(body).map_err(|e| error(e.to_string())),
+        code => Err(error(format!("the server responded with status {}", code))),
+    }
+}
+
+/// Asks for every configuration option on `output` and reads the answers from `input`
+///
+/// Empty answers select the default value. Invalid answers are reported and asked again.
+pub fn prompt_config(
+    input: &mut dyn BufRead,
+    output: &mut dyn Write,
+) -> Result<SbomConfig, InitError> {
+    let format = ask(
+        input,
+        output,
+        "Output format (xml, json)",
+        "xml",
+        Format::from_str,
+    )?;
+
+    let included_dependencies = ask(
+        input,
+        output,
+        "Included dependencies (top-level, all, library)",
+        "top-level",
+        IncludedDependencies::from_str,
+    )?;
+
+    let cdx_extension = ask(
+        input,
+        output,
+        "Prepend the file extension with .cdx (yes, no)",
+        "no",
+        |answer| match answer {
+            "yes" | "y" => Ok(CdxExtension::Included),
+            "no" | "n" => Ok(CdxExtension::NotIncluded),
+            _ => Err(format!("Expected yes or no, got `{}`", answer)),
+        },
+    )?;
+
+    let prefix = ask(
+        input,
+        output,
+        "File name pattern (bom, package) or a custom prefix",
+        "bom",
+        |answer| match Pattern::from_str(answer) {
+            Ok(pattern) => Ok(Prefix::Pattern(pattern)),
+            Err(_) => CustomPrefix::new(answer)
+                .map(Prefix::Custom)
+                .map_err(|e| e.to_string()),
+        },
+    )?;
+
+    let supplier_name = ask(
+        input,
+        output,
+        "Supplier name (empty to skip)",
+        "",
+        |answer| Ok(answer.to_string()),
+    )?;
+
+    let supplier = if supplier_name.is_empty() {
+        None
+    } else {
+        let url = ask(
+            input,
+            output,
+            "Supplier URL (empty to skip)",
+            "",
+            |answer| Ok(answer.to_string()),
+        )?;
+
+        Some(Supplier {
+            name: supplier_name,
+            url: Some(url).filter(|url| !url.is_empty()),
+        })
+    };
+
+    Ok(SbomConfig {
+        format: Some(format),
+        spec_version: None,
+        included_dependencies: Some(included_dependencies),
+        output_options: Some(OutputOptions {
+            cdx_extension,
+            prefix,
+        }),
+        supplier,
+        profile: None,
+        embedded_assets: None,
+        source_files: None,
+        scan_exclude: None,
+        license_preference: None,
+        external_boms: None,
+        registry_links: None,
+        duplicates: None,
+        feature_matrix: None,
+        feature_sets: None,
+        on_error: None,
+        services: None,
+        external_references: None,
+        enrichers: None,
+        document_license: None,
+        git_purl: None,
+        license_texts: None,
+        overrides: None,
+    })
+}
+
+fn ask<T>(
+    input: &mut dyn BufRead,
+    output: &mut dyn Write,
+    question: &str,
+    default: &str,
+    parse: impl Fn(&str) -> Result<T, String>,
+) -> Result<T, InitError> {
+    loop {
+        if default.is_empty() {
+            write!(output, "{}: ", question)?;
+        } else {
+            write!(output, "{} [{}]: ", question, default)?;
+        }
+        output.flush()?;
+
+        let mut answer = String::new();
+        let read = input.read_line(&mut answer)?;
+        let answer = match answer.trim() {
+            "" => default,
+            answer => answer,
+        };
+
+        match parse(answer) {
+            Ok(value) => return Ok(value),
+            // Without more input the same answer would be rejected forever
+            Err(message) if read == 0 => return Err(InitError::InvalidAnswer(message)),
+            Err(message) => writeln!(output, "{}", message)?,
+        }
+    }
+}
+
+/// Adds `config` as the `[<target>.metadata.cyclonedx]` table to the manifest contents,
+/// keeping the existing formatting and comments intact
+pub fn add_config_to_manifest(
+    manifest: &str,
+    target: InitTarget,
+    config: &SbomConfig,
+) -> Result<String, InitError> {
+    let mut document: Document = manifest.parse()?;
+
+    let section = child_table(document.as_table_mut(), &target.to_string())
+        .ok_or(InitError::MissingSection(target))?;
+
+    if !section.contains_key("metadata") {
+        let mut table = Table::new();
+        table.set_implicit(true);
+        section.insert("metadata", Item::Table(table));
+    }
+    let metadata = child_table(section, "metadata").ok_or(InitError::MissingSection(target))?;
+
+    if metadata.contains_key("cyclonedx") {
+        return Err(InitError::AlreadyConfigured(target));
+    }
+
+    metadata.insert("cyclonedx", Item::Table(config_table(config)));
+
+    Ok(document.to_string())
+}
+
+/// Inline tables cannot hold the `[...cyclonedx]` table, so they are turned into regular tables
+fn child_table<'a>(parent: &'a mut Table, key: &str) -> Option<&'a mut Table> {
+    if let Some(inline) = parent.get(key).and_then(Item::as_inline_table) {
+        let table = inline.clone().into_table();
+        parent.insert(key, Item::Table(table));
+        if let Some(decor) = parent.key_decor_mut(key) {
+            decor.clear();
+        }
+    }
+    parent.get_mut(key).and_then(Item::as_table_mut)
+}
+
+fn config_table(config: &SbomConfig) -> Table {
+    let mut table = Table::new();
+    table["format"] = value(config.format().to_string());
+    table["included_dependencies"] = value(config.included_dependencies().to_string());
+
+    let output_options = config.output_options();
+    let mut options = InlineTable::new();
+    options.insert(
+        "cdx",
+        (output_options.cdx_extension == CdxExtension::Included).into(),
+    );
+    match &output_options.prefix {
+        Prefix::Pattern(pattern) => options.insert("pattern", pattern.to_string().into()),
+        Prefix::Custom(prefix) => options.insert("prefix", prefix.to_string().into()),
+    };
+    table["output_options"] = value(options);
+
+    if let Some(supplier) = &config.supplier {
+        let mut entity = InlineTable::new();
+        entity.insert("name", supplier.name.as_str().into());
+        if let Some(url) = &supplier.url {
+            entity.insert("url", url.as_str().into());
+        }
+        table["supplier"] = value(entity);
+    }
+
+    table
+}
+
+#[derive(Error, Debug)]
+pub enum InitError {
+    #[error("Error reading the answer")]
+    IoError(#[from] io::Error),
+
+    #[error("Invalid answer: {0}")]
+    InvalidAnswer(String),
+
+    #[error("Failed to parse the manifest")]
+    ManifestParseError(#[from] toml_edit::TomlError),
+
+    #[error("The manifest has no [{0}] section")]
+    MissingSection(InitTarget),
+
+    #[error("The manifest already contains a [{0}.metadata.cyclonedx] section")]
+    AlreadyConfigured(InitTarget),
+
+    #[error("Failed to read the organization defaults from {location}: {message}")]
+    OrgDefaultsError { location: String, message: String },
+
+    #[error("Invalid organization defaults: {0}")]
+    InvalidOrgDefaults(String),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const MANIFEST: &str = r#"[package]
+name = "tacos"
+version = "0.1.0"
+
+# the best dependencies
+[dependencies]
+"#;
+
+    #[test]
+    fn it_should_use_defaults_for_empty_answers() {
+        let mut output = Vec::new();
+        let actual = prompt_config(&mut "\n\n\n\n\n".as_bytes(), &mut output)
+            .expect("Failed to prompt config");
+
+        assert_eq!(actual, default_config());
+    }
+
+    #[test]
+    fn it_should_ask_again_after_an_invalid_answer() {
+        let mut output = Vec::new();
+        let actual = prompt_config(
+            &mut "yaml\njson\nall\nyes\ntacos\nTacos Inc\nhttps://tacos.example.com\n".as_bytes(),
+            &mut output,
+        )
+        .expect("Failed to prompt config");
+
+        let expected = SbomConfig {
+            format: Some(Format::Json),
+            spec_version: None,
+            included_dependencies: Some(IncludedDependencies::AllDependencies),
+            output_options: Some(OutputOptions {
+                cdx_extension: CdxExtension::Included,
+                prefix: Prefix::Custom(CustomPrefix::new("tacos").unwrap()),
+            }),
+            supplier: Some(Supplier {
+                name: "Tacos Inc".to_string(),
+                url: Some("https://tacos.example.com".to_string()),
+            }),
+            profile: None,
+            embedded_assets: None,
+            source_files: None,
+            scan_exclude: None,
+            license_preference: None,
+            external_boms: None,
+            registry_links: None,
+            duplicates: None,
+            feature_matrix: None,
+            feature_sets: None,
+            on_error: None,
+            services: None,
+            external_references: None,
+            enrichers: None,
+            document_license: None,
+            git_purl: None,
+            license_texts: None,
+            overrides: None,
+        };
+
+        assert_eq!(actual, expected);
+        assert!(String::from_utf8_lossy(&output).contains("Expected xml or json, got `yaml`"));
+    }
+
+    #[test]
+    fn it_should_add_the_config_to_the_package_metadata() {
+        let config = SbomConfig {
+            supplier: Some(Supplier {
+                name: "Tacos Inc".to_string(),
+                url: None,
+            }),
+            ..default_config()
+        };
+
+        let actual = add_config_to_manifest(MANIFEST, InitTarget::Package, &config)
+            .expect("Failed to add config");
+
+        let expected = r#"[package]
+name = "tacos"
+version = "0.1.0"
+
+[package.metadata.cyclonedx]
+format = "xml"
+included_dependencies = "top-level"
+output_options = { cdx = false, pattern = "bom" }
+supplier = { name = "Tacos Inc" }
+
+# the best dependencies
+[dependencies]
+"#;
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn it_should_not_overwrite_an_existing_config() {
+        let manifest = add_config_to_manifest(MANIFEST, InitTarget::Package, &default_config())
+            .expect("Failed to add config");
+
+        let actual = add_config_to_manifest(&manifest, InitTarget::Package, &default_config())
+            .expect_err("Should not have added the config twice");
+
+        assert!(matches!(
+            actual,
+            InitError::AlreadyConfigured(InitTarget::Package)
+        ));
+    }
+
+    #[test]
+    fn it_should_print_the_recommended_block() {
+        let actual = baseline_block(InitTarget::Package, None).expect("Failed to create the block");
+
+        let expected = r#"[package.metadata.cyclonedx]
+format = "json"
+included_dependencies = "all"
+output_options = { cdx = true, pattern = "bom" }
+"#;
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn it_should_override_the_recommended_block_with_the_org_defaults() {
+        let org_defaults = r#"format = "xml"
+supplier = { name = "Tacos Inc", url = "https://tacos.example.com" }
+registry_links = false
+"#;
+
+        let actual = baseline_block(InitTarget::Workspace, Some(org_defaults))
+            .expect("Failed to create the block");
+
+        let expected = r#"[workspace.metadata.cyclonedx]
+format = "xml"
+included_dependencies = "all"
+output_options = { cdx = true, pattern = "bom" }
+supplier = { name = "Tacos Inc", url = "https://tacos.example.com" }
+registry_links = false
+"#;
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn it_should_reject_invalid_org_defaults() {
+        let actual = baseline_block(InitTarget::Package, Some(r#"format = "yaml""#))
+            .expect_err("Should not have accepted the defaults");
+
+        assert!(matches!(actual, InitError::InvalidOrgDefaults(_)));
+    }
+
+    #[test]
+    fn it_should_require_the_target_section() {
+        let actual = add_config_to_manifest(MANIFEST, InitTarget::Workspace, &default_config())
+            .expect_err("Should not have added the config");
+
+        assert!(matches!(
+            actual,
+            InitError::MissingSection(InitTarget::Workspace)
+        ));
+    }
+}
@@ -0,0 +1,310 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+use crate::build_info::BuildInfo;
+use cyclonedx_bom::external_models::purl::Purl;
+use cyclonedx_bom::external_models::uri::Uri;
+use cyclonedx_bom::models::bom::Bom;
+use cyclonedx_bom::models::component::{Classification, Component, Components};
+use cyclonedx_bom::models::external_reference::{
+    ExternalReference, ExternalReferenceType, ExternalReferences,
+};
+use cyclonedx_bom::models::metadata::{Metadata, MetadataError};
+use cyclonedx_bom::taxonomy::CargoProperty;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// Index URLs of crates.io, whose crates are downloaded from [`CRATES_IO_DOWNLOAD`]
+const CRATES_IO_INDEXES: &[&str] = &[
+    "registry+https://github.com/rust-lang/crates.io-index",
+    "sparse+https://index.crates.io/",
+];
+
+const CRATES_IO_DOWNLOAD: &str = "https://crates.io/api/v1/crates";
+
+/// A package installed with `cargo install`, as recorded in `$CARGO_HOME/.crates2.json`
+///
+/// * `source` - Registry, git repository or path the package was installed from
+/// * `bins` - Binaries installed from the package
+/// * `target` - Target triple the binaries were built for
+/// * `rustc` - Version of the compiler the binaries were built with
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstalledPackage {
+    pub name: String,
+    pub version: String,
+    pub source: String,
+    pub bins: Vec<String>,
+    pub features: Vec<String>,
+    pub profile: Option<String>,
+    pub target: Option<String>,
+    pub rustc: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CratesJson {
+    #[serde(default)]
+    installs: BTreeMap<String, InstallInfo>,
+}
+
+#[derive(Debug, Deserialize)]
+struct InstallInfo {
+    #[serde(default)]
+    bins: Vec<String>,
+    #[serde(default)]
+    features: Vec<String>,
+    profile: Option<String>,
+    target: Option<String>,
+    rustc: Option<String>,
+}
+
+/// Reads the packages installed into `cargo_home`
+///
+/// Returns no packages if nothing was ever installed there.
+pub fn installed_packages(cargo_home: &Path) -> Result<Vec<InstalledPackage>, InstalledError> {
+    let path = cargo_home.join(".crates2.json");
+    let contents = match std::fs::read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(error) => return Err(InstalledError::ReadError { path, error }),
+    };
+
+    parse_crates_json(&contents)
+}
+
+fn parse_crates_json(contents: &str) -> Result<Vec<InstalledPackage>, InstalledError> {
+    let crates: CratesJson = serde_json::from_str(contents)?;
+
+    crates
+        .installs
+        .into_iter()
+        .map(|(package_id, info)| {
+            // e.g. `ripgrep 13.0.0 (registry+https://github.com/rust-lang/crates.io-index)`
+            let mut parts = package_id.splitn(3, ' ');
+            let (name, version, source) = match (parts.next(), parts.next(), parts.next()) {
+                (Some(name), Some(version), Some(source))
+                    if source.starts_with('(') && source.ends_with(')') =>
+                {
+                    (name, version, &source[1..source.len() - 1])
+                }
+                _ => return Err(InstalledError::PackageIdError(package_id)),
+            };
+
+            Ok(InstalledPackage {
+                name: name.to_string(),
+                version: version.to_string(),
+                source: source.to_string(),
+                bins: info.bins,
+                features: info.features,
+                profile: info.profile,
+                target: info.target,
+                rustc: info.rustc,
+            })
+        })
+        .collect()
+}
+
+/// Creates an SBOM listing the installed packages as applications
+pub fn create_installed_bom(packages: &[InstalledPackage]) -> Result<Bom, InstalledError> {
+    let mut metadata = Metadata::new()?;
+    BuildInfo::current().record(&mut metadata);
+
+    let components = packages.iter().map(create_installed_component).collect();
+
+    let mut bom = Bom::default();
+    bom.metadata = Some(metadata);
+    bom.components = Some(Components(components));
+
+    Ok(bom)
+}
+
+fn create_installed_component(package: &InstalledPackage) -> Component {
+    let purl = match Purl::new("cargo", &package.name, &package.version) {
+        Ok(purl) => Some(purl),
+        Err(e) => {
+            log::error!("Package {} has an invalid Purl: {} ", package.name, e);
+            None
+        }
+    };
+
+    let mut component = Component::new(
+        Classification::Application,
+        &package.name,
+        &package.version,
+        purl.as_ref().map(ToString::to_string),
+    );
+    component.purl = purl;
+    component.external_references =
+        source_reference(package).map(|reference| ExternalReferences(vec![reference]));
+
+    component.set_cargo_property(CargoProperty::Source, &package.source);
+    component.set_cargo_bins(&package.bins);
+    if !package.features.is_empty() {
+        component.set_cargo_features(&package.features);
+    }
+    let settings = [
+        (CargoProperty::Profile, &package.profile),
+        (CargoProperty::Target, &package.target),
+        (CargoProperty::Rustc, &package.rustc),
+    ];
+    for (property, value) in settings {
+        if let Some(value) = value {
+            component.set_cargo_property(property, value);
+        }
+    }
+
+    component
+}
+
+/// Where the package was installed from: its download URL on crates.io, the index of another
+/// registry or its git repository. Packages installed from a local path have no reference.
+fn source_reference(package: &InstalledPackage) -> Option<ExternalReference> {
+    let (reference_type, url) = if CRATES_IO_INDEXES.contains(&package.source.as_str()) {
+        (
+            ExternalReferenceType::Distribution,
+            format!(
+                "{}/{}/{}/download",
+                CRATES_IO_DOWNLOAD, package.name, package.version
+            ),
+        )
+    } else if let Some(index) = package
+        .source
+        .strip_prefix("registry+")
+        .or_else(|| package.source.strip_prefix("sparse+"))
+    {
+        (ExternalReferenceType::Distribution, index.to_string())
+    } else if let Some(repository) = package.source.strip_prefix("git+") {
+        // the query and fragment select the branch and commit
+        let repository = repository.split(['?', '#']).next().unwrap_or(repository);
+        (ExternalReferenceType::Vcs, repository.to_string())
+    } else {
+        return None;
+    };
+
+    match Uri::try_from(url.clone()) {
+        Ok(uri) => Some(ExternalReference::new(reference_type, uri)),
+        Err(e) => {
+            log::error!(
+                "Package {} has an invalid source URI ({}): {} ",
+                package.name,
+                url,
+                e
+            );
+            None
+        }
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum InstalledError {
+    #[error("Could not read {}", .path.display())]
+    ReadError {
+        path: PathBuf,
+        #[source]
+        error: std::io::Error,
+    },
+
+    #[error("Invalid list of installed packages")]
+    ParseError(#[from] serde_json::Error),
+
+    #[error("Invalid installed package: {}", .0)]
+    PackageIdError(String),
+
+    #[error("Error creating Metadata")]
+    MetadataError(#[from] MetadataError),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const CRATES_JSON: &str = r#"{
+  "installs": {
+    "cargo-cyclonedx 0.3.8 (path+file:///home/user/cyclonedx-rust-cargo/cargo-cyclonedx)": {
+      "version_req": null,
+      "bins": ["cargo-cyclonedx"],
+      "features": [],
+      "all_features": false,
+      "no_default_features": false,
+      "profile": "release",
+      "target": "x86_64-unknown-linux-gnu",
+      "rustc": "rustc 1.70.0 (90c541806 2023-05-31)"
+    },
+    "ripgrep 13.0.0 (registry+https://github.com/rust-lang/crates.io-index)": {
+      "version_req": null,
+      "bins": ["rg"],
+      "features": ["pcre2"],
+      "all_features": false,
+      "no_default_features": false,
+      "profile": "release",
+      "target": "x86_64-unknown-linux-gnu",
+      "rustc": "rustc 1.70.0 (90c541806 2023-05-31)"
+    },
+    "tokei 13.0.0-alpha.0 (git+https://github.com/XAMPPRocky/tokei?branch=master#8cdb2a5b)": {
+      "bins": ["tokei"]
+    }
+  }
+}"#;
+
+    #[test]
+    fn it_should_parse_the_installed_packages() {
+        let packages = parse_crates_json(CRATES_JSON).expect("Failed to parse .crates2.json");
+
+        assert_eq!(packages.len(), 3);
+        assert_eq!(
+            packages[1],
+            InstalledPackage {
+                name: "ripgrep".to_string(),
+                version: "13.0.0".to_string(),
+                source: "registry+https://github.com/rust-lang/crates.io-index".to_string(),
+                bins: vec!["rg".to_string()],
+                features: vec!["pcre2".to_string()],
+                profile: Some("release".to_string()),
+                target: Some("x86_64-unknown-linux-gnu".to_string()),
+                rustc: Some("rustc 1.70.0 (90c541806 2023-05-31)".to_string()),
+            }
+        );
+        assert_eq!(packages[2].profile, None);
+    }
+
+    #[test]
+    fn it_should_fail_to_parse_an_invalid_package_id() {
+        let result = parse_crates_json(r#"{"installs": {"ripgrep": {"bins": ["rg"]}}}"#);
+
+        assert!(matches!(result, Err(InstalledError::PackageIdError(id)) if id == "ripgrep"));
+    }
+
+    #[test]
+    fn it_should_reference_where_the_package_was_installed_from() {
+        let packages = parse_crates_json(CRATES_JSON).expect("Failed to parse .crates2.json");
+        let references: Vec<_> = packages
+            .iter()
+            .map(|package| source_reference(package).map(|reference| reference.url.to_string()))
+            .collect();
+
+        assert_eq!(
+            references,
+            vec![
+                None,
+                Some("https://crates.io/api/v1/crates/ripgrep/13.0.0/download".to_string()),
+                Some("https://github.com/XAMPPRocky/tokei".to_string()),
+            ]
+        );
+    }
+}
@@ -19,9 +19,50 @@
 #![deny(clippy::all)]
 #![deny(warnings)]
 
+pub mod assets;
+#[cfg(feature = "async")]
+pub mod asynchronous;
+pub mod build;
+pub mod build_info;
 pub mod config;
+pub mod container;
+pub mod convert;
+pub mod copyleft;
+pub mod crate_hashes;
+pub mod crosscheck;
+pub mod discrepancies;
+pub mod doctor;
+pub mod duplicates;
+pub mod enrich;
+pub mod env;
+pub mod explain;
+pub mod exploitability;
+pub mod feature_matrix;
+pub mod forks;
 pub mod format;
 pub mod generator;
+pub mod init;
+pub mod installed;
+pub mod license;
+pub mod lockdiff;
+#[cfg(feature = "memory-stats")]
+pub mod memory;
+pub mod metrics;
+pub mod omissions;
+pub mod output;
+pub mod overrides;
+pub mod prepublish;
+pub mod profile;
+pub mod provenance;
+pub mod rescan;
+pub mod scan;
+pub mod sources;
+pub mod stats;
+pub mod summary;
+pub mod suppressions;
 pub mod toml;
+pub mod toolchains;
+pub mod validate;
+pub mod vex;
 
 pub use crate::generator::*;
@@ -0,0 +1,339 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+use crate::config::LicensePreference;
+
+use cargo::core::Package;
+use spdx::expression::{ExprNode, ExpressionReq, Operator};
+use spdx::{Expression, LicenseItem, LicenseReq, ParseMode};
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// The licenses under which a package is used, with every `OR` resolved to a single alternative
+///
+/// * `expression` - SPDX expression that only combines licenses with `AND`
+/// * `source` - Where the licenses were taken from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EffectiveLicense {
+    pub expression: String,
+    pub source: LicenseSource,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LicenseSource {
+    /// The `license` field of the manifest
+    Declared,
+    /// The names of the license files of the package, used if no license is declared
+    LicenseFiles,
+}
+
+impl fmt::Display for LicenseSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LicenseSource::Declared => "declared".fmt(f),
+            LicenseSource::LicenseFiles => "license-files".fmt(f),
+        }
+    }
+}
+
+/// Computes the effective license of `package`
+///
+/// The declared license expression is used if there is one. Otherwise, the licenses are
+/// detected from the names of the license files in the package root, such as `LICENSE-MIT`, and
+/// combined with `AND`, since it is unknown whether they are alternatives. Returns `None` if no
+/// license could be determined.
+pub fn effective_license(
+    package: &Package,
+    preference: &LicensePreference,
+) -> Option<EffectiveLicense> {
+    let metadata = package.manifest().metadata();
+
+    if let Some(declared) = metadata.license.as_deref() {
+        return resolve(declared, preference).map(|expression| EffectiveLicense {
+            expression,
+            source: LicenseSource::Declared,
+        });
+    }
+
+    let detected = detected_licenses(package);
+    if detected.is_empty() {
+        return None;
+    }
+
+    Some(EffectiveLicense {
+        expression: detected.join(" AND "),
+        source: LicenseSource::LicenseFiles,
+    })
+}
+
+/// The licenses named by the license files of `package`, see [`license_files`]
+pub fn detected_licenses(package: &Package) -> Vec<String> {
+    let mut detected: Vec<String> = Vec::new();
+    for path in license_files(package) {
+        let file_name = path.file_name().map(|name| name.to_string_lossy());
+        if let Some(license) = file_name.as_deref().and_then(license_from_file_name) {
+            if !detected.contains(&license) {
+                detected.push(license);
+            }
+        }
+    }
+    detected
+}
+
+/// The license files of `package`, which are the file given as `license-file` in the manifest
+/// and the files in the package root named like `LICENSE`, `LICENCE` or `COPYING`, in the order
+/// of their names
+pub fn license_files(package: &Package) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    if let Some(path) = package.manifest().metadata().license_file.as_deref() {
+        files.push(package.root().join(path));
+    }
+
+    let mut entries: Vec<_> = match std::fs::read_dir(package.root()) {
+        Ok(entries) => entries
+            .flatten()
+            .filter(|entry| entry.file_type().map_or(false, |t| t.is_file()))
+            .filter(|entry| is_license_file_name(&entry.file_name().to_string_lossy()))
+            .map(|entry| entry.path())
+            .collect(),
+        Err(e) => {
+            log::warn!(
+                "Could not look for the license files of {}: {}",
+                package.name(),
+                e
+            );
+            Vec::new()
+        }
+    };
+    entries.sort();
+    for path in entries {
+        if !files.contains(&path) {
+            files.push(path);
+        }
+    }
+
+    files
+}
+
+/// The SPDX license the license file at `path` of `package` holds, which is the license named by
+/// its file name, or else the declared license if it is a single license
+pub fn license_of_file(package: &Package, path: &Path) -> Option<String> {
+    let from_file_name = path
+        .file_name()
+        .and_then(|name| license_from_file_name(&name.to_string_lossy()));
+    from_file_name.or_else(|| {
+        let declared = package.manifest().metadata().license.as_deref()?;
+        match declared_licenses(declared)?.as_slice() {
+            [license] => Some(license.clone()),
+            _ => None,
+        }
+    })
+}
+
+/// The licenses in the SPDX `expression`, without their exceptions and in the order they are
+/// written, or `None` if the expression cannot be parsed, even leniently
+pub fn declared_licenses(expression: &str) -> Option<Vec<String>> {
+    let parsed = Expression::parse_mode(expression, ParseMode::LAX).ok()?;
+
+    let mut licenses: Vec<String> = Vec::new();
+    for req in parsed.requirements() {
+        let mut license = license_text(expression, req);
+        license.truncate(license.find(" WITH ").unwrap_or(license.len()));
+        if !licenses.contains(&license) {
+            licenses.push(license);
+        }
+    }
+
+    Some(licenses)
+}
+
+/// Resolves the `OR` operators of the SPDX `expression` to the alternative with the most
+/// preferred licenses
+///
+/// An alternative ranks as its least preferred license. Returns `None` if the expression cannot
+/// be parsed, even leniently.
+pub fn resolve(expression: &str, preference: &LicensePreference) -> Option<String> {
+    let parsed = Expression::parse_mode(expression, ParseMode::LAX).ok()?;
+
+    // The expression is stored in postfix order
+    let mut stack: Vec<(Vec<String>, usize)> = Vec::new();
+    for node in parsed.iter() {
+        match node {
+            ExprNode::Req(req) => stack.push((
+                vec![license_text(expression, req)],
+                rank(&req.req, preference),
+            )),
+            ExprNode::Op(operator) => {
+                let right = stack.pop()?;
+                let mut left = stack.pop()?;
+                match operator {
+                    Operator::And => {
+                        for license in right.0 {
+                            if !left.0.contains(&license) {
+                                left.0.push(license);
+                            }
+                        }
+                        left.1 = left.1.max(right.1);
+                        stack.push(left);
+                    }
+                    Operator::Or if right.1 < left.1 => stack.push(right),
+                    Operator::Or => stack.push(left),
+                }
+            }
+        }
+    }
+
+    let (licenses, _) = stack.pop()?;
+    Some(licenses.join(" AND "))
+}
+
+/// The license as written in `expression`, or its canonical form if it was only accepted by the
+/// lax parser, e.g. `Apache-2.0` for `Apache`
+///
+/// The canonical form is not always used since it replaces GNU licenses with their deprecated
+/// identifiers, e.g. `GPL-3.0` for `GPL-3.0-only`.
+fn license_text(expression: &str, req: &ExpressionReq) -> String {
+    let written = expression
+        .get(req.span.start as usize..req.span.end as usize)
+        .unwrap_or_default();
+    let license = if Expression::parse(written).is_ok() {
+        written.to_string()
+    } else {
+        req.req.license.to_string()
+    };
+
+    match req.req.exception {
+        Some(exception) => format!("{} WITH {}", license, exception.name),
+        None => license,
+    }
+}
+
+fn rank(req: &LicenseReq, preference: &LicensePreference) -> usize {
+    let by_name = match &req.license {
+        LicenseItem::Spdx { id, .. } => preference.rank(id.name),
+        LicenseItem::Other { .. } => preference.licenses().len(),
+    };
+    by_name.min(preference.rank(&req.license.to_string()))
+}
+
+fn is_license_file_name(file_name: &str) -> bool {
+    let upper = file_name.to_ascii_uppercase();
+    ["LICENSE", "LICENCE", "COPYING"]
+        .iter()
+        .any(|prefix| upper.starts_with(prefix))
+}
+
+/// The SPDX license named by the suffix of a license file name, e.g. `MIT` for `LICENSE-MIT` or
+/// `Apache-2.0` for `LICENSE-APACHE.txt`
+fn license_from_file_name(file_name: &str) -> Option<String> {
+    let upper = file_name.to_ascii_uppercase();
+    let suffix = ["LICENSE", "LICENCE", "COPYING"]
+        .iter()
+        .find_map(|prefix| upper.strip_prefix(prefix))?
+        .strip_prefix(['-', '_'])?;
+    let suffix = &file_name[file_name.len() - suffix.len()..];
+    let name = match suffix.rsplit_once('.') {
+        Some((name, extension)) if !extension.is_empty() && extension.len() <= 4 => name,
+        _ => suffix,
+    };
+
+    spdx::license_id(name)
+        .or_else(|| spdx::imprecise_license_id(name).map(|(id, _)| id))
+        .map(|id| id.name.to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn preference(licenses: &[&str]) -> LicensePreference {
+        LicensePreference::new(licenses.iter().map(|l| l.to_string()).collect()).unwrap()
+    }
+
+    #[test]
+    fn it_should_resolve_or_to_the_preferred_alternative() {
+        let expression = "MIT OR Apache-2.0";
+
+        assert_eq!(
+            resolve(expression, &preference(&[])),
+            Some("MIT".to_string())
+        );
+        assert_eq!(
+            resolve(expression, &preference(&["Apache-2.0", "MIT"])),
+            Some("Apache-2.0".to_string())
+        );
+        assert_eq!(
+            resolve("MIT/Apache-2.0", &preference(&["Apache-2.0"])),
+            Some("Apache-2.0".to_string())
+        );
+    }
+
+    #[test]
+    fn it_should_rank_alternatives_by_their_least_preferred_license() {
+        let expression = "(MIT AND Unicode-DFS-2016) OR (Apache-2.0 WITH LLVM-exception)";
+
+        assert_eq!(
+            resolve(expression, &preference(&["MIT", "Apache-2.0"])),
+            Some("Apache-2.0 WITH LLVM-exception".to_string())
+        );
+        assert_eq!(
+            resolve(
+                expression,
+                &preference(&["MIT", "Unicode-DFS-2016", "Apache-2.0"])
+            ),
+            Some("MIT AND Unicode-DFS-2016".to_string())
+        );
+        assert_eq!(
+            resolve("MIT AND (MIT OR BSD-3-Clause)", &preference(&[])),
+            Some("MIT".to_string())
+        );
+        assert_eq!(
+            resolve("GPL-3.0-only OR Apache", &preference(&["Apache-2.0"])),
+            Some("Apache-2.0".to_string())
+        );
+        assert_eq!(
+            resolve("GPL-3.0-only OR Apache", &preference(&[])),
+            Some("GPL-3.0-only".to_string())
+        );
+    }
+
+    #[test]
+    fn it_should_not_resolve_invalid_expressions() {
+        assert_eq!(resolve("MIT OR", &preference(&[])), None);
+    }
+
+    #[test]
+    fn it_should_detect_licenses_from_file_names() {
+        for (file_name, expected) in [
+            ("LICENSE-MIT", Some("MIT")),
+            ("LICENSE-APACHE", Some("Apache-2.0")),
+            ("license_BSD-3-Clause.txt", Some("BSD-3-Clause")),
+            ("COPYING-GPL-3.0-only.md", Some("GPL-3.0-only")),
+            ("LICENSE", None),
+            ("LICENSE.md", None),
+            ("LICENSE-THIRD-PARTY", None),
+            ("README.md", None),
+        ] {
+            assert_eq!(
+                license_from_file_name(file_name).as_deref(),
+                expected,
+                "{}",
+                file_name
+            );
+        }
+    }
+}
@@ -0,0 +1,390 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+use cargo::core::Workspace;
+use cyclonedx_bom::external_models::purl::Purl;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// A package pinned in a `Cargo.lock`
+///
+/// * `source` - Registry or git repository of the package, `None` for path dependencies
+/// * `purl` - Package URL identifying the package in the generated SBOMs
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct LockedPackage {
+    pub name: String,
+    pub version: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub purl: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Lockfile {
+    #[serde(default)]
+    package: Vec<LockfilePackage>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LockfilePackage {
+    name: String,
+    version: String,
+    source: Option<String>,
+}
+
+/// Parses the packages pinned by the contents of a `Cargo.lock`
+pub fn parse_lockfile(contents: &str) -> Result<Vec<LockedPackage>, LockdiffError> {
+    let lockfile: Lockfile = toml::from_str(contents)?;
+
+    Ok(lockfile
+        .package
+        .into_iter()
+        .map(|package| LockedPackage {
+            purl: Purl::new("cargo", &package.name, &package.version)
+                .ok()
+                .map(|purl| purl.to_string()),
+            name: package.name,
+            version: package.version,
+            source: package.source,
+        })
+        .collect())
+}
+
+/// A package whose version or source differs between two lockfiles
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct UpdatedPackage {
+    pub name: String,
+    pub from: LockedPackage,
+    pub to: LockedPackage,
+}
+
+/// The dependency changes between two revisions of a `Cargo.lock`, sorted by name
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct LockDiff {
+    pub added: Vec<LockedPackage>,
+    pub removed: Vec<LockedPackage>,
+    pub updated: Vec<UpdatedPackage>,
+}
+
+impl LockDiff {
+    /// Compares the packages of two lockfiles
+    ///
+    /// If a package is locked in several versions, the removed versions are paired with the
+    /// added ones in version order and reported as updates, and the versions left over as
+    /// additions or removals.
+    pub fn new(from: &[LockedPackage], to: &[LockedPackage]) -> Self {
+        let mut by_name: BTreeMap<&str, (BTreeSet<&LockedPackage>, BTreeSet<&LockedPackage>)> =
+            BTreeMap::new();
+        for package in from {
+            by_name.entry(&package.name).or_default().0.insert(package);
+        }
+        for package in to {
+            by_name.entry(&package.name).or_default().1.insert(package);
+        }
+
+        let mut diff = LockDiff::default();
+        for (name, (old, new)) in by_name {
+            let mut removed: Vec<_> = old.difference(&new).copied().collect();
+            let mut added: Vec<_> = new.difference(&old).copied().collect();
+            removed.sort_by(|a, b| compare_versions(&a.version, &b.version));
+            added.sort_by(|a, b| compare_versions(&a.version, &b.version));
+
+            let updates = removed.len().min(added.len());
+            for (from, to) in removed.iter().zip(&added) {
+                diff.updated.push(UpdatedPackage {
+                    name: name.to_string(),
+                    from: (*from).clone(),
+                    to: (*to).clone(),
+                });
+            }
+            diff.removed
+                .extend(removed[updates..].iter().map(|&package| package.clone()));
+            diff.added
+                .extend(added[updates..].iter().map(|&package| package.clone()));
+        }
+
+        diff
+    }
+
+    /// Drops the changes of the packages named in `names`, e.g. the workspace members
+    pub fn without(mut self, names: &[String]) -> Self {
+        self.added.retain(|package| !names.contains(&package.name));
+        self.removed
+            .retain(|package| !names.contains(&package.name));
+        self.updated.retain(|update| !names.contains(&update.name));
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.updated.is_empty()
+    }
+
+    /// Renders the changes as a Markdown table, e.g. for the description of a release PR
+    pub fn to_markdown(&self) -> String {
+        if self.is_empty() {
+            return "No dependency changes\n".to_string();
+        }
+
+        let mut rows: Vec<(&str, &str, String, String)> = Vec::new();
+        for package in &self.added {
+            rows.push((
+                &package.name,
+                "added",
+                String::new(),
+                package.version.clone(),
+            ));
+        }
+        for package in &self.removed {
+            rows.push((
+                &package.name,
+                "removed",
+                package.version.clone(),
+                String::new(),
+            ));
+        }
+        for update in &self.updated {
+            let (from, to) = if update.from.version == update.to.version {
+                // only the source changed, e.g. another commit of a git dependency
+                (describe(&update.from), describe(&update.to))
+            } else {
+                (update.from.version.clone(), update.to.version.clone())
+            };
+            rows.push((&update.name, "updated", from, to));
+        }
+        rows.sort();
+
+        let mut markdown =
+            "| Dependency | Change | From | To |\n| --- | --- | --- | --- |\n".to_string();
+        for (name, change, from, to) in rows {
+            markdown.push_str(&format!("| {} | {} | {} | {} |\n", name, change, from, to));
+        }
+        markdown
+    }
+}
+
+fn describe(package: &LockedPackage) -> String {
+    match &package.source {
+        Some(source) => format!("{} ({})", package.version, source),
+        None => package.version.clone(),
+    }
+}
+
+fn compare_versions(a: &str, b: &str) -> std::cmp::Ordering {
+    match (semver::Version::parse(a), semver::Version::parse(b)) {
+        (Ok(a), Ok(b)) => a.cmp(&b),
+        _ => a.cmp(b),
+    }
+}
+
+/// Output format of `cargo cyclonedx lockdiff`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DiffFormat {
+    #[default]
+    Markdown,
+    Json,
+}
+
+impl FromStr for DiffFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "markdown" => Ok(DiffFormat::Markdown),
+            "json" => Ok(DiffFormat::Json),
+            _ => Err(format!("Expected markdown or json, got `{}`", s)),
+        }
+    }
+}
+
+impl fmt::Display for DiffFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DiffFormat::Markdown => "markdown".fmt(f),
+            DiffFormat::Json => "json".fmt(f),
+        }
+    }
+}
+
+/// Compares the `Cargo.lock` of the workspace at the git revision `from` with the one at `to`,
+/// or with the lockfile in the working tree if `to` is `None`
+///
+/// Changes to the workspace members themselves are left out.
+pub fn compare(ws: &Workspace, from: &str, to: Option<&str>) -> Result<LockDiff, LockdiffError> {
+    let root = ws.root();
+
+    let old = parse_lockfile(&lockfile_at_revision(root, from)?)?;
+    let new = match to {
+        Some(revision) => lockfile_at_revision(root, revision)?,
+        None => {
+            let path = root.join("Cargo.lock");
+            std::fs::read_to_string(&path)
+                .map_err(|error| LockdiffError::ReadError { path, error })?
+        }
+    };
+    let new = parse_lockfile(&new)?;
+
+    let members: Vec<String> = ws
+        .members()
+        .map(|member| member.name().to_string())
+        .collect();
+    Ok(LockDiff::new(&old, &new).without(&members))
+}
+
+fn lockfile_at_revision(root: &Path, revision: &str) -> Result<String, LockdiffError> {
+    // `./` makes git resolve the path relative to the workspace root instead of the repository root
+    let output = Command::new("git")
+        .arg("show")
+        .arg(format!("{}:./Cargo.lock", revision))
+        .current_dir(root)
+        .output()
+        .map_err(LockdiffError::GitError)?;
+
+    if !output.status.success() {
+        return Err(LockdiffError::RevisionError {
+            revision: revision.to_string(),
+            message: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        });
+    }
+
+    String::from_utf8(output.stdout).map_err(|_| LockdiffError::RevisionError {
+        revision: revision.to_string(),
+        message: "Cargo.lock is not valid UTF-8".to_string(),
+    })
+}
+
+#[derive(Error, Debug)]
+pub enum LockdiffError {
+    #[error("Could not run git")]
+    GitError(#[source] std::io::Error),
+
+    #[error("Could not read Cargo.lock at revision {revision}: {message}")]
+    RevisionError { revision: String, message: String },
+
+    #[error("Could not read {}", .path.display())]
+    ReadError {
+        path: PathBuf,
+        #[source]
+        error: std::io::Error,
+    },
+
+    #[error("Invalid Cargo.lock")]
+    ParseError(#[from] toml::de::Error),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn package(name: &str, version: &str) -> LockedPackage {
+        LockedPackage {
+            name: name.to_string(),
+            version: version.to_string(),
+            source: None,
+            purl: None,
+        }
+    }
+
+    #[test]
+    fn it_should_parse_the_locked_packages() {
+        let lockfile = r#"
+version = 3
+
+[[package]]
+name = "app"
+version = "0.1.0"
+dependencies = ["serde"]
+
+[[package]]
+name = "serde"
+version = "1.0.164"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+checksum = "9e8c8cf938e98f769bc164923b06dce91cea1751522f46f8466461af04c9027d"
+"#;
+
+        let packages = parse_lockfile(lockfile).expect("Failed to parse the lockfile");
+
+        assert_eq!(packages.len(), 2);
+        assert_eq!(
+            packages[1].source.as_deref(),
+            Some("registry+https://github.com/rust-lang/crates.io-index")
+        );
+        assert_eq!(packages[1].purl.as_deref(), Some("pkg:cargo/serde@1.0.164"));
+    }
+
+    #[test]
+    fn it_should_pair_removed_and_added_versions_as_updates() {
+        let from = vec![
+            package("rand", "0.7.3"),
+            package("rand", "0.8.5"),
+            package("serde", "1.0.160"),
+            package("time", "0.1.45"),
+        ];
+        let to = vec![
+            package("rand", "0.8.5"),
+            package("serde", "1.0.164"),
+            package("syn", "2.0.18"),
+            package("syn", "1.0.109"),
+        ];
+
+        let diff = LockDiff::new(&from, &to);
+
+        assert_eq!(
+            diff.added,
+            vec![package("syn", "1.0.109"), package("syn", "2.0.18")]
+        );
+        assert_eq!(
+            diff.removed,
+            vec![package("rand", "0.7.3"), package("time", "0.1.45")]
+        );
+        assert_eq!(
+            diff.updated,
+            vec![UpdatedPackage {
+                name: "serde".to_string(),
+                from: package("serde", "1.0.160"),
+                to: package("serde", "1.0.164"),
+            }]
+        );
+    }
+
+    #[test]
+    fn it_should_render_a_markdown_table() {
+        let diff = LockDiff::new(
+            &[package("app", "0.1.0"), package("serde", "1.0.160")],
+            &[
+                package("app", "0.2.0"),
+                package("serde", "1.0.9"),
+                package("anyhow", "1.0.71"),
+            ],
+        )
+        .without(&["app".to_string()]);
+
+        assert_eq!(
+            diff.to_markdown(),
+            "| Dependency | Change | From | To |\n\
+             | --- | --- | --- | --- |\n\
+             | anyhow | added |  | 1.0.71 |\n\
+             | serde | updated | 1.0.160 | 1.0.9 |\n"
+        );
+        assert_eq!(LockDiff::default().to_markdown(), "No dependency changes\n");
+    }
+}
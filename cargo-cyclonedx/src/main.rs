@@ -46,11 +46,40 @@
 * SOFTWARE.
 */
 use cargo::core::Workspace;
+use cargo::util::Filesystem;
 use cargo::Config;
-use cargo_cyclonedx::generator::SbomGenerator;
+use cargo_cyclonedx::build;
+use cargo_cyclonedx::build_info::BuildInfo;
+use cargo_cyclonedx::config::{BuildProfile, SourceFiles};
+use cargo_cyclonedx::convert;
+use cargo_cyclonedx::copyleft;
+use cargo_cyclonedx::crosscheck::{self, CrosscheckFormat};
+use cargo_cyclonedx::discrepancies;
+use cargo_cyclonedx::doctor::{self, Status};
+use cargo_cyclonedx::env::config_from_env;
+use cargo_cyclonedx::explain::{self, CheckFailure, ErrorCode};
+use cargo_cyclonedx::exploitability::Exploitability;
+use cargo_cyclonedx::generator::{Document, GeneratedSbom, SbomGenerator};
+use cargo_cyclonedx::init::{self, InitTarget};
+use cargo_cyclonedx::installed;
+use cargo_cyclonedx::lockdiff::{self, DiffFormat};
+use cargo_cyclonedx::omissions::{OmissionReason, Omissions};
+use cargo_cyclonedx::output;
+use cargo_cyclonedx::prepublish;
+use cargo_cyclonedx::rescan;
+use cargo_cyclonedx::stats::{self, BomStats, CoverageThresholds, StatsFormat};
+use cargo_cyclonedx::summary::{GenerationSummary, PhaseStart, WarningCollector};
+use cargo_cyclonedx::suppressions::{self, Suppressions};
+use cargo_cyclonedx::toolchains;
+use cargo_cyclonedx::validate::{self, ValidationFormat};
+use cargo_cyclonedx::vex::{self, AdvisoryDb};
+use cyclonedx_bom::anonymize::Anonymizer;
+use cyclonedx_bom::models::bom::SpecVersion;
+use cyclonedx_bom::signing::SigningKey;
 use std::{
     io::{self},
-    path::PathBuf,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
 };
 
 use anyhow::Result;
@@ -61,32 +90,655 @@ use log::LevelFilter;
 #[deny(clippy::all)]
 #[deny(warnings)]
 mod cli;
-use cli::{Args, Opts};
+use cli::{Args, Command, ConfigCommand, Opts};
 
-fn main() -> anyhow::Result<()> {
+#[cfg(feature = "memory-stats")]
+#[global_allocator]
+static ALLOCATOR: cargo_cyclonedx::memory::CountingAllocator =
+    cargo_cyclonedx::memory::CountingAllocator;
+
+fn main() {
+    if let Err(error) = run() {
+        match explain::code_of(&error) {
+            Some(code) => eprintln!(
+                "Error[{}]: {:?}\n\nFor more information about this error, try `cargo cyclonedx explain {}`.",
+                code, error, code
+            ),
+            None => eprintln!("Error: {:?}", error),
+        }
+        std::process::exit(1);
+    }
+}
+
+fn run() -> anyhow::Result<()> {
     let Opts::Bom(args) = Opts::parse();
+
+    match &args.command {
+        Some(Command::Completions { shell }) => {
+            Opts::write_completions(*shell, &mut io::stdout());
+            return Ok(());
+        }
+        Some(Command::Manpage) => {
+            Opts::write_manpage(&mut io::stdout())?;
+            return Ok(());
+        }
+        Some(Command::Explain { code }) => {
+            match code {
+                Some(code) => {
+                    let code = code.parse::<ErrorCode>().map_err(anyhow::Error::msg)?;
+                    println!("{}: {}\n\n{}", code, code.title(), code.explanation());
+                }
+                None => {
+                    for code in ErrorCode::ALL {
+                        println!("{}: {}", code, code.title());
+                    }
+                }
+            }
+            return Ok(());
+        }
+        _ => {}
+    }
+
+    if args.capabilities {
+        let capabilities = BuildInfo::current().capabilities();
+        println!("{}", serde_json::to_string_pretty(&capabilities)?);
+        return Ok(());
+    }
+
     let mut config = Config::default()?;
-    setup_logging(&args, &mut config)?;
+    let warnings = setup_logging(&args, &mut config)?;
+    let mut summary = GenerationSummary::default();
+    #[cfg(feature = "memory-stats")]
+    if args.memory_stats {
+        summary.memory = Some(Default::default());
+    }
+
+    if let Some(Command::Template {
+        org_defaults,
+        workspace,
+    }) = &args.command
+    {
+        let target = if *workspace {
+            InitTarget::Workspace
+        } else {
+            InitTarget::Package
+        };
+        let org_defaults = match org_defaults {
+            Some(location) => Some(init::read_org_defaults(&config, location)?),
+            None => None,
+        };
+        print!("{}", init::baseline_block(target, org_defaults.as_deref())?);
+        return Ok(());
+    }
+
+    if let Some(Command::Stats { path, format }) = &args.command {
+        let stats = stats::read(path)?;
+        match format {
+            StatsFormat::Text => print!("{}", stats),
+            StatsFormat::Json => println!("{}", serde_json::to_string_pretty(&stats)?),
+        }
+        return Ok(());
+    }
+
+    if let Some(Command::Validate {
+        path,
+        format,
+        severity,
+    }) = &args.command
+    {
+        let validation = validate::validate(path, *severity)?;
+        match format {
+            ValidationFormat::Text => print!("{}", validation),
+            ValidationFormat::Json => println!("{}", serde_json::to_string_pretty(&validation)?),
+        }
+        if !validation.report.passed {
+            return Err(CheckFailure::new(
+                ErrorCode::InvalidBom,
+                format!("{} is not a valid BOM", path.display()),
+            )
+            .into());
+        }
+        return Ok(());
+    }
+
+    if let Some(Command::Convert {
+        path,
+        to,
+        format,
+        spec_version,
+        output,
+    }) = &args.command
+    {
+        let converted = convert::convert(path, *to, *format, *spec_version)?;
+        match output {
+            Some(output) => std::fs::write(output, converted)?,
+            None => io::Write::write_all(&mut io::stdout(), &converted)?,
+        }
+        return Ok(());
+    }
+
+    if let Some(Command::Rescan {
+        path,
+        advisory_db,
+        kev,
+        epss,
+        suppressions,
+    }) = &args.command
+    {
+        let db_path = match advisory_db {
+            Some(db_path) => db_path.clone(),
+            None => AdvisoryDb::default_path(config.home().as_path_unlocked()),
+        };
+        let db = AdvisoryDb::open(&db_path)?;
+        let exploitability = Exploitability::open(kev.as_deref(), epss.as_deref())?;
+        let suppressions = Suppressions::open(suppressions.as_deref())?;
+        print!(
+            "{}",
+            rescan::rescan(path, &db, &exploitability, &suppressions, args.dry_run)?
+        );
+        return Ok(());
+    }
+
+    if let Some(Command::Crosscheck {
+        path,
+        reports,
+        format,
+    }) = &args.command
+    {
+        let crosscheck = crosscheck::crosscheck(path, reports)?;
+        match format {
+            CrosscheckFormat::Text => print!("{}", crosscheck),
+            CrosscheckFormat::Json => println!("{}", serde_json::to_string_pretty(&crosscheck)?),
+        }
+        if !crosscheck.is_consistent() {
+            return Err(CheckFailure::new(
+                ErrorCode::CrosscheckMismatch,
+                format!(
+                    "{} crate(s) mentioned by the reports are missing from {}",
+                    crosscheck.missing.len(),
+                    path.display()
+                ),
+            )
+            .into());
+        }
+        return Ok(());
+    }
 
     let manifest_path = locate_manifest(&args)?;
+
+    if let Some(Command::Init {
+        defaults,
+        workspace,
+    }) = &args.command
+    {
+        return init(&manifest_path, *defaults, *workspace, args.dry_run);
+    }
+
+    if let Some(Command::Doctor) = &args.command {
+        let diagnostics = doctor::run_checks(&config, &manifest_path);
+        for diagnostic in &diagnostics {
+            println!("{}", diagnostic);
+        }
+
+        let errors = diagnostics
+            .iter()
+            .filter(|diagnostic| diagnostic.status == Status::Error)
+            .count();
+        if errors > 0 {
+            return Err(CheckFailure::new(
+                ErrorCode::DoctorChecksFailed,
+                format!("{} check(s) failed", errors),
+            )
+            .into());
+        }
+        return Ok(());
+    }
+
+    let env_config = config_from_env()?;
     let cli_config = args.as_config()?;
 
+    let inventory = match &args.command {
+        Some(Command::Installed) => {
+            let packages = installed::installed_packages(config.home().as_path_unlocked())?;
+            Some(("installed", installed::create_installed_bom(&packages)?))
+        }
+        Some(Command::Toolchains) => {
+            let toolchains = toolchains::installed_toolchains(&home::rustup_home()?)?;
+            Some((
+                "toolchains",
+                toolchains::create_toolchains_bom(&toolchains)?,
+            ))
+        }
+        _ => None,
+    };
+    if let Some((package_name, bom)) = inventory {
+        let bom = GeneratedSbom {
+            bom,
+            manifest_path: manifest_path.clone(),
+            package_name: package_name.to_string(),
+            sbom_config: env_config.merge(&cli_config),
+            output_dir: Some(std::env::current_dir()?),
+            published_refs: None,
+            feature_set: None,
+            document: Document::Sbom,
+        };
+
+        // the inventory does not belong to a workspace whose output could be locked
+        return write_sboms(&args, &config, vec![bom], None, summary, &warnings);
+    }
+
+    let started = PhaseStart::now();
     let ws = Workspace::new(&manifest_path, &config)?;
+    let root_manifest = ws.root_manifest().to_path_buf();
+    summary.record_phase("workspace", started);
+
+    if let Some(Command::Config(ConfigCommand::Show)) = &args.command {
+        for package_config in SbomGenerator::effective_configs(&ws, &env_config, &cli_config)? {
+            println!("{}", package_config);
+        }
+        return Ok(());
+    }
+
+    if let Some(Command::Lockdiff { from, to, format }) = &args.command {
+        let diff = lockdiff::compare(&ws, from, to.as_deref())?;
+        match format {
+            DiffFormat::Markdown => print!("{}", diff.to_markdown()),
+            DiffFormat::Json => println!("{}", serde_json::to_string_pretty(&diff)?),
+        }
+        return Ok(());
+    }
+
+    let mut config_override = env_config.merge(&cli_config);
+
+    if let Some(Command::Copyleft) = &args.command {
+        let reports = copyleft::analyze(&ws, &config_override)?;
+        let reports: Vec<_> = reports.iter().map(ToString::to_string).collect();
+        println!("{}", reports.join("\n\n"));
+        return Ok(());
+    }
+
+    if let Some(Command::LicenseDiscrepancies) = &args.command {
+        let discrepancies = discrepancies::analyze(&ws)?;
+        if discrepancies.is_empty() {
+            println!("No license discrepancies");
+        } else {
+            let discrepancies: Vec<_> = discrepancies.iter().map(ToString::to_string).collect();
+            println!("{}", discrepancies.join("\n"));
+        }
+        return Ok(());
+    }
+    let target_dir = ws.target_dir();
+
+    if let Some(Command::Prepublish { include }) = &args.command {
+        if *include && args.output_dir.is_some() {
+            anyhow::bail!("--include writes the SBOM into the package and cannot be combined with --output-dir");
+        }
+        // the packaged files are what is published
+        config_override.source_files = Some(SourceFiles::Included);
+
+        let started = PhaseStart::now();
+        let mut boms = if args.dry_run {
+            SbomGenerator::plan_sboms(ws, &config_override)?
+        } else {
+            SbomGenerator::create_sboms(ws, &config_override)?
+        };
+        summary.record_phase("generation", started);
+
+        if !include {
+            let dir = prepublish::package_dir(&target_dir);
+            let dir = if args.dry_run {
+                dir
+            } else {
+                output::canonical_output_dir(&dir)?
+            };
+            for bom in &mut boms {
+                prepublish::place_next_to_crate(bom, &dir)?;
+            }
+        }
+        let written: Vec<_> = boms
+            .iter()
+            .map(|bom| (bom.package_name.clone(), bom.output_path()))
+            .collect();
+        write_sboms(&args, &config, boms, Some(&target_dir), summary, &warnings)?;
+
+        if *include && !args.dry_run {
+            let ws = Workspace::new(&manifest_path, &config)?;
+            prepublish::check_included(&ws, &written)?;
+        }
+        return Ok(());
+    }
+
+    if let Some(Command::Saasbom) = &args.command {
+        if args.merged {
+            anyhow::bail!("--merged cannot be combined with saasbom");
+        }
+        let started = PhaseStart::now();
+        let boms = if args.dry_run {
+            SbomGenerator::plan_saasboms(ws, &config_override)?
+        } else {
+            SbomGenerator::create_saasboms(ws, &config_override)?
+        };
+        summary.record_phase("generation", started);
+        return write_sboms(&args, &config, boms, Some(&target_dir), summary, &warnings);
+    }
 
     log::trace!("SBOM generation started");
-    let boms = SbomGenerator::create_sboms(ws, &cli_config)?;
+    let started = PhaseStart::now();
+    let boms = match &args.command {
+        Some(Command::Build {
+            unit_graph,
+            cargo_args,
+        }) => {
+            // the recorded profile has to be the one that is built
+            let profile = config_override.profile();
+            config_override.profile = Some(profile.clone());
+
+            let cargo_args = build_args(&args, &profile, cargo_args);
+            let built = if *unit_graph {
+                build::run_unit_graph(&manifest_path, &cargo_args)?
+            } else {
+                build::run_build(&manifest_path, &cargo_args)?
+            };
+            summary.record_phase("build", started);
+
+            let started = PhaseStart::now();
+            let boms = if args.dry_run {
+                SbomGenerator::plan_sboms_for_build(ws, &config_override, &built)?
+            } else {
+                SbomGenerator::create_sboms_for_build(ws, &config_override, &built)?
+            };
+            summary.record_phase("generation", started);
+            boms
+        }
+        _ if args.dry_run => {
+            let boms = SbomGenerator::plan_sboms(ws, &config_override)?;
+            summary.record_phase("generation", started);
+            boms
+        }
+        _ => {
+            let boms = SbomGenerator::create_sboms(ws, &config_override)?;
+            summary.record_phase("generation", started);
+            boms
+        }
+    };
     log::trace!("SBOM generation finished");
 
+    let boms = if args.merged {
+        if boms.iter().any(|bom| bom.feature_set.is_some()) {
+            anyhow::bail!("--merged cannot be combined with the feature matrix");
+        }
+        SbomGenerator::merge_sboms(&root_manifest, boms)?
+    } else {
+        boms
+    };
+
+    write_sboms(&args, &config, boms, Some(&target_dir), summary, &warnings)
+}
+
+/// Applies the output options to the generated SBOMs and writes them
+fn write_sboms(
+    args: &Args,
+    config: &Config,
+    boms: Vec<GeneratedSbom>,
+    target_dir: Option<&Filesystem>,
+    mut summary: GenerationSummary,
+    warnings: &Mutex<Vec<String>>,
+) -> anyhow::Result<()> {
+    let boms = match &args.container_image {
+        Some(image) => {
+            if image.digest.is_none() {
+                log::warn!(
+                    "The container image {} has no digest, so it does not identify a single image",
+                    image
+                );
+            }
+            boms.into_iter()
+                .map(|mut bom| {
+                    image.set_as_parent(&mut bom.bom);
+                    bom
+                })
+                .collect()
+        }
+        None => boms,
+    };
+
+    let boms = match &args.filter {
+        Some(filter) => boms
+            .into_iter()
+            .map(|mut bom| {
+                let count = |bom: &GeneratedSbom| {
+                    bom.bom
+                        .components
+                        .as_ref()
+                        .map_or(0, |components| components.0.len())
+                };
+                let before = count(&bom);
+                filter.apply(&mut bom.bom);
+                let mut omissions = Omissions::default();
+                omissions.add(OmissionReason::Filter, before - count(&bom));
+                omissions.record(&mut bom.bom);
+                bom
+            })
+            .collect(),
+        None => boms,
+    };
+
+    if args.vex && args.anonymize.is_some() {
+        anyhow::bail!("--vex cannot be combined with --anonymize");
+    }
+
+    // the VEX documents, coverage and signature need the enriched SBOMs that are not generated
+    if args.dry_run {
+        for bom in &place_sboms(args, boms)? {
+            println!("Would write {}", bom.output_summary());
+        }
+        return Ok(());
+    }
+
+    let boms = if args.vex {
+        let path = match &args.advisory_db {
+            Some(path) => path.clone(),
+            None => AdvisoryDb::default_path(config.home().as_path_unlocked()),
+        };
+        let db = AdvisoryDb::open(&path)?;
+        let exploitability = Exploitability::open(args.kev.as_deref(), args.epss.as_deref())?;
+        let suppressions = Suppressions::open(args.suppressions.as_deref())?;
+        let today = suppressions::today()?;
+        let mut with_vex = Vec::with_capacity(boms.len() * 2);
+        for bom in boms {
+            let mut vex = vex::create_vex(&bom, &db)?;
+            if let Some(vex) = &mut vex {
+                exploitability.annotate(&mut vex.bom);
+                suppressions.apply(&mut vex.bom, &today);
+            }
+            with_vex.push(bom);
+            with_vex.extend(vex);
+        }
+        with_vex
+    } else {
+        boms
+    };
+
+    let boms = match &args.anonymize {
+        Some(salt) => {
+            let anonymizer = Anonymizer::new(salt.as_str());
+            boms.into_iter()
+                .map(|mut bom| {
+                    // the Purls of path and git dependencies look like those of crates on crates.io
+                    match &bom.published_refs {
+                        Some(published) => anonymizer.apply_with(&mut bom.bom, |component| {
+                            component
+                                .bom_ref
+                                .as_ref()
+                                .map_or(false, |bom_ref| published.contains(bom_ref))
+                        }),
+                        None => anonymizer.apply(&mut bom.bom),
+                    }
+                    bom
+                })
+                .collect()
+        }
+        None => boms,
+    };
+
+    let boms = place_sboms(args, boms)?;
+
+    let thresholds = CoverageThresholds {
+        license: args.require_license_coverage,
+        hash: args.require_hash_coverage,
+    };
+    let mut insufficient = 0;
+    for bom in &boms {
+        let shortfalls = thresholds.shortfalls(&BomStats::new(&bom.bom));
+        for shortfall in &shortfalls {
+            log::error!("{}: {}", bom.output_path().display(), shortfall);
+        }
+        if !shortfalls.is_empty() {
+            insufficient += 1;
+        }
+    }
+    if insufficient > 0 {
+        return Err(CheckFailure::new(
+            ErrorCode::InsufficientCoverage,
+            format!(
+                "{} SBOM(s) do not reach the required coverage",
+                insufficient
+            ),
+        )
+        .into());
+    }
+
+    let signing_key = match &args.sign_key {
+        Some(path) => {
+            for bom in &boms {
+                if bom.sbom_config.spec_version() == SpecVersion::V1_3 {
+                    return Err(CheckFailure::new(
+                        ErrorCode::Signing,
+                        format!(
+                            "--sign-key needs version 1.4 or later of the specification, but {} is written in version 1.3",
+                            bom.output_path().display()
+                        ),
+                    )
+                    .into());
+                }
+            }
+            let pem = std::fs::read_to_string(path).map_err(|error| {
+                CheckFailure::new(
+                    ErrorCode::Signing,
+                    format!("Failed to read the key {}: {}", path.display(), error),
+                )
+            })?;
+            Some(SigningKey::from_pem(&pem)?)
+        }
+        None => None,
+    };
+
     log::trace!("SBOM output started");
+    let started = PhaseStart::now();
+    let _lock = match target_dir {
+        Some(target_dir) if !args.no_lock => Some(output::lock_workspace(target_dir, config)?),
+        _ => None,
+    };
     for bom in boms {
-        bom.write_to_file()?;
+        summary.files.push(bom.output_summary());
+        bom.write_to_file(args.backup, signing_key.as_ref())?;
     }
+    summary.record_phase("output", started);
     log::trace!("SBOM output finished");
 
+    #[cfg(feature = "memory-stats")]
+    if let Some(memory) = &mut summary.memory {
+        memory.peak_rss_bytes = cargo_cyclonedx::memory::peak_rss_bytes();
+        eprint!("{}", memory);
+    }
+
+    if let Some(path) = &args.summary {
+        if let Ok(warnings) = warnings.lock() {
+            summary.warnings = warnings.clone();
+        }
+        summary.write_to(path)?;
+    }
+
+    Ok(())
+}
+
+/// Places the SBOMs in the output directory, naming the colliding ones after their packages
+fn place_sboms(args: &Args, boms: Vec<GeneratedSbom>) -> anyhow::Result<Vec<GeneratedSbom>> {
+    let mut boms: Vec<GeneratedSbom> = match &args.output_dir {
+        Some(dir) => {
+            // a dry run does not create the directory
+            let dir = if args.dry_run {
+                std::env::current_dir()?.join(dir)
+            } else {
+                output::canonical_output_dir(dir)?
+            };
+            boms.into_iter()
+                .map(|bom| GeneratedSbom {
+                    output_dir: Some(dir.clone()),
+                    ..bom
+                })
+                .collect()
+        }
+        None => boms,
+    };
+    output::name_colliding_after_packages(&mut boms)?;
+    Ok(boms)
+}
+
+fn init(
+    manifest_path: &Path,
+    defaults: bool,
+    workspace: bool,
+    dry_run: bool,
+) -> anyhow::Result<()> {
+    let target = if workspace {
+        InitTarget::Workspace
+    } else {
+        InitTarget::Package
+    };
+
+    let manifest = std::fs::read_to_string(manifest_path)?;
+    let config = if defaults {
+        init::default_config()
+    } else {
+        init::prompt_config(&mut io::stdin().lock(), &mut io::stdout())?
+    };
+
+    let manifest = init::add_config_to_manifest(&manifest, target, &config)?;
+    if dry_run {
+        println!("Would write {}:\n{}", manifest_path.display(), manifest);
+        return Ok(());
+    }
+    std::fs::write(manifest_path, manifest)?;
+
+    println!(
+        "Added [{}.metadata.cyclonedx] to {}",
+        target,
+        manifest_path.display()
+    );
+
     Ok(())
 }
 
-fn setup_logging(args: &Args, config: &mut Config) -> anyhow::Result<()> {
+/// Forwards the options that also apply to cargo build
+fn build_args(args: &Args, profile: &BuildProfile, cargo_args: &[String]) -> Vec<String> {
+    let flags = [
+        (args.frozen, "--frozen"),
+        (args.locked, "--locked"),
+        (args.offline, "--offline"),
+        (args.quiet, "--quiet"),
+    ];
+
+    flags
+        .iter()
+        .filter(|(enabled, _)| *enabled)
+        .map(|(_, flag)| flag.to_string())
+        .chain(vec!["--profile".to_string(), profile.to_string()])
+        .chain(cargo_args.iter().cloned())
+        .collect()
+}
+
+fn setup_logging(args: &Args, config: &mut Config) -> anyhow::Result<Arc<Mutex<Vec<String>>>> {
     let mut builder = Builder::new();
 
     // default cargo internals to quiet unless overridden via an environment variable
@@ -106,22 +758,29 @@ fn setup_logging(args: &Args, config: &mut Config) -> anyhow::Result<()> {
     builder.filter_level(level_filter);
 
     builder.parse_default_env(); // allow overriding CLI arguments
-    builder.try_init()?;
+    let logger = builder.build();
 
-    // configure logging level of cargo to match what was passed via CLI
+    // warnings are always collected for the summary, even if they are not printed
+    let max_level = logger.filter().max(LevelFilter::Warn);
+    let logger = WarningCollector::new(logger);
+    let warnings = logger.warnings();
+    log::set_boxed_logger(Box::new(logger))?;
+    log::set_max_level(max_level);
+
+    // configure logging level and network/lockfile behaviour of cargo to match what was passed via CLI
     config.configure(
         args.verbose as u32,
         args.quiet,
         None,
-        false,
-        false,
-        false,
+        args.frozen,
+        args.locked,
+        args.offline,
         &None,
         &[],
         &[],
     )?;
 
-    Ok(())
+    Ok(warnings)
 }
 
 fn locate_manifest(args: &Args) -> Result<PathBuf, io::Error> {
@@ -0,0 +1,190 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+use serde::Serialize;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// Allocator that counts the bytes allocated through it on top of the system allocator
+///
+/// The counts are only meaningful if it is installed as the `#[global_allocator]`.
+pub struct CountingAllocator;
+
+fn record_allocation(size: usize) {
+    ALLOCATED.fetch_add(size, Ordering::Relaxed);
+    let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(current, Ordering::Relaxed);
+}
+
+fn record_deallocation(size: usize) {
+    CURRENT.fetch_sub(size, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record_allocation(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record_allocation(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record_deallocation(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            record_deallocation(layout.size());
+            record_allocation(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// Allocation counters at the start of a phase
+#[derive(Debug, Clone, Copy)]
+pub struct Snapshot {
+    allocated: usize,
+}
+
+impl Snapshot {
+    /// Takes a snapshot and restarts tracking the peak heap size, so phases must not overlap
+    pub fn take() -> Self {
+        PEAK.store(CURRENT.load(Ordering::Relaxed), Ordering::Relaxed);
+        Self {
+            allocated: ALLOCATED.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Memory used since the snapshot was taken
+    pub fn usage(&self) -> PhaseMemory {
+        PhaseMemory {
+            allocated_bytes: ALLOCATED.load(Ordering::Relaxed) - self.allocated,
+            peak_heap_bytes: PEAK.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Memory used in a phase of the run
+///
+/// * `allocated_bytes` - Total size of the allocations made in the phase, including those that
+///   were freed again
+/// * `peak_heap_bytes` - Largest size of the heap during the phase
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct PhaseMemory {
+    pub allocated_bytes: usize,
+    pub peak_heap_bytes: usize,
+}
+
+/// Memory usage of a `cargo cyclonedx` run
+///
+/// * `peak_rss_bytes` - Largest resident set size of the process, where the OS reports it
+/// * `phases` - Memory used in each phase
+#[derive(Debug, Default, Serialize)]
+pub struct MemoryReport {
+    pub peak_rss_bytes: Option<u64>,
+    pub phases: BTreeMap<&'static str, PhaseMemory>,
+}
+
+impl fmt::Display for MemoryReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.peak_rss_bytes {
+            Some(bytes) => writeln!(f, "Peak RSS: {}", Mebibytes(bytes as usize))?,
+            None => writeln!(f, "Peak RSS: unknown")?,
+        }
+        for (phase, memory) in &self.phases {
+            writeln!(
+                f,
+                "{}: allocated {}, peak heap {}",
+                phase,
+                Mebibytes(memory.allocated_bytes),
+                Mebibytes(memory.peak_heap_bytes)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+struct Mebibytes(usize);
+
+impl fmt::Display for Mebibytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.1} MiB", self.0 as f64 / (1024.0 * 1024.0))
+    }
+}
+
+/// Largest resident set size of the process so far, which is only known on Linux
+pub fn peak_rss_bytes() -> Option<u64> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    parse_peak_rss(&status)
+}
+
+fn parse_peak_rss(status: &str) -> Option<u64> {
+    let kilobytes = status
+        .lines()
+        .find_map(|line| line.strip_prefix("VmHWM:"))?
+        .trim()
+        .strip_suffix("kB")?
+        .trim()
+        .parse::<u64>()
+        .ok()?;
+    Some(kilobytes * 1024)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_should_count_the_allocations_since_the_snapshot() {
+        let snapshot = Snapshot::take();
+        let layout = Layout::from_size_align(4096, 8).expect("Invalid layout");
+        unsafe {
+            let ptr = CountingAllocator.alloc(layout);
+            assert!(!ptr.is_null());
+            CountingAllocator.dealloc(ptr, layout);
+        }
+
+        let usage = snapshot.usage();
+        assert!(usage.allocated_bytes >= 4096);
+        assert!(usage.peak_heap_bytes >= 4096);
+    }
+
+    #[test]
+    fn it_should_parse_the_peak_rss() {
+        let status = "Name:\tcargo-cyclonedx\nVmPeak:\t  20000 kB\nVmHWM:\t    1536 kB\nVmRSS:\t    1024 kB\n";
+        assert_eq!(parse_peak_rss(status), Some(1536 * 1024));
+        assert_eq!(parse_peak_rss("Name:\tcargo-cyclonedx\n"), None);
+    }
+}
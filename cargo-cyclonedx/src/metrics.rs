@@ -0,0 +1,206 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! A hook for the metrics of the generation, which embedders implement to feed them into the
+//! metrics system they run, such as Prometheus or OpenTelemetry
+//!
+//! The crate reports the durations of the phases of the generation and a few counters to the
+//! [`Metrics`] set with [`set_metrics`], and nothing anywhere else. Like a logger, the hook is set
+//! once for the process, so that it reaches every generation without being passed along. Until it
+//! is set, the metrics are dropped.
+//!
+//! ```
+//! use cargo_cyclonedx::metrics::{set_metrics, Counter, Metrics, Phase};
+//! use std::time::Duration;
+//!
+//! struct Printed;
+//!
+//! impl Metrics for Printed {
+//!     fn phase(&self, phase: Phase, duration: Duration) {
+//!         println!("{} took {} ms", phase, duration.as_millis());
+//!     }
+//!
+//!     fn count(&self, counter: Counter, value: u64) {
+//!         println!("{} += {}", counter, value);
+//!     }
+//! }
+//!
+//! set_metrics(Box::new(Printed)).expect("The metrics were set already");
+//! ```
+
+use once_cell::sync::OnceCell;
+use std::fmt;
+use std::time::Duration;
+use thiserror::Error;
+
+/// A phase of the generation whose duration is reported
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Phase {
+    /// Reading the manifests and resolving the dependencies of the workspace
+    Metadata,
+    /// Adding the hashes of the `.crate` files of the components, once for each SBOM
+    Hashing,
+    /// Running the other enrichers on the components, once for each SBOM
+    Enrichment,
+    /// Serializing an SBOM into its file
+    Serialize,
+}
+
+impl Phase {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Phase::Metadata => "metadata",
+            Phase::Hashing => "hashing",
+            Phase::Enrichment => "enrichment",
+            Phase::Serialize => "serialize",
+        }
+    }
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A counter that the generation increases
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Counter {
+    /// SBOMs created
+    Sboms,
+    /// Top-level components listed in the created SBOMs
+    Components,
+    /// Components an enricher left out because it exceeded its timeout
+    SkippedEnrichments,
+}
+
+impl Counter {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Counter::Sboms => "sboms",
+            Counter::Components => "components",
+            Counter::SkippedEnrichments => "skipped_enrichments",
+        }
+    }
+}
+
+impl fmt::Display for Counter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Receives the metrics of the generation
+///
+/// Both methods do nothing by default, so an implementation only needs the ones it records. They
+/// are called from the thread that generates, which may be any thread of the process.
+pub trait Metrics: Send + Sync {
+    /// Records that `phase` took `duration`
+    fn phase(&self, _phase: Phase, _duration: Duration) {}
+
+    /// Increases `counter` by `value`
+    fn count(&self, _counter: Counter, _value: u64) {}
+}
+
+/// The hook until one is set, which drops the metrics
+struct NoMetrics;
+
+impl Metrics for NoMetrics {}
+
+static METRICS: OnceCell<Box<dyn Metrics>> = OnceCell::new();
+
+/// Sets the hook that receives the metrics of every generation in the process, which can only be
+/// done once
+pub fn set_metrics(metrics: Box<dyn Metrics>) -> Result<(), SetMetricsError> {
+    METRICS.set(metrics).map_err(|_| SetMetricsError)
+}
+
+/// The hook that receives the metrics, which drops them if none was set
+pub fn metrics() -> &'static dyn Metrics {
+    match METRICS.get() {
+        Some(metrics) => metrics.as_ref(),
+        None => &NoMetrics,
+    }
+}
+
+#[derive(Error, Debug)]
+#[error("The metrics hook was set already")]
+pub struct SetMetricsError;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::config::SbomConfig;
+    use crate::generator::SbomGenerator;
+    use assert_fs::prelude::*;
+    use cargo::core::Workspace;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Default)]
+    struct Recorded {
+        phases: Mutex<Vec<Phase>>,
+        counts: Mutex<Vec<(Counter, u64)>>,
+    }
+
+    impl Metrics for Arc<Recorded> {
+        fn phase(&self, phase: Phase, _duration: Duration) {
+            self.phases.lock().expect("Poisoned").push(phase);
+        }
+
+        fn count(&self, counter: Counter, value: u64) {
+            self.counts.lock().expect("Poisoned").push((counter, value));
+        }
+    }
+
+    #[test]
+    fn it_should_report_the_metrics_of_the_generation() {
+        let recorded = Arc::new(Recorded::default());
+        set_metrics(Box::new(recorded.clone())).expect("Failed to set the metrics");
+        assert!(set_metrics(Box::new(NoMetrics)).is_err());
+
+        let tmp_dir = assert_fs::TempDir::new().expect("Failed to create a temporary directory");
+        tmp_dir
+            .child("Cargo.toml")
+            .write_str(r#"package = { name = "pkg", version = "0.0.0" }"#)
+            .expect("Failed to write the manifest");
+        tmp_dir
+            .child("src/lib.rs")
+            .touch()
+            .expect("Failed to write the library");
+        let config = cargo::Config::default().expect("Failed to create the cargo config");
+        let ws = Workspace::new(tmp_dir.child("Cargo.toml").path(), &config)
+            .expect("Failed to open the workspace");
+        SbomGenerator::create_sboms(ws, &SbomConfig::empty_config())
+            .expect("Failed to generate the SBOMs");
+
+        // other tests may generate at the same time
+        let phases = recorded.phases.lock().expect("Poisoned");
+        assert!(phases.contains(&Phase::Metadata));
+        assert!(phases.contains(&Phase::Hashing));
+        assert!(phases.contains(&Phase::Enrichment));
+        let counts = recorded.counts.lock().expect("Poisoned");
+        assert!(counts.contains(&(Counter::Sboms, 1)));
+        assert_eq!(Phase::Serialize.to_string(), "serialize");
+        assert_eq!(
+            Counter::SkippedEnrichments.to_string(),
+            "skipped_enrichments"
+        );
+    }
+}
@@ -0,0 +1,286 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Accounting of the components the configuration leaves out of an SBOM
+//!
+//! Each reason is recorded as a `cargo-cyclonedx:omitted:<reason>` property of the metadata with
+//! the number of components it left out, and the SBOM is marked as an `incomplete` composition of
+//! the component it describes, so that consumers can tell an SBOM that is incomplete on purpose.
+//! An SBOM that nothing was left out of is marked as a `complete` composition instead.
+
+use cyclonedx_bom::models::bom::Bom;
+use cyclonedx_bom::models::composition::{AggregateType, BomReference, Composition, Compositions};
+use cyclonedx_bom::models::metadata::Metadata;
+use cyclonedx_bom::models::property::Properties;
+use std::collections::BTreeMap;
+use std::fmt;
+
+pub const OMITTED_PROPERTY_PREFIX: &str = "cargo-cyclonedx:omitted:";
+
+/// Why components were left out of an SBOM
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum OmissionReason {
+    /// Dev-dependencies of the workspace, which are not listed with only the top-level dependencies
+    DevDependency,
+    /// Build-dependencies of the workspace, which are not listed with only the top-level dependencies
+    BuildDependency,
+    /// Dependencies of dependencies, which are not listed with only the top-level dependencies
+    Transitive,
+    /// Optional dependencies the default features do not enable, which are not listed for a
+    /// library
+    OptionalDependency,
+    /// Dependencies that were not compiled by `cargo cyclonedx build`
+    NotBuilt,
+    /// Dependencies that the feature set of an SBOM of the feature matrix does not activate
+    DisabledFeature,
+    /// Packages that could not be retrieved from their registry, with `on_error` set to skip
+    /// or warn for the registry
+    Unavailable,
+    /// Components that did not match `--filter`
+    Filter,
+}
+
+impl fmt::Display for OmissionReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OmissionReason::DevDependency => write!(f, "dev-dependency"),
+            OmissionReason::BuildDependency => write!(f, "build-dependency"),
+            OmissionReason::Transitive => write!(f, "transitive"),
+            OmissionReason::OptionalDependency => write!(f, "optional-dependency"),
+            OmissionReason::NotBuilt => write!(f, "not-built"),
+            OmissionReason::DisabledFeature => write!(f, "disabled-feature"),
+            OmissionReason::Unavailable => write!(f, "unavailable"),
+            OmissionReason::Filter => write!(f, "filter"),
+        }
+    }
+}
+
+/// The number of components left out of an SBOM for each reason
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Omissions(BTreeMap<OmissionReason, usize>);
+
+impl Omissions {
+    pub fn add(&mut self, reason: OmissionReason, count: usize) {
+        if count > 0 {
+            *self.0.entry(reason).or_default() += count;
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Adds the omissions to those already recorded in `bom`, and marks it as an `incomplete`
+    /// composition of its metadata component, or as a `complete` one if nothing was left out of it
+    pub fn record(&self, bom: &mut Bom) {
+        if self.is_empty() {
+            mark_complete(bom);
+            return;
+        }
+
+        let metadata = bom.metadata.get_or_insert_with(Metadata::default);
+        let properties = metadata
+            .properties
+            .get_or_insert_with(|| Properties(Vec::new()));
+        for (reason, count) in &self.0 {
+            let name = format!("{}{}", OMITTED_PROPERTY_PREFIX, reason);
+            let recorded = properties
+                .0
+                .iter()
+                .find(|property| property.name == name)
+                .and_then(|property| property.value.to_string().parse::<usize>().ok())
+                .unwrap_or_default();
+            properties.upsert(&name, &(recorded + count).to_string());
+        }
+
+        let assemblies = metadata
+            .component
+            .as_ref()
+            .and_then(|component| component.bom_ref.clone());
+        let compositions = bom
+            .compositions
+            .get_or_insert_with(|| Compositions(Vec::new()));
+        // an SBOM marked as complete before the omissions no longer is
+        compositions.0.retain(|composition| {
+            composition.aggregate != AggregateType::Complete
+                || !is_composition_of(composition, assemblies.as_deref())
+        });
+        if !compositions
+            .0
+            .iter()
+            .any(|composition| composition.aggregate == AggregateType::Incomplete)
+        {
+            let composition = Composition::new(AggregateType::Incomplete);
+            compositions.0.push(match assemblies {
+                Some(bom_ref) => composition.with_assemblies(vec![BomReference::new(bom_ref)]),
+                None => composition,
+            });
+        }
+    }
+}
+
+/// Marks `bom` as a `complete` composition of its metadata component, unless it is already
+/// marked as complete or incomplete
+fn mark_complete(bom: &mut Bom) {
+    let bom_ref = match bom
+        .metadata
+        .as_ref()
+        .and_then(|metadata| metadata.component.as_ref())
+        .and_then(|component| component.bom_ref.clone())
+    {
+        Some(bom_ref) => bom_ref,
+        None => return,
+    };
+
+    let compositions = bom
+        .compositions
+        .get_or_insert_with(|| Compositions(Vec::new()));
+    if !compositions.0.iter().any(|composition| {
+        matches!(
+            composition.aggregate,
+            AggregateType::Complete | AggregateType::Incomplete
+        )
+    }) {
+        compositions.0.push(
+            Composition::new(AggregateType::Complete)
+                .with_assemblies(vec![BomReference::new(bom_ref)]),
+        );
+    }
+}
+
+/// Whether `composition` is the composition of the component `bom_ref` alone
+fn is_composition_of(composition: &Composition, bom_ref: Option<&str>) -> bool {
+    match (&composition.assemblies, bom_ref) {
+        (Some(assemblies), Some(bom_ref)) => {
+            assemblies.len() == 1 && assemblies[0] == BomReference::new(bom_ref)
+        }
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use cyclonedx_bom::models::component::{Classification, Component};
+
+    #[test]
+    fn it_should_record_the_omissions() {
+        let mut metadata = Metadata::default();
+        metadata.component = Some(Component::new(
+            Classification::Library,
+            "a",
+            "1.0.0",
+            Some("pkg:cargo/a@1.0.0".to_string()),
+        ));
+        let mut bom = Bom::default();
+        bom.metadata = Some(metadata);
+
+        let mut omissions = Omissions::default();
+        omissions.add(OmissionReason::Transitive, 3);
+        omissions.add(OmissionReason::DevDependency, 1);
+        omissions.add(OmissionReason::NotBuilt, 0);
+        omissions.record(&mut bom);
+
+        let mut filtered = Omissions::default();
+        filtered.add(OmissionReason::Filter, 2);
+        filtered.add(OmissionReason::Transitive, 1);
+        filtered.record(&mut bom);
+
+        let metadata = bom.metadata.unwrap();
+        let properties: Vec<_> = metadata
+            .properties
+            .unwrap()
+            .0
+            .into_iter()
+            .map(|property| (property.name, property.value.to_string()))
+            .collect();
+        assert_eq!(
+            properties,
+            vec![
+                (
+                    "cargo-cyclonedx:omitted:dev-dependency".to_string(),
+                    "1".to_string()
+                ),
+                (
+                    "cargo-cyclonedx:omitted:filter".to_string(),
+                    "2".to_string()
+                ),
+                (
+                    "cargo-cyclonedx:omitted:transitive".to_string(),
+                    "4".to_string()
+                ),
+            ]
+        );
+
+        assert_eq!(
+            bom.compositions,
+            Some(Compositions(vec![Composition::new(
+                AggregateType::Incomplete
+            )
+            .with_assemblies(vec![BomReference::new("pkg:cargo/a@1.0.0")])]))
+        );
+    }
+
+    #[test]
+    fn it_should_mark_sboms_without_omissions_as_complete() {
+        let mut metadata = Metadata::default();
+        metadata.component = Some(Component::new(
+            Classification::Library,
+            "a",
+            "1.0.0",
+            Some("pkg:cargo/a@1.0.0".to_string()),
+        ));
+        let mut bom = Bom::default();
+        bom.metadata = Some(metadata);
+
+        Omissions::default().record(&mut bom);
+        Omissions::default().record(&mut bom);
+
+        assert_eq!(bom.metadata.as_ref().unwrap().properties, None);
+        assert_eq!(
+            bom.compositions,
+            Some(Compositions(vec![Composition::new(
+                AggregateType::Complete
+            )
+            .with_assemblies(vec![BomReference::new("pkg:cargo/a@1.0.0")])]))
+        );
+
+        let mut filtered = Omissions::default();
+        filtered.add(OmissionReason::Filter, 1);
+        filtered.record(&mut bom);
+        Omissions::default().record(&mut bom);
+
+        assert_eq!(
+            bom.compositions,
+            Some(Compositions(vec![Composition::new(
+                AggregateType::Incomplete
+            )
+            .with_assemblies(vec![BomReference::new("pkg:cargo/a@1.0.0")])]))
+        );
+    }
+
+    #[test]
+    fn it_should_not_mark_sboms_without_a_metadata_component() {
+        let mut bom = Bom::default();
+        Omissions::default().record(&mut bom);
+
+        assert_eq!(bom.metadata, None);
+        assert_eq!(bom.compositions, None);
+    }
+}
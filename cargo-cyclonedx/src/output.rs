@@ -0,0 +1,492 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+use crate::config::{OutputOptions, Pattern, Prefix};
+use crate::generator::GeneratedSbom;
+use cargo::util::{FileLock, Filesystem};
+use cargo::Config;
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// Lock file shared by all `cargo cyclonedx` runs in a workspace, relative to its target
+/// directory
+pub const LOCK_FILE: &str = "cyclonedx/.lock";
+
+/// Prefix that lifts the `MAX_PATH` limit of Windows file APIs
+const VERBATIM_PREFIX: &str = r"\\?\";
+/// Verbatim form of a `\\server\share` UNC path
+const VERBATIM_UNC_PREFIX: &str = r"\\?\UNC\";
+
+/// Creates the output directory if needed and returns it as an absolute path
+///
+/// On Windows the path is resolved through the file system so that drive-relative paths,
+/// mapped drives, and UNC shares all resolve, but it is returned without the verbatim `\\?\`
+/// prefix so that it stays readable in logs and summaries.
+pub fn canonical_output_dir(dir: &Path) -> Result<PathBuf, OutputDirError> {
+    std::fs::create_dir_all(dir).map_err(|source| OutputDirError::CreateError {
+        path: dir.to_path_buf(),
+        source,
+    })?;
+
+    let canonical = dir
+        .canonicalize()
+        .map_err(|source| OutputDirError::CanonicalizeError {
+            path: dir.to_path_buf(),
+            source,
+        })?;
+
+    Ok(match canonical.to_str().and_then(strip_verbatim) {
+        Some(stripped) => PathBuf::from(stripped),
+        None => canonical,
+    })
+}
+
+/// Returns the form of `path` to pass to file system calls
+///
+/// On Windows, absolute paths are converted to their verbatim form so that files can be created
+/// below deep `target` directories and on UNC shares beyond the 260 character `MAX_PATH` limit.
+/// Other platforms have no such limit and get the path unchanged.
+pub fn io_path(path: &Path) -> PathBuf {
+    if cfg!(windows) {
+        if let Some(extended) = path.to_str().and_then(to_verbatim) {
+            return PathBuf::from(extended);
+        }
+    }
+
+    path.to_path_buf()
+}
+
+/// Converts an absolute Windows path to its verbatim form
+///
+/// Verbatim paths are not normalized by Windows, so relative paths and paths containing `.` or
+/// `..` are left alone, as are paths that are already verbatim.
+fn to_verbatim(path: &str) -> Option<String> {
+    if path.starts_with(VERBATIM_PREFIX) || path.split(['\\', '/']).any(|c| c == "." || c == "..") {
+        return None;
+    }
+
+    let path = path.replace('/', "\\");
+    if let Some(unc) = path.strip_prefix(r"\\") {
+        Some(format!("{}{}", VERBATIM_UNC_PREFIX, unc))
+    } else if is_drive_absolute(&path) {
+        Some(format!("{}{}", VERBATIM_PREFIX, path))
+    } else {
+        None
+    }
+}
+
+/// Converts a verbatim Windows path back to its conventional form
+fn strip_verbatim(path: &str) -> Option<String> {
+    if let Some(unc) = path.strip_prefix(VERBATIM_UNC_PREFIX) {
+        Some(format!(r"\\{}", unc))
+    } else {
+        path.strip_prefix(VERBATIM_PREFIX)
+            .filter(|path| is_drive_absolute(path))
+            .map(ToString::to_string)
+    }
+}
+
+fn is_drive_absolute(path: &str) -> bool {
+    let bytes = path.as_bytes();
+    bytes.len() >= 3 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':' && bytes[2] == b'\\'
+}
+
+/// A file that is written under a temporary name and only moved to its final location once it
+/// is complete
+///
+/// Readers of the final path see either the previous file or the complete new one, never a
+/// partially written file. If the `AtomicFile` is dropped without being committed, for example
+/// because serialization failed, the temporary file is removed and the previous file is kept.
+pub struct AtomicFile {
+    path: PathBuf,
+    temp_path: PathBuf,
+    // closed before the rename, which Windows does not allow for open files
+    file: Option<File>,
+    committed: bool,
+}
+
+impl AtomicFile {
+    /// Creates a temporary file in the directory of `path`, so that it can be renamed into place
+    /// without crossing file systems
+    ///
+    /// The temporary file is created like any other new file, so the final file gets the same
+    /// permissions as if it had been written directly.
+    pub fn create(path: &Path) -> std::io::Result<Self> {
+        let mut temp_name = std::ffi::OsString::from(".");
+        temp_name.push(path.file_name().unwrap_or_default());
+        temp_name.push(format!(".{}.tmp", std::process::id()));
+        let temp_path = path.with_file_name(temp_name);
+
+        let file = std::fs::OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(io_path(&temp_path))?;
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            temp_path,
+            file: Some(file),
+            committed: false,
+        })
+    }
+
+    pub fn file(&mut self) -> &mut File {
+        self.file
+            .as_mut()
+            .expect("The file is only closed when it is committed")
+    }
+
+    /// Flushes the file to disk and renames it to its final path
+    ///
+    /// With `backup`, an existing file at the final path is first preserved as `<name>.bak`,
+    /// replacing an older backup.
+    pub fn commit(mut self, backup: bool) -> std::io::Result<()> {
+        if let Some(file) = self.file.take() {
+            file.sync_all()?;
+        }
+
+        let path = io_path(&self.path);
+        if backup && path.exists() {
+            let backup_path = io_path(&backup_path(&self.path));
+            match std::fs::remove_file(&backup_path) {
+                Err(e) if e.kind() != std::io::ErrorKind::NotFound => return Err(e),
+                _ => {}
+            }
+            // a hard link keeps the current file in place until the rename replaces it
+            if std::fs::hard_link(&path, &backup_path).is_err() {
+                std::fs::copy(&path, &backup_path)?;
+            }
+        }
+
+        std::fs::rename(io_path(&self.temp_path), &path)?;
+        self.committed = true;
+        Ok(())
+    }
+}
+
+impl Drop for AtomicFile {
+    fn drop(&mut self) {
+        if !self.committed {
+            self.file.take();
+            let _ = std::fs::remove_file(io_path(&self.temp_path));
+        }
+    }
+}
+
+/// Location the previous version of `path` is kept at when backups are enabled
+pub fn backup_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".bak");
+    path.with_file_name(name)
+}
+
+/// Waits for exclusive access to the files written by `cargo cyclonedx` in the workspace with the
+/// given target directory
+///
+/// Parallel runs in the same workspace, such as jobs of a CI matrix, take turns writing their
+/// SBOMs instead of interleaving writes to the same files. The lock is released when the returned
+/// guard is dropped. Like cargo's own locks, a message is printed while waiting for another run.
+pub fn lock_workspace(target_dir: &Filesystem, config: &Config) -> Result<FileLock, LockError> {
+    target_dir
+        .open_rw(LOCK_FILE, config, "cyclonedx output")
+        .map_err(|error| LockError {
+            path: target_dir.as_path_unlocked().join(LOCK_FILE),
+            error,
+        })
+}
+
+/// Names the SBOMs that would be written to the same file after their packages, e.g. the
+/// `bom.xml` of every workspace member in a shared `--output-dir`
+///
+/// Fails if two SBOMs would still be written to the same file, since one would silently
+/// overwrite the other.
+pub fn name_colliding_after_packages(
+    boms: &mut [GeneratedSbom],
+) -> Result<(), OutputCollisionError> {
+    let colliding = colliding_paths(boms);
+    for bom in boms.iter_mut() {
+        let output_options = bom.sbom_config.output_options();
+        if output_options.prefix == Prefix::Pattern(Pattern::Package)
+            || !colliding.contains_key(&bom.output_path())
+        {
+            continue;
+        }
+        let path = bom.output_path();
+        bom.sbom_config.output_options = Some(OutputOptions {
+            prefix: Prefix::Pattern(Pattern::Package),
+            ..output_options
+        });
+        log::warn!(
+            "Several SBOMs would be written to {}, writing the SBOM of {} to {} instead",
+            path.display(),
+            bom.package_name,
+            bom.output_path().display()
+        );
+    }
+
+    match colliding_paths(boms).into_iter().next() {
+        Some((path, packages)) => Err(OutputCollisionError { path, packages }),
+        None => Ok(()),
+    }
+}
+
+/// Output paths shared by several SBOMs, with the names of their packages
+fn colliding_paths(boms: &[GeneratedSbom]) -> BTreeMap<PathBuf, Vec<String>> {
+    let mut packages = BTreeMap::<PathBuf, Vec<String>>::new();
+    for bom in boms {
+        packages
+            .entry(bom.output_path())
+            .or_default()
+            .push(bom.package_name.clone());
+    }
+    packages.retain(|_, packages| packages.len() > 1);
+    packages
+}
+
+#[derive(Error, Debug)]
+pub enum OutputDirError {
+    #[error("Failed to create output directory {}", .path.display())]
+    CreateError {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("Failed to resolve output directory {}", .path.display())]
+    CanonicalizeError {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+}
+
+#[derive(Error, Debug)]
+#[error("Could not lock {}, pass --no-lock to write without locking", .path.display())]
+pub struct LockError {
+    path: PathBuf,
+    #[source]
+    error: anyhow::Error,
+}
+
+#[derive(Error, Debug)]
+#[error(
+    "The SBOMs of {} would all be written to {}",
+    .packages.join(", "),
+    .path.display()
+)]
+pub struct OutputCollisionError {
+    path: PathBuf,
+    packages: Vec<String>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::io::Write;
+
+    #[test]
+    fn it_should_convert_absolute_windows_paths_to_verbatim_paths() {
+        assert_eq!(
+            to_verbatim(r"C:\build\target\bom.xml").as_deref(),
+            Some(r"\\?\C:\build\target\bom.xml")
+        );
+        assert_eq!(
+            to_verbatim(r"\\server\share\bom.xml").as_deref(),
+            Some(r"\\?\UNC\server\share\bom.xml")
+        );
+        assert_eq!(
+            to_verbatim("D:/build/bom.xml").as_deref(),
+            Some(r"\\?\D:\build\bom.xml")
+        );
+    }
+
+    #[test]
+    fn it_should_leave_paths_windows_would_have_to_normalize() {
+        for path in [
+            r"\\?\C:\build\bom.xml",
+            r"build\bom.xml",
+            r"C:build\bom.xml",
+            r"C:\build\..\bom.xml",
+            r"\\server\share\.\bom.xml",
+        ] {
+            assert_eq!(to_verbatim(path), None, "{}", path);
+        }
+    }
+
+    #[test]
+    fn it_should_strip_verbatim_prefixes() {
+        assert_eq!(
+            strip_verbatim(r"\\?\C:\build").as_deref(),
+            Some(r"C:\build")
+        );
+        assert_eq!(
+            strip_verbatim(r"\\?\UNC\server\share").as_deref(),
+            Some(r"\\server\share")
+        );
+        assert_eq!(strip_verbatim(r"\\?\Volume{1234}\build"), None);
+        assert_eq!(strip_verbatim("/home/user"), None);
+    }
+
+    #[test]
+    fn it_should_create_and_resolve_the_output_directory() {
+        let tmp_dir = assert_fs::TempDir::new().expect("Failed to create temporary directory");
+        let dir = tmp_dir.path().join("nested").join("..").join("sboms");
+
+        let actual = canonical_output_dir(&dir).expect("Failed to resolve output directory");
+
+        assert!(actual.is_absolute());
+        assert!(actual.is_dir());
+        assert_eq!(actual.file_name(), Some("sboms".as_ref()));
+        assert!(!actual.to_string_lossy().starts_with(VERBATIM_PREFIX));
+    }
+
+    #[test]
+    fn it_should_only_replace_the_file_when_committed() {
+        let tmp_dir = assert_fs::TempDir::new().expect("Failed to create temporary directory");
+        let path = tmp_dir.path().join("bom.xml");
+        std::fs::write(&path, "previous").expect("Failed to write file");
+
+        let mut abandoned = AtomicFile::create(&path).expect("Failed to create file");
+        abandoned
+            .file()
+            .write_all(b"partial")
+            .expect("Failed to write");
+        drop(abandoned);
+
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "previous");
+        assert_eq!(std::fs::read_dir(tmp_dir.path()).unwrap().count(), 1);
+
+        let mut file = AtomicFile::create(&path).expect("Failed to create file");
+        file.file().write_all(b"complete").expect("Failed to write");
+        file.commit(false).expect("Failed to commit");
+
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "complete");
+        assert!(!backup_path(&path).exists());
+    }
+
+    #[test]
+    fn it_should_keep_a_backup_of_the_previous_file() {
+        let tmp_dir = assert_fs::TempDir::new().expect("Failed to create temporary directory");
+        let path = tmp_dir.path().join("bom.xml");
+
+        for content in ["first", "second", "third"] {
+            let mut file = AtomicFile::create(&path).expect("Failed to create file");
+            file.file()
+                .write_all(content.as_bytes())
+                .expect("Failed to write");
+            file.commit(true).expect("Failed to commit");
+        }
+
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "third");
+        assert_eq!(
+            std::fs::read_to_string(tmp_dir.path().join("bom.xml.bak")).unwrap(),
+            "second"
+        );
+    }
+
+    #[test]
+    fn it_should_hold_the_lock_until_it_is_dropped() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+        use std::sync::Arc;
+
+        let tmp_dir = assert_fs::TempDir::new().expect("Failed to create temporary directory");
+        let target_path = tmp_dir.path().join("target");
+        let config = Config::default().expect("Failed to create cargo config");
+
+        let lock =
+            lock_workspace(&Filesystem::new(target_path.clone()), &config).expect("Failed to lock");
+        assert!(target_path.join(LOCK_FILE).exists());
+
+        let acquired = Arc::new(AtomicBool::new(false));
+        let waiter = {
+            let acquired = acquired.clone();
+            std::thread::spawn(move || {
+                let config = Config::default().expect("Failed to create cargo config");
+                let _lock =
+                    lock_workspace(&Filesystem::new(target_path), &config).expect("Failed to lock");
+                acquired.store(true, Ordering::SeqCst);
+            })
+        };
+
+        std::thread::sleep(std::time::Duration::from_millis(200));
+        assert!(!acquired.load(Ordering::SeqCst));
+
+        drop(lock);
+        waiter.join().expect("Waiting thread panicked");
+        assert!(acquired.load(Ordering::SeqCst));
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn it_should_write_files_beyond_max_path() {
+        let tmp_dir = assert_fs::TempDir::new().expect("Failed to create temporary directory");
+        let dir = canonical_output_dir(tmp_dir.path()).expect("Failed to resolve directory");
+        let deep = (0..30).fold(dir, |path, _| path.join("deeply-nested-directory"));
+
+        std::fs::create_dir_all(io_path(&deep)).expect("Failed to create deep directory");
+        std::fs::write(io_path(&deep.join("bom.xml")), "<bom/>").expect("Failed to write file");
+    }
+
+    fn sbom_in(dir: &str, package_name: &str) -> GeneratedSbom {
+        GeneratedSbom {
+            bom: cyclonedx_bom::models::bom::Bom::default(),
+            manifest_path: PathBuf::from(package_name).join("Cargo.toml"),
+            package_name: package_name.to_string(),
+            sbom_config: crate::config::SbomConfig::empty_config(),
+            output_dir: Some(PathBuf::from(dir)),
+            published_refs: None,
+            feature_set: None,
+            document: crate::generator::Document::Sbom,
+        }
+    }
+
+    #[test]
+    fn it_should_name_colliding_sboms_after_their_packages() {
+        let mut boms = vec![
+            sbom_in("out", "a"),
+            sbom_in("out", "b"),
+            sbom_in("other", "c"),
+        ];
+
+        name_colliding_after_packages(&mut boms).expect("Failed to resolve collisions");
+
+        let paths: Vec<_> = boms.iter().map(GeneratedSbom::output_path).collect();
+        assert_eq!(
+            paths,
+            vec![
+                PathBuf::from("out/a.xml"),
+                PathBuf::from("out/b.xml"),
+                PathBuf::from("other/bom.xml"),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_fail_on_collisions_package_names_cannot_resolve() {
+        let mut boms = vec![sbom_in("out", "a"), sbom_in("out", "a")];
+
+        let error = name_colliding_after_packages(&mut boms).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "The SBOMs of a, a would all be written to out/a.xml"
+        );
+    }
+}
@@ -0,0 +1,432 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+//! Corrections of the supplier, author, group or license of components, read from a mapping file
+//! the organization maintains, for packages whose manifests carry wrong or missing metadata
+//!
+//! The file is either TOML, with an `[[override]]` table per rule, or CSV, with a header row
+//! naming the columns. A rule matches components by their exact Purl, by their Purl without a
+//! version, which matches every version, or by a glob on their name, and sets the fields it names.
+//! Rules apply in the order of the file, so a later rule overrides the fields of an earlier one.
+
+use cyclonedx_bom::external_models::normalized_string::NormalizedString;
+use cyclonedx_bom::external_models::spdx::SpdxExpression;
+use cyclonedx_bom::models::bom::Bom;
+use cyclonedx_bom::models::component::Component;
+use cyclonedx_bom::models::license::{LicenseChoice, Licenses};
+use cyclonedx_bom::models::organization::OrganizationalEntity;
+use globset::{Glob, GlobMatcher};
+use serde::Deserialize;
+use std::convert::TryFrom;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// The rules of a mapping file
+#[derive(Debug, Default)]
+pub struct Overrides {
+    rules: Vec<OverrideRule>,
+}
+
+/// Fields to set on the components a rule matches
+#[derive(Debug)]
+pub struct OverrideRule {
+    matcher: Matcher,
+    supplier: Option<String>,
+    author: Option<String>,
+    group: Option<String>,
+    license: Option<String>,
+}
+
+#[derive(Debug)]
+enum Matcher {
+    Purl(String),
+    Name(GlobMatcher),
+}
+
+impl Overrides {
+    /// Reads the mapping file at `path`, as CSV if its extension is `csv` and as TOML otherwise
+    pub fn read(path: &Path) -> Result<Self, OverridesError> {
+        let content = std::fs::read_to_string(path).map_err(|error| OverridesError::ReadError {
+            path: path.to_path_buf(),
+            error,
+        })?;
+        let csv = path
+            .extension()
+            .map_or(false, |extension| extension.eq_ignore_ascii_case("csv"));
+        let rules = if csv {
+            parse_csv(&content)
+        } else {
+            parse_toml(&content)
+        };
+        rules
+            .map(|rules| Self { rules })
+            .map_err(|error| OverridesError::ParseError {
+                path: path.to_path_buf(),
+                error,
+            })
+    }
+
+    /// Applies the rules to the components and the metadata component of `bom`, and returns the
+    /// number of components that were changed
+    pub fn apply(&self, bom: &mut Bom) -> usize {
+        let metadata_component = bom
+            .metadata
+            .as_mut()
+            .and_then(|metadata| metadata.component.as_mut());
+        let components = bom
+            .components
+            .iter_mut()
+            .flat_map(|components| components.0.iter_mut());
+        metadata_component
+            .into_iter()
+            .chain(components)
+            .map(|component| self.apply_to(component))
+            .filter(|changed| *changed)
+            .count()
+    }
+
+    fn apply_to(&self, component: &mut Component) -> bool {
+        let mut changed = false;
+        for rule in &self.rules {
+            if rule.matches(component) {
+                rule.apply(component);
+                changed = true;
+            }
+        }
+        changed
+    }
+}
+
+impl OverrideRule {
+    fn matches(&self, component: &Component) -> bool {
+        match &self.matcher {
+            Matcher::Purl(purl) => component.purl.as_ref().map_or(false, |component_purl| {
+                let component_purl = component_purl.to_string();
+                component_purl == *purl
+                    || (!purl.contains('@')
+                        && component_purl.split('@').next() == Some(purl.as_str()))
+            }),
+            Matcher::Name(glob) => glob.is_match(component.name.to_string()),
+        }
+    }
+
+    fn apply(&self, component: &mut Component) {
+        if let Some(supplier) = &self.supplier {
+            component.supplier = Some(OrganizationalEntity::new(supplier));
+        }
+        if let Some(author) = &self.author {
+            component.author = Some(NormalizedString::new(author));
+        }
+        if let Some(group) = &self.group {
+            component.group = Some(NormalizedString::new(group));
+        }
+        if let Some(license) = &self.license {
+            let expression = SpdxExpression::try_from(license.clone())
+                .expect("Expressions are validated on construction");
+            component.licenses = Some(Licenses(vec![LicenseChoice::Expression(expression)]));
+        }
+    }
+}
+
+/// A rule as written in either format
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawRule {
+    purl: Option<String>,
+    name: Option<String>,
+    supplier: Option<String>,
+    author: Option<String>,
+    group: Option<String>,
+    license: Option<String>,
+}
+
+impl TryFrom<RawRule> for OverrideRule {
+    type Error = String;
+
+    fn try_from(raw: RawRule) -> Result<Self, Self::Error> {
+        let matcher = match (raw.purl, raw.name) {
+            (Some(purl), None) => Matcher::Purl(purl),
+            (None, Some(name)) => Matcher::Name(
+                Glob::new(&name)
+                    .map_err(|e| format!("Invalid name pattern `{}`: {}", name, e.kind()))?
+                    .compile_matcher(),
+            ),
+            _ => return Err("Expected either a purl or a name".to_string()),
+        };
+        if raw.supplier.is_none()
+            && raw.author.is_none()
+            && raw.group.is_none()
+            && raw.license.is_none()
+        {
+            return Err("Expected a supplier, author, group or license to set".to_string());
+        }
+        if let Some(license) = &raw.license {
+            SpdxExpression::try_from(license.clone())
+                .map_err(|e| format!("Invalid license expression `{}`: {}", license, e))?;
+        }
+        Ok(Self {
+            matcher,
+            supplier: raw.supplier,
+            author: raw.author,
+            group: raw.group,
+            license: raw.license,
+        })
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TomlOverrides {
+    #[serde(rename = "override", default)]
+    rules: Vec<RawRule>,
+}
+
+fn parse_toml(content: &str) -> Result<Vec<OverrideRule>, String> {
+    let overrides: TomlOverrides = toml::from_str(content).map_err(|e| e.to_string())?;
+    overrides
+        .rules
+        .into_iter()
+        .enumerate()
+        .map(|(index, raw)| {
+            OverrideRule::try_from(raw).map_err(|e| format!("Override {}: {}", index + 1, e))
+        })
+        .collect()
+}
+
+fn parse_csv(content: &str) -> Result<Vec<OverrideRule>, String> {
+    let mut lines = content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty());
+    let header = match lines.next() {
+        Some((_, header)) => split_csv_line(header).map_err(|e| format!("Line 1: {}", e))?,
+        None => return Ok(Vec::new()),
+    };
+    for column in &header {
+        if !["purl", "name", "supplier", "author", "group", "license"].contains(&column.as_str()) {
+            return Err(format!("Unknown column `{}`", column));
+        }
+    }
+
+    lines
+        .map(|(index, line)| {
+            let line_error = |e: String| format!("Line {}: {}", index + 1, e);
+            let cells = split_csv_line(line).map_err(line_error)?;
+            if cells.len() != header.len() {
+                return Err(line_error(format!(
+                    "Expected {} cells, got {}",
+                    header.len(),
+                    cells.len()
+                )));
+            }
+            let mut raw = RawRule::default();
+            for (column, cell) in header.iter().zip(cells) {
+                if cell.is_empty() {
+                    continue;
+                }
+                let field = match column.as_str() {
+                    "purl" => &mut raw.purl,
+                    "name" => &mut raw.name,
+                    "supplier" => &mut raw.supplier,
+                    "author" => &mut raw.author,
+                    "group" => &mut raw.group,
+                    _ => &mut raw.license,
+                };
+                *field = Some(cell);
+            }
+            OverrideRule::try_from(raw).map_err(line_error)
+        })
+        .collect()
+}
+
+/// Splits a line of CSV into its cells, which may be quoted with `"` to contain commas, with `""`
+/// for a quote within a quoted cell
+fn split_csv_line(line: &str) -> Result<Vec<String>, String> {
+    let mut cells = Vec::new();
+    let mut cell = String::new();
+    let mut chars = line.chars().peekable();
+    let mut quoted = false;
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                cell.push('"');
+                chars.next();
+            }
+            '"' if quoted => quoted = false,
+            '"' if cell.trim().is_empty() => {
+                cell.clear();
+                quoted = true;
+            }
+            ',' if !quoted => cells.push(std::mem::take(&mut cell).trim().to_string()),
+            _ => cell.push(c),
+        }
+    }
+    if quoted {
+        return Err("Unterminated quote".to_string());
+    }
+    cells.push(cell.trim().to_string());
+    Ok(cells)
+}
+
+#[derive(Debug, Error)]
+pub enum OverridesError {
+    #[error("Could not read the overrides {}", .path.display())]
+    ReadError {
+        path: PathBuf,
+        #[source]
+        error: std::io::Error,
+    },
+
+    #[error("Invalid overrides {}: {error}", .path.display())]
+    ParseError { path: PathBuf, error: String },
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use cyclonedx_bom::external_models::purl::Purl;
+    use cyclonedx_bom::models::component::{Classification, Components};
+
+    fn component(name: &str, version: &str) -> Component {
+        let mut component = Component::new(Classification::Library, name, version, None);
+        component.purl = Purl::new("cargo", name, version).ok();
+        component
+    }
+
+    fn bom() -> Bom {
+        let mut bom = Bom::default();
+        bom.components = Some(Components(vec![
+            component("openssl-sys", "0.9.80"),
+            component("windows-sys", "0.48.0"),
+            component("windows_x86_64_msvc", "0.48.0"),
+            component("tacos", "1.0.0"),
+        ]));
+        bom
+    }
+
+    #[test]
+    fn it_should_apply_the_rules_in_order() {
+        let rules = parse_toml(
+            r#"
+[[override]]
+name = "windows*"
+supplier = "Microsoft"
+license = "MIT OR Apache-2.0"
+
+[[override]]
+purl = "pkg:cargo/windows-sys@0.48.0"
+group = "windows-rs"
+supplier = "Microsoft Corporation"
+
+[[override]]
+purl = "pkg:cargo/openssl-sys"
+author = "Alex Crichton"
+"#,
+        )
+        .expect("Failed to parse the overrides");
+        let overrides = Overrides { rules };
+
+        let mut bom = bom();
+        assert_eq!(overrides.apply(&mut bom), 3);
+
+        let components = &bom.components.as_ref().unwrap().0;
+        let supplier = |component: &Component| {
+            component
+                .supplier
+                .as_ref()
+                .and_then(|supplier| supplier.name.as_ref())
+                .map(ToString::to_string)
+        };
+        assert_eq!(supplier(&components[0]), None);
+        assert_eq!(
+            components[0].author,
+            Some(NormalizedString::new("Alex Crichton"))
+        );
+        assert_eq!(
+            supplier(&components[1]),
+            Some("Microsoft Corporation".to_string())
+        );
+        assert_eq!(
+            components[1].group,
+            Some(NormalizedString::new("windows-rs"))
+        );
+        assert_eq!(supplier(&components[2]), Some("Microsoft".to_string()));
+        assert_eq!(
+            components[2].licenses,
+            Some(Licenses(vec![LicenseChoice::Expression(
+                SpdxExpression::try_from("MIT OR Apache-2.0".to_string()).unwrap()
+            )]))
+        );
+        assert_eq!(supplier(&components[3]), None);
+        assert_eq!(components[3].licenses, None);
+    }
+
+    #[test]
+    fn it_should_parse_csv() {
+        let rules = parse_csv(
+            "name,supplier,license\n\
+             tacos,\"Tacos, Inc.\",\n\
+             \n\
+             openssl-*,,Apache-2.0\n",
+        )
+        .expect("Failed to parse the overrides");
+        let overrides = Overrides { rules };
+
+        let mut bom = bom();
+        assert_eq!(overrides.apply(&mut bom), 2);
+        let components = &bom.components.as_ref().unwrap().0;
+        assert_eq!(
+            components[3].supplier,
+            Some(OrganizationalEntity::new("Tacos, Inc."))
+        );
+        assert!(components[0].licenses.is_some());
+
+        assert_eq!(
+            split_csv_line(r#"a, "b ""c""" ,"#),
+            Ok(vec!["a".to_string(), r#"b "c""#.to_string(), String::new()])
+        );
+        assert!(split_csv_line(r#"a,"b"#).is_err());
+    }
+
+    #[test]
+    fn it_should_reject_invalid_rules() {
+        assert_eq!(
+            parse_toml("[[override]]\nname = \"tacos\"\n").unwrap_err(),
+            "Override 1: Expected a supplier, author, group or license to set"
+        );
+        assert_eq!(
+            parse_toml(
+                "[[override]]\npurl = \"pkg:cargo/tacos\"\nname = \"tacos\"\nauthor = \"A\"\n"
+            )
+            .unwrap_err(),
+            "Override 1: Expected either a purl or a name"
+        );
+        assert!(
+            parse_toml("[[override]]\nname = \"tacos\"\nlicense = \"MIT AND\"\n")
+                .unwrap_err()
+                .starts_with("Override 1: Invalid license expression `MIT AND`")
+        );
+        assert_eq!(
+            parse_csv("name,vendor\ntacos,Tacos").unwrap_err(),
+            "Unknown column `vendor`"
+        );
+        assert_eq!(
+            parse_csv("name,supplier\ntacos").unwrap_err(),
+            "Line 2: Expected 2 cells, got 1"
+        );
+    }
+}
@@ -0,0 +1,116 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+use crate::config::{CdxExtension, CustomPrefix, OutputOptions, Prefix, PrefixError};
+use crate::generator::GeneratedSbom;
+use cargo::core::Workspace;
+use cargo::sources::PathSource;
+use cargo::util::Filesystem;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// Folder `cargo package` writes the `.crate` files to, where the SBOMs are put next to them
+pub fn package_dir(target_dir: &Filesystem) -> PathBuf {
+    target_dir.join("package").into_path_unlocked()
+}
+
+/// Writes `sbom` to `dir` as `<name>-<version>.cdx.<format>`, named like the `.crate` file of the
+/// package, so the SBOMs of the members of a workspace do not overwrite each other
+pub fn place_next_to_crate(sbom: &mut GeneratedSbom, dir: &Path) -> Result<(), PrepublishError> {
+    let version = sbom
+        .bom
+        .metadata
+        .as_ref()
+        .and_then(|metadata| metadata.component.as_ref())
+        .map(|component| component.version.to_string())
+        .unwrap_or_default();
+    let prefix =
+        CustomPrefix::new(format!("{}-{}", sbom.package_name, version)).map_err(|error| {
+            PrepublishError::FileNameError {
+                package: sbom.package_name.clone(),
+                error,
+            }
+        })?;
+    sbom.sbom_config.output_options = Some(OutputOptions {
+        cdx_extension: CdxExtension::Included,
+        prefix: Prefix::Custom(prefix),
+    });
+    sbom.output_dir = Some(dir.to_path_buf());
+    Ok(())
+}
+
+/// Checks that `cargo package` puts the SBOM written for each member into its `.crate`
+///
+/// * `sboms` - Package name and path of every written SBOM
+pub fn check_included(ws: &Workspace, sboms: &[(String, PathBuf)]) -> Result<(), PrepublishError> {
+    for member in ws.members() {
+        let path = match sboms
+            .iter()
+            .find(|(name, _)| name == member.name().as_str())
+        {
+            Some((_, path)) => path,
+            None => continue,
+        };
+
+        let source = PathSource::new(member.root(), member.package_id().source_id(), ws.config());
+        let files = source
+            .list_files(member)
+            .map_err(|error| PrepublishError::ListFilesError {
+                package: member.name().to_string(),
+                error,
+            })?;
+
+        if !files.iter().any(|file| same_file(file, path)) {
+            return Err(PrepublishError::NotIncludedError {
+                package: member.name().to_string(),
+                path: path.clone(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+fn same_file(a: &Path, b: &Path) -> bool {
+    match (a.canonicalize(), b.canonicalize()) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => a == b,
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum PrepublishError {
+    #[error("Could not name the SBOM of the package {package} after its .crate file")]
+    FileNameError {
+        package: String,
+        #[source]
+        error: PrefixError,
+    },
+
+    #[error("Could not list the files of the package {package}")]
+    ListFilesError {
+        package: String,
+        #[source]
+        error: anyhow::Error,
+    },
+
+    #[error(
+        "The SBOM {} is not part of the package {package}, add it to `include` in its Cargo.toml",
+        .path.display()
+    )]
+    NotIncludedError { package: String, path: PathBuf },
+}
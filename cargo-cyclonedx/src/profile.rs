@@ -0,0 +1,169 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+use cargo::core::profiles::{Lto, Profiles};
+use cargo::core::Workspace;
+use cargo::util::config::StringList;
+use cargo::util::interning::InternedString;
+use cyclonedx_bom::models::property::Property;
+use cyclonedx_bom::taxonomy::CargoProperty;
+
+/// Records the settings of the `name` profile that affect the hardening of the compiled binaries
+///
+/// Besides the profile itself this includes the `-C target-feature`, `-C relocation-model`,
+/// `-Z relro-level` and RELRO/PIE related `-C link-arg` flags from `CARGO_ENCODED_RUSTFLAGS`,
+/// `RUSTFLAGS` or `build.rustflags`.
+pub fn profile_properties(ws: &Workspace, name: &str) -> anyhow::Result<Vec<Property>> {
+    let profiles = Profiles::new(ws, InternedString::new(name))?;
+    // the name of a custom profile is that of the built-in profile it inherits from
+    let profile = profiles.base_profile();
+
+    let lto = match profile.lto {
+        Lto::Off => "off".to_string(),
+        Lto::Bool(true) => "fat".to_string(),
+        Lto::Bool(false) => "false".to_string(),
+        Lto::Named(name) => name.to_string(),
+    };
+
+    let mut properties = vec![
+        Property::new(CargoProperty::Profile, name),
+        Property::new(CargoProperty::ProfileOptLevel, &profile.opt_level),
+        Property::new(CargoProperty::ProfileLto, &lto),
+        Property::new(CargoProperty::ProfilePanic, &profile.panic.to_string()),
+        Property::new(
+            CargoProperty::ProfileOverflowChecks,
+            &profile.overflow_checks.to_string(),
+        ),
+        Property::new(
+            CargoProperty::ProfileDebugAssertions,
+            &profile.debug_assertions.to_string(),
+        ),
+        Property::new(CargoProperty::ProfileStrip, &profile.strip.to_string()),
+    ];
+
+    properties.extend(
+        hardening_flags(&rustflags(ws)?)
+            .into_iter()
+            .map(|(name, value)| Property::new(name, &value)),
+    );
+
+    Ok(properties)
+}
+
+/// The rustflags cargo passes to rustc, in the same order of precedence cargo uses
+fn rustflags(ws: &Workspace) -> anyhow::Result<Vec<String>> {
+    let config = ws.config();
+
+    if let Ok(encoded) = config.get_env("CARGO_ENCODED_RUSTFLAGS") {
+        return Ok(encoded
+            .split('\x1f')
+            .filter(|flag| !flag.is_empty())
+            .map(str::to_string)
+            .collect());
+    }
+
+    if let Ok(flags) = config.get_env("RUSTFLAGS") {
+        return Ok(flags.split_whitespace().map(str::to_string).collect());
+    }
+
+    Ok(config
+        .get::<Option<StringList>>("build.rustflags")?
+        .map(|flags| flags.as_slice().to_vec())
+        .unwrap_or_default())
+}
+
+fn hardening_flags(flags: &[String]) -> Vec<(CargoProperty, String)> {
+    let mut result = Vec::new();
+    let mut flags = flags.iter();
+
+    while let Some(flag) = flags.next() {
+        // both `-C opt=value` and `-Copt=value` are accepted by rustc
+        let (kind, option) = match flag.as_str() {
+            "-C" | "-Z" => match flags.next() {
+                Some(option) => (&flag[1..], option.as_str()),
+                None => break,
+            },
+            _ if flag.starts_with("-C") || flag.starts_with("-Z") => (&flag[1..2], &flag[2..]),
+            _ => continue,
+        };
+
+        let (key, value) = match option.split_once('=') {
+            Some(pair) => pair,
+            None => continue,
+        };
+
+        let name = match (kind, key) {
+            ("C", "target-feature") => CargoProperty::TargetFeature,
+            ("C", "relocation-model") => CargoProperty::RelocationModel,
+            ("Z", "relro-level") => CargoProperty::RelroLevel,
+            ("C", "link-arg") | ("C", "link-args") if is_hardening_link_arg(value) => {
+                CargoProperty::LinkArg
+            }
+            _ => continue,
+        };
+
+        result.push((name, value.to_string()));
+    }
+
+    result
+}
+
+fn is_hardening_link_arg(arg: &str) -> bool {
+    arg.split([',', ' ']).any(|part| {
+        matches!(
+            part,
+            "relro" | "norelro" | "now" | "lazy" | "-pie" | "-no-pie"
+        )
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_should_extract_hardening_flags() {
+        let flags: Vec<String> = [
+            "-C",
+            "target-feature=+crt-static",
+            "-Crelocation-model=pie",
+            "-Z",
+            "relro-level=full",
+            "-C",
+            "link-arg=-Wl,-z,relro,-z,now",
+            "-Clink-arg=-lfoo",
+            "-C",
+            "opt-level=3",
+            "--cfg",
+            "tacos",
+        ]
+        .iter()
+        .map(|flag| flag.to_string())
+        .collect();
+
+        let actual = hardening_flags(&flags);
+
+        let expected = vec![
+            (CargoProperty::TargetFeature, "+crt-static".to_string()),
+            (CargoProperty::RelocationModel, "pie".to_string()),
+            (CargoProperty::RelroLevel, "full".to_string()),
+            (CargoProperty::LinkArg, "-Wl,-z,relro,-z,now".to_string()),
+        ];
+
+        assert_eq!(actual, expected);
+    }
+}
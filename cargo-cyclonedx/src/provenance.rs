@@ -0,0 +1,117 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! The provenance of published packages: the commit of the repository that `cargo package`
+//! recorded in the `.cargo_vcs_info.json` of the `.crate` file
+//!
+//! crates.io does not publish attestations of trusted publishing yet, so the commit is the only
+//! trace of where the published sources come from.
+
+use cargo::core::Package;
+use serde::Deserialize;
+
+/// Name of the file `cargo package` records the version control information in
+pub const VCS_INFO_FILE: &str = ".cargo_vcs_info.json";
+
+/// The version control information of a published package
+///
+/// * `commit` - Git commit the package was published from
+/// * `dirty` - Whether the working tree had uncommitted changes, i.e. it was published with
+///   `--allow-dirty`
+/// * `path_in_vcs` - Directory of the package in the repository, empty for the root
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Provenance {
+    pub commit: String,
+    pub dirty: bool,
+    pub path_in_vcs: String,
+}
+
+#[derive(Deserialize)]
+struct VcsInfo {
+    git: Option<GitInfo>,
+    #[serde(default)]
+    path_in_vcs: String,
+}
+
+#[derive(Deserialize)]
+struct GitInfo {
+    sha1: String,
+    #[serde(default)]
+    dirty: bool,
+}
+
+/// The provenance recorded in the root of `package`, or `None` if the package was not published
+/// from a git repository
+pub fn provenance(package: &Package) -> Option<Provenance> {
+    let path = package.root().join(VCS_INFO_FILE);
+    let content = std::fs::read_to_string(&path).ok()?;
+    match parse_vcs_info(&content) {
+        Ok(provenance) => provenance,
+        Err(e) => {
+            log::warn!(
+                "Could not read the provenance of {} from {}: {}",
+                package.name(),
+                path.display(),
+                e
+            );
+            None
+        }
+    }
+}
+
+fn parse_vcs_info(content: &str) -> Result<Option<Provenance>, serde_json::Error> {
+    let VcsInfo { git, path_in_vcs } = serde_json::from_str(content)?;
+    Ok(git.map(|git| Provenance {
+        commit: git.sha1,
+        dirty: git.dirty,
+        path_in_vcs,
+    }))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_should_parse_the_vcs_info() {
+        assert_eq!(
+            parse_vcs_info(
+                r#"{ "git": { "sha1": "0123abcd", "dirty": true }, "path_in_vcs": "tacos" }"#
+            )
+            .expect("Failed to parse"),
+            Some(Provenance {
+                commit: "0123abcd".to_string(),
+                dirty: true,
+                path_in_vcs: "tacos".to_string(),
+            })
+        );
+        assert_eq!(
+            parse_vcs_info(r#"{ "git": { "sha1": "0123abcd" } }"#).expect("Failed to parse"),
+            Some(Provenance {
+                commit: "0123abcd".to_string(),
+                dirty: false,
+                path_in_vcs: String::new(),
+            })
+        );
+        assert_eq!(
+            parse_vcs_info(r#"{ "path_in_vcs": "" }"#).expect("Failed to parse"),
+            None
+        );
+        assert!(parse_vcs_info("tacos").is_err());
+    }
+}
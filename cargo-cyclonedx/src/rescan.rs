@@ -0,0 +1,276 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+//! Refreshing the vulnerabilities of a stored BOM from the RustSec advisory database, without the
+//! source tree it was generated from
+//!
+//! The crates are recognized by their `pkg:cargo` Purls. Those qualified with where they are taken
+//! from, see [`crate::sources`], are not from crates.io and are left out.
+
+use crate::exploitability::Exploitability;
+use crate::suppressions::{self, Suppressions, SuppressionsError};
+use crate::vex::{self, AdvisoryDb};
+use cyclonedx_bom::errors::{BomReadError, JsonWriteError, XmlWriteError};
+use cyclonedx_bom::format::DocumentFormat;
+use cyclonedx_bom::models::bom::{Bom, SpecVersion};
+use cyclonedx_bom::models::component::Component;
+use cyclonedx_bom::models::vulnerability::Vulnerabilities;
+use semver::Version;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+const SOURCE_QUALIFIERS: &[&str] = &["download_url", "repository_url", "vcs_url"];
+
+/// The outcome of re-scanning a BOM
+///
+/// * `crates` - Number of crates.io components looked up in the advisory database
+/// * `vulnerabilities` - Number of vulnerabilities found in them
+/// * `version` - Version of the rewritten BOM
+/// * `written` - Whether the BOM was written, i.e. the re-scan was not a dry run
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rescan {
+    pub crates: usize,
+    pub vulnerabilities: usize,
+    pub version: u32,
+    pub written: bool,
+}
+
+impl fmt::Display for Rescan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Found {} vulnerabilities in {} crates, {} version {} of the BOM",
+            self.vulnerabilities,
+            self.crates,
+            if self.written { "wrote" } else { "would write" },
+            self.version
+        )
+    }
+}
+
+/// Replaces the vulnerabilities of `bom` with those `db` lists for its crates.io components, and
+/// increments its version
+///
+/// The vulnerabilities target the components by their bom-ref, so components without one are left
+/// out.
+pub fn rescan_bom(bom: &mut Bom, db: &AdvisoryDb) -> Rescan {
+    let mut components = Vec::new();
+    if let Some(list) = &bom.components {
+        collect_components(&list.0, &mut components);
+    }
+    let crates: Vec<_> = components.into_iter().filter_map(crates_io_crate).collect();
+    let count = crates.len();
+
+    let vulnerabilities = vex::find_vulnerabilities(crates.into_iter(), db);
+    let rescan = Rescan {
+        crates: count,
+        vulnerabilities: vulnerabilities.len(),
+        version: bom.version + 1,
+        written: false,
+    };
+    bom.vulnerabilities = if vulnerabilities.is_empty() {
+        None
+    } else {
+        Some(Vulnerabilities(vulnerabilities))
+    };
+    bom.version = rescan.version;
+
+    rescan
+}
+
+/// Re-scans the BOM at `path` with [`rescan_bom`], annotates the vulnerabilities with their
+/// `exploitability`, records the analysis of the `suppressions` and writes the BOM back in the
+/// format and the version of the specification it was read in, unless it is a `dry_run`
+pub fn rescan(
+    path: &Path,
+    db: &AdvisoryDb,
+    exploitability: &Exploitability,
+    suppressions: &Suppressions,
+    dry_run: bool,
+) -> Result<Rescan, RescanError> {
+    let input = std::fs::read(path).map_err(|error| RescanError::ReadError {
+        path: path.to_path_buf(),
+        error,
+    })?;
+    let (mut bom, detected) = Bom::parse(&input).map_err(|error| RescanError::ParseError {
+        path: path.to_path_buf(),
+        error,
+    })?;
+    if detected.gzip || detected.format == DocumentFormat::Protobuf {
+        return Err(RescanError::UnsupportedFormat {
+            path: path.to_path_buf(),
+        });
+    }
+    // vulnerabilities were added in version 1.4
+    if detected.spec_version == SpecVersion::V1_3 {
+        return Err(RescanError::UnsupportedSpecVersion {
+            path: path.to_path_buf(),
+        });
+    }
+
+    let mut rescan = rescan_bom(&mut bom, db);
+    if dry_run {
+        return Ok(rescan);
+    }
+    exploitability.annotate(&mut bom);
+    suppressions.apply(&mut bom, &suppressions::today()?);
+
+    let mut output = Vec::new();
+    if detected.format == DocumentFormat::Json {
+        bom.output_as_json(&mut output, detected.spec_version)?;
+    } else {
+        bom.output_as_xml(&mut output, detected.spec_version)?;
+    }
+    std::fs::write(path, output).map_err(|error| RescanError::WriteError {
+        path: path.to_path_buf(),
+        error,
+    })?;
+    rescan.written = true;
+
+    Ok(rescan)
+}
+
+fn collect_components<'a>(components: &'a [Component], collected: &mut Vec<&'a Component>) {
+    for component in components {
+        collected.push(component);
+        if let Some(nested) = &component.components {
+            collect_components(&nested.0, collected);
+        }
+    }
+}
+
+/// The name, version and bom-ref of a component that is a crate from crates.io
+fn crates_io_crate(component: &Component) -> Option<(String, Version, String)> {
+    let bom_ref = component.bom_ref.clone()?;
+    let parts = component.purl.as_ref()?.parts().ok()?;
+    if parts.package_type != "cargo"
+        || SOURCE_QUALIFIERS
+            .iter()
+            .any(|qualifier| parts.qualifiers.contains_key(*qualifier))
+    {
+        return None;
+    }
+    let version = Version::parse(parts.version.as_deref()?).ok()?;
+    Some((parts.name, version, bom_ref))
+}
+
+#[derive(Debug, Error)]
+pub enum RescanError {
+    #[error("Failed to read {}", .path.display())]
+    ReadError {
+        path: PathBuf,
+        #[source]
+        error: std::io::Error,
+    },
+
+    #[error("Failed to parse {}", .path.display())]
+    ParseError {
+        path: PathBuf,
+        #[source]
+        error: BomReadError,
+    },
+
+    #[error("Cannot rewrite {}, only uncompressed JSON and XML BOMs are supported", .path.display())]
+    UnsupportedFormat { path: PathBuf },
+
+    #[error("Cannot add vulnerabilities to {}, which conforms to version 1.3 of the specification, convert it to 1.4 or later first", .path.display())]
+    UnsupportedSpecVersion { path: PathBuf },
+
+    #[error(transparent)]
+    SuppressionsError(#[from] SuppressionsError),
+
+    #[error("Failed to write the JSON document")]
+    JsonWriteError(#[from] JsonWriteError),
+
+    #[error("Failed to write the XML document")]
+    XmlWriteError(#[from] XmlWriteError),
+
+    #[error("Failed to write {}", .path.display())]
+    WriteError {
+        path: PathBuf,
+        #[source]
+        error: std::io::Error,
+    },
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::vex::RustsecAdvisory;
+    use cyclonedx_bom::external_models::purl::Purl;
+    use cyclonedx_bom::models::component::{Classification, Components};
+    use semver::VersionReq;
+
+    fn component(name: &str, version: &str, purl: Purl) -> Component {
+        let bom_ref = format!("{}@{}", name, version);
+        let mut component = Component::new(Classification::Library, name, version, Some(bom_ref));
+        component.purl = Some(purl);
+        component
+    }
+
+    #[test]
+    fn it_should_replace_the_vulnerabilities_of_crates_io_components() {
+        let mut db = AdvisoryDb::default();
+        db.add(RustsecAdvisory {
+            id: "RUSTSEC-2021-0078".to_string(),
+            package: "hyper".to_string(),
+            title: "Lenient header parsing".to_string(),
+            description: String::new(),
+            date: "2021-07-07".to_string(),
+            url: None,
+            aliases: Vec::new(),
+            cvss: None,
+            patched: vec![VersionReq::parse(">= 0.14.10").unwrap()],
+            unaffected: Vec::new(),
+        });
+        let fork = Purl::builder("cargo", "hyper")
+            .version("0.14.9")
+            .qualifier("vcs_url", "git+https://example.com/hyper@abc")
+            .build()
+            .unwrap();
+        let mut bom = Bom::default().with_components(Components(vec![
+            component(
+                "hyper",
+                "0.14.9",
+                Purl::new("cargo", "hyper", "0.14.9").unwrap(),
+            ),
+            component("fork", "0.14.9", fork),
+            component(
+                "hyper",
+                "0.14.10",
+                Purl::new("cargo", "hyper", "0.14.10").unwrap(),
+            ),
+        ]));
+
+        let rescan = rescan_bom(&mut bom, &db);
+
+        assert_eq!(
+            rescan,
+            Rescan {
+                crates: 2,
+                vulnerabilities: 1,
+                version: 2,
+                written: false,
+            }
+        );
+        assert_eq!(bom.version, 2);
+        let vulnerabilities = bom.vulnerabilities.unwrap().0;
+        let affects = vulnerabilities[0].affects.as_ref().unwrap();
+        assert_eq!(affects[0].bom_ref, "hyper@0.14.9");
+    }
+}
@@ -0,0 +1,134 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+use crate::config::ScanExclude;
+
+use globset::GlobSet;
+use ignore::WalkBuilder;
+use std::path::{Path, PathBuf};
+
+/// Walks the files of a package, skipping the paths ignored by `.gitignore` and `.ignore` files
+/// and the paths matching the [`ScanExclude`] globs
+///
+/// `.gitignore` files are honored outside of git repositories as well, so that the `target`
+/// directory and other build output are skipped in unpacked sources too.
+#[derive(Debug, Clone)]
+pub struct FileScanner {
+    root: PathBuf,
+    exclude: GlobSet,
+}
+
+impl FileScanner {
+    /// Scanner for the package at `root`, with `exclude` globs relative to `root`
+    pub fn new(root: &Path, exclude: &ScanExclude) -> Self {
+        Self {
+            root: root.to_path_buf(),
+            exclude: exclude.glob_set(),
+        }
+    }
+
+    /// Whether `path` matches one of the exclusion globs
+    pub fn is_excluded(&self, path: &Path) -> bool {
+        is_excluded(&self.root, &self.exclude, path)
+    }
+
+    /// All files below `dir`, sorted by path and without following symlinks
+    ///
+    /// Errors are logged and the affected files skipped.
+    pub fn files(&self, dir: &Path) -> Vec<PathBuf> {
+        let root = self.root.clone();
+        let exclude = self.exclude.clone();
+
+        WalkBuilder::new(dir)
+            .hidden(false)
+            .require_git(false)
+            .sort_by_file_name(|a, b| a.cmp(b))
+            .filter_entry(move |entry| {
+                entry.file_name() != ".git" && !is_excluded(&root, &exclude, entry.path())
+            })
+            .build()
+            .filter_map(|entry| match entry {
+                Ok(entry) => Some(entry),
+                Err(e) => {
+                    log::warn!("Could not scan {}: {}", dir.display(), e);
+                    None
+                }
+            })
+            .filter(|entry| entry.file_type().map_or(false, |t| t.is_file()))
+            .map(|entry| entry.into_path())
+            .collect()
+    }
+}
+
+fn is_excluded(root: &Path, exclude: &GlobSet, path: &Path) -> bool {
+    match path.strip_prefix(root) {
+        Ok(relative) => !relative.as_os_str().is_empty() && exclude.is_match(relative),
+        Err(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use assert_fs::prelude::*;
+
+    #[test]
+    fn it_should_skip_ignored_and_excluded_files() {
+        let tmp_dir = assert_fs::TempDir::new().expect("Failed to create temporary directory");
+        for file in [
+            "src/main.rs",
+            "src/generated.rs",
+            "target/debug/build.rs",
+            "tests/fixtures/data.bin",
+            "tests/it.rs",
+            "vendor/lib/lib.rs",
+            "notes.txt",
+        ] {
+            tmp_dir.child(file).touch().expect("Failed to create file");
+        }
+        tmp_dir
+            .child(".gitignore")
+            .write_str("/target\nsrc/generated.rs\n")
+            .expect("Failed to write .gitignore");
+        tmp_dir
+            .child(".ignore")
+            .write_str("*.txt\n")
+            .expect("Failed to write .ignore");
+
+        let exclude = ScanExclude::new(vec!["tests/fixtures".to_string(), "vendor/**".to_string()])
+            .expect("Invalid globs");
+        let scanner = FileScanner::new(tmp_dir.path(), &exclude);
+
+        let actual: Vec<_> = scanner
+            .files(tmp_dir.path())
+            .into_iter()
+            .map(|path| {
+                path.strip_prefix(tmp_dir.path())
+                    .expect("File outside of the scanned directory")
+                    .to_path_buf()
+            })
+            .collect();
+
+        let expected: Vec<PathBuf> = [".gitignore", ".ignore", "src/main.rs", "tests/it.rs"]
+            .iter()
+            .map(PathBuf::from)
+            .collect();
+        assert_eq!(actual, expected);
+        assert!(scanner.is_excluded(&tmp_dir.path().join("vendor/lib/lib.rs")));
+        assert!(!scanner.is_excluded(&tmp_dir.path().join("src/vendor/lib.rs")));
+    }
+}
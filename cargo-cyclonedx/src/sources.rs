@@ -0,0 +1,50 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Purls of packages that are not taken from crates.io, which are qualified with where they are
+//! taken from, so that they are not mistaken for the crates.io package of the same name
+
+use cargo::core::Package;
+use cyclonedx_bom::external_models::purl::Purl;
+use cyclonedx_bom::external_models::uri::UriError;
+
+/// The `pkg:cargo` Purl of `package`, qualified according to its source:
+///
+/// * `vcs_url`, the repository and commit, for packages from git repositories
+/// * `download_url`, the absolute `file:///` URL of the directory, for path packages
+/// * `repository_url`, the index, for packages from registries other than crates.io
+pub fn source_purl(package: &Package) -> Result<Purl, UriError> {
+    let source_id = package.package_id().source_id();
+    let version = package.version().to_string();
+    let purl = Purl::builder("cargo", package.name().trim()).version(&version);
+
+    let purl = if source_id.is_git() {
+        let revision = source_id.precise().unwrap_or(version.as_str());
+        purl.qualifier("vcs_url", &format!("git+{}@{}", source_id.url(), revision))
+    } else if source_id.is_path() {
+        // cargo resolves the directories of path packages to absolute paths
+        purl.qualifier("download_url", source_id.url().as_str())
+    } else if source_id.is_registry() && !source_id.is_crates_io() {
+        let index = source_id.url().as_str();
+        purl.repository_url(index.strip_prefix("sparse+").unwrap_or(index))
+    } else {
+        purl
+    };
+
+    purl.build()
+}
@@ -0,0 +1,547 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+use cyclonedx_bom::errors::BomReadError;
+use cyclonedx_bom::models::bom::Bom;
+use cyclonedx_bom::models::component::Component;
+use cyclonedx_bom::models::license::{LicenseChoice, LicenseIdentifier};
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Statistics of the components of a BOM, nested ones included, for a health check before
+/// handing it on
+///
+/// * `by_type` - Number of components of each type
+/// * `by_scope` - Number of components of each scope, `unspecified` for those without one
+/// * `licenses` - Number of components declaring each license or license expression
+/// * `unlicensed` - Number of components without any license
+/// * `license_coverage` - Percentage of the components with at least one license
+/// * `hashed` - Number of components with at least one hash
+/// * `hash_coverage` - Percentage of the components with at least one hash
+/// * `dependency_depth` - Number of edges of the longest path through the dependency graph
+/// * `duplicates` - Versions of the crates listed in more than one version
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct BomStats {
+    pub components: usize,
+    pub by_type: BTreeMap<String, usize>,
+    pub by_scope: BTreeMap<String, usize>,
+    pub licenses: BTreeMap<String, usize>,
+    pub unlicensed: usize,
+    pub license_coverage: f64,
+    pub hashed: usize,
+    pub hash_coverage: f64,
+    pub dependency_depth: usize,
+    pub duplicates: BTreeMap<String, Vec<String>>,
+}
+
+impl BomStats {
+    pub fn new(bom: &Bom) -> Self {
+        let mut components = Vec::new();
+        if let Some(list) = &bom.components {
+            collect_components(&list.0, &mut components);
+        }
+
+        let mut stats = BomStats {
+            components: components.len(),
+            ..BomStats::default()
+        };
+        let mut crate_versions: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+
+        for component in components {
+            *stats
+                .by_type
+                .entry(component.component_type.to_string())
+                .or_default() += 1;
+            let scope = component
+                .scope
+                .as_ref()
+                .map_or_else(|| "unspecified".to_string(), ToString::to_string);
+            *stats.by_scope.entry(scope).or_default() += 1;
+
+            let licenses = component_licenses(component);
+            if licenses.is_empty() {
+                stats.unlicensed += 1;
+            }
+            for license in licenses {
+                *stats.licenses.entry(license).or_default() += 1;
+            }
+
+            if component
+                .hashes
+                .as_ref()
+                .map_or(false, |hashes| !hashes.0.is_empty())
+            {
+                stats.hashed += 1;
+            }
+
+            if let Some(purl) = &component.purl {
+                if purl.to_string().starts_with("pkg:cargo/") {
+                    crate_versions
+                        .entry(component.name.to_string())
+                        .or_default()
+                        .insert(component.version.to_string());
+                }
+            }
+        }
+
+        if stats.components > 0 {
+            let licensed = stats.components - stats.unlicensed;
+            stats.license_coverage = licensed as f64 * 100.0 / stats.components as f64;
+            stats.hash_coverage = stats.hashed as f64 * 100.0 / stats.components as f64;
+        }
+        stats.dependency_depth = dependency_depth(bom);
+        stats.duplicates = crate_versions
+            .into_iter()
+            .filter(|(_, versions)| versions.len() > 1)
+            .map(|(name, versions)| (name, versions.into_iter().collect()))
+            .collect();
+
+        stats
+    }
+}
+
+impl fmt::Display for BomStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Components: {}", self.components)?;
+        writeln!(f, "  by type: {}", counts(&self.by_type))?;
+        writeln!(f, "  by scope: {}", counts(&self.by_scope))?;
+
+        let mut licenses: Vec<_> = self.licenses.iter().collect();
+        // the most common licenses first
+        licenses.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+        let mut licenses: Vec<_> = licenses
+            .into_iter()
+            .map(|(license, count)| format!("{} {}", license, count))
+            .collect();
+        if self.unlicensed > 0 {
+            licenses.push(format!("none {}", self.unlicensed));
+        }
+        writeln!(f, "Licenses: {}", or_none(licenses.join(", ")))?;
+        writeln!(
+            f,
+            "Licensed: {} of {} components ({:.1}%)",
+            self.components - self.unlicensed,
+            self.components,
+            self.license_coverage
+        )?;
+
+        writeln!(
+            f,
+            "Hashes: {} of {} components ({:.1}%)",
+            self.hashed, self.components, self.hash_coverage
+        )?;
+        writeln!(f, "Dependency graph depth: {}", self.dependency_depth)?;
+
+        let duplicates: Vec<_> = self
+            .duplicates
+            .iter()
+            .map(|(name, versions)| format!("{} ({})", name, versions.join(", ")))
+            .collect();
+        writeln!(f, "Duplicated crates: {}", or_none(duplicates.join(", ")))
+    }
+}
+
+fn counts(counts: &BTreeMap<String, usize>) -> String {
+    or_none(
+        counts
+            .iter()
+            .map(|(name, count)| format!("{} {}", name, count))
+            .collect::<Vec<_>>()
+            .join(", "),
+    )
+}
+
+fn or_none(list: String) -> String {
+    if list.is_empty() {
+        "none".to_string()
+    } else {
+        list
+    }
+}
+
+fn collect_components<'a>(components: &'a [Component], collected: &mut Vec<&'a Component>) {
+    for component in components {
+        collected.push(component);
+        if let Some(nested) = &component.components {
+            collect_components(&nested.0, collected);
+        }
+    }
+}
+
+fn component_licenses(component: &Component) -> Vec<String> {
+    let licenses = match &component.licenses {
+        Some(licenses) => &licenses.0,
+        None => return Vec::new(),
+    };
+    licenses
+        .iter()
+        .map(|license| match license {
+            LicenseChoice::License(license) => match &license.license_identifier {
+                LicenseIdentifier::SpdxId(id) => id.to_string(),
+                LicenseIdentifier::Name(name) => name.to_string(),
+            },
+            LicenseChoice::Expression(expression) => expression.to_string(),
+        })
+        .collect()
+}
+
+/// The number of edges of the longest path from the metadata component through the dependency
+/// graph, or from the components nothing depends on if it is not in the graph, where a cycle is
+/// followed until it would reach a component a second time
+fn dependency_depth(bom: &Bom) -> usize {
+    let graph: BTreeMap<&str, Vec<&str>> = match &bom.dependencies {
+        Some(dependencies) => dependencies
+            .iter()
+            .map(|dependency| {
+                (
+                    dependency.dependency_ref.as_str(),
+                    dependency.dependencies.iter().map(String::as_str).collect(),
+                )
+            })
+            .collect(),
+        None => return 0,
+    };
+
+    let root = bom
+        .metadata
+        .as_ref()
+        .and_then(|metadata| metadata.component.as_ref())
+        .and_then(|component| component.bom_ref.as_deref())
+        .filter(|root| graph.contains_key(root));
+    let roots: Vec<&str> = match root {
+        Some(root) => vec![root],
+        None => {
+            let dependencies: BTreeSet<&str> = graph.values().flatten().copied().collect();
+            graph
+                .keys()
+                .copied()
+                .filter(|node| !dependencies.contains(node))
+                .collect()
+        }
+    };
+
+    fn depth<'a>(
+        node: &'a str,
+        graph: &BTreeMap<&'a str, Vec<&'a str>>,
+        path: &mut Vec<&'a str>,
+        known: &mut HashMap<&'a str, usize>,
+    ) -> usize {
+        if let Some(depth) = known.get(node) {
+            return *depth;
+        }
+
+        path.push(node);
+        let dependencies: Vec<&str> = graph
+            .get(node)
+            .into_iter()
+            .flatten()
+            .copied()
+            .filter(|dependency| !path.contains(dependency))
+            .collect();
+        let deepest = dependencies
+            .into_iter()
+            .map(|dependency| depth(dependency, graph, path, known) + 1)
+            .max()
+            .unwrap_or(0);
+        path.pop();
+
+        known.insert(node, deepest);
+        deepest
+    }
+
+    let mut known = HashMap::new();
+    roots
+        .into_iter()
+        .map(|root| depth(root, &graph, &mut Vec::new(), &mut known))
+        .max()
+        .unwrap_or(0)
+}
+
+/// Reads the BOM at `path` in any supported format and computes its statistics
+pub fn read(path: &Path) -> Result<BomStats, StatsError> {
+    let input = std::fs::read(path).map_err(|error| StatsError::ReadError {
+        path: path.to_path_buf(),
+        error,
+    })?;
+    let (bom, _) = Bom::parse(&input).map_err(|error| StatsError::ParseError {
+        path: path.to_path_buf(),
+        error,
+    })?;
+    Ok(BomStats::new(&bom))
+}
+
+#[derive(Debug, Error)]
+pub enum StatsError {
+    #[error("Failed to read {}", .path.display())]
+    ReadError {
+        path: PathBuf,
+        #[source]
+        error: std::io::Error,
+    },
+
+    #[error("Failed to parse {}", .path.display())]
+    ParseError {
+        path: PathBuf,
+        #[source]
+        error: BomReadError,
+    },
+}
+
+/// The minimum percentages of the components of an SBOM that have a license and that have a
+/// hash, below which the SBOM is rejected
+///
+/// SBOMs without components have nothing to cover and always meet the thresholds.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct CoverageThresholds {
+    pub license: Option<Percentage>,
+    pub hash: Option<Percentage>,
+}
+
+impl CoverageThresholds {
+    /// The coverages of `stats` that fall below their threshold
+    pub fn shortfalls(&self, stats: &BomStats) -> Vec<CoverageShortfall> {
+        if stats.components == 0 {
+            return Vec::new();
+        }
+
+        [
+            ("license", self.license, stats.license_coverage),
+            ("hash", self.hash, stats.hash_coverage),
+        ]
+        .iter()
+        .filter_map(|&(coverage, required, actual)| match required {
+            Some(required) if actual < required.0 => Some(CoverageShortfall {
+                coverage,
+                actual,
+                required,
+            }),
+            _ => None,
+        })
+        .collect()
+    }
+}
+
+/// A percentage from 0 to 100, e.g. `95` or `99.5`
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Percentage(f64);
+
+impl FromStr for Percentage {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim_end_matches('%').parse::<f64>() {
+            Ok(percentage) if (0.0..=100.0).contains(&percentage) => Ok(Self(percentage)),
+            _ => Err(format!("Expected a percentage from 0 to 100, got `{}`", s)),
+        }
+    }
+}
+
+impl fmt::Display for Percentage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}%", self.0)
+    }
+}
+
+/// A coverage of an SBOM below its threshold
+#[derive(Debug, Clone, Copy, PartialEq, Error)]
+#[error("The {coverage} coverage of {actual:.1}% is below the required {required}")]
+pub struct CoverageShortfall {
+    pub coverage: &'static str,
+    pub actual: f64,
+    pub required: Percentage,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum StatsFormat {
+    #[default]
+    Text,
+    Json,
+}
+
+impl FromStr for StatsFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(StatsFormat::Text),
+            "json" => Ok(StatsFormat::Json),
+            _ => Err(format!("Expected text or json, got `{}`", s)),
+        }
+    }
+}
+
+impl fmt::Display for StatsFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StatsFormat::Text => "text".fmt(f),
+            StatsFormat::Json => "json".fmt(f),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const BOM: &str = r#"{
+  "bomFormat": "CycloneDX",
+  "specVersion": "1.3",
+  "version": 1,
+  "metadata": {
+    "component": { "type": "application", "bom-ref": "app", "name": "app", "version": "1.0.0" }
+  },
+  "components": [
+    {
+      "type": "library",
+      "bom-ref": "pkg:cargo/syn@1.0.109",
+      "name": "syn",
+      "version": "1.0.109",
+      "scope": "required",
+      "purl": "pkg:cargo/syn@1.0.109",
+      "hashes": [{ "alg": "SHA-256", "content": "72b64191b275b66ffe2469e8af2c1cfe3bafa67b529ead792a6d0160888b4237" }],
+      "licenses": [{ "expression": "MIT OR Apache-2.0" }]
+    },
+    {
+      "type": "library",
+      "bom-ref": "pkg:cargo/syn@2.0.18",
+      "name": "syn",
+      "version": "2.0.18",
+      "scope": "required",
+      "purl": "pkg:cargo/syn@2.0.18",
+      "licenses": [{ "expression": "MIT OR Apache-2.0" }]
+    },
+    {
+      "type": "library",
+      "bom-ref": "pkg:cargo/tacos@0.1.0",
+      "name": "tacos",
+      "version": "0.1.0",
+      "purl": "pkg:cargo/tacos@0.1.0",
+      "licenses": [{ "license": { "id": "MIT" } }],
+      "components": [
+        { "type": "file", "name": "src/lib.rs", "version": "0.1.0" }
+      ]
+    }
+  ],
+  "dependencies": [
+    { "ref": "app", "dependsOn": ["pkg:cargo/tacos@0.1.0", "pkg:cargo/syn@2.0.18"] },
+    { "ref": "pkg:cargo/tacos@0.1.0", "dependsOn": ["pkg:cargo/syn@1.0.109"] },
+    { "ref": "pkg:cargo/syn@1.0.109", "dependsOn": ["app"] }
+  ]
+}"#;
+
+    #[test]
+    fn it_should_compute_the_statistics() {
+        let (bom, _) = Bom::parse(BOM.as_bytes()).expect("Failed to parse the BOM");
+        let stats = BomStats::new(&bom);
+
+        assert_eq!(stats.components, 4);
+        assert_eq!(
+            stats.by_type,
+            BTreeMap::from([("file".to_string(), 1), ("library".to_string(), 3)])
+        );
+        assert_eq!(
+            stats.by_scope,
+            BTreeMap::from([("required".to_string(), 2), ("unspecified".to_string(), 2)])
+        );
+        assert_eq!(
+            stats.licenses,
+            BTreeMap::from([("MIT".to_string(), 1), ("MIT OR Apache-2.0".to_string(), 2)])
+        );
+        assert_eq!(stats.unlicensed, 1);
+        assert_eq!(stats.license_coverage, 75.0);
+        assert_eq!(stats.hashed, 1);
+        assert_eq!(stats.hash_coverage, 25.0);
+        // app -> tacos -> syn 1 -> app, where the cycle ends
+        assert_eq!(stats.dependency_depth, 2);
+        assert_eq!(
+            stats.duplicates,
+            BTreeMap::from([(
+                "syn".to_string(),
+                vec!["1.0.109".to_string(), "2.0.18".to_string()]
+            )])
+        );
+
+        assert_eq!(
+            stats.to_string(),
+            "Components: 4
+  by type: file 1, library 3
+  by scope: required 2, unspecified 2
+Licenses: MIT OR Apache-2.0 2, MIT 1, none 1
+Licensed: 3 of 4 components (75.0%)
+Hashes: 1 of 4 components (25.0%)
+Dependency graph depth: 2
+Duplicated crates: syn (1.0.109, 2.0.18)
+"
+        );
+    }
+
+    #[test]
+    fn it_should_report_the_coverages_below_their_threshold() {
+        let (bom, _) = Bom::parse(BOM.as_bytes()).expect("Failed to parse the BOM");
+        let stats = BomStats::new(&bom);
+        let percentage = |s: &str| s.parse::<Percentage>().expect("Invalid percentage");
+
+        let thresholds = CoverageThresholds {
+            license: Some(percentage("75")),
+            hash: Some(percentage("100%")),
+        };
+        let shortfalls = thresholds.shortfalls(&stats);
+        assert_eq!(
+            shortfalls,
+            vec![CoverageShortfall {
+                coverage: "hash",
+                actual: 25.0,
+                required: percentage("100"),
+            }]
+        );
+        assert_eq!(
+            shortfalls[0].to_string(),
+            "The hash coverage of 25.0% is below the required 100%"
+        );
+
+        assert_eq!(
+            thresholds.shortfalls(&BomStats::new(&Bom::default())),
+            Vec::new()
+        );
+        assert_eq!(
+            "101".parse::<Percentage>(),
+            Err("Expected a percentage from 0 to 100, got `101`".to_string())
+        );
+    }
+
+    #[test]
+    fn it_should_describe_an_empty_bom() {
+        let stats = BomStats::new(&Bom::default());
+
+        assert_eq!(
+            stats.to_string(),
+            "Components: 0
+  by type: none
+  by scope: none
+Licenses: none
+Licensed: 0 of 0 components (0.0%)
+Hashes: 0 of 0 components (0.0%)
+Dependency graph depth: 0
+Duplicated crates: none
+"
+        );
+    }
+}
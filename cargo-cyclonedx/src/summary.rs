@@ -0,0 +1,193 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+use crate::generator::OutputSummary;
+
+use log::{Level, Log, Metadata, Record};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+use thiserror::Error;
+
+/// Machine-readable summary of a `cargo cyclonedx` run
+///
+/// * `files` - SBOM files that were written
+/// * `warnings` - Warnings and non-fatal errors reported during the run
+/// * `durations_ms` - Wall-clock time spent in each phase, in milliseconds
+/// * `memory` - Memory used in each phase, if requested with `--memory-stats`
+#[derive(Debug, Default, Serialize)]
+pub struct GenerationSummary {
+    pub files: Vec<OutputSummary>,
+    pub warnings: Vec<String>,
+    pub durations_ms: BTreeMap<&'static str, u128>,
+    #[cfg(feature = "memory-stats")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory: Option<crate::memory::MemoryReport>,
+}
+
+/// Start of a phase of the run, see [`GenerationSummary::record_phase`]
+pub struct PhaseStart {
+    started: Instant,
+    #[cfg(feature = "memory-stats")]
+    memory: crate::memory::Snapshot,
+}
+
+impl PhaseStart {
+    pub fn now() -> Self {
+        Self {
+            started: Instant::now(),
+            #[cfg(feature = "memory-stats")]
+            memory: crate::memory::Snapshot::take(),
+        }
+    }
+}
+
+impl GenerationSummary {
+    /// Records the time elapsed since `start` as the duration of `phase`, and the memory used
+    /// since then if memory usage is reported
+    pub fn record_phase(&mut self, phase: &'static str, start: PhaseStart) {
+        self.durations_ms
+            .insert(phase, start.started.elapsed().as_millis());
+
+        #[cfg(feature = "memory-stats")]
+        if let Some(memory) = &mut self.memory {
+            memory.phases.insert(phase, start.memory.usage());
+        }
+    }
+
+    /// Writes the summary as JSON to `path`, or to stdout if the path is `-`
+    pub fn write_to(&self, path: &Path) -> Result<(), SummaryError> {
+        if path == Path::new("-") {
+            let stdout = std::io::stdout();
+            let mut stdout = stdout.lock();
+            serde_json::to_writer_pretty(&mut stdout, self)?;
+            writeln!(stdout).map_err(SummaryError::WriteError)?;
+        } else {
+            let file = std::fs::File::create(path).map_err(SummaryError::WriteError)?;
+            serde_json::to_writer_pretty(file, self)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Logger that forwards to another logger while remembering every warning and error logged by
+/// this crate, regardless of the verbosity chosen for the wrapped logger
+pub struct WarningCollector<L> {
+    inner: L,
+    warnings: Arc<Mutex<Vec<String>>>,
+}
+
+impl<L: Log> WarningCollector<L> {
+    pub fn new(inner: L) -> Self {
+        Self {
+            inner,
+            warnings: Arc::default(),
+        }
+    }
+
+    /// Handle to the collected warnings that stays valid after the logger is installed
+    pub fn warnings(&self) -> Arc<Mutex<Vec<String>>> {
+        self.warnings.clone()
+    }
+}
+
+impl<L: Log> Log for WarningCollector<L> {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        is_collected(metadata) || self.inner.enabled(metadata)
+    }
+
+    fn log(&self, record: &Record) {
+        if is_collected(record.metadata()) {
+            if let Ok(mut warnings) = self.warnings.lock() {
+                warnings.push(record.args().to_string());
+            }
+        }
+        self.inner.log(record);
+    }
+
+    fn flush(&self) {
+        self.inner.flush();
+    }
+}
+
+fn is_collected(metadata: &Metadata) -> bool {
+    metadata.level() <= Level::Warn && metadata.target().starts_with("cargo_cyclonedx")
+}
+
+#[derive(Error, Debug)]
+pub enum SummaryError {
+    #[error("Error serializing the summary")]
+    SerializeError(#[from] serde_json::Error),
+
+    #[error("Error writing the summary")]
+    WriteError(#[source] std::io::Error),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct NoopLogger;
+
+    impl Log for NoopLogger {
+        fn enabled(&self, _metadata: &Metadata) -> bool {
+            false
+        }
+
+        fn log(&self, _record: &Record) {}
+
+        fn flush(&self) {}
+    }
+
+    #[test]
+    fn it_should_collect_only_warnings_from_this_crate() {
+        let collector = WarningCollector::new(NoopLogger);
+
+        for (level, target, message) in [
+            (
+                Level::Warn,
+                "cargo_cyclonedx::generator",
+                "collected warning",
+            ),
+            (Level::Error, "cargo_cyclonedx", "collected error"),
+            (Level::Info, "cargo_cyclonedx::generator", "informational"),
+            (Level::Warn, "cargo::core", "cargo warning"),
+        ] {
+            collector.log(
+                &Record::builder()
+                    .level(level)
+                    .target(target)
+                    .args(format_args!("{}", message))
+                    .build(),
+            );
+        }
+
+        let warnings = collector.warnings();
+        let warnings = warnings.lock().expect("Failed to lock warnings");
+        assert_eq!(
+            *warnings,
+            vec![
+                "collected warning".to_string(),
+                "collected error".to_string()
+            ]
+        );
+    }
+}
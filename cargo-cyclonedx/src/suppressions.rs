@@ -0,0 +1,301 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+//! Suppression of advisories that were assessed not to affect a project, which turns their
+//! vulnerabilities into `not_affected` or `false_positive` analysis statements instead of raw
+//! findings
+//!
+//! The suppressions are read from a TOML file in the spirit of the ignore list of `audit.toml`,
+//! with the justification of each and the date it expires on, so that it is assessed again:
+//!
+//! ```toml
+//! [[ignore]]
+//! id = "RUSTSEC-2021-0078"
+//! justification = "code_not_reachable"
+//! detail = "Only the HTTP/2 server of hyper is used"
+//! expires = "2024-06-30"
+//!
+//! [[ignore]]
+//! id = "RUSTSEC-2020-0071"
+//! state = "false_positive"
+//! detail = "The time crate is only used for formatting"
+//! ```
+
+use cyclonedx_bom::external_models::date_time::DateTime;
+use cyclonedx_bom::models::bom::Bom;
+use cyclonedx_bom::models::vulnerability::{
+    Analysis, ImpactAnalysisJustification, ImpactAnalysisState,
+};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// The state of the vulnerabilities of a suppressed advisory
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SuppressedState {
+    #[default]
+    NotAffected,
+    FalsePositive,
+}
+
+/// Why a project is not affected by a vulnerability, as in
+/// [`ImpactAnalysisJustification`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Justification {
+    CodeNotPresent,
+    CodeNotReachable,
+    RequiresConfiguration,
+    RequiresDependency,
+    RequiresEnvironment,
+    ProtectedByCompiler,
+    ProtectedAtRuntime,
+    ProtectedAtPerimeter,
+    ProtectedByMitigatingControl,
+}
+
+impl From<Justification> for ImpactAnalysisJustification {
+    fn from(justification: Justification) -> Self {
+        match justification {
+            Justification::CodeNotPresent => Self::CodeNotPresent,
+            Justification::CodeNotReachable => Self::CodeNotReachable,
+            Justification::RequiresConfiguration => Self::RequiresConfiguration,
+            Justification::RequiresDependency => Self::RequiresDependency,
+            Justification::RequiresEnvironment => Self::RequiresEnvironment,
+            Justification::ProtectedByCompiler => Self::ProtectedByCompiler,
+            Justification::ProtectedAtRuntime => Self::ProtectedAtRuntime,
+            Justification::ProtectedAtPerimeter => Self::ProtectedAtPerimeter,
+            Justification::ProtectedByMitigatingControl => Self::ProtectedByMitigatingControl,
+        }
+    }
+}
+
+/// A suppressed advisory
+///
+/// * `state` - `not_affected` (the default) or `false_positive`
+/// * `justification` - Why the project is not affected, only for `not_affected`
+/// * `detail` - Explanation of the assessment
+/// * `expires` - Date in `YYYY-MM-DD` format from which the advisory is reported again
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Suppression {
+    pub id: String,
+    #[serde(default)]
+    pub state: SuppressedState,
+    pub justification: Option<Justification>,
+    pub detail: Option<String>,
+    pub expires: Option<String>,
+}
+
+impl Suppression {
+    fn analysis(&self) -> Analysis {
+        let mut analysis = match self.state {
+            SuppressedState::NotAffected => Analysis::new(ImpactAnalysisState::NotAffected),
+            SuppressedState::FalsePositive => Analysis::new(ImpactAnalysisState::FalsePositive),
+        };
+        analysis.justification = self.justification.map(Into::into);
+        analysis.detail = self.detail.clone();
+        analysis
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SuppressionsFile {
+    #[serde(default)]
+    ignore: Vec<Suppression>,
+}
+
+/// The suppressed advisories, by their ID
+#[derive(Debug, Default)]
+pub struct Suppressions {
+    suppressions: BTreeMap<String, Suppression>,
+}
+
+impl Suppressions {
+    /// Reads the suppressions at `path`, if given
+    pub fn open(path: Option<&Path>) -> Result<Self, SuppressionsError> {
+        let path = match path {
+            Some(path) => path,
+            None => return Ok(Self::default()),
+        };
+        let contents =
+            std::fs::read_to_string(path).map_err(|error| SuppressionsError::ReadError {
+                path: path.to_path_buf(),
+                error,
+            })?;
+        Self::parse(&contents).map_err(|message| SuppressionsError::ParseError {
+            path: path.to_path_buf(),
+            message,
+        })
+    }
+
+    fn parse(contents: &str) -> Result<Self, String> {
+        let file: SuppressionsFile = toml::from_str(contents).map_err(|error| error.to_string())?;
+        let mut suppressions = BTreeMap::new();
+        for suppression in file.ignore {
+            if suppression.justification.is_some()
+                && suppression.state != SuppressedState::NotAffected
+            {
+                return Err(format!(
+                    "The suppression of {} has a justification, which only not_affected takes",
+                    suppression.id
+                ));
+            }
+            if let Some(expires) = &suppression.expires {
+                if format!("{}T00:00:00Z", expires)
+                    .parse::<DateTime>()
+                    .is_err()
+                {
+                    return Err(format!(
+                        "The suppression of {} expires on `{}`, expected a date like 2024-06-30",
+                        suppression.id, expires
+                    ));
+                }
+            }
+            suppressions.insert(suppression.id.clone(), suppression);
+        }
+        Ok(Self { suppressions })
+    }
+
+    /// Records the analysis of the suppressed vulnerabilities of `bom` that have not expired on
+    /// `today`, in `YYYY-MM-DD` format, and warns about the expired ones
+    pub fn apply(&self, bom: &mut Bom, today: &str) {
+        for vulnerability in bom
+            .vulnerabilities
+            .iter_mut()
+            .flat_map(|vulnerabilities| vulnerabilities.0.iter_mut())
+        {
+            let suppression = match vulnerability
+                .id
+                .as_ref()
+                .and_then(|id| self.suppressions.get(&id.to_string()))
+            {
+                Some(suppression) => suppression,
+                None => continue,
+            };
+            match &suppression.expires {
+                Some(expires) if expires.as_str() <= today => log::warn!(
+                    "The suppression of {} expired on {}, reporting it again",
+                    suppression.id,
+                    expires
+                ),
+                _ => vulnerability.analysis = Some(suppression.analysis()),
+            }
+        }
+    }
+}
+
+/// The current date in `YYYY-MM-DD` format, for [`Suppressions::apply`]
+pub fn today() -> Result<String, SuppressionsError> {
+    let now = DateTime::now().map_err(|_| SuppressionsError::CurrentDateError)?;
+    Ok(now.to_string()[..10].to_string())
+}
+
+#[derive(Debug, Error)]
+pub enum SuppressionsError {
+    #[error("Could not read the suppressions {}", .path.display())]
+    ReadError {
+        path: PathBuf,
+        #[source]
+        error: std::io::Error,
+    },
+
+    #[error("Could not parse the suppressions {}: {message}", .path.display())]
+    ParseError { path: PathBuf, message: String },
+
+    #[error("Could not determine the current date")]
+    CurrentDateError,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use cyclonedx_bom::models::vulnerability::{Vulnerabilities, Vulnerability};
+
+    const SUPPRESSIONS: &str = r#"
+[[ignore]]
+id = "RUSTSEC-2021-0078"
+justification = "code_not_reachable"
+detail = "Only the HTTP/2 server of hyper is used"
+expires = "2024-06-30"
+
+[[ignore]]
+id = "RUSTSEC-2020-0071"
+state = "false_positive"
+"#;
+
+    #[test]
+    fn it_should_turn_suppressed_advisories_into_analysis_statements() {
+        let suppressions = Suppressions::parse(SUPPRESSIONS).unwrap();
+        let mut bom = Bom::default();
+        bom.vulnerabilities = Some(Vulnerabilities(
+            [
+                "RUSTSEC-2021-0078",
+                "RUSTSEC-2020-0071",
+                "RUSTSEC-2022-0001",
+            ]
+            .iter()
+            .map(|id| Vulnerability::new(Some(id)))
+            .collect(),
+        ));
+
+        suppressions.apply(&mut bom, "2024-01-01");
+
+        let vulnerabilities = &bom.vulnerabilities.as_ref().unwrap().0;
+        assert_eq!(
+            vulnerabilities[0].analysis,
+            Some(
+                Analysis::new(ImpactAnalysisState::NotAffected)
+                    .with_justification(ImpactAnalysisJustification::CodeNotReachable)
+                    .with_detail("Only the HTTP/2 server of hyper is used".to_string())
+            )
+        );
+        assert_eq!(
+            vulnerabilities[1].analysis,
+            Some(Analysis::new(ImpactAnalysisState::FalsePositive))
+        );
+        assert_eq!(vulnerabilities[2].analysis, None);
+    }
+
+    #[test]
+    fn it_should_report_expired_suppressions_again() {
+        let suppressions = Suppressions::parse(SUPPRESSIONS).unwrap();
+        let mut bom = Bom::default();
+        bom.vulnerabilities = Some(Vulnerabilities(vec![Vulnerability::new(Some(
+            "RUSTSEC-2021-0078",
+        ))]));
+
+        suppressions.apply(&mut bom, "2024-06-30");
+
+        assert_eq!(bom.vulnerabilities.unwrap().0[0].analysis, None);
+    }
+
+    #[test]
+    fn it_should_reject_invalid_suppressions() {
+        assert!(Suppressions::parse(
+            "[[ignore]]\nid = \"RUSTSEC-2020-0071\"\nstate = \"false_positive\"\njustification = \"code_not_present\""
+        )
+        .is_err());
+        assert!(
+            Suppressions::parse("[[ignore]]\nid = \"RUSTSEC-2020-0071\"\nexpires = \"soon\"")
+                .is_err()
+        );
+    }
+}
@@ -16,12 +16,21 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 use crate::config::{self, CdxExtension, PrefixError};
-use crate::config::{CustomPrefix, SbomConfig};
+use crate::config::{
+    BuildProfile, CustomPrefix, DataFlow, DeclaredService, DeclaredServices, DocumentLicense,
+    DocumentReference, DocumentReferences, Duplicates, EmbeddedAssets, EnricherConfig, Enrichers,
+    ErrorPolicy, ExternalBoms, FeatureMatrix, FeatureSets, GitPurl, LicensePreference,
+    LicenseTexts, OverridesFile, RegistryLinks, SbomConfig, ScanExclude, SourceFiles, SpecVersion,
+    Supplier, DEFAULT_MIN_ASSET_SIZE,
+};
 use crate::format::Format;
 
 use serde::Deserialize;
+use std::collections::BTreeMap;
 use std::convert::{TryFrom, TryInto};
+use std::path::PathBuf;
 use std::str::FromStr;
+use std::time::Duration;
 use thiserror::Error;
 
 pub fn config_from_toml(value: Option<&toml::value::Value>) -> Result<SbomConfig, ConfigError> {
@@ -57,16 +66,56 @@ impl TryFrom<ConfigWrapper> for SbomConfig {
 #[derive(Debug, Deserialize, PartialEq, Eq)]
 pub struct TomlConfig {
     pub format: Option<Format>,
+    pub spec_version: Option<String>,
     pub included_dependencies: Option<IncludedDependencies>,
     pub output_options: Option<OutputOptions>,
+    pub supplier: Option<TomlSupplier>,
+    pub profile: Option<String>,
+    pub embedded_assets: Option<TomlEmbeddedAssets>,
+    pub source_files: Option<bool>,
+    pub scan_exclude: Option<Vec<String>>,
+    pub license_preference: Option<Vec<String>>,
+    pub external_boms: Option<Vec<PathBuf>>,
+    pub registry_links: Option<bool>,
+    pub duplicates: Option<bool>,
+    pub feature_matrix: Option<bool>,
+    pub feature_sets: Option<BTreeMap<String, Vec<String>>>,
+    pub on_error: Option<TomlOnError>,
+    pub services: Option<Vec<TomlService>>,
+    pub external_references: Option<Vec<TomlExternalReference>>,
+    pub enrichers: Option<Vec<TomlEnricher>>,
+    pub document_license: Option<TomlDocumentLicense>,
+    pub git_purl: Option<String>,
+    pub license_texts: Option<bool>,
+    pub overrides: Option<PathBuf>,
 }
 
 impl TomlConfig {
     pub fn empty_config() -> Self {
         Self {
             format: None,
+            spec_version: None,
             included_dependencies: None,
             output_options: None,
+            supplier: None,
+            profile: None,
+            embedded_assets: None,
+            source_files: None,
+            scan_exclude: None,
+            license_preference: None,
+            external_boms: None,
+            registry_links: None,
+            duplicates: None,
+            feature_matrix: None,
+            feature_sets: None,
+            on_error: None,
+            services: None,
+            external_references: None,
+            enrichers: None,
+            document_license: None,
+            git_purl: None,
+            license_texts: None,
+            overrides: None,
         }
     }
 }
@@ -82,24 +131,292 @@ impl TryFrom<TomlConfig> for SbomConfig {
 
         Ok(Self {
             format: value.format,
+            spec_version: value
+                .spec_version
+                .map(|version| version.parse::<SpecVersion>())
+                .transpose()
+                .map_err(|error| ConfigError::ValidationError(error.to_string()))?,
             included_dependencies: value.included_dependencies.map(Into::into),
             output_options,
+            supplier: value.supplier.map(Into::into),
+            profile: value
+                .profile
+                .map(BuildProfile::new)
+                .transpose()
+                .map_err(ConfigError::ValidationError)?,
+            embedded_assets: value.embedded_assets.map(Into::into),
+            source_files: value.source_files.map(|included| {
+                if included {
+                    SourceFiles::Included
+                } else {
+                    SourceFiles::NotIncluded
+                }
+            }),
+            scan_exclude: value
+                .scan_exclude
+                .map(ScanExclude::new)
+                .transpose()
+                .map_err(ConfigError::ValidationError)?,
+            license_preference: value
+                .license_preference
+                .map(LicensePreference::new)
+                .transpose()
+                .map_err(ConfigError::ValidationError)?,
+            external_boms: value.external_boms.map(ExternalBoms::new),
+            registry_links: value.registry_links.map(|included| {
+                if included {
+                    RegistryLinks::Included
+                } else {
+                    RegistryLinks::NotIncluded
+                }
+            }),
+            duplicates: value.duplicates.map(|annotated| {
+                if annotated {
+                    Duplicates::Annotated
+                } else {
+                    Duplicates::NotAnnotated
+                }
+            }),
+            feature_matrix: value.feature_matrix.map(|enabled| {
+                if enabled {
+                    FeatureMatrix::Enabled
+                } else {
+                    FeatureMatrix::Disabled
+                }
+            }),
+            feature_sets: value
+                .feature_sets
+                .map(FeatureSets::new)
+                .transpose()
+                .map_err(ConfigError::ValidationError)?,
+            on_error: value
+                .on_error
+                .map(ErrorPolicy::try_from)
+                .transpose()
+                .map_err(ConfigError::ValidationError)?,
+            services: value
+                .services
+                .map(|services| {
+                    services
+                        .into_iter()
+                        .map(DeclaredService::try_from)
+                        .collect::<Result<Vec<_>, _>>()
+                        .and_then(DeclaredServices::new)
+                })
+                .transpose()
+                .map_err(ConfigError::ValidationError)?,
+            external_references: value
+                .external_references
+                .map(|references| {
+                    references
+                        .iter()
+                        .map(|reference| {
+                            DocumentReference::new(
+                                &reference.reference_type,
+                                &reference.url,
+                                reference.comment.as_deref(),
+                            )
+                        })
+                        .collect::<Result<Vec<_>, _>>()
+                        .map(DocumentReferences::new)
+                })
+                .transpose()
+                .map_err(ConfigError::ValidationError)?,
+            enrichers: value
+                .enrichers
+                .map(|enrichers| {
+                    enrichers
+                        .into_iter()
+                        .map(EnricherConfig::try_from)
+                        .collect::<Result<Vec<_>, _>>()
+                        .and_then(Enrichers::new)
+                })
+                .transpose()
+                .map_err(ConfigError::ValidationError)?,
+            document_license: value
+                .document_license
+                .map(DocumentLicense::try_from)
+                .transpose()
+                .map_err(ConfigError::ValidationError)?,
+            git_purl: value
+                .git_purl
+                .map(|git_purl| git_purl.parse::<GitPurl>())
+                .transpose()
+                .map_err(ConfigError::ValidationError)?,
+            license_texts: value.license_texts.map(|included| {
+                if included {
+                    LicenseTexts::Included
+                } else {
+                    LicenseTexts::NotIncluded
+                }
+            }),
+            overrides: value.overrides.map(OverridesFile::new),
         })
     }
 }
 
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+pub struct TomlSupplier {
+    pub name: String,
+    pub url: Option<String>,
+}
+
+impl From<TomlSupplier> for Supplier {
+    fn from(value: TomlSupplier) -> Self {
+        Self {
+            name: value.name,
+            url: value.url,
+        }
+    }
+}
+
+/// Either `true`/`false` or the minimum size in bytes of the assets to list
 #[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum TomlEmbeddedAssets {
+    Enabled(bool),
+    MinSize(u64),
+}
+
+impl From<TomlEmbeddedAssets> for EmbeddedAssets {
+    fn from(value: TomlEmbeddedAssets) -> Self {
+        match value {
+            TomlEmbeddedAssets::Enabled(false) => Self::Disabled,
+            TomlEmbeddedAssets::Enabled(true) => Self::Enabled {
+                min_size: DEFAULT_MIN_ASSET_SIZE,
+            },
+            TomlEmbeddedAssets::MinSize(min_size) => Self::Enabled { min_size },
+        }
+    }
+}
+
+/// Either the handling of all subsystems, e.g. `"warn"`, or a table of subsystems with their
+/// handling, e.g. `{ hashing = "fail" }`
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum TomlOnError {
+    All(String),
+    Subsystems(BTreeMap<String, String>),
+}
+
+impl TryFrom<TomlOnError> for ErrorPolicy {
+    type Error = String;
+
+    fn try_from(value: TomlOnError) -> Result<Self, Self::Error> {
+        match value {
+            TomlOnError::All(on_error) => Ok(Self::all(on_error.parse()?)),
+            TomlOnError::Subsystems(subsystems) => {
+                let mut policy = Self::default();
+                for (subsystem, on_error) in subsystems {
+                    policy.set(&subsystem, on_error.parse()?)?;
+                }
+                Ok(policy)
+            }
+        }
+    }
+}
+
+/// A `[[cyclonedx.services]]` entry
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+pub struct TomlService {
+    pub name: String,
+    pub description: Option<String>,
+    pub endpoints: Option<Vec<String>>,
+    pub authenticated: Option<bool>,
+    pub x_trust_boundary: Option<bool>,
+    pub data: Option<Vec<TomlDataFlow>>,
+}
+
+impl TryFrom<TomlService> for DeclaredService {
+    type Error = String;
+
+    fn try_from(value: TomlService) -> Result<Self, Self::Error> {
+        let data = value
+            .data
+            .unwrap_or_default()
+            .into_iter()
+            .map(|data| {
+                Ok(DataFlow {
+                    flow: data.flow.parse()?,
+                    classification: data.classification,
+                })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        Ok(Self {
+            name: value.name,
+            description: value.description,
+            endpoints: value.endpoints.unwrap_or_default(),
+            authenticated: value.authenticated,
+            x_trust_boundary: value.x_trust_boundary,
+            data,
+        })
+    }
+}
+
+/// A `[[cyclonedx.external_references]]` entry
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+pub struct TomlExternalReference {
+    #[serde(rename = "type")]
+    pub reference_type: String,
+    pub url: String,
+    pub comment: Option<String>,
+}
+
+/// A `[[cyclonedx.enrichers]]` entry
+///
+/// * `timeout` - In seconds
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+pub struct TomlEnricher {
+    pub name: String,
+    pub timeout: Option<u64>,
+}
+
+impl TryFrom<TomlEnricher> for EnricherConfig {
+    type Error = String;
+
+    fn try_from(value: TomlEnricher) -> Result<Self, Self::Error> {
+        Ok(Self {
+            kind: value.name.parse()?,
+            timeout: value.timeout.map(Duration::from_secs),
+        })
+    }
+}
+
+/// Either an SPDX expression or a table with the name and URL of other terms
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum TomlDocumentLicense {
+    Expression(String),
+    Named { name: String, url: Option<String> },
+}
+
+impl TryFrom<TomlDocumentLicense> for DocumentLicense {
+    type Error = String;
+
+    fn try_from(value: TomlDocumentLicense) -> Result<Self, Self::Error> {
+        match value {
+            TomlDocumentLicense::Expression(expression) => Self::expression(&expression),
+            TomlDocumentLicense::Named { name, url } => Self::named(&name, url.as_deref()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+pub struct TomlDataFlow {
+    pub flow: String,
+    pub classification: String,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq, Default)]
 pub enum IncludedDependencies {
     #[serde(rename(deserialize = "top-level"))]
+    #[default]
     TopLevelDependencies,
     #[serde(rename(deserialize = "all"))]
     AllDependencies,
-}
-
-impl Default for IncludedDependencies {
-    fn default() -> Self {
-        Self::TopLevelDependencies
-    }
+    #[serde(rename(deserialize = "library"))]
+    Library,
 }
 
 impl FromStr for IncludedDependencies {
@@ -109,7 +426,8 @@ impl FromStr for IncludedDependencies {
         match s {
             "all" => Ok(Self::AllDependencies),
             "top-level" => Ok(Self::TopLevelDependencies),
-            _ => Err(format!("Expected all or top-level, got `{}`", s)),
+            "library" => Ok(Self::Library),
+            _ => Err(format!("Expected all, top-level or library, got `{}`", s)),
         }
     }
 }
@@ -119,6 +437,7 @@ impl From<IncludedDependencies> for config::IncludedDependencies {
         match val {
             IncludedDependencies::TopLevelDependencies => Self::TopLevelDependencies,
             IncludedDependencies::AllDependencies => Self::AllDependencies,
+            IncludedDependencies::Library => Self::Library,
         }
     }
 }
@@ -161,19 +480,14 @@ impl TryFrom<OutputOptions> for config::OutputOptions {
     }
 }
 
-#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq, Default)]
 #[serde(rename_all(deserialize = "lowercase"))]
 pub enum Pattern {
+    #[default]
     Bom,
     Package,
 }
 
-impl Default for Pattern {
-    fn default() -> Self {
-        Self::Bom
-    }
-}
-
 impl FromStr for Pattern {
     type Err = String;
 
@@ -216,25 +530,150 @@ mod test {
         let toml = r#"
 [cyclonedx]
 format = "json"
+spec_version = "1.3"
 included_dependencies = "top-level"
 output_options = { cdx = true, pattern = "bom", prefix = "tacos" }
+supplier = { name = "Tacos Inc", url = "https://tacos.example.com" }
+profile = "dist"
+embedded_assets = 1024
+source_files = true
+scan_exclude = ["vendor/**"]
+license_preference = ["MIT"]
+external_boms = ["frontend/bom.json"]
+registry_links = true
+duplicates = true
+feature_matrix = true
+feature_sets = { minimal = [], tls = ["rustls"] }
+on_error = { registry = "warn" }
+document_license = "CC0-1.0"
+git_purl = "repository"
+license_texts = true
+overrides = "overrides.toml"
+
+[[cyclonedx.services]]
+name = "payments"
+endpoints = ["https://payments.example.com/v1"]
+data = [{ flow = "outbound", classification = "PII" }]
+
+[[cyclonedx.external_references]]
+type = "build-system"
+url = "https://ci.example.com/runs/42"
+comment = "CI run"
+
+[[cyclonedx.enrichers]]
+name = "hashes"
+timeout = 30
 "#;
 
         let actual: ConfigWrapper = toml::from_str(toml).expect("Failed to parse toml");
 
         let expected = TomlConfig {
             format: Some(Format::Json),
+            spec_version: Some("1.3".to_string()),
             included_dependencies: Some(IncludedDependencies::TopLevelDependencies),
             output_options: Some(OutputOptions {
                 cdx_extension: Some(true),
                 prefix: Some("tacos".to_string()),
                 pattern: Some(Pattern::Bom),
             }),
+            supplier: Some(TomlSupplier {
+                name: "Tacos Inc".to_string(),
+                url: Some("https://tacos.example.com".to_string()),
+            }),
+            profile: Some("dist".to_string()),
+            embedded_assets: Some(TomlEmbeddedAssets::MinSize(1024)),
+            source_files: Some(true),
+            scan_exclude: Some(vec!["vendor/**".to_string()]),
+            license_preference: Some(vec!["MIT".to_string()]),
+            external_boms: Some(vec![PathBuf::from("frontend/bom.json")]),
+            registry_links: Some(true),
+            duplicates: Some(true),
+            feature_matrix: Some(true),
+            feature_sets: Some(BTreeMap::from([
+                ("minimal".to_string(), vec![]),
+                ("tls".to_string(), vec!["rustls".to_string()]),
+            ])),
+            on_error: Some(TomlOnError::Subsystems(BTreeMap::from([(
+                "registry".to_string(),
+                "warn".to_string(),
+            )]))),
+            services: Some(vec![TomlService {
+                name: "payments".to_string(),
+                description: None,
+                endpoints: Some(vec!["https://payments.example.com/v1".to_string()]),
+                authenticated: None,
+                x_trust_boundary: None,
+                data: Some(vec![TomlDataFlow {
+                    flow: "outbound".to_string(),
+                    classification: "PII".to_string(),
+                }]),
+            }]),
+            external_references: Some(vec![TomlExternalReference {
+                reference_type: "build-system".to_string(),
+                url: "https://ci.example.com/runs/42".to_string(),
+                comment: Some("CI run".to_string()),
+            }]),
+            enrichers: Some(vec![TomlEnricher {
+                name: "hashes".to_string(),
+                timeout: Some(30),
+            }]),
+            document_license: Some(TomlDocumentLicense::Expression("CC0-1.0".to_string())),
+            git_purl: Some("repository".to_string()),
+            license_texts: Some(true),
+            overrides: Some(PathBuf::from("overrides.toml")),
         };
 
         assert_eq!(actual.cyclonedx, Some(expected));
     }
 
+    #[test]
+    fn it_should_convert_embedded_assets_settings() {
+        for (toml, expected) in [
+            (
+                "true",
+                EmbeddedAssets::Enabled {
+                    min_size: DEFAULT_MIN_ASSET_SIZE,
+                },
+            ),
+            ("false", EmbeddedAssets::Disabled),
+            ("0", EmbeddedAssets::Enabled { min_size: 0 }),
+        ] {
+            let toml = format!("[cyclonedx]\nembedded_assets = {}", toml);
+            let value: toml::Value = toml::from_str(&toml).expect("Failed to parse toml");
+
+            let actual = config_from_toml(Some(&value)).expect("Failed to convert config");
+
+            assert_eq!(actual.embedded_assets, Some(expected));
+        }
+    }
+
+    #[test]
+    fn it_should_convert_error_policies() {
+        for (toml, expected) in [
+            ("\"skip\"", Ok(ErrorPolicy::all(config::OnError::Skip))),
+            (
+                "{ hashing = \"fail\" }",
+                Ok(ErrorPolicy {
+                    hashing: config::OnError::Fail,
+                    registry: config::OnError::Fail,
+                }),
+            ),
+            (
+                "{ network = \"fail\" }",
+                Err(ConfigError::ValidationError(
+                    "Expected the subsystem hashing or registry, got `network`".to_string(),
+                )),
+            ),
+        ] {
+            let toml = format!("[cyclonedx]\non_error = {}", toml);
+            let value: toml::Value = toml::from_str(&toml).expect("Failed to parse toml");
+
+            let actual = config_from_toml(Some(&value)).map(|config| config.on_error);
+
+            assert_eq!(actual, expected.map(Some));
+        }
+    }
+
     #[test]
     fn it_should_return_an_error_for_mutually_exclusive_options() {
         let options = OutputOptions {
@@ -0,0 +1,463 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+use crate::build_info::BuildInfo;
+use cyclonedx_bom::external_models::uri::Uri;
+use cyclonedx_bom::models::bom::Bom;
+use cyclonedx_bom::models::component::{Classification, Component, Components};
+use cyclonedx_bom::models::external_reference::{
+    ExternalReference, ExternalReferenceType, ExternalReferences,
+};
+use cyclonedx_bom::models::hash::{Hash, HashAlgorithm, Hashes};
+use cyclonedx_bom::models::metadata::{Metadata, MetadataError};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// Components that are linked into or compiled with the programs built by a toolchain, rather
+/// than run as programs themselves
+const LIBRARY_COMPONENTS: &[&str] = &["rust-std", "rust-src", "rustc-dev"];
+
+/// A toolchain installed with rustup
+///
+/// * `name` - Name of the toolchain directory, e.g. `stable-x86_64-unknown-linux-gnu`
+/// * `channel` - Release channel, version or dated nightly, e.g. `stable` or `nightly-2023-06-01`
+/// * `host` - Target triple the toolchain runs on
+/// * `date` - Release date of the toolchain
+/// * `is_default` - Whether it is the default toolchain of rustup
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Toolchain {
+    pub name: String,
+    pub channel: String,
+    pub host: Option<String>,
+    pub version: Option<String>,
+    pub date: Option<String>,
+    pub is_default: bool,
+    pub components: Vec<ToolchainComponent>,
+}
+
+/// A rustup component installed into a toolchain
+///
+/// * `target` - Target triple of the component, `None` for target independent components like
+///   `rust-src`
+/// * `url` - Archive the component was installed from
+/// * `sha256` - SHA-256 hash of the archive
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ToolchainComponent {
+    pub name: String,
+    pub target: Option<String>,
+    pub version: Option<String>,
+    pub url: Option<String>,
+    pub sha256: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct Settings {
+    default_toolchain: Option<String>,
+}
+
+/// The parts of `multirust-channel-manifest.toml` describing the installed packages
+#[derive(Debug, Default, Deserialize)]
+struct ChannelManifest {
+    date: Option<String>,
+    #[serde(default)]
+    pkg: BTreeMap<String, ManifestPackage>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ManifestPackage {
+    version: Option<String>,
+    #[serde(default)]
+    target: BTreeMap<String, ManifestTarget>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ManifestTarget {
+    url: Option<String>,
+    hash: Option<String>,
+    xz_url: Option<String>,
+    xz_hash: Option<String>,
+}
+
+/// Reads the toolchains installed into `rustup_home`, sorted by name
+///
+/// Returns no toolchains if rustup never installed one there.
+pub fn installed_toolchains(rustup_home: &Path) -> Result<Vec<Toolchain>, ToolchainError> {
+    let settings: Settings = match read_optional(&rustup_home.join("settings.toml"))? {
+        Some(settings) => toml::from_str(&settings)?,
+        None => Settings::default(),
+    };
+
+    let toolchains_dir = rustup_home.join("toolchains");
+    let entries = match std::fs::read_dir(&toolchains_dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(error) => {
+            return Err(ToolchainError::ReadError {
+                path: toolchains_dir,
+                error,
+            })
+        }
+    };
+
+    let mut toolchains = Vec::new();
+    for entry in entries {
+        let entry = entry.map_err(|error| ToolchainError::ReadError {
+            path: toolchains_dir.clone(),
+            error,
+        })?;
+        let name = entry.file_name().to_string_lossy().into_owned();
+
+        // `rustup toolchain link` creates symlinks to custom toolchains, which are not managed
+        // by rustup and have no components
+        let rustlib = entry.path().join("lib").join("rustlib");
+        let components = match read_optional(&rustlib.join("components"))? {
+            Some(components) => components,
+            None => {
+                log::warn!(
+                    "Skipping toolchain {}, it was not installed by rustup",
+                    name
+                );
+                continue;
+            }
+        };
+        let manifest: ChannelManifest =
+            match read_optional(&rustlib.join("multirust-channel-manifest.toml"))? {
+                Some(manifest) => toml::from_str(&manifest)?,
+                None => ChannelManifest::default(),
+            };
+
+        let is_default = settings.default_toolchain.as_deref() == Some(name.as_str());
+        toolchains.push(parse_toolchain(name, &components, &manifest, is_default));
+    }
+    toolchains.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Ok(toolchains)
+}
+
+fn read_optional(path: &Path) -> Result<Option<String>, ToolchainError> {
+    match std::fs::read_to_string(path) {
+        Ok(contents) => Ok(Some(contents)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(error) => Err(ToolchainError::ReadError {
+            path: path.to_path_buf(),
+            error,
+        }),
+    }
+}
+
+fn parse_toolchain(
+    name: String,
+    components: &str,
+    manifest: &ChannelManifest,
+    is_default: bool,
+) -> Toolchain {
+    let components: Vec<_> = components
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| parse_component(line, manifest))
+        .collect();
+
+    let host = components
+        .iter()
+        .find(|component| component.name == "rustc")
+        .and_then(|rustc| rustc.target.clone());
+    let channel = host
+        .as_ref()
+        .and_then(|host| name.strip_suffix(&format!("-{}", host)))
+        .unwrap_or(&name)
+        .to_string();
+    let version = components
+        .iter()
+        .find(|component| component.name == "rustc")
+        .and_then(|rustc| rustc.version.clone());
+
+    Toolchain {
+        name,
+        channel,
+        host,
+        version,
+        date: manifest.date.clone(),
+        is_default,
+        components,
+    }
+}
+
+/// Splits an installed component like `rust-std-wasm32-unknown-unknown` into the name and the
+/// target of the package in the channel manifest
+fn parse_component(installed: &str, manifest: &ChannelManifest) -> ToolchainComponent {
+    let known = manifest.pkg.iter().find_map(|(name, package)| {
+        if installed == name {
+            return Some((name, package, "*"));
+        }
+        let target = installed.strip_prefix(name)?.strip_prefix('-')?;
+        package
+            .target
+            .contains_key(target)
+            .then_some((name, package, target))
+    });
+
+    let (name, package, target) = match known {
+        Some(known) => known,
+        None => {
+            log::warn!(
+                "Component {} is missing from the channel manifest",
+                installed
+            );
+            return ToolchainComponent {
+                name: installed.to_string(),
+                target: None,
+                version: None,
+                url: None,
+                sha256: None,
+            };
+        }
+    };
+
+    let archive = package.target.get(target);
+    ToolchainComponent {
+        name: name.clone(),
+        target: (target != "*").then(|| target.to_string()),
+        // e.g. `1.70.0 (90c541806 2023-05-31)`
+        version: package
+            .version
+            .as_deref()
+            .and_then(|version| version.split_whitespace().next())
+            .map(str::to_string),
+        url: archive.and_then(|archive| archive.xz_url.clone().or_else(|| archive.url.clone())),
+        sha256: archive.and_then(|archive| {
+            if archive.xz_url.is_some() {
+                archive.xz_hash.clone()
+            } else {
+                archive.hash.clone()
+            }
+        }),
+    }
+}
+
+/// Creates an SBOM listing the toolchains, with their rustup components nested in them
+pub fn create_toolchains_bom(toolchains: &[Toolchain]) -> Result<Bom, ToolchainError> {
+    let mut metadata = Metadata::new()?;
+    BuildInfo::current().record(&mut metadata);
+
+    let components = toolchains.iter().map(create_toolchain_component).collect();
+
+    let mut bom = Bom::default();
+    bom.metadata = Some(metadata);
+    bom.components = Some(Components(components));
+
+    Ok(bom)
+}
+
+fn create_toolchain_component(toolchain: &Toolchain) -> Component {
+    let mut component = Component::new(
+        Classification::Framework,
+        &toolchain.name,
+        toolchain.version.as_deref().unwrap_or_default(),
+        Some(format!("rustup:{}", toolchain.name)),
+    );
+
+    component.upsert_property("rustup:channel", &toolchain.channel);
+    if let Some(host) = &toolchain.host {
+        component.upsert_property("rustup:host", host);
+    }
+    if let Some(date) = &toolchain.date {
+        component.upsert_property("rustup:date", date);
+    }
+    if toolchain.is_default {
+        component.upsert_property("rustup:default", "true");
+    }
+
+    let components: Vec<_> = toolchain
+        .components
+        .iter()
+        .map(|installed| create_installed_component(toolchain, installed))
+        .collect();
+    if !components.is_empty() {
+        component.components = Some(Components(components));
+    }
+
+    component
+}
+
+fn create_installed_component(toolchain: &Toolchain, installed: &ToolchainComponent) -> Component {
+    let classification = if LIBRARY_COMPONENTS.contains(&installed.name.as_str()) {
+        Classification::Library
+    } else {
+        Classification::Application
+    };
+    let bom_ref = match &installed.target {
+        Some(target) => format!("rustup:{}#{}-{}", toolchain.name, installed.name, target),
+        None => format!("rustup:{}#{}", toolchain.name, installed.name),
+    };
+
+    let mut component = Component::new(
+        classification,
+        &installed.name,
+        installed.version.as_deref().unwrap_or_default(),
+        Some(bom_ref),
+    );
+    if let Some(target) = &installed.target {
+        component.upsert_property("rustup:target", target);
+    }
+
+    if let Some(url) = &installed.url {
+        match Uri::try_from(url.clone()) {
+            Ok(uri) => {
+                let mut reference =
+                    ExternalReference::new(ExternalReferenceType::Distribution, uri);
+                if let Some(sha256) = &installed.sha256 {
+                    reference = reference
+                        .with_hashes(Hashes(vec![Hash::new(HashAlgorithm::SHA256, sha256)]));
+                }
+                component.external_references = Some(ExternalReferences(vec![reference]));
+            }
+            Err(e) => log::error!(
+                "Component {} has an invalid download URI ({}): {} ",
+                installed.name,
+                url,
+                e
+            ),
+        }
+    }
+
+    component
+}
+
+#[derive(Error, Debug)]
+pub enum ToolchainError {
+    #[error("Could not read {}", .path.display())]
+    ReadError {
+        path: PathBuf,
+        #[source]
+        error: std::io::Error,
+    },
+
+    #[error("Invalid rustup settings or channel manifest")]
+    ParseError(#[from] toml::de::Error),
+
+    #[error("Error creating Metadata")]
+    MetadataError(#[from] MetadataError),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const MANIFEST: &str = r#"
+manifest-version = "2"
+date = "2023-06-01"
+
+[pkg.rustc]
+version = "1.70.0 (90c541806 2023-05-31)"
+
+[pkg.rustc.target.x86_64-unknown-linux-gnu]
+available = true
+url = "https://static.rust-lang.org/dist/2023-06-01/rustc-1.70.0-x86_64-unknown-linux-gnu.tar.gz"
+hash = "aaaa"
+xz_url = "https://static.rust-lang.org/dist/2023-06-01/rustc-1.70.0-x86_64-unknown-linux-gnu.tar.xz"
+xz_hash = "bbbb"
+
+[pkg.rust-std]
+version = "1.70.0 (90c541806 2023-05-31)"
+
+[pkg.rust-std.target.x86_64-unknown-linux-gnu]
+available = true
+
+[pkg.rust-std.target.wasm32-unknown-unknown]
+available = true
+
+[pkg.rust-src]
+version = "1.70.0 (90c541806 2023-05-31)"
+
+[pkg.rust-src.target."*"]
+available = true
+url = "https://static.rust-lang.org/dist/2023-06-01/rust-src-1.70.0.tar.gz"
+hash = "cccc"
+"#;
+
+    #[test]
+    fn it_should_parse_the_installed_components() {
+        let manifest: ChannelManifest = toml::from_str(MANIFEST).expect("Invalid manifest");
+        let toolchain = parse_toolchain(
+            "stable-x86_64-unknown-linux-gnu".to_string(),
+            "rustc-x86_64-unknown-linux-gnu\nrust-std-wasm32-unknown-unknown\nrust-src\nmiri-preview\n",
+            &manifest,
+            true,
+        );
+
+        assert_eq!(toolchain.channel, "stable");
+        assert_eq!(toolchain.host.as_deref(), Some("x86_64-unknown-linux-gnu"));
+        assert_eq!(toolchain.version.as_deref(), Some("1.70.0"));
+        assert_eq!(toolchain.date.as_deref(), Some("2023-06-01"));
+        assert_eq!(
+            toolchain.components,
+            vec![
+                ToolchainComponent {
+                    name: "rustc".to_string(),
+                    target: Some("x86_64-unknown-linux-gnu".to_string()),
+                    version: Some("1.70.0".to_string()),
+                    url: Some("https://static.rust-lang.org/dist/2023-06-01/rustc-1.70.0-x86_64-unknown-linux-gnu.tar.xz".to_string()),
+                    sha256: Some("bbbb".to_string()),
+                },
+                ToolchainComponent {
+                    name: "rust-std".to_string(),
+                    target: Some("wasm32-unknown-unknown".to_string()),
+                    version: Some("1.70.0".to_string()),
+                    url: None,
+                    sha256: None,
+                },
+                ToolchainComponent {
+                    name: "rust-src".to_string(),
+                    target: None,
+                    version: Some("1.70.0".to_string()),
+                    url: Some(
+                        "https://static.rust-lang.org/dist/2023-06-01/rust-src-1.70.0.tar.gz"
+                            .to_string()
+                    ),
+                    sha256: Some("cccc".to_string()),
+                },
+                ToolchainComponent {
+                    name: "miri-preview".to_string(),
+                    target: None,
+                    version: None,
+                    url: None,
+                    sha256: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_keep_the_channel_of_toolchains_without_rustc() {
+        let toolchain = parse_toolchain(
+            "nightly-2023-06-01-x86_64-unknown-linux-gnu".to_string(),
+            "",
+            &ChannelManifest::default(),
+            false,
+        );
+
+        assert_eq!(
+            toolchain.channel,
+            "nightly-2023-06-01-x86_64-unknown-linux-gnu"
+        );
+        assert_eq!(toolchain.host, None);
+    }
+}
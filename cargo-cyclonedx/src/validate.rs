@@ -0,0 +1,215 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use cyclonedx_bom::errors::BomReadError;
+use cyclonedx_bom::format::DocumentFormat;
+use cyclonedx_bom::models::bom::{Bom, SpecVersion};
+use cyclonedx_bom::validation::{
+    Severity, Validate, ValidationError, ValidationOptions, ValidationReport,
+};
+use serde::Serialize;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use thiserror::Error;
+
+/// The result of validating an existing BOM file
+///
+/// * `format` - Encoding detected from the content of the file
+/// * `gzip` - Whether the file was compressed with gzip
+/// * `spec_version` - Version of the specification the BOM declares
+/// * `report` - The failure reasons of at least the minimum severity
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BomValidation {
+    pub path: PathBuf,
+    pub format: DocumentFormat,
+    pub gzip: bool,
+    pub spec_version: SpecVersion,
+    #[serde(flatten)]
+    pub report: ValidationReport,
+}
+
+impl fmt::Display for BomValidation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{}: CycloneDX {} {}{}",
+            self.path.display(),
+            self.spec_version,
+            self.format,
+            if self.gzip {
+                ", compressed with gzip"
+            } else {
+                ""
+            }
+        )?;
+        for reason in &self.report.reasons {
+            let location = match self.format {
+                DocumentFormat::Xml => &reason.xpath,
+                _ => &reason.json_pointer,
+            };
+            writeln!(
+                f,
+                "  {}: {} at {}",
+                reason.severity, reason.message, location
+            )?;
+        }
+
+        let errors = self
+            .report
+            .reasons
+            .iter()
+            .filter(|reason| reason.severity == Severity::Error)
+            .count();
+        if self.report.passed {
+            writeln!(f, "Valid, {} reason(s) reported", self.report.reasons.len())
+        } else {
+            writeln!(f, "Invalid, {} error(s) reported", errors)
+        }
+    }
+}
+
+/// Reads the BOM file at `path` in any supported format and validates it, reporting the failure
+/// reasons of at least `minimum_severity`
+pub fn validate(path: &Path, minimum_severity: Severity) -> Result<BomValidation, ValidateError> {
+    let input = std::fs::read(path).map_err(|error| ValidateError::ReadError {
+        path: path.to_path_buf(),
+        error,
+    })?;
+    let (bom, detected) = Bom::parse(&input).map_err(|error| ValidateError::ParseError {
+        path: path.to_path_buf(),
+        error,
+    })?;
+    let result = bom
+        .validate_with_options(ValidationOptions { minimum_severity })
+        .map_err(|error| ValidateError::ValidationError {
+            path: path.to_path_buf(),
+            error,
+        })?;
+
+    Ok(BomValidation {
+        path: path.to_path_buf(),
+        format: detected.format,
+        gzip: detected.gzip,
+        spec_version: detected.spec_version,
+        report: ValidationReport::from(&result),
+    })
+}
+
+#[derive(Debug, Error)]
+pub enum ValidateError {
+    #[error("Failed to read {}", .path.display())]
+    ReadError {
+        path: PathBuf,
+        #[source]
+        error: std::io::Error,
+    },
+
+    #[error("Failed to parse {}", .path.display())]
+    ParseError {
+        path: PathBuf,
+        #[source]
+        error: BomReadError,
+    },
+
+    #[error("Failed to validate {}", .path.display())]
+    ValidationError {
+        path: PathBuf,
+        #[source]
+        error: ValidationError,
+    },
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ValidationFormat {
+    #[default]
+    Text,
+    Json,
+}
+
+impl FromStr for ValidationFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(ValidationFormat::Text),
+            "json" => Ok(ValidationFormat::Json),
+            _ => Err(format!("Expected text or json, got `{}`", s)),
+        }
+    }
+}
+
+impl fmt::Display for ValidationFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationFormat::Text => "text".fmt(f),
+            ValidationFormat::Json => "json".fmt(f),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn validate_str(content: &str, minimum_severity: Severity) -> BomValidation {
+        let tmp_dir = assert_fs::TempDir::new().expect("Failed to create temporary directory");
+        let path = tmp_dir.path().join("bom");
+        std::fs::write(&path, content).expect("Failed to write BOM");
+        validate(&path, minimum_severity).expect("Failed to validate")
+    }
+
+    #[test]
+    fn it_should_locate_the_failures_in_json_documents() {
+        let validation = validate_str(
+            r#"{
+  "bomFormat": "CycloneDX",
+  "specVersion": "1.4",
+  "version": 1,
+  "metadata": {
+    "component": { "type": "application", "name": "tacos", "version": "1.0.0", "purl": "tacos" }
+  }
+}"#,
+            Severity::Error,
+        );
+
+        assert_eq!(validation.format, DocumentFormat::Json);
+        assert_eq!(validation.spec_version, SpecVersion::V1_4);
+        assert!(!validation.report.passed);
+        let text = validation.to_string();
+        assert!(text.contains(
+            "  error: Purl does not conform to Package URL spec: missing scheme at /metadata/component/purl\n"
+        ));
+        assert!(text.ends_with("Invalid, 1 error(s) reported\n"));
+    }
+
+    #[test]
+    fn it_should_locate_the_failures_in_xml_documents() {
+        let validation = validate_str(
+            r#"<?xml version="1.0" encoding="utf-8"?>
+<bom xmlns="http://cyclonedx.org/schema/bom/1.3" version="1" />"#,
+            Severity::Warning,
+        );
+
+        assert_eq!(validation.format, DocumentFormat::Xml);
+        assert_eq!(validation.spec_version, SpecVersion::V1_3);
+        assert!(validation.report.passed);
+        assert!(validation.to_string().contains(" at /bom/"));
+    }
+}
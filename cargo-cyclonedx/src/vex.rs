@@ -0,0 +1,596 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use crate::build_info::BuildInfo;
+use crate::generator::{Document, GeneratedSbom};
+use cyclonedx_bom::external_models::normalized_string::NormalizedString;
+use cyclonedx_bom::external_models::uri::Uri;
+use cyclonedx_bom::models::bom::{Bom, SpecVersion};
+use cyclonedx_bom::models::metadata::{Metadata, MetadataError};
+use cyclonedx_bom::models::vulnerability::{
+    Advisory, AffectedStatus, AffectedVersion, ScoreMethod, VersionRange, Vulnerabilities,
+    Vulnerability, VulnerabilityRating, VulnerabilityReference, VulnerabilitySource,
+    VulnerabilityTarget,
+};
+use semver::{Comparator, Op, Version, VersionReq};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+const RUSTSEC_ADVISORIES: &str = "https://rustsec.org/advisories";
+
+/// A security advisory of the [RustSec advisory database](https://rustsec.org)
+///
+/// * `aliases` - Identifiers of the same vulnerability in other databases, e.g. CVEs
+/// * `cvss` - CVSS vector of the vulnerability
+/// * `patched` - Versions in which the vulnerability is fixed
+/// * `unaffected` - Versions that never had the vulnerability
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RustsecAdvisory {
+    pub id: String,
+    pub package: String,
+    pub title: String,
+    pub description: String,
+    pub date: String,
+    pub url: Option<String>,
+    pub aliases: Vec<String>,
+    pub cvss: Option<String>,
+    pub patched: Vec<VersionReq>,
+    pub unaffected: Vec<VersionReq>,
+}
+
+impl RustsecAdvisory {
+    /// Whether `version` of the package has the vulnerability
+    pub fn affects(&self, version: &Version) -> bool {
+        !self
+            .patched
+            .iter()
+            .chain(&self.unaffected)
+            .any(|req| req.matches(version))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct AdvisoryFile {
+    advisory: AdvisoryMetadata,
+    #[serde(default)]
+    versions: AdvisoryVersions,
+}
+
+#[derive(Debug, Deserialize)]
+struct AdvisoryMetadata {
+    id: String,
+    package: String,
+    date: String,
+    url: Option<String>,
+    #[serde(default)]
+    aliases: Vec<String>,
+    cvss: Option<String>,
+    informational: Option<String>,
+    withdrawn: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct AdvisoryVersions {
+    #[serde(default)]
+    patched: Vec<VersionReq>,
+    #[serde(default)]
+    unaffected: Vec<VersionReq>,
+}
+
+/// The vulnerabilities of the RustSec advisory database, by the name of the affected crate
+#[derive(Debug, Default)]
+pub struct AdvisoryDb {
+    advisories: BTreeMap<String, Vec<RustsecAdvisory>>,
+}
+
+impl AdvisoryDb {
+    /// Location of the advisory database in `cargo_home`, where `cargo audit` fetches it to
+    pub fn default_path(cargo_home: &Path) -> PathBuf {
+        cargo_home.join("advisory-db")
+    }
+
+    /// Reads the advisories from a checkout of the advisory database at `path`
+    ///
+    /// Informational advisories, e.g. about unmaintained crates, and withdrawn advisories are
+    /// left out, as they do not describe vulnerabilities.
+    pub fn open(path: &Path) -> Result<Self, VexError> {
+        let crates = path.join("crates");
+        if !crates.is_dir() {
+            return Err(VexError::MissingDatabase(path.to_path_buf()));
+        }
+
+        let mut db = Self::default();
+        for dir in read_dir(&crates)? {
+            for path in read_dir(&dir)? {
+                if path.extension().map_or(true, |extension| extension != "md") {
+                    continue;
+                }
+                let contents =
+                    std::fs::read_to_string(&path).map_err(|error| VexError::ReadError {
+                        path: path.clone(),
+                        error,
+                    })?;
+                let advisory = parse_advisory(&contents)
+                    .map_err(|message| VexError::ParseError { path, message })?;
+                if let Some(advisory) = advisory {
+                    db.add(advisory);
+                }
+            }
+        }
+
+        Ok(db)
+    }
+
+    pub fn add(&mut self, advisory: RustsecAdvisory) {
+        self.advisories
+            .entry(advisory.package.clone())
+            .or_default()
+            .push(advisory);
+    }
+
+    /// The advisories of the crate `package` whose `version` has the vulnerability
+    pub fn vulnerabilities<'a>(
+        &'a self,
+        package: &str,
+        version: &'a Version,
+    ) -> impl Iterator<Item = &'a RustsecAdvisory> {
+        self.advisories
+            .get(package)
+            .into_iter()
+            .flatten()
+            .filter(move |advisory| advisory.affects(version))
+    }
+}
+
+fn read_dir(dir: &Path) -> Result<Vec<PathBuf>, VexError> {
+    let read_error = |error| VexError::ReadError {
+        path: dir.to_path_buf(),
+        error,
+    };
+    let mut paths = std::fs::read_dir(dir)
+        .map_err(read_error)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(read_error)?;
+    paths.sort();
+    Ok(paths)
+}
+
+/// Parses an advisory in the Markdown format of the advisory database, with its metadata in a
+/// TOML code block followed by the title as heading and the description
+///
+/// Returns `None` for informational and withdrawn advisories.
+fn parse_advisory(contents: &str) -> Result<Option<RustsecAdvisory>, String> {
+    let contents = contents
+        .trim_start()
+        .strip_prefix("```toml")
+        .ok_or_else(|| "Expected the advisory to start with a TOML code block".to_string())?;
+    let (metadata, text) = contents
+        .split_once("\n```")
+        .ok_or_else(|| "Expected the TOML code block to be closed".to_string())?;
+    let file: AdvisoryFile = toml::from_str(metadata).map_err(|error| error.to_string())?;
+    if file.advisory.informational.is_some() || file.advisory.withdrawn.is_some() {
+        return Ok(None);
+    }
+
+    let text = text.trim();
+    let (title, description) = match text.strip_prefix("# ") {
+        Some(text) => text.split_once('\n').unwrap_or((text, "")),
+        None => ("", text),
+    };
+
+    Ok(Some(RustsecAdvisory {
+        id: file.advisory.id,
+        package: file.advisory.package,
+        title: title.trim().to_string(),
+        description: description.trim().to_string(),
+        date: file.advisory.date,
+        url: file.advisory.url,
+        aliases: file.advisory.aliases,
+        cvss: file.advisory.cvss,
+        patched: file.versions.patched,
+        unaffected: file.versions.unaffected,
+    }))
+}
+
+/// Creates the VEX document of the vulnerabilities of the crates.io components of `sbom`
+///
+/// The vulnerabilities refer to the components by
+/// [BOM-Link](https://cyclonedx.org/capabilities/bomlink/), as they are listed in `sbom`. Returns
+/// `None` for documents other than SBOMs of packages.
+pub fn create_vex(
+    sbom: &GeneratedSbom,
+    db: &AdvisoryDb,
+) -> Result<Option<GeneratedSbom>, VexError> {
+    let published = match (&sbom.document, &sbom.published_refs) {
+        (Document::Sbom, Some(published)) => published,
+        _ => return Ok(None),
+    };
+
+    let components = sbom
+        .bom
+        .components
+        .iter()
+        .flat_map(|components| &components.0)
+        .filter_map(|component| {
+            let bom_ref = component.bom_ref.as_ref()?;
+            let version = Version::parse(&component.version.to_string()).ok()?;
+            published.contains(bom_ref).then(|| {
+                (
+                    component.name.to_string(),
+                    version,
+                    bom_link(&sbom.bom, bom_ref),
+                )
+            })
+        });
+    let vulnerabilities = find_vulnerabilities(components, db);
+
+    let mut metadata = Metadata::new()?;
+    BuildInfo::current().record(&mut metadata);
+    let mut bom = Bom::default().with_metadata(metadata);
+    bom.vulnerabilities = Some(Vulnerabilities(vulnerabilities));
+
+    let mut sbom_config = sbom.sbom_config.clone();
+    // vulnerabilities were added in version 1.4
+    if sbom_config.spec_version() == SpecVersion::V1_3 {
+        sbom_config.spec_version = Some(SpecVersion::V1_4);
+    }
+
+    Ok(Some(GeneratedSbom {
+        bom,
+        manifest_path: sbom.manifest_path.clone(),
+        package_name: sbom.package_name.clone(),
+        sbom_config,
+        output_dir: sbom.output_dir.clone(),
+        published_refs: None,
+        feature_set: sbom.feature_set.clone(),
+        document: Document::Vex,
+    }))
+}
+
+/// The vulnerabilities in `db` of the crates given by their name, version and the reference the
+/// vulnerabilities target them by
+pub(crate) fn find_vulnerabilities(
+    crates: impl Iterator<Item = (String, Version, String)>,
+    db: &AdvisoryDb,
+) -> Vec<Vulnerability> {
+    let mut vulnerabilities = Vec::new();
+    for (name, version, target) in crates {
+        for advisory in db.vulnerabilities(&name, &version) {
+            vulnerabilities.push(create_vulnerability(advisory, target.clone(), &version));
+        }
+    }
+    vulnerabilities
+}
+
+fn create_vulnerability(
+    advisory: &RustsecAdvisory,
+    target: String,
+    version: &Version,
+) -> Vulnerability {
+    let page = format!("{}/{}", RUSTSEC_ADVISORIES, advisory.id);
+    let mut vulnerability = Vulnerability::new(Some(&advisory.id))
+        .with_source(VulnerabilitySource::new(
+            Some("RustSec"),
+            Uri::try_from(page.clone()).ok(),
+        ))
+        .with_description(advisory.title.clone())
+        .with_detail(advisory.description.clone());
+
+    let references: Vec<_> = advisory
+        .aliases
+        .iter()
+        .map(|alias| {
+            let source = if alias.starts_with("GHSA-") {
+                "GitHub"
+            } else if alias.starts_with("CVE-") {
+                "NVD"
+            } else {
+                "other"
+            };
+            VulnerabilityReference {
+                id: NormalizedString::new(alias),
+                source: VulnerabilitySource::new(Some(source), None),
+            }
+        })
+        .collect();
+    if !references.is_empty() {
+        vulnerability.references = Some(references);
+    }
+
+    if let Some(cvss) = &advisory.cvss {
+        let method = if cvss.starts_with("CVSS:3.1/") {
+            ScoreMethod::CVSSv31
+        } else {
+            ScoreMethod::CVSSv3
+        };
+        vulnerability.ratings = Some(vec![VulnerabilityRating {
+            source: None,
+            score: None,
+            severity: None,
+            method: Some(method),
+            vector: Some(NormalizedString::new(cvss)),
+            justification: None,
+        }]);
+    }
+
+    let advisories: Vec<_> = std::iter::once(page)
+        .chain(advisory.url.clone())
+        .filter_map(|url| Uri::try_from(url).ok())
+        .map(|url| Advisory {
+            title: Some(NormalizedString::new(&advisory.title)),
+            url,
+        })
+        .collect();
+    vulnerability.advisories = Some(advisories);
+    vulnerability.published = format!("{}T00:00:00Z", advisory.date).parse().ok();
+
+    let mut versions = vec![AffectedVersion {
+        version: VersionRange::Version(NormalizedString::new(&version.to_string())),
+        status: Some(AffectedStatus::Affected),
+    }];
+    versions.extend(
+        advisory
+            .patched
+            .iter()
+            .chain(&advisory.unaffected)
+            .filter_map(vers_range)
+            .map(|range| AffectedVersion {
+                version: VersionRange::Range(NormalizedString::new(&range)),
+                status: Some(AffectedStatus::Unaffected),
+            }),
+    );
+    let mut target = VulnerabilityTarget::new(target);
+    target.versions = Some(versions);
+    vulnerability.affects = Some(vec![target]);
+
+    vulnerability
+}
+
+/// The [BOM-Link](https://cyclonedx.org/capabilities/bomlink/) to the component `bom_ref` of
+/// `bom`, or `bom_ref` itself if `bom` has no serial number
+fn bom_link(bom: &Bom, bom_ref: &str) -> String {
+    match &bom.serial_number {
+        Some(serial_number) => {
+            let serial_number = serial_number.to_string();
+            let uuid = serial_number
+                .strip_prefix("urn:uuid:")
+                .unwrap_or(&serial_number);
+            let fragment: String = bom_ref
+                .bytes()
+                .map(|byte| match byte {
+                    b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                        char::from(byte).to_string()
+                    }
+                    _ => format!("%{:02X}", byte),
+                })
+                .collect();
+            format!("urn:cdx:{}/{}#{}", uuid, bom.version, fragment)
+        }
+        None => bom_ref.to_string(),
+    }
+}
+
+/// The versions matching `req` in the
+/// [vers](https://github.com/package-url/purl-spec/blob/version-range-spec/VERSION-RANGE-SPEC.rst)
+/// syntax, e.g. `vers:cargo/>=1.2.3|<2.0.0` for `^1.2.3`
+///
+/// Returns `None` for requirements with wildcards such as `1.*` or pre-releases.
+fn vers_range(req: &VersionReq) -> Option<String> {
+    let mut constraints = Vec::new();
+    for comparator in &req.comparators {
+        if !comparator.pre.is_empty() {
+            return None;
+        }
+        let version = format!(
+            "{}.{}.{}",
+            comparator.major,
+            comparator.minor.unwrap_or(0),
+            comparator.patch.unwrap_or(0)
+        );
+        let partial = comparator.patch.is_none();
+        match comparator.op {
+            Op::Exact if !partial => constraints.push(version),
+            Op::Greater if !partial => constraints.push(format!(">{}", version)),
+            Op::GreaterEq => constraints.push(format!(">={}", version)),
+            Op::Less => constraints.push(format!("<{}", version)),
+            Op::LessEq if !partial => constraints.push(format!("<={}", version)),
+            Op::Exact | Op::Tilde | Op::Caret => {
+                constraints.push(format!(">={}", version));
+                constraints.push(format!("<{}", upper_bound(comparator)));
+            }
+            _ => return None,
+        }
+    }
+    if constraints.is_empty() {
+        // `*`
+        constraints.push("*".to_string());
+    }
+    Some(format!("vers:cargo/{}", constraints.join("|")))
+}
+
+/// The lowest version above the versions matching the `=`, `~` or `^` `comparator`
+fn upper_bound(comparator: &Comparator) -> String {
+    let (major, minor, patch) = (comparator.major, comparator.minor, comparator.patch);
+    let (major, minor, patch) = match (comparator.op, minor, patch) {
+        (_, None, _) => (major + 1, 0, 0),
+        (Op::Exact, Some(minor), None) | (Op::Tilde, Some(minor), _) => (major, minor + 1, 0),
+        _ if major > 0 => (major + 1, 0, 0),
+        (_, Some(minor), _) if minor > 0 || patch.is_none() => (0, minor + 1, 0),
+        (_, _, patch) => (0, 0, patch.unwrap_or(0) + 1),
+    };
+    format!("{}.{}.{}", major, minor, patch)
+}
+
+#[derive(Debug, Error)]
+pub enum VexError {
+    #[error("The RustSec advisory database was not found at {}, clone https://github.com/rustsec/advisory-db there, run cargo audit or pass --advisory-db", .0.display())]
+    MissingDatabase(PathBuf),
+
+    #[error("Could not read {}", .path.display())]
+    ReadError {
+        path: PathBuf,
+        #[source]
+        error: std::io::Error,
+    },
+
+    #[error("Could not parse the advisory {}: {message}", .path.display())]
+    ParseError { path: PathBuf, message: String },
+
+    #[error("Could not create the metadata of the VEX document")]
+    MetadataError(#[from] MetadataError),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::config::SbomConfig;
+    use cyclonedx_bom::models::bom::UrnUuid;
+    use cyclonedx_bom::models::component::{Classification, Component, Components};
+
+    const ADVISORY: &str = r#"```toml
+[advisory]
+id = "RUSTSEC-2021-0078"
+package = "hyper"
+date = "2021-07-07"
+url = "https://github.com/hyperium/hyper/security/advisories/GHSA-f3pg-qwvg-p99c"
+categories = ["http"]
+aliases = ["CVE-2021-32715", "GHSA-f3pg-qwvg-p99c"]
+cvss = "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:N/I:L/A:N"
+
+[versions]
+patched = [">= 0.14.10"]
+unaffected = ["< 0.12.0"]
+```
+
+# Lenient `hyper` header parsing of `Content-Length` could allow request smuggling
+
+`hyper`'s HTTP/1 server code had a flaw.
+"#;
+
+    #[test]
+    fn it_should_parse_advisories() {
+        let advisory = parse_advisory(ADVISORY).unwrap().unwrap();
+
+        assert_eq!(advisory.id, "RUSTSEC-2021-0078");
+        assert_eq!(advisory.package, "hyper");
+        assert_eq!(
+            advisory.title,
+            "Lenient `hyper` header parsing of `Content-Length` could allow request smuggling"
+        );
+        assert_eq!(
+            advisory.description,
+            "`hyper`'s HTTP/1 server code had a flaw."
+        );
+        assert_eq!(advisory.aliases.len(), 2);
+
+        assert!(advisory.affects(&Version::new(0, 14, 9)));
+        assert!(!advisory.affects(&Version::new(0, 14, 10)));
+        assert!(!advisory.affects(&Version::new(0, 11, 0)));
+
+        let informational =
+            ADVISORY.replace("[versions]", "informational = \"unsound\"\n\n[versions]");
+        assert_eq!(parse_advisory(&informational), Ok(None));
+        assert!(parse_advisory("# No metadata").is_err());
+    }
+
+    #[test]
+    fn it_should_list_the_vulnerabilities_of_published_components() {
+        let mut db = AdvisoryDb::default();
+        db.add(parse_advisory(ADVISORY).unwrap().unwrap());
+        let components = [
+            ("hyper", "0.14.9"),
+            ("hyper", "0.14.10"),
+            ("internal", "0.1.0"),
+        ]
+        .iter()
+        .map(|(name, version)| {
+            let bom_ref = format!("pkg:cargo/{}@{}", name, version);
+            Component::new(Classification::Library, name, version, Some(bom_ref))
+        })
+        .collect();
+        let sbom = GeneratedSbom {
+            bom: Bom::default().with_components(Components(components)),
+            manifest_path: PathBuf::from("Cargo.toml"),
+            package_name: "pkg".to_string(),
+            sbom_config: SbomConfig::empty_config(),
+            output_dir: None,
+            published_refs: Some(
+                ["pkg:cargo/hyper@0.14.9", "pkg:cargo/hyper@0.14.10"]
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect(),
+            ),
+            feature_set: None,
+            document: Document::Sbom,
+        };
+
+        let vex = create_vex(&sbom, &db).unwrap().unwrap();
+
+        assert_eq!(vex.document, Document::Vex);
+        assert_eq!(vex.sbom_config.spec_version(), SpecVersion::V1_4);
+        let vulnerabilities = vex.bom.vulnerabilities.unwrap().0;
+        assert_eq!(vulnerabilities.len(), 1);
+        let affects = vulnerabilities[0].affects.as_ref().unwrap();
+        assert!(affects[0]
+            .bom_ref
+            .ends_with("#pkg%3Acargo%2Fhyper%400.14.9"));
+        assert_eq!(
+            affects[0].versions.as_ref().unwrap()[1],
+            AffectedVersion {
+                version: VersionRange::Range(NormalizedString::new("vers:cargo/>=0.14.10")),
+                status: Some(AffectedStatus::Unaffected),
+            }
+        );
+    }
+
+    #[test]
+    fn it_should_convert_version_requirements_to_vers_ranges() {
+        for (req, expected) in [
+            (">= 0.14.10", Some("vers:cargo/>=0.14.10")),
+            ("^1.2.3", Some("vers:cargo/>=1.2.3|<2.0.0")),
+            ("^0.2.3", Some("vers:cargo/>=0.2.3|<0.3.0")),
+            ("^0.0.3", Some("vers:cargo/>=0.0.3|<0.0.4")),
+            ("~1.2.3", Some("vers:cargo/>=1.2.3|<1.3.0")),
+            ("=1.2", Some("vers:cargo/>=1.2.0|<1.3.0")),
+            (">= 1.2.0, < 1.4.1", Some("vers:cargo/>=1.2.0|<1.4.1")),
+            ("*", Some("vers:cargo/*")),
+            ("1.*", None),
+        ] {
+            let req = VersionReq::parse(req).unwrap();
+
+            assert_eq!(vers_range(&req).as_deref(), expected);
+        }
+    }
+
+    #[test]
+    fn it_should_link_to_the_components_of_the_sbom() {
+        let mut bom = Bom::default();
+        bom.serial_number = Some(
+            UrnUuid::new("urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79".to_string()).unwrap(),
+        );
+
+        assert_eq!(
+            bom_link(&bom, "pkg:cargo/hyper@0.14.9"),
+            "urn:cdx:3e671687-395b-41f5-a30f-a58921a69b79/1#pkg%3Acargo%2Fhyper%400.14.9"
+        );
+    }
+}
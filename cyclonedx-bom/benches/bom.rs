@@ -0,0 +1,141 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion, Throughput};
+use cyclonedx_bom::models::bom::{Bom, SpecVersion};
+use cyclonedx_bom::validation::Validate;
+use serde_json::json;
+
+/// Number of components in the benchmarked BOM, in the range of a large application with its
+/// whole dependency tree
+const COMPONENTS: usize = 5_000;
+
+/// A BOM with `components` libraries, each with a Purl, a hash, a license, an external reference
+/// and dependencies on the next few components
+fn large_bom_json(components: usize) -> Vec<u8> {
+    let bom_ref = |index: usize| format!("crate-{} 1.0.{}", index, index % 100);
+    let document = json!({
+        "bomFormat": "CycloneDX",
+        "specVersion": "1.3",
+        "serialNumber": "urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79",
+        "version": 1,
+        "metadata": {
+            "timestamp": "2023-06-01T12:00:00Z",
+            "tools": [{ "vendor": "CycloneDX", "name": "cargo-cyclonedx", "version": "0.3.8" }],
+            "component": {
+                "type": "application",
+                "bom-ref": "app 1.0.0",
+                "name": "app",
+                "version": "1.0.0"
+            }
+        },
+        "components": (0..components).map(|index| json!({
+            "type": "library",
+            "bom-ref": bom_ref(index),
+            "name": format!("crate-{}", index),
+            "version": format!("1.0.{}", index % 100),
+            "description": "A library in the dependency tree of the application",
+            "scope": "required",
+            "hashes": [{
+                "alg": "SHA-256",
+                "content": format!("{:064x}", index)
+            }],
+            "licenses": [{ "expression": "MIT OR Apache-2.0" }],
+            "purl": format!("pkg:cargo/crate-{}@1.0.{}", index, index % 100),
+            "externalReferences": [{
+                "type": "vcs",
+                "url": format!("https://github.com/example/crate-{}", index)
+            }]
+        })).collect::<Vec<_>>(),
+        "dependencies": std::iter::once(json!({
+            "ref": "app 1.0.0",
+            "dependsOn": (0..components.min(10)).map(bom_ref).collect::<Vec<_>>()
+        }))
+        .chain((0..components).map(|index| json!({
+            "ref": bom_ref(index),
+            "dependsOn": (index + 1..components.min(index + 4)).map(bom_ref).collect::<Vec<_>>()
+        })))
+        .collect::<Vec<_>>()
+    });
+
+    serde_json::to_vec_pretty(&document).expect("Failed to serialize the fixture")
+}
+
+fn parse_json(input: &[u8]) -> Bom {
+    Bom::parse_from_json_v1_3(input).expect("Failed to parse the fixture")
+}
+
+fn benchmarks(c: &mut Criterion) {
+    let json = large_bom_json(COMPONENTS);
+    let mut xml = Vec::new();
+    parse_json(&json)
+        .output_as_xml(&mut xml, SpecVersion::V1_3)
+        .expect("Failed to write the fixture as XML");
+
+    let mut group = c.benchmark_group("parse");
+    group.throughput(Throughput::Bytes(json.len() as u64));
+    group.bench_function("json", |b| b.iter(|| parse_json(&json)));
+    group.throughput(Throughput::Bytes(xml.len() as u64));
+    group.bench_function("xml", |b| {
+        b.iter(|| Bom::parse_from_xml_v1_3(xml.as_slice()).expect("Failed to parse the fixture"))
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("serialize");
+    group.throughput(Throughput::Elements(COMPONENTS as u64));
+    group.bench_function("json", |b| {
+        b.iter_batched(
+            || parse_json(&json),
+            |bom| {
+                let mut output = Vec::with_capacity(json.len());
+                bom.output_as_json(&mut output, SpecVersion::V1_3)
+                    .expect("Failed to write the BOM");
+                output
+            },
+            BatchSize::LargeInput,
+        )
+    });
+    group.bench_function("xml", |b| {
+        b.iter_batched(
+            || parse_json(&json),
+            |bom| {
+                let mut output = Vec::with_capacity(xml.len());
+                bom.output_as_xml(&mut output, SpecVersion::V1_3)
+                    .expect("Failed to write the BOM");
+                output
+            },
+            BatchSize::LargeInput,
+        )
+    });
+    group.finish();
+
+    let bom = parse_json(&json);
+    let mut group = c.benchmark_group("validate");
+    group.throughput(Throughput::Elements(COMPONENTS as u64));
+    group.bench_function("bom", |b| {
+        b.iter(|| bom.validate().expect("Failed to validate the BOM"))
+    });
+    group.finish();
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(20);
+    targets = benchmarks
+}
+criterion_main!(benches);
@@ -0,0 +1,470 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Pseudonymization of the internal content of a BOM, e.g. to attach it to a bug report
+//!
+//! Components with a public Package URL, one without a `repository_url`, `download_url` or
+//! `vcs_url` qualifier and not of the `generic` type, are kept as they are. The name, group,
+//! description, author, publisher, copyright, supplier, Purl, external references and property
+//! values of every other component are replaced with pseudonyms, and their CPE, SWID, pedigree
+//! and evidence are removed. Services, the authors, manufacturer and supplier in the metadata, the
+//! external references of the BOM, and the text and annotators of annotations are pseudonymized
+//! as well. Callers that know which components are public by other means can tell with
+//! [`Anonymizer::apply_with`].
+//!
+//! Every `bom-ref` is replaced, as references often contain local paths, and the dependencies,
+//! compositions and annotations refer to the replaced `bom-ref`s, so the graph keeps its
+//! structure. Versions, hashes and licenses are kept.
+//!
+//! A pseudonym is derived from a salted SHA-256 digest of the value, so that equal values get
+//! equal pseudonyms and the same salt gives the same pseudonyms across BOMs. The salt should be
+//! kept secret, as the pseudonyms of guessable values could otherwise be reproduced.
+//!
+//! ```
+//! use cyclonedx_bom::anonymize::Anonymizer;
+//! use cyclonedx_bom::models::bom::Bom;
+//! use cyclonedx_bom::models::component::{Classification, Component, Components};
+//!
+//! let mut bom = Bom::default();
+//! bom.components = Some(Components(vec![Component::new(
+//!     Classification::Library,
+//!     "internal-billing",
+//!     "1.0.0",
+//!     Some("internal-billing 1.0.0 (path+file:///home/alice/billing)".to_string()),
+//! )]));
+//!
+//! Anonymizer::new("secret salt").apply(&mut bom);
+//!
+//! let component = &bom.components.expect("Missing components").0[0];
+//! assert!(component.name.to_string().starts_with("component-"));
+//! assert_eq!(component.version.to_string(), "1.0.0");
+//! ```
+use std::str::FromStr;
+
+use packageurl::PackageUrl;
+use sha2::{Digest, Sha256};
+
+use crate::external_models::normalized_string::NormalizedString;
+use crate::external_models::purl::Purl;
+use crate::external_models::uri::Uri;
+use crate::models::annotation::Annotator;
+use crate::models::bom::Bom;
+use crate::models::component::Component;
+use crate::models::composition::BomReference;
+use crate::models::external_reference::ExternalReferences;
+use crate::models::organization::{OrganizationalContact, OrganizationalEntity};
+use crate::models::property::Properties;
+use crate::models::service::Service;
+
+/// Qualifiers that point a Package URL to a location other than the public registry of its type
+const PRIVATE_LOCATION_QUALIFIERS: &[&str] = &["repository_url", "download_url", "vcs_url"];
+
+/// Replaces the internal content of BOMs with salted pseudonyms, see the
+/// [module documentation](self)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Anonymizer {
+    salt: String,
+}
+
+impl Anonymizer {
+    pub fn new(salt: impl Into<String>) -> Self {
+        Self { salt: salt.into() }
+    }
+
+    /// Pseudonymizes the internal content of `bom`, keeping the components with a public Package
+    /// URL, see [`has_public_purl`]
+    pub fn apply(&self, bom: &mut Bom) {
+        self.apply_with(bom, has_public_purl)
+    }
+
+    /// Pseudonymizes the internal content of `bom`, keeping the components for which `is_public`
+    /// returns `true`
+    ///
+    /// This is for callers that know more about the origin of the components than their Package
+    /// URLs tell.
+    pub fn apply_with(&self, bom: &mut Bom, is_public: impl Fn(&Component) -> bool) {
+        if let Some(metadata) = &mut bom.metadata {
+            for author in metadata.authors.iter_mut().flatten() {
+                self.contact(author);
+            }
+            if let Some(manufacture) = &mut metadata.manufacture {
+                self.organization(manufacture);
+            }
+            if let Some(supplier) = &mut metadata.supplier {
+                self.organization(supplier);
+            }
+            if let Some(component) = &mut metadata.component {
+                self.component(component, &is_public);
+            }
+        }
+
+        for component in bom.components.iter_mut().flat_map(|c| &mut c.0) {
+            self.component(component, &is_public);
+        }
+        for service in bom.services.iter_mut().flat_map(|s| &mut s.0) {
+            self.service(service);
+        }
+        if let Some(external_references) = &mut bom.external_references {
+            self.external_references(external_references);
+        }
+
+        for dependency in bom.dependencies.iter_mut().flat_map(|d| &mut d.0) {
+            dependency.dependency_ref = self.bom_ref(&dependency.dependency_ref);
+            for bom_ref in &mut dependency.dependencies {
+                *bom_ref = self.bom_ref(bom_ref);
+            }
+        }
+        for composition in bom.compositions.iter_mut().flat_map(|c| &mut c.0) {
+            let references = composition
+                .assemblies
+                .iter_mut()
+                .chain(composition.dependencies.iter_mut())
+                .flatten();
+            for reference in references {
+                *reference = BomReference(self.bom_ref(&reference.0));
+            }
+        }
+        for annotation in bom.annotations.iter_mut().flat_map(|a| &mut a.0) {
+            for subject in &mut annotation.subjects {
+                *subject = self.bom_ref(subject);
+            }
+            annotation.text = self.pseudonym("annotation", &annotation.text);
+            match &mut annotation.annotator {
+                Annotator::Organization(organization) => self.organization(organization),
+                Annotator::Individual(contact) => self.contact(contact),
+                Annotator::Component(component) => self.component(component, &is_public),
+                Annotator::Service(service) => self.service(service),
+            }
+        }
+    }
+
+    fn component(&self, component: &mut Component, is_public: &impl Fn(&Component) -> bool) {
+        let public = is_public(component);
+        component.bom_ref = component
+            .bom_ref
+            .as_ref()
+            .map(|bom_ref| self.bom_ref(bom_ref));
+
+        if !public {
+            component.name = self.text("component", &component.name);
+            component.group = self.optional_text("group", &component.group);
+            component.description = self.optional_text("description", &component.description);
+            component.author = self.optional_text("author", &component.author);
+            component.publisher = self.optional_text("publisher", &component.publisher);
+            component.copyright = self.optional_text("copyright", &component.copyright);
+            if let Some(supplier) = &mut component.supplier {
+                self.organization(supplier);
+            }
+            component.purl = component.purl.as_ref().and_then(|purl| self.purl(purl));
+            component.cpe = None;
+            component.swid = None;
+            component.pedigree = None;
+            component.evidence = None;
+            if let Some(external_references) = &mut component.external_references {
+                self.external_references(external_references);
+            }
+            if let Some(properties) = &mut component.properties {
+                self.properties(properties);
+            }
+        }
+
+        for component in component.components.iter_mut().flat_map(|c| &mut c.0) {
+            self.component(component, is_public);
+        }
+    }
+
+    fn service(&self, service: &mut Service) {
+        service.bom_ref = service
+            .bom_ref
+            .as_ref()
+            .map(|bom_ref| self.bom_ref(bom_ref));
+        service.name = self.text("service", &service.name);
+        service.group = self.optional_text("group", &service.group);
+        service.description = self.optional_text("description", &service.description);
+        if let Some(provider) = &mut service.provider {
+            self.organization(provider);
+        }
+        for endpoint in service.endpoints.iter_mut().flatten() {
+            *endpoint = self.url(endpoint);
+        }
+        if let Some(external_references) = &mut service.external_references {
+            self.external_references(external_references);
+        }
+        if let Some(properties) = &mut service.properties {
+            self.properties(properties);
+        }
+
+        for service in service.services.iter_mut().flat_map(|s| &mut s.0) {
+            self.service(service);
+        }
+    }
+
+    fn organization(&self, organization: &mut OrganizationalEntity) {
+        organization.name = self.optional_text("organization", &organization.name);
+        for url in organization.url.iter_mut().flatten() {
+            *url = self.url(url);
+        }
+        for contact in organization.contact.iter_mut().flatten() {
+            self.contact(contact);
+        }
+    }
+
+    fn contact(&self, contact: &mut OrganizationalContact) {
+        contact.name = self.optional_text("person", &contact.name);
+        contact.email = contact
+            .email
+            .as_ref()
+            .map(|email| NormalizedString::new(&format!("{}@example.com", self.digest(&email.0))));
+        contact.phone = self.optional_text("phone", &contact.phone);
+    }
+
+    fn external_references(&self, external_references: &mut ExternalReferences) {
+        for external_reference in &mut external_references.0 {
+            external_reference.url = self.url(&external_reference.url);
+            external_reference.comment = None;
+        }
+    }
+
+    fn properties(&self, properties: &mut Properties) {
+        for property in &mut properties.0 {
+            property.value = self.text("value", &property.value);
+        }
+    }
+
+    /// A Package URL of the same type and version with pseudonyms as namespace and name, and
+    /// without qualifiers and subpath
+    fn purl(&self, purl: &Purl) -> Option<Purl> {
+        let parsed = PackageUrl::from_str(&purl.0).ok()?;
+        let mut pseudonym =
+            PackageUrl::new(parsed.ty(), self.pseudonym("component", parsed.name())).ok()?;
+        if let Some(namespace) = parsed.namespace() {
+            pseudonym.with_namespace(self.pseudonym("group", namespace));
+        }
+        if let Some(version) = parsed.version() {
+            pseudonym.with_version(version);
+        }
+        Some(Purl(pseudonym.to_string()))
+    }
+
+    fn bom_ref(&self, bom_ref: &str) -> String {
+        self.pseudonym("ref", bom_ref)
+    }
+
+    fn url(&self, url: &Uri) -> Uri {
+        Uri(format!("https://example.com/{}", self.digest(&url.0)))
+    }
+
+    fn text(&self, kind: &str, value: &NormalizedString) -> NormalizedString {
+        NormalizedString::new(&self.pseudonym(kind, &value.0))
+    }
+
+    fn optional_text(
+        &self,
+        kind: &str,
+        value: &Option<NormalizedString>,
+    ) -> Option<NormalizedString> {
+        value.as_ref().map(|value| self.text(kind, value))
+    }
+
+    fn pseudonym(&self, kind: &str, value: &str) -> String {
+        format!("{}-{}", kind, self.digest(value))
+    }
+
+    /// The first 48 bits of the salted digest of `value`, which makes collisions unlikely even in
+    /// BOMs of many thousands of components
+    fn digest(&self, value: &str) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(self.salt.as_bytes());
+        hasher.update([0]);
+        hasher.update(value.as_bytes());
+        hasher.finalize()[..6]
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect()
+    }
+}
+
+/// Whether the component is identified by a Package URL of its public registry
+pub fn has_public_purl(component: &Component) -> bool {
+    let purl = match component
+        .purl
+        .as_ref()
+        .and_then(|purl| PackageUrl::from_str(&purl.0).ok())
+    {
+        Some(purl) => purl,
+        None => return false,
+    };
+
+    purl.ty() != "generic"
+        && !purl
+            .qualifiers()
+            .keys()
+            .any(|qualifier| PRIVATE_LOCATION_QUALIFIERS.contains(&qualifier.as_ref()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::models::annotation::{Annotation, Annotations};
+    use crate::models::component::{Classification, Components};
+    use crate::models::dependency::{Dependencies, Dependency};
+    use crate::models::metadata::Metadata;
+    use std::convert::TryFrom;
+
+    const APP_REF: &str = "app 1.0.0 (path+file:///home/alice/app)";
+    const SERDE_REF: &str = "serde 1.0.164 (registry+https://github.com/rust-lang/crates.io-index)";
+    const BILLING_REF: &str = "billing 0.3.0 (registry+https://cargo.internal.example/index)";
+
+    fn component(name: &str, version: &str, bom_ref: &str, purl: &str) -> Component {
+        Component {
+            purl: Some(Purl::try_from(purl.to_string()).expect("Invalid purl")),
+            ..Component::new(
+                Classification::Library,
+                name,
+                version,
+                Some(bom_ref.to_string()),
+            )
+        }
+    }
+
+    fn bom() -> Bom {
+        Bom {
+            metadata: Some(Metadata {
+                component: Some(component(
+                    "app",
+                    "1.0.0",
+                    APP_REF,
+                    "pkg:cargo/app@1.0.0?download_url=file:///home/alice/app",
+                )),
+                ..Metadata::default()
+            }),
+            components: Some(Components(vec![
+                component(
+                    "billing",
+                    "0.3.0",
+                    BILLING_REF,
+                    "pkg:cargo/billing@0.3.0?repository_url=https://cargo.internal.example/index",
+                ),
+                component("serde", "1.0.164", SERDE_REF, "pkg:cargo/serde@1.0.164"),
+            ])),
+            dependencies: Some(Dependencies(vec![
+                Dependency::new(
+                    APP_REF,
+                    vec![BILLING_REF.to_string(), SERDE_REF.to_string()],
+                ),
+                Dependency::new(BILLING_REF, vec![SERDE_REF.to_string()]),
+            ])),
+            ..Bom::default()
+        }
+    }
+
+    #[test]
+    fn it_should_pseudonymize_internal_components_and_keep_the_graph() {
+        let mut bom = bom();
+        Anonymizer::new("salt").apply(&mut bom);
+
+        let app = bom
+            .metadata
+            .and_then(|metadata| metadata.component)
+            .expect("Missing metadata component");
+        let components = bom.components.expect("Missing components").0;
+        let (billing, serde) = (&components[0], &components[1]);
+
+        assert!(app.name.0.starts_with("component-"));
+        assert!(billing.name.0.starts_with("component-"));
+        assert_eq!(billing.version.0, "0.3.0");
+        assert_eq!(
+            billing.purl.as_ref().map(|purl| purl.0.clone()),
+            Some(format!("pkg:cargo/{}@0.3.0", billing.name.0))
+        );
+        assert_eq!(serde.name.0, "serde");
+        assert_eq!(
+            serde.purl.as_ref().map(|purl| purl.0.as_str()),
+            Some("pkg:cargo/serde@1.0.164")
+        );
+
+        let app_ref = app.bom_ref.expect("Missing bom-ref");
+        let billing_ref = billing.bom_ref.clone().expect("Missing bom-ref");
+        let serde_ref = serde.bom_ref.clone().expect("Missing bom-ref");
+        assert!(!serde_ref.contains("serde"));
+        assert_eq!(
+            bom.dependencies.expect("Missing dependencies").0,
+            vec![
+                Dependency::new(app_ref, vec![billing_ref.clone(), serde_ref.clone()]),
+                Dependency::new(billing_ref, vec![serde_ref]),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_derive_the_pseudonyms_from_the_salt() {
+        let pseudonymized = |salt: &str| {
+            let mut bom = bom();
+            Anonymizer::new(salt).apply(&mut bom);
+            bom.components.expect("Missing components").0[0]
+                .name
+                .0
+                .clone()
+        };
+
+        assert_eq!(pseudonymized("salt"), pseudonymized("salt"));
+        assert_ne!(pseudonymized("salt"), pseudonymized("pepper"));
+    }
+
+    #[test]
+    fn it_should_keep_the_components_the_caller_knows_to_be_public() {
+        let mut bom = bom();
+        Anonymizer::new("salt").apply_with(&mut bom, |component| {
+            component.bom_ref.as_deref() == Some(BILLING_REF)
+        });
+
+        let components = bom.components.expect("Missing components").0;
+        assert_eq!(components[0].name.0, "billing");
+        assert!(components[1].name.0.starts_with("component-"));
+        assert_ne!(components[0].bom_ref.as_deref(), Some(BILLING_REF));
+    }
+
+    #[test]
+    fn it_should_pseudonymize_annotations() {
+        let mut bom = Bom {
+            annotations: Some(Annotations(vec![Annotation::new(
+                vec![BILLING_REF.to_string()],
+                Annotator::Individual(OrganizationalContact::new("Alice", None)),
+                "1969-06-28T01:20:00.00-04:00".parse().unwrap(),
+                "billing 0.3.0 is pinned for the audit",
+            )])),
+            ..bom()
+        };
+        Anonymizer::new("salt").apply(&mut bom);
+
+        let billing_ref = bom.components.expect("Missing components").0[0]
+            .bom_ref
+            .clone()
+            .expect("Missing bom-ref");
+        let annotation = &bom.annotations.expect("Missing annotations").0[0];
+        assert_eq!(annotation.subjects, vec![billing_ref]);
+        assert!(annotation.text.starts_with("annotation-"));
+        match &annotation.annotator {
+            Annotator::Individual(contact) => assert!(contact
+                .name
+                .as_ref()
+                .map_or(false, |name| name.0.starts_with("person-"))),
+            annotator => panic!("Unexpected annotator {:?}", annotator),
+        }
+    }
+}
@@ -0,0 +1,187 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! A machine-readable description of what the library supports, so that tools built on it can
+//! detect features instead of assuming them from the version of the library
+//!
+//! ```
+//! use cyclonedx_bom::format::DocumentFormat;
+//! use cyclonedx_bom::models::bom::SpecVersion;
+//!
+//! let capabilities = cyclonedx_bom::capabilities();
+//! let v1_4 = capabilities
+//!     .spec_version(SpecVersion::V1_4)
+//!     .expect("1.4 is supported");
+//! assert!(v1_4.supports_section("vulnerabilities", DocumentFormat::Xml));
+//! assert!(!v1_4.supports_section("signature", DocumentFormat::Xml));
+//! ```
+
+use serde::Serialize;
+
+use crate::format::DocumentFormat;
+use crate::models::bom::SpecVersion;
+
+/// What the library supports
+///
+/// * `version` - Version of the `cyclonedx-bom` library
+/// * `spec_versions` - The versions of the specification documents can be read and written as
+/// * `gzip` - Whether gzip compressed documents can be read by
+///   [`Bom::parse`](crate::models::bom::Bom::parse)
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Capabilities {
+    pub version: &'static str,
+    pub spec_versions: Vec<SpecCapabilities>,
+    pub gzip: bool,
+}
+
+impl Capabilities {
+    /// The capabilities of the version `spec_version` of the specification, if it is supported
+    pub fn spec_version(&self, spec_version: SpecVersion) -> Option<&SpecCapabilities> {
+        self.spec_versions
+            .iter()
+            .find(|capabilities| capabilities.spec_version == spec_version)
+    }
+}
+
+/// What the library supports of a version of the specification
+///
+/// * `read` - Formats documents can be parsed from
+/// * `write` - Formats documents can be written as
+/// * `sections` - Top-level sections of the BOM that are read and written
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpecCapabilities {
+    pub spec_version: SpecVersion,
+    pub read: Vec<DocumentFormat>,
+    pub write: Vec<DocumentFormat>,
+    pub sections: Vec<Section>,
+}
+
+impl SpecCapabilities {
+    /// Whether the section `name`, e.g. `vulnerabilities`, is supported in documents of `format`
+    pub fn supports_section(&self, name: &str, format: DocumentFormat) -> bool {
+        self.sections
+            .iter()
+            .any(|section| section.name == name && section.formats.contains(&format))
+    }
+}
+
+/// A top-level section of a BOM, named as in JSON documents, and the formats it is supported in
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Section {
+    pub name: &'static str,
+    pub formats: Vec<DocumentFormat>,
+}
+
+const FORMATS: &[DocumentFormat] = &[DocumentFormat::Json, DocumentFormat::Xml];
+
+/// The sections and the version of the specification they were added in
+const SECTIONS: &[(&str, SpecVersion)] = &[
+    ("metadata", SpecVersion::V1_3),
+    ("components", SpecVersion::V1_3),
+    ("services", SpecVersion::V1_3),
+    ("externalReferences", SpecVersion::V1_3),
+    ("dependencies", SpecVersion::V1_3),
+    ("compositions", SpecVersion::V1_3),
+    ("properties", SpecVersion::V1_3),
+    ("vulnerabilities", SpecVersion::V1_4),
+    ("signature", SpecVersion::V1_4),
+    ("annotations", SpecVersion::V1_5),
+    ("formulation", SpecVersion::V1_5),
+];
+
+/// Describes what this version of the library supports
+pub fn capabilities() -> Capabilities {
+    let spec_versions = SpecVersion::SUPPORTED
+        .iter()
+        .map(|&spec_version| {
+            let sections = SECTIONS
+                .iter()
+                .filter(|(_, added)| since(spec_version, *added))
+                .map(|&(name, _)| Section {
+                    name,
+                    formats: match name {
+                        // XML documents are signed with XML Signature instead
+                        "signature" => vec![DocumentFormat::Json],
+                        _ => FORMATS.to_vec(),
+                    },
+                })
+                .collect();
+
+            SpecCapabilities {
+                spec_version,
+                read: FORMATS.to_vec(),
+                write: FORMATS.to_vec(),
+                sections,
+            }
+        })
+        .collect();
+
+    Capabilities {
+        version: env!("CARGO_PKG_VERSION"),
+        spec_versions,
+        gzip: true,
+    }
+}
+
+/// Whether `spec_version` is `added` or a later version
+fn since(spec_version: SpecVersion, added: SpecVersion) -> bool {
+    let position = |version| SpecVersion::SUPPORTED.iter().position(|v| *v == version);
+    position(spec_version) >= position(added)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_describe_every_supported_spec_version() {
+        let capabilities = capabilities();
+
+        let versions: Vec<_> = capabilities
+            .spec_versions
+            .iter()
+            .map(|capabilities| capabilities.spec_version)
+            .collect();
+        assert_eq!(versions, SpecVersion::SUPPORTED);
+
+        let v1_3 = capabilities.spec_version(SpecVersion::V1_3).unwrap();
+        assert!(v1_3.supports_section("components", DocumentFormat::Json));
+        assert!(!v1_3.supports_section("vulnerabilities", DocumentFormat::Json));
+        let v1_5 = capabilities.spec_version(SpecVersion::V1_5).unwrap();
+        assert!(v1_5.supports_section("formulation", DocumentFormat::Xml));
+        assert!(v1_5.supports_section("signature", DocumentFormat::Json));
+    }
+
+    #[test]
+    fn it_should_serialize_as_json() {
+        let json = serde_json::to_value(capabilities()).unwrap();
+
+        assert_eq!(json["specVersions"][0]["specVersion"], "1.3");
+        assert_eq!(
+            json["specVersions"][0]["read"],
+            serde_json::json!(["json", "xml"])
+        );
+        assert_eq!(
+            json["specVersions"][1]["sections"][8],
+            serde_json::json!({ "name": "signature", "formats": ["json"] })
+        );
+    }
+}
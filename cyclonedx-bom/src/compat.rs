@@ -0,0 +1,222 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Which versions of the specification can represent a BOM without dropping any of its content,
+//! e.g. to choose the version a BOM is converted to, or to explain why a downgrade loses content
+//!
+//! ```
+//! use cyclonedx_bom::compat::compatibility;
+//! use cyclonedx_bom::format::DocumentFormat;
+//! use cyclonedx_bom::models::bom::{Bom, SpecVersion};
+//! use cyclonedx_bom::models::vulnerability::{Vulnerabilities, Vulnerability};
+//!
+//! let bom = Bom::default().with_vulnerabilities(Vulnerabilities(vec![Vulnerability::new(Some(
+//!     "RUSTSEC-2021-0078",
+//! ))]));
+//!
+//! let compatibility = compatibility(&bom, DocumentFormat::Json);
+//! assert_eq!(
+//!     compatibility.lossless_versions(),
+//!     vec![SpecVersion::V1_4, SpecVersion::V1_5]
+//! );
+//! assert_eq!(
+//!     compatibility.check(SpecVersion::V1_3).unwrap_err().to_string(),
+//!     "Version 1.3 of the specification cannot represent 1 field(s) of the BOM in JSON documents, \
+//!      such as vulnerabilities, use version 1.4 or 1.5 instead"
+//! );
+//! ```
+
+use std::fmt;
+
+use crate::format::DocumentFormat;
+use crate::models::bom::{Bom, SpecVersion};
+use crate::warnings::{format_downgrade_warnings, DowngradeWarning};
+
+/// The content of a BOM that each supported version of the specification cannot represent in
+/// documents of a format
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Compatibility {
+    pub format: DocumentFormat,
+    pub versions: Vec<VersionCompatibility>,
+}
+
+/// The content of a BOM that a version of the specification cannot represent, which is dropped
+/// when the BOM is written in that version
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VersionCompatibility {
+    pub spec_version: SpecVersion,
+    pub dropped: Vec<DowngradeWarning>,
+}
+
+impl VersionCompatibility {
+    /// Whether the version represents the BOM without dropping any content
+    pub fn is_lossless(&self) -> bool {
+        self.dropped.is_empty()
+    }
+}
+
+/// Checks which of the supported versions of the specification can represent `bom` in documents
+/// of `format`
+pub fn compatibility(bom: &Bom, format: DocumentFormat) -> Compatibility {
+    Compatibility {
+        format,
+        versions: SpecVersion::SUPPORTED
+            .iter()
+            .map(|&spec_version| VersionCompatibility {
+                spec_version,
+                dropped: format_downgrade_warnings(bom, spec_version, format),
+            })
+            .collect(),
+    }
+}
+
+impl Compatibility {
+    /// The compatibility of the version `spec_version` of the specification
+    pub fn version(&self, spec_version: SpecVersion) -> Option<&VersionCompatibility> {
+        self.versions
+            .iter()
+            .find(|version| version.spec_version == spec_version)
+    }
+
+    /// The versions of the specification that represent the BOM without dropping any content,
+    /// oldest first
+    pub fn lossless_versions(&self) -> Vec<SpecVersion> {
+        self.versions
+            .iter()
+            .filter(|version| version.is_lossless())
+            .map(|version| version.spec_version)
+            .collect()
+    }
+
+    /// The oldest version of the specification that represents the BOM without dropping any
+    /// content, which the most consumers can read, if any
+    pub fn oldest_lossless_version(&self) -> Option<SpecVersion> {
+        self.lossless_versions().first().copied()
+    }
+
+    /// Checks that `spec_version` represents the BOM without dropping any content
+    pub fn check(&self, spec_version: SpecVersion) -> Result<(), IncompatibleVersion> {
+        let dropped = self
+            .version(spec_version)
+            .map(|version| version.dropped.clone())
+            .unwrap_or_default();
+        if dropped.is_empty() {
+            return Ok(());
+        }
+
+        Err(IncompatibleVersion {
+            spec_version,
+            format: self.format,
+            dropped,
+            lossless_versions: self.lossless_versions(),
+        })
+    }
+}
+
+/// A version of the specification that cannot represent all of the content of a BOM
+///
+/// * `dropped` - The content that would be dropped
+/// * `lossless_versions` - The versions that represent the BOM without dropping any content
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IncompatibleVersion {
+    pub spec_version: SpecVersion,
+    pub format: DocumentFormat,
+    pub dropped: Vec<DowngradeWarning>,
+    pub lossless_versions: Vec<SpecVersion>,
+}
+
+impl fmt::Display for IncompatibleVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Version {} of the specification cannot represent {} field(s) of the BOM in {} documents",
+            self.spec_version,
+            self.dropped.len(),
+            self.format
+        )?;
+        if let Some(path) = self.dropped.first().map(dropped_path) {
+            write!(f, ", such as {}", path)?;
+        }
+
+        let versions: Vec<_> = self
+            .lossless_versions
+            .iter()
+            .map(SpecVersion::as_str)
+            .collect();
+        match versions.split_last() {
+            None => write!(f, ", and no other version can"),
+            Some((last, [])) => write!(f, ", use version {} instead", last),
+            Some((last, others)) => {
+                write!(f, ", use version {} or {} instead", others.join(", "), last)
+            }
+        }
+    }
+}
+
+impl std::error::Error for IncompatibleVersion {}
+
+fn dropped_path(warning: &DowngradeWarning) -> &str {
+    match warning {
+        DowngradeWarning::UnsupportedField { path, .. }
+        | DowngradeWarning::UnsupportedInFormat { path, .. } => path,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::models::signature::{Algorithm, Signature};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_represent_an_empty_bom_in_every_version() {
+        let compatibility = compatibility(&Bom::default(), DocumentFormat::Xml);
+
+        assert_eq!(compatibility.lossless_versions(), SpecVersion::SUPPORTED);
+        assert_eq!(
+            compatibility.oldest_lossless_version(),
+            Some(SpecVersion::V1_3)
+        );
+        assert_eq!(compatibility.check(SpecVersion::V1_3), Ok(()));
+    }
+
+    #[test]
+    fn it_should_report_the_signatures_of_xml_documents() {
+        let bom = Bom::default().with_signature(Signature::new(Algorithm::ES256, "c2lnbmF0dXJl"));
+
+        let json = compatibility(&bom, DocumentFormat::Json);
+        assert_eq!(json.oldest_lossless_version(), Some(SpecVersion::V1_4));
+
+        let xml = compatibility(&bom, DocumentFormat::Xml);
+        assert_eq!(xml.oldest_lossless_version(), None);
+        assert_eq!(
+            xml.check(SpecVersion::V1_5).unwrap_err().to_string(),
+            "Version 1.5 of the specification cannot represent 1 field(s) of the BOM in XML \
+             documents, such as signature, and no other version can"
+        );
+        assert_eq!(
+            xml.version(SpecVersion::V1_4)
+                .map(|version| version.dropped.clone()),
+            Some(vec![DowngradeWarning::UnsupportedInFormat {
+                path: "signature".to_string(),
+                spec_version: SpecVersion::V1_4,
+                format: DocumentFormat::Xml,
+            }])
+        );
+    }
+}
@@ -0,0 +1,329 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Decoding of XML documents to UTF-8, as the XML parser only reads UTF-8
+//!
+//! The encoding is detected from the byte order mark or the first characters of the document, as
+//! described in [Appendix F of the XML specification](https://www.w3.org/TR/xml/#sec-guessing),
+//! and then checked against the encoding in the XML declaration.
+
+use std::borrow::Cow;
+use std::fmt;
+
+use crate::errors::XmlReadError;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Encoding {
+    Utf8,
+    /// UTF-16 with the byte order given by the byte order mark
+    Utf16,
+    Utf16Le,
+    Utf16Be,
+    Latin1,
+    Ascii,
+}
+
+impl fmt::Display for Encoding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Encoding::Utf8 => write!(f, "UTF-8"),
+            Encoding::Utf16 => write!(f, "UTF-16"),
+            Encoding::Utf16Le => write!(f, "UTF-16LE"),
+            Encoding::Utf16Be => write!(f, "UTF-16BE"),
+            Encoding::Latin1 => write!(f, "ISO-8859-1"),
+            Encoding::Ascii => write!(f, "US-ASCII"),
+        }
+    }
+}
+
+impl Encoding {
+    /// The encoding of a name in an XML declaration, which is case-insensitive
+    fn from_label(label: &str) -> Option<Self> {
+        match label.to_ascii_lowercase().as_str() {
+            "utf-8" | "utf8" => Some(Encoding::Utf8),
+            "utf-16" | "utf16" => Some(Encoding::Utf16),
+            "utf-16le" => Some(Encoding::Utf16Le),
+            "utf-16be" => Some(Encoding::Utf16Be),
+            "iso-8859-1" | "iso8859-1" | "iso_8859-1" | "latin1" | "latin-1" | "l1" => {
+                Some(Encoding::Latin1)
+            }
+            "us-ascii" | "ascii" => Some(Encoding::Ascii),
+            _ => None,
+        }
+    }
+
+    fn is_utf16(&self) -> bool {
+        matches!(
+            self,
+            Encoding::Utf16 | Encoding::Utf16Le | Encoding::Utf16Be
+        )
+    }
+}
+
+/// How the first bytes of a document are encoded
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Detected {
+    encoding: Option<Encoding>,
+    byte_order_mark: usize,
+}
+
+fn detect(input: &[u8]) -> Detected {
+    let (encoding, byte_order_mark) = match input {
+        [0xef, 0xbb, 0xbf, ..] => (Some(Encoding::Utf8), 3),
+        [0xff, 0xfe, ..] => (Some(Encoding::Utf16Le), 2),
+        [0xfe, 0xff, ..] => (Some(Encoding::Utf16Be), 2),
+        [b'<', 0x00, b'?', 0x00, ..] => (Some(Encoding::Utf16Le), 0),
+        [0x00, b'<', 0x00, b'?', ..] => (Some(Encoding::Utf16Be), 0),
+        // an ASCII compatible encoding, which the declaration has to name
+        _ => (None, 0),
+    };
+    Detected {
+        encoding,
+        byte_order_mark,
+    }
+}
+
+/// Whether the document is encoded in UTF-16, which no other supported format uses
+pub(crate) fn is_utf16(input: &[u8]) -> bool {
+    detect(input).encoding.map_or(false, |e| e.is_utf16())
+}
+
+/// Decodes `input` to UTF-8 according to its byte order mark and XML declaration
+///
+/// The encoding in the declaration of a decoded document is replaced with UTF-8, so that the
+/// document describes itself correctly.
+pub(crate) fn decode_xml(input: &[u8]) -> Result<Cow<'_, [u8]>, XmlReadError> {
+    let detected = detect(input);
+    let body = &input[detected.byte_order_mark..];
+
+    let text: Cow<'_, str> = match detected.encoding {
+        Some(encoding) if encoding.is_utf16() => {
+            let text = decode_utf16(body, encoding, detected.byte_order_mark)?;
+            match declared_encoding(&text)? {
+                Some(declared) if !declared.is_utf16() => {
+                    return Err(XmlReadError::EncodingMismatch {
+                        declared: declared.to_string(),
+                        detected: encoding.to_string(),
+                    })
+                }
+                _ => Cow::Owned(text),
+            }
+        }
+        _ => {
+            // the declaration only contains ASCII characters, which these encodings share
+            let declared = declared_encoding(&String::from_utf8_lossy(prefix(body)))?;
+            match (detected.encoding, declared) {
+                (_, Some(declared)) if declared.is_utf16() => {
+                    return Err(XmlReadError::EncodingMismatch {
+                        declared: declared.to_string(),
+                        detected: detected
+                            .encoding
+                            .map_or("an 8-bit encoding".to_string(), |e| e.to_string()),
+                    })
+                }
+                (Some(Encoding::Utf8), Some(declared)) if declared != Encoding::Utf8 => {
+                    return Err(XmlReadError::EncodingMismatch {
+                        declared: declared.to_string(),
+                        detected: Encoding::Utf8.to_string(),
+                    })
+                }
+                (_, Some(Encoding::Latin1)) => {
+                    Cow::Owned(body.iter().map(|&b| b as char).collect())
+                }
+                (_, Some(Encoding::Ascii)) => match body.iter().position(|b| !b.is_ascii()) {
+                    Some(offset) => {
+                        return Err(XmlReadError::InvalidEncoding {
+                            encoding: Encoding::Ascii.to_string(),
+                            offset: detected.byte_order_mark + offset,
+                        })
+                    }
+                    None => String::from_utf8_lossy(body),
+                },
+                _ => match std::str::from_utf8(body) {
+                    Ok(_) => return Ok(Cow::Borrowed(input)),
+                    Err(error) => {
+                        return Err(XmlReadError::InvalidEncoding {
+                            encoding: Encoding::Utf8.to_string(),
+                            offset: detected.byte_order_mark + error.valid_up_to(),
+                        })
+                    }
+                },
+            }
+        }
+    };
+
+    Ok(Cow::Owned(declare_utf8(&text).into_bytes()))
+}
+
+/// Decodes the `body` of a document that starts at byte `start` after the byte order mark
+fn decode_utf16(body: &[u8], encoding: Encoding, start: usize) -> Result<String, XmlReadError> {
+    let invalid = |offset| XmlReadError::InvalidEncoding {
+        encoding: encoding.to_string(),
+        offset,
+    };
+    if body.len() % 2 != 0 {
+        return Err(invalid(start + body.len() - 1));
+    }
+
+    let units = body.chunks_exact(2).map(|pair| match encoding {
+        Encoding::Utf16Be => u16::from_be_bytes([pair[0], pair[1]]),
+        _ => u16::from_le_bytes([pair[0], pair[1]]),
+    });
+    let mut text = String::with_capacity(body.len() / 2);
+    let mut offset = start;
+    for c in std::char::decode_utf16(units) {
+        let c = c.map_err(|_| invalid(offset))?;
+        offset += c.len_utf16() * 2;
+        text.push(c);
+    }
+    Ok(text)
+}
+
+/// The start of a document, long enough to hold its XML declaration
+fn prefix(body: &[u8]) -> &[u8] {
+    match body.iter().position(|&b| b == b'>') {
+        Some(end) => &body[..=end],
+        None => body,
+    }
+}
+
+/// The `encoding` pseudo-attribute of the XML declaration at the start of `text`, if any
+fn declared_encoding(text: &str) -> Result<Option<Encoding>, XmlReadError> {
+    let label = match encoding_label(text) {
+        Some((_, label)) => label,
+        None => return Ok(None),
+    };
+    Encoding::from_label(label)
+        .map(Some)
+        .ok_or_else(|| XmlReadError::UnsupportedEncoding {
+            encoding: label.to_string(),
+        })
+}
+
+/// The position and value of the `encoding` pseudo-attribute in the XML declaration of `text`
+fn encoding_label(text: &str) -> Option<(usize, &str)> {
+    let declaration = text.strip_prefix("<?xml")?;
+    let declaration = &declaration[..declaration.find("?>")?];
+
+    let start = declaration.find("encoding")? + "encoding".len();
+    let value = declaration[start..]
+        .trim_start()
+        .strip_prefix('=')?
+        .trim_start();
+    let quote = value.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+    let label = &value[1..][..value[1..].find(quote)?];
+
+    let offset = "<?xml".len() + declaration.len() - value.len() + 1;
+    Some((offset, label))
+}
+
+fn declare_utf8(text: &str) -> String {
+    match encoding_label(text) {
+        Some((offset, label)) => {
+            format!("{}UTF-8{}", &text[..offset], &text[offset + label.len()..])
+        }
+        None => text.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const DOCUMENT: &str = r#"<bom xmlns="http://cyclonedx.org/schema/bom/1.3" version="1"><components><component type="library"><name>Café</name><version>1.0.0</version></component></components></bom>"#;
+
+    fn utf16(text: &str, big_endian: bool) -> Vec<u8> {
+        let mut bytes = if big_endian {
+            vec![0xfe, 0xff]
+        } else {
+            vec![0xff, 0xfe]
+        };
+        for unit in text.encode_utf16() {
+            if big_endian {
+                bytes.extend_from_slice(&unit.to_be_bytes());
+            } else {
+                bytes.extend_from_slice(&unit.to_le_bytes());
+            }
+        }
+        bytes
+    }
+
+    fn decoded(input: &[u8]) -> String {
+        String::from_utf8(decode_xml(input).expect("Failed to decode").into_owned())
+            .expect("Decoded document is not UTF-8")
+    }
+
+    #[test]
+    fn it_should_keep_utf8_documents() {
+        let input = format!(r#"<?xml version="1.0" encoding="utf-8"?>{}"#, DOCUMENT);
+        assert!(matches!(decode_xml(input.as_bytes()), Ok(Cow::Borrowed(_))));
+    }
+
+    #[test]
+    fn it_should_decode_utf16_documents() {
+        let input = format!(r#"<?xml version="1.0" encoding="UTF-16"?>{}"#, DOCUMENT);
+        let expected = format!(r#"<?xml version="1.0" encoding="UTF-8"?>{}"#, DOCUMENT);
+
+        assert_eq!(decoded(&utf16(&input, false)), expected);
+        assert_eq!(decoded(&utf16(&input, true)), expected);
+        assert!(is_utf16(&utf16(&input, false)));
+    }
+
+    #[test]
+    fn it_should_decode_latin1_documents() {
+        let input = format!(r#"<?xml version='1.0' encoding='ISO-8859-1'?>{}"#, DOCUMENT);
+        let bytes: Vec<u8> = input.chars().map(|c| c as u8).collect();
+
+        assert_eq!(
+            decoded(&bytes),
+            format!(r#"<?xml version='1.0' encoding='UTF-8'?>{}"#, DOCUMENT)
+        );
+    }
+
+    #[test]
+    fn it_should_reject_mismatched_encodings() {
+        let input = format!(r#"<?xml version="1.0" encoding="ISO-8859-1"?>{}"#, DOCUMENT);
+        assert_eq!(
+            decode_xml(&utf16(&input, false))
+                .expect_err("Expected an error")
+                .to_string(),
+            "The XML declaration declares the encoding ISO-8859-1, but the document is encoded in UTF-16LE"
+        );
+
+        let input = format!(r#"<?xml version="1.0" encoding="UTF-16"?>{}"#, DOCUMENT);
+        assert!(matches!(
+            decode_xml(input.as_bytes()),
+            Err(XmlReadError::EncodingMismatch { .. })
+        ));
+
+        let input =
+            r#"<?xml version="1.0"?><bom>Caf"#.bytes().chain(vec![0xe9]).collect::<Vec<u8>>();
+        assert_eq!(
+            decode_xml(&input)
+                .expect_err("Expected an error")
+                .to_string(),
+            "The document is not valid UTF-8 at byte 29"
+        );
+
+        let input = r#"<?xml version="1.0" encoding="Shift_JIS"?><bom/>"#;
+        assert!(matches!(
+            decode_xml(input.as_bytes()),
+            Err(XmlReadError::UnsupportedEncoding { encoding }) if encoding == "Shift_JIS"
+        ));
+    }
+}
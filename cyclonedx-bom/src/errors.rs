@@ -45,6 +45,12 @@ pub enum XmlWriteError {
         error: xml::writer::Error,
         element: String,
     },
+
+    #[error("Failed to flush the XML document: {error}")]
+    FlushError {
+        #[source]
+        error: std::io::Error,
+    },
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -55,6 +61,15 @@ pub enum JsonReadError {
         #[from]
         error: serde_json::Error,
     },
+
+    #[error("Missing specVersion, which is required to select the version of the specification")]
+    MissingSpecVersion,
+
+    #[error("{error}")]
+    SpecVersionError {
+        #[from]
+        error: crate::models::bom::SpecVersionError,
+    },
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -89,4 +104,148 @@ pub enum XmlReadError {
         expected_namespace: String,
         actual_namespace: Option<String>,
     },
+
+    #[error("The XML declaration declares the encoding {encoding}, which is not supported, expected UTF-8, UTF-16, ISO-8859-1 or US-ASCII")]
+    UnsupportedEncoding { encoding: String },
+
+    #[error(
+        "The XML declaration declares the encoding {declared}, but the document is encoded in {detected}"
+    )]
+    EncodingMismatch { declared: String, detected: String },
+
+    #[error("The document is not valid {encoding} at byte {offset}")]
+    InvalidEncoding { encoding: String, offset: usize },
+}
+
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum BomReadError {
+    #[error("{error}")]
+    JsonReadError {
+        #[from]
+        error: JsonReadError,
+    },
+
+    #[error("{error}")]
+    XmlReadError {
+        #[from]
+        error: XmlReadError,
+    },
+
+    #[error("Failed to decompress the gzip document: {error}")]
+    GzipError {
+        #[source]
+        error: std::io::Error,
+    },
+
+    #[error("Protobuf documents are not supported, convert the document to JSON or XML")]
+    UnsupportedProtobuf,
+
+    #[error("Unknown document format, expected a JSON, XML or Protobuf document")]
+    UnknownFormat,
+
+    #[error("{error}")]
+    LimitExceeded {
+        #[from]
+        error: LimitError,
+    },
+}
+
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum LimitError {
+    #[error("The document is larger than the limit of {limit} bytes")]
+    InputTooLarge { limit: usize },
+
+    #[error("The document is nested deeper than the limit of {limit} levels at {location}")]
+    TooDeep { limit: usize, location: String },
+
+    #[error("The document has more than the limit of {limit} items in a list at {location}")]
+    ArrayTooLong { limit: usize, location: String },
+}
+
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum OsvError {
+    #[error("The vulnerability has no id, which OSV requires")]
+    MissingId,
+
+    #[error("The vulnerability `{id}` has no created, published or updated timestamp, which OSV requires as its modified timestamp")]
+    MissingModified { id: String },
+
+    #[error(
+        "The affected `{bom_ref}` of the vulnerability `{id}` is not identified by a Package URL"
+    )]
+    UnsupportedTarget { id: String, bom_ref: String },
+
+    #[error(
+        "The version range `{range}` of the vulnerability `{id}` cannot be expressed as OSV events"
+    )]
+    UnsupportedVersionRange { id: String, range: String },
+}
+
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum SpdxError {
+    #[error("Failed to parse the SPDX JSON document: {0}")]
+    JsonReadError(#[from] serde_json::Error),
+
+    #[error("Line {line} of the SPDX tag-value document {message}")]
+    TagValueError { line: usize, message: String },
+
+    #[error("The SPDX tag-value document has no {0}")]
+    MissingTag(String),
+
+    #[error("Unsupported SPDX version `{0}`, expected SPDX-2.2 or SPDX-2.3")]
+    UnsupportedVersion(String),
+
+    #[error("The SPDX document has more than one element `{0}`")]
+    DuplicateId(String),
+}
+
+#[cfg(feature = "sign")]
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum SigningError {
+    #[error("The key is not a PEM encoded P-384 or Ed25519 key")]
+    UnsupportedKey,
+
+    #[error("Failed to parse the JSON document: {error}")]
+    JsonReadError {
+        #[from]
+        error: serde_json::Error,
+    },
+
+    #[error("The document is not a JSON object")]
+    NotAnObject,
+
+    #[error("The document has no root element")]
+    NoRootElement,
+
+    #[error("Failed to parse the XML document: {error}")]
+    XmlReadError {
+        #[from]
+        error: xml::reader::Error,
+    },
+
+    #[error("Version {spec_version} of the specification does not support signatures")]
+    UnsupportedSpecVersion { spec_version: String },
+
+    #[error("The document is signed already")]
+    AlreadySigned,
+
+    #[error("The document has no signature")]
+    Unsigned,
+
+    #[error("The document is signed with {algorithm}, but the key is a {key_algorithm} key")]
+    AlgorithmMismatch {
+        algorithm: String,
+        key_algorithm: String,
+    },
+
+    #[error("The signature does not match the document")]
+    InvalidSignature,
+
+    #[error("The signature uses {algorithm}, which is not supported")]
+    UnsupportedAlgorithm { algorithm: String },
 }
@@ -17,38 +17,58 @@
  */
 
 use std::convert::TryFrom;
+use std::str::FromStr;
 
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use thiserror::Error;
-use time::{format_description::well_known::Iso8601, OffsetDateTime};
+use time::{format_description::well_known::Rfc3339, OffsetDateTime};
 
-use crate::validation::{
-    FailureReason, Validate, ValidationContext, ValidationError, ValidationResult,
-};
+use crate::validation::{Validate, ValidationContext, ValidationError, ValidationResult};
 
-/// For the purposes of CycloneDX SBOM documents, `DateTime` is a ISO8601 formatted timestamp
+/// For the purposes of CycloneDX SBOM documents, `DateTime` is a RFC 3339 formatted timestamp
 ///
 /// The corresponding CycloneDX XML schema definition is the [`xs` namespace](https://cyclonedx.org/docs/1.3/xml/#ns_xs), which defines the [`dateTime`](https://www.w3.org/TR/xmlschema11-2/#dateTime)) format.
 ///
+/// The timestamp is checked when it is created, so documents with malformed timestamps are
+/// rejected while they are parsed. The UTC offset of the timestamp is kept as written.
+///
 /// A valid timestamp can be created from a [`String`](std::string::String) using the [`TryFrom`](std::convert::TryFrom) / [`TryInto`](std::convert::TryInto) traits.
 ///
 /// ```
 /// use cyclonedx_bom::external_models::date_time::DateTime;
 /// use std::convert::TryInto;
 ///
-/// let timestamp = String::from("1970-01-01T00:00:00Z");
+/// let timestamp = String::from("1969-06-28T01:20:00-04:00");
 /// let date_time: DateTime = timestamp.clone().try_into().expect("Failed to parse as DateTime");
 ///
 /// assert_eq!(date_time.to_string(), timestamp);
 /// ```
-#[derive(Debug, PartialEq, Eq)]
-pub struct DateTime(pub(crate) String);
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DateTime(pub(crate) OffsetDateTime);
 
 impl DateTime {
     pub fn now() -> Result<Self, DateTimeError> {
-        let now = OffsetDateTime::now_utc()
-            .format(&Iso8601::DEFAULT)
-            .map_err(|_| DateTimeError::FailedCurrentTime)?;
-        Ok(Self(now))
+        Self::try_from(OffsetDateTime::now_utc()).map_err(|_| DateTimeError::FailedCurrentTime)
+    }
+
+    /// The timestamp with the UTC offset it was created with
+    pub fn as_offset_date_time(&self) -> OffsetDateTime {
+        self.0
+    }
+}
+
+impl TryFrom<OffsetDateTime> for DateTime {
+    type Error = DateTimeError;
+
+    /// Fails for dates that RFC 3339 cannot represent, such as years before 0 or after 9999
+    fn try_from(value: OffsetDateTime) -> Result<Self, Self::Error> {
+        match value.format(&Rfc3339) {
+            Ok(_) => Ok(Self(value)),
+            Err(e) => Err(DateTimeError::InvalidDateTime(format!(
+                "DateTime cannot be represented in RFC 3339: {}",
+                e
+            ))),
+        }
     }
 }
 
@@ -56,10 +76,18 @@ impl TryFrom<String> for DateTime {
     type Error = DateTimeError;
 
     fn try_from(value: String) -> Result<Self, Self::Error> {
-        match OffsetDateTime::parse(&value, &Iso8601::DEFAULT) {
-            Ok(_) => Ok(Self(value)),
+        value.parse()
+    }
+}
+
+impl FromStr for DateTime {
+    type Err = DateTimeError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match OffsetDateTime::parse(value, &Rfc3339) {
+            Ok(date_time) => Ok(Self(date_time)),
             Err(e) => Err(DateTimeError::InvalidDateTime(format!(
-                "DateTime does not conform to ISO 8601: {}",
+                "DateTime does not conform to RFC 3339: {}",
                 e
             ))),
         }
@@ -69,23 +97,30 @@ impl TryFrom<String> for DateTime {
 impl Validate for DateTime {
     fn validate_with_context(
         &self,
-        context: ValidationContext,
+        _context: ValidationContext,
     ) -> Result<ValidationResult, ValidationError> {
-        match OffsetDateTime::parse(&self.0.to_string(), &Iso8601::DEFAULT) {
-            Ok(_) => Ok(ValidationResult::Passed),
-            Err(_) => Ok(ValidationResult::Failed {
-                reasons: vec![FailureReason {
-                    message: "DateTime does not conform to ISO 8601".to_string(),
-                    context,
-                }],
-            }),
-        }
+        // Only valid timestamps can be constructed
+        Ok(ValidationResult::Passed)
+    }
+}
+
+impl std::fmt::Display for DateTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let formatted = self.0.format(&Rfc3339).map_err(|_| std::fmt::Error)?;
+        f.write_str(&formatted)
+    }
+}
+
+impl Serialize for DateTime {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
     }
 }
 
-impl ToString for DateTime {
-    fn to_string(&self) -> String {
-        self.0.clone()
+impl<'de> Deserialize<'de> for DateTime {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        value.parse().map_err(serde::de::Error::custom)
     }
 }
 
@@ -101,32 +136,55 @@ pub enum DateTimeError {
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::validation::FailureReason;
     use pretty_assertions::assert_eq;
+    use time::{Date, Month, UtcOffset};
 
     #[test]
-    fn valid_datetimes_should_pass_validation() {
-        let validation_result = DateTime("1969-06-28T01:20:00.00-04:00".to_string())
-            .validate_with_context(ValidationContext::default())
-            .expect("Error while validating");
+    fn it_should_keep_the_offset() {
+        let date_time: DateTime = "1969-06-28T01:20:00.00-04:00"
+            .parse()
+            .expect("Failed to parse as DateTime");
 
-        assert_eq!(validation_result, ValidationResult::Passed)
+        assert_eq!(
+            date_time.as_offset_date_time().offset(),
+            UtcOffset::from_hms(-4, 0, 0).unwrap()
+        );
+        assert_eq!(date_time.to_string(), "1969-06-28T01:20:00-04:00");
+    }
+
+    #[test]
+    fn it_should_reject_timestamps_without_an_offset() {
+        for invalid in ["invalid date", "2020-04-07", "2020-04-07T07:01:00"] {
+            let actual = invalid.parse::<DateTime>();
+
+            assert!(
+                matches!(actual, Err(DateTimeError::InvalidDateTime(_))),
+                "{} should have been rejected",
+                invalid
+            );
+        }
+    }
+
+    #[test]
+    fn it_should_reject_dates_outside_of_rfc_3339() {
+        let before_year_zero = Date::from_calendar_date(-1, Month::January, 1)
+            .unwrap()
+            .midnight()
+            .assume_utc();
+        let actual = DateTime::try_from(before_year_zero);
+
+        assert!(matches!(actual, Err(DateTimeError::InvalidDateTime(_))));
     }
 
     #[test]
-    fn invalid_datetimes_should_fail_validation() {
-        let validation_result = DateTime("invalid date".to_string())
-            .validate_with_context(ValidationContext::default())
-            .expect("Error while validating");
+    fn it_should_round_trip_through_json() {
+        let date_time: DateTime =
+            serde_json::from_str("\"2020-04-13T20:20:39+02:00\"").expect("Failed to deserialize");
 
         assert_eq!(
-            validation_result,
-            ValidationResult::Failed {
-                reasons: vec![FailureReason {
-                    message: "DateTime does not conform to ISO 8601".to_string(),
-                    context: ValidationContext::default()
-                }]
-            }
-        )
+            serde_json::to_string(&date_time).expect("Failed to serialize"),
+            "\"2020-04-13T20:20:39+02:00\""
+        );
+        assert!(serde_json::from_str::<DateTime>("\"2020-04-13\"").is_err());
     }
 }
@@ -18,5 +18,6 @@
 
 pub mod date_time;
 pub mod normalized_string;
+pub mod purl;
 pub mod spdx;
 pub mod uri;
@@ -45,9 +45,9 @@ impl NormalizedString {
     }
 }
 
-impl ToString for NormalizedString {
-    fn to_string(&self) -> String {
-        self.0.clone()
+impl std::fmt::Display for NormalizedString {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
     }
 }
 
@@ -62,11 +62,10 @@ impl Validate for NormalizedString {
             || self.0.contains('\t')
         {
             return Ok(ValidationResult::Failed {
-                reasons: vec![FailureReason {
-                    message: "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
-                        .to_string(),
+                reasons: vec![FailureReason::error(
+                    "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
                     context,
-                }],
+                )],
             });
         }
 
@@ -114,11 +113,10 @@ mod test {
         assert_eq!(
             validation_result,
             ValidationResult::Failed {
-                reasons: vec![FailureReason {
-                    message: "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
-                        .to_string(),
-                    context: ValidationContext::default()
-                }]
+                reasons: vec![FailureReason::error(
+                    "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                    ValidationContext::default()
+                )]
             }
         );
     }
@@ -0,0 +1,338 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Package URLs, see the [purl specification](https://github.com/package-url/purl-spec)
+//!
+//! A [`Purl`] is either built from its parts with a [`PurlBuilder`], or parsed from a complete
+//! Package URL, and can be taken apart again with [`Purl::parts`].
+//!
+//! ```
+//! use cyclonedx_bom::external_models::purl::Purl;
+//!
+//! let purl = Purl::builder("cargo", "cyclonedx-bom")
+//!     .version("0.4.3")
+//!     .repository_url("https://example.com/registry")
+//!     .checksum("sha256", "0123abcd")
+//!     .build()
+//!     .expect("Failed to build a Purl");
+//! assert_eq!(
+//!     purl.to_string(),
+//!     "pkg:cargo/cyclonedx-bom@0.4.3?checksum=sha256:0123abcd&repository_url=https://example.com/registry"
+//! );
+//!
+//! let parts = purl.parts().expect("Failed to parse the Purl");
+//! assert_eq!(parts.version.as_deref(), Some("0.4.3"));
+//! assert_eq!(parts.checksums(), vec![("sha256", "0123abcd")]);
+//! ```
+
+use std::{collections::BTreeMap, convert::TryFrom, str::FromStr};
+
+use packageurl::PackageUrl;
+
+use crate::external_models::uri::UriError;
+use crate::validation::{
+    FailureReason, Validate, ValidationContext, ValidationError, ValidationResult,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Purl(pub(crate) String);
+
+impl Purl {
+    pub fn new(package_type: &str, name: &str, version: &str) -> Result<Purl, UriError> {
+        Self::builder(package_type, name).version(version).build()
+    }
+
+    /// Starts building a Package URL for the package `name` of type `package_type`
+    pub fn builder(package_type: &str, name: &str) -> PurlBuilder {
+        PurlBuilder {
+            package_type: package_type.to_string(),
+            name: name.to_string(),
+            ..PurlBuilder::default()
+        }
+    }
+
+    /// Parses the Package URL into its parts, which fails for Purls read from a document that do
+    /// not conform to the specification
+    pub fn parts(&self) -> Result<PurlParts, UriError> {
+        let purl =
+            PackageUrl::from_str(&self.0).map_err(|e| UriError::InvalidPurl(e.to_string()))?;
+
+        Ok(PurlParts {
+            package_type: purl.ty().to_string(),
+            namespace: purl.namespace().map(ToString::to_string),
+            name: purl.name().to_string(),
+            version: purl.version().map(ToString::to_string),
+            qualifiers: purl
+                .qualifiers()
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect(),
+            subpath: purl.subpath().map(ToString::to_string),
+        })
+    }
+}
+
+/// Parses a complete Package URL, e.g. one with qualifiers that [`Purl::new`] cannot express
+impl TryFrom<String> for Purl {
+    type Error = UriError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match PackageUrl::from_str(&value) {
+            Ok(_) => Ok(Purl(value)),
+            Err(e) => Err(UriError::InvalidPurl(e.to_string())),
+        }
+    }
+}
+
+impl FromStr for Purl {
+    type Err = UriError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s.to_string())
+    }
+}
+
+impl std::fmt::Display for Purl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Validate for Purl {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        match PackageUrl::from_str(&self.0) {
+            Ok(_) => Ok(ValidationResult::Passed),
+            Err(e) => Ok(ValidationResult::Failed {
+                reasons: vec![FailureReason::error(
+                    format!("Purl does not conform to Package URL spec: {}", e),
+                    context,
+                )],
+            }),
+        }
+    }
+}
+
+/// Builds a [`Purl`], percent-encoding its parts and sorting its qualifiers as the specification
+/// requires
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PurlBuilder {
+    package_type: String,
+    name: String,
+    namespace: Option<String>,
+    version: Option<String>,
+    qualifiers: Vec<(String, String)>,
+    checksums: Vec<String>,
+    subpath: Option<String>,
+}
+
+impl PurlBuilder {
+    pub fn namespace(mut self, namespace: &str) -> Self {
+        self.namespace = Some(namespace.to_string());
+        self
+    }
+
+    pub fn version(mut self, version: &str) -> Self {
+        self.version = Some(version.trim().to_string());
+        self
+    }
+
+    /// Adds the qualifier `key`, replacing an earlier value of the same key
+    pub fn qualifier(mut self, key: &str, value: &str) -> Self {
+        self.qualifiers
+            .retain(|(k, _)| !k.eq_ignore_ascii_case(key));
+        self.qualifiers.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// The registry or repository the package is downloaded from, if it is not the default one of
+    /// its type
+    pub fn repository_url(self, repository_url: &str) -> Self {
+        self.qualifier("repository_url", repository_url)
+    }
+
+    /// Adds a checksum of the package, e.g. `checksum("sha256", "0123abcd")`, which are combined
+    /// into a single `checksum` qualifier
+    pub fn checksum(mut self, algorithm: &str, hex: &str) -> Self {
+        self.checksums.push(format!(
+            "{}:{}",
+            algorithm.to_lowercase(),
+            hex.to_lowercase()
+        ));
+        self
+    }
+
+    pub fn subpath(mut self, subpath: &str) -> Self {
+        self.subpath = Some(subpath.to_string());
+        self
+    }
+
+    pub fn build(self) -> Result<Purl, UriError> {
+        let invalid = |e: packageurl::Error| UriError::InvalidPurl(e.to_string());
+
+        let mut purl = PackageUrl::new(self.package_type, self.name).map_err(invalid)?;
+        if let Some(namespace) = self.namespace {
+            purl.with_namespace(namespace);
+        }
+        if let Some(version) = self.version {
+            purl.with_version(version);
+        }
+        for (key, value) in self.qualifiers {
+            purl.add_qualifier(key, value).map_err(invalid)?;
+        }
+        if !self.checksums.is_empty() {
+            purl.add_qualifier("checksum", self.checksums.join(","))
+                .map_err(invalid)?;
+        }
+        if let Some(subpath) = self.subpath {
+            purl.with_subpath(subpath).map_err(invalid)?;
+        }
+
+        Ok(Purl(purl.to_string()))
+    }
+}
+
+/// The decoded parts of a [`Purl`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PurlParts {
+    pub package_type: String,
+    pub namespace: Option<String>,
+    pub name: String,
+    pub version: Option<String>,
+    pub qualifiers: BTreeMap<String, String>,
+    pub subpath: Option<String>,
+}
+
+impl PurlParts {
+    pub fn repository_url(&self) -> Option<&str> {
+        self.qualifiers.get("repository_url").map(String::as_str)
+    }
+
+    /// The `(algorithm, hex)` pairs of the `checksum` qualifier
+    pub fn checksums(&self) -> Vec<(&str, &str)> {
+        self.qualifiers
+            .get("checksum")
+            .map(|checksums| {
+                checksums
+                    .split(',')
+                    .filter_map(|checksum| checksum.split_once(':'))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::validation::FailureReason;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn valid_purls_should_pass_validation() {
+        let validation_result = Purl("pkg:cargo/cyclonedx-bom@0.3.1".to_string())
+            .validate_with_context(ValidationContext::default())
+            .expect("Error while validating");
+
+        assert_eq!(validation_result, ValidationResult::Passed);
+    }
+
+    #[test]
+    fn invalid_purls_should_fail_validation() {
+        let validation_result = Purl("invalid purl".to_string())
+            .validate_with_context(ValidationContext::default())
+            .expect("Error while validating");
+
+        assert_eq!(
+            validation_result,
+            ValidationResult::Failed {
+                reasons: vec![FailureReason::error(
+                    "Purl does not conform to Package URL spec: missing scheme",
+                    ValidationContext::default()
+                )]
+            }
+        );
+    }
+
+    #[test]
+    fn it_should_parse_purls_with_qualifiers() {
+        let purl = "pkg:oci/app@sha256%3A0123abcd?repository_url=ghcr.io/tacos/app&tag=1.0.0";
+
+        assert_eq!(Purl::try_from(purl.to_string()), Ok(Purl(purl.to_string())));
+        assert_eq!(
+            Purl::try_from("invalid purl".to_string()),
+            Err(UriError::InvalidPurl("missing scheme".to_string()))
+        );
+    }
+
+    #[test]
+    fn it_should_build_purls() {
+        let purl = Purl::builder("maven", "batik-anim")
+            .namespace("org.apache.xmlgraphics")
+            .version(" 1.9.1 ")
+            .qualifier("type", "pom")
+            .qualifier("classifier", "dist")
+            .qualifier("type", "jar")
+            .checksum("SHA1", "AD9503C3")
+            .checksum("sha256", "41f1ac1a")
+            .subpath("lib/anim")
+            .build()
+            .expect("Failed to build a Purl");
+
+        assert_eq!(
+            purl.to_string(),
+            "pkg:maven/org.apache.xmlgraphics/batik-anim@1.9.1?checksum=sha1:ad9503c3,sha256:41f1ac1a&classifier=dist&type=jar#lib/anim"
+        );
+        assert_eq!(
+            Purl::new("cargo", "cyclonedx-bom", "0.3.1"),
+            Ok(Purl("pkg:cargo/cyclonedx-bom@0.3.1".to_string()))
+        );
+        assert_eq!(
+            Purl::builder("cargo", "tacos")
+                .qualifier("not a key", "value")
+                .build(),
+            Err(UriError::InvalidPurl(
+                "invalid key: \"not a key\"".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn it_should_take_purls_apart() {
+        let purl: Purl =
+            "pkg:cargo/cyclonedx-bom@0.3.1?checksum=sha256:0123abcd&repository_url=https://example.com/index"
+                .parse()
+                .expect("Failed to parse the Purl");
+
+        let parts = purl.parts().expect("Failed to parse the Purl");
+
+        assert_eq!(parts.package_type, "cargo");
+        assert_eq!(parts.namespace, None);
+        assert_eq!(parts.name, "cyclonedx-bom");
+        assert_eq!(parts.version.as_deref(), Some("0.3.1"));
+        assert_eq!(parts.repository_url(), Some("https://example.com/index"));
+        assert_eq!(parts.checksums(), vec![("sha256", "0123abcd")]);
+        assert_eq!(parts.subpath, None);
+        assert_eq!(
+            Purl("invalid purl".to_string()).parts(),
+            Err(UriError::InvalidPurl("missing scheme".to_string()))
+        );
+    }
+}
@@ -74,9 +74,9 @@ impl TryFrom<String> for SpdxIdentifier {
     }
 }
 
-impl ToString for SpdxIdentifier {
-    fn to_string(&self) -> String {
-        self.0.clone()
+impl std::fmt::Display for SpdxIdentifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
     }
 }
 
@@ -88,10 +88,10 @@ impl Validate for SpdxIdentifier {
         match Self::try_from(self.0.clone()) {
             Ok(_) => Ok(ValidationResult::Passed),
             Err(_) => Ok(ValidationResult::Failed {
-                reasons: vec![FailureReason {
-                    message: "SPDX identifier is not valid".to_string(),
+                reasons: vec![FailureReason::error(
+                    "SPDX identifier is not valid",
                     context,
-                }],
+                )],
             }),
         }
     }
@@ -147,6 +147,60 @@ impl SpdxExpression {
         }
     }
 
+    /// Parse the expression into its tree of licenses and `AND`/`OR` operators
+    ///
+    /// Every license has to be on the SPDX license list, matched case-insensitively, or be a
+    /// `LicenseRef-`. The tree spells each license as the list does.
+    /// ```
+    /// use cyclonedx_bom::prelude::*;
+    /// use cyclonedx_bom::external_models::spdx::{SpdxLicense, SpdxTree};
+    /// # use cyclonedx_bom::external_models::spdx::SpdxExpressionError;
+    ///
+    /// let tree = SpdxExpression::parse_lax("MIT/Apache-2.0".to_string())?.tree()?;
+    /// assert_eq!(
+    ///     tree,
+    ///     SpdxTree::Or(vec![
+    ///         SpdxTree::License(SpdxLicense::new("MIT")),
+    ///         SpdxTree::License(SpdxLicense::new("Apache-2.0")),
+    ///     ])
+    /// );
+    /// # Ok::<(), SpdxExpressionError>(())
+    /// ```
+    pub fn tree(&self) -> Result<SpdxTree, SpdxExpressionError> {
+        let tokens = tokenize(&self.0);
+        let mut parser = Parser {
+            tokens: &tokens,
+            position: 0,
+        };
+        let tree = parser.parse_or()?;
+        match parser.next() {
+            None => Ok(tree),
+            Some(token) => Err(SpdxExpressionError::InvalidSpdxExpression(format!(
+                "unexpected `{}`",
+                token
+            ))),
+        }
+    }
+
+    /// Replaces the deprecated licenses of the expression with their successors, e.g. `GPL-2.0+`
+    /// with `GPL-2.0-or-later` and `wxWindows` with `GPL-2.0-or-later WITH WxWindows-exception-3.1`
+    ///
+    /// ```
+    /// use cyclonedx_bom::prelude::*;
+    /// # use cyclonedx_bom::external_models::spdx::SpdxExpressionError;
+    /// use std::convert::TryFrom;
+    ///
+    /// let expression = SpdxExpression::try_from("StandardML-NJ OR wxWindows".to_string())?;
+    /// assert_eq!(
+    ///     expression.normalized()?.to_string(),
+    ///     "SMLNJ OR GPL-2.0-or-later WITH WxWindows-exception-3.1"
+    /// );
+    /// # Ok::<(), SpdxExpressionError>(())
+    /// ```
+    pub fn normalized(&self) -> Result<Self, SpdxExpressionError> {
+        Ok(Self(self.tree()?.normalized().to_string()))
+    }
+
     fn convert_lax(self) -> Result<Self, SpdxExpressionError> {
         let converted = self.0.replace('/', " OR ");
 
@@ -174,9 +228,9 @@ impl TryFrom<String> for SpdxExpression {
     }
 }
 
-impl ToString for SpdxExpression {
-    fn to_string(&self) -> String {
-        self.0.clone()
+impl std::fmt::Display for SpdxExpression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
     }
 }
 
@@ -188,10 +242,10 @@ impl Validate for SpdxExpression {
         match SpdxExpression::try_from(self.0.clone()) {
             Ok(_) => Ok(ValidationResult::Passed),
             Err(_) => Ok(ValidationResult::Failed {
-                reasons: vec![FailureReason {
-                    message: "SPDX expression is not valid".to_string(),
+                reasons: vec![FailureReason::error(
+                    "SPDX expression is not valid",
                     context,
-                }],
+                )],
             }),
         }
     }
@@ -206,6 +260,333 @@ pub enum SpdxExpressionError {
     InvalidLaxSpdxExpression(String),
 }
 
+/// A parsed SPDX license expression, with `AND` binding tighter than `OR`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SpdxTree {
+    License(SpdxLicense),
+    /// All of the licenses apply
+    And(Vec<SpdxTree>),
+    /// Any of the licenses can be chosen
+    Or(Vec<SpdxTree>),
+}
+
+impl SpdxTree {
+    /// The licenses of the expression, from left to right
+    pub fn licenses(&self) -> Vec<&SpdxLicense> {
+        match self {
+            SpdxTree::License(license) => vec![license],
+            SpdxTree::And(operands) | SpdxTree::Or(operands) => {
+                operands.iter().flat_map(SpdxTree::licenses).collect()
+            }
+        }
+    }
+
+    /// Replaces the deprecated licenses with their successors, see [`SpdxExpression::normalized`]
+    pub fn normalized(self) -> Self {
+        match self {
+            SpdxTree::License(license) => SpdxTree::License(license.normalized()),
+            SpdxTree::And(operands) => {
+                SpdxTree::And(operands.into_iter().map(SpdxTree::normalized).collect())
+            }
+            SpdxTree::Or(operands) => {
+                SpdxTree::Or(operands.into_iter().map(SpdxTree::normalized).collect())
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for SpdxTree {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (operands, operator) = match self {
+            SpdxTree::License(license) => return license.fmt(f),
+            SpdxTree::And(operands) => (operands, " AND "),
+            SpdxTree::Or(operands) => (operands, " OR "),
+        };
+        for (index, operand) in operands.iter().enumerate() {
+            if index > 0 {
+                f.write_str(operator)?;
+            }
+            match (self, operand) {
+                (SpdxTree::And(_), SpdxTree::Or(_)) => write!(f, "({})", operand)?,
+                _ => operand.fmt(f)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A license of an SPDX license expression, e.g. `GPL-3.0-or-later WITH GCC-exception-3.1`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpdxLicense {
+    /// An identifier of the SPDX license list, or a `LicenseRef-`
+    pub id: String,
+    /// Whether later versions of the license can be chosen, written as `+`
+    pub or_later: bool,
+    /// The identifier of the exception following `WITH`
+    pub exception: Option<String>,
+}
+
+impl SpdxLicense {
+    pub fn new(id: &str) -> Self {
+        Self {
+            id: id.to_string(),
+            or_later: false,
+            exception: None,
+        }
+    }
+
+    fn normalized(self) -> Self {
+        if let Some((_, successor, exception)) =
+            RENAMED_LICENSES.iter().find(|(id, _, exception)| {
+                *id == self.id && (exception.is_none() || self.exception.is_none())
+            })
+        {
+            return Self {
+                id: successor.to_string(),
+                or_later: self.or_later,
+                exception: exception.map(ToString::to_string).or(self.exception),
+            };
+        }
+
+        if spdx::license_id(&self.id).map_or(false, |id| id.is_deprecated()) {
+            let (root, plus) = match self.id.strip_suffix('+') {
+                Some(root) => (root, true),
+                None => (self.id.as_str(), self.or_later),
+            };
+            let suffix = if plus { "-or-later" } else { "-only" };
+            if let Some(successor) = spdx::license_id(&format!("{}{}", root, suffix)) {
+                return Self {
+                    id: successor.name.to_string(),
+                    or_later: false,
+                    exception: self.exception,
+                };
+            }
+        }
+
+        self
+    }
+}
+
+impl std::fmt::Display for SpdxLicense {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.id)?;
+        if self.or_later {
+            f.write_str("+")?;
+        }
+        if let Some(exception) = &self.exception {
+            write!(f, " WITH {}", exception)?;
+        }
+        Ok(())
+    }
+}
+
+/// Deprecated licenses that are not succeeded by their `-only` variant, with their successor and
+/// the exception the successor needs
+const RENAMED_LICENSES: &[(&str, &str, Option<&str>)] = &[
+    ("BSD-2-Clause-FreeBSD", "BSD-2-Clause", None),
+    ("BSD-2-Clause-NetBSD", "BSD-2-Clause", None),
+    ("Nunit", "zlib-acknowledgement", None),
+    ("StandardML-NJ", "SMLNJ", None),
+    ("eCos-2.0", "GPL-2.0-or-later", Some("eCos-exception-2.0")),
+    (
+        "wxWindows",
+        "GPL-2.0-or-later",
+        Some("WxWindows-exception-3.1"),
+    ),
+    (
+        "GPL-2.0-with-autoconf-exception",
+        "GPL-2.0-only",
+        Some("Autoconf-exception-2.0"),
+    ),
+    (
+        "GPL-2.0-with-bison-exception",
+        "GPL-2.0-or-later",
+        Some("Bison-exception-2.2"),
+    ),
+    (
+        "GPL-2.0-with-classpath-exception",
+        "GPL-2.0-only",
+        Some("Classpath-exception-2.0"),
+    ),
+    (
+        "GPL-2.0-with-font-exception",
+        "GPL-2.0-only",
+        Some("Font-exception-2.0"),
+    ),
+    (
+        "GPL-2.0-with-GCC-exception",
+        "GPL-2.0-only",
+        Some("GCC-exception-2.0"),
+    ),
+    (
+        "GPL-3.0-with-autoconf-exception",
+        "GPL-3.0-only",
+        Some("Autoconf-exception-3.0"),
+    ),
+    (
+        "GPL-3.0-with-GCC-exception",
+        "GPL-3.0-only",
+        Some("GCC-exception-3.1"),
+    ),
+];
+
+/// Splits an expression into parentheses and the words between whitespace and parentheses
+fn tokenize(expression: &str) -> Vec<&str> {
+    let mut tokens = Vec::new();
+    let mut start = None;
+    for (index, c) in expression.char_indices() {
+        if c.is_whitespace() || c == '(' || c == ')' {
+            if let Some(start) = start.take() {
+                tokens.push(&expression[start..index]);
+            }
+            if !c.is_whitespace() {
+                tokens.push(&expression[index..index + 1]);
+            }
+        } else if start.is_none() {
+            start = Some(index);
+        }
+    }
+    if let Some(start) = start {
+        tokens.push(&expression[start..]);
+    }
+    tokens
+}
+
+/// Recursive descent parser of the SPDX license expression grammar
+struct Parser<'a> {
+    tokens: &'a [&'a str],
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn next(&mut self) -> Option<&'a str> {
+        let token = self.tokens.get(self.position).copied();
+        self.position += 1;
+        token
+    }
+
+    fn next_is(&mut self, expected: &str) -> bool {
+        if self.tokens.get(self.position) == Some(&expected) {
+            self.position += 1;
+            return true;
+        }
+        false
+    }
+
+    fn parse_or(&mut self) -> Result<SpdxTree, SpdxExpressionError> {
+        let mut operands = vec![self.parse_and()?];
+        while self.next_is("OR") {
+            operands.push(self.parse_and()?);
+        }
+        Ok(combine(operands, SpdxTree::Or))
+    }
+
+    fn parse_and(&mut self) -> Result<SpdxTree, SpdxExpressionError> {
+        let mut operands = vec![self.parse_with()?];
+        while self.next_is("AND") {
+            operands.push(self.parse_with()?);
+        }
+        Ok(combine(operands, SpdxTree::And))
+    }
+
+    fn parse_with(&mut self) -> Result<SpdxTree, SpdxExpressionError> {
+        let parenthesized = self.tokens.get(self.position) == Some(&"(");
+        let tree = self.parse_term()?;
+        if !self.next_is("WITH") {
+            return Ok(tree);
+        }
+        match (tree, self.next()) {
+            (SpdxTree::License(mut license), Some(exception)) if !parenthesized => {
+                license.exception = Some(
+                    spdx::exception_id(exception)
+                        .or_else(|| {
+                            spdx::identifiers::EXCEPTIONS
+                                .iter()
+                                .find(|(name, _)| name.eq_ignore_ascii_case(exception))
+                                .and_then(|(name, _)| spdx::exception_id(name))
+                        })
+                        .map(|id| id.name.to_string())
+                        .ok_or_else(|| invalid(format!("unknown exception `{}`", exception)))?,
+                );
+                Ok(SpdxTree::License(license))
+            }
+            (_, Some(token)) => Err(invalid(format!(
+                "`WITH {}` does not follow a license",
+                token
+            ))),
+            (_, None) => Err(invalid("unexpected end of expression".to_string())),
+        }
+    }
+
+    fn parse_term(&mut self) -> Result<SpdxTree, SpdxExpressionError> {
+        match self.next() {
+            Some("(") => {
+                let tree = self.parse_or()?;
+                if self.next_is(")") {
+                    Ok(tree)
+                } else {
+                    Err(invalid("unclosed parenthesis".to_string()))
+                }
+            }
+            Some(token @ (")" | "AND" | "OR" | "WITH")) => {
+                Err(invalid(format!("unexpected `{}`", token)))
+            }
+            Some(token) => parse_license(token).map(SpdxTree::License),
+            None => Err(invalid("unexpected end of expression".to_string())),
+        }
+    }
+}
+
+fn combine(mut operands: Vec<SpdxTree>, operator: fn(Vec<SpdxTree>) -> SpdxTree) -> SpdxTree {
+    if operands.len() == 1 {
+        return operands.remove(0);
+    }
+    operator(operands)
+}
+
+fn parse_license(token: &str) -> Result<SpdxLicense, SpdxExpressionError> {
+    let reference = token
+        .strip_prefix("DocumentRef-")
+        .and_then(|reference| reference.split_once(':'))
+        .map_or(token, |(_, license)| license);
+    if let Some(id) = reference.strip_prefix("LicenseRef-") {
+        if !id.is_empty()
+            && id
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '.')
+        {
+            return Ok(SpdxLicense::new(token));
+        }
+    }
+
+    if let Some(id) = license_id(token) {
+        return Ok(SpdxLicense::new(id));
+    }
+    match token.strip_suffix('+').and_then(license_id) {
+        Some(id) => Ok(SpdxLicense {
+            id: id.to_string(),
+            or_later: true,
+            exception: None,
+        }),
+        None => Err(invalid(format!("unknown license `{}`", token))),
+    }
+}
+
+/// The identifier on the SPDX license list that matches `id` case-insensitively
+///
+/// Unlike [`spdx::license_id`], a `+` suffix is not ignored, as the list contains e.g. `GPL-2.0+`.
+fn license_id(id: &str) -> Option<&'static str> {
+    let licenses = spdx::identifiers::LICENSES.iter().map(|(name, _, _)| *name);
+    licenses
+        .clone()
+        .find(|name| *name == id)
+        .or_else(|| licenses.clone().find(|name| name.eq_ignore_ascii_case(id)))
+}
+
+fn invalid(reason: String) -> SpdxExpressionError {
+    SpdxExpressionError::InvalidSpdxExpression(reason)
+}
+
 #[cfg(test)]
 mod test {
     use crate::validation::{FailureReason, ValidationContext, ValidationResult};
@@ -273,10 +654,10 @@ mod test {
         assert_eq!(
             validation_result,
             ValidationResult::Failed {
-                reasons: vec![FailureReason {
-                    message: "SPDX identifier is not valid".to_string(),
-                    context: ValidationContext::default()
-                }]
+                reasons: vec![FailureReason::error(
+                    "SPDX identifier is not valid",
+                    ValidationContext::default()
+                )]
             }
         );
     }
@@ -305,6 +686,103 @@ mod test {
         );
     }
 
+    #[test]
+    fn it_should_parse_spdx_expressions_into_a_tree() {
+        let tree = SpdxExpression(
+            "mit AND (Apache-2.0 WITH llvm-exception OR GPL-2.0+) AND LicenseRef-Tacos".to_string(),
+        )
+        .tree()
+        .expect("Failed to parse the expression");
+
+        assert_eq!(
+            tree,
+            SpdxTree::And(vec![
+                SpdxTree::License(SpdxLicense::new("MIT")),
+                SpdxTree::Or(vec![
+                    SpdxTree::License(SpdxLicense {
+                        id: "Apache-2.0".to_string(),
+                        or_later: false,
+                        exception: Some("LLVM-exception".to_string()),
+                    }),
+                    SpdxTree::License(SpdxLicense::new("GPL-2.0+")),
+                ]),
+                SpdxTree::License(SpdxLicense::new("LicenseRef-Tacos")),
+            ])
+        );
+        assert_eq!(
+            tree.to_string(),
+            "MIT AND (Apache-2.0 WITH LLVM-exception OR GPL-2.0+) AND LicenseRef-Tacos"
+        );
+        assert_eq!(tree.licenses().len(), 4);
+        assert_eq!(
+            SpdxExpression("(MIT AND Zlib) OR CC-BY-4.0+".to_string())
+                .tree()
+                .expect("Failed to parse the expression")
+                .to_string(),
+            "MIT AND Zlib OR CC-BY-4.0+"
+        );
+    }
+
+    #[test]
+    fn it_should_fail_to_parse_invalid_spdx_expressions_into_a_tree() {
+        for (expression, reason) in [
+            ("MIT OR", "unexpected end of expression"),
+            ("MIT Apache-2.0", "unexpected `Apache-2.0`"),
+            ("(MIT OR Apache-2.0", "unclosed parenthesis"),
+            ("MIT OR Tacos", "unknown license `Tacos`"),
+            ("MIT WITH Tacos", "unknown exception `Tacos`"),
+            (
+                "(MIT) WITH LLVM-exception",
+                "`WITH LLVM-exception` does not follow a license",
+            ),
+            ("MIT or Apache-2.0", "unexpected `or`"),
+        ] {
+            assert_eq!(
+                SpdxExpression(expression.to_string()).tree(),
+                Err(SpdxExpressionError::InvalidSpdxExpression(
+                    reason.to_string()
+                )),
+                "{}",
+                expression
+            );
+        }
+    }
+
+    #[test]
+    fn it_should_normalize_deprecated_licenses() {
+        for (expression, normalized) in [
+            ("GPL-2.0 OR LGPL-2.1+", "GPL-2.0-only OR LGPL-2.1-or-later"),
+            (
+                "GFDL-1.3+ AND AGPL-3.0",
+                "GFDL-1.3-or-later AND AGPL-3.0-only",
+            ),
+            (
+                "GPL-2.0-with-classpath-exception",
+                "GPL-2.0-only WITH Classpath-exception-2.0",
+            ),
+            ("wxWindows", "GPL-2.0-or-later WITH WxWindows-exception-3.1"),
+            ("StandardML-NJ OR Nunit", "SMLNJ OR zlib-acknowledgement"),
+            ("MIT OR Apache-2.0", "MIT OR Apache-2.0"),
+        ] {
+            assert_eq!(
+                SpdxExpression(expression.to_string())
+                    .normalized()
+                    .expect("Failed to normalize the expression")
+                    .to_string(),
+                normalized
+            );
+        }
+
+        for (_, successor, exception) in RENAMED_LICENSES {
+            assert!(spdx::license_id(successor).is_some(), "{}", successor);
+            assert!(
+                exception.map_or(true, |exception| spdx::exception_id(exception).is_some()),
+                "{:?}",
+                exception
+            );
+        }
+    }
+
     #[test]
     fn valid_spdx_expressions_should_pass_validation() {
         let validation_result = SpdxExpression("MIT OR Apache-2.0".to_string())
@@ -323,10 +801,10 @@ mod test {
         assert_eq!(
             validation_result,
             ValidationResult::Failed {
-                reasons: vec![FailureReason {
-                    message: "SPDX expression is not valid".to_string(),
-                    context: ValidationContext::default()
-                }]
+                reasons: vec![FailureReason::error(
+                    "SPDX expression is not valid",
+                    ValidationContext::default()
+                )]
             }
         );
     }
@@ -16,50 +16,17 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
-use std::{convert::TryFrom, str::FromStr};
+use std::convert::TryFrom;
 
-use packageurl::PackageUrl;
 use thiserror::Error;
 
+/// Package URLs used to live in this module
+pub use crate::external_models::purl::Purl;
+
 use crate::validation::{
     FailureReason, Validate, ValidationContext, ValidationError, ValidationResult,
 };
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct Purl(pub(crate) String);
-
-impl Purl {
-    pub fn new(package_type: &str, name: &str, version: &str) -> Result<Purl, UriError> {
-        match packageurl::PackageUrl::new(package_type, name) {
-            Ok(mut purl) => Ok(Self(purl.with_version(version.trim()).to_string())),
-            Err(e) => Err(UriError::InvalidPurl(e.to_string())),
-        }
-    }
-}
-
-impl ToString for Purl {
-    fn to_string(&self) -> String {
-        self.0.to_string()
-    }
-}
-
-impl Validate for Purl {
-    fn validate_with_context(
-        &self,
-        context: ValidationContext,
-    ) -> Result<ValidationResult, ValidationError> {
-        match PackageUrl::from_str(&self.0.to_string()) {
-            Ok(_) => Ok(ValidationResult::Passed),
-            Err(e) => Ok(ValidationResult::Failed {
-                reasons: vec![FailureReason {
-                    message: format!("Purl does not conform to Package URL spec: {}", e),
-                    context,
-                }],
-            }),
-        }
-    }
-}
-
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Uri(pub(crate) String);
 
@@ -84,18 +51,18 @@ impl Validate for Uri {
         match self.0.parse::<http::Uri>() {
             Ok(_) => Ok(ValidationResult::Passed),
             Err(_) => Ok(ValidationResult::Failed {
-                reasons: vec![FailureReason {
-                    message: "Uri does not conform to ISO 8601".to_string(),
+                reasons: vec![FailureReason::error(
+                    "Uri does not conform to ISO 8601",
                     context,
-                }],
+                )],
             }),
         }
     }
 }
 
-impl ToString for Uri {
-    fn to_string(&self) -> String {
-        self.0.clone()
+impl std::fmt::Display for Uri {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
     }
 }
 
@@ -114,33 +81,6 @@ mod test {
     use crate::validation::FailureReason;
     use pretty_assertions::assert_eq;
 
-    #[test]
-    fn valid_purls_should_pass_validation() {
-        let validation_result = Purl("pkg:cargo/cyclonedx-bom@0.3.1".to_string())
-            .validate_with_context(ValidationContext::default())
-            .expect("Error while validating");
-
-        assert_eq!(validation_result, ValidationResult::Passed);
-    }
-
-    #[test]
-    fn invalid_purls_should_fail_validation() {
-        let validation_result = Purl("invalid purl".to_string())
-            .validate_with_context(ValidationContext::default())
-            .expect("Error while validating");
-
-        assert_eq!(
-            validation_result,
-            ValidationResult::Failed {
-                reasons: vec![FailureReason {
-                    message: "Purl does not conform to Package URL spec: missing scheme"
-                        .to_string(),
-                    context: ValidationContext::default()
-                }]
-            }
-        );
-    }
-
     #[test]
     fn valid_uris_should_pass_validation() {
         let validation_result = Uri("https://example.com".to_string())
@@ -159,10 +99,10 @@ mod test {
         assert_eq!(
             validation_result,
             ValidationResult::Failed {
-                reasons: vec![FailureReason {
-                    message: "Uri does not conform to ISO 8601".to_string(),
-                    context: ValidationContext::default()
-                }]
+                reasons: vec![FailureReason::error(
+                    "Uri does not conform to ISO 8601",
+                    ValidationContext::default()
+                )]
             }
         );
     }
@@ -0,0 +1,116 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use serde::Serialize;
+use std::fmt;
+
+use crate::models::bom::SpecVersion;
+
+/// Encoding of a BOM document
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DocumentFormat {
+    Json,
+    Xml,
+    Protobuf,
+}
+
+impl fmt::Display for DocumentFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DocumentFormat::Json => write!(f, "JSON"),
+            DocumentFormat::Xml => write!(f, "XML"),
+            DocumentFormat::Protobuf => write!(f, "Protobuf"),
+        }
+    }
+}
+
+/// The format [`Bom::parse`](crate::models::bom::Bom::parse) detected for a document
+///
+/// * `format` - Encoding of the BOM
+/// * `gzip` - Whether the document was compressed with gzip
+/// * `spec_version` - Version of the specification the document conforms to
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DetectedFormat {
+    pub format: DocumentFormat,
+    pub gzip: bool,
+    pub spec_version: SpecVersion,
+}
+
+const GZIP_MAGIC: &[u8] = &[0x1f, 0x8b];
+const UTF8_BOM: &[u8] = &[0xef, 0xbb, 0xbf];
+
+/// Whether `bytes` start like a gzip stream
+pub(crate) fn is_gzip(bytes: &[u8]) -> bool {
+    bytes.starts_with(GZIP_MAGIC)
+}
+
+/// Detects the encoding of an uncompressed document from its first bytes
+pub(crate) fn sniff(bytes: &[u8]) -> Option<DocumentFormat> {
+    if is_protobuf(bytes) {
+        return Some(DocumentFormat::Protobuf);
+    }
+    // JSON documents have to be UTF-8 encoded
+    if crate::encoding::is_utf16(bytes) {
+        return Some(DocumentFormat::Xml);
+    }
+
+    let text = bytes.strip_prefix(UTF8_BOM).unwrap_or(bytes);
+    match text.iter().find(|byte| !byte.is_ascii_whitespace()) {
+        Some(b'{') => Some(DocumentFormat::Json),
+        Some(b'<') => Some(DocumentFormat::Xml),
+        _ => None,
+    }
+}
+
+/// A CycloneDX Protobuf message starts with its first field, the spec version, which is a
+/// length-delimited string like `1.3`
+fn is_protobuf(bytes: &[u8]) -> bool {
+    match bytes {
+        [0x0a, length, b'1', b'.', ..] => (3..=4).contains(length),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_should_sniff_the_document_format() {
+        assert_eq!(
+            sniff(b"  \n{\"bomFormat\": \"CycloneDX\"}"),
+            Some(DocumentFormat::Json)
+        );
+        assert_eq!(
+            sniff(b"\xef\xbb\xbf<?xml version=\"1.0\"?><bom/>"),
+            Some(DocumentFormat::Xml)
+        );
+        assert_eq!(
+            sniff(b"\x0a\x031.4\x10\x01"),
+            Some(DocumentFormat::Protobuf)
+        );
+        assert_eq!(
+            sniff(b"\xff\xfe<\x00?\x00x\x00m\x00l\x00"),
+            Some(DocumentFormat::Xml)
+        );
+        assert_eq!(sniff(b"bomFormat: CycloneDX"), None);
+        assert_eq!(sniff(b""), None);
+        assert!(is_gzip(&[0x1f, 0x8b, 0x08]));
+    }
+}
@@ -64,20 +64,14 @@
 //!     tool::{Tool, Tools},
 //! };
 //!
-//! let bom = Bom {
-//!     serial_number: Some(
+//! let bom = Bom::default()
+//!     .with_serial_number(
 //!         UrnUuid::new("urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79".to_string())
 //!             .expect("Failed to create UrnUuid"),
-//!     ),
-//!     metadata: Some(Metadata {
-//!         tools: Some(Tools(vec![Tool {
-//!             name: Some(NormalizedString::new("my_tool")),
-//!             ..Tool::default()
-//!         }])),
-//!         ..Metadata::default()
-//!     }),
-//!     ..Bom::default()
-//! };
+//!     )
+//!     .with_metadata(Metadata::default().with_tools(Tools::List(vec![
+//!         Tool::default().with_name(NormalizedString::new("my_tool")),
+//!     ])));
 //!
 //! let mut output = Vec::<u8>::new();
 //!
@@ -117,6 +111,13 @@
 //! [`Validate`](crate::validation::Validate) trait to enable you to find invalid data in a parsed
 //! SBOM. An example of this can be seen in the "Read and validate an SBOM" code snippet.
 //!
+//! ### Forward compatibility
+//!
+//! The main models are `#[non_exhaustive]`, so that fields for new versions of the specification
+//! can be added without a breaking change. Outside of this crate they are constructed with `new`
+//! (taking the required fields) or `Default`, and the optional fields are set with the `with_*`
+//! methods. All fields remain public for reading and modification.
+//!
 //! ### Prelude
 //!
 //! The library provides a prelude (similar to the [Rust Standard Library's prelude](https://doc.rust-lang.org/std/prelude/index.html)) to make it easier to use the code. The prelude contains commonly used types and traits. To use this in your library, include the following code snippet:
@@ -125,12 +126,27 @@
 //! use cyclonedx_bom::prelude::*;
 //! ```
 
+pub mod anonymize;
+pub mod capabilities;
+pub mod compat;
 pub mod errors;
 pub mod external_models;
+pub mod format;
+pub mod limits;
 pub mod models;
+pub mod osv;
 pub mod prelude;
+pub mod query;
+#[cfg(feature = "sign")]
+pub mod signing;
+pub mod spdx_document;
+pub mod taxonomy;
 pub mod validation;
+pub mod warnings;
+
+pub use crate::capabilities::capabilities;
 
+mod encoding;
 mod specs;
 mod utilities;
 mod xml;
@@ -0,0 +1,285 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Limits on the resources spent parsing a document, for BOMs from untrusted sources
+//!
+//! The structure of a document is checked against the limits in a single pass before it is
+//! parsed, so that a document exceeding them is rejected before any of it is held in memory as a
+//! BOM. The size limit applies to the decompressed document, so it also guards against gzip
+//! bombs.
+//!
+//! ```
+//! use cyclonedx_bom::errors::{BomReadError, LimitError};
+//! use cyclonedx_bom::limits::ParseLimits;
+//! use cyclonedx_bom::models::bom::Bom;
+//!
+//! let limits = ParseLimits {
+//!     max_input_size: Some(10 * 1024 * 1024),
+//!     max_depth: Some(32),
+//!     max_array_length: Some(2),
+//! };
+//! let json = r#"{
+//!   "bomFormat": "CycloneDX",
+//!   "specVersion": "1.3",
+//!   "version": 1,
+//!   "components": [
+//!     { "type": "library", "name": "a", "version": "1.0.0" },
+//!     { "type": "library", "name": "b", "version": "1.0.0" },
+//!     { "type": "library", "name": "c", "version": "1.0.0" }
+//!   ]
+//! }"#;
+//!
+//! assert!(matches!(
+//!     Bom::parse_with_limits(json.as_bytes(), &limits),
+//!     Err(BomReadError::LimitExceeded {
+//!         error: LimitError::ArrayTooLong { limit: 2, .. }
+//!     })
+//! ));
+//! ```
+
+use xml::{reader, EventReader, ParserConfig};
+
+use crate::errors::LimitError;
+
+/// Limits on a document to parse, where `None` does not limit it
+///
+/// * `max_input_size` - Size of the document in bytes, after decompressing it
+/// * `max_depth` - Nesting depth of the JSON objects and arrays or the XML elements
+/// * `max_array_length` - Number of items in a JSON array or of child elements of an XML element
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ParseLimits {
+    pub max_input_size: Option<usize>,
+    pub max_depth: Option<usize>,
+    pub max_array_length: Option<usize>,
+}
+
+impl ParseLimits {
+    fn limits_structure(&self) -> bool {
+        self.max_depth.is_some() || self.max_array_length.is_some()
+    }
+
+    pub(crate) fn check_size(&self, size: usize) -> Result<(), LimitError> {
+        match self.max_input_size {
+            Some(limit) if size > limit => Err(LimitError::InputTooLarge { limit }),
+            _ => Ok(()),
+        }
+    }
+
+    fn check_depth(
+        &self,
+        depth: usize,
+        location: impl FnOnce() -> String,
+    ) -> Result<(), LimitError> {
+        match self.max_depth {
+            Some(limit) if depth > limit => Err(LimitError::TooDeep {
+                limit,
+                location: location(),
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    fn check_length(
+        &self,
+        length: usize,
+        location: impl FnOnce() -> String,
+    ) -> Result<(), LimitError> {
+        match self.max_array_length {
+            Some(limit) if length > limit => Err(LimitError::ArrayTooLong {
+                limit,
+                location: location(),
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks the nesting and the arrays of a JSON document, which are located by byte offset
+    ///
+    /// Malformed documents are left to the JSON parser to report.
+    pub(crate) fn check_json(&self, input: &[u8]) -> Result<(), LimitError> {
+        if !self.limits_structure() {
+            return Ok(());
+        }
+
+        struct Array {
+            length: usize,
+            expects_item: bool,
+        }
+
+        // `None` for an object
+        let mut open: Vec<Option<Array>> = Vec::new();
+        let mut in_string = false;
+        let mut escaped = false;
+
+        for (offset, &byte) in input.iter().enumerate() {
+            let location = || format!("byte {}", offset);
+            if in_string {
+                match byte {
+                    _ if escaped => escaped = false,
+                    b'\\' => escaped = true,
+                    b'"' => in_string = false,
+                    _ => {}
+                }
+                continue;
+            }
+            if byte.is_ascii_whitespace() {
+                continue;
+            }
+
+            match open.last_mut() {
+                Some(Some(array)) if byte == b',' => array.expects_item = true,
+                Some(Some(array)) if array.expects_item && byte != b']' => {
+                    array.expects_item = false;
+                    array.length += 1;
+                    self.check_length(array.length, location)?;
+                }
+                _ => {}
+            }
+
+            match byte {
+                b'"' => in_string = true,
+                b'{' | b'[' => {
+                    open.push(if byte == b'[' {
+                        Some(Array {
+                            length: 0,
+                            expects_item: true,
+                        })
+                    } else {
+                        None
+                    });
+                    self.check_depth(open.len(), location)?;
+                }
+                b'}' | b']' => {
+                    open.pop();
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks the nesting and the child elements of an XML document, which are located by the
+    /// path of the element
+    ///
+    /// Malformed documents are left to the XML parser to report.
+    pub(crate) fn check_xml(&self, input: &[u8]) -> Result<(), LimitError> {
+        if !self.limits_structure() {
+            return Ok(());
+        }
+
+        // the path of the open elements and the number of children of each
+        let mut open: Vec<(String, usize)> = Vec::new();
+        let location = |open: &[(String, usize)]| {
+            open.iter()
+                .map(|(name, _)| format!("/{}", name))
+                .collect::<String>()
+        };
+
+        for event in EventReader::new_with_config(input, ParserConfig::default()) {
+            match event {
+                Ok(reader::XmlEvent::StartElement { name, .. }) => {
+                    if let Some((_, children)) = open.last_mut() {
+                        *children += 1;
+                        let children = *children;
+                        self.check_length(children, || location(&open))?;
+                    }
+                    open.push((name.local_name, 0));
+                    self.check_depth(open.len(), || location(&open))?;
+                }
+                Ok(reader::XmlEvent::EndElement { .. }) => {
+                    open.pop();
+                }
+                Ok(_) => {}
+                Err(_) => break,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn limits(max_depth: Option<usize>, max_array_length: Option<usize>) -> ParseLimits {
+        ParseLimits {
+            max_depth,
+            max_array_length,
+            ..ParseLimits::default()
+        }
+    }
+
+    #[test]
+    fn it_should_check_the_structure_of_json_documents() {
+        let json = br#"{"a": [1, [2, 3], {"b": "[[[, ]"}], "c": []}"#;
+
+        assert_eq!(limits(Some(3), Some(3)).check_json(json), Ok(()));
+        assert_eq!(
+            limits(Some(2), None).check_json(json),
+            Err(LimitError::TooDeep {
+                limit: 2,
+                location: "byte 10".to_string()
+            })
+        );
+        assert_eq!(
+            limits(None, Some(2)).check_json(json),
+            Err(LimitError::ArrayTooLong {
+                limit: 2,
+                location: "byte 18".to_string()
+            })
+        );
+        assert_eq!(limits(None, Some(0)).check_json(b"[ ]"), Ok(()));
+    }
+
+    #[test]
+    fn it_should_check_the_structure_of_xml_documents() {
+        let xml = br#"<?xml version="1.0"?><bom><components><component/><component><name>a</name></component></components></bom>"#;
+
+        assert_eq!(limits(Some(4), Some(2)).check_xml(xml), Ok(()));
+        assert_eq!(
+            limits(Some(3), None).check_xml(xml),
+            Err(LimitError::TooDeep {
+                limit: 3,
+                location: "/bom/components/component/name".to_string()
+            })
+        );
+        assert_eq!(
+            limits(None, Some(1)).check_xml(xml),
+            Err(LimitError::ArrayTooLong {
+                limit: 1,
+                location: "/bom/components".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn it_should_check_the_size() {
+        let limits = ParseLimits {
+            max_input_size: Some(4),
+            ..ParseLimits::default()
+        };
+
+        assert_eq!(limits.check_size(4), Ok(()));
+        assert_eq!(
+            limits.check_size(5),
+            Err(LimitError::InputTooLarge { limit: 4 })
+        );
+    }
+}
@@ -0,0 +1,242 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use crate::external_models::date_time::DateTime;
+use crate::models::component::Component;
+use crate::models::organization::{OrganizationalContact, OrganizationalEntity};
+use crate::models::service::Service;
+use crate::models::signature::Signature;
+use crate::validation::{
+    FailureReason, Validate, ValidationContext, ValidationError, ValidationPathComponent,
+    ValidationResult,
+};
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Annotations(pub Vec<Annotation>);
+
+impl Validate for Annotations {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        for (index, annotation) in self.0.iter().enumerate() {
+            let context = context.extend_context(vec![ValidationPathComponent::Array { index }]);
+            results.push(annotation.validate_with_context(context)?);
+        }
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+/// A comment, note, or explanation about the parts of the BOM referenced by their `bom-ref`,
+/// added in version 1.5 of the specification
+///
+/// Defined via the [XML schema](https://cyclonedx.org/docs/1.5/xml/#type_annotationsType)
+#[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Annotation {
+    pub bom_ref: Option<String>,
+    pub subjects: Vec<String>,
+    pub annotator: Annotator,
+    pub timestamp: DateTime,
+    pub text: String,
+    pub signature: Option<Signature>,
+}
+
+with_setters!(Annotation {
+    with_bom_ref => bom_ref: String,
+    with_signature => signature: Signature,
+});
+
+impl Annotation {
+    /// Construct an `Annotation` of the subjects by the annotator
+    /// ```
+    /// use cyclonedx_bom::external_models::date_time::DateTime;
+    /// use cyclonedx_bom::models::annotation::{Annotation, Annotator};
+    /// use cyclonedx_bom::models::organization::OrganizationalContact;
+    ///
+    /// let annotation = Annotation::new(
+    ///     vec!["component-a".to_string()],
+    ///     Annotator::Individual(OrganizationalContact::new("Jane Doe", None)),
+    ///     DateTime::now().expect("Failed to get the current time"),
+    ///     "Reviewed for the 1.0 release",
+    /// );
+    /// ```
+    pub fn new(
+        subjects: Vec<String>,
+        annotator: Annotator,
+        timestamp: DateTime,
+        text: &str,
+    ) -> Self {
+        Self {
+            bom_ref: None,
+            subjects,
+            annotator,
+            timestamp,
+            text: text.to_string(),
+            signature: None,
+        }
+    }
+}
+
+impl Validate for Annotation {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        if self.subjects.is_empty() {
+            let context = context.extend_context_with_struct_field("Annotation", "subjects");
+
+            results.push(ValidationResult::Failed {
+                reasons: vec![FailureReason::error("Annotation has no subjects", context)],
+            });
+        }
+
+        let annotator_context = context.extend_context_with_struct_field("Annotation", "annotator");
+        results.push(self.annotator.validate_with_context(annotator_context)?);
+
+        let timestamp_context = context.extend_context_with_struct_field("Annotation", "timestamp");
+        results.push(self.timestamp.validate_with_context(timestamp_context)?);
+
+        if let Some(signature) = &self.signature {
+            let context = context.extend_context_with_struct_field("Annotation", "signature");
+
+            results.push(signature.validate_with_context(context)?);
+        }
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+/// The organization, person, component, or service that created an annotation
+#[derive(Debug, PartialEq, Eq)]
+pub enum Annotator {
+    Organization(OrganizationalEntity),
+    Individual(OrganizationalContact),
+    Component(Box<Component>),
+    Service(Box<Service>),
+}
+
+impl Validate for Annotator {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        match self {
+            Annotator::Organization(organization) => {
+                let context = context.extend_context_with_struct_field("Annotator", "organization");
+
+                organization.validate_with_context(context)
+            }
+            Annotator::Individual(individual) => {
+                let context = context.extend_context_with_struct_field("Annotator", "individual");
+
+                individual.validate_with_context(context)
+            }
+            Annotator::Component(component) => {
+                let context = context.extend_context_with_struct_field("Annotator", "component");
+
+                component.validate_with_context(context)
+            }
+            Annotator::Service(service) => {
+                let context = context.extend_context_with_struct_field("Annotator", "service");
+
+                service.validate_with_context(context)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::external_models::normalized_string::NormalizedString;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn valid_annotations_should_pass_validation() {
+        let validation_result = Annotations(vec![Annotation::new(
+            vec!["component-a".to_string()],
+            Annotator::Organization(OrganizationalEntity::new("Acme, Inc.")),
+            "2023-06-28T01:20:00.00-04:00".parse().unwrap(),
+            "Reviewed for the 1.0 release",
+        )])
+        .validate_with_context(ValidationContext::default())
+        .expect("Error while validating");
+
+        assert_eq!(validation_result, ValidationResult::Passed);
+    }
+
+    #[test]
+    fn invalid_annotations_should_fail_validation() {
+        let mut individual = OrganizationalContact::new("Jane Doe", None);
+        individual.name = Some(NormalizedString("spaces and \ttabs".to_string()));
+        let validation_result = Annotations(vec![Annotation::new(
+            vec![],
+            Annotator::Individual(individual),
+            "2023-06-28T01:20:00.00-04:00".parse().unwrap(),
+            "Reviewed for the 1.0 release",
+        )])
+        .validate_with_context(ValidationContext::default())
+        .expect("Error while validating");
+
+        assert_eq!(
+            validation_result,
+            ValidationResult::Failed {
+                reasons: vec![
+                    FailureReason::error(
+                        "Annotation has no subjects",
+                        ValidationContext(vec![
+                            ValidationPathComponent::Array { index: 0 },
+                            ValidationPathComponent::Struct {
+                                struct_name: "Annotation".to_string(),
+                                field_name: "subjects".to_string(),
+                            },
+                        ])
+                    ),
+                    FailureReason::error(
+                        "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                        ValidationContext(vec![
+                            ValidationPathComponent::Array { index: 0 },
+                            ValidationPathComponent::Struct {
+                                struct_name: "Annotation".to_string(),
+                                field_name: "annotator".to_string(),
+                            },
+                            ValidationPathComponent::Struct {
+                                struct_name: "Annotator".to_string(),
+                                field_name: "individual".to_string(),
+                            },
+                            ValidationPathComponent::Struct {
+                                struct_name: "OrganizationalContact".to_string(),
+                                field_name: "name".to_string(),
+                            },
+                        ])
+                    ),
+                ]
+            }
+        );
+    }
+}
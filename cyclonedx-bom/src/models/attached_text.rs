@@ -42,6 +42,34 @@ impl AttachedText {
             content: STANDARD.encode(content),
         }
     }
+
+    /// Construct a new `AttachedText` holding `content` as written if it is text, or base64
+    /// encoded if it is not valid UTF-8 or holds control characters other than whitespace
+    /// ```
+    /// use cyclonedx_bom::models::attached_text::AttachedText;
+    ///
+    /// let text = AttachedText::text_or_base64(None, "Permission is hereby granted");
+    /// let binary = AttachedText::text_or_base64(None, [0xff, 0xfe]);
+    /// ```
+    pub fn text_or_base64<T: AsRef<[u8]>>(
+        content_type: Option<NormalizedString>,
+        content: T,
+    ) -> Self {
+        match std::str::from_utf8(content.as_ref()) {
+            Ok(text)
+                if !text
+                    .chars()
+                    .any(|c| c.is_control() && !matches!(c, '\t' | '\n' | '\r')) =>
+            {
+                Self {
+                    content_type,
+                    encoding: None,
+                    content: text.to_string(),
+                }
+            }
+            _ => Self::new(content_type, content),
+        }
+    }
 }
 
 impl Validate for AttachedText {
@@ -65,10 +93,10 @@ impl Validate for AttachedText {
                         context.extend_context_with_struct_field("AttachedText", "content");
 
                     results.push(ValidationResult::Failed {
-                        reasons: vec![FailureReason {
-                            message: "Content is not Base64 encoded".to_string(),
+                        reasons: vec![FailureReason::error(
+                            "Content is not Base64 encoded",
                             context,
-                        }],
+                        )],
                     })
                 }
                 (Encoding::UnknownEncoding(_), _) => {
@@ -93,11 +121,11 @@ pub(crate) enum Encoding {
     UnknownEncoding(String),
 }
 
-impl ToString for Encoding {
-    fn to_string(&self) -> String {
+impl std::fmt::Display for Encoding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Encoding::Base64 => "base64".to_string(),
-            Encoding::UnknownEncoding(ue) => ue.clone(),
+            Encoding::Base64 => f.write_str("base64"),
+            Encoding::UnknownEncoding(ue) => f.write_str(ue),
         }
     }
 }
@@ -118,10 +146,7 @@ impl Validate for Encoding {
     ) -> Result<ValidationResult, ValidationError> {
         match self {
             Encoding::UnknownEncoding(_) => Ok(ValidationResult::Failed {
-                reasons: vec![FailureReason {
-                    message: "Unknown encoding".to_string(),
-                    context,
-                }],
+                reasons: vec![FailureReason::error("Unknown encoding", context)],
             }),
             _ => Ok(ValidationResult::Passed),
         }
@@ -151,6 +176,26 @@ mod test {
         )
     }
 
+    #[test]
+    fn it_should_only_encode_attached_binary_content() {
+        assert_eq!(
+            AttachedText::text_or_base64(None, "MIT License\r\n\tCopyright"),
+            AttachedText {
+                content_type: None,
+                encoding: None,
+                content: "MIT License\r\n\tCopyright".to_string(),
+            }
+        );
+        assert_eq!(
+            AttachedText::text_or_base64(None, "bell\u{7}"),
+            AttachedText::new(None, "bell\u{7}")
+        );
+        assert_eq!(
+            AttachedText::text_or_base64(None, [0xff, 0xfe]),
+            AttachedText::new(None, [0xff, 0xfe])
+        );
+    }
+
     #[test]
     fn valid_attached_text_should_pass_validation() {
         let validation_result = AttachedText {
@@ -178,22 +223,20 @@ mod test {
             validation_result,
             ValidationResult::Failed {
                 reasons: vec![
-                    FailureReason {
-                        message:
-                            "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
-                                .to_string(),
-                        context: ValidationContext(vec![ValidationPathComponent::Struct {
+                    FailureReason::error(
+                        "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                        ValidationContext(vec![ValidationPathComponent::Struct {
                             struct_name: "AttachedText".to_string(),
                             field_name: "content_type".to_string()
                         }])
-                    },
-                    FailureReason {
-                        message: "Content is not Base64 encoded".to_string(),
-                        context: ValidationContext(vec![ValidationPathComponent::Struct {
+                    ),
+                    FailureReason::error(
+                        "Content is not Base64 encoded",
+                        ValidationContext(vec![ValidationPathComponent::Struct {
                             struct_name: "AttachedText".to_string(),
                             field_name: "content".to_string()
                         }])
-                    }
+                    )
                 ]
             }
         );
@@ -212,13 +255,13 @@ mod test {
         assert_eq!(
             validation_result,
             ValidationResult::Failed {
-                reasons: vec![FailureReason {
-                    message: "Unknown encoding".to_string(),
-                    context: ValidationContext(vec![ValidationPathComponent::Struct {
+                reasons: vec![FailureReason::error(
+                    "Unknown encoding",
+                    ValidationContext(vec![ValidationPathComponent::Struct {
                         struct_name: "AttachedText".to_string(),
                         field_name: "encoding".to_string()
                     }])
-                }]
+                )]
             }
         );
     }
@@ -16,27 +16,42 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
+use std::io::Read;
 
 use once_cell::sync::Lazy;
 use regex::Regex;
+use thiserror::Error;
 use xml::{EmitterConfig, EventReader, EventWriter, ParserConfig};
 
+use crate::format::{self, DetectedFormat, DocumentFormat};
+use crate::limits::ParseLimits;
+use crate::models::annotation::Annotations;
 use crate::models::component::{Component, Components};
 use crate::models::composition::{BomReference, Compositions};
-use crate::models::dependency::Dependencies;
-use crate::models::external_reference::ExternalReferences;
+use crate::models::dependency::{Dependencies, Dependency};
+use crate::models::external_reference::{ExternalReference, ExternalReferences};
+use crate::models::formulation::Formulation;
 use crate::models::metadata::Metadata;
+use crate::models::organization::OrganizationalEntity;
 use crate::models::property::Properties;
 use crate::models::service::{Service, Services};
+use crate::models::signature::Signature;
+use crate::models::tool::{Tool, Tools};
+use crate::models::vulnerability::Vulnerabilities;
 use crate::validation::{
     FailureReason, Validate, ValidationContext, ValidationError, ValidationPathComponent,
     ValidationResult,
 };
-use crate::xml::{FromXmlDocument, ToXml};
+use crate::warnings::{
+    downgrade_warnings, format_downgrade_warnings, json_warnings, newer_version_warnings,
+    unknown_values, xml_fragment, xml_warnings, DowngradeWarning, ParseWarning,
+};
+use crate::xml::{to_xml_read_error, FromXmlDocument, ToXml};
 
 #[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub struct Bom {
     pub version: u32,
     pub serial_number: Option<UrnUuid>,
@@ -47,9 +62,810 @@ pub struct Bom {
     pub dependencies: Option<Dependencies>,
     pub compositions: Option<Compositions>,
     pub properties: Option<Properties>,
+    pub vulnerabilities: Option<Vulnerabilities>,
+    pub signature: Option<Signature>,
+    pub annotations: Option<Annotations>,
+    pub formulation: Option<Formulation>,
 }
 
+with_setters!(Bom {
+    with_serial_number => serial_number: UrnUuid,
+    with_metadata => metadata: Metadata,
+    with_components => components: Components,
+    with_services => services: Services,
+    with_external_references => external_references: ExternalReferences,
+    with_dependencies => dependencies: Dependencies,
+    with_compositions => compositions: Compositions,
+    with_properties => properties: Properties,
+    with_vulnerabilities => vulnerabilities: Vulnerabilities,
+    with_signature => signature: Signature,
+    with_annotations => annotations: Annotations,
+    with_formulation => formulation: Formulation,
+});
+
 impl Bom {
+    /// Adds `component` to the top-level components, keeping them sorted by name and version
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `bom_ref` of the component or one of its subcomponents is already
+    /// used by a component or service of the BOM
+    /// ```
+    /// use cyclonedx_bom::models::bom::Bom;
+    /// use cyclonedx_bom::models::component::{Classification, Component};
+    ///
+    /// let mut bom = Bom::default();
+    /// let component = Component::new(Classification::Library, "tacos", "1.0.0", Some("tacos".to_string()));
+    /// bom.add_component(component).expect("Failed to add component");
+    ///
+    /// let duplicate = Component::new(Classification::Library, "tacos", "1.0.1", Some("tacos".to_string()));
+    /// assert!(bom.add_component(duplicate).is_err());
+    /// ```
+    pub fn add_component(&mut self, component: Component) -> Result<(), BomEditError> {
+        let existing = self.bom_refs();
+        let mut added = Vec::new();
+        collect_component_bom_refs(&component, &mut added);
+        let mut unique = HashSet::new();
+        for bom_ref in added {
+            if existing.contains(bom_ref) || !unique.insert(bom_ref) {
+                return Err(BomEditError::DuplicateBomRef(bom_ref.to_string()));
+            }
+        }
+
+        let components = &mut self
+            .components
+            .get_or_insert_with(|| Components(Vec::new()))
+            .0;
+        let key = (component.name.to_string(), component.version.to_string());
+        let index = components.partition_point(|existing| {
+            (existing.name.to_string(), existing.version.to_string()) < key
+        });
+        components.insert(index, component);
+
+        Ok(())
+    }
+
+    /// Adds `tool` to the tools in the metadata, after the tools that are already listed, e.g. for
+    /// a wrapper that post-processes a generated BOM to record itself
+    ///
+    /// A listed tool with the same vendor and name is replaced, so that processing a BOM again
+    /// does not list a tool twice. Tools in the object form get the tool as an application
+    /// component.
+    /// ```
+    /// use cyclonedx_bom::models::bom::Bom;
+    /// use cyclonedx_bom::models::tool::{Tool, Tools};
+    ///
+    /// let mut bom = Bom::default();
+    /// bom.add_tool(Tool::new("CycloneDX", "cargo-cyclonedx", "0.3.8"));
+    /// bom.add_tool(Tool::new("Tacos Inc", "sbom-signer", "1.0.0"));
+    /// bom.add_tool(Tool::new("Tacos Inc", "sbom-signer", "1.1.0"));
+    ///
+    /// let tools = bom.metadata.and_then(|m| m.tools).map(Tools::into_list).unwrap_or_default();
+    /// assert_eq!(tools.len(), 2);
+    /// assert_eq!(tools[1].version.as_ref().map(ToString::to_string), Some("1.1.0".to_string()));
+    /// ```
+    pub fn add_tool(&mut self, tool: Tool) {
+        let tools = self
+            .metadata
+            .get_or_insert_with(Metadata::default)
+            .tools
+            .get_or_insert_with(|| Tools::List(Vec::new()));
+
+        match tools {
+            Tools::List(tools) => {
+                let same_tool =
+                    |existing: &Tool| existing.vendor == tool.vendor && existing.name == tool.name;
+                match tools.iter_mut().find(|existing| same_tool(existing)) {
+                    Some(existing) => *existing = tool,
+                    None => tools.push(tool),
+                }
+            }
+            Tools::Object { components, .. } => {
+                let component = Component::from(tool);
+                let vendor = |component: &Component| {
+                    component
+                        .supplier
+                        .as_ref()
+                        .and_then(|supplier| supplier.name.as_ref())
+                        .map(ToString::to_string)
+                };
+                let components = &mut components.get_or_insert_with(|| Components(Vec::new())).0;
+                match components.iter_mut().find(|existing| {
+                    vendor(existing) == vendor(&component) && existing.name == component.name
+                }) {
+                    Some(existing) => *existing = component,
+                    None => components.push(component),
+                }
+            }
+        }
+    }
+
+    /// Adds the metadata component of `other` as a top-level component, with the components of
+    /// `other` nested in it, and merges the dependencies of `other` into those of the BOM
+    ///
+    /// This merges a BOM generated for another part of the product, e.g. a bundled frontend, as
+    /// an assembly. The services of `other` are not merged.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `other` has no metadata component or if one of its `bom_ref`s is
+    /// already used by a component or service of the BOM
+    /// ```
+    /// use cyclonedx_bom::models::bom::Bom;
+    /// use cyclonedx_bom::models::component::{Classification, Component, Components};
+    /// use cyclonedx_bom::models::metadata::Metadata;
+    ///
+    /// let mut frontend = Bom::default();
+    /// let mut metadata = Metadata::default();
+    /// metadata.component = Some(Component::new(Classification::Application, "frontend", "1.0.0", None));
+    /// frontend.metadata = Some(metadata);
+    /// frontend.components = Some(Components(vec![
+    ///     Component::new(Classification::Library, "react", "18.2.0", None),
+    /// ]));
+    ///
+    /// let mut bom = Bom::default();
+    /// bom.add_assembly(frontend).expect("Failed to add the assembly");
+    /// ```
+    pub fn add_assembly(&mut self, other: Bom) -> Result<(), BomEditError> {
+        let mut assembly = other
+            .metadata
+            .and_then(|metadata| metadata.component)
+            .ok_or(BomEditError::MissingMetadataComponent)?;
+
+        if let Some(components) = other.components {
+            assembly
+                .components
+                .get_or_insert_with(|| Components(Vec::new()))
+                .0
+                .extend(components.0);
+        }
+        self.add_component(assembly)?;
+
+        if let Some(other_dependencies) = other.dependencies {
+            self.merge_dependencies(other_dependencies.0);
+        }
+
+        Ok(())
+    }
+
+    /// Merges the BOMs `others`, e.g. the SBOMs of the packages of a workspace, into this BOM
+    ///
+    /// Components are deduplicated by `bom_ref` and Package URL: a component that is already
+    /// listed is not added again, the components nested in it are nested in the listed one, and
+    /// the dependencies on it are redirected to the listed one. The metadata component of each of
+    /// `others` is nested in the metadata component of the BOM, or added as a top-level component
+    /// if the BOM has none, and the dependencies of `others` are merged into those of the BOM. The
+    /// services, compositions, vulnerabilities and annotations of `others` are not merged.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if one of `others` has no metadata component, leaving the BOM unchanged,
+    /// or if a component of `others` uses the `bom_ref` of a service of the BOM
+    /// ```
+    /// use cyclonedx_bom::models::bom::Bom;
+    /// use cyclonedx_bom::models::component::{Classification, Component, Components};
+    /// use cyclonedx_bom::models::metadata::Metadata;
+    ///
+    /// let package_bom = |name: &str| {
+    ///     let mut bom = Bom::default();
+    ///     let mut metadata = Metadata::default();
+    ///     metadata.component = Some(Component::new(Classification::Application, name, "1.0.0", Some(name.to_string())));
+    ///     bom.metadata = Some(metadata);
+    ///     bom.components = Some(Components(vec![
+    ///         Component::new(Classification::Library, "salsa", "0.2.0", Some("salsa".to_string())),
+    ///     ]));
+    ///     bom
+    /// };
+    ///
+    /// let mut bom = Bom::default();
+    /// bom.merge(vec![package_bom("tacos"), package_bom("burritos")]).expect("Failed to merge");
+    ///
+    /// let names: Vec<_> = bom.components.unwrap().0.iter().map(|c| c.name.to_string()).collect();
+    /// assert_eq!(names, vec!["burritos", "salsa", "tacos"]);
+    /// ```
+    pub fn merge(&mut self, others: Vec<Bom>) -> Result<(), BomEditError> {
+        if others.iter().any(|other| {
+            other
+                .metadata
+                .as_ref()
+                .and_then(|m| m.component.as_ref())
+                .is_none()
+        }) {
+            return Err(BomEditError::MissingMetadataComponent);
+        }
+
+        let mut known = KnownComponents::of(self);
+        for other in others {
+            let mut renamed = HashMap::new();
+
+            if let Some(root) = other.metadata.and_then(|metadata| metadata.component) {
+                if let Some(root) = self.merge_component(root, &mut known, &mut renamed)? {
+                    match self.metadata.as_mut().and_then(|m| m.component.as_mut()) {
+                        Some(own_root) => own_root
+                            .components
+                            .get_or_insert_with(|| Components(Vec::new()))
+                            .0
+                            .push(root),
+                        None => self.add_component(root)?,
+                    }
+                }
+            }
+            for component in other
+                .components
+                .into_iter()
+                .flat_map(|components| components.0)
+            {
+                if let Some(component) =
+                    self.merge_component(component, &mut known, &mut renamed)?
+                {
+                    self.add_component(component)?;
+                }
+            }
+
+            if let Some(other_dependencies) = other.dependencies {
+                let rename = |bom_ref: String| renamed.get(&bom_ref).cloned().unwrap_or(bom_ref);
+                self.merge_dependencies(other_dependencies.0.into_iter().map(|dependency| {
+                    Dependency::new(
+                        rename(dependency.dependency_ref),
+                        dependency.dependencies.into_iter().map(rename).collect(),
+                    )
+                }));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns `component` with its nested components merged, or `None` if it is already listed,
+    /// in which case its nested components are merged into the listed one
+    fn merge_component(
+        &mut self,
+        mut component: Component,
+        known: &mut KnownComponents,
+        renamed: &mut HashMap<String, String>,
+    ) -> Result<Option<Component>, BomEditError> {
+        let nested = component.components.take().map(|components| components.0);
+
+        match known.find(&component) {
+            Some(listed) => {
+                if let (Some(bom_ref), Some(listed_ref)) = (&component.bom_ref, &listed.bom_ref) {
+                    if bom_ref != listed_ref {
+                        renamed.insert(bom_ref.clone(), listed_ref.clone());
+                    }
+                }
+                for nested in nested.into_iter().flatten() {
+                    if let Some(nested) = self.merge_component(nested, known, renamed)? {
+                        match self.find_component_mut(&listed) {
+                            Some(listed) => listed
+                                .components
+                                .get_or_insert_with(|| Components(Vec::new()))
+                                .0
+                                .push(nested),
+                            None => self.add_component(nested)?,
+                        }
+                    }
+                }
+                Ok(None)
+            }
+            None => {
+                known.insert(&component)?;
+                let mut merged = Vec::new();
+                for nested in nested.into_iter().flatten() {
+                    merged.extend(self.merge_component(nested, known, renamed)?);
+                }
+                if !merged.is_empty() {
+                    component.components = Some(Components(merged));
+                }
+                Ok(Some(component))
+            }
+        }
+    }
+
+    /// The listed component, including nested components, with the identity `key`
+    fn find_component_mut(&mut self, key: &ComponentKey) -> Option<&mut Component> {
+        fn find<'a>(
+            components: impl Iterator<Item = &'a mut Component>,
+            key: &ComponentKey,
+        ) -> Option<&'a mut Component> {
+            for component in components {
+                if key.matches(component) {
+                    return Some(component);
+                }
+                if let Some(nested) = &mut component.components {
+                    if let Some(found) = find(nested.0.iter_mut(), key) {
+                        return Some(found);
+                    }
+                }
+            }
+            None
+        }
+
+        let root = self
+            .metadata
+            .as_mut()
+            .and_then(|metadata| metadata.component.as_mut());
+        let components = self
+            .components
+            .iter_mut()
+            .flat_map(|components| components.0.iter_mut());
+        find(root.into_iter().chain(components), key)
+    }
+
+    /// Records that the component or service `dependency_ref` of `dependency` depends on the
+    /// components or services of its `dependencies`, joining them with the dependencies already
+    /// recorded for it
+    /// ```
+    /// use cyclonedx_bom::models::bom::Bom;
+    /// use cyclonedx_bom::models::dependency::Dependency;
+    ///
+    /// let mut bom = Bom::default();
+    /// bom.add_dependency(Dependency::new("tacos", vec!["salsa".to_string()]));
+    /// bom.add_dependency(Dependency::new("tacos", vec!["tortilla".to_string()]));
+    ///
+    /// let dependencies: Vec<_> = bom.dependencies.iter().flat_map(|d| d.iter()).collect();
+    /// assert_eq!(dependencies.len(), 1);
+    /// assert_eq!(dependencies[0].dependencies, vec!["salsa", "tortilla"]);
+    /// ```
+    pub fn add_dependency(&mut self, dependency: Dependency) {
+        self.merge_dependencies(Some(dependency));
+    }
+
+    /// Merges `other_dependencies` into the dependencies of the BOM, joining the dependencies of
+    /// the same `dependency_ref`
+    fn merge_dependencies(&mut self, other_dependencies: impl IntoIterator<Item = Dependency>) {
+        let dependencies = &mut self
+            .dependencies
+            .get_or_insert_with(|| Dependencies(Vec::new()))
+            .0;
+        for dependency in other_dependencies {
+            match dependencies
+                .iter_mut()
+                .find(|existing| existing.dependency_ref == dependency.dependency_ref)
+            {
+                Some(existing) => {
+                    for bom_ref in dependency.dependencies {
+                        if !existing.dependencies.contains(&bom_ref) {
+                            existing.dependencies.push(bom_ref);
+                        }
+                    }
+                }
+                None => dependencies.push(dependency),
+            }
+        }
+    }
+
+    /// Keeps only the top-level components for which `keep` returns `true`
+    ///
+    /// Dependencies on the `bom_ref`s of the removed components, including their nested
+    /// components, are removed as well, as are the removed components from the subjects of
+    /// annotations, dropping the annotations left without subjects, so that the BOM stays valid.
+    /// ```
+    /// use cyclonedx_bom::models::bom::Bom;
+    /// use cyclonedx_bom::models::component::{Classification, Component};
+    ///
+    /// let mut bom = Bom::default();
+    /// for name in ["tacos", "burritos"] {
+    ///     let component = Component::new(Classification::Library, name, "1.0.0", None);
+    ///     bom.add_component(component).expect("Failed to add component");
+    /// }
+    ///
+    /// bom.retain_components(|component| component.name.to_string() == "tacos");
+    /// assert_eq!(bom.components.map(|components| components.0.len()), Some(1));
+    /// ```
+    pub fn retain_components(&mut self, mut keep: impl FnMut(&Component) -> bool) {
+        let components = match &mut self.components {
+            Some(components) => components,
+            None => return,
+        };
+
+        let mut removed = Vec::new();
+        components.0.retain(|component| {
+            let retained = keep(component);
+            if !retained {
+                let mut bom_refs = Vec::new();
+                collect_component_bom_refs(component, &mut bom_refs);
+                removed.extend(bom_refs.into_iter().map(String::from));
+            }
+            retained
+        });
+
+        if removed.is_empty() {
+            return;
+        }
+        let removed: HashSet<String> = removed.into_iter().collect();
+        if let Some(dependencies) = &mut self.dependencies {
+            dependencies
+                .0
+                .retain(|dependency| !removed.contains(&dependency.dependency_ref));
+            for dependency in &mut dependencies.0 {
+                dependency
+                    .dependencies
+                    .retain(|bom_ref| !removed.contains(bom_ref));
+            }
+        }
+        if let Some(annotations) = &mut self.annotations {
+            for annotation in &mut annotations.0 {
+                annotation
+                    .subjects
+                    .retain(|bom_ref| !removed.contains(bom_ref));
+            }
+            annotations
+                .0
+                .retain(|annotation| !annotation.subjects.is_empty());
+        }
+    }
+
+    /// Sets the supplier of the BOM, creating the metadata if necessary
+    pub fn set_supplier(&mut self, supplier: OrganizationalEntity) {
+        self.metadata.get_or_insert_with(Metadata::default).supplier = Some(supplier);
+    }
+
+    /// Sets the value of the BOM-level property called `name`, see [`Properties::upsert`]
+    pub fn upsert_property(&mut self, name: &str, value: &str) {
+        self.properties
+            .get_or_insert_with(|| Properties(Vec::new()))
+            .upsert(name, value);
+    }
+
+    /// The `bom_ref`s of all components and services, including the nested ones
+    fn bom_refs(&self) -> HashSet<&str> {
+        let mut bom_refs = Vec::new();
+
+        if let Some(component) = self.metadata.as_ref().and_then(|m| m.component.as_ref()) {
+            collect_component_bom_refs(component, &mut bom_refs);
+        }
+        if let Some(components) = &self.components {
+            for component in &components.0 {
+                collect_component_bom_refs(component, &mut bom_refs);
+            }
+        }
+        if let Some(services) = &self.services {
+            for service in &services.0 {
+                collect_service_bom_refs(service, &mut bom_refs);
+            }
+        }
+
+        bom_refs.into_iter().collect()
+    }
+
+    /// Starts building a BOM of `version` 1 with a random serial number
+    ///
+    /// The components are added with [`Bom::add_component`] and the dependencies with
+    /// [`Bom::add_dependency`], so building fails if two components share a `bom-ref`.
+    /// ```
+    /// use cyclonedx_bom::models::bom::Bom;
+    /// use cyclonedx_bom::models::component::{Classification, Component};
+    /// use cyclonedx_bom::models::dependency::Dependency;
+    /// use cyclonedx_bom::models::metadata::Metadata;
+    ///
+    /// let bom = Bom::builder()
+    ///     .metadata(
+    ///         Metadata::builder()
+    ///             .component(
+    ///                 Component::builder(Classification::Application, "tacos", "1.0.0")
+    ///                     .bom_ref("tacos")
+    ///                     .build(),
+    ///             )
+    ///             .build()?,
+    ///     )
+    ///     .component(
+    ///         Component::builder(Classification::Library, "salsa", "0.2.0")
+    ///             .bom_ref("salsa")
+    ///             .build(),
+    ///     )
+    ///     .dependency(Dependency::new("tacos", vec!["salsa".to_string()]))
+    ///     .build()?;
+    /// assert_eq!(bom.version, 1);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn builder() -> BomBuilder {
+        BomBuilder {
+            bom: Bom::default(),
+            components: Vec::new(),
+        }
+    }
+
+    /// Sets the `version` of the BOM, which is incremented every time the BOM is modified
+    /// ```
+    /// use cyclonedx_bom::models::bom::Bom;
+    ///
+    /// let bom = Bom::default().with_version(2);
+    /// ```
+    pub fn with_version(mut self, version: u32) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// Parse a document in any supported format, detecting from its content whether it is JSON
+    /// or XML and whether it is compressed with gzip
+    ///
+    /// The version of the specification is detected like in [`Bom::parse_from_json`] and
+    /// [`Bom::parse_from_xml`]. Protobuf documents are recognized, but not supported.
+    /// ```
+    /// use cyclonedx_bom::format::DocumentFormat;
+    /// use cyclonedx_bom::models::bom::Bom;
+    ///
+    /// let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+    /// <bom xmlns="http://cyclonedx.org/schema/bom/1.3" version="1"/>"#;
+    /// let (bom, detected) = Bom::parse(xml.as_bytes())?;
+    /// assert_eq!(detected.format, DocumentFormat::Xml);
+    /// assert!(!detected.gzip);
+    /// # Ok::<(), cyclonedx_bom::errors::BomReadError>(())
+    /// ```
+    pub fn parse(input: &[u8]) -> Result<(Self, DetectedFormat), crate::errors::BomReadError> {
+        Self::parse_with_limits(input, &ParseLimits::default())
+    }
+
+    /// Parse a document in any supported format like [`Bom::parse`], rejecting documents that
+    /// exceed the `limits`, see [`crate::limits`]
+    pub fn parse_with_limits(
+        input: &[u8],
+        limits: &ParseLimits,
+    ) -> Result<(Self, DetectedFormat), crate::errors::BomReadError> {
+        use crate::errors::BomReadError;
+
+        limits.check_size(input.len())?;
+
+        let mut decompressed = Vec::new();
+        let gzip = format::is_gzip(input);
+        let input = if gzip {
+            // one byte more than the limit tells whether the decompressed document exceeds it
+            let max_read = limits
+                .max_input_size
+                .map_or(u64::MAX, |limit| limit as u64 + 1);
+            flate2::read::MultiGzDecoder::new(input)
+                .take(max_read)
+                .read_to_end(&mut decompressed)
+                .map_err(|error| BomReadError::GzipError { error })?;
+            limits.check_size(decompressed.len())?;
+            decompressed.as_slice()
+        } else {
+            input
+        };
+
+        let format = format::sniff(input).ok_or(BomReadError::UnknownFormat)?;
+        let (bom, spec_version) = match format {
+            DocumentFormat::Json => {
+                limits.check_json(input)?;
+                Self::parse_json_value(
+                    serde_json::from_slice(input).map_err(crate::errors::JsonReadError::from)?,
+                )?
+            }
+            DocumentFormat::Xml => {
+                limits.check_xml(&crate::encoding::decode_xml(input)?)?;
+                Self::parse_xml_input(read_xml_input(input)?)?
+            }
+            DocumentFormat::Protobuf => return Err(BomReadError::UnsupportedProtobuf),
+        };
+
+        Ok((
+            bom,
+            DetectedFormat {
+                format,
+                gzip,
+                spec_version,
+            },
+        ))
+    }
+
+    /// Parse the input as a JSON document conforming to the version of the specification given
+    /// by its `specVersion`
+    /// ```
+    /// use cyclonedx_bom::models::bom::Bom;
+    ///
+    /// let json = r#"{ "bomFormat": "CycloneDX", "specVersion": "1.3", "version": 1 }"#;
+    /// let bom = Bom::parse_from_json(json.as_bytes())?;
+    /// # Ok::<(), cyclonedx_bom::errors::JsonReadError>(())
+    /// ```
+    pub fn parse_from_json<R: std::io::Read>(
+        mut reader: R,
+    ) -> Result<Self, crate::errors::JsonReadError> {
+        let input: serde_json::Value = serde_json::from_reader(&mut reader)?;
+        Ok(Self::parse_json_value(input)?.0)
+    }
+
+    /// Parse a JSON document in the version of the specification given by its `specVersion`,
+    /// returning the version alongside the BOM
+    fn parse_json_value(
+        input: serde_json::Value,
+    ) -> Result<(Self, SpecVersion), crate::errors::JsonReadError> {
+        let spec_version = input
+            .get("specVersion")
+            .and_then(serde_json::Value::as_str)
+            .ok_or(crate::errors::JsonReadError::MissingSpecVersion)?
+            .parse::<SpecVersion>()?;
+
+        let bom = match spec_version {
+            SpecVersion::V1_3 => {
+                serde_json::from_value::<crate::specs::v1_3::bom::Bom>(input)?.into()
+            }
+            SpecVersion::V1_4 => {
+                serde_json::from_value::<crate::specs::v1_4::bom::Bom>(input)?.into()
+            }
+            SpecVersion::V1_5 => {
+                serde_json::from_value::<crate::specs::v1_5::bom::Bom>(input)?.into()
+            }
+        };
+
+        Ok((bom, spec_version))
+    }
+
+    /// Parse the input as an XML document conforming to the version of the specification given
+    /// by the namespace of its `bom` element
+    pub fn parse_from_xml<R: std::io::Read>(
+        reader: R,
+    ) -> Result<Self, crate::errors::XmlReadError> {
+        Ok(Self::parse_xml_input(read_xml_input(reader)?)?.0)
+    }
+
+    /// Parse an XML document in the version of the specification given by the namespace of its
+    /// `bom` element, returning the version alongside the BOM
+    fn parse_xml_input(input: Vec<u8>) -> Result<(Self, SpecVersion), crate::errors::XmlReadError> {
+        let spec_version = xml_spec_version(&input)?;
+
+        let bom = match spec_version {
+            SpecVersion::V1_3 => Self::parse_from_xml_v1_3(input.as_slice())?,
+            SpecVersion::V1_4 => Self::parse_from_xml_v1_4(input.as_slice())?,
+            SpecVersion::V1_5 => Self::parse_from_xml_v1_5(input.as_slice())?,
+        };
+
+        Ok((bom, spec_version))
+    }
+
+    /// Parse the input as a JSON document in the version of the specification given by its
+    /// `specVersion`, also returning the non-fatal issues found in it, like
+    /// [`Bom::parse_from_json_v1_3_with_warnings`]
+    ///
+    /// A document in a newer version of the specification than is supported is read as the
+    /// newest supported version instead of being rejected. The warnings then start with a
+    /// [`ParseWarning::UnsupportedVersion`], and the content the parsed BOM does not hold is kept
+    /// as written in [`ParseWarning::Extension`]s.
+    /// ```
+    /// use cyclonedx_bom::models::bom::{Bom, SpecVersion};
+    /// use cyclonedx_bom::warnings::ParseWarning;
+    ///
+    /// let json = r#"{
+    ///   "bomFormat": "CycloneDX",
+    ///   "specVersion": "1.6",
+    ///   "version": 1,
+    ///   "definitions": { "standards": [] }
+    /// }"#;
+    /// let (bom, warnings) = Bom::parse_from_json_with_warnings(json.as_bytes())?;
+    ///
+    /// assert_eq!(
+    ///     warnings,
+    ///     vec![
+    ///         ParseWarning::UnsupportedVersion {
+    ///             found: "1.6".to_string(),
+    ///             parsed_as: SpecVersion::V1_5,
+    ///         },
+    ///         ParseWarning::Extension {
+    ///             path: "/definitions".to_string(),
+    ///             content: r#"{"standards":[]}"#.to_string(),
+    ///         },
+    ///     ]
+    /// );
+    /// # Ok::<(), cyclonedx_bom::errors::JsonReadError>(())
+    /// ```
+    pub fn parse_from_json_with_warnings<R: std::io::Read>(
+        mut reader: R,
+    ) -> Result<(Self, Vec<ParseWarning>), crate::errors::JsonReadError> {
+        use crate::specs::{v1_3, v1_4, v1_5};
+
+        let input: serde_json::Value = serde_json::from_reader(&mut reader)?;
+        let found = input
+            .get("specVersion")
+            .and_then(serde_json::Value::as_str)
+            .ok_or(crate::errors::JsonReadError::MissingSpecVersion)?
+            .to_string();
+
+        match found.parse::<SpecVersion>() {
+            Ok(SpecVersion::V1_3) => Self::json_value_with_warnings::<v1_3::bom::Bom>(input),
+            Ok(SpecVersion::V1_4) => Self::json_value_with_warnings::<v1_4::bom::Bom>(input),
+            Ok(SpecVersion::V1_5) => Self::json_value_with_warnings::<v1_5::bom::Bom>(input),
+            Err(_) if is_newer_spec_version(&found) => {
+                let (bom, warnings) =
+                    Self::json_value_with_warnings::<v1_5::bom::Bom>(input.clone())?;
+                // the version the BOM is read as is reported on its own
+                let warnings = warnings
+                    .into_iter()
+                    .filter(|warning| !matches!(warning, ParseWarning::ChangedValue { path, .. } if path == "/specVersion"))
+                    .collect();
+                let warnings =
+                    newer_version_warnings(warnings, &found, NEWEST_SPEC_VERSION, |path| {
+                        input
+                            .pointer(path)
+                            .map(ToString::to_string)
+                            .unwrap_or_default()
+                    });
+                Ok((bom, warnings))
+            }
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    /// Parse the input as an XML document in the version of the specification given by the
+    /// namespace of its `bom` element, also returning the non-fatal issues found in it, like
+    /// [`Bom::parse_from_xml_v1_3_with_warnings`]
+    ///
+    /// A document in a newer version of the specification than is supported is read as the
+    /// newest supported version, like in [`Bom::parse_from_json_with_warnings`].
+    pub fn parse_from_xml_with_warnings<R: std::io::Read>(
+        reader: R,
+    ) -> Result<(Self, Vec<ParseWarning>), crate::errors::XmlReadError> {
+        use crate::specs::{v1_3, v1_4, v1_5};
+
+        let input = read_xml_input(reader)?;
+        let namespace = xml_namespace(&input)?;
+        let newer_version = namespace
+            .as_deref()
+            .and_then(|namespace| namespace.strip_prefix(XML_NAMESPACE_PREFIX))
+            .filter(|version| is_newer_spec_version(version));
+
+        match newer_version {
+            Some(found) => {
+                // the namespace of the document only appears where it is declared
+                let renamed = String::from_utf8_lossy(&input).replace(
+                    &format!("{}{}", XML_NAMESPACE_PREFIX, found),
+                    &NEWEST_SPEC_VERSION.xml_namespace(),
+                );
+                let (bom, warnings) =
+                    Self::xml_input_with_warnings::<v1_5::bom::Bom>(renamed.as_bytes())?;
+                let warnings =
+                    newer_version_warnings(warnings, found, NEWEST_SPEC_VERSION, |path| {
+                        xml_fragment(&input, path)
+                    });
+                Ok((bom, warnings))
+            }
+            None => match xml_spec_version(&input)? {
+                SpecVersion::V1_3 => Self::xml_input_with_warnings::<v1_3::bom::Bom>(&input),
+                SpecVersion::V1_4 => Self::xml_input_with_warnings::<v1_4::bom::Bom>(&input),
+                SpecVersion::V1_5 => Self::xml_input_with_warnings::<v1_5::bom::Bom>(&input),
+            },
+        }
+    }
+
+    /// Parse a JSON document as the version of the specification `B` belongs to, also returning
+    /// the non-fatal issues found in it
+    fn json_value_with_warnings<B>(
+        input: serde_json::Value,
+    ) -> Result<(Self, Vec<ParseWarning>), crate::errors::JsonReadError>
+    where
+        B: serde::de::DeserializeOwned + serde::Serialize + Into<Self>,
+    {
+        let bom: B = serde_json::from_value(input.clone())?;
+
+        let mut warnings = json_warnings(&input, &serde_json::to_value(&bom)?);
+        let bom: Self = bom.into();
+        warnings.extend(unknown_values(&bom));
+
+        Ok((bom, warnings))
+    }
+
+    /// Parse an XML document as the version of the specification `B` belongs to, also returning
+    /// the non-fatal issues found in it
+    fn xml_input_with_warnings<B>(
+        input: &[u8],
+    ) -> Result<(Self, Vec<ParseWarning>), crate::errors::XmlReadError>
+    where
+        B: FromXmlDocument + ToXml + Into<Self>,
+    {
+        let config = ParserConfig::default().trim_whitespace(true);
+        let mut event_reader = EventReader::new_with_config(input, config);
+        let bom = B::read_xml_document(&mut event_reader)?;
+
+        let mut parsed = Vec::new();
+        let mut warnings = match bom.write_xml_element(&mut EventWriter::new(&mut parsed)) {
+            Ok(()) => xml_warnings(input, &parsed),
+            // without the written document there is nothing to compare with
+            Err(_) => Vec::new(),
+        };
+        let bom: Self = bom.into();
+        warnings.extend(unknown_values(&bom));
+
+        Ok((bom, warnings))
+    }
+
     /// Parse the input as a JSON document conforming to [version 1.3 of the specification](https://cyclonedx.org/docs/1.3/json/)
     pub fn parse_from_json_v1_3<R: std::io::Read>(
         mut reader: R,
@@ -62,12 +878,241 @@ impl Bom {
     pub fn parse_from_xml_v1_3<R: std::io::Read>(
         reader: R,
     ) -> Result<Self, crate::errors::XmlReadError> {
+        let input = read_xml_input(reader)?;
+
         let config = ParserConfig::default().trim_whitespace(true);
-        let mut event_reader = EventReader::new_with_config(reader, config);
+        let mut event_reader = EventReader::new_with_config(input.as_slice(), config);
         let bom = crate::specs::v1_3::bom::Bom::read_xml_document(&mut event_reader)?;
         Ok(bom.into())
     }
 
+    /// Parse the input as a JSON document conforming to [version 1.3 of the specification](https://cyclonedx.org/docs/1.3/json/),
+    /// also returning the non-fatal issues found in it
+    ///
+    /// The warnings list the unknown values, the fields the specification does not define and
+    /// the values that are held differently than written.
+    /// ```
+    /// use cyclonedx_bom::models::bom::Bom;
+    /// use cyclonedx_bom::warnings::ParseWarning;
+    ///
+    /// let json = r#"{
+    ///   "bomFormat": "CycloneDX",
+    ///   "specVersion": "1.3",
+    ///   "version": 1,
+    ///   "components": [{ "type": "gadget", "name": "tacos", "version": "1.0.0", "spiciness": "hot" }]
+    /// }"#;
+    /// let (bom, warnings) = Bom::parse_from_json_v1_3_with_warnings(json.as_bytes())?;
+    ///
+    /// assert_eq!(
+    ///     warnings,
+    ///     vec![
+    ///         ParseWarning::DroppedContent { path: "/components/0/spiciness".to_string() },
+    ///         ParseWarning::UnknownValue {
+    ///             path: "components[0].component_type".to_string(),
+    ///             message: "Unknown classification".to_string(),
+    ///         },
+    ///     ]
+    /// );
+    /// # Ok::<(), cyclonedx_bom::errors::JsonReadError>(())
+    /// ```
+    pub fn parse_from_json_v1_3_with_warnings<R: std::io::Read>(
+        mut reader: R,
+    ) -> Result<(Self, Vec<ParseWarning>), crate::errors::JsonReadError> {
+        let input: serde_json::Value = serde_json::from_reader(&mut reader)?;
+        Self::json_value_with_warnings::<crate::specs::v1_3::bom::Bom>(input)
+    }
+
+    /// Parse the input as an XML document conforming to [version 1.3 of the specification](https://cyclonedx.org/docs/1.3/xml/),
+    /// also returning the non-fatal issues found in it
+    ///
+    /// The warnings list the unknown values, the elements and attributes from other schemas and
+    /// the values that are held differently than written.
+    pub fn parse_from_xml_v1_3_with_warnings<R: std::io::Read>(
+        reader: R,
+    ) -> Result<(Self, Vec<ParseWarning>), crate::errors::XmlReadError> {
+        Self::xml_input_with_warnings::<crate::specs::v1_3::bom::Bom>(&read_xml_input(reader)?)
+    }
+
+    /// Parse the input as a JSON document conforming to [version 1.4 of the specification](https://cyclonedx.org/docs/1.4/json/)
+    pub fn parse_from_json_v1_4<R: std::io::Read>(
+        mut reader: R,
+    ) -> Result<Self, crate::errors::JsonReadError> {
+        let bom: crate::specs::v1_4::bom::Bom = serde_json::from_reader(&mut reader)?;
+        Ok(bom.into())
+    }
+
+    /// Parse the input as an XML document conforming to [version 1.4 of the specification](https://cyclonedx.org/docs/1.4/xml/)
+    pub fn parse_from_xml_v1_4<R: std::io::Read>(
+        reader: R,
+    ) -> Result<Self, crate::errors::XmlReadError> {
+        let input = read_xml_input(reader)?;
+
+        let config = ParserConfig::default().trim_whitespace(true);
+        let mut event_reader = EventReader::new_with_config(input.as_slice(), config);
+        let bom = crate::specs::v1_4::bom::Bom::read_xml_document(&mut event_reader)?;
+        Ok(bom.into())
+    }
+
+    /// Parse the input as a JSON document conforming to [version 1.4 of the specification](https://cyclonedx.org/docs/1.4/json/),
+    /// also returning the non-fatal issues found in it, like [`Bom::parse_from_json_v1_3_with_warnings`]
+    pub fn parse_from_json_v1_4_with_warnings<R: std::io::Read>(
+        mut reader: R,
+    ) -> Result<(Self, Vec<ParseWarning>), crate::errors::JsonReadError> {
+        let input: serde_json::Value = serde_json::from_reader(&mut reader)?;
+        Self::json_value_with_warnings::<crate::specs::v1_4::bom::Bom>(input)
+    }
+
+    /// Parse the input as an XML document conforming to [version 1.4 of the specification](https://cyclonedx.org/docs/1.4/xml/),
+    /// also returning the non-fatal issues found in it, like [`Bom::parse_from_xml_v1_3_with_warnings`]
+    pub fn parse_from_xml_v1_4_with_warnings<R: std::io::Read>(
+        reader: R,
+    ) -> Result<(Self, Vec<ParseWarning>), crate::errors::XmlReadError> {
+        Self::xml_input_with_warnings::<crate::specs::v1_4::bom::Bom>(&read_xml_input(reader)?)
+    }
+
+    /// Parse the input as a JSON document conforming to [version 1.5 of the specification](https://cyclonedx.org/docs/1.5/json/)
+    pub fn parse_from_json_v1_5<R: std::io::Read>(
+        mut reader: R,
+    ) -> Result<Self, crate::errors::JsonReadError> {
+        let bom: crate::specs::v1_5::bom::Bom = serde_json::from_reader(&mut reader)?;
+        Ok(bom.into())
+    }
+
+    /// Parse the input as an XML document conforming to [version 1.5 of the specification](https://cyclonedx.org/docs/1.5/xml/)
+    pub fn parse_from_xml_v1_5<R: std::io::Read>(
+        reader: R,
+    ) -> Result<Self, crate::errors::XmlReadError> {
+        let input = read_xml_input(reader)?;
+
+        let config = ParserConfig::default().trim_whitespace(true);
+        let mut event_reader = EventReader::new_with_config(input.as_slice(), config);
+        let bom = crate::specs::v1_5::bom::Bom::read_xml_document(&mut event_reader)?;
+        Ok(bom.into())
+    }
+
+    /// Parse the input as a JSON document conforming to [version 1.5 of the specification](https://cyclonedx.org/docs/1.5/json/),
+    /// also returning the non-fatal issues found in it, like [`Bom::parse_from_json_v1_3_with_warnings`]
+    pub fn parse_from_json_v1_5_with_warnings<R: std::io::Read>(
+        mut reader: R,
+    ) -> Result<(Self, Vec<ParseWarning>), crate::errors::JsonReadError> {
+        let input: serde_json::Value = serde_json::from_reader(&mut reader)?;
+        Self::json_value_with_warnings::<crate::specs::v1_5::bom::Bom>(input)
+    }
+
+    /// Parse the input as an XML document conforming to [version 1.5 of the specification](https://cyclonedx.org/docs/1.5/xml/),
+    /// also returning the non-fatal issues found in it, like [`Bom::parse_from_xml_v1_3_with_warnings`]
+    pub fn parse_from_xml_v1_5_with_warnings<R: std::io::Read>(
+        reader: R,
+    ) -> Result<(Self, Vec<ParseWarning>), crate::errors::XmlReadError> {
+        Self::xml_input_with_warnings::<crate::specs::v1_5::bom::Bom>(&read_xml_input(reader)?)
+    }
+
+    /// Output as a JSON document conforming to `spec_version` of the specification
+    pub fn output_as_json<W: std::io::Write>(
+        self,
+        writer: &mut W,
+        spec_version: SpecVersion,
+    ) -> Result<(), crate::errors::JsonWriteError> {
+        match spec_version {
+            SpecVersion::V1_3 => self.output_as_json_v1_3(writer),
+            SpecVersion::V1_4 => self.output_as_json_v1_4(writer),
+            SpecVersion::V1_5 => self.output_as_json_v1_5(writer),
+        }
+    }
+
+    /// Output as an XML document conforming to `spec_version` of the specification
+    pub fn output_as_xml<W: std::io::Write>(
+        self,
+        writer: &mut W,
+        spec_version: SpecVersion,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        match spec_version {
+            SpecVersion::V1_3 => self.output_as_xml_v1_3(writer),
+            SpecVersion::V1_4 => self.output_as_xml_v1_4(writer),
+            SpecVersion::V1_5 => self.output_as_xml_v1_5(writer),
+        }
+    }
+
+    /// Lists the content that `spec_version` of the specification cannot represent, which is
+    /// dropped when the BOM is output in that version
+    /// ```
+    /// use cyclonedx_bom::models::bom::{Bom, SpecVersion};
+    /// use cyclonedx_bom::models::component::{Classification, Component, Components};
+    /// use cyclonedx_bom::models::metadata::Metadata;
+    /// use cyclonedx_bom::models::tool::Tools;
+    ///
+    /// let mut tool = Component::new(Classification::Application, "cargo-cyclonedx", "1.0.0", None);
+    /// tool.bom_ref = Some("cargo-cyclonedx".to_string());
+    /// let mut metadata = Metadata::default();
+    /// metadata.tools = Some(Tools::Object {
+    ///     components: Some(Components(vec![tool])),
+    ///     services: None,
+    /// });
+    /// let bom = Bom::default().with_metadata(metadata);
+    ///
+    /// let warnings = bom.downgrade_warnings(SpecVersion::V1_3);
+    /// assert_eq!(
+    ///     warnings[0].to_string(),
+    ///     "Dropped metadata.tools.components[0].bom_ref, which version 1.3 of the specification does not support"
+    /// );
+    /// ```
+    pub fn downgrade_warnings(&self, spec_version: SpecVersion) -> Vec<DowngradeWarning> {
+        downgrade_warnings(self, spec_version)
+    }
+
+    /// Output as a JSON document conforming to `spec_version` of the specification, also
+    /// returning the content that was dropped because the version cannot represent it
+    pub fn output_as_json_with_warnings<W: std::io::Write>(
+        self,
+        writer: &mut W,
+        spec_version: SpecVersion,
+    ) -> Result<Vec<DowngradeWarning>, crate::errors::JsonWriteError> {
+        let warnings = self.downgrade_warnings(spec_version);
+        self.output_as_json(writer, spec_version)?;
+        Ok(warnings)
+    }
+
+    /// Output as an XML document conforming to `spec_version` of the specification, also
+    /// returning the content that was dropped because the version cannot represent it
+    pub fn output_as_xml_with_warnings<W: std::io::Write>(
+        self,
+        writer: &mut W,
+        spec_version: SpecVersion,
+    ) -> Result<Vec<DowngradeWarning>, crate::errors::XmlWriteError> {
+        let warnings = format_downgrade_warnings(&self, spec_version, DocumentFormat::Xml);
+        self.output_as_xml(writer, spec_version)?;
+        Ok(warnings)
+    }
+
+    /// Output as an XML document conforming to `spec_version` of the specification to a writer
+    /// that is buffered while the document is written, and returned when it is complete
+    ///
+    /// Like all the `output_as_xml*` functions, it writes each element as soon as it is
+    /// serialized, without building the document in memory. As the writes are buffered, an
+    /// unbuffered writer such as a `File` can be passed as it is.
+    /// ```no_run
+    /// use cyclonedx_bom::models::bom::{Bom, SpecVersion};
+    ///
+    /// let file = std::fs::File::create("bom.xml").expect("Failed to create the file");
+    /// let file = Bom::default()
+    ///     .output_as_xml_to_writer(file, SpecVersion::V1_5)
+    ///     .expect("Failed to write BOM");
+    /// file.sync_all().expect("Failed to sync the file");
+    /// ```
+    pub fn output_as_xml_to_writer<W: std::io::Write>(
+        self,
+        writer: W,
+        spec_version: SpecVersion,
+    ) -> Result<W, crate::errors::XmlWriteError> {
+        let mut writer = std::io::BufWriter::new(writer);
+        self.output_as_xml(&mut writer, spec_version)?;
+        writer
+            .into_inner()
+            .map_err(|error| crate::errors::XmlWriteError::FlushError {
+                error: error.into_error(),
+            })
+    }
+
     /// Output as a JSON document conforming to [version 1.3 of the specification](https://cyclonedx.org/docs/1.3/json/)
     pub fn output_as_json_v1_3<W: std::io::Write>(
         self,
@@ -88,6 +1133,124 @@ impl Bom {
         let bom: crate::specs::v1_3::bom::Bom = self.into();
         bom.write_xml_element(&mut event_writer)
     }
+
+    /// Output as a JSON document conforming to [version 1.4 of the specification](https://cyclonedx.org/docs/1.4/json/)
+    pub fn output_as_json_v1_4<W: std::io::Write>(
+        self,
+        writer: &mut W,
+    ) -> Result<(), crate::errors::JsonWriteError> {
+        let bom: crate::specs::v1_4::bom::Bom = self.into();
+        serde_json::to_writer_pretty(writer, &bom)?;
+        Ok(())
+    }
+
+    /// Output as an XML document conforming to [version 1.4 of the specification](https://cyclonedx.org/docs/1.4/xml/)
+    ///
+    /// Signatures are not written, as XML documents are signed with XML Signature instead.
+    pub fn output_as_xml_v1_4<W: std::io::Write>(
+        self,
+        writer: &mut W,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        let config = EmitterConfig::default().perform_indent(true);
+        let mut event_writer = EventWriter::new_with_config(writer, config);
+
+        let bom: crate::specs::v1_4::bom::Bom = self.into();
+        bom.write_xml_element(&mut event_writer)
+    }
+
+    /// Output as a JSON document conforming to [version 1.5 of the specification](https://cyclonedx.org/docs/1.5/json/)
+    pub fn output_as_json_v1_5<W: std::io::Write>(
+        self,
+        writer: &mut W,
+    ) -> Result<(), crate::errors::JsonWriteError> {
+        let bom: crate::specs::v1_5::bom::Bom = self.into();
+        serde_json::to_writer_pretty(writer, &bom)?;
+        Ok(())
+    }
+
+    /// Output as an XML document conforming to [version 1.5 of the specification](https://cyclonedx.org/docs/1.5/xml/)
+    ///
+    /// Signatures are not written, as XML documents are signed with XML Signature instead.
+    pub fn output_as_xml_v1_5<W: std::io::Write>(
+        self,
+        writer: &mut W,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        let config = EmitterConfig::default().perform_indent(true);
+        let mut event_writer = EventWriter::new_with_config(writer, config);
+
+        let bom: crate::specs::v1_5::bom::Bom = self.into();
+        bom.write_xml_element(&mut event_writer)
+    }
+}
+
+/// Builds a [`Bom`], adding components, services, dependencies, external references and
+/// properties one at a time
+#[derive(Debug)]
+pub struct BomBuilder {
+    bom: Bom,
+    components: Vec<Component>,
+}
+
+impl BomBuilder {
+    pub fn serial_number(mut self, serial_number: UrnUuid) -> Self {
+        self.bom.serial_number = Some(serial_number);
+        self
+    }
+
+    pub fn version(mut self, version: u32) -> Self {
+        self.bom.version = version;
+        self
+    }
+
+    pub fn metadata(mut self, metadata: Metadata) -> Self {
+        self.bom.metadata = Some(metadata);
+        self
+    }
+
+    pub fn component(mut self, component: Component) -> Self {
+        self.components.push(component);
+        self
+    }
+
+    pub fn service(mut self, service: Service) -> Self {
+        self.bom
+            .services
+            .get_or_insert_with(|| Services(Vec::new()))
+            .0
+            .push(service);
+        self
+    }
+
+    /// Adds `dependency`, joining the dependencies of the same `dependency_ref`, see
+    /// [`Bom::add_dependency`]
+    pub fn dependency(mut self, dependency: Dependency) -> Self {
+        self.bom.add_dependency(dependency);
+        self
+    }
+
+    pub fn external_reference(mut self, external_reference: ExternalReference) -> Self {
+        self.bom
+            .external_references
+            .get_or_insert_with(|| ExternalReferences(Vec::new()))
+            .0
+            .push(external_reference);
+        self
+    }
+
+    /// Sets the value of the property called `name`, see [`Properties::upsert`]
+    pub fn property(mut self, name: &str, value: &str) -> Self {
+        self.bom.upsert_property(name, value);
+        self
+    }
+
+    /// Returns an error variant if two components, nested ones and the metadata component
+    /// included, share a `bom-ref`
+    pub fn build(mut self) -> Result<Bom, BomEditError> {
+        for component in self.components {
+            self.bom.add_component(component)?;
+        }
+        Ok(self.bom)
+    }
 }
 
 impl Default for Bom {
@@ -103,6 +1266,10 @@ impl Default for Bom {
             dependencies: None,
             compositions: None,
             properties: None,
+            vulnerabilities: None,
+            signature: None,
+            annotations: None,
+            formulation: None,
         }
     }
 }
@@ -139,6 +1306,28 @@ impl Validate for Bom {
             }
         }
 
+        if self
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.timestamp.as_ref())
+            .is_none()
+        {
+            let context = context.extend_context(vec![
+                ValidationPathComponent::Struct {
+                    struct_name: "Bom".to_string(),
+                    field_name: "metadata".to_string(),
+                },
+                ValidationPathComponent::Struct {
+                    struct_name: "Metadata".to_string(),
+                    field_name: "timestamp".to_string(),
+                },
+            ]);
+
+            results.push(ValidationResult::from_reasons(vec![
+                FailureReason::warning("Missing timestamp", context),
+            ]));
+        }
+
         if let Some(components) = &self.components {
             let context = context.extend_context_with_struct_field("Bom", "components");
             let component_bom_ref_context = context.clone();
@@ -187,10 +1376,10 @@ impl Validate for Bom {
                         context.extend_context_with_struct_field("Dependency", "dependency_ref");
 
                     results.push(ValidationResult::Failed {
-                        reasons: vec![FailureReason {
-                            message: "Dependency reference does not exist in the BOM".to_string(),
-                            context: dependency_context,
-                        }],
+                        reasons: vec![FailureReason::error(
+                            "Dependency reference does not exist in the BOM",
+                            dependency_context,
+                        )],
                     })
                 }
 
@@ -209,11 +1398,10 @@ impl Validate for Bom {
                         ]);
 
                         results.push(ValidationResult::Failed {
-                            reasons: vec![FailureReason {
-                                message: "Dependency reference does not exist in the BOM"
-                                    .to_string(),
+                            reasons: vec![FailureReason::error(
+                                "Dependency reference does not exist in the BOM",
                                 context,
-                            }],
+                            )],
                         })
                     }
                 }
@@ -243,11 +1431,10 @@ impl Validate for Bom {
                                 },
                             ]);
                             results.push(ValidationResult::Failed {
-                                reasons: vec![FailureReason {
-                                    message: "Composition reference does not exist in the BOM"
-                                        .to_string(),
-                                    context: compositions_context,
-                                }],
+                                reasons: vec![FailureReason::error(
+                                    "Composition reference does not exist in the BOM",
+                                    compositions_context,
+                                )],
                             });
                         }
                     }
@@ -266,11 +1453,10 @@ impl Validate for Bom {
                                 },
                             ]);
                             results.push(ValidationResult::Failed {
-                                reasons: vec![FailureReason {
-                                    message: "Composition reference does not exist in the BOM"
-                                        .to_string(),
-                                    context: compositions_context,
-                                }],
+                                reasons: vec![FailureReason::error(
+                                    "Composition reference does not exist in the BOM",
+                                    compositions_context,
+                                )],
                             });
                         }
                     }
@@ -281,15 +1467,157 @@ impl Validate for Bom {
         if let Some(properties) = &self.properties {
             let context = context.extend_context_with_struct_field("Bom", "properties");
 
-            results.push(properties.validate_with_context(context)?);
+            results.push(properties.validate_with_context(context)?);
+        }
+
+        if let Some(vulnerabilities) = &self.vulnerabilities {
+            let context = context.extend_context_with_struct_field("Bom", "vulnerabilities");
+
+            results.push(vulnerabilities.validate_with_context(context)?);
+        }
+
+        if let Some(signature) = &self.signature {
+            let context = context.extend_context_with_struct_field("Bom", "signature");
+
+            results.push(signature.validate_with_context(context)?);
+        }
+
+        if let Some(annotations) = &self.annotations {
+            let context = context.extend_context_with_struct_field("Bom", "annotations");
+
+            results.push(annotations.validate_with_context(context)?);
+        }
+
+        if let Some(formulation) = &self.formulation {
+            let context = context.extend_context_with_struct_field("Bom", "formulation");
+
+            results.push(formulation.validate_with_context(context)?);
+        }
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+fn collect_component_bom_refs<'a>(component: &'a Component, bom_refs: &mut Vec<&'a str>) {
+    if let Some(bom_ref) = &component.bom_ref {
+        bom_refs.push(bom_ref);
+    }
+    if let Some(components) = &component.components {
+        for component in &components.0 {
+            collect_component_bom_refs(component, bom_refs);
+        }
+    }
+}
+
+fn collect_service_bom_refs<'a>(service: &'a Service, bom_refs: &mut Vec<&'a str>) {
+    if let Some(bom_ref) = &service.bom_ref {
+        bom_refs.push(bom_ref);
+    }
+    if let Some(services) = &service.services {
+        for service in &services.0 {
+            collect_service_bom_refs(service, bom_refs);
+        }
+    }
+}
+
+/// The identity of a component when merging BOMs
+#[derive(Clone, Debug)]
+struct ComponentKey {
+    bom_ref: Option<String>,
+    purl: Option<String>,
+}
+
+impl ComponentKey {
+    fn of(component: &Component) -> Self {
+        Self {
+            bom_ref: component.bom_ref.clone(),
+            purl: component.purl.as_ref().map(ToString::to_string),
+        }
+    }
+
+    fn matches(&self, component: &Component) -> bool {
+        let key = Self::of(component);
+        (self.bom_ref.is_some() && self.bom_ref == key.bom_ref)
+            || (self.purl.is_some() && self.purl == key.purl)
+    }
+}
+
+/// The components listed in a BOM, by `bom_ref` and Package URL, and the `bom_ref`s of its
+/// services, which merged components may not reuse
+#[derive(Default)]
+struct KnownComponents {
+    by_bom_ref: HashMap<String, ComponentKey>,
+    by_purl: HashMap<String, ComponentKey>,
+    service_bom_refs: HashSet<String>,
+}
+
+impl KnownComponents {
+    fn of(bom: &Bom) -> Self {
+        fn insert_all(known: &mut KnownComponents, component: &Component) {
+            known.insert_key(ComponentKey::of(component));
+            for nested in component.components.iter().flat_map(|c| c.0.iter()) {
+                insert_all(known, nested);
+            }
+        }
+
+        let mut known = Self::default();
+        if let Some(root) = bom.metadata.as_ref().and_then(|m| m.component.as_ref()) {
+            insert_all(&mut known, root);
+        }
+        for component in bom.components.iter().flat_map(|c| c.0.iter()) {
+            insert_all(&mut known, component);
+        }
+        let mut service_bom_refs = Vec::new();
+        for service in bom.services.iter().flat_map(|s| s.0.iter()) {
+            collect_service_bom_refs(service, &mut service_bom_refs);
+        }
+        known.service_bom_refs = service_bom_refs.into_iter().map(String::from).collect();
+        known
+    }
+
+    fn find(&self, component: &Component) -> Option<ComponentKey> {
+        let key = ComponentKey::of(component);
+        let listed = key
+            .bom_ref
+            .as_ref()
+            .and_then(|bom_ref| self.by_bom_ref.get(bom_ref))
+            .or_else(|| key.purl.as_ref().and_then(|purl| self.by_purl.get(purl)))?;
+        Some(listed.clone())
+    }
+
+    fn insert(&mut self, component: &Component) -> Result<(), BomEditError> {
+        if let Some(bom_ref) = &component.bom_ref {
+            if self.service_bom_refs.contains(bom_ref) {
+                return Err(BomEditError::DuplicateBomRef(bom_ref.clone()));
+            }
         }
+        self.insert_key(ComponentKey::of(component));
+        Ok(())
+    }
 
-        Ok(results
-            .into_iter()
-            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    fn insert_key(&mut self, key: ComponentKey) {
+        if let Some(bom_ref) = &key.bom_ref {
+            self.by_bom_ref
+                .entry(bom_ref.clone())
+                .or_insert_with(|| key.clone());
+        }
+        if let Some(purl) = &key.purl {
+            self.by_purl.entry(purl.clone()).or_insert(key);
+        }
     }
 }
 
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum BomEditError {
+    #[error("The bom-ref {0} is already used in the BOM")]
+    DuplicateBomRef(String),
+
+    #[error("The BOM to add as an assembly has no metadata component")]
+    MissingMetadataComponent,
+}
+
 #[derive(Default)]
 struct BomReferencesContext {
     component_bom_refs: HashSet<String>,
@@ -320,10 +1648,10 @@ fn validate_component_bom_refs(
         if bom_refs.contains(bom_ref) {
             let context = context.extend_context_with_struct_field("Component", "bom_ref");
             results.push(ValidationResult::Failed {
-                reasons: vec![FailureReason {
-                    message: format!(r#"Bom ref "{bom_ref}" is not unique"#),
+                reasons: vec![FailureReason::error(
+                    format!(r#"Bom ref "{bom_ref}" is not unique"#),
                     context,
-                }],
+                )],
             });
         }
         bom_refs.add_component_bom_ref(bom_ref);
@@ -361,10 +1689,10 @@ fn validate_service_bom_refs(
         if bom_refs.contains(bom_ref) {
             let context = context.extend_context_with_struct_field("Service", "bom_ref");
             results.push(ValidationResult::Failed {
-                reasons: vec![FailureReason {
-                    message: format!(r#"Bom ref "{bom_ref}" is not unique"#),
+                reasons: vec![FailureReason::error(
+                    format!(r#"Bom ref "{bom_ref}" is not unique"#),
                     context,
-                }],
+                )],
             });
         }
         bom_refs.add_service_bom_ref(bom_ref);
@@ -392,6 +1720,147 @@ fn validate_services(
     }
 }
 
+/// Reads the whole document and decodes it to UTF-8
+fn read_xml_input<R: std::io::Read>(mut reader: R) -> Result<Vec<u8>, crate::errors::XmlReadError> {
+    let mut input = Vec::new();
+    reader.read_to_end(&mut input).map_err(|error| {
+        crate::errors::XmlReadError::ElementReadError {
+            error: error.into(),
+            element: "bom".to_string(),
+        }
+    })?;
+    match crate::encoding::decode_xml(&input)? {
+        std::borrow::Cow::Borrowed(_) => Ok(input),
+        std::borrow::Cow::Owned(decoded) => Ok(decoded),
+    }
+}
+
+/// Reads the version of the specification from the namespace of the root element of `input`
+fn xml_spec_version(input: &[u8]) -> Result<SpecVersion, crate::errors::XmlReadError> {
+    let actual_namespace = xml_namespace(input)?;
+    actual_namespace
+        .as_deref()
+        .and_then(SpecVersion::from_xml_namespace)
+        .ok_or_else(|| crate::errors::XmlReadError::InvalidNamespaceError {
+            expected_namespace: SpecVersion::SUPPORTED
+                .iter()
+                .map(SpecVersion::xml_namespace)
+                .collect::<Vec<_>>()
+                .join(" or "),
+            actual_namespace,
+        })
+}
+
+/// Reads the default namespace of the root element of `input`
+fn xml_namespace(input: &[u8]) -> Result<Option<String>, crate::errors::XmlReadError> {
+    let config = ParserConfig::default().trim_whitespace(true);
+    for event in EventReader::new_with_config(input, config) {
+        if let xml::reader::XmlEvent::StartElement { namespace, .. } =
+            event.map_err(to_xml_read_error("bom"))?
+        {
+            return Ok(namespace
+                .get(xml::namespace::NS_NO_PREFIX)
+                .map(String::from));
+        }
+    }
+
+    Err(crate::errors::XmlReadError::RequiredDataMissing {
+        required_field: "bom".to_string(),
+        element: "document".to_string(),
+    })
+}
+
+const XML_NAMESPACE_PREFIX: &str = "http://cyclonedx.org/schema/bom/";
+
+/// The version newer documents are read as by the lenient parse functions
+const NEWEST_SPEC_VERSION: SpecVersion = SpecVersion::V1_5;
+
+/// Whether `version`, e.g. `1.6`, is a newer version of the specification than is supported
+fn is_newer_spec_version(version: &str) -> bool {
+    fn number(version: &str) -> Option<(u32, u32)> {
+        let (major, minor) = version.split_once('.')?;
+        Some((major.parse().ok()?, minor.parse().ok()?))
+    }
+
+    match (number(version), number(NEWEST_SPEC_VERSION.as_str())) {
+        (Some(version), Some(newest)) => version > newest,
+        _ => false,
+    }
+}
+
+/// Version of the CycloneDX specification a BOM document conforms to
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SpecVersion {
+    #[default]
+    V1_3,
+    V1_4,
+    V1_5,
+}
+
+impl SpecVersion {
+    /// The versions documents can be read and written as
+    pub const SUPPORTED: &'static [SpecVersion] =
+        &[SpecVersion::V1_3, SpecVersion::V1_4, SpecVersion::V1_5];
+
+    /// The version number, e.g. `1.3`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SpecVersion::V1_3 => "1.3",
+            SpecVersion::V1_4 => "1.4",
+            SpecVersion::V1_5 => "1.5",
+        }
+    }
+
+    fn xml_namespace(&self) -> String {
+        format!("{}{}", XML_NAMESPACE_PREFIX, self.as_str())
+    }
+
+    fn from_xml_namespace(namespace: &str) -> Option<Self> {
+        namespace
+            .strip_prefix(XML_NAMESPACE_PREFIX)
+            .and_then(|version| version.parse().ok())
+    }
+}
+
+impl std::str::FromStr for SpecVersion {
+    type Err = SpecVersionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        SpecVersion::SUPPORTED
+            .iter()
+            .find(|version| version.as_str() == s)
+            .copied()
+            .ok_or_else(|| SpecVersionError::UnsupportedSpecVersion(s.to_string()))
+    }
+}
+
+/// Serializes the version number, e.g. `"1.3"`
+impl serde::Serialize for SpecVersion {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl fmt::Display for SpecVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum SpecVersionError {
+    #[error("Unsupported specification version {}, expected one of: {}", .0, supported_versions())]
+    UnsupportedSpecVersion(String),
+}
+
+fn supported_versions() -> String {
+    SpecVersion::SUPPORTED
+        .iter()
+        .map(SpecVersion::as_str)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 #[derive(Debug, PartialEq, Eq)]
 pub struct UrnUuid(pub(crate) String);
 
@@ -430,10 +1899,10 @@ impl Validate for UrnUuid {
         match matches_urn_uuid_regex(&self.0) {
             Ok(true) => Ok(ValidationResult::Passed),
             Ok(false) => Ok(ValidationResult::Failed {
-                reasons: vec![FailureReason {
-                    message: "UrnUuid does not match regular expression".to_string(),
+                reasons: vec![FailureReason::error(
+                    "UrnUuid does not match regular expression",
                     context,
-                }],
+                )],
             }),
             Err(e) => Err(e.into()),
         }
@@ -459,16 +1928,18 @@ fn matches_urn_uuid_regex(value: &str) -> Result<bool, regex::Error> {
 #[cfg(test)]
 mod test {
     use crate::{
-        external_models::{date_time::DateTime, normalized_string::NormalizedString, uri::Uri},
+        external_models::{normalized_string::NormalizedString, purl::Purl, uri::Uri},
         models::{
+            annotation::{Annotation, Annotator},
             component::{Classification, Component},
             composition::{AggregateType, BomReference, Composition},
             dependency::Dependency,
             external_reference::{ExternalReference, ExternalReferenceType},
+            organization::OrganizationalContact,
             property::Property,
             service::Service,
         },
-        validation::ValidationPathComponent,
+        validation::{Severity, ValidationOptions, ValidationPathComponent},
     };
 
     use super::*;
@@ -486,13 +1957,40 @@ mod test {
             dependencies: None,
             compositions: None,
             properties: None,
+            signature: None,
+            vulnerabilities: None,
+            annotations: None,
+            formulation: None,
         };
 
+        let actual = bom.validate().expect("Failed to validate bom");
+
+        assert_eq!(actual, ValidationResult::Passed);
+
         let actual = bom
-            .validate_with_context(ValidationContext::default())
+            .validate_with_options(ValidationOptions {
+                minimum_severity: Severity::Warning,
+            })
             .expect("Failed to validate bom");
 
-        assert_eq!(actual, ValidationResult::Passed);
+        assert_eq!(
+            actual,
+            ValidationResult::PassedWithWarnings {
+                reasons: vec![FailureReason::warning(
+                    "Missing timestamp",
+                    ValidationContext(vec![
+                        ValidationPathComponent::Struct {
+                            struct_name: "Bom".to_string(),
+                            field_name: "metadata".to_string(),
+                        },
+                        ValidationPathComponent::Struct {
+                            struct_name: "Metadata".to_string(),
+                            field_name: "timestamp".to_string(),
+                        },
+                    ])
+                )]
+            }
+        );
     }
 
     #[test]
@@ -510,6 +2008,10 @@ mod test {
             }])),
             compositions: None,
             properties: None,
+            signature: None,
+            vulnerabilities: None,
+            annotations: None,
+            formulation: None,
         };
 
         let actual = bom.validate().expect("Failed to validate bom");
@@ -518,9 +2020,9 @@ mod test {
             actual,
             ValidationResult::Failed {
                 reasons: vec![
-                    FailureReason {
-                        message: "Dependency reference does not exist in the BOM".to_string(),
-                        context: ValidationContext(vec![
+                    FailureReason::error(
+                        "Dependency reference does not exist in the BOM",
+                        ValidationContext(vec![
                             ValidationPathComponent::Struct {
                                 struct_name: "Bom".to_string(),
                                 field_name: "dependencies".to_string(),
@@ -531,10 +2033,10 @@ mod test {
                                 field_name: "dependency_ref".to_string(),
                             },
                         ])
-                    },
-                    FailureReason {
-                        message: "Dependency reference does not exist in the BOM".to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "Dependency reference does not exist in the BOM",
+                        ValidationContext(vec![
                             ValidationPathComponent::Struct {
                                 struct_name: "Bom".to_string(),
                                 field_name: "dependencies".to_string(),
@@ -546,7 +2048,7 @@ mod test {
                             },
                             ValidationPathComponent::Array { index: 0 },
                         ])
-                    },
+                    ),
                 ]
             }
         );
@@ -568,6 +2070,10 @@ mod test {
                 dependencies: Some(vec![BomReference("dependencies".to_string())]),
             }])),
             properties: None,
+            signature: None,
+            vulnerabilities: None,
+            annotations: None,
+            formulation: None,
         };
 
         let actual = bom.validate().expect("Failed to validate bom");
@@ -576,9 +2082,9 @@ mod test {
             actual,
             ValidationResult::Failed {
                 reasons: vec![
-                    FailureReason {
-                        message: "Composition reference does not exist in the BOM".to_string(),
-                        context: ValidationContext(vec![
+                    FailureReason::error(
+                        "Composition reference does not exist in the BOM",
+                        ValidationContext(vec![
                             ValidationPathComponent::Struct {
                                 struct_name: "Bom".to_string(),
                                 field_name: "compositions".to_string(),
@@ -590,10 +2096,10 @@ mod test {
                             },
                             ValidationPathComponent::Array { index: 0 },
                         ])
-                    },
-                    FailureReason {
-                        message: "Composition reference does not exist in the BOM".to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "Composition reference does not exist in the BOM",
+                        ValidationContext(vec![
                             ValidationPathComponent::Struct {
                                 struct_name: "Bom".to_string(),
                                 field_name: "compositions".to_string(),
@@ -605,7 +2111,7 @@ mod test {
                             },
                             ValidationPathComponent::Array { index: 0 },
                         ])
-                    },
+                    ),
                 ]
             }
         );
@@ -617,7 +2123,7 @@ mod test {
             version: 1,
             serial_number: Some(UrnUuid("invalid uuid".to_string())),
             metadata: Some(Metadata {
-                timestamp: Some(DateTime("invalid datetime".to_string())),
+                timestamp: None,
                 tools: None,
                 authors: None,
                 component: None,
@@ -625,6 +2131,7 @@ mod test {
                 supplier: None,
                 licenses: None,
                 properties: None,
+                lifecycles: None,
             }),
             components: Some(Components(vec![Component {
                 component_type: Classification::UnknownClassification("unknown".to_string()),
@@ -650,6 +2157,10 @@ mod test {
                 properties: None,
                 components: None,
                 evidence: None,
+                release_notes: None,
+                signature: None,
+                data: None,
+                model_card: None,
             }])),
             services: Some(Services(vec![Service {
                 bom_ref: None,
@@ -661,11 +2172,14 @@ mod test {
                 endpoints: None,
                 authenticated: None,
                 x_trust_boundary: None,
+                trust_zone: None,
                 data: None,
                 licenses: None,
                 external_references: None,
                 properties: None,
                 services: None,
+                release_notes: None,
+                signature: None,
             }])),
             external_references: Some(ExternalReferences(vec![ExternalReference {
                 external_reference_type: ExternalReferenceType::UnknownExternalReferenceType(
@@ -688,39 +2202,28 @@ mod test {
                 name: "name".to_string(),
                 value: NormalizedString("invalid\tvalue".to_string()),
             }])),
+            signature: None,
+            vulnerabilities: None,
+            annotations: None,
+            formulation: None,
         };
 
-        let actual = bom
-            .validate_with_context(ValidationContext::default())
-            .expect("Failed to validate bom");
+        let actual = bom.validate().expect("Failed to validate bom");
 
         assert_eq!(
             actual,
             ValidationResult::Failed {
                 reasons: vec![
-                    FailureReason {
-                        message: "UrnUuid does not match regular expression".to_string(),
-                        context: ValidationContext(vec![ValidationPathComponent::Struct {
+                    FailureReason::error(
+                        "UrnUuid does not match regular expression",
+                        ValidationContext(vec![ValidationPathComponent::Struct {
                             struct_name: "Bom".to_string(),
                             field_name: "serial_number".to_string()
                         }])
-                    },
-                    FailureReason {
-                        message: "DateTime does not conform to ISO 8601".to_string(),
-                        context: ValidationContext(vec![
-                            ValidationPathComponent::Struct {
-                                struct_name: "Bom".to_string(),
-                                field_name: "metadata".to_string()
-                            },
-                            ValidationPathComponent::Struct {
-                                struct_name: "Metadata".to_string(),
-                                field_name: "timestamp".to_string()
-                            }
-                        ])
-                    },
-                    FailureReason {
-                        message: "Unknown classification".to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "Unknown classification",
+                        ValidationContext(vec![
                             ValidationPathComponent::Struct {
                                 struct_name: "Bom".to_string(),
                                 field_name: "components".to_string()
@@ -731,12 +2234,10 @@ mod test {
                                 field_name: "component_type".to_string()
                             }
                         ])
-                    },
-                    FailureReason {
-                        message:
-                            "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
-                                .to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                        ValidationContext(vec![
                             ValidationPathComponent::Struct {
                                 struct_name: "Bom".to_string(),
                                 field_name: "services".to_string()
@@ -747,10 +2248,10 @@ mod test {
                                 field_name: "name".to_string()
                             }
                         ])
-                    },
-                    FailureReason {
-                        message: "Unknown external reference type".to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "Unknown external reference type",
+                        ValidationContext(vec![
                             ValidationPathComponent::Struct {
                                 struct_name: "Bom".to_string(),
                                 field_name: "external_references".to_string()
@@ -761,10 +2262,10 @@ mod test {
                                 field_name: "external_reference_type".to_string()
                             }
                         ])
-                    },
-                    FailureReason {
-                        message: "Unknown aggregate type".to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "Unknown aggregate type",
+                        ValidationContext(vec![
                             ValidationPathComponent::Struct {
                                 struct_name: "Bom".to_string(),
                                 field_name: "compositions".to_string()
@@ -775,12 +2276,10 @@ mod test {
                                 field_name: "aggregate".to_string()
                             }
                         ])
-                    },
-                    FailureReason {
-                        message:
-                            "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
-                                .to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                        ValidationContext(vec![
                             ValidationPathComponent::Struct {
                                 struct_name: "Bom".to_string(),
                                 field_name: "properties".to_string()
@@ -791,7 +2290,7 @@ mod test {
                                 field_name: "value".to_string()
                             }
                         ])
-                    },
+                    ),
                 ]
             }
         )
@@ -829,6 +2328,7 @@ mod test {
                 supplier: None,
                 licenses: None,
                 properties: None,
+                lifecycles: None,
             }),
             components: Some(Components(vec![
                 component_builder("metadata-component"),
@@ -847,17 +2347,21 @@ mod test {
             dependencies: None,
             compositions: None,
             properties: None,
+            signature: None,
+            vulnerabilities: None,
+            annotations: None,
+            formulation: None,
         }
-        .validate_with_context(ValidationContext::default())
+        .validate()
         .expect("Error while validating");
 
         assert_eq!(
             validation_result,
             ValidationResult::Failed {
                 reasons: vec![
-                    FailureReason {
-                        message: r#"Bom ref "metadata-component" is not unique"#.to_string(),
-                        context: ValidationContext(vec![
+                    FailureReason::error(
+                        r#"Bom ref "metadata-component" is not unique"#.to_string(),
+                        ValidationContext(vec![
                             ValidationPathComponent::Struct {
                                 struct_name: "Bom".to_string(),
                                 field_name: "components".to_string()
@@ -868,10 +2372,10 @@ mod test {
                                 field_name: "bom_ref".to_string()
                             },
                         ])
-                    },
-                    FailureReason {
-                        message: r#"Bom ref "component-component" is not unique"#.to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        r#"Bom ref "component-component" is not unique"#.to_string(),
+                        ValidationContext(vec![
                             ValidationPathComponent::Struct {
                                 struct_name: "Bom".to_string(),
                                 field_name: "components".to_string()
@@ -882,10 +2386,10 @@ mod test {
                                 field_name: "bom_ref".to_string()
                             },
                         ])
-                    },
-                    FailureReason {
-                        message: r#"Bom ref "subcomponent-component" is not unique"#.to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        r#"Bom ref "subcomponent-component" is not unique"#.to_string(),
+                        ValidationContext(vec![
                             ValidationPathComponent::Struct {
                                 struct_name: "Bom".to_string(),
                                 field_name: "components".to_string()
@@ -901,10 +2405,10 @@ mod test {
                                 field_name: "bom_ref".to_string()
                             },
                         ])
-                    },
-                    FailureReason {
-                        message: r#"Bom ref "service-service" is not unique"#.to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        r#"Bom ref "service-service" is not unique"#.to_string(),
+                        ValidationContext(vec![
                             ValidationPathComponent::Struct {
                                 struct_name: "Bom".to_string(),
                                 field_name: "services".to_string()
@@ -915,10 +2419,10 @@ mod test {
                                 field_name: "bom_ref".to_string()
                             },
                         ])
-                    },
-                    FailureReason {
-                        message: r#"Bom ref "subservice-service" is not unique"#.to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        r#"Bom ref "subservice-service" is not unique"#.to_string(),
+                        ValidationContext(vec![
                             ValidationPathComponent::Struct {
                                 struct_name: "Bom".to_string(),
                                 field_name: "services".to_string()
@@ -934,10 +2438,10 @@ mod test {
                                 field_name: "bom_ref".to_string()
                             },
                         ])
-                    },
-                    FailureReason {
-                        message: r#"Bom ref "component-service" is not unique"#.to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        r#"Bom ref "component-service" is not unique"#.to_string(),
+                        ValidationContext(vec![
                             ValidationPathComponent::Struct {
                                 struct_name: "Bom".to_string(),
                                 field_name: "services".to_string()
@@ -948,12 +2452,297 @@ mod test {
                                 field_name: "bom_ref".to_string()
                             },
                         ])
-                    },
+                    ),
                 ]
             },
         );
     }
 
+    #[test]
+    fn it_should_add_components_sorted_by_name_and_version() {
+        let mut bom = Bom::default();
+
+        for (name, version) in [("b", "1.0.0"), ("a", "2.0.0"), ("a", "1.0.0")] {
+            bom.add_component(Component::new(
+                Classification::Library,
+                name,
+                version,
+                Some(format!("{}@{}", name, version)),
+            ))
+            .expect("Failed to add component");
+        }
+
+        let actual: Vec<_> = bom
+            .components
+            .expect("Components should have been created")
+            .0
+            .into_iter()
+            .map(|component| component.bom_ref.unwrap_or_default())
+            .collect();
+
+        assert_eq!(actual, vec!["a@1.0.0", "a@2.0.0", "b@1.0.0"]);
+    }
+
+    #[test]
+    fn it_should_reject_components_with_a_used_bom_ref() {
+        let mut bom = Bom::default().with_services(Services(vec![Service::new(
+            "service",
+            Some("used".to_string()),
+        )]));
+
+        let mut component = Component::new(
+            Classification::Library,
+            "tacos",
+            "1.0.0",
+            Some("new".to_string()),
+        );
+        component.components = Some(Components(vec![Component::new(
+            Classification::Library,
+            "nested",
+            "1.0.0",
+            Some("used".to_string()),
+        )]));
+
+        let actual = bom
+            .add_component(component)
+            .expect_err("Should not have added the component");
+
+        assert_eq!(actual, BomEditError::DuplicateBomRef("used".to_string()));
+        assert_eq!(bom.components, None);
+    }
+
+    #[test]
+    fn it_should_remove_dependencies_on_removed_components() {
+        let mut bom = Bom::default();
+        let mut parent = Component::new(
+            Classification::Library,
+            "parent",
+            "1.0.0",
+            Some("parent".to_string()),
+        );
+        parent.components = Some(Components(vec![Component::new(
+            Classification::Library,
+            "nested",
+            "1.0.0",
+            Some("nested".to_string()),
+        )]));
+        bom.add_component(parent).expect("Failed to add component");
+        bom.add_component(Component::new(
+            Classification::Library,
+            "kept",
+            "1.0.0",
+            Some("kept".to_string()),
+        ))
+        .expect("Failed to add component");
+        bom.dependencies = Some(Dependencies(vec![
+            Dependency::new("kept", vec!["parent".to_string(), "nested".to_string()]),
+            Dependency::new("nested", vec!["kept".to_string()]),
+        ]));
+        let annotation = |subjects: &[&str]| {
+            Annotation::new(
+                subjects.iter().map(|subject| subject.to_string()).collect(),
+                Annotator::Individual(OrganizationalContact::new("Jane Doe", None)),
+                "1969-06-28T01:20:00.00-04:00".parse().unwrap(),
+                "reviewed",
+            )
+        };
+        bom.annotations = Some(Annotations(vec![
+            annotation(&["kept", "nested"]),
+            annotation(&["parent"]),
+        ]));
+
+        bom.retain_components(|component| component.name.to_string() == "kept");
+
+        assert_eq!(
+            bom.dependencies,
+            Some(Dependencies(vec![Dependency::new("kept", vec![])]))
+        );
+        assert_eq!(
+            bom.annotations,
+            Some(Annotations(vec![annotation(&["kept"])]))
+        );
+        assert_eq!(bom.components.map(|components| components.0.len()), Some(1));
+    }
+
+    #[test]
+    fn it_should_nest_an_assembly_and_merge_its_dependencies() {
+        let mut bom = Bom {
+            dependencies: Some(Dependencies(vec![Dependency::new(
+                "app",
+                vec!["frontend".to_string()],
+            )])),
+            ..Bom::default()
+        };
+
+        let frontend = Bom {
+            metadata: Some(Metadata {
+                component: Some(Component::new(
+                    Classification::Application,
+                    "frontend",
+                    "1.0.0",
+                    Some("frontend".to_string()),
+                )),
+                ..Metadata::default()
+            }),
+            components: Some(Components(vec![Component::new(
+                Classification::Library,
+                "react",
+                "18.2.0",
+                Some("react".to_string()),
+            )])),
+            dependencies: Some(Dependencies(vec![
+                Dependency::new("app", vec!["frontend".to_string(), "react".to_string()]),
+                Dependency::new("frontend", vec!["react".to_string()]),
+            ])),
+            ..Bom::default()
+        };
+
+        bom.add_assembly(frontend)
+            .expect("Failed to add the assembly");
+
+        let assembly = &bom.components.as_ref().expect("Missing components").0[0];
+        assert_eq!(assembly.name.to_string(), "frontend");
+        assert_eq!(
+            assembly.components.as_ref().map(|nested| nested.0.len()),
+            Some(1)
+        );
+        assert_eq!(
+            bom.dependencies,
+            Some(Dependencies(vec![
+                Dependency::new("app", vec!["frontend".to_string(), "react".to_string()]),
+                Dependency::new("frontend", vec!["react".to_string()]),
+            ]))
+        );
+
+        assert!(matches!(
+            bom.add_assembly(Bom::default()),
+            Err(BomEditError::MissingMetadataComponent)
+        ));
+    }
+
+    #[test]
+    fn it_should_merge_boms_deduplicating_their_components() {
+        let component = |name: &str, bom_ref: &str, nested: Vec<Component>| {
+            let mut component = Component::new(
+                Classification::Library,
+                name,
+                "1.0.0",
+                Some(bom_ref.to_string()),
+            );
+            component.purl = Some(Purl::new("cargo", name, "1.0.0").unwrap());
+            if !nested.is_empty() {
+                component.components = Some(Components(nested));
+            }
+            component
+        };
+        let file =
+            |name: &str| Component::new(Classification::File, name, "", Some(name.to_string()));
+        let package_bom = |root: Component, components: Vec<Component>, dependencies| Bom {
+            metadata: Some(Metadata {
+                component: Some(root),
+                ..Metadata::default()
+            }),
+            components: Some(Components(components)),
+            dependencies: Some(Dependencies(dependencies)),
+            ..Bom::default()
+        };
+
+        let tacos = package_bom(
+            component("tacos", "tacos", vec![file("tacos.rs")]),
+            vec![component("salsa", "salsa-in-tacos", vec![])],
+            vec![Dependency::new("tacos", vec!["salsa-in-tacos".to_string()])],
+        );
+        let burritos = package_bom(
+            component("burritos", "burritos", vec![]),
+            vec![
+                component("salsa", "salsa-in-burritos", vec![file("salsa.rs")]),
+                component("tacos", "tacos", vec![]),
+            ],
+            vec![Dependency::new(
+                "burritos",
+                vec!["salsa-in-burritos".to_string(), "tacos".to_string()],
+            )],
+        );
+        let mut bom = Bom {
+            metadata: Some(Metadata {
+                component: Some(component("workspace", "workspace", vec![])),
+                ..Metadata::default()
+            }),
+            ..Bom::default()
+        };
+
+        bom.merge(vec![tacos, burritos])
+            .expect("Failed to merge the BOMs");
+
+        let bom_refs = |components: &Option<Components>| -> Vec<String> {
+            components
+                .iter()
+                .flat_map(|components| components.0.iter())
+                .filter_map(|component| component.bom_ref.clone())
+                .collect()
+        };
+        let root = bom
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.component.as_ref())
+            .expect("Missing metadata component");
+        assert_eq!(bom_refs(&root.components), vec!["tacos", "burritos"]);
+        assert_eq!(
+            bom_refs(&root.components.as_ref().unwrap().0[0].components),
+            vec!["tacos.rs"]
+        );
+        assert_eq!(bom_refs(&bom.components), vec!["salsa-in-tacos"]);
+        assert_eq!(
+            bom_refs(&bom.components.as_ref().unwrap().0[0].components),
+            vec!["salsa.rs"]
+        );
+        assert_eq!(
+            bom.dependencies,
+            Some(Dependencies(vec![
+                Dependency::new("tacos", vec!["salsa-in-tacos".to_string()]),
+                Dependency::new(
+                    "burritos",
+                    vec!["salsa-in-tacos".to_string(), "tacos".to_string()]
+                ),
+            ]))
+        );
+
+        let unchanged = bom.components.as_ref().map(|components| components.0.len());
+        assert_eq!(
+            bom.merge(vec![
+                package_bom(file("lib.rs"), vec![], vec![]),
+                Bom::default()
+            ]),
+            Err(BomEditError::MissingMetadataComponent)
+        );
+        assert_eq!(
+            bom.components.as_ref().map(|components| components.0.len()),
+            unchanged
+        );
+    }
+
+    #[test]
+    fn it_should_upsert_properties_and_set_the_supplier() {
+        let mut bom = Bom::default();
+
+        bom.upsert_property("b", "1");
+        bom.upsert_property("a", "1");
+        bom.upsert_property("b", "2");
+        bom.set_supplier(OrganizationalEntity::new("Tacos Inc"));
+
+        assert_eq!(
+            bom.properties,
+            Some(Properties(vec![
+                Property::new("a", "1"),
+                Property::new("b", "2")
+            ]))
+        );
+        assert_eq!(
+            bom.metadata.and_then(|metadata| metadata.supplier),
+            Some(OrganizationalEntity::new("Tacos Inc"))
+        );
+    }
+
     #[test]
     fn valid_uuids_should_pass_validation() {
         let validation_result = UrnUuid(format!("urn:uuid:{}", uuid::Uuid::new_v4()))
@@ -972,11 +2761,364 @@ mod test {
         assert_eq!(
             validation_result,
             ValidationResult::Failed {
-                reasons: vec![FailureReason {
-                    message: "UrnUuid does not match regular expression".to_string(),
-                    context: ValidationContext::default()
-                }]
+                reasons: vec![FailureReason::error(
+                    "UrnUuid does not match regular expression",
+                    ValidationContext::default()
+                )]
+            }
+        );
+    }
+
+    #[test]
+    fn it_should_detect_the_spec_version_of_json_documents() {
+        let bom = Bom::parse_from_json(
+            r#"{ "bomFormat": "CycloneDX", "specVersion": "1.3", "version": 2 }"#.as_bytes(),
+        )
+        .expect("Failed to parse the document");
+        assert_eq!(bom.version, 2);
+
+        let bom = Bom::parse_from_json(
+            r#"{
+              "bomFormat": "CycloneDX",
+              "specVersion": "1.4",
+              "version": 3,
+              "signature": { "algorithm": "ES256", "value": "c2lnbmF0dXJl" }
+            }"#
+            .as_bytes(),
+        )
+        .expect("Failed to parse the document");
+        assert_eq!(bom.version, 3);
+        assert!(bom.signature.is_some());
+
+        let bom = Bom::parse_from_json(
+            r#"{
+              "bomFormat": "CycloneDX",
+              "specVersion": "1.5",
+              "version": 4,
+              "metadata": { "lifecycles": [{ "phase": "build" }] }
+            }"#
+            .as_bytes(),
+        )
+        .expect("Failed to parse the document");
+        assert_eq!(bom.version, 4);
+        assert!(bom.metadata.and_then(|m| m.lifecycles).is_some());
+
+        let error = Bom::parse_from_json(
+            r#"{ "bomFormat": "CycloneDX", "specVersion": "1.2", "version": 1 }"#.as_bytes(),
+        )
+        .expect_err("Should have failed");
+        assert_eq!(
+            error.to_string(),
+            "Unsupported specification version 1.2, expected one of: 1.3, 1.4, 1.5"
+        );
+
+        let error = Bom::parse_from_json(r#"{ "bomFormat": "CycloneDX" }"#.as_bytes())
+            .expect_err("Should have failed");
+        assert!(matches!(
+            error,
+            crate::errors::JsonReadError::MissingSpecVersion
+        ));
+    }
+
+    #[test]
+    fn it_should_detect_the_spec_version_of_xml_documents() {
+        let bom = Bom::parse_from_xml(
+            r#"<?xml version="1.0"?><bom xmlns="http://cyclonedx.org/schema/bom/1.3" version="2"/>"#
+                .as_bytes(),
+        )
+        .expect("Failed to parse the document");
+        assert_eq!(bom.version, 2);
+
+        let bom = Bom::parse_from_xml(
+            r#"<?xml version="1.0"?><bom xmlns="http://cyclonedx.org/schema/bom/1.4" version="3"/>"#
+                .as_bytes(),
+        )
+        .expect("Failed to parse the document");
+        assert_eq!(bom.version, 3);
+
+        let bom = Bom::parse_from_xml(
+            r#"<?xml version="1.0"?><bom xmlns="http://cyclonedx.org/schema/bom/1.5" version="4"/>"#
+                .as_bytes(),
+        )
+        .expect("Failed to parse the document");
+        assert_eq!(bom.version, 4);
+
+        let error = Bom::parse_from_xml(
+            r#"<?xml version="1.0"?><bom xmlns="http://cyclonedx.org/schema/bom/1.2" version="1"/>"#
+                .as_bytes(),
+        )
+        .expect_err("Should have failed");
+        assert!(matches!(
+            error,
+            crate::errors::XmlReadError::InvalidNamespaceError { actual_namespace: Some(namespace), .. }
+                if namespace == "http://cyclonedx.org/schema/bom/1.2"
+        ));
+    }
+
+    #[test]
+    fn it_should_read_documents_in_newer_spec_versions_with_warnings() {
+        let (bom, warnings) = Bom::parse_from_xml_with_warnings(
+            r#"<?xml version="1.0"?>
+<bom xmlns="http://cyclonedx.org/schema/bom/1.6" version="2">
+  <components>
+    <component type="library"><name>tacos</name><version>1.0.0</version></component>
+  </components>
+  <declarations><assessors><assessor bom-ref="a1"><thirdParty>true</thirdParty></assessor></assessors></declarations>
+</bom>"#
+                .as_bytes(),
+        )
+        .expect("Failed to parse the document");
+        assert_eq!(bom.version, 2);
+        assert_eq!(bom.components.map(|c| c.0.len()), Some(1));
+        assert_eq!(
+            warnings,
+            vec![
+                ParseWarning::UnsupportedVersion {
+                    found: "1.6".to_string(),
+                    parsed_as: SpecVersion::V1_5,
+                },
+                ParseWarning::Extension {
+                    path: "/bom/declarations".to_string(),
+                    content: r#"<declarations xmlns="http://cyclonedx.org/schema/bom/1.6"><assessors><assessor bom-ref="a1"><thirdParty>true</thirdParty></assessor></assessors></declarations>"#.to_string(),
+                },
+            ]
+        );
+
+        let (_, warnings) = Bom::parse_from_json_with_warnings(
+            r#"{ "bomFormat": "CycloneDX", "specVersion": "1.4", "version": 1 }"#.as_bytes(),
+        )
+        .expect("Failed to parse the document");
+        assert_eq!(warnings, Vec::new());
+
+        let error = Bom::parse_from_json_with_warnings(
+            r#"{ "bomFormat": "CycloneDX", "specVersion": "1.2", "version": 1 }"#.as_bytes(),
+        )
+        .expect_err("Should have failed");
+        assert_eq!(
+            error.to_string(),
+            "Unsupported specification version 1.2, expected one of: 1.3, 1.4, 1.5"
+        );
+
+        assert!(is_newer_spec_version("1.6"));
+        assert!(is_newer_spec_version("2.0"));
+        assert!(!is_newer_spec_version("1.5"));
+        assert!(!is_newer_spec_version("tacos"));
+    }
+
+    #[test]
+    fn it_should_buffer_the_xml_written_to_a_writer() {
+        #[derive(Default)]
+        struct CountingWriter {
+            output: Vec<u8>,
+            writes: usize,
+        }
+
+        impl std::io::Write for CountingWriter {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                self.writes += 1;
+                self.output.write(buf)
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let bom = || {
+            let mut bom = Bom {
+                serial_number: None,
+                ..Bom::default()
+            };
+            for index in 0..100 {
+                bom.add_component(Component::new(
+                    Classification::Library,
+                    &format!("crate-{}", index),
+                    "1.0.0",
+                    Some(format!("crate-{}@1.0.0", index)),
+                ))
+                .expect("Failed to add component");
+            }
+            bom
+        };
+
+        let mut expected = Vec::new();
+        bom()
+            .output_as_xml(&mut expected, SpecVersion::V1_5)
+            .expect("Failed to write BOM");
+        let actual = bom()
+            .output_as_xml_to_writer(CountingWriter::default(), SpecVersion::V1_5)
+            .expect("Failed to write BOM");
+
+        assert_eq!(actual.output, expected);
+        assert!(actual.writes <= expected.len() / 8192 + 1);
+    }
+
+    #[test]
+    fn it_should_detect_the_format_of_documents() {
+        use flate2::{write::GzEncoder, Compression};
+        use std::io::Write;
+
+        let json = r#"{ "bomFormat": "CycloneDX", "specVersion": "1.3", "version": 3 }"#;
+        let (bom, detected) = Bom::parse(json.as_bytes()).expect("Failed to parse JSON");
+        assert_eq!(bom.version, 3);
+        assert_eq!(
+            detected,
+            DetectedFormat {
+                format: DocumentFormat::Json,
+                gzip: false,
+                spec_version: SpecVersion::V1_3,
             }
         );
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder
+            .write_all(json.as_bytes())
+            .expect("Failed to compress");
+        let compressed = encoder.finish().expect("Failed to compress");
+        let (bom, detected) = Bom::parse(&compressed).expect("Failed to parse gzip");
+        assert_eq!(bom.version, 3);
+        assert_eq!(
+            detected,
+            DetectedFormat {
+                format: DocumentFormat::Json,
+                gzip: true,
+                spec_version: SpecVersion::V1_3,
+            }
+        );
+
+        assert!(matches!(
+            Bom::parse(b"\x0a\x031.3\x10\x01"),
+            Err(crate::errors::BomReadError::UnsupportedProtobuf)
+        ));
+        assert!(matches!(
+            Bom::parse(b"bomFormat: CycloneDX"),
+            Err(crate::errors::BomReadError::UnknownFormat)
+        ));
+        assert!(matches!(
+            Bom::parse(&[0x1f, 0x8b, 0x00]),
+            Err(crate::errors::BomReadError::GzipError { .. })
+        ));
+    }
+
+    #[test]
+    fn it_should_reject_documents_exceeding_the_limits() {
+        use crate::errors::{BomReadError, LimitError};
+        use flate2::{write::GzEncoder, Compression};
+        use std::io::Write;
+
+        let json = format!(
+            r#"{{ "bomFormat": "CycloneDX", "specVersion": "1.3", "version": 1, "serialNumber": "urn:uuid:{}" }}"#,
+            "0".repeat(1000)
+        );
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder
+            .write_all(json.as_bytes())
+            .expect("Failed to compress");
+        let compressed = encoder.finish().expect("Failed to compress");
+
+        let limits = ParseLimits {
+            max_input_size: Some(512),
+            ..ParseLimits::default()
+        };
+        assert!(compressed.len() < 512);
+        assert!(matches!(
+            Bom::parse_with_limits(&compressed, &limits),
+            Err(BomReadError::LimitExceeded {
+                error: LimitError::InputTooLarge { limit: 512 }
+            })
+        ));
+
+        let xml = r#"<?xml version="1.0"?><bom xmlns="http://cyclonedx.org/schema/bom/1.3" version="1"><metadata><component type="library"><name>a</name><version>1.0.0</version></component></metadata></bom>"#;
+        let limits = ParseLimits {
+            max_depth: Some(3),
+            ..ParseLimits::default()
+        };
+        assert!(matches!(
+            Bom::parse_with_limits(xml.as_bytes(), &limits),
+            Err(BomReadError::LimitExceeded {
+                error: LimitError::TooDeep { limit: 3, .. }
+            })
+        ));
+        assert!(Bom::parse_with_limits(xml.as_bytes(), &ParseLimits::default()).is_ok());
+    }
+
+    #[test]
+    fn it_should_parse_xml_documents_in_other_encodings() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-16"?><bom xmlns="http://cyclonedx.org/schema/bom/1.3" version="2"><components><component type="library"><name>Café</name><version>1.0.0</version></component></components></bom>"#;
+        let mut utf16 = vec![0xff, 0xfe];
+        utf16.extend(
+            xml.encode_utf16()
+                .flat_map(|unit| unit.to_le_bytes().to_vec()),
+        );
+
+        let (bom, detected) = Bom::parse(&utf16).expect("Failed to parse UTF-16");
+        assert_eq!(bom.version, 2);
+        assert_eq!(detected.format, DocumentFormat::Xml);
+        assert_eq!(
+            bom.components.expect("Missing components").0[0].name,
+            NormalizedString::new("Café")
+        );
+
+        let latin1: Vec<u8> = xml
+            .replace("UTF-16", "ISO-8859-1")
+            .chars()
+            .map(|c| c as u8)
+            .collect();
+        let bom = Bom::parse_from_xml_v1_3(latin1.as_slice()).expect("Failed to parse latin-1");
+        assert_eq!(
+            bom.components.expect("Missing components").0[0].name,
+            NormalizedString::new("Café")
+        );
+
+        assert!(matches!(
+            Bom::parse_from_xml(xml.as_bytes()),
+            Err(crate::errors::XmlReadError::EncodingMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn it_should_build_boms() {
+        let bom = Bom::builder()
+            .version(2)
+            .component(Component::builder(Classification::Library, "salsa", "0.2.0").build())
+            .component(
+                Component::builder(Classification::Library, "guac", "0.3.0")
+                    .bom_ref("guac")
+                    .build(),
+            )
+            .dependency(Dependency::new("guac", vec![]))
+            .property("tacos:flavor", "spicy")
+            .build()
+            .unwrap();
+
+        assert_eq!(bom.version, 2);
+        assert!(bom.serial_number.is_some());
+        let names: Vec<_> = bom
+            .components
+            .iter()
+            .flat_map(|components| &components.0)
+            .map(|component| component.name.to_string())
+            .collect();
+        assert_eq!(names, ["guac", "salsa"]);
+        assert_eq!(
+            bom.dependencies,
+            Some(Dependencies(vec![Dependency::new("guac", vec![])]))
+        );
+
+        let duplicate = Bom::builder()
+            .component(
+                Component::builder(Classification::Library, "guac", "0.3.0")
+                    .bom_ref("guac")
+                    .build(),
+            )
+            .component(
+                Component::builder(Classification::Library, "guac", "0.3.1")
+                    .bom_ref("guac")
+                    .build(),
+            )
+            .build();
+        assert!(
+            matches!(duplicate, Err(BomEditError::DuplicateBomRef(bom_ref)) if bom_ref == "guac")
+        );
     }
 }
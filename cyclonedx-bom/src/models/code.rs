@@ -243,15 +243,15 @@ pub enum IssueClassification {
     UnknownIssueClassification(String),
 }
 
-impl ToString for IssueClassification {
-    fn to_string(&self) -> String {
-        match self {
+impl std::fmt::Display for IssueClassification {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
             IssueClassification::Defect => "defect",
             IssueClassification::Enhancement => "enhancement",
             IssueClassification::Security => "security",
             IssueClassification::UnknownIssueClassification(uic) => uic,
-        }
-        .to_string()
+        };
+        f.write_str(s)
     }
 }
 
@@ -273,10 +273,10 @@ impl Validate for IssueClassification {
     ) -> Result<ValidationResult, ValidationError> {
         match self {
             IssueClassification::UnknownIssueClassification(_) => Ok(ValidationResult::Failed {
-                reasons: vec![FailureReason {
-                    message: "Unknown issue classification".to_string(),
+                reasons: vec![FailureReason::error(
+                    "Unknown issue classification",
                     context,
-                }],
+                )],
             }),
             _ => Ok(ValidationResult::Passed),
         }
@@ -357,16 +357,16 @@ pub enum PatchClassification {
     UnknownPatchClassification(String),
 }
 
-impl ToString for PatchClassification {
-    fn to_string(&self) -> String {
-        match self {
+impl std::fmt::Display for PatchClassification {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
             PatchClassification::Unofficial => "unofficial",
             PatchClassification::Monkey => "monkey",
             PatchClassification::Backport => "backport",
             PatchClassification::CherryPick => "cherry-pick",
             PatchClassification::UnknownPatchClassification(upc) => upc,
-        }
-        .to_string()
+        };
+        f.write_str(s)
     }
 }
 
@@ -389,10 +389,10 @@ impl Validate for PatchClassification {
     ) -> Result<ValidationResult, ValidationError> {
         match self {
             PatchClassification::UnknownPatchClassification(_) => Ok(ValidationResult::Failed {
-                reasons: vec![FailureReason {
-                    message: "Unknown patch classification".to_string(),
+                reasons: vec![FailureReason::error(
+                    "Unknown patch classification",
                     context,
-                }],
+                )],
             }),
             _ => Ok(ValidationResult::Passed),
         }
@@ -443,12 +443,12 @@ mod test {
             uid: Some(NormalizedString("no_whitespace".to_string())),
             url: Some(Uri("https://www.example.com".to_string())),
             author: Some(IdentifiableAction {
-                timestamp: Some(DateTime("1969-06-28T01:20:00.00-04:00".to_string())),
+                timestamp: Some("1969-06-28T01:20:00.00-04:00".parse().unwrap()),
                 name: Some(NormalizedString("Name".to_string())),
                 email: Some(NormalizedString("email@example.com".to_string())),
             }),
             committer: Some(IdentifiableAction {
-                timestamp: Some(DateTime("1969-06-28T01:20:00.00-04:00".to_string())),
+                timestamp: Some("1969-06-28T01:20:00.00-04:00".parse().unwrap()),
                 name: Some(NormalizedString("Name".to_string())),
                 email: Some(NormalizedString("email@example.com".to_string())),
             }),
@@ -466,12 +466,12 @@ mod test {
             uid: Some(NormalizedString("spaces and\ttabs".to_string())),
             url: Some(Uri("invalid uri".to_string())),
             author: Some(IdentifiableAction {
-                timestamp: Some(DateTime("Thursday".to_string())),
+                timestamp: None,
                 name: Some(NormalizedString("spaces and\ttabs".to_string())),
                 email: Some(NormalizedString("spaces and\ttabs".to_string())),
             }),
             committer: Some(IdentifiableAction {
-                timestamp: Some(DateTime("1970-01-01".to_string())),
+                timestamp: None,
                 name: Some(NormalizedString("spaces and\ttabs".to_string())),
                 email: Some(NormalizedString("spaces and\ttabs".to_string())),
             }),
@@ -484,47 +484,29 @@ mod test {
             validation_result,
             ValidationResult::Failed {
                 reasons: vec![
-                    FailureReason {
-                        message:
-                            "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
-                                .to_string(),
-                        context: ValidationContext(vec![
+                    FailureReason::error(
+                        "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Commit".to_string(),
                                 field_name: "uid".to_string()
                             }
                         ])
-                    },
-                    FailureReason {
-                        message: "Uri does not conform to ISO 8601".to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "Uri does not conform to ISO 8601",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Commit".to_string(),
                                 field_name: "url".to_string()
                             }
                         ])
-                    },
-                    FailureReason {
-                        message: "DateTime does not conform to ISO 8601".to_string(),
-                        context: ValidationContext(vec![
-                            ValidationPathComponent::Array { index: 0 },
-                            ValidationPathComponent::Struct {
-                                struct_name: "Commit".to_string(),
-                                field_name: "author".to_string()
-                            },
-                            ValidationPathComponent::Struct {
-                                struct_name: "IdentifiableAction".to_string(),
-                                field_name: "timestamp".to_string()
-                            }
-                        ])
-                    },
-                    FailureReason {
-                        message:
-                            "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
-                                .to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Commit".to_string(),
@@ -535,12 +517,10 @@ mod test {
                                 field_name: "name".to_string()
                             }
                         ])
-                    },
-                    FailureReason {
-                        message:
-                            "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
-                                .to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Commit".to_string(),
@@ -551,26 +531,10 @@ mod test {
                                 field_name: "email".to_string()
                             }
                         ])
-                    },
-                    FailureReason {
-                        message: "DateTime does not conform to ISO 8601".to_string(),
-                        context: ValidationContext(vec![
-                            ValidationPathComponent::Array { index: 0 },
-                            ValidationPathComponent::Struct {
-                                struct_name: "Commit".to_string(),
-                                field_name: "committer".to_string()
-                            },
-                            ValidationPathComponent::Struct {
-                                struct_name: "IdentifiableAction".to_string(),
-                                field_name: "timestamp".to_string()
-                            }
-                        ])
-                    },
-                    FailureReason {
-                        message:
-                            "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
-                                .to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Commit".to_string(),
@@ -581,12 +545,10 @@ mod test {
                                 field_name: "name".to_string()
                             }
                         ])
-                    },
-                    FailureReason {
-                        message:
-                            "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
-                                .to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Commit".to_string(),
@@ -597,19 +559,17 @@ mod test {
                                 field_name: "email".to_string()
                             }
                         ])
-                    },
-                    FailureReason {
-                        message:
-                            "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
-                                .to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Commit".to_string(),
                                 field_name: "message".to_string()
                             },
                         ])
-                    },
+                    ),
                 ]
             }
         );
@@ -676,21 +636,19 @@ mod test {
             validation_result,
             ValidationResult::Failed {
                 reasons: vec![
-                    FailureReason {
-                        message: "Unknown patch classification".to_string(),
-                        context: ValidationContext(vec![
+                    FailureReason::error(
+                        "Unknown patch classification",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Patch".to_string(),
                                 field_name: "patch_type".to_string()
                             },
                         ])
-                    },
-                    FailureReason {
-                        message:
-                            "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
-                                .to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Patch".to_string(),
@@ -705,10 +663,10 @@ mod test {
                                 field_name: "content_type".to_string()
                             }
                         ])
-                    },
-                    FailureReason {
-                        message: "Uri does not conform to ISO 8601".to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "Uri does not conform to ISO 8601",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Patch".to_string(),
@@ -719,10 +677,10 @@ mod test {
                                 field_name: "url".to_string()
                             },
                         ])
-                    },
-                    FailureReason {
-                        message: "Unknown issue classification".to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "Unknown issue classification",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Patch".to_string(),
@@ -734,12 +692,10 @@ mod test {
                                 field_name: "issue_type".to_string()
                             },
                         ])
-                    },
-                    FailureReason {
-                        message:
-                            "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
-                                .to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Patch".to_string(),
@@ -751,12 +707,10 @@ mod test {
                                 field_name: "id".to_string()
                             },
                         ])
-                    },
-                    FailureReason {
-                        message:
-                            "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
-                                .to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Patch".to_string(),
@@ -768,12 +722,10 @@ mod test {
                                 field_name: "name".to_string()
                             },
                         ])
-                    },
-                    FailureReason {
-                        message:
-                            "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
-                                .to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Patch".to_string(),
@@ -785,12 +737,10 @@ mod test {
                                 field_name: "description".to_string()
                             },
                         ])
-                    },
-                    FailureReason {
-                        message:
-                            "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
-                                .to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Patch".to_string(),
@@ -806,10 +756,10 @@ mod test {
                                 field_name: "name".to_string()
                             },
                         ])
-                    },
-                    FailureReason {
-                        message: "Uri does not conform to ISO 8601".to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "Uri does not conform to ISO 8601",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Patch".to_string(),
@@ -825,10 +775,10 @@ mod test {
                                 field_name: "url".to_string()
                             },
                         ])
-                    },
-                    FailureReason {
-                        message: "Uri does not conform to ISO 8601".to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "Uri does not conform to ISO 8601",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Patch".to_string(),
@@ -841,7 +791,7 @@ mod test {
                             },
                             ValidationPathComponent::Array { index: 0 }
                         ])
-                    },
+                    ),
                 ]
             }
         );
@@ -21,21 +21,23 @@ use regex::Regex;
 
 use crate::models::attached_text::AttachedText;
 use crate::models::code::{Commits, Patches};
-use crate::models::external_reference::ExternalReferences;
-use crate::models::hash::Hashes;
-use crate::models::license::Licenses;
+use crate::models::component_data::ComponentData;
+use crate::models::external_reference::{ExternalReference, ExternalReferences};
+use crate::models::hash::{Hash, Hashes};
+use crate::models::license::{LicenseChoice, Licenses};
+use crate::models::model_card::ModelCard;
 use crate::models::organization::OrganizationalEntity;
 use crate::models::property::Properties;
+use crate::models::release_notes::ReleaseNotes;
+use crate::models::signature::Signature;
 use crate::validation::{FailureReason, ValidationPathComponent};
 use crate::{
-    external_models::{
-        normalized_string::NormalizedString,
-        uri::{Purl, Uri},
-    },
+    external_models::{normalized_string::NormalizedString, purl::Purl, uri::Uri},
     validation::{Validate, ValidationContext, ValidationError, ValidationResult},
 };
 
 #[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub struct Component {
     pub component_type: Classification,
     pub mime_type: Option<MimeType>,
@@ -60,8 +62,39 @@ pub struct Component {
     pub properties: Option<Properties>,
     pub components: Option<Components>,
     pub evidence: Option<ComponentEvidence>,
+    pub release_notes: Option<ReleaseNotes>,
+    pub signature: Option<Signature>,
+    pub model_card: Option<ModelCard>,
+    pub data: Option<Vec<ComponentData>>,
 }
 
+with_setters!(Component {
+    with_mime_type => mime_type: MimeType,
+    with_bom_ref => bom_ref: String,
+    with_supplier => supplier: OrganizationalEntity,
+    with_author => author: NormalizedString,
+    with_publisher => publisher: NormalizedString,
+    with_group => group: NormalizedString,
+    with_description => description: NormalizedString,
+    with_scope => scope: Scope,
+    with_hashes => hashes: Hashes,
+    with_licenses => licenses: Licenses,
+    with_copyright => copyright: NormalizedString,
+    with_cpe => cpe: Cpe,
+    with_purl => purl: Purl,
+    with_swid => swid: Swid,
+    with_modified => modified: bool,
+    with_pedigree => pedigree: Pedigree,
+    with_external_references => external_references: ExternalReferences,
+    with_properties => properties: Properties,
+    with_components => components: Components,
+    with_evidence => evidence: ComponentEvidence,
+    with_release_notes => release_notes: ReleaseNotes,
+    with_signature => signature: Signature,
+    with_model_card => model_card: ModelCard,
+    with_data => data: Vec<ComponentData>,
+});
+
 impl Component {
     pub fn new(
         component_type: Classification,
@@ -93,7 +126,161 @@ impl Component {
             properties: None,
             components: None,
             evidence: None,
+            release_notes: None,
+            signature: None,
+            model_card: None,
+            data: None,
+        }
+    }
+
+    /// Adds `hash`, replacing an existing hash with the same algorithm and keeping the hashes
+    /// sorted by algorithm
+    /// ```
+    /// use cyclonedx_bom::models::component::{Classification, Component};
+    /// use cyclonedx_bom::models::hash::{Hash, HashAlgorithm};
+    ///
+    /// let mut component = Component::new(Classification::Library, "tacos", "1.0.0", None);
+    /// component.add_hash(Hash::new(HashAlgorithm::SHA256, "0123"));
+    /// ```
+    pub fn add_hash(&mut self, hash: Hash) {
+        let hashes = &mut self.hashes.get_or_insert_with(|| Hashes(Vec::new())).0;
+        let alg = hash.alg.to_string();
+
+        match hashes.iter_mut().find(|existing| existing.alg == hash.alg) {
+            Some(existing) => *existing = hash,
+            None => {
+                let index = hashes.partition_point(|existing| existing.alg.to_string() < alg);
+                hashes.insert(index, hash);
+            }
+        }
+    }
+
+    /// Sets the value of the property called `name`, see [`Properties::upsert`]
+    pub fn upsert_property(&mut self, name: &str, value: &str) {
+        self.properties
+            .get_or_insert_with(|| Properties(Vec::new()))
+            .upsert(name, value);
+    }
+
+    /// Starts building a component of the type, name and version every component needs
+    /// ```
+    /// use cyclonedx_bom::external_models::purl::Purl;
+    /// use cyclonedx_bom::models::component::{Classification, Component};
+    ///
+    /// let component = Component::builder(Classification::Library, "tacos", "1.0.0")
+    ///     .purl(Purl::new("cargo", "tacos", "1.0.0")?)
+    ///     .description("Delicious tacos")
+    ///     .build();
+    /// assert_eq!(component.bom_ref.as_deref(), Some("pkg:cargo/tacos@1.0.0"));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn builder(component_type: Classification, name: &str, version: &str) -> ComponentBuilder {
+        ComponentBuilder {
+            component: Component::new(component_type, name, version, None),
+        }
+    }
+}
+
+/// Builds a [`Component`], adding hashes, licenses, external references, properties and nested
+/// components one at a time
+///
+/// A component with a Package URL but without a `bom-ref` gets the Package URL as its `bom-ref`.
+#[derive(Debug)]
+pub struct ComponentBuilder {
+    component: Component,
+}
+
+impl ComponentBuilder {
+    pub fn bom_ref(mut self, bom_ref: &str) -> Self {
+        self.component.bom_ref = Some(bom_ref.to_string());
+        self
+    }
+
+    pub fn supplier(mut self, supplier: OrganizationalEntity) -> Self {
+        self.component.supplier = Some(supplier);
+        self
+    }
+
+    pub fn author(mut self, author: &str) -> Self {
+        self.component.author = Some(NormalizedString::new(author));
+        self
+    }
+
+    pub fn publisher(mut self, publisher: &str) -> Self {
+        self.component.publisher = Some(NormalizedString::new(publisher));
+        self
+    }
+
+    pub fn group(mut self, group: &str) -> Self {
+        self.component.group = Some(NormalizedString::new(group));
+        self
+    }
+
+    pub fn description(mut self, description: &str) -> Self {
+        self.component.description = Some(NormalizedString::new(description));
+        self
+    }
+
+    pub fn scope(mut self, scope: Scope) -> Self {
+        self.component.scope = Some(scope);
+        self
+    }
+
+    pub fn copyright(mut self, copyright: &str) -> Self {
+        self.component.copyright = Some(NormalizedString::new(copyright));
+        self
+    }
+
+    pub fn purl(mut self, purl: Purl) -> Self {
+        self.component.purl = Some(purl);
+        self
+    }
+
+    /// Adds `hash`, replacing a hash with the same algorithm, see [`Component::add_hash`]
+    pub fn hash(mut self, hash: Hash) -> Self {
+        self.component.add_hash(hash);
+        self
+    }
+
+    pub fn license(mut self, license: LicenseChoice) -> Self {
+        self.component
+            .licenses
+            .get_or_insert_with(|| Licenses(Vec::new()))
+            .0
+            .push(license);
+        self
+    }
+
+    pub fn external_reference(mut self, external_reference: ExternalReference) -> Self {
+        self.component
+            .external_references
+            .get_or_insert_with(|| ExternalReferences(Vec::new()))
+            .0
+            .push(external_reference);
+        self
+    }
+
+    /// Sets the value of the property called `name`, see [`Properties::upsert`]
+    pub fn property(mut self, name: &str, value: &str) -> Self {
+        self.component.upsert_property(name, value);
+        self
+    }
+
+    /// Adds `component` to the nested components
+    pub fn component(mut self, component: Component) -> Self {
+        self.component
+            .components
+            .get_or_insert_with(|| Components(Vec::new()))
+            .0
+            .push(component);
+        self
+    }
+
+    pub fn build(mut self) -> Component {
+        if self.component.bom_ref.is_none() {
+            self.component.bom_ref = self.component.purl.as_ref().map(ToString::to_string);
         }
+        self.component
     }
 }
 
@@ -122,6 +309,13 @@ impl Validate for Component {
             let context = context.extend_context_with_struct_field("Component", "supplier");
 
             results.push(supplier.validate_with_context(context)?);
+        } else {
+            let context = context.extend_context_with_struct_field("Component", "supplier");
+
+            results.push(ValidationResult::from_reasons(vec![FailureReason::info(
+                "Missing supplier",
+                context,
+            )]));
         }
 
         if let Some(author) = &self.author {
@@ -198,6 +392,14 @@ impl Validate for Component {
             results.push(swid.validate_with_context(context)?);
         }
 
+        if self.cpe.is_none() && self.purl.is_none() && self.swid.is_none() {
+            let context = context.extend_context_with_struct_field("Component", "purl");
+
+            results.push(ValidationResult::from_reasons(vec![
+                FailureReason::warning("Missing Package URL, CPE or SWID identifier", context),
+            ]));
+        }
+
         if let Some(pedigree) = &self.pedigree {
             let context = context.extend_context_with_struct_field("Component", "pedigree");
 
@@ -229,6 +431,37 @@ impl Validate for Component {
             results.push(evidence.validate_with_context(context)?);
         }
 
+        if let Some(release_notes) = &self.release_notes {
+            let context = context.extend_context_with_struct_field("Component", "release_notes");
+
+            results.push(release_notes.validate_with_context(context)?);
+        }
+
+        if let Some(signature) = &self.signature {
+            let context = context.extend_context_with_struct_field("Component", "signature");
+
+            results.push(signature.validate_with_context(context)?);
+        }
+
+        if let Some(model_card) = &self.model_card {
+            let context = context.extend_context_with_struct_field("Component", "model_card");
+
+            results.push(model_card.validate_with_context(context)?);
+        }
+
+        if let Some(data) = &self.data {
+            for (index, data) in data.iter().enumerate() {
+                let context = context.extend_context(vec![
+                    ValidationPathComponent::Struct {
+                        struct_name: "Component".to_string(),
+                        field_name: "data".to_string(),
+                    },
+                    ValidationPathComponent::Array { index },
+                ]);
+                results.push(data.validate_with_context(context)?);
+            }
+        }
+
         Ok(results
             .into_iter()
             .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
@@ -266,13 +499,21 @@ pub enum Classification {
     Device,
     Firmware,
     File,
+    /// Added in version 1.5 of the specification
+    Platform,
+    /// Added in version 1.5 of the specification
+    DeviceDriver,
+    /// Added in version 1.5 of the specification
+    MachineLearningModel,
+    /// Added in version 1.5 of the specification
+    Data,
     #[doc(hidden)]
     UnknownClassification(String),
 }
 
-impl ToString for Classification {
-    fn to_string(&self) -> String {
-        match self {
+impl std::fmt::Display for Classification {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
             Classification::Application => "application",
             Classification::Framework => "framework",
             Classification::Library => "library",
@@ -281,9 +522,13 @@ impl ToString for Classification {
             Classification::Device => "device",
             Classification::Firmware => "firmware",
             Classification::File => "file",
+            Classification::Platform => "platform",
+            Classification::DeviceDriver => "device-driver",
+            Classification::MachineLearningModel => "machine-learning-model",
+            Classification::Data => "data",
             Classification::UnknownClassification(uc) => uc,
-        }
-        .to_string()
+        };
+        f.write_str(s)
     }
 }
 
@@ -298,6 +543,10 @@ impl Classification {
             "device" => Self::Device,
             "firmware" => Self::Firmware,
             "file" => Self::File,
+            "platform" => Self::Platform,
+            "device-driver" => Self::DeviceDriver,
+            "machine-learning-model" => Self::MachineLearningModel,
+            "data" => Self::Data,
             unknown => Self::UnknownClassification(unknown.to_string()),
         }
     }
@@ -310,10 +559,7 @@ impl Validate for Classification {
     ) -> Result<ValidationResult, ValidationError> {
         match self {
             Classification::UnknownClassification(_) => Ok(ValidationResult::Failed {
-                reasons: vec![FailureReason {
-                    message: "Unknown classification".to_string(),
-                    context,
-                }],
+                reasons: vec![FailureReason::error("Unknown classification", context)],
             }),
             _ => Ok(ValidationResult::Passed),
         }
@@ -329,15 +575,15 @@ pub enum Scope {
     UnknownScope(String),
 }
 
-impl ToString for Scope {
-    fn to_string(&self) -> String {
-        match self {
+impl std::fmt::Display for Scope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
             Scope::Required => "required",
             Scope::Optional => "optional",
             Scope::Excluded => "excluded",
             Scope::UnknownScope(us) => us,
-        }
-        .to_string()
+        };
+        f.write_str(s)
     }
 }
 
@@ -359,10 +605,7 @@ impl Validate for Scope {
     ) -> Result<ValidationResult, ValidationError> {
         match self {
             Scope::UnknownScope(_) => Ok(ValidationResult::Failed {
-                reasons: vec![FailureReason {
-                    message: "Unknown scope".to_string(),
-                    context,
-                }],
+                reasons: vec![FailureReason::error("Unknown scope", context)],
             }),
             _ => Ok(ValidationResult::Passed),
         }
@@ -386,10 +629,10 @@ impl Validate for MimeType {
                     Ok(ValidationResult::Passed)
                 } else {
                     Ok(ValidationResult::Failed {
-                        reasons: vec![FailureReason {
-                            message: "MimeType does not match regular expression".to_string(),
+                        reasons: vec![FailureReason::error(
+                            "MimeType does not match regular expression",
                             context,
-                        }],
+                        )],
                     })
                 }
             }
@@ -453,10 +696,10 @@ impl Validate for Cpe {
                     Ok(ValidationResult::Passed)
                 } else {
                     Ok(ValidationResult::Failed {
-                        reasons: vec![FailureReason {
-                            message: "Cpe does not match regular expression".to_string(),
+                        reasons: vec![FailureReason::error(
+                            "Cpe does not match regular expression",
                             context,
-                        }],
+                        )],
                     })
                 }
             }
@@ -595,12 +838,67 @@ mod test {
             license::LicenseChoice,
             property::Property,
         },
-        validation::ValidationPathComponent,
+        validation::{Severity, ValidationOptions, ValidationPathComponent},
     };
 
     use super::*;
     use pretty_assertions::assert_eq;
 
+    #[test]
+    fn it_should_replace_hashes_with_the_same_algorithm() {
+        let mut component = Component::new(Classification::Library, "tacos", "1.0.0", None);
+
+        component.add_hash(Hash::new(HashAlgorithm::SHA256, "01"));
+        component.add_hash(Hash::new(HashAlgorithm::MD5, "02"));
+        component.add_hash(Hash::new(HashAlgorithm::SHA256, "03"));
+
+        assert_eq!(
+            component.hashes,
+            Some(Hashes(vec![
+                Hash::new(HashAlgorithm::MD5, "02"),
+                Hash::new(HashAlgorithm::SHA256, "03")
+            ]))
+        );
+    }
+
+    #[test]
+    fn it_should_report_missing_identifiers_and_suppliers_below_errors() {
+        let component = Component::new(Classification::Library, "tacos", "1.0.0", None);
+
+        assert_eq!(
+            component.validate().expect("Failed to validate"),
+            ValidationResult::Passed
+        );
+
+        let reason = |message: &str, field_name: &str, severity| {
+            FailureReason::new(
+                message,
+                ValidationContext(vec![ValidationPathComponent::Struct {
+                    struct_name: "Component".to_string(),
+                    field_name: field_name.to_string(),
+                }]),
+                severity,
+            )
+        };
+        assert_eq!(
+            component
+                .validate_with_options(ValidationOptions {
+                    minimum_severity: Severity::Info,
+                })
+                .expect("Failed to validate"),
+            ValidationResult::PassedWithWarnings {
+                reasons: vec![
+                    reason("Missing supplier", "supplier", Severity::Info),
+                    reason(
+                        "Missing Package URL, CPE or SWID identifier",
+                        "purl",
+                        Severity::Warning
+                    ),
+                ]
+            }
+        );
+    }
+
     #[test]
     fn valid_components_should_pass_validation() {
         let validation_result = Components(vec![Component {
@@ -678,6 +976,10 @@ mod test {
                 ))])),
                 copyright: Some(CopyrightTexts(vec![Copyright("copyright".to_string())])),
             }),
+            release_notes: None,
+            signature: None,
+            data: None,
+            model_card: None,
         }])
         .validate_with_context(ValidationContext::default())
         .expect("Error while validating");
@@ -766,39 +1068,41 @@ mod test {
                 ))])),
                 copyright: Some(CopyrightTexts(vec![Copyright("copyright".to_string())])),
             }),
+            release_notes: None,
+            signature: None,
+            data: None,
+            model_card: None,
         }])
-        .validate_with_context(ValidationContext::default())
+        .validate()
         .expect("Error while validating");
 
         assert_eq!(
             validation_result,
             ValidationResult::Failed {
                 reasons: vec![
-                    FailureReason {
-                        message: "Unknown classification".to_string(),
-                        context: ValidationContext(vec![
+                    FailureReason::error(
+                        "Unknown classification",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Component".to_string(),
                                 field_name: "component_type".to_string()
                             },
                         ])
-                    },
-                    FailureReason {
-                        message: "MimeType does not match regular expression".to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "MimeType does not match regular expression",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Component".to_string(),
                                 field_name: "mime_type".to_string()
                             },
                         ])
-                    },
-                    FailureReason {
-                        message:
-                            "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
-                                .to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Component".to_string(),
@@ -809,92 +1113,80 @@ mod test {
                                 field_name: "name".to_string()
                             },
                         ])
-                    },
-                    FailureReason {
-                        message:
-                            "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
-                                .to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Component".to_string(),
                                 field_name: "author".to_string()
                             },
                         ])
-                    },
-                    FailureReason {
-                        message:
-                            "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
-                                .to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Component".to_string(),
                                 field_name: "publisher".to_string()
                             },
                         ])
-                    },
-                    FailureReason {
-                        message:
-                            "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
-                                .to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Component".to_string(),
                                 field_name: "group".to_string()
                             },
                         ])
-                    },
-                    FailureReason {
-                        message:
-                            "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
-                                .to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Component".to_string(),
                                 field_name: "name".to_string()
                             },
                         ])
-                    },
-                    FailureReason {
-                        message:
-                            "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
-                                .to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Component".to_string(),
                                 field_name: "version".to_string()
                             },
                         ])
-                    },
-                    FailureReason {
-                        message:
-                            "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
-                                .to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Component".to_string(),
                                 field_name: "description".to_string()
                             },
                         ])
-                    },
-                    FailureReason {
-                        message: "Unknown scope".to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "Unknown scope",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Component".to_string(),
                                 field_name: "scope".to_string()
                             },
                         ])
-                    },
-                    FailureReason {
-                        message: "HashValue does not match regular expression".to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "HashValue does not match regular expression",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Component".to_string(),
@@ -906,10 +1198,10 @@ mod test {
                                 field_name: "content".to_string()
                             }
                         ])
-                    },
-                    FailureReason {
-                        message: "SPDX expression is not valid".to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "SPDX expression is not valid",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Component".to_string(),
@@ -920,45 +1212,40 @@ mod test {
                                 variant_name: "Expression".to_string()
                             },
                         ])
-                    },
-                    FailureReason {
-                        message:
-                            "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
-                                .to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Component".to_string(),
                                 field_name: "copyright".to_string()
                             },
                         ])
-                    },
-                    FailureReason {
-                        message: "Cpe does not match regular expression".to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "Cpe does not match regular expression",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Component".to_string(),
                                 field_name: "cpe".to_string()
                             },
                         ])
-                    },
-                    FailureReason {
-                        message: "Purl does not conform to Package URL spec: missing scheme"
-                            .to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "Purl does not conform to Package URL spec: missing scheme",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Component".to_string(),
                                 field_name: "purl".to_string()
                             },
                         ])
-                    },
-                    FailureReason {
-                        message:
-                            "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
-                                .to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Component".to_string(),
@@ -973,10 +1260,10 @@ mod test {
                                 field_name: "content_type".to_string()
                             },
                         ])
-                    },
-                    FailureReason {
-                        message: "Uri does not conform to ISO 8601".to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "Uri does not conform to ISO 8601",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Component".to_string(),
@@ -987,10 +1274,10 @@ mod test {
                                 field_name: "url".to_string()
                             },
                         ])
-                    },
-                    FailureReason {
-                        message: "Unknown classification".to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "Unknown classification",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Component".to_string(),
@@ -1006,10 +1293,10 @@ mod test {
                                 field_name: "component_type".to_string()
                             }
                         ])
-                    },
-                    FailureReason {
-                        message: "Unknown classification".to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "Unknown classification",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Component".to_string(),
@@ -1025,10 +1312,10 @@ mod test {
                                 field_name: "component_type".to_string()
                             }
                         ])
-                    },
-                    FailureReason {
-                        message: "Unknown classification".to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "Unknown classification",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Component".to_string(),
@@ -1044,12 +1331,10 @@ mod test {
                                 field_name: "component_type".to_string()
                             }
                         ])
-                    },
-                    FailureReason {
-                        message:
-                            "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
-                                .to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Component".to_string(),
@@ -1065,10 +1350,10 @@ mod test {
                                 field_name: "uid".to_string()
                             }
                         ])
-                    },
-                    FailureReason {
-                        message: "Unknown patch classification".to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "Unknown patch classification",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Component".to_string(),
@@ -1084,10 +1369,10 @@ mod test {
                                 field_name: "patch_type".to_string()
                             }
                         ])
-                    },
-                    FailureReason {
-                        message: "Unknown external reference type".to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "Unknown external reference type",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Component".to_string(),
@@ -1099,12 +1384,10 @@ mod test {
                                 field_name: "external_reference_type".to_string()
                             }
                         ])
-                    },
-                    FailureReason {
-                        message:
-                            "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
-                                .to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Component".to_string(),
@@ -1116,10 +1399,10 @@ mod test {
                                 field_name: "value".to_string()
                             }
                         ])
-                    },
-                    FailureReason {
-                        message: "Unknown classification".to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "Unknown classification",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Component".to_string(),
@@ -1131,10 +1414,10 @@ mod test {
                                 field_name: "component_type".to_string()
                             }
                         ])
-                    },
-                    FailureReason {
-                        message: "SPDX expression is not valid".to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "SPDX expression is not valid",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Component".to_string(),
@@ -1149,7 +1432,7 @@ mod test {
                                 variant_name: "Expression".to_string()
                             },
                         ])
-                    },
+                    ),
                 ]
             }
         );
@@ -1180,6 +1463,56 @@ mod test {
             properties: None,
             components: None,
             evidence: None,
+            release_notes: None,
+            signature: None,
+            data: None,
+            model_card: None,
         }
     }
+
+    #[test]
+    fn it_should_build_components() {
+        let nested = Component::builder(Classification::File, "src/main.rs", "").build();
+        let component = Component::builder(Classification::Library, "tacos", "1.0.0")
+            .group("tortillas")
+            .scope(Scope::Required)
+            .purl(Purl::new("cargo", "tacos", "1.0.0").unwrap())
+            .hash(Hash::new(HashAlgorithm::SHA256, "0123"))
+            .hash(Hash::new(HashAlgorithm::SHA1, "abcd"))
+            .license(LicenseChoice::Expression(SpdxExpression("MIT".to_string())))
+            .external_reference(ExternalReference::new(
+                ExternalReferenceType::Website,
+                Uri("https://tacos.example.com".to_string()),
+            ))
+            .property("cargo:edition", "2018")
+            .component(nested)
+            .build();
+
+        assert_eq!(component.bom_ref.as_deref(), Some("pkg:cargo/tacos@1.0.0"));
+        assert_eq!(component.group, Some(NormalizedString::new("tortillas")));
+        assert_eq!(
+            component.hashes,
+            Some(Hashes(vec![
+                Hash::new(HashAlgorithm::SHA1, "abcd"),
+                Hash::new(HashAlgorithm::SHA256, "0123"),
+            ]))
+        );
+        assert_eq!(component.licenses.map(|licenses| licenses.0.len()), Some(1));
+        assert_eq!(
+            component.properties,
+            Some(Properties(vec![Property::new("cargo:edition", "2018")]))
+        );
+        assert_eq!(
+            component
+                .components
+                .map(|components| components.0[0].component_type.to_string()),
+            Some("file".to_string())
+        );
+
+        let component = Component::builder(Classification::Library, "tacos", "1.0.0")
+            .bom_ref("tacos")
+            .purl(Purl::new("cargo", "tacos", "1.0.0").unwrap())
+            .build();
+        assert_eq!(component.bom_ref.as_deref(), Some("tacos"));
+    }
 }
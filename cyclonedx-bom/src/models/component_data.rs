@@ -0,0 +1,306 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use crate::external_models::uri::Uri;
+use crate::models::attached_text::AttachedText;
+use crate::models::property::Properties;
+use crate::validation::{
+    FailureReason, Validate, ValidationContext, ValidationError, ValidationPathComponent,
+    ValidationResult,
+};
+
+/// The data of a component, such as the dataset of a machine learning model, added in version
+/// 1.5 of the specification
+///
+/// Defined via the [XML schema](https://cyclonedx.org/docs/1.5/xml/#type_componentDataType).
+/// The governance of the data is not modelled.
+#[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ComponentData {
+    pub bom_ref: Option<String>,
+    pub data_type: ComponentDataType,
+    pub name: Option<String>,
+    pub contents: Option<DataContents>,
+    pub classification: Option<String>,
+    pub sensitive_data: Option<Vec<String>>,
+    pub graphics: Option<GraphicsCollection>,
+    pub description: Option<String>,
+}
+
+with_setters!(ComponentData {
+    with_bom_ref => bom_ref: String,
+    with_name => name: String,
+    with_contents => contents: DataContents,
+    with_classification => classification: String,
+    with_sensitive_data => sensitive_data: Vec<String>,
+    with_graphics => graphics: GraphicsCollection,
+    with_description => description: String,
+});
+
+impl ComponentData {
+    /// Construct `ComponentData` of a type
+    /// ```
+    /// use cyclonedx_bom::models::component_data::{ComponentData, ComponentDataType};
+    ///
+    /// let data = ComponentData::new(ComponentDataType::Dataset)
+    ///     .with_name("Training Data".to_string());
+    /// ```
+    pub fn new(data_type: ComponentDataType) -> Self {
+        Self {
+            bom_ref: None,
+            data_type,
+            name: None,
+            contents: None,
+            classification: None,
+            sensitive_data: None,
+            graphics: None,
+            description: None,
+        }
+    }
+}
+
+impl Validate for ComponentData {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        let data_type_context =
+            context.extend_context_with_struct_field("ComponentData", "data_type");
+        results.push(self.data_type.validate_with_context(data_type_context)?);
+
+        if let Some(contents) = &self.contents {
+            let context = context.extend_context_with_struct_field("ComponentData", "contents");
+
+            results.push(contents.validate_with_context(context)?);
+        }
+
+        if let Some(graphics) = &self.graphics {
+            let context = context.extend_context_with_struct_field("ComponentData", "graphics");
+
+            results.push(graphics.validate_with_context(context)?);
+        }
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ComponentDataType {
+    SourceCode,
+    Configuration,
+    Dataset,
+    Definition,
+    Other,
+    #[doc(hidden)]
+    UnknownComponentDataType(String),
+}
+
+impl std::fmt::Display for ComponentDataType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            ComponentDataType::SourceCode => "source-code",
+            ComponentDataType::Configuration => "configuration",
+            ComponentDataType::Dataset => "dataset",
+            ComponentDataType::Definition => "definition",
+            ComponentDataType::Other => "other",
+            ComponentDataType::UnknownComponentDataType(ut) => ut,
+        };
+        f.write_str(s)
+    }
+}
+
+impl ComponentDataType {
+    pub(crate) fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
+        match value.as_ref() {
+            "source-code" => Self::SourceCode,
+            "configuration" => Self::Configuration,
+            "dataset" => Self::Dataset,
+            "definition" => Self::Definition,
+            "other" => Self::Other,
+            unknown => Self::UnknownComponentDataType(unknown.to_string()),
+        }
+    }
+}
+
+impl Validate for ComponentDataType {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        match self {
+            ComponentDataType::UnknownComponentDataType(_) => Ok(ValidationResult::Failed {
+                reasons: vec![FailureReason::error("Unknown component data type", context)],
+            }),
+            _ => Ok(ValidationResult::Passed),
+        }
+    }
+}
+
+/// The contents of the data, attached to the BOM or referenced by its URL
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct DataContents {
+    pub attachment: Option<AttachedText>,
+    pub url: Option<Uri>,
+    pub properties: Option<Properties>,
+}
+
+impl Validate for DataContents {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        if let Some(attachment) = &self.attachment {
+            let context = context.extend_context_with_struct_field("DataContents", "attachment");
+
+            results.push(attachment.validate_with_context(context)?);
+        }
+
+        if let Some(url) = &self.url {
+            let context = context.extend_context_with_struct_field("DataContents", "url");
+
+            results.push(url.validate_with_context(context)?);
+        }
+
+        if let Some(properties) = &self.properties {
+            let context = context.extend_context_with_struct_field("DataContents", "properties");
+
+            results.push(properties.validate_with_context(context)?);
+        }
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+/// A collection of graphics that describe the data or the performance of a model
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct GraphicsCollection {
+    pub description: Option<String>,
+    pub collection: Option<Vec<Graphic>>,
+}
+
+impl Validate for GraphicsCollection {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        for (index, graphic) in self.collection.iter().flatten().enumerate() {
+            let context = context.extend_context(vec![
+                ValidationPathComponent::Struct {
+                    struct_name: "GraphicsCollection".to_string(),
+                    field_name: "collection".to_string(),
+                },
+                ValidationPathComponent::Array { index },
+            ]);
+            results.push(graphic.validate_with_context(context)?);
+        }
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Graphic {
+    pub name: Option<String>,
+    pub image: Option<AttachedText>,
+}
+
+impl Validate for Graphic {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        match &self.image {
+            Some(image) => {
+                let context = context.extend_context_with_struct_field("Graphic", "image");
+
+                image.validate_with_context(context)
+            }
+            None => Ok(ValidationResult::Passed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn valid_component_data_should_pass_validation() {
+        let validation_result = ComponentData::new(ComponentDataType::Dataset)
+            .with_contents(DataContents {
+                url: Some(Uri("https://example.com/training-data".to_string())),
+                ..DataContents::default()
+            })
+            .validate_with_context(ValidationContext::default())
+            .expect("Error while validating");
+
+        assert_eq!(validation_result, ValidationResult::Passed);
+    }
+
+    #[test]
+    fn invalid_component_data_should_fail_validation() {
+        let validation_result = ComponentData::new(ComponentDataType::new_unchecked("recipes"))
+            .with_contents(DataContents {
+                url: Some(Uri("invalid uri".to_string())),
+                ..DataContents::default()
+            })
+            .validate_with_context(ValidationContext::default())
+            .expect("Error while validating");
+
+        assert_eq!(
+            validation_result,
+            ValidationResult::Failed {
+                reasons: vec![
+                    FailureReason::error(
+                        "Unknown component data type",
+                        ValidationContext(vec![ValidationPathComponent::Struct {
+                            struct_name: "ComponentData".to_string(),
+                            field_name: "data_type".to_string(),
+                        }])
+                    ),
+                    FailureReason::error(
+                        "Uri does not conform to ISO 8601",
+                        ValidationContext(vec![
+                            ValidationPathComponent::Struct {
+                                struct_name: "ComponentData".to_string(),
+                                field_name: "contents".to_string(),
+                            },
+                            ValidationPathComponent::Struct {
+                                struct_name: "DataContents".to_string(),
+                                field_name: "url".to_string(),
+                            },
+                        ])
+                    ),
+                ]
+            }
+        );
+    }
+}
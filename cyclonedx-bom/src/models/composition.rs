@@ -22,12 +22,35 @@ use crate::validation::{
 };
 
 #[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub struct Composition {
     pub aggregate: AggregateType,
     pub assemblies: Option<Vec<BomReference>>,
     pub dependencies: Option<Vec<BomReference>>,
 }
 
+with_setters!(Composition {
+    with_assemblies => assemblies: Vec<BomReference>,
+    with_dependencies => dependencies: Vec<BomReference>,
+});
+
+impl Composition {
+    /// Constructs a `Composition` with the given completeness
+    /// ```
+    /// use cyclonedx_bom::models::composition::{AggregateType, BomReference, Composition};
+    ///
+    /// let composition = Composition::new(AggregateType::Complete)
+    ///     .with_assemblies(vec![BomReference::new("component-a")]);
+    /// ```
+    pub fn new(aggregate: AggregateType) -> Self {
+        Self {
+            aggregate,
+            assemblies: None,
+            dependencies: None,
+        }
+    }
+}
+
 impl Validate for Composition {
     fn validate_with_context(
         &self,
@@ -80,9 +103,9 @@ pub enum AggregateType {
     UnknownAggregateType(String),
 }
 
-impl ToString for AggregateType {
-    fn to_string(&self) -> String {
-        match self {
+impl std::fmt::Display for AggregateType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
             AggregateType::Complete => "complete",
             AggregateType::Incomplete => "incomplete",
             AggregateType::IncompleteFirstPartyOnly => "incomplete_first_party_only",
@@ -90,8 +113,8 @@ impl ToString for AggregateType {
             AggregateType::Unknown => "unknown",
             AggregateType::NotSpecified => "not_specified",
             AggregateType::UnknownAggregateType(uat) => uat,
-        }
-        .to_string()
+        };
+        f.write_str(s)
     }
 }
 
@@ -116,10 +139,7 @@ impl Validate for AggregateType {
     ) -> Result<ValidationResult, ValidationError> {
         match self {
             AggregateType::UnknownAggregateType(_) => Ok(ValidationResult::Failed {
-                reasons: vec![FailureReason {
-                    message: "Unknown aggregate type".to_string(),
-                    context,
-                }],
+                reasons: vec![FailureReason::error("Unknown aggregate type", context)],
             }),
             _ => Ok(ValidationResult::Passed),
         }
@@ -129,6 +149,12 @@ impl Validate for AggregateType {
 #[derive(Debug, PartialEq, Eq)]
 pub struct BomReference(pub(crate) String);
 
+impl BomReference {
+    pub fn new(reference: impl Into<String>) -> Self {
+        Self(reference.into())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -160,16 +186,16 @@ mod test {
         assert_eq!(
             validation_result,
             ValidationResult::Failed {
-                reasons: vec![FailureReason {
-                    message: "Unknown aggregate type".to_string(),
-                    context: ValidationContext(vec![
+                reasons: vec![FailureReason::error(
+                    "Unknown aggregate type",
+                    ValidationContext(vec![
                         ValidationPathComponent::Array { index: 0 },
                         ValidationPathComponent::Struct {
                             struct_name: "Composition".to_string(),
                             field_name: "aggregate".to_string()
                         }
                     ])
-                }]
+                )]
             }
         );
     }
@@ -19,8 +19,32 @@
 #[derive(Debug, PartialEq, Eq)]
 pub struct Dependencies(pub(crate) Vec<Dependency>);
 
+impl Dependencies {
+    /// Iterates over the dependencies of each component or service
+    pub fn iter(&self) -> std::slice::Iter<'_, Dependency> {
+        self.0.iter()
+    }
+}
+
 #[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub struct Dependency {
     pub dependency_ref: String,
     pub dependencies: Vec<String>,
 }
+
+impl Dependency {
+    /// Constructs a `Dependency` of the component or service with the `bom-ref` `dependency_ref`
+    /// on the components or services with the `bom-ref`s in `dependencies`
+    /// ```
+    /// use cyclonedx_bom::models::dependency::Dependency;
+    ///
+    /// let dependency = Dependency::new("component-a", vec!["component-b".to_string()]);
+    /// ```
+    pub fn new(dependency_ref: impl Into<String>, dependencies: Vec<String>) -> Self {
+        Self {
+            dependency_ref: dependency_ref.into(),
+            dependencies,
+        }
+    }
+}
@@ -16,6 +16,8 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+use thiserror::Error;
+
 use crate::external_models::uri::Uri;
 use crate::models::hash::Hashes;
 use crate::validation::{
@@ -27,6 +29,7 @@ use crate::validation::{
 ///
 /// Please see the [CycloneDX use case](https://cyclonedx.org/use-cases/#external-references) for more information and examples.
 #[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub struct ExternalReference {
     pub external_reference_type: ExternalReferenceType,
     pub url: Uri,
@@ -34,6 +37,11 @@ pub struct ExternalReference {
     pub hashes: Option<Hashes>,
 }
 
+with_setters!(ExternalReference {
+    with_comment => comment: String,
+    with_hashes => hashes: Hashes,
+});
+
 impl ExternalReference {
     /// Constructs a new `ExternalReference` with the reference type and url
     /// ```
@@ -129,9 +137,9 @@ pub enum ExternalReferenceType {
     UnknownExternalReferenceType(String),
 }
 
-impl ToString for ExternalReferenceType {
-    fn to_string(&self) -> String {
-        match self {
+impl std::fmt::Display for ExternalReferenceType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
             ExternalReferenceType::Vcs => "vcs",
             ExternalReferenceType::IssueTracker => "issue-tracker",
             ExternalReferenceType::Website => "website",
@@ -148,8 +156,8 @@ impl ToString for ExternalReferenceType {
             ExternalReferenceType::BuildSystem => "build-system",
             ExternalReferenceType::Other => "other",
             ExternalReferenceType::UnknownExternalReferenceType(un) => un,
-        }
-        .to_string()
+        };
+        f.write_str(s)
     }
 }
 
@@ -176,6 +184,26 @@ impl ExternalReferenceType {
     }
 }
 
+/// Parses a type as it is written in documents, e.g. `build-system`
+impl std::str::FromStr for ExternalReferenceType {
+    type Err = ExternalReferenceTypeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match Self::new_unchecked(s) {
+            Self::UnknownExternalReferenceType(unknown) => Err(
+                ExternalReferenceTypeError::UnknownExternalReferenceType(unknown),
+            ),
+            known => Ok(known),
+        }
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum ExternalReferenceTypeError {
+    #[error("Unknown external reference type `{}`", .0)]
+    UnknownExternalReferenceType(String),
+}
+
 impl Validate for ExternalReferenceType {
     fn validate_with_context(
         &self,
@@ -184,10 +212,10 @@ impl Validate for ExternalReferenceType {
         match self {
             ExternalReferenceType::UnknownExternalReferenceType(_) => {
                 Ok(ValidationResult::Failed {
-                    reasons: vec![FailureReason {
-                        message: "Unknown external reference type".to_string(),
+                    reasons: vec![FailureReason::error(
+                        "Unknown external reference type",
                         context,
-                    }],
+                    )],
                 })
             }
             _ => Ok(ValidationResult::Passed),
@@ -203,6 +231,20 @@ mod test {
     use super::*;
     use pretty_assertions::assert_eq;
 
+    #[test]
+    fn it_should_parse_external_reference_types() {
+        assert_eq!(
+            "build-system".parse::<ExternalReferenceType>(),
+            Ok(ExternalReferenceType::BuildSystem)
+        );
+        assert_eq!(
+            "ci".parse::<ExternalReferenceType>(),
+            Err(ExternalReferenceTypeError::UnknownExternalReferenceType(
+                "ci".to_string()
+            ))
+        );
+    }
+
     #[test]
     fn it_should_pass_validation() {
         let validation_result = ExternalReferences(vec![ExternalReference {
@@ -237,29 +279,29 @@ mod test {
             validation_result,
             ValidationResult::Failed {
                 reasons: vec![
-                    FailureReason {
-                        message: "Unknown external reference type".to_string(),
-                        context: ValidationContext(vec![
+                    FailureReason::error(
+                        "Unknown external reference type",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "ExternalReference".to_string(),
                                 field_name: "external_reference_type".to_string()
                             }
                         ])
-                    },
-                    FailureReason {
-                        message: "Uri does not conform to ISO 8601".to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "Uri does not conform to ISO 8601",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "ExternalReference".to_string(),
                                 field_name: "url".to_string()
                             }
                         ])
-                    },
-                    FailureReason {
-                        message: "HashValue does not match regular expression".to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "HashValue does not match regular expression",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "ExternalReference".to_string(),
@@ -271,7 +313,7 @@ mod test {
                                 field_name: "content".to_string()
                             },
                         ])
-                    },
+                    ),
                 ]
             }
         );
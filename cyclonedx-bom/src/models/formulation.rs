@@ -0,0 +1,1093 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use crate::external_models::{date_time::DateTime, normalized_string::NormalizedString};
+use crate::models::attached_text::AttachedText;
+use crate::models::component::Components;
+use crate::models::dependency::Dependency;
+use crate::models::external_reference::ExternalReference;
+use crate::models::property::{Properties, Property};
+use crate::models::service::Services;
+use crate::validation::{
+    FailureReason, Validate, ValidationContext, ValidationError, ValidationPathComponent,
+    ValidationResult,
+};
+
+/// How the components and services of the BOM were manufactured or deployed, added in version
+/// 1.5 of the specification
+///
+/// Defined via the [XML schema](https://cyclonedx.org/docs/1.5/xml/#type_formulationType)
+#[derive(Debug, PartialEq, Eq)]
+pub struct Formulation(pub Vec<Formula>);
+
+impl Validate for Formulation {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        for (index, formula) in self.0.iter().enumerate() {
+            let context = context.extend_context(vec![ValidationPathComponent::Array { index }]);
+            results.push(formula.validate_with_context(context)?);
+        }
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+/// The components, services, and workflows that took part in a formulation
+///
+/// The workspaces and the runtime topology of workflows and tasks, and the events of triggers,
+/// are not modelled.
+#[derive(Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Formula {
+    pub bom_ref: Option<String>,
+    pub components: Option<Components>,
+    pub services: Option<Services>,
+    pub workflows: Option<Vec<Workflow>>,
+    pub properties: Option<Properties>,
+}
+
+with_setters!(Formula {
+    with_bom_ref => bom_ref: String,
+    with_components => components: Components,
+    with_services => services: Services,
+    with_workflows => workflows: Vec<Workflow>,
+    with_properties => properties: Properties,
+});
+
+impl Formula {
+    /// Construct an empty `Formula`
+    /// ```
+    /// use cyclonedx_bom::models::formulation::{Formula, TaskType, Workflow};
+    ///
+    /// let formula = Formula::new().with_workflows(vec![Workflow::new(
+    ///     "release-workflow",
+    ///     "release-workflow",
+    ///     vec![TaskType::Build, TaskType::Release],
+    /// )]);
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Validate for Formula {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        if let Some(components) = &self.components {
+            let context = context.extend_context_with_struct_field("Formula", "components");
+
+            results.push(components.validate_with_context(context)?);
+        }
+
+        if let Some(services) = &self.services {
+            let context = context.extend_context_with_struct_field("Formula", "services");
+
+            results.push(services.validate_with_context(context)?);
+        }
+
+        results.extend(validate_list(
+            &context,
+            "Formula",
+            "workflows",
+            self.workflows.as_ref(),
+        )?);
+
+        if let Some(properties) = &self.properties {
+            let context = context.extend_context_with_struct_field("Formula", "properties");
+
+            results.push(properties.validate_with_context(context)?);
+        }
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+/// A workflow of tasks, e.g. a pipeline that builds and releases the components
+#[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Workflow {
+    pub bom_ref: String,
+    pub uid: String,
+    pub name: Option<NormalizedString>,
+    pub description: Option<String>,
+    pub resource_references: Option<Vec<ResourceReference>>,
+    pub tasks: Option<Vec<Task>>,
+    pub task_dependencies: Option<Vec<Dependency>>,
+    pub task_types: Vec<TaskType>,
+    pub trigger: Option<Trigger>,
+    pub steps: Option<Vec<Step>>,
+    pub inputs: Option<Vec<Input>>,
+    pub outputs: Option<Vec<Output>>,
+    pub time_start: Option<DateTime>,
+    pub time_end: Option<DateTime>,
+    pub properties: Option<Properties>,
+}
+
+with_setters!(Workflow {
+    with_name => name: NormalizedString,
+    with_description => description: String,
+    with_resource_references => resource_references: Vec<ResourceReference>,
+    with_tasks => tasks: Vec<Task>,
+    with_task_dependencies => task_dependencies: Vec<Dependency>,
+    with_trigger => trigger: Trigger,
+    with_steps => steps: Vec<Step>,
+    with_inputs => inputs: Vec<Input>,
+    with_outputs => outputs: Vec<Output>,
+    with_time_start => time_start: DateTime,
+    with_time_end => time_end: DateTime,
+    with_properties => properties: Properties,
+});
+
+impl Workflow {
+    /// Construct a `Workflow` with its `bom-ref`, its unique identifier, and the types of its tasks
+    pub fn new(bom_ref: &str, uid: &str, task_types: Vec<TaskType>) -> Self {
+        Self {
+            bom_ref: bom_ref.to_string(),
+            uid: uid.to_string(),
+            name: None,
+            description: None,
+            resource_references: None,
+            tasks: None,
+            task_dependencies: None,
+            task_types,
+            trigger: None,
+            steps: None,
+            inputs: None,
+            outputs: None,
+            time_start: None,
+            time_end: None,
+            properties: None,
+        }
+    }
+}
+
+impl Validate for Workflow {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        if let Some(name) = &self.name {
+            let context = context.extend_context_with_struct_field("Workflow", "name");
+
+            results.push(name.validate_with_context(context)?);
+        }
+
+        results.extend(validate_list(
+            &context,
+            "Workflow",
+            "resource_references",
+            self.resource_references.as_ref(),
+        )?);
+        results.extend(validate_list(
+            &context,
+            "Workflow",
+            "tasks",
+            self.tasks.as_ref(),
+        )?);
+        results.extend(validate_list(
+            &context,
+            "Workflow",
+            "task_types",
+            Some(&self.task_types),
+        )?);
+
+        if let Some(trigger) = &self.trigger {
+            let context = context.extend_context_with_struct_field("Workflow", "trigger");
+
+            results.push(trigger.validate_with_context(context)?);
+        }
+
+        results.extend(validate_list(
+            &context,
+            "Workflow",
+            "steps",
+            self.steps.as_ref(),
+        )?);
+        results.extend(validate_list(
+            &context,
+            "Workflow",
+            "inputs",
+            self.inputs.as_ref(),
+        )?);
+        results.extend(validate_list(
+            &context,
+            "Workflow",
+            "outputs",
+            self.outputs.as_ref(),
+        )?);
+
+        if let Some(properties) = &self.properties {
+            let context = context.extend_context_with_struct_field("Workflow", "properties");
+
+            results.push(properties.validate_with_context(context)?);
+        }
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+/// A task of a workflow
+#[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Task {
+    pub bom_ref: String,
+    pub uid: String,
+    pub name: Option<NormalizedString>,
+    pub description: Option<String>,
+    pub resource_references: Option<Vec<ResourceReference>>,
+    pub task_types: Vec<TaskType>,
+    pub trigger: Option<Trigger>,
+    pub steps: Option<Vec<Step>>,
+    pub inputs: Option<Vec<Input>>,
+    pub outputs: Option<Vec<Output>>,
+    pub time_start: Option<DateTime>,
+    pub time_end: Option<DateTime>,
+    pub properties: Option<Properties>,
+}
+
+with_setters!(Task {
+    with_name => name: NormalizedString,
+    with_description => description: String,
+    with_resource_references => resource_references: Vec<ResourceReference>,
+    with_trigger => trigger: Trigger,
+    with_steps => steps: Vec<Step>,
+    with_inputs => inputs: Vec<Input>,
+    with_outputs => outputs: Vec<Output>,
+    with_time_start => time_start: DateTime,
+    with_time_end => time_end: DateTime,
+    with_properties => properties: Properties,
+});
+
+impl Task {
+    /// Construct a `Task` with its `bom-ref`, its unique identifier, and its types
+    pub fn new(bom_ref: &str, uid: &str, task_types: Vec<TaskType>) -> Self {
+        Self {
+            bom_ref: bom_ref.to_string(),
+            uid: uid.to_string(),
+            name: None,
+            description: None,
+            resource_references: None,
+            task_types,
+            trigger: None,
+            steps: None,
+            inputs: None,
+            outputs: None,
+            time_start: None,
+            time_end: None,
+            properties: None,
+        }
+    }
+}
+
+impl Validate for Task {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        if let Some(name) = &self.name {
+            let context = context.extend_context_with_struct_field("Task", "name");
+
+            results.push(name.validate_with_context(context)?);
+        }
+
+        results.extend(validate_list(
+            &context,
+            "Task",
+            "resource_references",
+            self.resource_references.as_ref(),
+        )?);
+        results.extend(validate_list(
+            &context,
+            "Task",
+            "task_types",
+            Some(&self.task_types),
+        )?);
+
+        if let Some(trigger) = &self.trigger {
+            let context = context.extend_context_with_struct_field("Task", "trigger");
+
+            results.push(trigger.validate_with_context(context)?);
+        }
+
+        results.extend(validate_list(
+            &context,
+            "Task",
+            "steps",
+            self.steps.as_ref(),
+        )?);
+        results.extend(validate_list(
+            &context,
+            "Task",
+            "inputs",
+            self.inputs.as_ref(),
+        )?);
+        results.extend(validate_list(
+            &context,
+            "Task",
+            "outputs",
+            self.outputs.as_ref(),
+        )?);
+
+        if let Some(properties) = &self.properties {
+            let context = context.extend_context_with_struct_field("Task", "properties");
+
+            results.push(properties.validate_with_context(context)?);
+        }
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum TaskType {
+    Copy,
+    Clone,
+    Lint,
+    Scan,
+    Merge,
+    Build,
+    Test,
+    Deliver,
+    Deploy,
+    Release,
+    Clean,
+    Other,
+    #[doc(hidden)]
+    UnknownTaskType(String),
+}
+
+impl std::fmt::Display for TaskType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            TaskType::Copy => "copy",
+            TaskType::Clone => "clone",
+            TaskType::Lint => "lint",
+            TaskType::Scan => "scan",
+            TaskType::Merge => "merge",
+            TaskType::Build => "build",
+            TaskType::Test => "test",
+            TaskType::Deliver => "deliver",
+            TaskType::Deploy => "deploy",
+            TaskType::Release => "release",
+            TaskType::Clean => "clean",
+            TaskType::Other => "other",
+            TaskType::UnknownTaskType(ut) => ut,
+        };
+        f.write_str(s)
+    }
+}
+
+impl TaskType {
+    pub(crate) fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
+        match value.as_ref() {
+            "copy" => Self::Copy,
+            "clone" => Self::Clone,
+            "lint" => Self::Lint,
+            "scan" => Self::Scan,
+            "merge" => Self::Merge,
+            "build" => Self::Build,
+            "test" => Self::Test,
+            "deliver" => Self::Deliver,
+            "deploy" => Self::Deploy,
+            "release" => Self::Release,
+            "clean" => Self::Clean,
+            "other" => Self::Other,
+            unknown => Self::UnknownTaskType(unknown.to_string()),
+        }
+    }
+}
+
+impl Validate for TaskType {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        match self {
+            TaskType::UnknownTaskType(_) => Ok(ValidationResult::Failed {
+                reasons: vec![FailureReason::error("Unknown task type", context)],
+            }),
+            _ => Ok(ValidationResult::Passed),
+        }
+    }
+}
+
+/// A step of a workflow or task, with the commands it executed
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Step {
+    pub name: Option<NormalizedString>,
+    pub description: Option<String>,
+    pub commands: Option<Vec<Command>>,
+    pub properties: Option<Properties>,
+}
+
+impl Validate for Step {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        if let Some(name) = &self.name {
+            let context = context.extend_context_with_struct_field("Step", "name");
+
+            results.push(name.validate_with_context(context)?);
+        }
+
+        results.extend(validate_list(
+            &context,
+            "Step",
+            "commands",
+            self.commands.as_ref(),
+        )?);
+
+        if let Some(properties) = &self.properties {
+            let context = context.extend_context_with_struct_field("Step", "properties");
+
+            results.push(properties.validate_with_context(context)?);
+        }
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Command {
+    pub executed: Option<String>,
+    pub properties: Option<Properties>,
+}
+
+impl Validate for Command {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        match &self.properties {
+            Some(properties) => {
+                let context = context.extend_context_with_struct_field("Command", "properties");
+
+                properties.validate_with_context(context)
+            }
+            None => Ok(ValidationResult::Passed),
+        }
+    }
+}
+
+/// What started a workflow or task
+#[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Trigger {
+    pub bom_ref: String,
+    pub uid: String,
+    pub name: Option<NormalizedString>,
+    pub description: Option<String>,
+    pub resource_references: Option<Vec<ResourceReference>>,
+    pub trigger_type: TriggerType,
+    pub conditions: Option<Vec<Condition>>,
+    pub time_activated: Option<DateTime>,
+    pub inputs: Option<Vec<Input>>,
+    pub outputs: Option<Vec<Output>>,
+    pub properties: Option<Properties>,
+}
+
+with_setters!(Trigger {
+    with_name => name: NormalizedString,
+    with_description => description: String,
+    with_resource_references => resource_references: Vec<ResourceReference>,
+    with_conditions => conditions: Vec<Condition>,
+    with_time_activated => time_activated: DateTime,
+    with_inputs => inputs: Vec<Input>,
+    with_outputs => outputs: Vec<Output>,
+    with_properties => properties: Properties,
+});
+
+impl Trigger {
+    /// Construct a `Trigger` with its `bom-ref`, its unique identifier, and its type
+    pub fn new(bom_ref: &str, uid: &str, trigger_type: TriggerType) -> Self {
+        Self {
+            bom_ref: bom_ref.to_string(),
+            uid: uid.to_string(),
+            name: None,
+            description: None,
+            resource_references: None,
+            trigger_type,
+            conditions: None,
+            time_activated: None,
+            inputs: None,
+            outputs: None,
+            properties: None,
+        }
+    }
+}
+
+impl Validate for Trigger {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        if let Some(name) = &self.name {
+            let context = context.extend_context_with_struct_field("Trigger", "name");
+
+            results.push(name.validate_with_context(context)?);
+        }
+
+        results.extend(validate_list(
+            &context,
+            "Trigger",
+            "resource_references",
+            self.resource_references.as_ref(),
+        )?);
+
+        let trigger_type_context =
+            context.extend_context_with_struct_field("Trigger", "trigger_type");
+        results.push(
+            self.trigger_type
+                .validate_with_context(trigger_type_context)?,
+        );
+
+        results.extend(validate_list(
+            &context,
+            "Trigger",
+            "conditions",
+            self.conditions.as_ref(),
+        )?);
+        results.extend(validate_list(
+            &context,
+            "Trigger",
+            "inputs",
+            self.inputs.as_ref(),
+        )?);
+        results.extend(validate_list(
+            &context,
+            "Trigger",
+            "outputs",
+            self.outputs.as_ref(),
+        )?);
+
+        if let Some(properties) = &self.properties {
+            let context = context.extend_context_with_struct_field("Trigger", "properties");
+
+            results.push(properties.validate_with_context(context)?);
+        }
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum TriggerType {
+    Manual,
+    Api,
+    Webhook,
+    Scheduled,
+    #[doc(hidden)]
+    UnknownTriggerType(String),
+}
+
+impl std::fmt::Display for TriggerType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            TriggerType::Manual => "manual",
+            TriggerType::Api => "api",
+            TriggerType::Webhook => "webhook",
+            TriggerType::Scheduled => "scheduled",
+            TriggerType::UnknownTriggerType(ut) => ut,
+        };
+        f.write_str(s)
+    }
+}
+
+impl TriggerType {
+    pub(crate) fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
+        match value.as_ref() {
+            "manual" => Self::Manual,
+            "api" => Self::Api,
+            "webhook" => Self::Webhook,
+            "scheduled" => Self::Scheduled,
+            unknown => Self::UnknownTriggerType(unknown.to_string()),
+        }
+    }
+}
+
+impl Validate for TriggerType {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        match self {
+            TriggerType::UnknownTriggerType(_) => Ok(ValidationResult::Failed {
+                reasons: vec![FailureReason::error("Unknown trigger type", context)],
+            }),
+            _ => Ok(ValidationResult::Passed),
+        }
+    }
+}
+
+/// A condition that has to be met for a trigger to start its workflow or task
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Condition {
+    pub description: Option<String>,
+    pub expression: Option<String>,
+    pub properties: Option<Properties>,
+}
+
+impl Validate for Condition {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        match &self.properties {
+            Some(properties) => {
+                let context = context.extend_context_with_struct_field("Condition", "properties");
+
+                properties.validate_with_context(context)
+            }
+            None => Ok(ValidationResult::Passed),
+        }
+    }
+}
+
+/// A resource used by a workflow, task, or trigger, referenced by its `bom-ref` or by an
+/// external reference
+#[derive(Debug, PartialEq, Eq)]
+pub enum ResourceReference {
+    Reference(String),
+    ExternalReference(ExternalReference),
+}
+
+impl Validate for ResourceReference {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        match self {
+            ResourceReference::Reference(_) => Ok(ValidationResult::Passed),
+            ResourceReference::ExternalReference(external_reference) => {
+                let context = context
+                    .extend_context_with_struct_field("ResourceReference", "external_reference");
+
+                external_reference.validate_with_context(context)
+            }
+        }
+    }
+}
+
+/// An input of a workflow, task, or trigger
+#[derive(Debug, PartialEq, Eq)]
+pub struct Input {
+    pub data: InputData,
+    pub source: Option<ResourceReference>,
+    pub target: Option<ResourceReference>,
+    pub properties: Option<Properties>,
+}
+
+impl Validate for Input {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        let data_context = context.extend_context_with_struct_field("Input", "data");
+        results.push(self.data.validate_with_context(data_context)?);
+
+        if let Some(source) = &self.source {
+            let context = context.extend_context_with_struct_field("Input", "source");
+
+            results.push(source.validate_with_context(context)?);
+        }
+
+        if let Some(target) = &self.target {
+            let context = context.extend_context_with_struct_field("Input", "target");
+
+            results.push(target.validate_with_context(context)?);
+        }
+
+        if let Some(properties) = &self.properties {
+            let context = context.extend_context_with_struct_field("Input", "properties");
+
+            results.push(properties.validate_with_context(context)?);
+        }
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+/// The data of an input, which is exactly one of a resource, parameters, environment variables,
+/// or attached data
+#[derive(Debug, PartialEq, Eq)]
+pub enum InputData {
+    Resource(ResourceReference),
+    Parameters(Vec<Parameter>),
+    EnvironmentVars(Vec<EnvironmentVar>),
+    Data(AttachedText),
+}
+
+impl Validate for InputData {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        match self {
+            InputData::Resource(resource) => resource.validate_with_context(context),
+            InputData::Parameters(_) => Ok(ValidationResult::Passed),
+            InputData::EnvironmentVars(environment_vars) => {
+                validate_environment_vars(environment_vars, context)
+            }
+            InputData::Data(data) => data.validate_with_context(context),
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Parameter {
+    pub name: Option<String>,
+    pub value: Option<String>,
+    pub data_type: Option<String>,
+}
+
+/// An environment variable, given as a name and value or only as its value
+#[derive(Debug, PartialEq, Eq)]
+pub enum EnvironmentVar {
+    Property(Property),
+    Value(String),
+}
+
+fn validate_environment_vars(
+    environment_vars: &[EnvironmentVar],
+    context: ValidationContext,
+) -> Result<ValidationResult, ValidationError> {
+    let mut results: Vec<ValidationResult> = vec![];
+
+    for (index, environment_var) in environment_vars.iter().enumerate() {
+        if let EnvironmentVar::Property(property) = environment_var {
+            let context = context.extend_context(vec![ValidationPathComponent::Array { index }]);
+            results.push(property.validate_with_context(context)?);
+        }
+    }
+
+    Ok(results
+        .into_iter()
+        .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+}
+
+/// An output of a workflow, task, or trigger
+#[derive(Debug, PartialEq, Eq)]
+pub struct Output {
+    pub output_type: Option<OutputType>,
+    pub data: OutputData,
+    pub source: Option<ResourceReference>,
+    pub target: Option<ResourceReference>,
+    pub properties: Option<Properties>,
+}
+
+impl Validate for Output {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        if let Some(output_type) = &self.output_type {
+            let context = context.extend_context_with_struct_field("Output", "output_type");
+
+            results.push(output_type.validate_with_context(context)?);
+        }
+
+        let data_context = context.extend_context_with_struct_field("Output", "data");
+        results.push(self.data.validate_with_context(data_context)?);
+
+        if let Some(source) = &self.source {
+            let context = context.extend_context_with_struct_field("Output", "source");
+
+            results.push(source.validate_with_context(context)?);
+        }
+
+        if let Some(target) = &self.target {
+            let context = context.extend_context_with_struct_field("Output", "target");
+
+            results.push(target.validate_with_context(context)?);
+        }
+
+        if let Some(properties) = &self.properties {
+            let context = context.extend_context_with_struct_field("Output", "properties");
+
+            results.push(properties.validate_with_context(context)?);
+        }
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+/// The data of an output, which is exactly one of a resource, environment variables, or
+/// attached data
+#[derive(Debug, PartialEq, Eq)]
+pub enum OutputData {
+    Resource(ResourceReference),
+    EnvironmentVars(Vec<EnvironmentVar>),
+    Data(AttachedText),
+}
+
+impl Validate for OutputData {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        match self {
+            OutputData::Resource(resource) => resource.validate_with_context(context),
+            OutputData::EnvironmentVars(environment_vars) => {
+                validate_environment_vars(environment_vars, context)
+            }
+            OutputData::Data(data) => data.validate_with_context(context),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum OutputType {
+    Artifact,
+    Attestation,
+    Log,
+    Evidence,
+    Metrics,
+    Other,
+    #[doc(hidden)]
+    UnknownOutputType(String),
+}
+
+impl std::fmt::Display for OutputType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            OutputType::Artifact => "artifact",
+            OutputType::Attestation => "attestation",
+            OutputType::Log => "log",
+            OutputType::Evidence => "evidence",
+            OutputType::Metrics => "metrics",
+            OutputType::Other => "other",
+            OutputType::UnknownOutputType(ut) => ut,
+        };
+        f.write_str(s)
+    }
+}
+
+impl OutputType {
+    pub(crate) fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
+        match value.as_ref() {
+            "artifact" => Self::Artifact,
+            "attestation" => Self::Attestation,
+            "log" => Self::Log,
+            "evidence" => Self::Evidence,
+            "metrics" => Self::Metrics,
+            "other" => Self::Other,
+            unknown => Self::UnknownOutputType(unknown.to_string()),
+        }
+    }
+}
+
+impl Validate for OutputType {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        match self {
+            OutputType::UnknownOutputType(_) => Ok(ValidationResult::Failed {
+                reasons: vec![FailureReason::error("Unknown output type", context)],
+            }),
+            _ => Ok(ValidationResult::Passed),
+        }
+    }
+}
+
+/// Validates the items of the list `field_name` of `struct_name`
+fn validate_list<T: Validate>(
+    context: &ValidationContext,
+    struct_name: &str,
+    field_name: &str,
+    items: Option<&Vec<T>>,
+) -> Result<Vec<ValidationResult>, ValidationError> {
+    let mut results: Vec<ValidationResult> = vec![];
+
+    for (index, item) in items.into_iter().flatten().enumerate() {
+        let context = context.extend_context(vec![
+            ValidationPathComponent::Struct {
+                struct_name: struct_name.to_string(),
+                field_name: field_name.to_string(),
+            },
+            ValidationPathComponent::Array { index },
+        ]);
+        results.push(item.validate_with_context(context)?);
+    }
+
+    Ok(results)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn valid_formulations_should_pass_validation() {
+        let validation_result =
+            Formulation(vec![Formula::new().with_workflows(vec![Workflow::new(
+                "release",
+                "release",
+                vec![TaskType::Release],
+            )
+            .with_tasks(vec![Task::new("build", "build", vec![TaskType::Build])
+                .with_steps(vec![Step {
+                    commands: Some(vec![Command {
+                        executed: Some("cargo build --release".to_string()),
+                        properties: None,
+                    }]),
+                    ..Step::default()
+                }])])
+            .with_trigger(Trigger::new("push", "push", TriggerType::Webhook))
+            .with_inputs(vec![Input {
+                data: InputData::EnvironmentVars(vec![EnvironmentVar::Property(Property::new(
+                    "RUSTFLAGS",
+                    "-Dwarnings",
+                ))]),
+                source: None,
+                target: None,
+                properties: None,
+            }])
+            .with_outputs(vec![Output {
+                output_type: Some(OutputType::Artifact),
+                data: OutputData::Resource(ResourceReference::Reference("component-a".to_string())),
+                source: None,
+                target: None,
+                properties: None,
+            }])])])
+            .validate_with_context(ValidationContext::default())
+            .expect("Error while validating");
+
+        assert_eq!(validation_result, ValidationResult::Passed);
+    }
+
+    #[test]
+    fn invalid_formulations_should_fail_validation() {
+        let validation_result =
+            Formulation(vec![Formula::new().with_workflows(vec![Workflow::new(
+                "release",
+                "release",
+                vec![TaskType::new_unchecked("bake")],
+            )
+            .with_trigger(Trigger::new(
+                "push",
+                "push",
+                TriggerType::new_unchecked("telepathy"),
+            ))
+            .with_outputs(vec![Output {
+                output_type: Some(OutputType::new_unchecked("crumbs")),
+                data: OutputData::EnvironmentVars(vec![EnvironmentVar::Value(
+                    "RUSTFLAGS=-Dwarnings".to_string(),
+                )]),
+                source: None,
+                target: None,
+                properties: None,
+            }])])])
+            .validate_with_context(ValidationContext::default())
+            .expect("Error while validating");
+
+        let workflow_context = [
+            ValidationPathComponent::Array { index: 0 },
+            ValidationPathComponent::Struct {
+                struct_name: "Formula".to_string(),
+                field_name: "workflows".to_string(),
+            },
+            ValidationPathComponent::Array { index: 0 },
+        ];
+        let context = |path: Vec<ValidationPathComponent>| {
+            ValidationContext(workflow_context.iter().cloned().chain(path).collect())
+        };
+        assert_eq!(
+            validation_result,
+            ValidationResult::Failed {
+                reasons: vec![
+                    FailureReason::error(
+                        "Unknown task type",
+                        context(vec![
+                            ValidationPathComponent::Struct {
+                                struct_name: "Workflow".to_string(),
+                                field_name: "task_types".to_string(),
+                            },
+                            ValidationPathComponent::Array { index: 0 },
+                        ])
+                    ),
+                    FailureReason::error(
+                        "Unknown trigger type",
+                        context(vec![
+                            ValidationPathComponent::Struct {
+                                struct_name: "Workflow".to_string(),
+                                field_name: "trigger".to_string(),
+                            },
+                            ValidationPathComponent::Struct {
+                                struct_name: "Trigger".to_string(),
+                                field_name: "trigger_type".to_string(),
+                            },
+                        ])
+                    ),
+                    FailureReason::error(
+                        "Unknown output type",
+                        context(vec![
+                            ValidationPathComponent::Struct {
+                                struct_name: "Workflow".to_string(),
+                                field_name: "outputs".to_string(),
+                            },
+                            ValidationPathComponent::Array { index: 0 },
+                            ValidationPathComponent::Struct {
+                                struct_name: "Output".to_string(),
+                                field_name: "output_type".to_string(),
+                            },
+                        ])
+                    ),
+                ]
+            }
+        );
+    }
+}
@@ -28,11 +28,27 @@ use crate::validation::{
 ///
 /// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.3/xml/#type_hashType)
 #[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub struct Hash {
     pub alg: HashAlgorithm,
     pub content: HashValue,
 }
 
+impl Hash {
+    /// Constructs a `Hash` from the hexadecimal digest computed with `alg`
+    /// ```
+    /// use cyclonedx_bom::models::hash::{Hash, HashAlgorithm};
+    ///
+    /// let hash = Hash::new(HashAlgorithm::MD5, "a3bf1f3d584747e2569483783ddee45b");
+    /// ```
+    pub fn new(alg: HashAlgorithm, content: &str) -> Self {
+        Self {
+            alg,
+            content: HashValue(content.to_string()),
+        }
+    }
+}
+
 impl Validate for Hash {
     fn validate_with_context(
         &self,
@@ -98,9 +114,9 @@ pub enum HashAlgorithm {
     UnknownHashAlgorithm(String),
 }
 
-impl ToString for HashAlgorithm {
-    fn to_string(&self) -> String {
-        match self {
+impl std::fmt::Display for HashAlgorithm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
             HashAlgorithm::MD5 => "MD5",
             HashAlgorithm::SHA1 => "SHA-1",
             HashAlgorithm::SHA256 => "SHA-256",
@@ -114,8 +130,8 @@ impl ToString for HashAlgorithm {
             HashAlgorithm::BLAKE2b_512 => "BLAKE2b-512",
             HashAlgorithm::BLAKE3 => "BLAKE3",
             HashAlgorithm::UnknownHashAlgorithm(un) => un,
-        }
-        .to_string()
+        };
+        f.write_str(s)
     }
 }
 
@@ -146,10 +162,7 @@ impl Validate for HashAlgorithm {
     ) -> Result<ValidationResult, ValidationError> {
         match self {
             HashAlgorithm::UnknownHashAlgorithm(_) => Ok(ValidationResult::Failed {
-                reasons: vec![FailureReason {
-                    message: "Unknown HashAlgorithm".to_string(),
-                    context,
-                }],
+                reasons: vec![FailureReason::error("Unknown HashAlgorithm", context)],
             }),
             _ => Ok(ValidationResult::Passed),
         }
@@ -177,10 +190,10 @@ impl Validate for HashValue {
                     Ok(ValidationResult::Passed)
                 } else {
                     Ok(ValidationResult::Failed {
-                        reasons: vec![FailureReason {
-                            message: "HashValue does not match regular expression".to_string(),
+                        reasons: vec![FailureReason::error(
+                            "HashValue does not match regular expression",
                             context,
-                        }],
+                        )],
                     })
                 }
             }
@@ -219,26 +232,26 @@ mod test {
             validation_result,
             ValidationResult::Failed {
                 reasons: vec![
-                    FailureReason {
-                        message: "Unknown HashAlgorithm".to_string(),
-                        context: ValidationContext(vec![
+                    FailureReason::error(
+                        "Unknown HashAlgorithm",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Hash".to_string(),
                                 field_name: "alg".to_string()
                             }
                         ])
-                    },
-                    FailureReason {
-                        message: "HashValue does not match regular expression".to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "HashValue does not match regular expression",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Hash".to_string(),
                                 field_name: "content".to_string()
                             }
                         ])
-                    }
+                    )
                 ]
             }
         );
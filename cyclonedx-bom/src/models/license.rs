@@ -68,12 +68,18 @@ impl Validate for LicenseChoice {
 ///
 /// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.3/xml/#type_licenseType)
 #[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub struct License {
     pub license_identifier: LicenseIdentifier,
     pub text: Option<AttachedText>,
     pub url: Option<Uri>,
 }
 
+with_setters!(License {
+    with_text => text: AttachedText,
+    with_url => url: Uri,
+});
+
 impl License {
     /// Constructs a `License` with a named license identifier
     /// ```
@@ -226,10 +232,9 @@ mod test {
         assert_eq!(
             validation_result,
             ValidationResult::Failed {
-                reasons: vec![FailureReason {
-                    message: "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
-                        .to_string(),
-                    context: ValidationContext(vec![
+                reasons: vec![FailureReason::error(
+                    "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                    ValidationContext(vec![
                         ValidationPathComponent::Array { index: 0 },
                         ValidationPathComponent::EnumVariant {
                             variant_name: "License".to_string()
@@ -242,7 +247,7 @@ mod test {
                             variant_name: "Name".to_string()
                         },
                     ])
-                }]
+                )]
             }
         );
     }
@@ -260,9 +265,9 @@ mod test {
         assert_eq!(
             validation_result,
             ValidationResult::Failed {
-                reasons: vec![FailureReason {
-                    message: "SPDX identifier is not valid".to_string(),
-                    context: ValidationContext(vec![
+                reasons: vec![FailureReason::error(
+                    "SPDX identifier is not valid",
+                    ValidationContext(vec![
                         ValidationPathComponent::Array { index: 0 },
                         ValidationPathComponent::EnumVariant {
                             variant_name: "License".to_string()
@@ -275,7 +280,7 @@ mod test {
                             variant_name: "SpdxId".to_string()
                         },
                     ])
-                }]
+                )]
             }
         );
     }
@@ -291,15 +296,15 @@ mod test {
         assert_eq!(
             validation_result,
             ValidationResult::Failed {
-                reasons: vec![FailureReason {
-                    message: "SPDX expression is not valid".to_string(),
-                    context: ValidationContext(vec![
+                reasons: vec![FailureReason::error(
+                    "SPDX expression is not valid",
+                    ValidationContext(vec![
                         ValidationPathComponent::Array { index: 0 },
                         ValidationPathComponent::EnumVariant {
                             variant_name: "Expression".to_string()
                         }
                     ])
-                }]
+                )]
             }
         );
     }
@@ -330,11 +335,9 @@ mod test {
             validation_result,
             ValidationResult::Failed {
                 reasons: vec![
-                    FailureReason {
-                        message:
-                            "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
-                                .to_string(),
-                        context: ValidationContext(vec![
+                    FailureReason::error(
+                        "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 1 },
                             ValidationPathComponent::EnumVariant {
                                 variant_name: "License".to_string()
@@ -347,10 +350,10 @@ mod test {
                                 variant_name: "Name".to_string()
                             },
                         ])
-                    },
-                    FailureReason {
-                        message: "SPDX identifier is not valid".to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "SPDX identifier is not valid",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 2 },
                             ValidationPathComponent::EnumVariant {
                                 variant_name: "License".to_string()
@@ -363,7 +366,7 @@ mod test {
                                 variant_name: "SpdxId".to_string()
                             },
                         ])
-                    }
+                    )
                 ]
             }
         );
@@ -0,0 +1,211 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use crate::external_models::normalized_string::NormalizedString;
+use crate::validation::{
+    FailureReason, Validate, ValidationContext, ValidationError, ValidationPathComponent,
+    ValidationResult,
+};
+
+/// The phases of the product lifecycle that the BOM describes, added in version 1.5 of the
+/// specification
+///
+/// Defined via the [XML schema](https://cyclonedx.org/docs/1.5/xml/#type_metadata_lifecycles)
+#[derive(Debug, PartialEq, Eq)]
+pub struct Lifecycles(pub Vec<Lifecycle>);
+
+impl Validate for Lifecycles {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        for (index, lifecycle) in self.0.iter().enumerate() {
+            let context = context.extend_context(vec![ValidationPathComponent::Array { index }]);
+            results.push(lifecycle.validate_with_context(context)?);
+        }
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+/// A phase of the product lifecycle, either one of the pre-defined phases or one described by
+/// its name
+#[derive(Debug, PartialEq, Eq)]
+pub enum Lifecycle {
+    Phase(Phase),
+    Description {
+        name: NormalizedString,
+        description: Option<NormalizedString>,
+    },
+}
+
+impl Validate for Lifecycle {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        match self {
+            Lifecycle::Phase(phase) => {
+                let context = context.extend_context_with_struct_field("Lifecycle", "phase");
+
+                phase.validate_with_context(context)
+            }
+            Lifecycle::Description { name, description } => {
+                let mut results: Vec<ValidationResult> = vec![];
+
+                let name_context = context.extend_context_with_struct_field("Lifecycle", "name");
+                results.push(name.validate_with_context(name_context)?);
+
+                if let Some(description) = description {
+                    let context =
+                        context.extend_context_with_struct_field("Lifecycle", "description");
+
+                    results.push(description.validate_with_context(context)?);
+                }
+
+                Ok(results
+                    .into_iter()
+                    .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+            }
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Phase {
+    Design,
+    PreBuild,
+    Build,
+    PostBuild,
+    Operations,
+    Discovery,
+    Decommission,
+    #[doc(hidden)]
+    UnknownPhase(String),
+}
+
+impl std::fmt::Display for Phase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Phase::Design => "design",
+            Phase::PreBuild => "pre-build",
+            Phase::Build => "build",
+            Phase::PostBuild => "post-build",
+            Phase::Operations => "operations",
+            Phase::Discovery => "discovery",
+            Phase::Decommission => "decommission",
+            Phase::UnknownPhase(up) => up,
+        };
+        f.write_str(s)
+    }
+}
+
+impl Phase {
+    pub(crate) fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
+        match value.as_ref() {
+            "design" => Self::Design,
+            "pre-build" => Self::PreBuild,
+            "build" => Self::Build,
+            "post-build" => Self::PostBuild,
+            "operations" => Self::Operations,
+            "discovery" => Self::Discovery,
+            "decommission" => Self::Decommission,
+            unknown => Self::UnknownPhase(unknown.to_string()),
+        }
+    }
+}
+
+impl Validate for Phase {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        match self {
+            Phase::UnknownPhase(_) => Ok(ValidationResult::Failed {
+                reasons: vec![FailureReason::error("Unknown lifecycle phase", context)],
+            }),
+            _ => Ok(ValidationResult::Passed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn valid_lifecycles_should_pass_validation() {
+        let validation_result = Lifecycles(vec![
+            Lifecycle::Phase(Phase::Build),
+            Lifecycle::Description {
+                name: NormalizedString::new("platform-integration-testing"),
+                description: Some(NormalizedString::new("Integration testing on the platform")),
+            },
+        ])
+        .validate_with_context(ValidationContext::default())
+        .expect("Error while validating");
+
+        assert_eq!(validation_result, ValidationResult::Passed);
+    }
+
+    #[test]
+    fn invalid_lifecycles_should_fail_validation() {
+        let validation_result = Lifecycles(vec![
+            Lifecycle::Phase(Phase::new_unchecked("tasting")),
+            Lifecycle::Description {
+                name: NormalizedString("spaces and \ttabs".to_string()),
+                description: None,
+            },
+        ])
+        .validate_with_context(ValidationContext::default())
+        .expect("Error while validating");
+
+        assert_eq!(
+            validation_result,
+            ValidationResult::Failed {
+                reasons: vec![
+                    FailureReason::error(
+                        "Unknown lifecycle phase",
+                        ValidationContext(vec![
+                            ValidationPathComponent::Array { index: 0 },
+                            ValidationPathComponent::Struct {
+                                struct_name: "Lifecycle".to_string(),
+                                field_name: "phase".to_string(),
+                            },
+                        ])
+                    ),
+                    FailureReason::error(
+                        "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                        ValidationContext(vec![
+                            ValidationPathComponent::Array { index: 1 },
+                            ValidationPathComponent::Struct {
+                                struct_name: "Lifecycle".to_string(),
+                                field_name: "name".to_string(),
+                            },
+                        ])
+                    ),
+                ]
+            }
+        );
+    }
+}
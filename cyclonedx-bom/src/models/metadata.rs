@@ -20,10 +20,11 @@ use thiserror::Error;
 
 use crate::external_models::date_time::{DateTime, DateTimeError};
 use crate::models::component::Component;
-use crate::models::license::Licenses;
+use crate::models::license::{LicenseChoice, Licenses};
+use crate::models::lifecycle::Lifecycles;
 use crate::models::organization::{OrganizationalContact, OrganizationalEntity};
 use crate::models::property::Properties;
-use crate::models::tool::Tools;
+use crate::models::tool::{Tool, Tools};
 use crate::validation::{
     Validate, ValidationContext, ValidationError, ValidationPathComponent, ValidationResult,
 };
@@ -32,8 +33,10 @@ use crate::validation::{
 ///
 /// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.3/xml/#type_metadata)
 #[derive(Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
 pub struct Metadata {
     pub timestamp: Option<DateTime>,
+    pub lifecycles: Option<Lifecycles>,
     pub tools: Option<Tools>,
     pub authors: Option<Vec<OrganizationalContact>>,
     pub component: Option<Component>,
@@ -43,6 +46,18 @@ pub struct Metadata {
     pub properties: Option<Properties>,
 }
 
+with_setters!(Metadata {
+    with_timestamp => timestamp: DateTime,
+    with_lifecycles => lifecycles: Lifecycles,
+    with_tools => tools: Tools,
+    with_authors => authors: Vec<OrganizationalContact>,
+    with_component => component: Component,
+    with_manufacture => manufacture: OrganizationalEntity,
+    with_supplier => supplier: OrganizationalEntity,
+    with_licenses => licenses: Licenses,
+    with_properties => properties: Properties,
+});
+
 impl Metadata {
     /// Constructs a new `Metadata` with a timestamp based on the current time
     /// ```
@@ -63,6 +78,99 @@ impl Metadata {
             Err(e) => Err(MetadataError::InvalidTimestamp(e)),
         }
     }
+
+    /// Starts building metadata, which is timestamped with the current time unless another
+    /// timestamp is given
+    /// ```
+    /// use cyclonedx_bom::models::component::{Classification, Component};
+    /// use cyclonedx_bom::models::metadata::Metadata;
+    /// use cyclonedx_bom::models::tool::Tool;
+    ///
+    /// let metadata = Metadata::builder()
+    ///     .tool(Tool::new("CycloneDX", "cargo-cyclonedx", "0.3.8"))
+    ///     .component(Component::builder(Classification::Application, "tacos", "1.0.0").build())
+    ///     .build()?;
+    /// assert!(metadata.timestamp.is_some());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn builder() -> MetadataBuilder {
+        MetadataBuilder::default()
+    }
+}
+
+/// Builds [`Metadata`], adding tools, authors, licenses and properties one at a time
+#[derive(Debug, Default)]
+pub struct MetadataBuilder {
+    metadata: Metadata,
+    tools: Vec<Tool>,
+}
+
+impl MetadataBuilder {
+    pub fn timestamp(mut self, timestamp: DateTime) -> Self {
+        self.metadata.timestamp = Some(timestamp);
+        self
+    }
+
+    /// Adds `tool` to the tools, which are listed in the list form
+    pub fn tool(mut self, tool: Tool) -> Self {
+        self.tools.push(tool);
+        self
+    }
+
+    pub fn author(mut self, author: OrganizationalContact) -> Self {
+        self.metadata
+            .authors
+            .get_or_insert_with(Vec::new)
+            .push(author);
+        self
+    }
+
+    /// Sets the component the BOM describes
+    pub fn component(mut self, component: Component) -> Self {
+        self.metadata.component = Some(component);
+        self
+    }
+
+    pub fn manufacture(mut self, manufacture: OrganizationalEntity) -> Self {
+        self.metadata.manufacture = Some(manufacture);
+        self
+    }
+
+    pub fn supplier(mut self, supplier: OrganizationalEntity) -> Self {
+        self.metadata.supplier = Some(supplier);
+        self
+    }
+
+    /// Adds a license of the BOM itself
+    pub fn license(mut self, license: LicenseChoice) -> Self {
+        self.metadata
+            .licenses
+            .get_or_insert_with(|| Licenses(Vec::new()))
+            .0
+            .push(license);
+        self
+    }
+
+    /// Sets the value of the property called `name`, see [`Properties::upsert`]
+    pub fn property(mut self, name: &str, value: &str) -> Self {
+        self.metadata
+            .properties
+            .get_or_insert_with(|| Properties(Vec::new()))
+            .upsert(name, value);
+        self
+    }
+
+    /// Returns an error variant if no timestamp was given and the current time cannot be used
+    pub fn build(mut self) -> Result<Metadata, MetadataError> {
+        if self.metadata.timestamp.is_none() {
+            let timestamp = DateTime::now().map_err(MetadataError::InvalidTimestamp)?;
+            self.metadata.timestamp = Some(timestamp);
+        }
+        if !self.tools.is_empty() {
+            self.metadata.tools = Some(Tools::List(self.tools));
+        }
+        Ok(self.metadata)
+    }
 }
 
 impl Validate for Metadata {
@@ -78,6 +186,12 @@ impl Validate for Metadata {
             results.push(timestamp.validate_with_context(context)?);
         }
 
+        if let Some(lifecycles) = &self.lifecycles {
+            let context = context.extend_context_with_struct_field("Metadata", "lifecycles");
+
+            results.push(lifecycles.validate_with_context(context)?);
+        }
+
         if let Some(tools) = &self.tools {
             let context = context.extend_context_with_struct_field("Metadata", "tools");
 
@@ -155,12 +269,13 @@ mod test {
     #[test]
     fn valid_metadata_should_pass_validation() {
         let validation_result = Metadata {
-            timestamp: Some(DateTime("1969-06-28T01:20:00.00-04:00".to_string())),
-            tools: Some(Tools(vec![Tool {
+            timestamp: Some("1969-06-28T01:20:00.00-04:00".parse().unwrap()),
+            tools: Some(Tools::List(vec![Tool {
                 vendor: Some(NormalizedString::new("vendor")),
                 name: None,
                 version: None,
                 hashes: None,
+                external_references: None,
             }])),
             authors: Some(vec![OrganizationalContact {
                 name: Some(NormalizedString::new("name")),
@@ -191,6 +306,10 @@ mod test {
                 properties: None,
                 components: None,
                 evidence: None,
+                release_notes: None,
+                signature: None,
+                data: None,
+                model_card: None,
             }),
             manufacture: Some(OrganizationalEntity {
                 name: Some(NormalizedString::new("name")),
@@ -209,8 +328,9 @@ mod test {
                 name: "name".to_string(),
                 value: NormalizedString::new("value"),
             }])),
+            lifecycles: None,
         }
-        .validate_with_context(ValidationContext::default())
+        .validate()
         .expect("Error while validating");
 
         assert_eq!(validation_result, ValidationResult::Passed);
@@ -219,12 +339,13 @@ mod test {
     #[test]
     fn invalid_metadata_should_fail_validation() {
         let validation_result = Metadata {
-            timestamp: Some(DateTime("invalid date".to_string())),
-            tools: Some(Tools(vec![Tool {
+            timestamp: None,
+            tools: Some(Tools::List(vec![Tool {
                 vendor: Some(NormalizedString("invalid\tvendor".to_string())),
                 name: None,
                 version: None,
                 hashes: None,
+                external_references: None,
             }])),
             authors: Some(vec![OrganizationalContact {
                 name: Some(NormalizedString("invalid\tname".to_string())),
@@ -255,6 +376,10 @@ mod test {
                 properties: None,
                 components: None,
                 evidence: None,
+                release_notes: None,
+                signature: None,
+                data: None,
+                model_card: None,
             }),
             manufacture: Some(OrganizationalEntity {
                 name: Some(NormalizedString("invalid\tname".to_string())),
@@ -273,26 +398,18 @@ mod test {
                 name: "name".to_string(),
                 value: NormalizedString("invalid\tvalue".to_string()),
             }])),
+            lifecycles: None,
         }
-        .validate_with_context(ValidationContext::default())
+        .validate()
         .expect("Error while validating");
 
         assert_eq!(
             validation_result,
             ValidationResult::Failed {
                 reasons: vec![
-                    FailureReason {
-                        message: "DateTime does not conform to ISO 8601".to_string(),
-                        context: ValidationContext(vec![ValidationPathComponent::Struct {
-                            struct_name: "Metadata".to_string(),
-                            field_name: "timestamp".to_string()
-                        }])
-                    },
-                    FailureReason {
-                        message:
-                            "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
-                                .to_string(),
-                        context: ValidationContext(vec![
+                    FailureReason::error(
+                        "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                        ValidationContext(vec![
                             ValidationPathComponent::Struct {
                                 struct_name: "Metadata".to_string(),
                                 field_name: "tools".to_string()
@@ -303,12 +420,10 @@ mod test {
                                 field_name: "vendor".to_string()
                             }
                         ])
-                    },
-                    FailureReason {
-                        message:
-                            "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
-                                .to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                        ValidationContext(vec![
                             ValidationPathComponent::Struct {
                                 struct_name: "Metadata".to_string(),
                                 field_name: "authors".to_string()
@@ -319,10 +434,10 @@ mod test {
                                 field_name: "name".to_string()
                             }
                         ])
-                    },
-                    FailureReason {
-                        message: "Unknown classification".to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "Unknown classification",
+                        ValidationContext(vec![
                             ValidationPathComponent::Struct {
                                 struct_name: "Metadata".to_string(),
                                 field_name: "component".to_string()
@@ -332,12 +447,10 @@ mod test {
                                 field_name: "component_type".to_string()
                             }
                         ])
-                    },
-                    FailureReason {
-                        message:
-                            "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
-                                .to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                        ValidationContext(vec![
                             ValidationPathComponent::Struct {
                                 struct_name: "Metadata".to_string(),
                                 field_name: "manufacture".to_string()
@@ -347,12 +460,10 @@ mod test {
                                 field_name: "name".to_string()
                             }
                         ])
-                    },
-                    FailureReason {
-                        message:
-                            "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
-                                .to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                        ValidationContext(vec![
                             ValidationPathComponent::Struct {
                                 struct_name: "Metadata".to_string(),
                                 field_name: "supplier".to_string()
@@ -362,10 +473,10 @@ mod test {
                                 field_name: "name".to_string()
                             }
                         ])
-                    },
-                    FailureReason {
-                        message: "SPDX expression is not valid".to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "SPDX expression is not valid",
+                        ValidationContext(vec![
                             ValidationPathComponent::Struct {
                                 struct_name: "Metadata".to_string(),
                                 field_name: "licenses".to_string()
@@ -375,12 +486,10 @@ mod test {
                                 variant_name: "Expression".to_string()
                             },
                         ])
-                    },
-                    FailureReason {
-                        message:
-                            "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
-                                .to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                        ValidationContext(vec![
                             ValidationPathComponent::Struct {
                                 struct_name: "Metadata".to_string(),
                                 field_name: "properties".to_string()
@@ -391,9 +500,44 @@ mod test {
                                 field_name: "value".to_string()
                             }
                         ])
-                    },
+                    ),
                 ]
             }
         );
     }
+
+    #[test]
+    fn it_should_build_metadata() {
+        let timestamp: DateTime = "2023-06-01T12:00:00Z".parse().unwrap();
+        let metadata = Metadata::builder()
+            .timestamp(timestamp)
+            .tool(Tool::new("CycloneDX", "cargo-cyclonedx", "0.3.8"))
+            .tool(Tool::new("Tacos Inc", "sbom-signer", "1.0.0"))
+            .author(OrganizationalContact::new("Jane Doe", None))
+            .supplier(OrganizationalEntity::new("Tacos Inc"))
+            .property("tacos:flavor", "spicy")
+            .build()
+            .unwrap();
+
+        assert_eq!(metadata.timestamp, Some(timestamp));
+        assert_eq!(
+            metadata
+                .tools
+                .map(Tools::into_list)
+                .map(|tools| tools.len()),
+            Some(2)
+        );
+        assert_eq!(
+            metadata.authors,
+            Some(vec![OrganizationalContact::new("Jane Doe", None)])
+        );
+        assert_eq!(
+            metadata.properties,
+            Some(Properties(vec![Property::new("tacos:flavor", "spicy")]))
+        );
+
+        let metadata = Metadata::builder().build().unwrap();
+        assert!(metadata.timestamp.is_some());
+        assert_eq!(metadata.tools, None);
+    }
 }
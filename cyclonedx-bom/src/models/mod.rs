@@ -16,17 +16,43 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+/// Generates a `with_*` setter for each of the listed optional fields of a model
+///
+/// Together with `#[non_exhaustive]` this allows fields to be added to the models for new versions
+/// of the specification without breaking code that constructs them.
+macro_rules! with_setters {
+    ($model:ident { $($setter:ident => $field:ident: $type:ty),* $(,)? }) => {
+        impl $model {
+            $(
+                #[doc = concat!("Sets `", stringify!($field), "`")]
+                pub fn $setter(mut self, $field: $type) -> Self {
+                    self.$field = Some($field);
+                    self
+                }
+            )*
+        }
+    };
+}
+
+pub mod annotation;
 pub mod attached_text;
 pub mod bom;
 pub mod code;
 pub mod component;
+pub mod component_data;
 pub mod composition;
 pub mod dependency;
 pub mod external_reference;
+pub mod formulation;
 pub mod hash;
 pub mod license;
+pub mod lifecycle;
 pub mod metadata;
+pub mod model_card;
 pub mod organization;
 pub mod property;
+pub mod release_notes;
 pub mod service;
+pub mod signature;
 pub mod tool;
+pub mod vulnerability;
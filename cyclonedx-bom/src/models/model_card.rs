@@ -0,0 +1,343 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use crate::models::component_data::{ComponentData, GraphicsCollection};
+use crate::models::property::Properties;
+use crate::validation::{
+    FailureReason, Validate, ValidationContext, ValidationError, ValidationPathComponent,
+    ValidationResult,
+};
+
+/// The model card of a machine learning model, added in version 1.5 of the specification
+///
+/// Defined via the [XML schema](https://cyclonedx.org/docs/1.5/xml/#type_modelCardType)
+#[derive(Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ModelCard {
+    pub bom_ref: Option<String>,
+    pub model_parameters: Option<ModelParameters>,
+    pub quantitative_analysis: Option<QuantitativeAnalysis>,
+    pub considerations: Option<Considerations>,
+    pub properties: Option<Properties>,
+}
+
+with_setters!(ModelCard {
+    with_bom_ref => bom_ref: String,
+    with_model_parameters => model_parameters: ModelParameters,
+    with_quantitative_analysis => quantitative_analysis: QuantitativeAnalysis,
+    with_considerations => considerations: Considerations,
+    with_properties => properties: Properties,
+});
+
+impl ModelCard {
+    /// Construct an empty `ModelCard`
+    /// ```
+    /// use cyclonedx_bom::models::model_card::{ApproachType, ModelCard, ModelParameters};
+    ///
+    /// let model_card = ModelCard::new().with_model_parameters(ModelParameters {
+    ///     approach: Some(ApproachType::Supervised),
+    ///     task: Some("classification".to_string()),
+    ///     ..ModelParameters::default()
+    /// });
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Validate for ModelCard {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        if let Some(model_parameters) = &self.model_parameters {
+            let context = context.extend_context_with_struct_field("ModelCard", "model_parameters");
+
+            results.push(model_parameters.validate_with_context(context)?);
+        }
+
+        if let Some(quantitative_analysis) = &self.quantitative_analysis {
+            let context =
+                context.extend_context_with_struct_field("ModelCard", "quantitative_analysis");
+
+            results.push(quantitative_analysis.validate_with_context(context)?);
+        }
+
+        if let Some(properties) = &self.properties {
+            let context = context.extend_context_with_struct_field("ModelCard", "properties");
+
+            results.push(properties.validate_with_context(context)?);
+        }
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+/// The parameters used to create the model
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct ModelParameters {
+    pub approach: Option<ApproachType>,
+    pub task: Option<String>,
+    pub architecture_family: Option<String>,
+    pub model_architecture: Option<String>,
+    pub datasets: Option<Vec<Dataset>>,
+    pub inputs: Option<Vec<MLParameter>>,
+    pub outputs: Option<Vec<MLParameter>>,
+}
+
+impl Validate for ModelParameters {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        if let Some(approach) = &self.approach {
+            let context = context.extend_context_with_struct_field("ModelParameters", "approach");
+
+            results.push(approach.validate_with_context(context)?);
+        }
+
+        for (index, dataset) in self.datasets.iter().flatten().enumerate() {
+            if let Dataset::Component(data) = dataset {
+                let context = context.extend_context(vec![
+                    ValidationPathComponent::Struct {
+                        struct_name: "ModelParameters".to_string(),
+                        field_name: "datasets".to_string(),
+                    },
+                    ValidationPathComponent::Array { index },
+                ]);
+                results.push(data.validate_with_context(context)?);
+            }
+        }
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ApproachType {
+    Supervised,
+    Unsupervised,
+    ReinforcementLearning,
+    SemiSupervised,
+    SelfSupervised,
+    #[doc(hidden)]
+    UnknownApproachType(String),
+}
+
+impl std::fmt::Display for ApproachType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            ApproachType::Supervised => "supervised",
+            ApproachType::Unsupervised => "unsupervised",
+            ApproachType::ReinforcementLearning => "reinforcement-learning",
+            ApproachType::SemiSupervised => "semi-supervised",
+            ApproachType::SelfSupervised => "self-supervised",
+            ApproachType::UnknownApproachType(ua) => ua,
+        };
+        f.write_str(s)
+    }
+}
+
+impl ApproachType {
+    pub(crate) fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
+        match value.as_ref() {
+            "supervised" => Self::Supervised,
+            "unsupervised" => Self::Unsupervised,
+            "reinforcement-learning" => Self::ReinforcementLearning,
+            "semi-supervised" => Self::SemiSupervised,
+            "self-supervised" => Self::SelfSupervised,
+            unknown => Self::UnknownApproachType(unknown.to_string()),
+        }
+    }
+}
+
+impl Validate for ApproachType {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        match self {
+            ApproachType::UnknownApproachType(_) => Ok(ValidationResult::Failed {
+                reasons: vec![FailureReason::error("Unknown approach type", context)],
+            }),
+            _ => Ok(ValidationResult::Passed),
+        }
+    }
+}
+
+/// A dataset the model was trained or evaluated on, either described in the model card or
+/// referenced by the `bom-ref` of a data component
+#[derive(Debug, PartialEq, Eq)]
+pub enum Dataset {
+    Component(Box<ComponentData>),
+    Reference(String),
+}
+
+/// The format of an input or output of the model, e.g. `string` or `image`
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct MLParameter {
+    pub format: Option<String>,
+}
+
+/// The quantitative analysis of the performance of the model
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct QuantitativeAnalysis {
+    pub performance_metrics: Option<Vec<PerformanceMetric>>,
+    pub graphics: Option<GraphicsCollection>,
+}
+
+impl Validate for QuantitativeAnalysis {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        match &self.graphics {
+            Some(graphics) => {
+                let context =
+                    context.extend_context_with_struct_field("QuantitativeAnalysis", "graphics");
+
+                graphics.validate_with_context(context)
+            }
+            None => Ok(ValidationResult::Passed),
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct PerformanceMetric {
+    pub metric_type: Option<String>,
+    pub value: Option<String>,
+    pub slice: Option<String>,
+    pub confidence_interval: Option<ConfidenceInterval>,
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct ConfidenceInterval {
+    pub lower_bound: Option<String>,
+    pub upper_bound: Option<String>,
+}
+
+/// What the users of the model should consider, such as its limitations and ethical risks
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Considerations {
+    pub users: Option<Vec<String>>,
+    pub use_cases: Option<Vec<String>>,
+    pub technical_limitations: Option<Vec<String>>,
+    pub performance_tradeoffs: Option<Vec<String>>,
+    pub ethical_considerations: Option<Vec<EthicalConsideration>>,
+    pub fairness_assessments: Option<Vec<FairnessAssessment>>,
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct EthicalConsideration {
+    pub name: Option<String>,
+    pub mitigation_strategy: Option<String>,
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct FairnessAssessment {
+    pub group_at_risk: Option<String>,
+    pub benefits: Option<String>,
+    pub harms: Option<String>,
+    pub mitigation_strategy: Option<String>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::models::component_data::ComponentDataType;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn valid_model_cards_should_pass_validation() {
+        let validation_result = ModelCard::new()
+            .with_model_parameters(ModelParameters {
+                approach: Some(ApproachType::Supervised),
+                datasets: Some(vec![
+                    Dataset::Component(Box::new(ComponentData::new(ComponentDataType::Dataset))),
+                    Dataset::Reference("training-data".to_string()),
+                ]),
+                ..ModelParameters::default()
+            })
+            .validate_with_context(ValidationContext::default())
+            .expect("Error while validating");
+
+        assert_eq!(validation_result, ValidationResult::Passed);
+    }
+
+    #[test]
+    fn invalid_model_cards_should_fail_validation() {
+        let validation_result = ModelCard::new()
+            .with_model_parameters(ModelParameters {
+                approach: Some(ApproachType::new_unchecked("guessing")),
+                datasets: Some(vec![Dataset::Component(Box::new(ComponentData::new(
+                    ComponentDataType::new_unchecked("recipes"),
+                )))]),
+                ..ModelParameters::default()
+            })
+            .validate_with_context(ValidationContext::default())
+            .expect("Error while validating");
+
+        assert_eq!(
+            validation_result,
+            ValidationResult::Failed {
+                reasons: vec![
+                    FailureReason::error(
+                        "Unknown approach type",
+                        ValidationContext(vec![
+                            ValidationPathComponent::Struct {
+                                struct_name: "ModelCard".to_string(),
+                                field_name: "model_parameters".to_string(),
+                            },
+                            ValidationPathComponent::Struct {
+                                struct_name: "ModelParameters".to_string(),
+                                field_name: "approach".to_string(),
+                            },
+                        ])
+                    ),
+                    FailureReason::error(
+                        "Unknown component data type",
+                        ValidationContext(vec![
+                            ValidationPathComponent::Struct {
+                                struct_name: "ModelCard".to_string(),
+                                field_name: "model_parameters".to_string(),
+                            },
+                            ValidationPathComponent::Struct {
+                                struct_name: "ModelParameters".to_string(),
+                                field_name: "datasets".to_string(),
+                            },
+                            ValidationPathComponent::Array { index: 0 },
+                            ValidationPathComponent::Struct {
+                                struct_name: "ComponentData".to_string(),
+                                field_name: "data_type".to_string(),
+                            },
+                        ])
+                    ),
+                ]
+            }
+        );
+    }
+}
@@ -27,12 +27,19 @@ use crate::{
 ///
 /// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.3/xml/#type_organizationalContact)
 #[derive(Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
 pub struct OrganizationalContact {
     pub name: Option<NormalizedString>,
     pub email: Option<NormalizedString>,
     pub phone: Option<NormalizedString>,
 }
 
+with_setters!(OrganizationalContact {
+    with_name => name: NormalizedString,
+    with_email => email: NormalizedString,
+    with_phone => phone: NormalizedString,
+});
+
 impl OrganizationalContact {
     /// Construct an `OrganizationalContact` with name and email
     /// ```
@@ -86,12 +93,35 @@ impl Validate for OrganizationalContact {
 ///
 /// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.3/xml/#type_organizationalEntity)
 #[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub struct OrganizationalEntity {
     pub name: Option<NormalizedString>,
     pub url: Option<Vec<Uri>>,
     pub contact: Option<Vec<OrganizationalContact>>,
 }
 
+impl OrganizationalEntity {
+    /// Construct an `OrganizationalEntity` with a name
+    /// ```
+    /// use cyclonedx_bom::models::organization::OrganizationalEntity;
+    ///
+    /// let organizational_entity = OrganizationalEntity::new("Example Inc.");
+    /// ```
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: Some(NormalizedString::new(name)),
+            url: None,
+            contact: None,
+        }
+    }
+}
+
+with_setters!(OrganizationalEntity {
+    with_name => name: NormalizedString,
+    with_url => url: Vec<Uri>,
+    with_contact => contact: Vec<OrganizationalContact>,
+});
+
 impl Validate for OrganizationalEntity {
     fn validate_with_context(
         &self,
@@ -172,14 +202,13 @@ mod test {
         assert_eq!(
             actual,
             ValidationResult::Failed {
-                reasons: vec![FailureReason {
-                    message: "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
-                        .to_string(),
-                    context: ValidationContext(vec![ValidationPathComponent::Struct {
+                reasons: vec![FailureReason::error(
+                    "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                    ValidationContext(vec![ValidationPathComponent::Struct {
                         struct_name: "OrganizationalContact".to_string(),
                         field_name: "name".to_string()
                     }])
-                }]
+                )]
             }
         )
     }
@@ -202,33 +231,27 @@ mod test {
             actual,
             ValidationResult::Failed {
                 reasons: vec![
-                    FailureReason {
-                        message:
-                            "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
-                                .to_string(),
-                        context: ValidationContext(vec![ValidationPathComponent::Struct {
+                    FailureReason::error(
+                        "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                        ValidationContext(vec![ValidationPathComponent::Struct {
                             struct_name: "OrganizationalContact".to_string(),
                             field_name: "name".to_string()
                         }])
-                    },
-                    FailureReason {
-                        message:
-                            "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
-                                .to_string(),
-                        context: ValidationContext(vec![ValidationPathComponent::Struct {
+                    ),
+                    FailureReason::error(
+                        "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                        ValidationContext(vec![ValidationPathComponent::Struct {
                             struct_name: "OrganizationalContact".to_string(),
                             field_name: "email".to_string()
                         }])
-                    },
-                    FailureReason {
-                        message:
-                            "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
-                                .to_string(),
-                        context: ValidationContext(vec![ValidationPathComponent::Struct {
+                    ),
+                    FailureReason::error(
+                        "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                        ValidationContext(vec![ValidationPathComponent::Struct {
                             struct_name: "OrganizationalContact".to_string(),
                             field_name: "phone".to_string()
                         }])
-                    }
+                    )
                 ]
             }
         )
@@ -247,14 +270,13 @@ mod test {
         assert_eq!(
             actual,
             ValidationResult::Failed {
-                reasons: vec![FailureReason {
-                    message: "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
-                        .to_string(),
-                    context: ValidationContext(vec![ValidationPathComponent::Struct {
+                reasons: vec![FailureReason::error(
+                    "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                    ValidationContext(vec![ValidationPathComponent::Struct {
                         struct_name: "OrganizationalEntity".to_string(),
                         field_name: "name".to_string()
                     }])
-                }]
+                )]
             }
         )
     }
@@ -277,30 +299,26 @@ mod test {
             actual,
             ValidationResult::Failed {
                 reasons: vec![
-                    FailureReason {
-                        message:
-                            "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
-                                .to_string(),
-                        context: ValidationContext(vec![ValidationPathComponent::Struct {
+                    FailureReason::error(
+                        "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                        ValidationContext(vec![ValidationPathComponent::Struct {
                             struct_name: "OrganizationalEntity".to_string(),
                             field_name: "name".to_string()
                         }])
-                    },
-                    FailureReason {
-                        message: "Uri does not conform to ISO 8601".to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "Uri does not conform to ISO 8601",
+                        ValidationContext(vec![
                             ValidationPathComponent::Struct {
                                 struct_name: "OrganizationalEntity".to_string(),
                                 field_name: "url".to_string()
                             },
                             ValidationPathComponent::Array { index: 0 }
                         ])
-                    },
-                    FailureReason {
-                        message:
-                            "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
-                                .to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                        ValidationContext(vec![
                             ValidationPathComponent::Struct {
                                 struct_name: "OrganizationalEntity".to_string(),
                                 field_name: "contact".to_string()
@@ -311,7 +329,7 @@ mod test {
                                 field_name: "name".to_string()
                             }
                         ])
-                    }
+                    )
                 ]
             }
         )
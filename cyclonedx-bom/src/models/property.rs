@@ -31,6 +31,52 @@ use crate::{
 #[derive(Debug, PartialEq, Eq)]
 pub struct Properties(pub Vec<Property>);
 
+impl Properties {
+    /// Sets the value of the property called `name`, adding it at its position by name if there
+    /// is no such property yet
+    /// ```
+    /// use cyclonedx_bom::models::property::{Properties, Property};
+    ///
+    /// let mut properties = Properties(vec![Property::new("b", "1")]);
+    /// properties.upsert("a", "2");
+    /// properties.upsert("b", "3");
+    ///
+    /// assert_eq!(properties, Properties(vec![Property::new("a", "2"), Property::new("b", "3")]));
+    /// ```
+    pub fn upsert(&mut self, name: &str, value: &str) {
+        match self.0.iter_mut().find(|property| property.name == name) {
+            Some(property) => property.value = NormalizedString::new(value),
+            None => {
+                let index = self
+                    .0
+                    .partition_point(|property| property.name.as_str() < name);
+                self.0.insert(index, Property::new(name, value));
+            }
+        }
+    }
+
+    /// The value of the property called `name`, if there is one
+    /// ```
+    /// use cyclonedx_bom::models::property::{Properties, Property};
+    ///
+    /// let properties = Properties(vec![Property::new("a", "1")]);
+    ///
+    /// assert_eq!(properties.get("a"), Some("1"));
+    /// assert_eq!(properties.get("b"), None);
+    /// ```
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|property| property.name == name)
+            .map(|property| property.value.0.as_str())
+    }
+
+    /// Removes the properties called `name`
+    pub fn remove(&mut self, name: &str) {
+        self.0.retain(|property| property.name != name);
+    }
+}
+
 impl Validate for Properties {
     fn validate_with_context(
         &self,
@@ -54,6 +100,7 @@ impl Validate for Properties {
 ///
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.3/xml/#type_propertyType)
 #[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub struct Property {
     pub name: String,
     pub value: NormalizedString,
@@ -121,17 +168,16 @@ mod test {
         assert_eq!(
             validation_result,
             ValidationResult::Failed {
-                reasons: vec![FailureReason {
-                    message: "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
-                        .to_string(),
-                    context: ValidationContext(vec![
+                reasons: vec![FailureReason::error(
+                    "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                    ValidationContext(vec![
                         ValidationPathComponent::Array { index: 0 },
                         ValidationPathComponent::Struct {
                             struct_name: "Property".to_string(),
                             field_name: "value".to_string(),
                         },
-                    ]),
-                }],
+                    ])
+                )],
             }
         );
     }
@@ -0,0 +1,304 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::external_models::{date_time::DateTime, normalized_string::NormalizedString, uri::Uri};
+use crate::models::attached_text::AttachedText;
+use crate::models::code::Issue;
+use crate::models::property::Properties;
+use crate::validation::{
+    FailureReason, Validate, ValidationContext, ValidationError, ValidationPathComponent,
+    ValidationResult,
+};
+
+/// The release notes of a component or service, added in version 1.4 of the specification
+///
+/// Defined via the [XML schema](https://cyclonedx.org/docs/1.4/xml/#type_releaseNotesType)
+#[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ReleaseNotes {
+    pub release_type: NormalizedString,
+    pub title: Option<NormalizedString>,
+    pub featured_image: Option<Uri>,
+    pub social_image: Option<Uri>,
+    pub description: Option<NormalizedString>,
+    pub timestamp: Option<DateTime>,
+    pub aliases: Option<Vec<NormalizedString>>,
+    pub tags: Option<Vec<NormalizedString>>,
+    pub resolves: Option<Vec<Issue>>,
+    pub notes: Option<Vec<Note>>,
+    pub properties: Option<Properties>,
+}
+
+with_setters!(ReleaseNotes {
+    with_title => title: NormalizedString,
+    with_featured_image => featured_image: Uri,
+    with_social_image => social_image: Uri,
+    with_description => description: NormalizedString,
+    with_timestamp => timestamp: DateTime,
+    with_aliases => aliases: Vec<NormalizedString>,
+    with_tags => tags: Vec<NormalizedString>,
+    with_resolves => resolves: Vec<Issue>,
+    with_notes => notes: Vec<Note>,
+    with_properties => properties: Properties,
+});
+
+impl ReleaseNotes {
+    /// Construct `ReleaseNotes` of a release type, such as `major`, `minor`, `patch`,
+    /// `pre-release` or `internal`
+    /// ```
+    /// use cyclonedx_bom::models::release_notes::ReleaseNotes;
+    ///
+    /// let release_notes = ReleaseNotes::new("major");
+    /// ```
+    pub fn new(release_type: &str) -> Self {
+        Self {
+            release_type: NormalizedString::new(release_type),
+            title: None,
+            featured_image: None,
+            social_image: None,
+            description: None,
+            timestamp: None,
+            aliases: None,
+            tags: None,
+            resolves: None,
+            notes: None,
+            properties: None,
+        }
+    }
+}
+
+impl Validate for ReleaseNotes {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        let release_type_context =
+            context.extend_context_with_struct_field("ReleaseNotes", "release_type");
+
+        results.push(
+            self.release_type
+                .validate_with_context(release_type_context)?,
+        );
+
+        if let Some(title) = &self.title {
+            let context = context.extend_context_with_struct_field("ReleaseNotes", "title");
+
+            results.push(title.validate_with_context(context)?);
+        }
+
+        if let Some(featured_image) = &self.featured_image {
+            let context =
+                context.extend_context_with_struct_field("ReleaseNotes", "featured_image");
+
+            results.push(featured_image.validate_with_context(context)?);
+        }
+
+        if let Some(social_image) = &self.social_image {
+            let context = context.extend_context_with_struct_field("ReleaseNotes", "social_image");
+
+            results.push(social_image.validate_with_context(context)?);
+        }
+
+        if let Some(description) = &self.description {
+            let context = context.extend_context_with_struct_field("ReleaseNotes", "description");
+
+            results.push(description.validate_with_context(context)?);
+        }
+
+        if let Some(timestamp) = &self.timestamp {
+            let context = context.extend_context_with_struct_field("ReleaseNotes", "timestamp");
+
+            results.push(timestamp.validate_with_context(context)?);
+        }
+
+        for (field, values) in [("aliases", &self.aliases), ("tags", &self.tags)] {
+            for (index, value) in values.iter().flatten().enumerate() {
+                let context = context.extend_context(vec![
+                    ValidationPathComponent::Struct {
+                        struct_name: "ReleaseNotes".to_string(),
+                        field_name: field.to_string(),
+                    },
+                    ValidationPathComponent::Array { index },
+                ]);
+                results.push(value.validate_with_context(context)?);
+            }
+        }
+
+        if let Some(resolves) = &self.resolves {
+            for (index, issue) in resolves.iter().enumerate() {
+                let context = context.extend_context(vec![
+                    ValidationPathComponent::Struct {
+                        struct_name: "ReleaseNotes".to_string(),
+                        field_name: "resolves".to_string(),
+                    },
+                    ValidationPathComponent::Array { index },
+                ]);
+                results.push(issue.validate_with_context(context)?);
+            }
+        }
+
+        if let Some(notes) = &self.notes {
+            for (index, note) in notes.iter().enumerate() {
+                let context = context.extend_context(vec![
+                    ValidationPathComponent::Struct {
+                        struct_name: "ReleaseNotes".to_string(),
+                        field_name: "notes".to_string(),
+                    },
+                    ValidationPathComponent::Array { index },
+                ]);
+                results.push(note.validate_with_context(context)?);
+            }
+        }
+
+        if let Some(properties) = &self.properties {
+            let context = context.extend_context_with_struct_field("ReleaseNotes", "properties");
+
+            results.push(properties.validate_with_context(context)?);
+        }
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+/// A note in the release notes, with the ISO-639 language code and optional ISO-3166 country
+/// code of its text
+#[derive(Debug, PartialEq, Eq)]
+pub struct Note {
+    pub locale: Option<NormalizedString>,
+    pub text: AttachedText,
+}
+
+impl Validate for Note {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        if let Some(locale) = &self.locale {
+            static LOCALE_REGEX: Lazy<Regex> = Lazy::new(|| {
+                Regex::new(r"^([a-z]{2})(-[A-Z]{2})?$").expect("Failed to compile regex.")
+            });
+
+            let context = context.extend_context_with_struct_field("Note", "locale");
+            if LOCALE_REGEX.is_match(&locale.to_string()) {
+                results.push(ValidationResult::Passed);
+            } else {
+                results.push(ValidationResult::Failed {
+                    reasons: vec![FailureReason::error(
+                        "Locale does not match regular expression",
+                        context,
+                    )],
+                });
+            }
+        }
+
+        let text_context = context.extend_context_with_struct_field("Note", "text");
+        results.push(self.text.validate_with_context(text_context)?);
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::models::code::IssueClassification;
+
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn valid_release_notes_should_pass_validation() {
+        let validation_result = ReleaseNotes::new("major")
+            .with_title(NormalizedString::new("Tacos 2.0"))
+            .with_timestamp("2023-06-28T01:20:00.00-04:00".parse().unwrap())
+            .with_tags(vec![NormalizedString::new("spicy")])
+            .with_notes(vec![Note {
+                locale: Some(NormalizedString::new("en-US")),
+                text: AttachedText::new(None, "Now with salsa"),
+            }])
+            .validate_with_context(ValidationContext::default())
+            .expect("Error while validating");
+
+        assert_eq!(validation_result, ValidationResult::Passed);
+    }
+
+    #[test]
+    fn invalid_release_notes_should_fail_validation() {
+        let validation_result = ReleaseNotes::new("major")
+            .with_resolves(vec![Issue {
+                issue_type: IssueClassification::new_unchecked("complaint"),
+                id: None,
+                name: None,
+                description: None,
+                source: None,
+                references: None,
+            }])
+            .with_notes(vec![Note {
+                locale: Some(NormalizedString::new("english")),
+                text: AttachedText::new(None, "Now with salsa"),
+            }])
+            .validate_with_context(ValidationContext::default())
+            .expect("Error while validating");
+
+        assert_eq!(
+            validation_result,
+            ValidationResult::Failed {
+                reasons: vec![
+                    FailureReason::error(
+                        "Unknown issue classification",
+                        ValidationContext(vec![
+                            ValidationPathComponent::Struct {
+                                struct_name: "ReleaseNotes".to_string(),
+                                field_name: "resolves".to_string(),
+                            },
+                            ValidationPathComponent::Array { index: 0 },
+                            ValidationPathComponent::Struct {
+                                struct_name: "Issue".to_string(),
+                                field_name: "issue_type".to_string(),
+                            },
+                        ])
+                    ),
+                    FailureReason::error(
+                        "Locale does not match regular expression",
+                        ValidationContext(vec![
+                            ValidationPathComponent::Struct {
+                                struct_name: "ReleaseNotes".to_string(),
+                                field_name: "notes".to_string(),
+                            },
+                            ValidationPathComponent::Array { index: 0 },
+                            ValidationPathComponent::Struct {
+                                struct_name: "Note".to_string(),
+                                field_name: "locale".to_string(),
+                            },
+                        ])
+                    ),
+                ]
+            }
+        );
+    }
+}
@@ -21,6 +21,8 @@ use crate::models::external_reference::ExternalReferences;
 use crate::models::license::Licenses;
 use crate::models::organization::OrganizationalEntity;
 use crate::models::property::Properties;
+use crate::models::release_notes::ReleaseNotes;
+use crate::models::signature::Signature;
 use crate::validation::{
     FailureReason, Validate, ValidationContext, ValidationError, ValidationPathComponent,
     ValidationResult,
@@ -30,6 +32,7 @@ use crate::validation::{
 ///
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.3/xml/#type_service)
 #[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub struct Service {
     pub bom_ref: Option<String>,
     pub provider: Option<OrganizationalEntity>,
@@ -40,13 +43,36 @@ pub struct Service {
     pub endpoints: Option<Vec<Uri>>,
     pub authenticated: Option<bool>,
     pub x_trust_boundary: Option<bool>,
+    /// The name of the trust zone the service resides in, added in version 1.5
+    pub trust_zone: Option<NormalizedString>,
     pub data: Option<Vec<DataClassification>>,
     pub licenses: Option<Licenses>,
     pub external_references: Option<ExternalReferences>,
     pub properties: Option<Properties>,
     pub services: Option<Services>,
+    pub release_notes: Option<ReleaseNotes>,
+    pub signature: Option<Signature>,
 }
 
+with_setters!(Service {
+    with_bom_ref => bom_ref: String,
+    with_provider => provider: OrganizationalEntity,
+    with_group => group: NormalizedString,
+    with_version => version: NormalizedString,
+    with_description => description: NormalizedString,
+    with_endpoints => endpoints: Vec<Uri>,
+    with_authenticated => authenticated: bool,
+    with_x_trust_boundary => x_trust_boundary: bool,
+    with_trust_zone => trust_zone: NormalizedString,
+    with_data => data: Vec<DataClassification>,
+    with_licenses => licenses: Licenses,
+    with_external_references => external_references: ExternalReferences,
+    with_properties => properties: Properties,
+    with_services => services: Services,
+    with_release_notes => release_notes: ReleaseNotes,
+    with_signature => signature: Signature,
+});
+
 impl Service {
     /// Construct a `Service` with a name and BOM reference
     /// ```
@@ -65,11 +91,14 @@ impl Service {
             endpoints: None,
             authenticated: None,
             x_trust_boundary: None,
+            trust_zone: None,
             data: None,
             licenses: None,
             external_references: None,
             properties: None,
             services: None,
+            release_notes: None,
+            signature: None,
         }
     }
 }
@@ -122,6 +151,12 @@ impl Validate for Service {
             }
         }
 
+        if let Some(trust_zone) = &self.trust_zone {
+            let context = context.extend_context_with_struct_field("Service", "trust_zone");
+
+            results.push(trust_zone.validate_with_context(context)?);
+        }
+
         if let Some(data) = &self.data {
             for (index, classification) in data.iter().enumerate() {
                 let context = context.extend_context(vec![
@@ -160,6 +195,18 @@ impl Validate for Service {
             results.push(services.validate_with_context(context)?);
         }
 
+        if let Some(release_notes) = &self.release_notes {
+            let context = context.extend_context_with_struct_field("Service", "release_notes");
+
+            results.push(release_notes.validate_with_context(context)?);
+        }
+
+        if let Some(signature) = &self.signature {
+            let context = context.extend_context_with_struct_field("Service", "signature");
+
+            results.push(signature.validate_with_context(context)?);
+        }
+
         Ok(results
             .into_iter()
             .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
@@ -234,16 +281,16 @@ pub enum DataFlowType {
     UnknownDataFlow(String),
 }
 
-impl ToString for DataFlowType {
-    fn to_string(&self) -> String {
-        match self {
+impl std::fmt::Display for DataFlowType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
             DataFlowType::Inbound => "inbound",
             DataFlowType::Outbound => "outbound",
             DataFlowType::BiDirectional => "bi-directional",
             DataFlowType::Unknown => "unknown",
             DataFlowType::UnknownDataFlow(df) => df,
-        }
-        .to_string()
+        };
+        f.write_str(s)
     }
 }
 
@@ -266,10 +313,7 @@ impl Validate for DataFlowType {
     ) -> Result<ValidationResult, ValidationError> {
         match self {
             DataFlowType::UnknownDataFlow(_) => Ok(ValidationResult::Failed {
-                reasons: vec![FailureReason {
-                    message: "Unknown data flow type".to_string(),
-                    context,
-                }],
+                reasons: vec![FailureReason::error("Unknown data flow type", context)],
             }),
             _ => Ok(ValidationResult::Passed),
         }
@@ -306,6 +350,7 @@ mod test {
             endpoints: Some(vec![Uri("https://example.com".to_string())]),
             authenticated: Some(true),
             x_trust_boundary: Some(true),
+            trust_zone: Some(NormalizedString::new("trust zone")),
             data: Some(vec![DataClassification {
                 flow: DataFlowType::Inbound,
                 classification: NormalizedString::new("classification"),
@@ -324,6 +369,8 @@ mod test {
                 value: NormalizedString::new("value"),
             }])),
             services: Some(Services(vec![])),
+            release_notes: None,
+            signature: None,
         }])
         .validate_with_context(ValidationContext::default())
         .expect("Error while validating");
@@ -347,6 +394,7 @@ mod test {
             endpoints: Some(vec![Uri("invalid url".to_string())]),
             authenticated: Some(true),
             x_trust_boundary: Some(true),
+            trust_zone: Some(NormalizedString("invalid\ttrust zone".to_string())),
             data: Some(vec![DataClassification {
                 flow: DataFlowType::UnknownDataFlow("unknown".to_string()),
                 classification: NormalizedString("invalid\tclassification".to_string()),
@@ -376,12 +424,17 @@ mod test {
                 endpoints: None,
                 authenticated: None,
                 x_trust_boundary: None,
+                trust_zone: None,
                 data: None,
                 licenses: None,
                 external_references: None,
                 properties: None,
                 services: None,
+                release_notes: None,
+                signature: None,
             }])),
+            release_notes: None,
+            signature: None,
         }])
         .validate_with_context(ValidationContext::default())
         .expect("Error while validating");
@@ -390,11 +443,9 @@ mod test {
             validation_result,
             ValidationResult::Failed {
                 reasons: vec![
-                    FailureReason {
-                        message:
-                            "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
-                                .to_string(),
-                        context: ValidationContext(vec![
+                    FailureReason::error(
+                        "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Service".to_string(),
@@ -405,58 +456,50 @@ mod test {
                                 field_name: "name".to_string()
                             },
                         ])
-                    },
-                    FailureReason {
-                        message:
-                            "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
-                                .to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Service".to_string(),
                                 field_name: "group".to_string()
                             },
                         ])
-                    },
-                    FailureReason {
-                        message:
-                            "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
-                                .to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Service".to_string(),
                                 field_name: "name".to_string()
                             },
                         ])
-                    },
-                    FailureReason {
-                        message:
-                            "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
-                                .to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Service".to_string(),
                                 field_name: "version".to_string()
                             },
                         ])
-                    },
-                    FailureReason {
-                        message:
-                            "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
-                                .to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Service".to_string(),
                                 field_name: "description".to_string()
                             },
                         ])
-                    },
-                    FailureReason {
-                        message: "Uri does not conform to ISO 8601".to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "Uri does not conform to ISO 8601",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Service".to_string(),
@@ -464,10 +507,20 @@ mod test {
                             },
                             ValidationPathComponent::Array { index: 0 },
                         ])
-                    },
-                    FailureReason {
-                        message: "Unknown data flow type".to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                        ValidationContext(vec![
+                            ValidationPathComponent::Array { index: 0 },
+                            ValidationPathComponent::Struct {
+                                struct_name: "Service".to_string(),
+                                field_name: "trust_zone".to_string()
+                            },
+                        ])
+                    ),
+                    FailureReason::error(
+                        "Unknown data flow type",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Service".to_string(),
@@ -479,12 +532,10 @@ mod test {
                                 field_name: "flow".to_string()
                             }
                         ])
-                    },
-                    FailureReason {
-                        message:
-                            "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
-                                .to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Service".to_string(),
@@ -496,10 +547,10 @@ mod test {
                                 field_name: "classification".to_string()
                             }
                         ])
-                    },
-                    FailureReason {
-                        message: "SPDX expression is not valid".to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "SPDX expression is not valid",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Service".to_string(),
@@ -510,10 +561,10 @@ mod test {
                                 variant_name: "Expression".to_string()
                             },
                         ])
-                    },
-                    FailureReason {
-                        message: "Unknown external reference type".to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "Unknown external reference type",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Service".to_string(),
@@ -525,12 +576,10 @@ mod test {
                                 field_name: "external_reference_type".to_string()
                             }
                         ])
-                    },
-                    FailureReason {
-                        message:
-                            "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
-                                .to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Service".to_string(),
@@ -542,12 +591,10 @@ mod test {
                                 field_name: "value".to_string()
                             }
                         ])
-                    },
-                    FailureReason {
-                        message:
-                            "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
-                                .to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Service".to_string(),
@@ -559,7 +606,7 @@ mod test {
                                 field_name: "name".to_string()
                             },
                         ])
-                    },
+                    ),
                 ]
             }
         );
@@ -0,0 +1,174 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use crate::validation::{
+    FailureReason, Validate, ValidationContext, ValidationError, ValidationResult,
+};
+
+/// An enveloped signature in the [JSON Signature Format](https://cyberphone.github.io/doc/security/jsf.html)
+///
+/// Signatures were added in version 1.4 of the specification and are only written to JSON
+/// documents, as the XML format uses XML Signature instead.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Signature {
+    pub algorithm: Algorithm,
+    pub value: String,
+}
+
+impl Signature {
+    /// Construct a `Signature` with the algorithm and the Base64URL encoded signature value
+    /// ```
+    /// use cyclonedx_bom::models::signature::{Algorithm, Signature};
+    ///
+    /// let signature = Signature::new(Algorithm::ES256, "MEUCIQCy");
+    /// ```
+    pub fn new(algorithm: Algorithm, value: &str) -> Self {
+        Self {
+            algorithm,
+            value: value.to_string(),
+        }
+    }
+}
+
+impl Validate for Signature {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let algorithm_context = context.extend_context_with_struct_field("Signature", "algorithm");
+
+        self.algorithm.validate_with_context(algorithm_context)
+    }
+}
+
+/// Signature algorithm, as registered for JSON Web Signatures
+#[derive(Debug, PartialEq, Eq)]
+pub enum Algorithm {
+    RS256,
+    RS384,
+    RS512,
+    PS256,
+    PS384,
+    PS512,
+    ES256,
+    ES384,
+    ES512,
+    Ed25519,
+    Ed448,
+    HS256,
+    HS384,
+    HS512,
+    #[doc(hidden)]
+    UnknownAlgorithm(String),
+}
+
+impl std::fmt::Display for Algorithm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Algorithm::RS256 => "RS256",
+            Algorithm::RS384 => "RS384",
+            Algorithm::RS512 => "RS512",
+            Algorithm::PS256 => "PS256",
+            Algorithm::PS384 => "PS384",
+            Algorithm::PS512 => "PS512",
+            Algorithm::ES256 => "ES256",
+            Algorithm::ES384 => "ES384",
+            Algorithm::ES512 => "ES512",
+            Algorithm::Ed25519 => "Ed25519",
+            Algorithm::Ed448 => "Ed448",
+            Algorithm::HS256 => "HS256",
+            Algorithm::HS384 => "HS384",
+            Algorithm::HS512 => "HS512",
+            Algorithm::UnknownAlgorithm(ua) => ua,
+        };
+        f.write_str(s)
+    }
+}
+
+impl Algorithm {
+    pub(crate) fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
+        match value.as_ref() {
+            "RS256" => Self::RS256,
+            "RS384" => Self::RS384,
+            "RS512" => Self::RS512,
+            "PS256" => Self::PS256,
+            "PS384" => Self::PS384,
+            "PS512" => Self::PS512,
+            "ES256" => Self::ES256,
+            "ES384" => Self::ES384,
+            "ES512" => Self::ES512,
+            "Ed25519" => Self::Ed25519,
+            "Ed448" => Self::Ed448,
+            "HS256" => Self::HS256,
+            "HS384" => Self::HS384,
+            "HS512" => Self::HS512,
+            unknown => Self::UnknownAlgorithm(unknown.to_string()),
+        }
+    }
+}
+
+impl Validate for Algorithm {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        match self {
+            Algorithm::UnknownAlgorithm(_) => Ok(ValidationResult::Failed {
+                reasons: vec![FailureReason::error("Unknown signature algorithm", context)],
+            }),
+            _ => Ok(ValidationResult::Passed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::validation::ValidationPathComponent;
+
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn valid_signatures_should_pass_validation() {
+        let validation_result = Signature::new(Algorithm::Ed25519, "c2lnbmF0dXJl")
+            .validate_with_context(ValidationContext::default())
+            .expect("Error while validating");
+
+        assert_eq!(validation_result, ValidationResult::Passed);
+    }
+
+    #[test]
+    fn invalid_signatures_should_fail_validation() {
+        let validation_result = Signature::new(Algorithm::new_unchecked("MD5"), "c2lnbmF0dXJl")
+            .validate_with_context(ValidationContext::default())
+            .expect("Error while validating");
+
+        assert_eq!(
+            validation_result,
+            ValidationResult::Failed {
+                reasons: vec![FailureReason::error(
+                    "Unknown signature algorithm",
+                    ValidationContext(vec![ValidationPathComponent::Struct {
+                        struct_name: "Signature".to_string(),
+                        field_name: "algorithm".to_string(),
+                    }])
+                )]
+            }
+        );
+    }
+}
@@ -17,22 +17,37 @@
  */
 
 use crate::external_models::normalized_string::NormalizedString;
+use crate::models::component::{Classification, Component, Components};
+use crate::models::external_reference::ExternalReferences;
 use crate::models::hash::Hashes;
+use crate::models::organization::OrganizationalEntity;
+use crate::models::service::{Service, Services};
 use crate::validation::{
     Validate, ValidationContext, ValidationError, ValidationPathComponent, ValidationResult,
 };
 
 /// Represents the tool used to create the BOM
 ///
-/// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.3/xml/#type_toolType)
+/// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.3/xml/#type_toolType).
+/// The `external_references` were introduced in CycloneDX 1.4 and are dropped when writing 1.3.
 #[derive(Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
 pub struct Tool {
     pub vendor: Option<NormalizedString>,
     pub name: Option<NormalizedString>,
     pub version: Option<NormalizedString>,
     pub hashes: Option<Hashes>,
+    pub external_references: Option<ExternalReferences>,
 }
 
+with_setters!(Tool {
+    with_vendor => vendor: NormalizedString,
+    with_name => name: NormalizedString,
+    with_version => version: NormalizedString,
+    with_hashes => hashes: Hashes,
+    with_external_references => external_references: ExternalReferences,
+});
+
 impl Tool {
     /// Construct a `Tool` with the vendor, name, and version
     /// ```
@@ -46,6 +61,7 @@ impl Tool {
             name: Some(NormalizedString::new(name)),
             version: Some(NormalizedString::new(version)),
             hashes: None,
+            external_references: None,
         }
     }
 }
@@ -81,14 +97,127 @@ impl Validate for Tool {
             results.push(hashes.validate_with_context(context)?);
         }
 
+        if let Some(external_references) = &self.external_references {
+            let context = context.extend_context_with_struct_field("Tool", "external_references");
+
+            results.push(external_references.validate_with_context(context)?);
+        }
+
         Ok(results
             .into_iter()
             .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
     }
 }
 
+/// The tools used to create the BOM
+///
+/// CycloneDX 1.5 deprecated the list of tools in favour of an object that describes the tools as
+/// components and services. Both forms are modelled so that neither loses information when read,
+/// and converted between with [`Tools::into_list`] and [`Tools::into_object`] when a document is
+/// written in a spec version that only supports the other form.
 #[derive(Debug, PartialEq, Eq)]
-pub struct Tools(pub Vec<Tool>);
+#[non_exhaustive]
+pub enum Tools {
+    /// The legacy array of tools, used up to CycloneDX 1.4
+    List(Vec<Tool>),
+    /// The tools as components and services, introduced in CycloneDX 1.5
+    Object {
+        components: Option<Components>,
+        services: Option<Services>,
+    },
+}
+
+impl Tools {
+    /// Returns the tools in the legacy list form
+    ///
+    /// Components and services keep their vendor, name, version, and (for components) hashes.
+    /// The vendor is taken from the supplier of a component or the provider of a service.
+    /// ```
+    /// use cyclonedx_bom::models::component::{Classification, Component, Components};
+    /// use cyclonedx_bom::models::tool::Tools;
+    ///
+    /// let tools = Tools::Object {
+    ///     components: Some(Components(vec![Component::new(
+    ///         Classification::Application,
+    ///         "cargo-cyclonedx",
+    ///         "1.0.0",
+    ///         None,
+    ///     )])),
+    ///     services: None,
+    /// };
+    /// assert_eq!(tools.into_list().len(), 1);
+    /// ```
+    pub fn into_list(self) -> Vec<Tool> {
+        match self {
+            Self::List(tools) => tools,
+            Self::Object {
+                components,
+                services,
+            } => {
+                let components = components.map(|c| c.0).unwrap_or_default();
+                let services = services.map(|s| s.0).unwrap_or_default();
+
+                components
+                    .into_iter()
+                    .map(Tool::from)
+                    .chain(services.into_iter().map(Tool::from))
+                    .collect()
+            }
+        }
+    }
+
+    /// Converts the tools to the object form, describing each legacy tool as an application
+    /// component supplied by its vendor
+    pub fn into_object(self) -> Self {
+        match self {
+            Self::List(tools) => Self::Object {
+                components: Some(Components(tools.into_iter().map(Component::from).collect())),
+                services: None,
+            },
+            object => object,
+        }
+    }
+}
+
+impl From<Component> for Tool {
+    fn from(component: Component) -> Self {
+        Self {
+            vendor: component.supplier.and_then(|supplier| supplier.name),
+            name: Some(component.name),
+            version: Some(component.version),
+            hashes: component.hashes,
+            external_references: component.external_references,
+        }
+    }
+}
+
+impl From<Service> for Tool {
+    fn from(service: Service) -> Self {
+        Self {
+            vendor: service.provider.and_then(|provider| provider.name),
+            name: Some(service.name),
+            version: service.version,
+            hashes: None,
+            external_references: service.external_references,
+        }
+    }
+}
+
+impl From<Tool> for Component {
+    fn from(tool: Tool) -> Self {
+        let mut component = Component::new(Classification::Application, "", "", None);
+        component.name = tool.name.unwrap_or_default();
+        component.version = tool.version.unwrap_or_default();
+        component.supplier = tool.vendor.map(|vendor| OrganizationalEntity {
+            name: Some(vendor),
+            url: None,
+            contact: None,
+        });
+        component.hashes = tool.hashes;
+        component.external_references = tool.external_references;
+        component
+    }
+}
 
 impl Validate for Tools {
     fn validate_with_context(
@@ -97,10 +226,28 @@ impl Validate for Tools {
     ) -> Result<ValidationResult, ValidationError> {
         let mut results: Vec<ValidationResult> = vec![];
 
-        for (index, tool) in self.0.iter().enumerate() {
-            let tool_context =
-                context.extend_context(vec![ValidationPathComponent::Array { index }]);
-            results.push(tool.validate_with_context(tool_context)?);
+        match self {
+            Self::List(tools) => {
+                for (index, tool) in tools.iter().enumerate() {
+                    let tool_context =
+                        context.extend_context(vec![ValidationPathComponent::Array { index }]);
+                    results.push(tool.validate_with_context(tool_context)?);
+                }
+            }
+            Self::Object {
+                components,
+                services,
+            } => {
+                if let Some(components) = components {
+                    let context = context.extend_context_with_struct_field("Tools", "components");
+                    results.push(components.validate_with_context(context)?);
+                }
+
+                if let Some(services) = services {
+                    let context = context.extend_context_with_struct_field("Tools", "services");
+                    results.push(services.validate_with_context(context)?);
+                }
+            }
         }
 
         Ok(results
@@ -118,11 +265,12 @@ mod test {
 
     #[test]
     fn it_should_pass_validation() {
-        let validation_result = Tools(vec![Tool {
+        let validation_result = Tools::List(vec![Tool {
             vendor: Some(NormalizedString("no_whitespace".to_string())),
             name: None,
             version: None,
             hashes: None,
+            external_references: None,
         }])
         .validate_with_context(ValidationContext::default())
         .expect("Error while validating");
@@ -132,11 +280,12 @@ mod test {
 
     #[test]
     fn it_should_fail_validation() {
-        let validation_result = Tools(vec![Tool {
+        let validation_result = Tools::List(vec![Tool {
             vendor: Some(NormalizedString("spaces and\ttabs".to_string())),
             name: None,
             version: None,
             hashes: None,
+            external_references: None,
         }])
         .validate_with_context(ValidationContext::default())
         .expect("Error while validating");
@@ -144,41 +293,43 @@ mod test {
         assert_eq!(
             validation_result,
             ValidationResult::Failed {
-                reasons: vec![FailureReason {
-                    message: "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
-                        .to_string(),
-                    context: ValidationContext(vec![
+                reasons: vec![FailureReason::error(
+                    "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                    ValidationContext(vec![
                         ValidationPathComponent::Array { index: 0 },
                         ValidationPathComponent::Struct {
                             struct_name: "Tool".to_string(),
                             field_name: "vendor".to_string(),
                         }
                     ])
-                }]
+                )]
             }
         );
     }
 
     #[test]
     fn it_should_merge_validations_correctly() {
-        let validation_result = Tools(vec![
+        let validation_result = Tools::List(vec![
             Tool {
                 vendor: Some(NormalizedString("no_whitespace".to_string())),
                 name: None,
                 version: None,
                 hashes: None,
+                external_references: None,
             },
             Tool {
                 vendor: Some(NormalizedString("spaces and\ttabs".to_string())),
                 name: None,
                 version: None,
                 hashes: None,
+                external_references: None,
             },
             Tool {
                 vendor: None,
                 name: Some(NormalizedString("spaces and\ttabs".to_string())),
                 version: None,
                 hashes: None,
+                external_references: None,
             },
         ])
         .validate_with_context(ValidationContext::default())
@@ -188,32 +339,98 @@ mod test {
             validation_result,
             ValidationResult::Failed {
                 reasons: vec![
-                    FailureReason {
-                        message:
-                            "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
-                                .to_string(),
-                        context: ValidationContext(vec![
+                    FailureReason::error(
+                        "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 1 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Tool".to_string(),
                                 field_name: "vendor".to_string(),
                             }
                         ])
-                    },
-                    FailureReason {
-                        message:
-                            "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
-                                .to_string(),
-                        context: ValidationContext(vec![
+                    ),
+                    FailureReason::error(
+                        "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                        ValidationContext(vec![
                             ValidationPathComponent::Array { index: 2 },
                             ValidationPathComponent::Struct {
                                 struct_name: "Tool".to_string(),
                                 field_name: "name".to_string(),
                             }
                         ])
-                    }
+                    )
                 ]
             }
         );
     }
+
+    #[test]
+    fn it_should_convert_the_object_form_to_a_list() {
+        let tools = Tools::Object {
+            components: Some(Components(vec![Component::new(
+                Classification::Application,
+                "cargo-cyclonedx",
+                "1.0.0",
+                None,
+            )
+            .with_supplier(OrganizationalEntity::new("CycloneDX"))])),
+            services: Some(Services(vec![
+                Service::new("scanner", None).with_version(NormalizedString::new("2.0.0"))
+            ])),
+        };
+
+        assert_eq!(
+            tools.into_list(),
+            vec![
+                Tool::new("CycloneDX", "cargo-cyclonedx", "1.0.0"),
+                Tool {
+                    vendor: None,
+                    name: Some(NormalizedString::new("scanner")),
+                    version: Some(NormalizedString::new("2.0.0")),
+                    hashes: None,
+                    external_references: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_round_trip_a_list_through_the_object_form() {
+        let list = || vec![Tool::new("CycloneDX", "cargo-cyclonedx", "1.0.0")];
+
+        assert_eq!(Tools::List(list()).into_object().into_list(), list());
+    }
+
+    #[test]
+    fn it_should_validate_the_object_form() {
+        let mut service = Service::new("scanner", None);
+        service.name = NormalizedString("spaces and\ttabs".to_string());
+
+        let validation_result = Tools::Object {
+            components: None,
+            services: Some(Services(vec![service])),
+        }
+        .validate_with_context(ValidationContext::default())
+        .expect("Error while validating");
+
+        assert_eq!(
+            validation_result,
+            ValidationResult::Failed {
+                reasons: vec![FailureReason::error(
+                    "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
+                    ValidationContext(vec![
+                        ValidationPathComponent::Struct {
+                            struct_name: "Tools".to_string(),
+                            field_name: "services".to_string(),
+                        },
+                        ValidationPathComponent::Array { index: 0 },
+                        ValidationPathComponent::Struct {
+                            struct_name: "Service".to_string(),
+                            field_name: "name".to_string(),
+                        },
+                    ])
+                )]
+            }
+        );
+    }
 }
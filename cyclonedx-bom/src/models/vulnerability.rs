@@ -0,0 +1,1159 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use crate::external_models::{date_time::DateTime, normalized_string::NormalizedString, uri::Uri};
+use crate::models::organization::{OrganizationalContact, OrganizationalEntity};
+use crate::models::property::Properties;
+use crate::models::tool::Tools;
+use crate::validation::{
+    FailureReason, Validate, ValidationContext, ValidationError, ValidationPathComponent,
+    ValidationResult,
+};
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Vulnerabilities(pub Vec<Vulnerability>);
+
+impl Validate for Vulnerabilities {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        for (index, vulnerability) in self.0.iter().enumerate() {
+            let context = context.extend_context(vec![ValidationPathComponent::Array { index }]);
+            results.push(vulnerability.validate_with_context(context)?);
+        }
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+/// A known vulnerability of the components or services of a BOM, added in version 1.4 of the
+/// specification
+///
+/// Defined via the [XML schema](https://cyclonedx.org/docs/1.4/xml/#type_vulnerabilityType)
+#[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Vulnerability {
+    pub bom_ref: Option<String>,
+    pub id: Option<NormalizedString>,
+    pub source: Option<VulnerabilitySource>,
+    pub references: Option<Vec<VulnerabilityReference>>,
+    pub ratings: Option<Vec<VulnerabilityRating>>,
+    pub cwes: Option<Vec<u32>>,
+    pub description: Option<String>,
+    pub detail: Option<String>,
+    pub recommendation: Option<String>,
+    pub advisories: Option<Vec<Advisory>>,
+    pub created: Option<DateTime>,
+    pub published: Option<DateTime>,
+    pub updated: Option<DateTime>,
+    pub credits: Option<VulnerabilityCredits>,
+    pub tools: Option<Tools>,
+    pub analysis: Option<VulnerabilityAnalysis>,
+    pub affects: Option<Vec<VulnerabilityTarget>>,
+    pub properties: Option<Properties>,
+}
+
+with_setters!(Vulnerability {
+    with_bom_ref => bom_ref: String,
+    with_source => source: VulnerabilitySource,
+    with_references => references: Vec<VulnerabilityReference>,
+    with_ratings => ratings: Vec<VulnerabilityRating>,
+    with_cwes => cwes: Vec<u32>,
+    with_description => description: String,
+    with_detail => detail: String,
+    with_recommendation => recommendation: String,
+    with_advisories => advisories: Vec<Advisory>,
+    with_created => created: DateTime,
+    with_published => published: DateTime,
+    with_updated => updated: DateTime,
+    with_credits => credits: VulnerabilityCredits,
+    with_tools => tools: Tools,
+    with_analysis => analysis: VulnerabilityAnalysis,
+    with_affects => affects: Vec<VulnerabilityTarget>,
+    with_properties => properties: Properties,
+});
+
+impl Vulnerability {
+    /// Construct a `Vulnerability` with its identifier, e.g. a CVE or GHSA identifier
+    /// ```
+    /// use cyclonedx_bom::models::vulnerability::{Vulnerability, VulnerabilitySource, VulnerabilityTarget};
+    ///
+    /// let vulnerability = Vulnerability::new(Some("RUSTSEC-2021-0078"))
+    ///     .with_source(VulnerabilitySource::new(Some("RustSec"), None))
+    ///     .with_affects(vec![VulnerabilityTarget::new("pkg:cargo/hyper@0.14.9")]);
+    /// ```
+    pub fn new(id: Option<&str>) -> Self {
+        Self {
+            bom_ref: None,
+            id: id.map(NormalizedString::new),
+            source: None,
+            references: None,
+            ratings: None,
+            cwes: None,
+            description: None,
+            detail: None,
+            recommendation: None,
+            advisories: None,
+            created: None,
+            published: None,
+            updated: None,
+            credits: None,
+            tools: None,
+            analysis: None,
+            affects: None,
+            properties: None,
+        }
+    }
+}
+
+impl Validate for Vulnerability {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        if let Some(id) = &self.id {
+            let context = context.extend_context_with_struct_field("Vulnerability", "id");
+
+            results.push(id.validate_with_context(context)?);
+        }
+
+        if let Some(source) = &self.source {
+            let context = context.extend_context_with_struct_field("Vulnerability", "source");
+
+            results.push(source.validate_with_context(context)?);
+        }
+
+        if let Some(references) = &self.references {
+            for (index, reference) in references.iter().enumerate() {
+                let context = list_context(&context, "references", index);
+                results.push(reference.validate_with_context(context)?);
+            }
+        }
+
+        if let Some(ratings) = &self.ratings {
+            for (index, rating) in ratings.iter().enumerate() {
+                let context = list_context(&context, "ratings", index);
+                results.push(rating.validate_with_context(context)?);
+            }
+        }
+
+        if let Some(advisories) = &self.advisories {
+            for (index, advisory) in advisories.iter().enumerate() {
+                let context = list_context(&context, "advisories", index);
+                results.push(advisory.validate_with_context(context)?);
+            }
+        }
+
+        for (field, timestamp) in [
+            ("created", &self.created),
+            ("published", &self.published),
+            ("updated", &self.updated),
+        ] {
+            if let Some(timestamp) = timestamp {
+                let context = context.extend_context_with_struct_field("Vulnerability", field);
+
+                results.push(timestamp.validate_with_context(context)?);
+            }
+        }
+
+        if let Some(credits) = &self.credits {
+            let context = context.extend_context_with_struct_field("Vulnerability", "credits");
+
+            results.push(credits.validate_with_context(context)?);
+        }
+
+        if let Some(tools) = &self.tools {
+            let context = context.extend_context_with_struct_field("Vulnerability", "tools");
+
+            results.push(tools.validate_with_context(context)?);
+        }
+
+        if let Some(analysis) = &self.analysis {
+            let context = context.extend_context_with_struct_field("Vulnerability", "analysis");
+
+            results.push(analysis.validate_with_context(context)?);
+        }
+
+        if let Some(affects) = &self.affects {
+            for (index, target) in affects.iter().enumerate() {
+                let context = list_context(&context, "affects", index);
+                results.push(target.validate_with_context(context)?);
+            }
+        }
+
+        if let Some(properties) = &self.properties {
+            let context = context.extend_context_with_struct_field("Vulnerability", "properties");
+
+            results.push(properties.validate_with_context(context)?);
+        }
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+fn list_context(context: &ValidationContext, field: &str, index: usize) -> ValidationContext {
+    context.extend_context(vec![
+        ValidationPathComponent::Struct {
+            struct_name: "Vulnerability".to_string(),
+            field_name: field.to_string(),
+        },
+        ValidationPathComponent::Array { index },
+    ])
+}
+
+/// The source that published a vulnerability, e.g. NVD or GitHub
+#[derive(Debug, PartialEq, Eq)]
+pub struct VulnerabilitySource {
+    pub name: Option<NormalizedString>,
+    pub url: Option<Uri>,
+}
+
+impl VulnerabilitySource {
+    pub fn new(name: Option<&str>, url: Option<Uri>) -> Self {
+        Self {
+            name: name.map(NormalizedString::new),
+            url,
+        }
+    }
+}
+
+impl Validate for VulnerabilitySource {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        if let Some(name) = &self.name {
+            let context = context.extend_context_with_struct_field("VulnerabilitySource", "name");
+
+            results.push(name.validate_with_context(context)?);
+        }
+
+        if let Some(url) = &self.url {
+            let context = context.extend_context_with_struct_field("VulnerabilitySource", "url");
+
+            results.push(url.validate_with_context(context)?);
+        }
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+/// The identifier of the same vulnerability in another source, e.g. the CVE of a GHSA
+#[derive(Debug, PartialEq, Eq)]
+pub struct VulnerabilityReference {
+    pub id: NormalizedString,
+    pub source: VulnerabilitySource,
+}
+
+impl Validate for VulnerabilityReference {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let id_context = context.extend_context_with_struct_field("VulnerabilityReference", "id");
+        let source_context =
+            context.extend_context_with_struct_field("VulnerabilityReference", "source");
+
+        Ok(self
+            .id
+            .validate_with_context(id_context)?
+            .merge(self.source.validate_with_context(source_context)?))
+    }
+}
+
+/// The severity or risk rating of a vulnerability, as scored by a method such as CVSS
+#[derive(Debug, PartialEq, Eq)]
+pub struct VulnerabilityRating {
+    pub source: Option<VulnerabilitySource>,
+    pub score: Option<Score>,
+    pub severity: Option<Severity>,
+    pub method: Option<ScoreMethod>,
+    pub vector: Option<NormalizedString>,
+    pub justification: Option<String>,
+}
+
+/// Shorthand for a [`VulnerabilityRating`]
+pub type Rating = VulnerabilityRating;
+
+with_setters!(VulnerabilityRating {
+    with_source => source: VulnerabilitySource,
+    with_vector => vector: NormalizedString,
+    with_justification => justification: String,
+});
+
+impl VulnerabilityRating {
+    /// Construct a `VulnerabilityRating` with its score, severity and scoring method
+    /// ```
+    /// use cyclonedx_bom::models::vulnerability::{Rating, Score, ScoreMethod, Severity};
+    /// use cyclonedx_bom::prelude::NormalizedString;
+    ///
+    /// let rating = Rating::new(Some(Score::new(7.5)), Some(Severity::High), Some(ScoreMethod::CVSSv31))
+    ///     .with_vector(NormalizedString::new("AV:N/AC:L/PR:N/UI:N/S:U/C:N/I:H/A:N"));
+    /// ```
+    pub fn new(
+        score: Option<Score>,
+        severity: Option<Severity>,
+        method: Option<ScoreMethod>,
+    ) -> Self {
+        Self {
+            source: None,
+            score,
+            severity,
+            method,
+            vector: None,
+            justification: None,
+        }
+    }
+}
+
+impl Validate for VulnerabilityRating {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        if let Some(source) = &self.source {
+            let context = context.extend_context_with_struct_field("VulnerabilityRating", "source");
+
+            results.push(source.validate_with_context(context)?);
+        }
+
+        if let Some(score) = &self.score {
+            let context = context.extend_context_with_struct_field("VulnerabilityRating", "score");
+
+            results.push(score.validate_with_context(context)?);
+        }
+
+        if let Some(severity) = &self.severity {
+            let context =
+                context.extend_context_with_struct_field("VulnerabilityRating", "severity");
+
+            results.push(severity.validate_with_context(context)?);
+        }
+
+        if let Some(method) = &self.method {
+            let context = context.extend_context_with_struct_field("VulnerabilityRating", "method");
+
+            results.push(method.validate_with_context(context)?);
+        }
+
+        if let Some(vector) = &self.vector {
+            let context = context.extend_context_with_struct_field("VulnerabilityRating", "vector");
+
+            results.push(vector.validate_with_context(context)?);
+        }
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+/// The numerical score of a rating, such as a CVSS base score
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Score(pub(crate) f64);
+
+// scores are read from decimals, which are never NaN
+impl Eq for Score {}
+
+impl Score {
+    pub fn new(score: f64) -> Self {
+        Self(score)
+    }
+
+    pub fn value(&self) -> f64 {
+        self.0
+    }
+}
+
+impl Validate for Score {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        if self.0.is_finite() {
+            Ok(ValidationResult::Passed)
+        } else {
+            Ok(ValidationResult::Failed {
+                reasons: vec![FailureReason::error("Score is not a number", context)],
+            })
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Severity {
+    Critical,
+    High,
+    Medium,
+    Low,
+    Info,
+    None,
+    Unknown,
+    #[doc(hidden)]
+    UnknownSeverity(String),
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Severity::Critical => "critical",
+            Severity::High => "high",
+            Severity::Medium => "medium",
+            Severity::Low => "low",
+            Severity::Info => "info",
+            Severity::None => "none",
+            Severity::Unknown => "unknown",
+            Severity::UnknownSeverity(us) => us,
+        };
+        f.write_str(s)
+    }
+}
+
+impl Severity {
+    pub(crate) fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
+        match value.as_ref() {
+            "critical" => Self::Critical,
+            "high" => Self::High,
+            "medium" => Self::Medium,
+            "low" => Self::Low,
+            "info" => Self::Info,
+            "none" => Self::None,
+            "unknown" => Self::Unknown,
+            unknown => Self::UnknownSeverity(unknown.to_string()),
+        }
+    }
+}
+
+impl Validate for Severity {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        match self {
+            Severity::UnknownSeverity(_) => Ok(ValidationResult::Failed {
+                reasons: vec![FailureReason::error("Unknown severity", context)],
+            }),
+            _ => Ok(ValidationResult::Passed),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ScoreMethod {
+    CVSSv2,
+    CVSSv3,
+    CVSSv31,
+    OWASP,
+    Other,
+    #[doc(hidden)]
+    UnknownScoreMethod(String),
+}
+
+impl std::fmt::Display for ScoreMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            ScoreMethod::CVSSv2 => "CVSSv2",
+            ScoreMethod::CVSSv3 => "CVSSv3",
+            ScoreMethod::CVSSv31 => "CVSSv31",
+            ScoreMethod::OWASP => "OWASP",
+            ScoreMethod::Other => "other",
+            ScoreMethod::UnknownScoreMethod(usm) => usm,
+        };
+        f.write_str(s)
+    }
+}
+
+impl ScoreMethod {
+    pub(crate) fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
+        match value.as_ref() {
+            "CVSSv2" => Self::CVSSv2,
+            "CVSSv3" => Self::CVSSv3,
+            "CVSSv31" => Self::CVSSv31,
+            "OWASP" => Self::OWASP,
+            "other" => Self::Other,
+            unknown => Self::UnknownScoreMethod(unknown.to_string()),
+        }
+    }
+}
+
+impl Validate for ScoreMethod {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        match self {
+            ScoreMethod::UnknownScoreMethod(_) => Ok(ValidationResult::Failed {
+                reasons: vec![FailureReason::error("Unknown score method", context)],
+            }),
+            _ => Ok(ValidationResult::Passed),
+        }
+    }
+}
+
+/// A published advisory of a vulnerability
+#[derive(Debug, PartialEq, Eq)]
+pub struct Advisory {
+    pub title: Option<NormalizedString>,
+    pub url: Uri,
+}
+
+impl Advisory {
+    /// Construct an `Advisory` with its location and an optional title
+    /// ```
+    /// use cyclonedx_bom::models::vulnerability::Advisory;
+    /// use cyclonedx_bom::prelude::Uri;
+    /// use std::convert::TryFrom;
+    ///
+    /// let url = Uri::try_from("https://rustsec.org/advisories/RUSTSEC-2021-0078".to_string())?;
+    /// let advisory = Advisory::new(Some("Lenient hyper header parsing"), url);
+    /// # Ok::<(), cyclonedx_bom::external_models::uri::UriError>(())
+    /// ```
+    pub fn new(title: Option<&str>, url: Uri) -> Self {
+        Self {
+            title: title.map(NormalizedString::new),
+            url,
+        }
+    }
+}
+
+impl Validate for Advisory {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        if let Some(title) = &self.title {
+            let context = context.extend_context_with_struct_field("Advisory", "title");
+
+            results.push(title.validate_with_context(context)?);
+        }
+
+        let url_context = context.extend_context_with_struct_field("Advisory", "url");
+        results.push(self.url.validate_with_context(url_context)?);
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+/// The organizations and individuals credited with finding a vulnerability
+#[derive(Debug, PartialEq, Eq)]
+pub struct VulnerabilityCredits {
+    pub organizations: Option<Vec<OrganizationalEntity>>,
+    pub individuals: Option<Vec<OrganizationalContact>>,
+}
+
+impl Validate for VulnerabilityCredits {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        if let Some(organizations) = &self.organizations {
+            for (index, organization) in organizations.iter().enumerate() {
+                let context = context.extend_context(vec![
+                    ValidationPathComponent::Struct {
+                        struct_name: "VulnerabilityCredits".to_string(),
+                        field_name: "organizations".to_string(),
+                    },
+                    ValidationPathComponent::Array { index },
+                ]);
+                results.push(organization.validate_with_context(context)?);
+            }
+        }
+
+        if let Some(individuals) = &self.individuals {
+            for (index, individual) in individuals.iter().enumerate() {
+                let context = context.extend_context(vec![
+                    ValidationPathComponent::Struct {
+                        struct_name: "VulnerabilityCredits".to_string(),
+                        field_name: "individuals".to_string(),
+                    },
+                    ValidationPathComponent::Array { index },
+                ]);
+                results.push(individual.validate_with_context(context)?);
+            }
+        }
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+/// The impact analysis of a vulnerability, following the states of
+/// [VEX](https://cyclonedx.org/capabilities/vex/)
+#[derive(Debug, PartialEq, Eq)]
+pub struct VulnerabilityAnalysis {
+    pub state: Option<ImpactAnalysisState>,
+    pub justification: Option<ImpactAnalysisJustification>,
+    pub response: Option<Vec<ImpactAnalysisResponse>>,
+    pub detail: Option<String>,
+}
+
+/// Shorthand for a [`VulnerabilityAnalysis`]
+pub type Analysis = VulnerabilityAnalysis;
+
+with_setters!(VulnerabilityAnalysis {
+    with_justification => justification: ImpactAnalysisJustification,
+    with_response => response: Vec<ImpactAnalysisResponse>,
+    with_detail => detail: String,
+});
+
+impl VulnerabilityAnalysis {
+    /// Construct a `VulnerabilityAnalysis` stating whether a vulnerability affects the BOM
+    /// ```
+    /// use cyclonedx_bom::models::vulnerability::{
+    ///     Analysis, ImpactAnalysisJustification, ImpactAnalysisState,
+    /// };
+    ///
+    /// let analysis = Analysis::new(ImpactAnalysisState::NotAffected)
+    ///     .with_justification(ImpactAnalysisJustification::CodeNotReachable);
+    /// ```
+    pub fn new(state: ImpactAnalysisState) -> Self {
+        Self {
+            state: Some(state),
+            justification: None,
+            response: None,
+            detail: None,
+        }
+    }
+}
+
+impl Validate for VulnerabilityAnalysis {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        if let Some(state) = &self.state {
+            let context =
+                context.extend_context_with_struct_field("VulnerabilityAnalysis", "state");
+
+            results.push(state.validate_with_context(context)?);
+        }
+
+        if let Some(justification) = &self.justification {
+            let context =
+                context.extend_context_with_struct_field("VulnerabilityAnalysis", "justification");
+
+            results.push(justification.validate_with_context(context)?);
+        }
+
+        if let Some(response) = &self.response {
+            for (index, response) in response.iter().enumerate() {
+                let context = context.extend_context(vec![
+                    ValidationPathComponent::Struct {
+                        struct_name: "VulnerabilityAnalysis".to_string(),
+                        field_name: "response".to_string(),
+                    },
+                    ValidationPathComponent::Array { index },
+                ]);
+                results.push(response.validate_with_context(context)?);
+            }
+        }
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ImpactAnalysisState {
+    Resolved,
+    ResolvedWithPedigree,
+    Exploitable,
+    InTriage,
+    FalsePositive,
+    NotAffected,
+    #[doc(hidden)]
+    UnknownImpactAnalysisState(String),
+}
+
+impl std::fmt::Display for ImpactAnalysisState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            ImpactAnalysisState::Resolved => "resolved",
+            ImpactAnalysisState::ResolvedWithPedigree => "resolved_with_pedigree",
+            ImpactAnalysisState::Exploitable => "exploitable",
+            ImpactAnalysisState::InTriage => "in_triage",
+            ImpactAnalysisState::FalsePositive => "false_positive",
+            ImpactAnalysisState::NotAffected => "not_affected",
+            ImpactAnalysisState::UnknownImpactAnalysisState(uias) => uias,
+        };
+        f.write_str(s)
+    }
+}
+
+impl ImpactAnalysisState {
+    pub(crate) fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
+        match value.as_ref() {
+            "resolved" => Self::Resolved,
+            "resolved_with_pedigree" => Self::ResolvedWithPedigree,
+            "exploitable" => Self::Exploitable,
+            "in_triage" => Self::InTriage,
+            "false_positive" => Self::FalsePositive,
+            "not_affected" => Self::NotAffected,
+            unknown => Self::UnknownImpactAnalysisState(unknown.to_string()),
+        }
+    }
+}
+
+impl Validate for ImpactAnalysisState {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        match self {
+            ImpactAnalysisState::UnknownImpactAnalysisState(_) => Ok(ValidationResult::Failed {
+                reasons: vec![FailureReason::error(
+                    "Unknown impact analysis state",
+                    context,
+                )],
+            }),
+            _ => Ok(ValidationResult::Passed),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ImpactAnalysisJustification {
+    CodeNotPresent,
+    CodeNotReachable,
+    RequiresConfiguration,
+    RequiresDependency,
+    RequiresEnvironment,
+    ProtectedByCompiler,
+    ProtectedAtRuntime,
+    ProtectedAtPerimeter,
+    ProtectedByMitigatingControl,
+    #[doc(hidden)]
+    UnknownImpactAnalysisJustification(String),
+}
+
+impl std::fmt::Display for ImpactAnalysisJustification {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            ImpactAnalysisJustification::CodeNotPresent => "code_not_present",
+            ImpactAnalysisJustification::CodeNotReachable => "code_not_reachable",
+            ImpactAnalysisJustification::RequiresConfiguration => "requires_configuration",
+            ImpactAnalysisJustification::RequiresDependency => "requires_dependency",
+            ImpactAnalysisJustification::RequiresEnvironment => "requires_environment",
+            ImpactAnalysisJustification::ProtectedByCompiler => "protected_by_compiler",
+            ImpactAnalysisJustification::ProtectedAtRuntime => "protected_at_runtime",
+            ImpactAnalysisJustification::ProtectedAtPerimeter => "protected_at_perimeter",
+            ImpactAnalysisJustification::ProtectedByMitigatingControl => {
+                "protected_by_mitigating_control"
+            }
+            ImpactAnalysisJustification::UnknownImpactAnalysisJustification(uiaj) => uiaj,
+        };
+        f.write_str(s)
+    }
+}
+
+impl ImpactAnalysisJustification {
+    pub(crate) fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
+        match value.as_ref() {
+            "code_not_present" => Self::CodeNotPresent,
+            "code_not_reachable" => Self::CodeNotReachable,
+            "requires_configuration" => Self::RequiresConfiguration,
+            "requires_dependency" => Self::RequiresDependency,
+            "requires_environment" => Self::RequiresEnvironment,
+            "protected_by_compiler" => Self::ProtectedByCompiler,
+            "protected_at_runtime" => Self::ProtectedAtRuntime,
+            "protected_at_perimeter" => Self::ProtectedAtPerimeter,
+            "protected_by_mitigating_control" => Self::ProtectedByMitigatingControl,
+            unknown => Self::UnknownImpactAnalysisJustification(unknown.to_string()),
+        }
+    }
+}
+
+impl Validate for ImpactAnalysisJustification {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        match self {
+            ImpactAnalysisJustification::UnknownImpactAnalysisJustification(_) => {
+                Ok(ValidationResult::Failed {
+                    reasons: vec![FailureReason::error(
+                        "Unknown impact analysis justification",
+                        context,
+                    )],
+                })
+            }
+            _ => Ok(ValidationResult::Passed),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ImpactAnalysisResponse {
+    CanNotFix,
+    WillNotFix,
+    Update,
+    Rollback,
+    WorkaroundAvailable,
+    #[doc(hidden)]
+    UnknownImpactAnalysisResponse(String),
+}
+
+impl std::fmt::Display for ImpactAnalysisResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            ImpactAnalysisResponse::CanNotFix => "can_not_fix",
+            ImpactAnalysisResponse::WillNotFix => "will_not_fix",
+            ImpactAnalysisResponse::Update => "update",
+            ImpactAnalysisResponse::Rollback => "rollback",
+            ImpactAnalysisResponse::WorkaroundAvailable => "workaround_available",
+            ImpactAnalysisResponse::UnknownImpactAnalysisResponse(uiar) => uiar,
+        };
+        f.write_str(s)
+    }
+}
+
+impl ImpactAnalysisResponse {
+    pub(crate) fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
+        match value.as_ref() {
+            "can_not_fix" => Self::CanNotFix,
+            "will_not_fix" => Self::WillNotFix,
+            "update" => Self::Update,
+            "rollback" => Self::Rollback,
+            "workaround_available" => Self::WorkaroundAvailable,
+            unknown => Self::UnknownImpactAnalysisResponse(unknown.to_string()),
+        }
+    }
+}
+
+impl Validate for ImpactAnalysisResponse {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        match self {
+            ImpactAnalysisResponse::UnknownImpactAnalysisResponse(_) => {
+                Ok(ValidationResult::Failed {
+                    reasons: vec![FailureReason::error(
+                        "Unknown impact analysis response",
+                        context,
+                    )],
+                })
+            }
+            _ => Ok(ValidationResult::Passed),
+        }
+    }
+}
+
+/// A component or service affected by a vulnerability, referenced by its `bom-ref`
+#[derive(Debug, PartialEq, Eq)]
+pub struct VulnerabilityTarget {
+    pub bom_ref: String,
+    pub versions: Option<Vec<AffectedVersion>>,
+}
+
+impl VulnerabilityTarget {
+    pub fn new(bom_ref: impl Into<String>) -> Self {
+        Self {
+            bom_ref: bom_ref.into(),
+            versions: None,
+        }
+    }
+}
+
+impl Validate for VulnerabilityTarget {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        if let Some(versions) = &self.versions {
+            for (index, version) in versions.iter().enumerate() {
+                let context = context.extend_context(vec![
+                    ValidationPathComponent::Struct {
+                        struct_name: "VulnerabilityTarget".to_string(),
+                        field_name: "versions".to_string(),
+                    },
+                    ValidationPathComponent::Array { index },
+                ]);
+                results.push(version.validate_with_context(context)?);
+            }
+        }
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+/// A version or range of versions of an affected component or service
+#[derive(Debug, PartialEq, Eq)]
+pub struct AffectedVersion {
+    pub version: VersionRange,
+    pub status: Option<AffectedStatus>,
+}
+
+impl Validate for AffectedVersion {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        let version_context =
+            context.extend_context_with_struct_field("AffectedVersion", "version");
+        results.push(match &self.version {
+            VersionRange::Version(version) => version.validate_with_context(version_context)?,
+            VersionRange::Range(range) => range.validate_with_context(version_context)?,
+        });
+
+        if let Some(status) = &self.status {
+            let context = context.extend_context_with_struct_field("AffectedVersion", "status");
+
+            results.push(status.validate_with_context(context)?);
+        }
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+/// A single version, or a range of versions in the
+/// [vers](https://github.com/package-url/purl-spec/blob/version-range-spec/VERSION-RANGE-SPEC.rst)
+/// syntax
+#[derive(Debug, PartialEq, Eq)]
+pub enum VersionRange {
+    Version(NormalizedString),
+    Range(NormalizedString),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum AffectedStatus {
+    Affected,
+    Unaffected,
+    Unknown,
+    #[doc(hidden)]
+    UnknownAffectedStatus(String),
+}
+
+impl std::fmt::Display for AffectedStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            AffectedStatus::Affected => "affected",
+            AffectedStatus::Unaffected => "unaffected",
+            AffectedStatus::Unknown => "unknown",
+            AffectedStatus::UnknownAffectedStatus(uas) => uas,
+        };
+        f.write_str(s)
+    }
+}
+
+impl AffectedStatus {
+    pub(crate) fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
+        match value.as_ref() {
+            "affected" => Self::Affected,
+            "unaffected" => Self::Unaffected,
+            "unknown" => Self::Unknown,
+            unknown => Self::UnknownAffectedStatus(unknown.to_string()),
+        }
+    }
+}
+
+impl Validate for AffectedStatus {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        match self {
+            AffectedStatus::UnknownAffectedStatus(_) => Ok(ValidationResult::Failed {
+                reasons: vec![FailureReason::error("Unknown affected status", context)],
+            }),
+            _ => Ok(ValidationResult::Passed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::models::bom::Bom;
+
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn example_vulnerability() -> Vulnerability {
+        Vulnerability::new(Some("RUSTSEC-2021-0078"))
+            .with_source(VulnerabilitySource::new(
+                Some("RustSec"),
+                Some(Uri(
+                    "https://rustsec.org/advisories/RUSTSEC-2021-0078".to_string()
+                )),
+            ))
+            .with_references(vec![VulnerabilityReference {
+                id: NormalizedString::new("CVE-2021-32715"),
+                source: VulnerabilitySource::new(Some("NVD"), None),
+            }])
+            .with_ratings(vec![Rating::new(
+                Some(Score::new(7.5)),
+                Some(Severity::High),
+                Some(ScoreMethod::CVSSv31),
+            )
+            .with_vector(NormalizedString::new(
+                "AV:N/AC:L/PR:N/UI:N/S:U/C:N/I:H/A:N",
+            ))])
+            .with_cwes(vec![444])
+            .with_advisories(vec![Advisory::new(
+                Some(
+                    "Lenient hyper header parsing of Content-Length could allow request smuggling",
+                ),
+                Uri(
+                    "https://github.com/hyperium/hyper/security/advisories/GHSA-f3pg-qwvg-p99c"
+                        .to_string(),
+                ),
+            )])
+            .with_analysis(
+                Analysis::new(ImpactAnalysisState::NotAffected)
+                    .with_justification(ImpactAnalysisJustification::CodeNotReachable)
+                    .with_response(vec![ImpactAnalysisResponse::Update]),
+            )
+            .with_affects(vec![VulnerabilityTarget {
+                bom_ref: "pkg:cargo/hyper@0.14.9".to_string(),
+                versions: Some(vec![AffectedVersion {
+                    version: VersionRange::Range(NormalizedString::new("vers:cargo/<0.14.10")),
+                    status: Some(AffectedStatus::Affected),
+                }]),
+            }])
+    }
+
+    #[test]
+    fn valid_vulnerabilities_should_pass_validation() {
+        let validation_result = Vulnerabilities(vec![example_vulnerability()])
+            .validate_with_context(ValidationContext::default())
+            .expect("Error while validating");
+
+        assert_eq!(validation_result, ValidationResult::Passed);
+    }
+
+    #[test]
+    fn it_should_round_trip_vulnerabilities_through_json_and_xml() {
+        let vex =
+            || Bom::default().with_vulnerabilities(Vulnerabilities(vec![example_vulnerability()]));
+        let expected = Some(Vulnerabilities(vec![example_vulnerability()]));
+
+        let mut output = Vec::new();
+        vex().output_as_json_v1_4(&mut output).unwrap();
+        let actual = Bom::parse_from_json_v1_4(output.as_slice()).unwrap();
+        assert_eq!(actual.vulnerabilities, expected);
+
+        let mut output = Vec::new();
+        vex().output_as_xml_v1_4(&mut output).unwrap();
+        let actual = Bom::parse_from_xml_v1_4(output.as_slice()).unwrap();
+        assert_eq!(actual.vulnerabilities, expected);
+
+        let mut output = Vec::new();
+        vex().output_as_json_v1_5(&mut output).unwrap();
+        let actual = Bom::parse_from_json_v1_5(output.as_slice()).unwrap();
+        assert_eq!(actual.vulnerabilities, expected);
+
+        let mut output = Vec::new();
+        vex().output_as_xml_v1_5(&mut output).unwrap();
+        let actual = Bom::parse_from_xml_v1_5(output.as_slice()).unwrap();
+        assert_eq!(actual.vulnerabilities, expected);
+    }
+
+    #[test]
+    fn invalid_vulnerabilities_should_fail_validation() {
+        let mut vulnerability = example_vulnerability();
+        vulnerability.ratings = Some(vec![VulnerabilityRating {
+            source: None,
+            score: Some(Score::new(f64::NAN)),
+            severity: Some(Severity::new_unchecked("spicy")),
+            method: None,
+            vector: None,
+            justification: None,
+        }]);
+        vulnerability.analysis = Some(VulnerabilityAnalysis {
+            state: Some(ImpactAnalysisState::new_unchecked("fine")),
+            justification: None,
+            response: None,
+            detail: None,
+        });
+
+        let validation_result = Vulnerabilities(vec![vulnerability])
+            .validate_with_context(ValidationContext::default())
+            .expect("Error while validating");
+
+        let rating_context = |field: &str| {
+            ValidationContext(vec![
+                ValidationPathComponent::Array { index: 0 },
+                ValidationPathComponent::Struct {
+                    struct_name: "Vulnerability".to_string(),
+                    field_name: "ratings".to_string(),
+                },
+                ValidationPathComponent::Array { index: 0 },
+                ValidationPathComponent::Struct {
+                    struct_name: "VulnerabilityRating".to_string(),
+                    field_name: field.to_string(),
+                },
+            ])
+        };
+        assert_eq!(
+            validation_result,
+            ValidationResult::Failed {
+                reasons: vec![
+                    FailureReason::error("Score is not a number", rating_context("score")),
+                    FailureReason::error("Unknown severity", rating_context("severity")),
+                    FailureReason::error(
+                        "Unknown impact analysis state",
+                        ValidationContext(vec![
+                            ValidationPathComponent::Array { index: 0 },
+                            ValidationPathComponent::Struct {
+                                struct_name: "Vulnerability".to_string(),
+                                field_name: "analysis".to_string(),
+                            },
+                            ValidationPathComponent::Struct {
+                                struct_name: "VulnerabilityAnalysis".to_string(),
+                                field_name: "state".to_string(),
+                            },
+                        ])
+                    ),
+                ]
+            }
+        );
+    }
+}
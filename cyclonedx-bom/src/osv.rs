@@ -0,0 +1,734 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Conversion between the vulnerabilities of a BOM and entries of the
+//! [OSV format](https://ossf.github.io/osv-schema/)
+//!
+//! An OSV entry converts into a [`Vulnerability`](models::vulnerability::Vulnerability) with
+//! [`From`], and a vulnerability converts back into an entry with [`TryFrom`], which fails when the
+//! vulnerability has no id, no timestamp or affects a target that is not identified by a Package
+//! URL. The fields map as follows:
+//!
+//! * `id`, `summary` and `details` are the id, description and detail of the vulnerability
+//! * `published` and `modified` are its published and updated timestamps, and `withdrawn` is kept
+//!   as its `osv:withdrawn` property
+//! * `aliases` are its references
+//! * `severity` entries are its ratings, with the CVSS vector
+//! * `references` are its advisories, which are written back as `ADVISORY` references
+//! * `credits` are the credited individuals and organizations
+//! * `affected` packages are its targets, referenced by their Package URL, with the `SEMVER` and
+//!   `ECOSYSTEM` ranges as `vers` ranges and the listed versions as affected versions
+//!
+//! The `related` entries, `GIT` ranges and ecosystem or database specific content of an entry and
+//! the impact analysis of a vulnerability have no counterpart in the other format and are dropped.
+//!
+//! ```
+//! use cyclonedx_bom::models::vulnerability::Vulnerability;
+//! use cyclonedx_bom::osv;
+//! use std::convert::TryFrom;
+//!
+//! let entry: osv::Vulnerability = serde_json::from_str(
+//!     r#"{
+//!       "id": "RUSTSEC-2021-0078",
+//!       "modified": "2021-07-08T00:00:00Z",
+//!       "aliases": ["CVE-2021-32715"],
+//!       "affected": [{
+//!         "package": {"ecosystem": "crates.io", "name": "hyper", "purl": "pkg:cargo/hyper"},
+//!         "ranges": [{"type": "SEMVER", "events": [{"introduced": "0"}, {"fixed": "0.14.10"}]}]
+//!       }]
+//!     }"#,
+//! )?;
+//!
+//! let vulnerability = Vulnerability::from(entry);
+//! let target = &vulnerability.affects.as_ref().expect("Missing affects")[0];
+//! assert_eq!(target.bom_ref, "pkg:cargo/hyper");
+//!
+//! let entry = osv::Vulnerability::try_from(vulnerability)?;
+//! assert_eq!(entry.aliases, vec!["CVE-2021-32715".to_string()]);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+use packageurl::PackageUrl;
+use serde::{Deserialize, Serialize};
+
+use crate::errors::OsvError;
+use crate::external_models::{date_time::DateTime, normalized_string::NormalizedString, uri::Uri};
+use crate::models;
+use crate::models::organization::{OrganizationalContact, OrganizationalEntity};
+use crate::models::property::{Properties, Property};
+use crate::models::vulnerability::{
+    Advisory, AffectedStatus, AffectedVersion, ScoreMethod, VersionRange, VulnerabilityCredits,
+    VulnerabilityRating, VulnerabilityReference, VulnerabilitySource, VulnerabilityTarget,
+};
+
+/// The property that keeps the `withdrawn` timestamp of an entry
+const WITHDRAWN_PROPERTY: &str = "osv:withdrawn";
+
+/// The Package URL types and the OSV ecosystems of their packages
+const ECOSYSTEMS: &[(&str, &str)] = &[
+    ("cargo", "crates.io"),
+    ("composer", "Packagist"),
+    ("gem", "RubyGems"),
+    ("golang", "Go"),
+    ("hex", "Hex"),
+    ("maven", "Maven"),
+    ("npm", "npm"),
+    ("nuget", "NuGet"),
+    ("pub", "Pub"),
+    ("pypi", "PyPI"),
+];
+
+/// An entry of an OSV database, describing one vulnerability
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Vulnerability {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema_version: Option<String>,
+    pub id: String,
+    pub modified: DateTime,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub published: Option<DateTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub withdrawn: Option<DateTime>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub related: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub severity: Vec<Severity>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub affected: Vec<Affected>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub references: Vec<Reference>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub credits: Vec<Credit>,
+}
+
+/// A severity score of an entry, such as a CVSS vector
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Severity {
+    #[serde(rename = "type")]
+    pub severity_type: String,
+    pub score: String,
+}
+
+/// A package affected by an entry, with the affected ranges and versions
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Affected {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub package: Option<Package>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ranges: Vec<Range>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub versions: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Package {
+    pub ecosystem: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub purl: Option<String>,
+}
+
+/// A range of affected versions, given by the events that introduce and fix the vulnerability
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Range {
+    #[serde(rename = "type")]
+    pub range_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repo: Option<String>,
+    pub events: Vec<Event>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Event {
+    Introduced(String),
+    Fixed(String),
+    LastAffected(String),
+    Limit(String),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Reference {
+    #[serde(rename = "type")]
+    pub reference_type: String,
+    pub url: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Credit {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub contact: Vec<String>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub credit_type: Option<String>,
+}
+
+impl From<Vulnerability> for models::vulnerability::Vulnerability {
+    fn from(entry: Vulnerability) -> Self {
+        let mut vulnerability = Self::new(Some(&entry.id))
+            .with_source(osv_source(&entry.id))
+            .with_updated(entry.modified);
+
+        vulnerability.published = entry.published;
+        vulnerability.description = entry.summary;
+        vulnerability.detail = entry.details;
+        vulnerability.references = non_empty(
+            entry
+                .aliases
+                .iter()
+                .map(|alias| VulnerabilityReference {
+                    id: NormalizedString::new(alias),
+                    source: osv_source(alias),
+                })
+                .collect(),
+        );
+        vulnerability.ratings = non_empty(entry.severity.iter().map(rating).collect());
+        vulnerability.advisories = non_empty(
+            entry
+                .references
+                .into_iter()
+                .map(|reference| Advisory::new(None, Uri(reference.url)))
+                .collect(),
+        );
+        if !entry.credits.is_empty() {
+            vulnerability.credits = Some(VulnerabilityCredits {
+                organizations: None,
+                individuals: Some(entry.credits.iter().map(contact).collect()),
+            });
+        }
+        vulnerability.affects = non_empty(entry.affected.into_iter().filter_map(target).collect());
+        vulnerability.properties = entry.withdrawn.map(|withdrawn| {
+            Properties(vec![Property::new(
+                WITHDRAWN_PROPERTY,
+                &withdrawn.to_string(),
+            )])
+        });
+
+        vulnerability
+    }
+}
+
+impl TryFrom<models::vulnerability::Vulnerability> for Vulnerability {
+    type Error = OsvError;
+
+    fn try_from(vulnerability: models::vulnerability::Vulnerability) -> Result<Self, Self::Error> {
+        let id = vulnerability
+            .id
+            .as_ref()
+            .map(ToString::to_string)
+            .ok_or(OsvError::MissingId)?;
+        let modified = vulnerability
+            .updated
+            .or(vulnerability.published)
+            .or(vulnerability.created)
+            .ok_or_else(|| OsvError::MissingModified { id: id.clone() })?;
+        let withdrawn = vulnerability
+            .properties
+            .iter()
+            .flat_map(|properties| &properties.0)
+            .find(|property| property.name == WITHDRAWN_PROPERTY)
+            .and_then(|property| DateTime::from_str(&property.value.to_string()).ok());
+
+        let credits = vulnerability.credits.as_ref();
+        let individuals = credits
+            .and_then(|credits| credits.individuals.as_ref())
+            .into_iter()
+            .flatten()
+            .filter_map(individual_credit);
+        let organizations = credits
+            .and_then(|credits| credits.organizations.as_ref())
+            .into_iter()
+            .flatten()
+            .filter_map(organization_credit);
+
+        Ok(Self {
+            schema_version: None,
+            modified,
+            published: vulnerability.published,
+            withdrawn,
+            aliases: vulnerability
+                .references
+                .iter()
+                .flatten()
+                .map(|reference| reference.id.to_string())
+                .collect(),
+            related: Vec::new(),
+            summary: vulnerability.description,
+            details: vulnerability.detail,
+            severity: vulnerability
+                .ratings
+                .iter()
+                .flatten()
+                .filter_map(severity)
+                .collect(),
+            affected: vulnerability
+                .affects
+                .iter()
+                .flatten()
+                .map(|target| affected(&id, target))
+                .collect::<Result<_, _>>()?,
+            references: vulnerability
+                .advisories
+                .iter()
+                .flatten()
+                .map(|advisory| Reference {
+                    reference_type: "ADVISORY".to_string(),
+                    url: advisory.url.to_string(),
+                })
+                .collect(),
+            credits: individuals.chain(organizations).collect(),
+            id,
+        })
+    }
+}
+
+fn non_empty<T>(values: Vec<T>) -> Option<Vec<T>> {
+    if values.is_empty() {
+        None
+    } else {
+        Some(values)
+    }
+}
+
+fn osv_source(id: &str) -> VulnerabilitySource {
+    VulnerabilitySource::new(
+        Some("OSV"),
+        Some(Uri(format!("https://osv.dev/vulnerability/{}", id))),
+    )
+}
+
+fn rating(severity: &Severity) -> VulnerabilityRating {
+    let method = match severity.severity_type.as_str() {
+        "CVSS_V2" => ScoreMethod::CVSSv2,
+        "CVSS_V3" if severity.score.starts_with("CVSS:3.1/") => ScoreMethod::CVSSv31,
+        "CVSS_V3" => ScoreMethod::CVSSv3,
+        _ => ScoreMethod::Other,
+    };
+
+    VulnerabilityRating::new(None, None, Some(method))
+        .with_vector(NormalizedString::new(&severity.score))
+}
+
+/// The OSV severity of a rating with a CVSS vector
+fn severity(rating: &VulnerabilityRating) -> Option<Severity> {
+    let score = rating.vector.as_ref()?.to_string();
+    let severity_type = match rating.method.as_ref()? {
+        ScoreMethod::CVSSv2 => "CVSS_V2",
+        ScoreMethod::CVSSv3 | ScoreMethod::CVSSv31 => "CVSS_V3",
+        _ if score.starts_with("CVSS:4") => "CVSS_V4",
+        _ => return None,
+    };
+
+    Some(Severity {
+        severity_type: severity_type.to_string(),
+        score,
+    })
+}
+
+fn contact(credit: &Credit) -> OrganizationalContact {
+    let email = credit
+        .contact
+        .iter()
+        .find_map(|contact| contact.strip_prefix("mailto:"));
+
+    OrganizationalContact::new(&credit.name, email)
+}
+
+fn individual_credit(individual: &OrganizationalContact) -> Option<Credit> {
+    Some(Credit {
+        name: individual.name.as_ref()?.to_string(),
+        contact: individual
+            .email
+            .iter()
+            .map(|email| format!("mailto:{}", email))
+            .collect(),
+        credit_type: None,
+    })
+}
+
+fn organization_credit(organization: &OrganizationalEntity) -> Option<Credit> {
+    Some(Credit {
+        name: organization.name.as_ref()?.to_string(),
+        contact: organization
+            .url
+            .iter()
+            .flatten()
+            .map(ToString::to_string)
+            .collect(),
+        credit_type: None,
+    })
+}
+
+fn purl_type(ecosystem: &str) -> &str {
+    ECOSYSTEMS
+        .iter()
+        .find(|(_, name)| *name == ecosystem)
+        .map_or("generic", |(purl_type, _)| purl_type)
+}
+
+fn ecosystem(purl_type: &str) -> &str {
+    ECOSYSTEMS
+        .iter()
+        .find(|(name, _)| *name == purl_type)
+        .map_or(purl_type, |(_, ecosystem)| ecosystem)
+}
+
+/// The Package URL of a package, without a version, splitting the namespace off its name
+fn package_purl(purl_type: &str, name: &str) -> Option<String> {
+    let (namespace, name) = match purl_type {
+        "maven" => name.split_once(':'),
+        "npm" if name.starts_with('@') => name.split_once('/'),
+        "composer" | "golang" => name.rsplit_once('/'),
+        _ => None,
+    }
+    .map_or((None, name), |(namespace, name)| (Some(namespace), name));
+
+    let mut purl = PackageUrl::new(purl_type, name).ok()?;
+    if let Some(namespace) = namespace {
+        purl.with_namespace(namespace);
+    }
+    Some(purl.to_string())
+}
+
+fn target(affected: Affected) -> Option<VulnerabilityTarget> {
+    let package = affected.package?;
+    let purl_type = purl_type(&package.ecosystem);
+    let bom_ref = match package.purl {
+        Some(purl) => purl,
+        None => package_purl(purl_type, &package.name)?,
+    };
+
+    let ranges = affected
+        .ranges
+        .iter()
+        .filter(|range| range.range_type == "SEMVER" || range.range_type == "ECOSYSTEM")
+        .map(|range| VersionRange::Range(NormalizedString::new(&vers(purl_type, &range.events))));
+    let versions = affected
+        .versions
+        .iter()
+        .map(|version| VersionRange::Version(NormalizedString::new(version)));
+
+    let mut target = VulnerabilityTarget::new(bom_ref);
+    target.versions = non_empty(
+        ranges
+            .chain(versions)
+            .map(|version| AffectedVersion {
+                version,
+                status: Some(AffectedStatus::Affected),
+            })
+            .collect(),
+    );
+    Some(target)
+}
+
+fn affected(id: &str, target: &VulnerabilityTarget) -> Result<Affected, OsvError> {
+    let unsupported_target = || OsvError::UnsupportedTarget {
+        id: id.to_string(),
+        bom_ref: target.bom_ref.clone(),
+    };
+    let purl = PackageUrl::from_str(&target.bom_ref).map_err(|_| unsupported_target())?;
+    let name = match (purl.ty(), purl.namespace()) {
+        ("maven", Some(namespace)) => format!("{}:{}", namespace, purl.name()),
+        (_, Some(namespace)) => format!("{}/{}", namespace, purl.name()),
+        (_, None) => purl.name().to_string(),
+    };
+
+    let mut ranges = Vec::new();
+    let mut versions = Vec::new();
+    let affected_versions = target
+        .versions
+        .iter()
+        .flatten()
+        .filter(|version| matches!(version.status, None | Some(AffectedStatus::Affected)));
+    for affected_version in affected_versions {
+        match &affected_version.version {
+            VersionRange::Version(version) => versions.push(version.to_string()),
+            VersionRange::Range(range) => ranges.push(Range {
+                range_type: range_type(purl.ty()).to_string(),
+                repo: None,
+                events: events(&range.to_string()).ok_or_else(|| {
+                    OsvError::UnsupportedVersionRange {
+                        id: id.to_string(),
+                        range: range.to_string(),
+                    }
+                })?,
+            }),
+        }
+    }
+
+    Ok(Affected {
+        package: Some(Package {
+            ecosystem: ecosystem(purl.ty()).to_string(),
+            purl: package_purl(purl.ty(), &name),
+            name,
+        }),
+        ranges,
+        versions,
+    })
+}
+
+/// The type of the ranges of an ecosystem, which is `SEMVER` for ecosystems versioned by SemVer
+fn range_type(purl_type: &str) -> &'static str {
+    match purl_type {
+        "cargo" | "golang" | "npm" => "SEMVER",
+        _ => "ECOSYSTEM",
+    }
+}
+
+/// The `vers` range of the events of an OSV range
+fn vers(purl_type: &str, events: &[Event]) -> String {
+    let constraints: Vec<String> = events
+        .iter()
+        .filter_map(|event| match event {
+            Event::Introduced(version) if version == "0" => None,
+            Event::Introduced(version) => Some(format!(">={}", version)),
+            Event::Fixed(version) | Event::Limit(version) => Some(format!("<{}", version)),
+            Event::LastAffected(version) => Some(format!("<={}", version)),
+        })
+        .collect();
+
+    if constraints.is_empty() {
+        format!("vers:{}/*", purl_type)
+    } else {
+        format!("vers:{}/{}", purl_type, constraints.join("|"))
+    }
+}
+
+/// The events of a `vers` range, or `None` for constraints that OSV cannot express, such as `>`
+/// or `!=`
+fn events(range: &str) -> Option<Vec<Event>> {
+    let (_, constraints) = range.strip_prefix("vers:")?.split_once('/')?;
+    if constraints.trim() == "*" {
+        return Some(vec![Event::Introduced("0".to_string())]);
+    }
+
+    let mut events = Vec::new();
+    for constraint in constraints.split('|').map(str::trim) {
+        let event = if let Some(version) = constraint.strip_prefix(">=") {
+            Event::Introduced(version.to_string())
+        } else if let Some(version) = constraint.strip_prefix("<=") {
+            Event::LastAffected(version.to_string())
+        } else if let Some(version) = constraint.strip_prefix('<') {
+            Event::Fixed(version.to_string())
+        } else {
+            return None;
+        };
+        events.push(event);
+    }
+
+    if !matches!(events.first(), Some(Event::Introduced(_))) {
+        events.insert(0, Event::Introduced("0".to_string()));
+    }
+    Some(events)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::models::vulnerability::{Rating, Vulnerability as CdxVulnerability};
+    use pretty_assertions::assert_eq;
+
+    fn example_entry() -> Vulnerability {
+        serde_json::from_str(
+            r#"{
+              "id": "RUSTSEC-2021-0078",
+              "modified": "2021-10-19T22:14:35Z",
+              "published": "2021-07-07T12:00:00Z",
+              "aliases": ["CVE-2021-32715", "GHSA-f3pg-qwvg-p99c"],
+              "summary": "Lenient hyper header parsing of Content-Length could allow request smuggling",
+              "details": "hyper's HTTP/1 server code had a flaw that incorrectly parses and accepts requests with a Content-Length header with a prefixed plus sign.",
+              "severity": [
+                {"type": "CVSS_V3", "score": "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:N/I:H/A:N"}
+              ],
+              "affected": [
+                {
+                  "package": {"ecosystem": "crates.io", "name": "hyper", "purl": "pkg:cargo/hyper"},
+                  "ranges": [
+                    {"type": "SEMVER", "events": [{"introduced": "0"}, {"fixed": "0.14.10"}]}
+                  ],
+                  "versions": ["0.14.9"]
+                }
+              ],
+              "references": [
+                {"type": "ADVISORY", "url": "https://github.com/hyperium/hyper/security/advisories/GHSA-f3pg-qwvg-p99c"}
+              ],
+              "credits": [
+                {"name": "Jane Doe", "contact": ["mailto:jane@example.com"]}
+              ]
+            }"#,
+        )
+        .expect("Failed to parse the OSV entry")
+    }
+
+    #[test]
+    fn it_should_convert_an_osv_entry_to_a_vulnerability() {
+        let vulnerability = CdxVulnerability::from(example_entry());
+
+        let mut target = VulnerabilityTarget::new("pkg:cargo/hyper");
+        target.versions = Some(vec![
+            AffectedVersion {
+                version: VersionRange::Range(NormalizedString::new("vers:cargo/<0.14.10")),
+                status: Some(AffectedStatus::Affected),
+            },
+            AffectedVersion {
+                version: VersionRange::Version(NormalizedString::new("0.14.9")),
+                status: Some(AffectedStatus::Affected),
+            },
+        ]);
+        let mut expected = CdxVulnerability::new(Some("RUSTSEC-2021-0078"))
+            .with_source(osv_source("RUSTSEC-2021-0078"))
+            .with_references(vec![
+                VulnerabilityReference {
+                    id: NormalizedString::new("CVE-2021-32715"),
+                    source: osv_source("CVE-2021-32715"),
+                },
+                VulnerabilityReference {
+                    id: NormalizedString::new("GHSA-f3pg-qwvg-p99c"),
+                    source: osv_source("GHSA-f3pg-qwvg-p99c"),
+                },
+            ])
+            .with_ratings(vec![Rating::new(None, None, Some(ScoreMethod::CVSSv31))
+                .with_vector(NormalizedString::new(
+                    "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:N/I:H/A:N",
+                ))])
+            .with_description(
+                "Lenient hyper header parsing of Content-Length could allow request smuggling"
+                    .to_string(),
+            )
+            .with_advisories(vec![Advisory::new(
+                None,
+                Uri(
+                    "https://github.com/hyperium/hyper/security/advisories/GHSA-f3pg-qwvg-p99c"
+                        .to_string(),
+                ),
+            )])
+            .with_published(DateTime::from_str("2021-07-07T12:00:00Z").unwrap())
+            .with_updated(DateTime::from_str("2021-10-19T22:14:35Z").unwrap())
+            .with_credits(VulnerabilityCredits {
+                organizations: None,
+                individuals: Some(vec![OrganizationalContact::new(
+                    "Jane Doe",
+                    Some("jane@example.com"),
+                )]),
+            })
+            .with_affects(vec![target]);
+        expected.detail = example_entry().details;
+
+        assert_eq!(vulnerability, expected);
+    }
+
+    #[test]
+    fn it_should_convert_a_vulnerability_back_to_the_same_entry() {
+        let mut entry = example_entry();
+        entry.withdrawn = Some(DateTime::from_str("2021-11-01T00:00:00Z").unwrap());
+
+        let vulnerability = CdxVulnerability::from(entry.clone());
+        let actual = Vulnerability::try_from(vulnerability).expect("Failed to convert");
+
+        assert_eq!(actual, entry);
+    }
+
+    #[test]
+    fn it_should_map_namespaced_package_names() {
+        for (ecosystem, name, purl) in [
+            ("npm", "@babel/core", "pkg:npm/%40babel/core"),
+            (
+                "Maven",
+                "org.yaml:snakeyaml",
+                "pkg:maven/org.yaml/snakeyaml",
+            ),
+            (
+                "Go",
+                "github.com/gin-gonic/gin",
+                "pkg:golang/github.com/gin-gonic/gin",
+            ),
+        ] {
+            let mut entry = example_entry();
+            entry.affected = vec![Affected {
+                package: Some(Package {
+                    ecosystem: ecosystem.to_string(),
+                    name: name.to_string(),
+                    purl: None,
+                }),
+                ranges: Vec::new(),
+                versions: vec!["1.0.0".to_string()],
+            }];
+
+            let vulnerability = CdxVulnerability::from(entry.clone());
+            assert_eq!(vulnerability.affects.as_ref().unwrap()[0].bom_ref, purl);
+
+            let actual = Vulnerability::try_from(vulnerability).expect("Failed to convert");
+            let package = actual.affected[0].package.as_ref().unwrap();
+            assert_eq!(package.ecosystem, ecosystem);
+            assert_eq!(package.name, name);
+        }
+    }
+
+    #[test]
+    fn it_should_fail_to_convert_vulnerabilities_osv_cannot_represent() {
+        let vulnerability =
+            CdxVulnerability::from(example_entry()).with_affects(vec![VulnerabilityTarget::new(
+                "urn:cdx:3e671687-395b-41f5-a30f-a58921a69b79/1#hyper",
+            )]);
+        assert_eq!(
+            Vulnerability::try_from(vulnerability),
+            Err(OsvError::UnsupportedTarget {
+                id: "RUSTSEC-2021-0078".to_string(),
+                bom_ref: "urn:cdx:3e671687-395b-41f5-a30f-a58921a69b79/1#hyper".to_string(),
+            })
+        );
+
+        let mut target = VulnerabilityTarget::new("pkg:cargo/hyper");
+        target.versions = Some(vec![AffectedVersion {
+            version: VersionRange::Range(NormalizedString::new("vers:cargo/>0.14.0")),
+            status: None,
+        }]);
+        let vulnerability = CdxVulnerability::from(example_entry()).with_affects(vec![target]);
+        assert_eq!(
+            Vulnerability::try_from(vulnerability),
+            Err(OsvError::UnsupportedVersionRange {
+                id: "RUSTSEC-2021-0078".to_string(),
+                range: "vers:cargo/>0.14.0".to_string(),
+            })
+        );
+
+        let mut vulnerability = CdxVulnerability::from(example_entry());
+        vulnerability.updated = None;
+        vulnerability.published = None;
+        assert_eq!(
+            Vulnerability::try_from(vulnerability),
+            Err(OsvError::MissingModified {
+                id: "RUSTSEC-2021-0078".to_string()
+            })
+        );
+
+        assert_eq!(
+            Vulnerability::try_from(CdxVulnerability::new(None)),
+            Err(OsvError::MissingId)
+        );
+    }
+}
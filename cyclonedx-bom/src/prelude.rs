@@ -19,8 +19,9 @@
 pub use crate::external_models::{
     date_time::DateTime,
     normalized_string::NormalizedString,
+    purl::Purl,
     spdx::{SpdxExpression, SpdxIdentifier},
-    uri::{Purl, Uri},
+    uri::Uri,
 };
 pub use crate::models::{
     bom::{Bom, UrnUuid},
@@ -0,0 +1,517 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Filter expressions that select components of a BOM
+//!
+//! A filter compares fields of a component with values and combines the comparisons with `&&`,
+//! `||`, `!` and parentheses, where `!` binds tighter than `&&`, which binds tighter than `||`.
+//!
+//! | Operator | Matches if the field                                   |
+//! | -------- | ------------------------------------------------------ |
+//! | `=`      | is equal to the value                                  |
+//! | `!=`     | is not equal to the value                              |
+//! | `~`      | contains the value, ignoring case                      |
+//! | `!~`     | does not contain the value, ignoring case              |
+//!
+//! The fields are `name`, `version`, `group`, `type`, `scope`, `purl`, `bom-ref`, `description`
+//! and `license`. A component can have several licenses, and `license` matches if any of them
+//! does. A component without the field never matches `=` or `~`. Values that contain spaces or
+//! operator characters have to be quoted with `"`, escaping `"` and `\` with `\`.
+//!
+//! ```
+//! use cyclonedx_bom::models::component::{Classification, Component};
+//! use cyclonedx_bom::query::Filter;
+//!
+//! let filter: Filter = "license ~ GPL || name = openssl-sys".parse()?;
+//!
+//! let component = Component::new(Classification::Library, "openssl-sys", "0.9.0", None);
+//! assert!(filter.matches(&component));
+//! # Ok::<(), cyclonedx_bom::query::FilterError>(())
+//! ```
+use crate::models::bom::Bom;
+use crate::models::component::Component;
+use crate::models::license::{LicenseChoice, LicenseIdentifier};
+
+use std::iter::Peekable;
+use std::str::{CharIndices, FromStr};
+use thiserror::Error;
+
+/// A parsed filter expression, see the [module documentation](self) for the syntax
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Filter(Expr);
+
+impl Filter {
+    /// Whether `component` matches the filter
+    pub fn matches(&self, component: &Component) -> bool {
+        self.0.matches(component)
+    }
+
+    /// Removes the top-level components of `bom` that do not match the filter, see
+    /// [`Bom::retain_components`]
+    pub fn apply(&self, bom: &mut Bom) {
+        bom.retain_components(|component| self.matches(component));
+    }
+}
+
+impl FromStr for Filter {
+    type Err = FilterError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser {
+            tokens: tokenize(s)?.into_iter().peekable(),
+        };
+        let expr = parser.parse_or()?;
+        match parser.tokens.next() {
+            Some((position, token)) => Err(FilterError::UnexpectedToken {
+                found: token.to_string(),
+                position,
+                expected: "`&&`, `||` or the end of the filter",
+            }),
+            None => Ok(Self(expr)),
+        }
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum FilterError {
+    #[error("Unexpected end of the filter, expected {0}")]
+    UnexpectedEnd(&'static str),
+
+    #[error("Unexpected {found} at position {position}, expected {expected}")]
+    UnexpectedToken {
+        found: String,
+        position: usize,
+        expected: &'static str,
+    },
+
+    #[error("Unknown field `{field}` at position {position}")]
+    UnknownField { field: String, position: usize },
+
+    #[error("Unterminated string starting at position {0}")]
+    UnterminatedString(usize),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Expr {
+    Comparison {
+        field: Field,
+        operator: Operator,
+        value: String,
+    },
+    Not(Box<Expr>),
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+}
+
+impl Expr {
+    fn matches(&self, component: &Component) -> bool {
+        match self {
+            Expr::Comparison {
+                field,
+                operator,
+                value,
+            } => operator.matches(&field.values(component), value),
+            Expr::Not(expr) => !expr.matches(component),
+            Expr::And(left, right) => left.matches(component) && right.matches(component),
+            Expr::Or(left, right) => left.matches(component) || right.matches(component),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    Name,
+    Version,
+    Group,
+    Type,
+    Scope,
+    Purl,
+    BomRef,
+    Description,
+    License,
+}
+
+impl Field {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "name" => Some(Self::Name),
+            "version" => Some(Self::Version),
+            "group" => Some(Self::Group),
+            "type" => Some(Self::Type),
+            "scope" => Some(Self::Scope),
+            "purl" => Some(Self::Purl),
+            "bom-ref" => Some(Self::BomRef),
+            "description" => Some(Self::Description),
+            "license" => Some(Self::License),
+            _ => None,
+        }
+    }
+
+    fn values(&self, component: &Component) -> Vec<String> {
+        match self {
+            Field::Name => vec![component.name.to_string()],
+            Field::Version => vec![component.version.to_string()],
+            Field::Group => component.group.iter().map(ToString::to_string).collect(),
+            Field::Type => vec![component.component_type.to_string()],
+            Field::Scope => component.scope.iter().map(ToString::to_string).collect(),
+            Field::Purl => component.purl.iter().map(ToString::to_string).collect(),
+            Field::BomRef => component.bom_ref.iter().cloned().collect(),
+            Field::Description => component
+                .description
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            Field::License => component
+                .licenses
+                .iter()
+                .flat_map(|licenses| &licenses.0)
+                .map(|license| match license {
+                    LicenseChoice::Expression(expression) => expression.to_string(),
+                    LicenseChoice::License(license) => match &license.license_identifier {
+                        LicenseIdentifier::SpdxId(id) => id.to_string(),
+                        LicenseIdentifier::Name(name) => name.to_string(),
+                    },
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operator {
+    Equal,
+    NotEqual,
+    Contains,
+    NotContains,
+}
+
+impl Operator {
+    fn matches(&self, values: &[String], expected: &str) -> bool {
+        let contains = || {
+            let expected = expected.to_lowercase();
+            values
+                .iter()
+                .any(|value| value.to_lowercase().contains(&expected))
+        };
+
+        match self {
+            Operator::Equal => values.iter().any(|value| value == expected),
+            Operator::NotEqual => !values.iter().any(|value| value == expected),
+            Operator::Contains => contains(),
+            Operator::NotContains => !contains(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Word(String),
+    Quoted(String),
+    Operator(Operator),
+    Not,
+    And,
+    Or,
+    OpenParen,
+    CloseParen,
+}
+
+impl std::fmt::Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Token::Word(word) => write!(f, "`{}`", word),
+            Token::Quoted(value) => write!(f, "{:?}", value),
+            Token::Operator(Operator::Equal) => f.write_str("`=`"),
+            Token::Operator(Operator::NotEqual) => f.write_str("`!=`"),
+            Token::Operator(Operator::Contains) => f.write_str("`~`"),
+            Token::Operator(Operator::NotContains) => f.write_str("`!~`"),
+            Token::Not => f.write_str("`!`"),
+            Token::And => f.write_str("`&&`"),
+            Token::Or => f.write_str("`||`"),
+            Token::OpenParen => f.write_str("`(`"),
+            Token::CloseParen => f.write_str("`)`"),
+        }
+    }
+}
+
+fn is_word_char(c: char) -> bool {
+    !c.is_whitespace() && !"()!=~&|\"".contains(c)
+}
+
+fn tokenize(input: &str) -> Result<Vec<(usize, Token)>, FilterError> {
+    let mut tokens = Vec::new();
+    let mut chars = input.char_indices().peekable();
+
+    while let Some((position, c)) = chars.next() {
+        let token = match c {
+            c if c.is_whitespace() => continue,
+            '(' => Token::OpenParen,
+            ')' => Token::CloseParen,
+            '=' => Token::Operator(Operator::Equal),
+            '~' => Token::Operator(Operator::Contains),
+            '!' => match chars.peek() {
+                Some((_, '=')) => {
+                    chars.next();
+                    Token::Operator(Operator::NotEqual)
+                }
+                Some((_, '~')) => {
+                    chars.next();
+                    Token::Operator(Operator::NotContains)
+                }
+                _ => Token::Not,
+            },
+            '&' | '|' => {
+                if chars.next_if(|(_, next)| *next == c).is_none() {
+                    return Err(FilterError::UnexpectedToken {
+                        found: format!("`{}`", c),
+                        position,
+                        expected: if c == '&' { "`&&`" } else { "`||`" },
+                    });
+                }
+                if c == '&' {
+                    Token::And
+                } else {
+                    Token::Or
+                }
+            }
+            '"' => Token::Quoted(quoted(&mut chars, position)?),
+            _ => {
+                let mut word = c.to_string();
+                while let Some((_, c)) = chars.next_if(|(_, c)| is_word_char(*c)) {
+                    word.push(c);
+                }
+                Token::Word(word)
+            }
+        };
+        tokens.push((position, token));
+    }
+
+    Ok(tokens)
+}
+
+fn quoted(chars: &mut Peekable<CharIndices>, start: usize) -> Result<String, FilterError> {
+    let mut value = String::new();
+    loop {
+        match chars.next() {
+            Some((_, '"')) => return Ok(value),
+            Some((_, '\\')) => match chars.next() {
+                Some((_, c)) => value.push(c),
+                None => return Err(FilterError::UnterminatedString(start)),
+            },
+            Some((_, c)) => value.push(c),
+            None => return Err(FilterError::UnterminatedString(start)),
+        }
+    }
+}
+
+struct Parser {
+    tokens: Peekable<std::vec::IntoIter<(usize, Token)>>,
+}
+
+impl Parser {
+    fn parse_or(&mut self) -> Result<Expr, FilterError> {
+        let mut expr = self.parse_and()?;
+        while self
+            .tokens
+            .next_if(|(_, token)| *token == Token::Or)
+            .is_some()
+        {
+            expr = Expr::Or(Box::new(expr), Box::new(self.parse_and()?));
+        }
+        Ok(expr)
+    }
+
+    fn parse_and(&mut self) -> Result<Expr, FilterError> {
+        let mut expr = self.parse_unary()?;
+        while self
+            .tokens
+            .next_if(|(_, token)| *token == Token::And)
+            .is_some()
+        {
+            expr = Expr::And(Box::new(expr), Box::new(self.parse_unary()?));
+        }
+        Ok(expr)
+    }
+
+    fn parse_unary(&mut self) -> Result<Expr, FilterError> {
+        const EXPECTED: &str = "a field, `!` or `(`";
+
+        match self.tokens.next() {
+            Some((_, Token::Not)) => Ok(Expr::Not(Box::new(self.parse_unary()?))),
+            Some((_, Token::OpenParen)) => {
+                let expr = self.parse_or()?;
+                match self.tokens.next() {
+                    Some((_, Token::CloseParen)) => Ok(expr),
+                    Some((position, token)) => Err(FilterError::UnexpectedToken {
+                        found: token.to_string(),
+                        position,
+                        expected: "`)`",
+                    }),
+                    None => Err(FilterError::UnexpectedEnd("`)`")),
+                }
+            }
+            Some((position, Token::Word(name))) => {
+                let field = Field::from_name(&name).ok_or(FilterError::UnknownField {
+                    field: name,
+                    position,
+                })?;
+                let operator = match self.tokens.next() {
+                    Some((_, Token::Operator(operator))) => operator,
+                    Some((position, token)) => {
+                        return Err(FilterError::UnexpectedToken {
+                            found: token.to_string(),
+                            position,
+                            expected: "`=`, `!=`, `~` or `!~`",
+                        })
+                    }
+                    None => return Err(FilterError::UnexpectedEnd("`=`, `!=`, `~` or `!~`")),
+                };
+                let value = match self.tokens.next() {
+                    Some((_, Token::Word(value))) | Some((_, Token::Quoted(value))) => value,
+                    Some((position, token)) => {
+                        return Err(FilterError::UnexpectedToken {
+                            found: token.to_string(),
+                            position,
+                            expected: "a value",
+                        })
+                    }
+                    None => return Err(FilterError::UnexpectedEnd("a value")),
+                };
+                Ok(Expr::Comparison {
+                    field,
+                    operator,
+                    value,
+                })
+            }
+            Some((position, token)) => Err(FilterError::UnexpectedToken {
+                found: token.to_string(),
+                position,
+                expected: EXPECTED,
+            }),
+            None => Err(FilterError::UnexpectedEnd(EXPECTED)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::external_models::spdx::SpdxExpression;
+    use crate::models::component::{Classification, Scope};
+    use crate::models::license::Licenses;
+    use std::convert::TryFrom;
+
+    fn component(name: &str, license: &str) -> Component {
+        let mut component = Component::new(Classification::Library, name, "1.0.0", None);
+        component.scope = Some(Scope::Required);
+        component.licenses = Some(Licenses(vec![LicenseChoice::Expression(
+            SpdxExpression::try_from(license.to_string()).unwrap(),
+        )]));
+        component
+    }
+
+    fn matches(filter: &str, component: &Component) -> bool {
+        filter
+            .parse::<Filter>()
+            .unwrap_or_else(|e| panic!("Failed to parse `{}`: {}", filter, e))
+            .matches(component)
+    }
+
+    #[test]
+    fn it_should_compare_fields() {
+        let tacos = component("tacos", "MIT OR GPL-3.0-only");
+
+        assert!(matches("name = tacos", &tacos));
+        assert!(!matches("name = taco", &tacos));
+        assert!(matches("name != taco", &tacos));
+        assert!(matches("license ~ gpl", &tacos));
+        assert!(!matches("license !~ GPL", &tacos));
+        assert!(matches("scope = required && type = library", &tacos));
+        assert!(matches(r#"description != "with \"spaces\"""#, &tacos));
+        assert!(!matches("group = tacos", &tacos));
+        assert!(matches("group != tacos", &tacos));
+    }
+
+    #[test]
+    fn it_should_combine_comparisons_with_precedence() {
+        let tacos = component("tacos", "MIT");
+
+        assert!(matches("name = tacos || name = x && name = y", &tacos));
+        assert!(!matches("(name = tacos || name = x) && name = y", &tacos));
+        assert!(matches("!name = x && !(license ~ GPL)", &tacos));
+        assert!(!matches("!!name = x", &tacos));
+    }
+
+    #[test]
+    fn it_should_report_syntax_errors() {
+        for (filter, expected) in [
+            (
+                "nom = tacos",
+                FilterError::UnknownField {
+                    field: "nom".to_string(),
+                    position: 0,
+                },
+            ),
+            ("name =", FilterError::UnexpectedEnd("a value")),
+            (
+                "name = a & name = b",
+                FilterError::UnexpectedToken {
+                    found: "`&`".to_string(),
+                    position: 9,
+                    expected: "`&&`",
+                },
+            ),
+            (
+                "name = a name = b",
+                FilterError::UnexpectedToken {
+                    found: "`name`".to_string(),
+                    position: 9,
+                    expected: "`&&`, `||` or the end of the filter",
+                },
+            ),
+            ("(name = a", FilterError::UnexpectedEnd("`)`")),
+            (r#"name = "a"#, FilterError::UnterminatedString(7)),
+        ] {
+            assert_eq!(filter.parse::<Filter>(), Err(expected), "{}", filter);
+        }
+    }
+
+    #[test]
+    fn it_should_remove_components_that_do_not_match() {
+        let mut bom = Bom::default();
+        for (name, license) in [
+            ("tacos", "MIT"),
+            ("openssl-sys", "MIT"),
+            ("gpl", "GPL-3.0-only"),
+        ] {
+            bom.add_component(component(name, license))
+                .expect("Failed to add component");
+        }
+
+        let filter: Filter = "license ~ GPL || name = openssl-sys".parse().unwrap();
+        filter.apply(&mut bom);
+
+        let actual: Vec<_> = bom
+            .components
+            .expect("Missing components")
+            .0
+            .iter()
+            .map(|component| component.name.to_string())
+            .collect();
+        assert_eq!(actual, vec!["gpl", "openssl-sys"]);
+    }
+}
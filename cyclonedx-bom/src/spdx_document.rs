@@ -0,0 +1,1420 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Conversion between BOMs and [SPDX 2.3](https://spdx.github.io/spdx-spec/v2.3/) documents, in
+//! the JSON and the tag-value format
+//!
+//! A BOM converts into an [`SpdxDocument`] with [`From`], and a document converts back into a BOM
+//! with [`TryFrom`], which fails for documents of another version than SPDX 2.2 or 2.3 and for
+//! documents with two elements of the same `SPDXID`. The metadata component and the components
+//! of the BOM, nested ones included, are the packages of the document, and the fields map as
+//! follows:
+//!
+//! * the serial number is the `documentNamespace`, a new `urn:uuid` for BOMs without one
+//! * the timestamp of the metadata is the `created` timestamp, and its tools, authors and
+//!   manufacturer are the `Tool:`, `Person:` and `Organization:` creators
+//! * the name, version, description and copyright of a component are the `name`, `versionInfo`,
+//!   `description` and `copyrightText` of its package, and its type the `primaryPackagePurpose`
+//! * the supplier and the author of a component are the `supplier` and the `originator`
+//! * its hashes are the `checksums`
+//! * its licenses, combined with `AND`, are the `licenseDeclared`
+//! * its Package URL and CPE are `purl` and `cpe23Type` external references
+//! * its `distribution` and `website` external references are the `downloadLocation` and the
+//!   `homepage`
+//! * the document `DESCRIBES` the metadata component, or the components if there is none, every
+//!   component `CONTAINS` its nested components and `DEPENDS_ON` its dependencies
+//!
+//! Everything else, such as services, vulnerabilities, properties, the scope of the components
+//! and their other external references, has no counterpart in SPDX and is dropped. So are the
+//! licenses without an SPDX identifier, and a package without a declared license has a
+//! `licenseDeclared` of `NOASSERTION`. The `SPDXID` of a package is made from the `bom-ref` of
+//! its component, and the `bom-ref` of a component converted back is the Package URL of the
+//! package, or its `SPDXID` if it has none. Files and snippets of a document are skipped.
+//!
+//! ```
+//! use cyclonedx_bom::models::bom::Bom;
+//! use cyclonedx_bom::spdx_document::SpdxDocument;
+//! use std::convert::TryFrom;
+//!
+//! let bom = Bom::parse_from_json(
+//!     r#"{
+//!       "bomFormat": "CycloneDX",
+//!       "specVersion": "1.5",
+//!       "version": 1,
+//!       "metadata": {
+//!         "component": {
+//!           "type": "application", "bom-ref": "pkg:cargo/tacos@1.0.0", "name": "tacos",
+//!           "version": "1.0.0", "purl": "pkg:cargo/tacos@1.0.0", "licenses": [{"expression": "MIT"}]
+//!         }
+//!       }
+//!     }"#
+//!     .as_bytes(),
+//! )?;
+//!
+//! let document = SpdxDocument::from(&bom);
+//! assert_eq!(document.packages[0].spdx_id, "SPDXRef-pkg-cargo-tacos-1.0.0");
+//! assert!(document.to_tag_value().contains("PackageLicenseDeclared: MIT\n"));
+//!
+//! let bom = Bom::try_from(document)?;
+//! let component = bom.metadata.and_then(|metadata| metadata.component).expect("Missing component");
+//! assert_eq!(component.bom_ref.as_deref(), Some("pkg:cargo/tacos@1.0.0"));
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::convert::TryFrom;
+use std::fmt::Write;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::errors::SpdxError;
+use crate::external_models::date_time::DateTime;
+use crate::external_models::normalized_string::NormalizedString;
+use crate::external_models::purl::Purl;
+use crate::external_models::spdx::{SpdxExpression, SpdxIdentifier};
+use crate::external_models::uri::Uri;
+use crate::models::bom::{Bom, UrnUuid};
+use crate::models::component::{Classification, Component, Components, Cpe};
+use crate::models::dependency::{Dependencies, Dependency};
+use crate::models::external_reference::{
+    ExternalReference, ExternalReferenceType, ExternalReferences,
+};
+use crate::models::hash::{Hash, HashAlgorithm, Hashes};
+use crate::models::license::{LicenseChoice, LicenseIdentifier, Licenses};
+use crate::models::metadata::Metadata;
+use crate::models::organization::{OrganizationalContact, OrganizationalEntity};
+use crate::models::tool::{Tool, Tools};
+
+const SPDX_VERSION: &str = "SPDX-2.3";
+const DATA_LICENSE: &str = "CC0-1.0";
+const DOCUMENT_ID: &str = "SPDXRef-DOCUMENT";
+const NOASSERTION: &str = "NOASSERTION";
+const NONE: &str = "NONE";
+
+/// The component types and the primary package purposes of their packages
+const PURPOSES: &[(&str, &str)] = &[
+    ("application", "APPLICATION"),
+    ("framework", "FRAMEWORK"),
+    ("library", "LIBRARY"),
+    ("container", "CONTAINER"),
+    ("operating-system", "OPERATING-SYSTEM"),
+    ("device", "DEVICE"),
+    ("firmware", "FIRMWARE"),
+    ("file", "FILE"),
+];
+
+/// The hash algorithms and the SPDX checksum algorithms of the same name
+const ALGORITHMS: &[(&str, &str)] = &[
+    ("MD5", "MD5"),
+    ("SHA-1", "SHA1"),
+    ("SHA-256", "SHA256"),
+    ("SHA-384", "SHA384"),
+    ("SHA-512", "SHA512"),
+    ("SHA3-256", "SHA3-256"),
+    ("SHA3-384", "SHA3-384"),
+    ("SHA3-512", "SHA3-512"),
+    ("BLAKE2b-256", "BLAKE2b-256"),
+    ("BLAKE2b-384", "BLAKE2b-384"),
+    ("BLAKE2b-512", "BLAKE2b-512"),
+    ("BLAKE3", "BLAKE3"),
+];
+
+/// An SPDX document, as far as it describes packages and their relationships
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpdxDocument {
+    pub spdx_version: String,
+    pub data_license: String,
+    #[serde(rename = "SPDXID")]
+    pub spdx_id: String,
+    pub name: String,
+    pub document_namespace: String,
+    pub creation_info: CreationInfo,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub packages: Vec<Package>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub relationships: Vec<Relationship>,
+}
+
+/// The creators of a document, e.g. `Tool: cargo-cyclonedx-0.3.8`, and when they created it
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct CreationInfo {
+    pub created: String,
+    #[serde(default)]
+    pub creators: Vec<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Package {
+    #[serde(rename = "SPDXID")]
+    pub spdx_id: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version_info: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supplier: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub originator: Option<String>,
+    pub download_location: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub files_analyzed: Option<bool>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub checksums: Vec<Checksum>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub homepage: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub license_concluded: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub license_declared: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub copyright_text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub external_refs: Vec<ExternalRef>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub primary_package_purpose: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Checksum {
+    pub algorithm: String,
+    pub checksum_value: String,
+}
+
+/// A reference of a package to an external source of information, e.g. its Package URL in the
+/// `PACKAGE-MANAGER` category
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExternalRef {
+    pub reference_category: String,
+    pub reference_type: String,
+    pub reference_locator: String,
+}
+
+/// A relationship between two elements of a document, e.g. `DEPENDS_ON`
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Relationship {
+    pub spdx_element_id: String,
+    pub relationship_type: String,
+    pub related_spdx_element: String,
+}
+
+impl Relationship {
+    fn new(element: &str, relationship_type: &str, related: &str) -> Self {
+        Self {
+            spdx_element_id: element.to_string(),
+            relationship_type: relationship_type.to_string(),
+            related_spdx_element: related.to_string(),
+        }
+    }
+}
+
+impl SpdxDocument {
+    /// Parses a document in the JSON or the tag-value format, telling them apart by the first
+    /// character that is not whitespace
+    pub fn parse(input: &str) -> Result<Self, SpdxError> {
+        if input.trim_start().starts_with('{') {
+            Ok(serde_json::from_str(input)?)
+        } else {
+            Self::parse_tag_value(input)
+        }
+    }
+
+    /// Parses a document in the tag-value format
+    pub fn parse_tag_value(input: &str) -> Result<Self, SpdxError> {
+        enum Section {
+            Document,
+            Package,
+            Other,
+        }
+
+        let mut document = SpdxDocument {
+            spdx_version: String::new(),
+            data_license: String::new(),
+            spdx_id: String::new(),
+            name: String::new(),
+            document_namespace: String::new(),
+            creation_info: CreationInfo::default(),
+            packages: Vec::new(),
+            relationships: Vec::new(),
+        };
+        let mut section = Section::Document;
+        let mut lines = input.lines().enumerate();
+
+        while let Some((number, line)) = lines.next() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let tag_value_error = |message: &str| SpdxError::TagValueError {
+                line: number + 1,
+                message: message.to_string(),
+            };
+            let (tag, value) = line
+                .split_once(':')
+                .ok_or_else(|| tag_value_error("is not of the form `Tag: Value`"))?;
+            let mut value = value.trim().to_string();
+            if let Some(text) = value.strip_prefix("<text>") {
+                // free-form text spans lines up to the closing tag
+                let mut text = text.to_string();
+                while !text.contains("</text>") {
+                    let (_, line) = lines
+                        .next()
+                        .ok_or_else(|| tag_value_error("opens a <text> that is never closed"))?;
+                    text.push('\n');
+                    text.push_str(line);
+                }
+                value = text[..text.find("</text>").unwrap_or(text.len())].to_string();
+            }
+
+            match (tag, &section) {
+                ("PackageName", _) => {
+                    section = Section::Package;
+                    document.packages.push(Package {
+                        name: value,
+                        ..Package::default()
+                    });
+                }
+                ("FileName" | "SnippetSPDXID" | "LicenseID", _) => section = Section::Other,
+                ("Relationship", _) => {
+                    let parts: Vec<&str> = value.split_whitespace().collect();
+                    match parts[..] {
+                        [element, relationship_type, related] => document
+                            .relationships
+                            .push(Relationship::new(element, relationship_type, related)),
+                        _ => return Err(tag_value_error("is not a relationship of two elements")),
+                    }
+                }
+                ("SPDXVersion", _) => document.spdx_version = value,
+                ("DataLicense", _) => document.data_license = value,
+                ("DocumentName", _) => document.name = value,
+                ("DocumentNamespace", _) => document.document_namespace = value,
+                ("Creator", _) => document.creation_info.creators.push(value),
+                ("Created", _) => document.creation_info.created = value,
+                ("SPDXID", Section::Document) => document.spdx_id = value,
+                (tag, Section::Package) => {
+                    let package = document
+                        .packages
+                        .last_mut()
+                        .expect("A package section starts with its PackageName");
+                    match tag {
+                        "SPDXID" => package.spdx_id = value,
+                        "PackageVersion" => package.version_info = Some(value),
+                        "PackageSupplier" => package.supplier = Some(value),
+                        "PackageOriginator" => package.originator = Some(value),
+                        "PackageDownloadLocation" => package.download_location = value,
+                        "FilesAnalyzed" => package.files_analyzed = Some(value == "true"),
+                        "PackageChecksum" => {
+                            let (algorithm, checksum) = value
+                                .split_once(':')
+                                .ok_or_else(|| tag_value_error("is not a checksum"))?;
+                            package.checksums.push(Checksum {
+                                algorithm: algorithm.trim().to_string(),
+                                checksum_value: checksum.trim().to_string(),
+                            });
+                        }
+                        "PackageHomePage" => package.homepage = Some(value),
+                        "PackageLicenseConcluded" => package.license_concluded = Some(value),
+                        "PackageLicenseDeclared" => package.license_declared = Some(value),
+                        "PackageCopyrightText" => package.copyright_text = Some(value),
+                        "PackageDescription" => package.description = Some(value),
+                        "PrimaryPackagePurpose" => package.primary_package_purpose = Some(value),
+                        "ExternalRef" => {
+                            let parts: Vec<&str> = value.split_whitespace().collect();
+                            match parts[..] {
+                                [category, reference_type, locator] => {
+                                    package.external_refs.push(ExternalRef {
+                                        reference_category: category.to_string(),
+                                        reference_type: reference_type.to_string(),
+                                        reference_locator: locator.to_string(),
+                                    })
+                                }
+                                _ => return Err(tag_value_error("is not an external reference")),
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        for (tag, value) in [
+            ("SPDXVersion", &document.spdx_version),
+            ("DocumentNamespace", &document.document_namespace),
+        ] {
+            if value.is_empty() {
+                return Err(SpdxError::MissingTag(tag.to_string()));
+            }
+        }
+        Ok(document)
+    }
+
+    /// Writes the document in the tag-value format
+    pub fn to_tag_value(&self) -> String {
+        let mut output = String::new();
+        let mut tag = |tag: &str, value: &str| {
+            if value.contains('\n') {
+                writeln!(output, "{}: <text>{}</text>", tag, value)
+            } else {
+                writeln!(output, "{}: {}", tag, value)
+            }
+            .expect("Writing to a String cannot fail");
+        };
+
+        tag("SPDXVersion", &self.spdx_version);
+        tag("DataLicense", &self.data_license);
+        tag("SPDXID", &self.spdx_id);
+        tag("DocumentName", &self.name);
+        tag("DocumentNamespace", &self.document_namespace);
+        for creator in &self.creation_info.creators {
+            tag("Creator", creator);
+        }
+        tag("Created", &self.creation_info.created);
+
+        for package in &self.packages {
+            tag("", "");
+            tag("PackageName", &package.name);
+            tag("SPDXID", &package.spdx_id);
+            let fields = [
+                ("PackageVersion", &package.version_info),
+                ("PackageSupplier", &package.supplier),
+                ("PackageOriginator", &package.originator),
+            ];
+            for (name, value) in fields {
+                if let Some(value) = value {
+                    tag(name, value);
+                }
+            }
+            tag("PackageDownloadLocation", &package.download_location);
+            if let Some(files_analyzed) = package.files_analyzed {
+                tag("FilesAnalyzed", &files_analyzed.to_string());
+            }
+            for checksum in &package.checksums {
+                tag(
+                    "PackageChecksum",
+                    &format!("{}: {}", checksum.algorithm, checksum.checksum_value),
+                );
+            }
+            let fields = [
+                ("PackageHomePage", &package.homepage),
+                ("PackageLicenseConcluded", &package.license_concluded),
+                ("PackageLicenseDeclared", &package.license_declared),
+                ("PackageCopyrightText", &package.copyright_text),
+                ("PackageDescription", &package.description),
+                ("PrimaryPackagePurpose", &package.primary_package_purpose),
+            ];
+            for (name, value) in fields {
+                if let Some(value) = value {
+                    tag(name, value);
+                }
+            }
+            for reference in &package.external_refs {
+                tag(
+                    "ExternalRef",
+                    &format!(
+                        "{} {} {}",
+                        reference.reference_category,
+                        reference.reference_type,
+                        reference.reference_locator
+                    ),
+                );
+            }
+        }
+
+        if !self.relationships.is_empty() {
+            tag("", "");
+        }
+        for relationship in &self.relationships {
+            tag(
+                "Relationship",
+                &format!(
+                    "{} {} {}",
+                    relationship.spdx_element_id,
+                    relationship.relationship_type,
+                    relationship.related_spdx_element
+                ),
+            );
+        }
+        output
+    }
+}
+
+impl From<&Bom> for SpdxDocument {
+    fn from(bom: &Bom) -> Self {
+        let metadata = bom.metadata.as_ref();
+        let root = metadata.and_then(|metadata| metadata.component.as_ref());
+
+        let mut packages = Vec::new();
+        let mut relationships = Vec::new();
+        let mut ids = PackageIds::default();
+        if let Some(root) = root {
+            let id = add_package(root, &mut packages, &mut relationships, &mut ids);
+            relationships.insert(0, Relationship::new(DOCUMENT_ID, "DESCRIBES", &id));
+        }
+        for component in bom.components.iter().flat_map(|components| &components.0) {
+            let id = add_package(component, &mut packages, &mut relationships, &mut ids);
+            if root.is_none() {
+                relationships.push(Relationship::new(DOCUMENT_ID, "DESCRIBES", &id));
+            }
+        }
+
+        for dependency in bom
+            .dependencies
+            .iter()
+            .flat_map(|dependencies| &dependencies.0)
+        {
+            let element = match ids.by_ref.get(&dependency.dependency_ref) {
+                Some(element) => element,
+                None => continue,
+            };
+            for dependency in &dependency.dependencies {
+                if let Some(related) = ids.by_ref.get(dependency) {
+                    relationships.push(Relationship::new(element, "DEPENDS_ON", related));
+                }
+            }
+        }
+
+        let namespace = bom
+            .serial_number
+            .as_ref()
+            .map_or_else(|| UrnUuid::generate().to_string(), ToString::to_string);
+        let created = metadata
+            .and_then(|metadata| metadata.timestamp.as_ref())
+            .map(ToString::to_string)
+            .or_else(|| DateTime::now().ok().map(|now| now.to_string()))
+            .unwrap_or_default();
+
+        Self {
+            spdx_version: SPDX_VERSION.to_string(),
+            data_license: DATA_LICENSE.to_string(),
+            spdx_id: DOCUMENT_ID.to_string(),
+            name: root.map_or_else(|| "bom".to_string(), |root| root.name.to_string()),
+            document_namespace: namespace,
+            creation_info: CreationInfo {
+                created,
+                creators: creators(metadata),
+            },
+            packages,
+            relationships,
+        }
+    }
+}
+
+/// The `SPDXID`s given to the packages, by the `bom-ref` of their components
+#[derive(Default)]
+struct PackageIds {
+    used: HashSet<String>,
+    by_ref: HashMap<String, String>,
+}
+
+impl PackageIds {
+    fn assign(&mut self, component: &Component) -> String {
+        let name = component
+            .bom_ref
+            .clone()
+            .unwrap_or_else(|| format!("{}-{}", component.name, component.version));
+        let sanitized: String = name
+            .chars()
+            .map(|c| match c {
+                'a'..='z' | 'A'..='Z' | '0'..='9' | '.' | '-' => c,
+                _ => '-',
+            })
+            .collect();
+
+        let mut id = format!("SPDXRef-{}", sanitized);
+        let mut suffix = 1;
+        while self.used.contains(&id) || id == DOCUMENT_ID {
+            suffix += 1;
+            id = format!("SPDXRef-{}-{}", sanitized, suffix);
+        }
+        self.used.insert(id.clone());
+        if let Some(bom_ref) = &component.bom_ref {
+            self.by_ref
+                .entry(bom_ref.clone())
+                .or_insert_with(|| id.clone());
+        }
+        id
+    }
+}
+
+/// Adds the package of `component` and those of its nested components, returning its `SPDXID`
+fn add_package(
+    component: &Component,
+    packages: &mut Vec<Package>,
+    relationships: &mut Vec<Relationship>,
+    ids: &mut PackageIds,
+) -> String {
+    let id = ids.assign(component);
+    let reference = |reference_type: ExternalReferenceType| {
+        component
+            .external_references
+            .iter()
+            .flat_map(|references| &references.0)
+            .find(|reference| reference.external_reference_type == reference_type)
+            .map(|reference| reference.url.0.clone())
+    };
+
+    let mut external_refs = Vec::new();
+    if let Some(purl) = &component.purl {
+        external_refs.push(ExternalRef {
+            reference_category: "PACKAGE-MANAGER".to_string(),
+            reference_type: "purl".to_string(),
+            reference_locator: purl.to_string(),
+        });
+    }
+    if let Some(cpe) = &component.cpe {
+        external_refs.push(ExternalRef {
+            reference_category: "SECURITY".to_string(),
+            reference_type: "cpe23Type".to_string(),
+            reference_locator: cpe.0.clone(),
+        });
+    }
+
+    let component_type = component.component_type.to_string();
+    packages.push(Package {
+        spdx_id: id.clone(),
+        name: component.name.to_string(),
+        version_info: Some(component.version.to_string()).filter(|version| !version.is_empty()),
+        supplier: component
+            .supplier
+            .as_ref()
+            .and_then(|supplier| supplier.name.as_ref())
+            .map(|name| format!("Organization: {}", name)),
+        originator: component
+            .author
+            .as_ref()
+            .map(|author| format!("Person: {}", author)),
+        download_location: reference(ExternalReferenceType::Distribution)
+            .unwrap_or_else(|| NOASSERTION.to_string()),
+        files_analyzed: Some(false),
+        checksums: component
+            .hashes
+            .iter()
+            .flat_map(|hashes| &hashes.0)
+            .filter_map(|hash| {
+                let alg = hash.alg.to_string();
+                let (_, algorithm) = ALGORITHMS.iter().find(|(name, _)| *name == alg)?;
+                Some(Checksum {
+                    algorithm: algorithm.to_string(),
+                    checksum_value: hash.content.0.clone(),
+                })
+            })
+            .collect(),
+        homepage: reference(ExternalReferenceType::Website),
+        license_concluded: Some(NOASSERTION.to_string()),
+        license_declared: Some(
+            declared_license(component.licenses.as_ref())
+                .unwrap_or_else(|| NOASSERTION.to_string()),
+        ),
+        copyright_text: Some(
+            component
+                .copyright
+                .as_ref()
+                .map_or_else(|| NOASSERTION.to_string(), ToString::to_string),
+        ),
+        description: component.description.as_ref().map(ToString::to_string),
+        external_refs,
+        primary_package_purpose: Some(
+            PURPOSES
+                .iter()
+                .find(|(name, _)| *name == component_type)
+                .map_or("OTHER", |(_, purpose)| purpose)
+                .to_string(),
+        ),
+    });
+
+    for nested in component
+        .components
+        .iter()
+        .flat_map(|components| &components.0)
+    {
+        let nested_id = add_package(nested, packages, relationships, ids);
+        relationships.push(Relationship::new(&id, "CONTAINS", &nested_id));
+    }
+    id
+}
+
+/// The licenses with an SPDX identifier or expression, combined with `AND`
+fn declared_license(licenses: Option<&Licenses>) -> Option<String> {
+    let licenses: Vec<String> = licenses
+        .iter()
+        .flat_map(|licenses| &licenses.0)
+        .filter_map(|license| match license {
+            LicenseChoice::License(license) => match &license.license_identifier {
+                LicenseIdentifier::SpdxId(id) => Some(id.to_string()),
+                LicenseIdentifier::Name(_) => None,
+            },
+            LicenseChoice::Expression(expression) => Some(expression.to_string()),
+        })
+        .collect();
+
+    match licenses.len() {
+        0 => None,
+        1 => licenses.into_iter().next(),
+        _ => Some(
+            licenses
+                .iter()
+                .map(|license| format!("({})", license))
+                .collect::<Vec<_>>()
+                .join(" AND "),
+        ),
+    }
+}
+
+fn creators(metadata: Option<&Metadata>) -> Vec<String> {
+    let mut creators = Vec::new();
+    let metadata = match metadata {
+        Some(metadata) => metadata,
+        None => return vec![default_creator()],
+    };
+
+    let tool_creator = |name: Option<&NormalizedString>, version: Option<&NormalizedString>| {
+        name.map(|name| match version {
+            Some(version) => format!("Tool: {}-{}", name, version),
+            None => format!("Tool: {}", name),
+        })
+    };
+    match &metadata.tools {
+        Some(Tools::List(tools)) => creators.extend(
+            tools
+                .iter()
+                .filter_map(|tool| tool_creator(tool.name.as_ref(), tool.version.as_ref())),
+        ),
+        Some(Tools::Object { components, .. }) => creators.extend(
+            components
+                .iter()
+                .flat_map(|components| &components.0)
+                .filter_map(|tool| tool_creator(Some(&tool.name), Some(&tool.version))),
+        ),
+        None => {}
+    }
+    for author in metadata.authors.iter().flatten() {
+        match (&author.name, &author.email) {
+            (Some(name), Some(email)) => creators.push(format!("Person: {} ({})", name, email)),
+            (Some(name), None) => creators.push(format!("Person: {}", name)),
+            _ => {}
+        }
+    }
+    if let Some(name) = metadata
+        .manufacture
+        .as_ref()
+        .and_then(|manufacture| manufacture.name.as_ref())
+    {
+        creators.push(format!("Organization: {}", name));
+    }
+
+    if creators.is_empty() {
+        creators.push(default_creator());
+    }
+    creators
+}
+
+/// SPDX requires a creator, which is the library itself for BOMs that do not name any
+fn default_creator() -> String {
+    format!("Tool: cyclonedx-bom-{}", env!("CARGO_PKG_VERSION"))
+}
+
+impl TryFrom<SpdxDocument> for Bom {
+    type Error = SpdxError;
+
+    fn try_from(document: SpdxDocument) -> Result<Self, Self::Error> {
+        if !["SPDX-2.2", SPDX_VERSION].contains(&document.spdx_version.as_str()) {
+            return Err(SpdxError::UnsupportedVersion(document.spdx_version));
+        }
+
+        let mut by_id: HashMap<String, usize> = HashMap::new();
+        for (index, package) in document.packages.iter().enumerate() {
+            if by_id.insert(package.spdx_id.clone(), index).is_some() {
+                return Err(SpdxError::DuplicateId(package.spdx_id.clone()));
+            }
+        }
+
+        let mut described = Vec::new();
+        let mut parents: HashMap<usize, usize> = HashMap::new();
+        let mut dependencies: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+        let mut has_dependencies = false;
+        for relationship in &document.relationships {
+            let element = by_id.get(&relationship.spdx_element_id).copied();
+            let related = by_id.get(&relationship.related_spdx_element).copied();
+            match (relationship.relationship_type.as_str(), element, related) {
+                ("DESCRIBES", None, Some(related))
+                    if relationship.spdx_element_id == document.spdx_id =>
+                {
+                    described.push(related)
+                }
+                ("DESCRIBED_BY", Some(element), None)
+                    if relationship.related_spdx_element == document.spdx_id =>
+                {
+                    described.push(element)
+                }
+                ("CONTAINS", Some(element), Some(related)) => {
+                    parents.entry(related).or_insert(element);
+                }
+                ("CONTAINED_BY", Some(element), Some(related)) => {
+                    parents.entry(element).or_insert(related);
+                }
+                ("DEPENDS_ON", Some(element), Some(related)) => {
+                    has_dependencies = true;
+                    dependencies.entry(element).or_default().push(related);
+                }
+                ("DEPENDENCY_OF", Some(element), Some(related)) => {
+                    has_dependencies = true;
+                    dependencies.entry(related).or_default().push(element);
+                }
+                _ => {}
+            }
+        }
+
+        // a single described package is the subject of the BOM, several are its components
+        let root = match described[..] {
+            [root] => {
+                parents.remove(&root);
+                Some(root)
+            }
+            _ => None,
+        };
+        let mut children: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+        for (child, parent) in &parents {
+            children.entry(*parent).or_default().push(*child);
+        }
+        for nested in children.values_mut() {
+            nested.sort_unstable();
+        }
+
+        let purls: Vec<Option<String>> = document.packages.iter().map(package_purl).collect();
+        let bom_refs: Vec<String> = document
+            .packages
+            .iter()
+            .zip(&purls)
+            .map(|(package, purl)| {
+                let shared = purls.iter().filter(|other| *other == purl).count() > 1;
+                match purl {
+                    Some(purl) if !shared => purl.clone(),
+                    _ => package.spdx_id.clone(),
+                }
+            })
+            .collect();
+
+        let mut placed = HashSet::new();
+        let root_component = root.map(|root| {
+            build_component(root, &document.packages, &bom_refs, &children, &mut placed)
+        });
+        let mut components = Vec::new();
+        for index in 0..document.packages.len() {
+            if Some(index) != root && !parents.contains_key(&index) && !placed.contains(&index) {
+                components.push(build_component(
+                    index,
+                    &document.packages,
+                    &bom_refs,
+                    &children,
+                    &mut placed,
+                ));
+            }
+        }
+        // packages that contain each other are listed at the top level rather than dropped
+        for index in 0..document.packages.len() {
+            if !placed.contains(&index) {
+                components.push(build_component(
+                    index,
+                    &document.packages,
+                    &bom_refs,
+                    &children,
+                    &mut placed,
+                ));
+            }
+        }
+
+        let dependencies = if has_dependencies {
+            Some(Dependencies(
+                (0..document.packages.len())
+                    .map(|index| {
+                        Dependency::new(
+                            bom_refs[index].clone(),
+                            dependencies
+                                .get(&index)
+                                .into_iter()
+                                .flatten()
+                                .map(|dependency| bom_refs[*dependency].clone())
+                                .collect(),
+                        )
+                    })
+                    .collect(),
+            ))
+        } else {
+            None
+        };
+
+        Ok(Bom {
+            serial_number: UrnUuid::new(document.document_namespace).ok(),
+            metadata: Some(metadata(&document.creation_info, root_component)),
+            components: Some(Components(components)).filter(|components| !components.0.is_empty()),
+            dependencies,
+            ..Bom::default()
+        })
+    }
+}
+
+fn package_purl(package: &Package) -> Option<String> {
+    package
+        .external_refs
+        .iter()
+        .find(|reference| {
+            ["PACKAGE-MANAGER", "PACKAGE_MANAGER"].contains(&reference.reference_category.as_str())
+                && reference.reference_type == "purl"
+        })
+        .map(|reference| reference.reference_locator.clone())
+}
+
+fn build_component(
+    index: usize,
+    packages: &[Package],
+    bom_refs: &[String],
+    children: &BTreeMap<usize, Vec<usize>>,
+    placed: &mut HashSet<usize>,
+) -> Component {
+    placed.insert(index);
+    let package = &packages[index];
+    let classification = package
+        .primary_package_purpose
+        .as_ref()
+        .and_then(|purpose| PURPOSES.iter().find(|(_, name)| name == purpose))
+        .map_or("library", |(classification, _)| classification);
+
+    let mut component = Component::new(
+        Classification::new_unchecked(classification),
+        &package.name,
+        package.version_info.as_deref().unwrap_or_default(),
+        Some(bom_refs[index].clone()),
+    );
+    component.supplier = package
+        .supplier
+        .as_deref()
+        .and_then(party_name)
+        .map(OrganizationalEntity::new);
+    component.author = package
+        .originator
+        .as_deref()
+        .and_then(party_name)
+        .map(NormalizedString::new);
+    component.description = package.description.as_deref().map(NormalizedString::new);
+    component.copyright = package
+        .copyright_text
+        .as_deref()
+        .filter(|copyright| ![NOASSERTION, NONE].contains(copyright))
+        .map(NormalizedString::new);
+    component.purl = package_purl(package).map(Purl);
+    component.cpe = package
+        .external_refs
+        .iter()
+        .find(|reference| ["cpe23Type", "cpe22Type"].contains(&reference.reference_type.as_str()))
+        .map(|reference| Cpe(reference.reference_locator.clone()));
+
+    let hashes: Vec<Hash> = package
+        .checksums
+        .iter()
+        .filter_map(|checksum| {
+            let (alg, _) = ALGORITHMS
+                .iter()
+                .find(|(_, algorithm)| *algorithm == checksum.algorithm)?;
+            Some(Hash::new(
+                HashAlgorithm::new_unchecked(alg),
+                &checksum.checksum_value,
+            ))
+        })
+        .collect();
+    component.hashes = Some(Hashes(hashes)).filter(|hashes| !hashes.0.is_empty());
+
+    let license = package
+        .license_declared
+        .iter()
+        .chain(&package.license_concluded)
+        .find(|license| ![NOASSERTION, NONE].contains(&license.as_str()));
+    component.licenses = license.and_then(|license| {
+        let choice = match SpdxIdentifier::try_from(license.clone()) {
+            Ok(id) => LicenseChoice::License(crate::models::license::License {
+                license_identifier: LicenseIdentifier::SpdxId(id),
+                text: None,
+                url: None,
+            }),
+            Err(_) => LicenseChoice::Expression(SpdxExpression::try_from(license.clone()).ok()?),
+        };
+        Some(Licenses(vec![choice]))
+    });
+
+    let mut references = Vec::new();
+    let locations = [
+        (
+            ExternalReferenceType::Distribution,
+            Some(&package.download_location),
+        ),
+        (ExternalReferenceType::Website, package.homepage.as_ref()),
+    ];
+    for (reference_type, location) in locations {
+        if let Some(location) = location.filter(|l| ![NOASSERTION, NONE].contains(&l.as_str())) {
+            references.push(ExternalReference::new(
+                reference_type,
+                Uri(location.clone()),
+            ));
+        }
+    }
+    component.external_references =
+        Some(ExternalReferences(references)).filter(|references| !references.0.is_empty());
+
+    let nested: Vec<Component> = children
+        .get(&index)
+        .into_iter()
+        .flatten()
+        .filter(|child| !placed.contains(*child))
+        .copied()
+        .collect::<Vec<_>>()
+        .into_iter()
+        .map(|child| build_component(child, packages, bom_refs, children, placed))
+        .collect();
+    component.components = Some(Components(nested)).filter(|nested| !nested.0.is_empty());
+    component
+}
+
+/// The name of a `Person: name (email)` or `Organization: name (email)`
+fn party_name(party: &str) -> Option<&str> {
+    let (_, name) = party.split_once(':')?;
+    let name = name.split(" (").next().unwrap_or(name).trim();
+    Some(name).filter(|name| !name.is_empty() && *name != NOASSERTION)
+}
+
+fn metadata(creation_info: &CreationInfo, component: Option<Component>) -> Metadata {
+    let mut tools = Vec::new();
+    let mut authors = Vec::new();
+    let mut manufacture = None;
+    for creator in &creation_info.creators {
+        let (kind, name) = match creator.split_once(':') {
+            Some((kind, name)) => (kind.trim(), name.trim()),
+            None => continue,
+        };
+        match kind {
+            "Tool" => {
+                // the version follows the last hyphen, e.g. `cargo-cyclonedx-0.3.8`
+                let tool = match name.rsplit_once('-') {
+                    Some((name, version)) if version.starts_with(|c: char| c.is_ascii_digit()) => {
+                        Tool {
+                            name: Some(NormalizedString::new(name)),
+                            version: Some(NormalizedString::new(version)),
+                            ..Tool::default()
+                        }
+                    }
+                    _ => Tool {
+                        name: Some(NormalizedString::new(name)),
+                        ..Tool::default()
+                    },
+                };
+                tools.push(tool);
+            }
+            "Person" => {
+                let email = name
+                    .split_once(" (")
+                    .map(|(_, email)| email.trim_end_matches(')'))
+                    .filter(|email| !email.is_empty());
+                if let Some(name) = party_name(creator) {
+                    authors.push(OrganizationalContact::new(name, email));
+                }
+            }
+            "Organization" if manufacture.is_none() => {
+                manufacture = party_name(creator).map(OrganizationalEntity::new);
+            }
+            _ => {}
+        }
+    }
+
+    Metadata {
+        timestamp: DateTime::from_str(&creation_info.created).ok(),
+        tools: Some(Tools::List(tools))
+            .filter(|tools| !matches!(tools, Tools::List(list) if list.is_empty())),
+        authors: Some(authors).filter(|authors| !authors.is_empty()),
+        component,
+        manufacture,
+        ..Metadata::default()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn example_bom() -> Bom {
+        Bom::parse_from_json(
+            r#"{
+              "bomFormat": "CycloneDX",
+              "specVersion": "1.5",
+              "serialNumber": "urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79",
+              "version": 1,
+              "metadata": {
+                "timestamp": "2023-06-01T12:00:00Z",
+                "tools": [{"vendor": "CycloneDX", "name": "cargo-cyclonedx", "version": "0.3.8"}],
+                "authors": [{"name": "Jane Doe", "email": "jane@example.com"}],
+                "component": {
+                  "type": "application",
+                  "bom-ref": "pkg:cargo/tacos@1.0.0",
+                  "name": "tacos",
+                  "version": "1.0.0",
+                  "purl": "pkg:cargo/tacos@1.0.0",
+                  "licenses": [{"expression": "MIT OR Apache-2.0"}],
+                  "components": [
+                    {
+                      "type": "file",
+                      "bom-ref": "pkg:cargo/tacos@1.0.0#src/main.rs",
+                      "name": "src/main.rs",
+                      "version": "",
+                      "purl": "pkg:cargo/tacos@1.0.0#src/main.rs",
+                      "hashes": [{"alg": "SHA-256", "content": "72b64191b275b66ffe2469e8af2c1cfe3bafa67b529ead792a6d0160888b4237"}]
+                    }
+                  ]
+                }
+              },
+              "components": [
+                {
+                  "type": "library",
+                  "bom-ref": "pkg:cargo/salsa@0.2.0",
+                  "supplier": {"name": "Salsa Inc"},
+                  "author": "John Doe",
+                  "name": "salsa",
+                  "version": "0.2.0",
+                  "description": "Spicy sauces",
+                  "hashes": [
+                    {"alg": "SHA-256", "content": "aa4d5b1bd1ab1c4ba22e3e8b3d0e6f3eab5bbf4e4b4e2b7b1a9c2b4e3d6f5a4b"},
+                    {"alg": "SHA3-384", "content": "ab"}
+                  ],
+                  "licenses": [{"license": {"id": "MIT"}}, {"license": {"name": "Salsa License"}}],
+                  "copyright": "Copyright Salsa Inc",
+                  "cpe": "cpe:2.3:a:salsa:salsa:0.2.0:*:*:*:*:*:*:*",
+                  "purl": "pkg:cargo/salsa@0.2.0",
+                  "externalReferences": [
+                    {"type": "distribution", "url": "https://crates.io/api/v1/crates/salsa/0.2.0/download"},
+                    {"type": "website", "url": "https://salsa.example.com"},
+                    {"type": "vcs", "url": "https://github.com/example/salsa"}
+                  ]
+                }
+              ],
+              "dependencies": [
+                {"ref": "pkg:cargo/tacos@1.0.0", "dependsOn": ["pkg:cargo/salsa@0.2.0"]},
+                {"ref": "pkg:cargo/salsa@0.2.0", "dependsOn": []}
+              ]
+            }"#
+            .as_bytes(),
+        )
+        .expect("Failed to parse the BOM")
+    }
+
+    fn example_document() -> SpdxDocument {
+        SpdxDocument {
+            spdx_version: "SPDX-2.3".to_string(),
+            data_license: "CC0-1.0".to_string(),
+            spdx_id: "SPDXRef-DOCUMENT".to_string(),
+            name: "tacos".to_string(),
+            document_namespace: "urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79".to_string(),
+            creation_info: CreationInfo {
+                created: "2023-06-01T12:00:00Z".to_string(),
+                creators: vec![
+                    "Tool: cargo-cyclonedx-0.3.8".to_string(),
+                    "Person: Jane Doe (jane@example.com)".to_string(),
+                ],
+            },
+            packages: vec![
+                Package {
+                    spdx_id: "SPDXRef-pkg-cargo-tacos-1.0.0".to_string(),
+                    name: "tacos".to_string(),
+                    version_info: Some("1.0.0".to_string()),
+                    download_location: "NOASSERTION".to_string(),
+                    files_analyzed: Some(false),
+                    license_concluded: Some("NOASSERTION".to_string()),
+                    license_declared: Some("MIT OR Apache-2.0".to_string()),
+                    copyright_text: Some("NOASSERTION".to_string()),
+                    external_refs: vec![ExternalRef {
+                        reference_category: "PACKAGE-MANAGER".to_string(),
+                        reference_type: "purl".to_string(),
+                        reference_locator: "pkg:cargo/tacos@1.0.0".to_string(),
+                    }],
+                    primary_package_purpose: Some("APPLICATION".to_string()),
+                    ..Package::default()
+                },
+                Package {
+                    spdx_id: "SPDXRef-pkg-cargo-tacos-1.0.0-src-main.rs".to_string(),
+                    name: "src/main.rs".to_string(),
+                    download_location: "NOASSERTION".to_string(),
+                    files_analyzed: Some(false),
+                    checksums: vec![Checksum {
+                        algorithm: "SHA256".to_string(),
+                        checksum_value:
+                            "72b64191b275b66ffe2469e8af2c1cfe3bafa67b529ead792a6d0160888b4237"
+                                .to_string(),
+                    }],
+                    license_concluded: Some("NOASSERTION".to_string()),
+                    license_declared: Some("NOASSERTION".to_string()),
+                    copyright_text: Some("NOASSERTION".to_string()),
+                    external_refs: vec![ExternalRef {
+                        reference_category: "PACKAGE-MANAGER".to_string(),
+                        reference_type: "purl".to_string(),
+                        reference_locator: "pkg:cargo/tacos@1.0.0#src/main.rs".to_string(),
+                    }],
+                    primary_package_purpose: Some("FILE".to_string()),
+                    ..Package::default()
+                },
+                Package {
+                    spdx_id: "SPDXRef-pkg-cargo-salsa-0.2.0".to_string(),
+                    name: "salsa".to_string(),
+                    version_info: Some("0.2.0".to_string()),
+                    supplier: Some("Organization: Salsa Inc".to_string()),
+                    originator: Some("Person: John Doe".to_string()),
+                    download_location: "https://crates.io/api/v1/crates/salsa/0.2.0/download"
+                        .to_string(),
+                    files_analyzed: Some(false),
+                    checksums: vec![
+                        Checksum {
+                            algorithm: "SHA256".to_string(),
+                            checksum_value:
+                                "aa4d5b1bd1ab1c4ba22e3e8b3d0e6f3eab5bbf4e4b4e2b7b1a9c2b4e3d6f5a4b"
+                                    .to_string(),
+                        },
+                        Checksum {
+                            algorithm: "SHA3-384".to_string(),
+                            checksum_value: "ab".to_string(),
+                        },
+                    ],
+                    homepage: Some("https://salsa.example.com".to_string()),
+                    license_concluded: Some("NOASSERTION".to_string()),
+                    license_declared: Some("MIT".to_string()),
+                    copyright_text: Some("Copyright Salsa Inc".to_string()),
+                    description: Some("Spicy sauces".to_string()),
+                    external_refs: vec![
+                        ExternalRef {
+                            reference_category: "PACKAGE-MANAGER".to_string(),
+                            reference_type: "purl".to_string(),
+                            reference_locator: "pkg:cargo/salsa@0.2.0".to_string(),
+                        },
+                        ExternalRef {
+                            reference_category: "SECURITY".to_string(),
+                            reference_type: "cpe23Type".to_string(),
+                            reference_locator: "cpe:2.3:a:salsa:salsa:0.2.0:*:*:*:*:*:*:*"
+                                .to_string(),
+                        },
+                    ],
+                    primary_package_purpose: Some("LIBRARY".to_string()),
+                },
+            ],
+            relationships: vec![
+                Relationship::new(
+                    "SPDXRef-DOCUMENT",
+                    "DESCRIBES",
+                    "SPDXRef-pkg-cargo-tacos-1.0.0",
+                ),
+                Relationship::new(
+                    "SPDXRef-pkg-cargo-tacos-1.0.0",
+                    "CONTAINS",
+                    "SPDXRef-pkg-cargo-tacos-1.0.0-src-main.rs",
+                ),
+                Relationship::new(
+                    "SPDXRef-pkg-cargo-tacos-1.0.0",
+                    "DEPENDS_ON",
+                    "SPDXRef-pkg-cargo-salsa-0.2.0",
+                ),
+            ],
+        }
+    }
+
+    #[test]
+    fn it_should_convert_a_bom_into_a_document() {
+        assert_eq!(SpdxDocument::from(&example_bom()), example_document());
+    }
+
+    #[test]
+    fn it_should_convert_a_document_back_into_a_bom() {
+        let bom = Bom::try_from(example_document()).expect("Failed to convert the document");
+
+        assert_eq!(
+            bom.serial_number,
+            Some(
+                UrnUuid::new("urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79".to_string()).unwrap()
+            )
+        );
+        let metadata = bom.metadata.as_ref().expect("Missing metadata");
+        assert_eq!(
+            metadata.tools,
+            Some(Tools::List(vec![Tool {
+                name: Some(NormalizedString::new("cargo-cyclonedx")),
+                version: Some(NormalizedString::new("0.3.8")),
+                ..Tool::default()
+            }]))
+        );
+        assert_eq!(
+            metadata.authors,
+            Some(vec![OrganizationalContact::new(
+                "Jane Doe",
+                Some("jane@example.com")
+            )])
+        );
+
+        let root = metadata.component.as_ref().expect("Missing component");
+        assert_eq!(root.bom_ref.as_deref(), Some("pkg:cargo/tacos@1.0.0"));
+        let nested = &root
+            .components
+            .as_ref()
+            .expect("Missing nested components")
+            .0;
+        assert_eq!(nested[0].component_type, Classification::File);
+
+        let components = &bom.components.as_ref().expect("Missing components").0;
+        assert_eq!(components.len(), 1);
+        assert_eq!(
+            components[0].supplier,
+            Some(OrganizationalEntity::new("Salsa Inc"))
+        );
+        assert_eq!(
+            components[0].licenses,
+            Some(Licenses(vec![LicenseChoice::License(
+                crate::models::license::License {
+                    license_identifier: LicenseIdentifier::SpdxId(
+                        SpdxIdentifier::try_from("MIT".to_string()).unwrap()
+                    ),
+                    text: None,
+                    url: None,
+                }
+            )]))
+        );
+
+        let dependencies = bom.dependencies.as_ref().expect("Missing dependencies");
+        assert_eq!(
+            dependencies.0[0],
+            Dependency::new(
+                "pkg:cargo/tacos@1.0.0",
+                vec!["pkg:cargo/salsa@0.2.0".to_string()]
+            )
+        );
+
+        // converted back, the document is the same again
+        assert_eq!(SpdxDocument::from(&bom), example_document());
+    }
+
+    #[test]
+    fn it_should_write_and_parse_the_tag_value_format() {
+        let mut document = example_document();
+        document.packages[2].description = Some("Spicy\nsauces".to_string());
+        let tag_value = document.to_tag_value();
+
+        assert!(tag_value.starts_with("SPDXVersion: SPDX-2.3\nDataLicense: CC0-1.0\n"));
+        assert!(tag_value.contains("\nPackageChecksum: SHA256: 72b64191"));
+        assert!(tag_value.contains("\nPackageDescription: <text>Spicy\nsauces</text>\n"));
+        assert!(tag_value.contains(
+            "\nRelationship: SPDXRef-DOCUMENT DESCRIBES SPDXRef-pkg-cargo-tacos-1.0.0\n"
+        ));
+        assert_eq!(SpdxDocument::parse(&tag_value).unwrap(), document);
+
+        let json = serde_json::to_string(&document).unwrap();
+        assert!(json.contains(r#""SPDXID":"SPDXRef-DOCUMENT""#));
+        assert_eq!(SpdxDocument::parse(&json).unwrap(), document);
+    }
+
+    #[test]
+    fn it_should_skip_files_and_elements_of_other_documents() {
+        let document = SpdxDocument::parse(
+            "## Document Information
+SPDXVersion: SPDX-2.2
+DataLicense: CC0-1.0
+SPDXID: SPDXRef-DOCUMENT
+DocumentName: guac
+DocumentNamespace: https://example.com/spdxdocs/guac-0.3.0
+Creator: Organization: Guac Inc
+Created: 2023-06-01T12:00:00Z
+
+PackageName: guac
+SPDXID: SPDXRef-guac
+PackageVersion: 0.3.0
+PackageDownloadLocation: NONE
+PackageLicenseDeclared: NOASSERTION
+PackageLicenseConcluded: Apache-2.0 WITH LLVM-exception
+
+FileName: ./guac.rs
+SPDXID: SPDXRef-File
+FileChecksum: SHA1: 85ed0817af83a24ad8da68c2b5094de69833983c
+
+Relationship: SPDXRef-DOCUMENT DESCRIBES SPDXRef-guac
+Relationship: SPDXRef-guac CONTAINS SPDXRef-File
+Relationship: SPDXRef-guac DEPENDS_ON DocumentRef-avocado:SPDXRef-avocado
+",
+        )
+        .unwrap();
+        assert_eq!(document.spdx_id, "SPDXRef-DOCUMENT");
+        assert_eq!(document.packages.len(), 1);
+        assert_eq!(document.packages[0].spdx_id, "SPDXRef-guac");
+
+        let bom = Bom::try_from(document).unwrap();
+        assert_eq!(bom.serial_number, None);
+        let metadata = bom.metadata.unwrap();
+        assert_eq!(
+            metadata.manufacture,
+            Some(OrganizationalEntity::new("Guac Inc"))
+        );
+        let root = metadata.component.unwrap();
+        assert_eq!(root.bom_ref.as_deref(), Some("SPDXRef-guac"));
+        assert_eq!(root.component_type, Classification::Library);
+        assert_eq!(root.external_references, None);
+        assert_eq!(
+            root.licenses,
+            Some(Licenses(vec![LicenseChoice::Expression(
+                SpdxExpression::try_from("Apache-2.0 WITH LLVM-exception".to_string()).unwrap()
+            )]))
+        );
+        assert_eq!(root.components, None);
+        assert_eq!(bom.components, None);
+        assert_eq!(bom.dependencies, None);
+    }
+
+    #[test]
+    fn it_should_reject_invalid_documents() {
+        assert!(matches!(
+            SpdxDocument::parse("SPDXVersion: SPDX-2.3\n"),
+            Err(SpdxError::MissingTag(tag)) if tag == "DocumentNamespace"
+        ));
+        assert!(matches!(
+            SpdxDocument::parse("SPDXVersion: SPDX-2.3\nPackageName guac\n"),
+            Err(SpdxError::TagValueError { line: 2, .. })
+        ));
+        assert!(matches!(
+            SpdxDocument::parse("{\"spdxVersion\": \"SPDX-2.3\"}"),
+            Err(SpdxError::JsonReadError(_))
+        ));
+
+        let mut document = example_document();
+        document.spdx_version = "SPDX-3.0".to_string();
+        assert!(matches!(
+            Bom::try_from(document),
+            Err(SpdxError::UnsupportedVersion(version)) if version == "SPDX-3.0"
+        ));
+
+        let mut document = example_document();
+        document.packages[1].spdx_id = document.packages[0].spdx_id.clone();
+        assert!(matches!(
+            Bom::try_from(document),
+            Err(SpdxError::DuplicateId(id)) if id == "SPDXRef-pkg-cargo-tacos-1.0.0"
+        ));
+    }
+}
@@ -89,6 +89,10 @@ impl From<Bom> for models::bom::Bom {
             dependencies: convert_optional(other.dependencies),
             compositions: convert_optional(other.compositions),
             properties: convert_optional(other.properties),
+            signature: None,
+            vulnerabilities: None,
+            annotations: None,
+            formulation: None,
         }
     }
 }
@@ -392,6 +396,10 @@ pub(crate) mod test {
             dependencies: Some(corresponding_dependencies()),
             compositions: Some(corresponding_compositions()),
             properties: Some(corresponding_properties()),
+            signature: None,
+            vulnerabilities: None,
+            annotations: None,
+            formulation: None,
         }
     }
 
@@ -450,7 +458,7 @@ pub(crate) mod test {
 <?xml version="1.0" encoding="utf-8"?>
 <bom xmlns="http://cyclonedx.org/schema/bom/1.3" xmlns:example="https://example.com" serialNumber="fake-uuid" version="1">
   <metadata>
-    <timestamp>timestamp</timestamp>
+    <timestamp>1969-06-28T01:20:00-04:00</timestamp>
     <tools>
       <tool>
         <vendor>vendor</vendor>
@@ -508,12 +516,12 @@ pub(crate) mod test {
             <uid>uid</uid>
             <url>url</url>
             <author>
-              <timestamp>timestamp</timestamp>
+              <timestamp>1969-06-28T01:20:00-04:00</timestamp>
               <name>name</name>
               <email>email</email>
             </author>
             <committer>
-              <timestamp>timestamp</timestamp>
+              <timestamp>1969-06-28T01:20:00-04:00</timestamp>
               <name>name</name>
               <email>email</email>
             </committer>
@@ -632,12 +640,12 @@ pub(crate) mod test {
             <uid>uid</uid>
             <url>url</url>
             <author>
-              <timestamp>timestamp</timestamp>
+              <timestamp>1969-06-28T01:20:00-04:00</timestamp>
               <name>name</name>
               <email>email</email>
             </author>
             <committer>
-              <timestamp>timestamp</timestamp>
+              <timestamp>1969-06-28T01:20:00-04:00</timestamp>
               <name>name</name>
               <email>email</email>
             </committer>
@@ -23,9 +23,9 @@ use crate::{
     specs::v1_3::attached_text::AttachedText,
     utilities::{convert_optional, convert_optional_vec, convert_vec},
     xml::{
-        attribute_or_error, read_lax_validation_list_tag, read_lax_validation_tag, read_list_tag,
-        read_simple_tag, to_xml_read_error, to_xml_write_error, unexpected_element_error,
-        write_simple_tag, FromXml, ToInnerXml, ToXml,
+        attribute_or_error, read_date_time_tag, read_lax_validation_list_tag,
+        read_lax_validation_tag, read_list_tag, read_simple_tag, to_xml_read_error,
+        to_xml_write_error, unexpected_element_error, write_simple_tag, FromXml, ToInnerXml, ToXml,
     },
 };
 use serde::{Deserialize, Serialize};
@@ -235,7 +235,7 @@ impl FromXml for Commit {
 #[serde(rename_all = "camelCase")]
 struct IdentifiableAction {
     #[serde(skip_serializing_if = "Option::is_none")]
-    timestamp: Option<String>,
+    timestamp: Option<DateTime>,
     #[serde(skip_serializing_if = "Option::is_none")]
     name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -245,7 +245,7 @@ struct IdentifiableAction {
 impl From<models::code::IdentifiableAction> for IdentifiableAction {
     fn from(other: models::code::IdentifiableAction) -> Self {
         Self {
-            timestamp: other.timestamp.map(|t| t.to_string()),
+            timestamp: other.timestamp,
             name: other.name.map(|n| n.to_string()),
             email: other.email.map(|e| e.to_string()),
         }
@@ -255,7 +255,7 @@ impl From<models::code::IdentifiableAction> for IdentifiableAction {
 impl From<IdentifiableAction> for models::code::IdentifiableAction {
     fn from(other: IdentifiableAction) -> Self {
         Self {
-            timestamp: other.timestamp.map(DateTime),
+            timestamp: other.timestamp,
             name: other.name.map(NormalizedString::new_unchecked),
             email: other.email.map(NormalizedString::new_unchecked),
         }
@@ -277,7 +277,7 @@ impl ToInnerXml for IdentifiableAction {
             .map_err(to_xml_write_error(tag))?;
 
         if let Some(timestamp) = &self.timestamp {
-            write_simple_tag(writer, TIMESTAMP_TAG, timestamp)?;
+            write_simple_tag(writer, TIMESTAMP_TAG, &timestamp.to_string())?;
         }
 
         if let Some(name) = &self.name {
@@ -305,7 +305,7 @@ impl FromXml for IdentifiableAction {
     where
         Self: Sized,
     {
-        let mut timestamp: Option<String> = None;
+        let mut timestamp: Option<DateTime> = None;
         let mut identity_name: Option<String> = None;
         let mut email: Option<String> = None;
 
@@ -316,7 +316,7 @@ impl FromXml for IdentifiableAction {
                 .map_err(to_xml_read_error(element_name.local_name.as_str()))?;
             match next_element {
                 reader::XmlEvent::StartElement { name, .. } if name.local_name == TIMESTAMP_TAG => {
-                    timestamp = Some(read_simple_tag(event_reader, &name)?)
+                    timestamp = Some(read_date_time_tag(event_reader, &name)?)
                 }
                 reader::XmlEvent::StartElement { name, .. } if name.local_name == NAME_TAG => {
                     identity_name = Some(read_simple_tag(event_reader, &name)?)
@@ -613,7 +613,7 @@ impl FromXml for Diff {
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-struct Issue {
+pub(crate) struct Issue {
     #[serde(rename = "type")]
     issue_type: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -872,7 +872,10 @@ impl FromXml for Source {
 #[cfg(test)]
 pub(crate) mod test {
     use crate::{
-        specs::v1_3::attached_text::test::{corresponding_attached_text, example_attached_text},
+        specs::v1_3::{
+            attached_text::test::{corresponding_attached_text, example_attached_text},
+            metadata::test::example_timestamp,
+        },
         xml::test::{read_element_from_string, write_element_to_string},
     };
 
@@ -908,7 +911,7 @@ pub(crate) mod test {
 
     fn example_identifiable_action() -> IdentifiableAction {
         IdentifiableAction {
-            timestamp: Some("timestamp".to_string()),
+            timestamp: Some(example_timestamp()),
             name: Some("name".to_string()),
             email: Some("email".to_string()),
         }
@@ -916,7 +919,7 @@ pub(crate) mod test {
 
     fn corresponding_identifiable_action() -> models::code::IdentifiableAction {
         models::code::IdentifiableAction {
-            timestamp: Some(DateTime("timestamp".to_string())),
+            timestamp: Some(example_timestamp()),
             name: Some(NormalizedString::new_unchecked("name".to_string())),
             email: Some(NormalizedString::new_unchecked("email".to_string())),
         }
@@ -962,7 +965,7 @@ pub(crate) mod test {
         }
     }
 
-    fn example_issue() -> Issue {
+    pub(crate) fn example_issue() -> Issue {
         Issue {
             issue_type: "issue type".to_string(),
             id: Some("id".to_string()),
@@ -973,7 +976,7 @@ pub(crate) mod test {
         }
     }
 
-    fn corresponding_issue() -> models::code::Issue {
+    pub(crate) fn corresponding_issue() -> models::code::Issue {
         models::code::Issue {
             issue_type: models::code::IssueClassification::UnknownIssueClassification(
                 "issue type".to_string(),
@@ -1020,12 +1023,12 @@ pub(crate) mod test {
     <uid>uid</uid>
     <url>url</url>
     <author>
-      <timestamp>timestamp</timestamp>
+      <timestamp>1969-06-28T01:20:00-04:00</timestamp>
       <name>name</name>
       <email>email</email>
     </author>
     <committer>
-      <timestamp>timestamp</timestamp>
+      <timestamp>1969-06-28T01:20:00-04:00</timestamp>
       <name>name</name>
       <email>email</email>
     </committer>
@@ -18,10 +18,7 @@
 
 use crate::{
     errors::XmlReadError,
-    external_models::{
-        normalized_string::NormalizedString,
-        uri::{Purl, Uri},
-    },
+    external_models::{normalized_string::NormalizedString, purl::Purl, uri::Uri},
     specs::v1_3::{
         attached_text::AttachedText, code::Commits, code::Patches,
         external_reference::ExternalReferences, hash::Hashes, license::Licenses,
@@ -207,6 +204,10 @@ impl From<Component> for models::component::Component {
             properties: convert_optional(other.properties),
             components: convert_optional(other.components),
             evidence: convert_optional(other.evidence),
+            release_notes: None,
+            signature: None,
+            data: None,
+            model_card: None,
         }
     }
 }
@@ -1235,6 +1236,10 @@ pub(crate) mod test {
             properties: Some(corresponding_properties()),
             components: Some(corresponding_empty_components()),
             evidence: Some(corresponding_evidence()),
+            release_notes: None,
+            signature: None,
+            data: None,
+            model_card: None,
         }
     }
 
@@ -1382,12 +1387,12 @@ pub(crate) mod test {
           <uid>uid</uid>
           <url>url</url>
           <author>
-            <timestamp>timestamp</timestamp>
+            <timestamp>1969-06-28T01:20:00-04:00</timestamp>
             <name>name</name>
             <email>email</email>
           </author>
           <committer>
-            <timestamp>timestamp</timestamp>
+            <timestamp>1969-06-28T01:20:00-04:00</timestamp>
             <name>name</name>
             <email>email</email>
           </committer>
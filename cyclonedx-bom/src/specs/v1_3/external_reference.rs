@@ -21,7 +21,7 @@ use crate::{
     external_models::uri::Uri,
     xml::{
         attribute_or_error, read_list_tag, read_simple_tag, to_xml_read_error,
-        unexpected_element_error, write_simple_tag, FromXml, ToXml,
+        unexpected_element_error, write_simple_tag, FromXml, ToInnerXml, ToXml,
     },
 };
 use crate::{
@@ -129,13 +129,20 @@ impl ToXml for ExternalReference {
     fn write_xml_element<W: std::io::Write>(
         &self,
         writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        self.write_xml_named_element(writer, REFERENCE_TAG)
+    }
+}
+
+impl ToInnerXml for ExternalReference {
+    fn write_xml_named_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+        tag: &str,
     ) -> Result<(), crate::errors::XmlWriteError> {
         writer
-            .write(
-                XmlEvent::start_element(REFERENCE_TAG)
-                    .attr(TYPE_ATTR, &self.external_reference_type),
-            )
-            .map_err(to_xml_write_error(REFERENCE_TAG))?;
+            .write(XmlEvent::start_element(tag).attr(TYPE_ATTR, &self.external_reference_type))
+            .map_err(to_xml_write_error(tag))?;
 
         write_simple_tag(writer, URL_TAG, &self.url)?;
 
@@ -149,7 +156,7 @@ impl ToXml for ExternalReference {
 
         writer
             .write(XmlEvent::end_element())
-            .map_err(to_xml_write_error(REFERENCE_TAG))?;
+            .map_err(to_xml_write_error(tag))?;
 
         Ok(())
     }
@@ -25,7 +25,7 @@ use crate::{
     },
     utilities::{convert_optional, convert_optional_vec},
     xml::{
-        read_lax_validation_tag, read_list_tag, read_simple_tag, to_xml_read_error,
+        read_date_time_tag, read_lax_validation_tag, read_list_tag, to_xml_read_error,
         to_xml_write_error, unexpected_element_error, write_simple_tag, FromXml, ToInnerXml, ToXml,
     },
 };
@@ -36,7 +36,7 @@ use xml::{reader, writer::XmlEvent};
 #[serde(rename_all = "camelCase")]
 pub(crate) struct Metadata {
     #[serde(skip_serializing_if = "Option::is_none")]
-    timestamp: Option<String>,
+    timestamp: Option<DateTime>,
     #[serde(skip_serializing_if = "Option::is_none")]
     tools: Option<Tools>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -56,7 +56,7 @@ pub(crate) struct Metadata {
 impl From<models::metadata::Metadata> for Metadata {
     fn from(other: models::metadata::Metadata) -> Self {
         Self {
-            timestamp: other.timestamp.map(|t| t.to_string()),
+            timestamp: other.timestamp,
             tools: convert_optional(other.tools),
             authors: convert_optional_vec(other.authors),
             component: convert_optional(other.component),
@@ -71,7 +71,7 @@ impl From<models::metadata::Metadata> for Metadata {
 impl From<Metadata> for models::metadata::Metadata {
     fn from(other: Metadata) -> Self {
         Self {
-            timestamp: other.timestamp.map(DateTime),
+            timestamp: other.timestamp,
             tools: convert_optional(other.tools),
             authors: convert_optional_vec(other.authors),
             component: convert_optional(other.component),
@@ -79,6 +79,7 @@ impl From<Metadata> for models::metadata::Metadata {
             supplier: convert_optional(other.supplier),
             licenses: convert_optional(other.licenses),
             properties: convert_optional(other.properties),
+            lifecycles: None,
         }
     }
 }
@@ -100,7 +101,7 @@ impl ToXml for Metadata {
             .map_err(to_xml_write_error(METADATA_TAG))?;
 
         if let Some(timestamp) = &self.timestamp {
-            write_simple_tag(writer, TIMESTAMP_TAG, timestamp)?;
+            write_simple_tag(writer, TIMESTAMP_TAG, &timestamp.to_string())?;
         }
 
         if let Some(tools) = &self.tools {
@@ -176,7 +177,7 @@ impl FromXml for Metadata {
     where
         Self: Sized,
     {
-        let mut timestamp: Option<String> = None;
+        let mut timestamp: Option<DateTime> = None;
         let mut tools: Option<Tools> = None;
         let mut authors: Option<Vec<OrganizationalContact>> = None;
         let mut component: Option<Component> = None;
@@ -192,7 +193,7 @@ impl FromXml for Metadata {
                 .map_err(to_xml_read_error(METADATA_TAG))?;
             match next_element {
                 reader::XmlEvent::StartElement { name, .. } if name.local_name == TIMESTAMP_TAG => {
-                    timestamp = Some(read_simple_tag(event_reader, &name)?)
+                    timestamp = Some(read_date_time_tag(event_reader, &name)?)
                 }
                 reader::XmlEvent::StartElement {
                     name, attributes, ..
@@ -288,9 +289,15 @@ pub(crate) mod test {
 
     use super::*;
 
+    pub(crate) fn example_timestamp() -> DateTime {
+        "1969-06-28T01:20:00-04:00"
+            .parse()
+            .expect("Failed to parse the example timestamp")
+    }
+
     pub(crate) fn example_metadata() -> Metadata {
         Metadata {
-            timestamp: Some("timestamp".to_string()),
+            timestamp: Some(example_timestamp()),
             tools: Some(example_tools()),
             authors: Some(vec![example_contact()]),
             component: Some(example_component()),
@@ -303,7 +310,7 @@ pub(crate) mod test {
 
     pub(crate) fn corresponding_metadata() -> models::metadata::Metadata {
         models::metadata::Metadata {
-            timestamp: Some(DateTime("timestamp".to_string())),
+            timestamp: Some(example_timestamp()),
             tools: Some(corresponding_tools()),
             authors: Some(vec![corresponding_contact()]),
             component: Some(corresponding_component()),
@@ -311,6 +318,7 @@ pub(crate) mod test {
             supplier: Some(corresponding_entity()),
             licenses: Some(corresponding_licenses()),
             properties: Some(corresponding_properties()),
+            lifecycles: None,
         }
     }
 
@@ -324,7 +332,7 @@ pub(crate) mod test {
     fn it_should_read_xml_full() {
         let input = r#"
 <metadata>
-  <timestamp>timestamp</timestamp>
+  <timestamp>1969-06-28T01:20:00-04:00</timestamp>
   <tools>
     <tool>
       <vendor>vendor</vendor>
@@ -382,12 +390,12 @@ pub(crate) mod test {
           <uid>uid</uid>
           <url>url</url>
           <author>
-            <timestamp>timestamp</timestamp>
+            <timestamp>1969-06-28T01:20:00-04:00</timestamp>
             <name>name</name>
             <email>email</email>
           </author>
           <committer>
-            <timestamp>timestamp</timestamp>
+            <timestamp>1969-06-28T01:20:00-04:00</timestamp>
             <name>name</name>
             <email>email</email>
           </committer>
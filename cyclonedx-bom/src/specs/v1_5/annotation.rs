@@ -0,0 +1,555 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use crate::{
+    errors::XmlReadError,
+    external_models::date_time::DateTime,
+    models,
+    specs::v1_5::{
+        component::Component,
+        organization::{OrganizationalContact, OrganizationalEntity},
+        service::Service,
+        signature::Signature,
+    },
+    utilities::{convert_optional, convert_vec},
+    xml::{
+        attribute_or_error, closing_tag_or_error, optional_attribute, read_date_time_tag,
+        read_lax_validation_tag, read_list_tag, read_simple_tag, to_xml_read_error,
+        to_xml_write_error, unexpected_element_error, write_simple_tag, FromXml, ToInnerXml, ToXml,
+    },
+};
+use serde::{Deserialize, Serialize};
+use xml::{reader, writer::XmlEvent};
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(transparent)]
+pub(crate) struct Annotations(Vec<Annotation>);
+
+impl From<models::annotation::Annotations> for Annotations {
+    fn from(other: models::annotation::Annotations) -> Self {
+        Annotations(convert_vec(other.0))
+    }
+}
+
+impl From<Annotations> for models::annotation::Annotations {
+    fn from(other: Annotations) -> Self {
+        models::annotation::Annotations(convert_vec(other.0))
+    }
+}
+
+const ANNOTATIONS_TAG: &str = "annotations";
+const ANNOTATION_TAG: &str = "annotation";
+
+impl ToXml for Annotations {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(ANNOTATIONS_TAG))
+            .map_err(to_xml_write_error(ANNOTATIONS_TAG))?;
+
+        for annotation in &self.0 {
+            annotation.write_xml_element(writer)?;
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(ANNOTATIONS_TAG))?;
+
+        Ok(())
+    }
+}
+
+impl FromXml for Annotations {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        read_list_tag(event_reader, element_name, ANNOTATION_TAG).map(Annotations)
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Annotation {
+    #[serde(rename = "bom-ref", skip_serializing_if = "Option::is_none")]
+    bom_ref: Option<String>,
+    subjects: Vec<String>,
+    annotator: Annotator,
+    timestamp: DateTime,
+    text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    signature: Option<Signature>,
+}
+
+impl From<models::annotation::Annotation> for Annotation {
+    fn from(other: models::annotation::Annotation) -> Self {
+        Self {
+            bom_ref: other.bom_ref,
+            subjects: other.subjects,
+            annotator: other.annotator.into(),
+            timestamp: other.timestamp,
+            text: other.text,
+            signature: convert_optional(other.signature),
+        }
+    }
+}
+
+impl From<Annotation> for models::annotation::Annotation {
+    fn from(other: Annotation) -> Self {
+        Self {
+            bom_ref: other.bom_ref,
+            subjects: other.subjects,
+            annotator: other.annotator.into(),
+            timestamp: other.timestamp,
+            text: other.text,
+            signature: convert_optional(other.signature),
+        }
+    }
+}
+
+const BOM_REF_ATTR: &str = "bom-ref";
+const SUBJECTS_TAG: &str = "subjects";
+const SUBJECT_TAG: &str = "subject";
+const REF_ATTR: &str = "ref";
+const ANNOTATOR_TAG: &str = "annotator";
+const TIMESTAMP_TAG: &str = "timestamp";
+const TEXT_TAG: &str = "text";
+
+impl ToXml for Annotation {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        let mut start_tag = XmlEvent::start_element(ANNOTATION_TAG);
+
+        if let Some(bom_ref) = &self.bom_ref {
+            start_tag = start_tag.attr(BOM_REF_ATTR, bom_ref);
+        }
+
+        writer
+            .write(start_tag)
+            .map_err(to_xml_write_error(ANNOTATION_TAG))?;
+
+        writer
+            .write(XmlEvent::start_element(SUBJECTS_TAG))
+            .map_err(to_xml_write_error(SUBJECTS_TAG))?;
+
+        for subject in &self.subjects {
+            writer
+                .write(XmlEvent::start_element(SUBJECT_TAG).attr(REF_ATTR, subject))
+                .map_err(to_xml_write_error(SUBJECT_TAG))?;
+            writer
+                .write(XmlEvent::end_element())
+                .map_err(to_xml_write_error(SUBJECT_TAG))?;
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(SUBJECTS_TAG))?;
+
+        self.annotator.write_xml_element(writer)?;
+
+        write_simple_tag(writer, TIMESTAMP_TAG, &self.timestamp.to_string())?;
+
+        write_simple_tag(writer, TEXT_TAG, &self.text)?;
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(ANNOTATION_TAG))?;
+
+        Ok(())
+    }
+}
+
+impl FromXml for Annotation {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let bom_ref = optional_attribute(attributes, BOM_REF_ATTR);
+        let mut subjects: Option<Vec<String>> = None;
+        let mut annotator: Option<Annotator> = None;
+        let mut timestamp: Option<DateTime> = None;
+        let mut text: Option<String> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(ANNOTATION_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == SUBJECTS_TAG => {
+                    subjects = Some(read_subjects(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == ANNOTATOR_TAG => {
+                    annotator = Some(Annotator::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == TIMESTAMP_TAG => {
+                    timestamp = Some(read_date_time_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == TEXT_TAG => {
+                    text = Some(read_simple_tag(event_reader, &name)?)
+                }
+                // lax validation of any elements from a different schema
+                reader::XmlEvent::StartElement { name, .. } => {
+                    read_lax_validation_tag(event_reader, &name)?
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        let subjects = subjects.ok_or_else(|| XmlReadError::RequiredDataMissing {
+            required_field: SUBJECTS_TAG.to_string(),
+            element: element_name.local_name.to_string(),
+        })?;
+
+        let annotator = annotator.ok_or_else(|| XmlReadError::RequiredDataMissing {
+            required_field: ANNOTATOR_TAG.to_string(),
+            element: element_name.local_name.to_string(),
+        })?;
+
+        let timestamp = timestamp.ok_or_else(|| XmlReadError::RequiredDataMissing {
+            required_field: TIMESTAMP_TAG.to_string(),
+            element: element_name.local_name.to_string(),
+        })?;
+
+        let text = text.ok_or_else(|| XmlReadError::RequiredDataMissing {
+            required_field: TEXT_TAG.to_string(),
+            element: element_name.local_name.to_string(),
+        })?;
+
+        Ok(Self {
+            bom_ref,
+            subjects,
+            annotator,
+            timestamp,
+            text,
+            // signatures are only part of JSON documents
+            signature: None,
+        })
+    }
+}
+
+fn read_subjects<R: std::io::Read>(
+    event_reader: &mut xml::EventReader<R>,
+    element_name: &xml::name::OwnedName,
+) -> Result<Vec<String>, XmlReadError> {
+    let mut subjects = Vec::new();
+
+    let mut got_end_tag = false;
+    while !got_end_tag {
+        let next_element = event_reader
+            .next()
+            .map_err(to_xml_read_error(SUBJECTS_TAG))?;
+        match next_element {
+            reader::XmlEvent::StartElement {
+                name, attributes, ..
+            } if name.local_name == SUBJECT_TAG => {
+                subjects.push(attribute_or_error(&name, &attributes, REF_ATTR)?);
+                event_reader
+                    .next()
+                    .map_err(to_xml_read_error(SUBJECT_TAG))
+                    .and_then(closing_tag_or_error(&name))?;
+            }
+            reader::XmlEvent::EndElement { name } if &name == element_name => {
+                got_end_tag = true;
+            }
+            unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+        }
+    }
+
+    Ok(subjects)
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) enum Annotator {
+    Organization(OrganizationalEntity),
+    Individual(OrganizationalContact),
+    Component(Box<Component>),
+    Service(Box<Service>),
+}
+
+impl From<models::annotation::Annotator> for Annotator {
+    fn from(other: models::annotation::Annotator) -> Self {
+        match other {
+            models::annotation::Annotator::Organization(organization) => {
+                Self::Organization(organization.into())
+            }
+            models::annotation::Annotator::Individual(individual) => {
+                Self::Individual(individual.into())
+            }
+            models::annotation::Annotator::Component(component) => {
+                Self::Component(Box::new((*component).into()))
+            }
+            models::annotation::Annotator::Service(service) => {
+                Self::Service(Box::new((*service).into()))
+            }
+        }
+    }
+}
+
+impl From<Annotator> for models::annotation::Annotator {
+    fn from(other: Annotator) -> Self {
+        match other {
+            Annotator::Organization(organization) => Self::Organization(organization.into()),
+            Annotator::Individual(individual) => Self::Individual(individual.into()),
+            Annotator::Component(component) => Self::Component(Box::new((*component).into())),
+            Annotator::Service(service) => Self::Service(Box::new((*service).into())),
+        }
+    }
+}
+
+const ORGANIZATION_TAG: &str = "organization";
+const INDIVIDUAL_TAG: &str = "individual";
+const COMPONENT_TAG: &str = "component";
+const SERVICE_TAG: &str = "service";
+
+impl ToXml for Annotator {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(ANNOTATOR_TAG))
+            .map_err(to_xml_write_error(ANNOTATOR_TAG))?;
+
+        match self {
+            Annotator::Organization(organization) => {
+                organization.write_xml_named_element(writer, ORGANIZATION_TAG)?
+            }
+            Annotator::Individual(individual) => {
+                individual.write_xml_named_element(writer, INDIVIDUAL_TAG)?
+            }
+            Annotator::Component(component) => component.write_xml_element(writer)?,
+            Annotator::Service(service) => service.write_xml_element(writer)?,
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(ANNOTATOR_TAG))?;
+
+        Ok(())
+    }
+}
+
+impl FromXml for Annotator {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let mut annotator: Option<Annotator> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(ANNOTATOR_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == ORGANIZATION_TAG => {
+                    annotator = Some(Annotator::Organization(
+                        OrganizationalEntity::read_xml_element(event_reader, &name, &attributes)?,
+                    ))
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == INDIVIDUAL_TAG => {
+                    annotator = Some(Annotator::Individual(
+                        OrganizationalContact::read_xml_element(event_reader, &name, &attributes)?,
+                    ))
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == COMPONENT_TAG => {
+                    annotator = Some(Annotator::Component(Box::new(Component::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)))
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == SERVICE_TAG => {
+                    annotator = Some(Annotator::Service(Box::new(Service::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)))
+                }
+                // lax validation of any elements from a different schema
+                reader::XmlEvent::StartElement { name, .. } => {
+                    read_lax_validation_tag(event_reader, &name)?
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        annotator.ok_or_else(|| XmlReadError::RequiredDataMissing {
+            required_field: format!("{ORGANIZATION_TAG} or {INDIVIDUAL_TAG}"),
+            element: element_name.local_name.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use super::*;
+    use crate::{
+        specs::v1_5::{
+            metadata::test::example_timestamp,
+            organization::test::{corresponding_contact, example_contact},
+            signature::test::{corresponding_signature, example_signature},
+        },
+        xml::test::{read_element_from_string, write_element_to_string},
+    };
+
+    pub(crate) fn example_annotations() -> Annotations {
+        Annotations(vec![example_annotation()])
+    }
+
+    pub(crate) fn corresponding_annotations() -> models::annotation::Annotations {
+        models::annotation::Annotations(vec![corresponding_annotation()])
+    }
+
+    /// The annotations as read from XML, where signatures are not part of the document
+    pub(crate) fn example_xml_annotations() -> Annotations {
+        Annotations(vec![Annotation {
+            signature: None,
+            ..example_annotation()
+        }])
+    }
+
+    fn example_annotation() -> Annotation {
+        Annotation {
+            bom_ref: Some("annotation".to_string()),
+            subjects: vec!["subject".to_string()],
+            annotator: Annotator::Individual(example_contact()),
+            timestamp: example_timestamp(),
+            text: "text".to_string(),
+            signature: Some(example_signature()),
+        }
+    }
+
+    fn corresponding_annotation() -> models::annotation::Annotation {
+        models::annotation::Annotation {
+            bom_ref: Some("annotation".to_string()),
+            subjects: vec!["subject".to_string()],
+            annotator: models::annotation::Annotator::Individual(corresponding_contact()),
+            timestamp: example_timestamp(),
+            text: "text".to_string(),
+            signature: Some(corresponding_signature()),
+        }
+    }
+
+    #[test]
+    fn it_should_write_json_full() {
+        insta::assert_json_snapshot!(example_annotations());
+    }
+
+    #[test]
+    fn it_should_read_json_full() {
+        let input = r#"[
+  {
+    "bom-ref": "annotation",
+    "subjects": ["subject"],
+    "annotator": {
+      "individual": {
+        "name": "name",
+        "email": "email",
+        "phone": "phone"
+      }
+    },
+    "timestamp": "1969-06-28T01:20:00-04:00",
+    "text": "text",
+    "signature": {
+      "algorithm": "HS512",
+      "value": "1234567890"
+    }
+  }
+]"#;
+        let actual: Annotations = serde_json::from_str(input).expect("Failed to read the JSON");
+        assert_eq!(actual, example_annotations());
+    }
+
+    #[test]
+    fn it_should_write_xml_full() {
+        let xml_output = write_element_to_string(example_annotations());
+        insta::assert_snapshot!(xml_output);
+    }
+
+    #[test]
+    fn it_should_read_xml_full() {
+        let input = r#"
+<annotations>
+  <annotation bom-ref="annotation">
+    <subjects>
+      <subject ref="subject" />
+    </subjects>
+    <annotator>
+      <individual>
+        <name>name</name>
+        <email>email</email>
+        <phone>phone</phone>
+      </individual>
+    </annotator>
+    <timestamp>1969-06-28T01:20:00-04:00</timestamp>
+    <text>text</text>
+  </annotation>
+</annotations>
+"#;
+        let actual: Annotations = read_element_from_string(input);
+        assert_eq!(actual, example_xml_annotations());
+    }
+
+    #[test]
+    fn it_should_convert_to_the_model() {
+        let actual: models::annotation::Annotations = example_annotations().into();
+        assert_eq!(actual, corresponding_annotations());
+
+        let actual: Annotations = corresponding_annotations().into();
+        assert_eq!(actual, example_annotations());
+    }
+}
@@ -0,0 +1,687 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use crate::{
+    errors::XmlReadError,
+    external_models::uri::Uri,
+    models,
+    specs::v1_5::{attached_text::AttachedText, property::Properties},
+    utilities::{convert_optional, convert_optional_vec},
+    xml::{
+        optional_attribute, read_lax_validation_tag, read_list_tag, read_simple_tag,
+        to_xml_read_error, to_xml_write_error, unexpected_element_error, write_simple_tag, FromXml,
+        ToInnerXml, ToXml,
+    },
+};
+use serde::{Deserialize, Serialize};
+use xml::{reader, writer::XmlEvent};
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ComponentData {
+    #[serde(rename = "bom-ref", skip_serializing_if = "Option::is_none")]
+    bom_ref: Option<String>,
+    #[serde(rename = "type")]
+    data_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    contents: Option<DataContents>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    classification: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sensitive_data: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    graphics: Option<GraphicsCollection>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+}
+
+impl From<models::component_data::ComponentData> for ComponentData {
+    fn from(other: models::component_data::ComponentData) -> Self {
+        Self {
+            bom_ref: other.bom_ref,
+            data_type: other.data_type.to_string(),
+            name: other.name,
+            contents: convert_optional(other.contents),
+            classification: other.classification,
+            sensitive_data: other.sensitive_data,
+            graphics: convert_optional(other.graphics),
+            description: other.description,
+        }
+    }
+}
+
+impl From<ComponentData> for models::component_data::ComponentData {
+    fn from(other: ComponentData) -> Self {
+        Self {
+            bom_ref: other.bom_ref,
+            data_type: models::component_data::ComponentDataType::new_unchecked(other.data_type),
+            name: other.name,
+            contents: convert_optional(other.contents),
+            classification: other.classification,
+            sensitive_data: other.sensitive_data,
+            graphics: convert_optional(other.graphics),
+            description: other.description,
+        }
+    }
+}
+
+const BOM_REF_ATTR: &str = "bom-ref";
+const TYPE_TAG: &str = "type";
+const NAME_TAG: &str = "name";
+const CONTENTS_TAG: &str = "contents";
+const CLASSIFICATION_TAG: &str = "classification";
+const SENSITIVE_DATA_TAG: &str = "sensitiveData";
+const GRAPHICS_TAG: &str = "graphics";
+const DESCRIPTION_TAG: &str = "description";
+
+impl ToInnerXml for ComponentData {
+    fn write_xml_named_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+        tag: &str,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        let mut start_tag = XmlEvent::start_element(tag);
+
+        if let Some(bom_ref) = &self.bom_ref {
+            start_tag = start_tag.attr(BOM_REF_ATTR, bom_ref);
+        }
+
+        writer.write(start_tag).map_err(to_xml_write_error(tag))?;
+
+        write_simple_tag(writer, TYPE_TAG, &self.data_type)?;
+
+        if let Some(name) = &self.name {
+            write_simple_tag(writer, NAME_TAG, name)?;
+        }
+
+        if let Some(contents) = &self.contents {
+            contents.write_xml_element(writer)?;
+        }
+
+        if let Some(classification) = &self.classification {
+            write_simple_tag(writer, CLASSIFICATION_TAG, classification)?;
+        }
+
+        for sensitive_data in self.sensitive_data.iter().flatten() {
+            write_simple_tag(writer, SENSITIVE_DATA_TAG, sensitive_data)?;
+        }
+
+        if let Some(graphics) = &self.graphics {
+            graphics.write_xml_element(writer)?;
+        }
+
+        if let Some(description) = &self.description {
+            write_simple_tag(writer, DESCRIPTION_TAG, description)?;
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(tag))?;
+
+        Ok(())
+    }
+}
+
+impl FromXml for ComponentData {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let bom_ref = optional_attribute(attributes, BOM_REF_ATTR);
+        let mut data_type: Option<String> = None;
+        let mut name: Option<String> = None;
+        let mut contents: Option<DataContents> = None;
+        let mut classification: Option<String> = None;
+        let mut sensitive_data: Option<Vec<String>> = None;
+        let mut graphics: Option<GraphicsCollection> = None;
+        let mut description: Option<String> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(&element_name.local_name))?;
+            match next_element {
+                reader::XmlEvent::StartElement { name: tag, .. } if tag.local_name == TYPE_TAG => {
+                    data_type = Some(read_simple_tag(event_reader, &tag)?)
+                }
+                reader::XmlEvent::StartElement { name: tag, .. } if tag.local_name == NAME_TAG => {
+                    name = Some(read_simple_tag(event_reader, &tag)?)
+                }
+                reader::XmlEvent::StartElement {
+                    name: tag,
+                    attributes,
+                    ..
+                } if tag.local_name == CONTENTS_TAG => {
+                    contents = Some(DataContents::read_xml_element(
+                        event_reader,
+                        &tag,
+                        &attributes,
+                    )?)
+                }
+                reader::XmlEvent::StartElement { name: tag, .. }
+                    if tag.local_name == CLASSIFICATION_TAG =>
+                {
+                    classification = Some(read_simple_tag(event_reader, &tag)?)
+                }
+                reader::XmlEvent::StartElement { name: tag, .. }
+                    if tag.local_name == SENSITIVE_DATA_TAG =>
+                {
+                    sensitive_data
+                        .get_or_insert_with(Vec::new)
+                        .push(read_simple_tag(event_reader, &tag)?)
+                }
+                reader::XmlEvent::StartElement {
+                    name: tag,
+                    attributes,
+                    ..
+                } if tag.local_name == GRAPHICS_TAG => {
+                    graphics = Some(GraphicsCollection::read_xml_element(
+                        event_reader,
+                        &tag,
+                        &attributes,
+                    )?)
+                }
+                reader::XmlEvent::StartElement { name: tag, .. }
+                    if tag.local_name == DESCRIPTION_TAG =>
+                {
+                    description = Some(read_simple_tag(event_reader, &tag)?)
+                }
+                // lax validation of any elements from a different schema
+                reader::XmlEvent::StartElement { name: tag, .. } => {
+                    read_lax_validation_tag(event_reader, &tag)?
+                }
+                reader::XmlEvent::EndElement { name: tag } if &tag == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        let data_type = data_type.ok_or_else(|| XmlReadError::RequiredDataMissing {
+            required_field: TYPE_TAG.to_string(),
+            element: element_name.local_name.to_string(),
+        })?;
+
+        Ok(Self {
+            bom_ref,
+            data_type,
+            name,
+            contents,
+            classification,
+            sensitive_data,
+            graphics,
+            description,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct DataContents {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attachment: Option<AttachedText>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    properties: Option<Properties>,
+}
+
+impl From<models::component_data::DataContents> for DataContents {
+    fn from(other: models::component_data::DataContents) -> Self {
+        Self {
+            attachment: convert_optional(other.attachment),
+            url: other.url.map(|u| u.0),
+            properties: convert_optional(other.properties),
+        }
+    }
+}
+
+impl From<DataContents> for models::component_data::DataContents {
+    fn from(other: DataContents) -> Self {
+        Self {
+            attachment: convert_optional(other.attachment),
+            url: other.url.map(Uri),
+            properties: convert_optional(other.properties),
+        }
+    }
+}
+
+const ATTACHMENT_TAG: &str = "attachment";
+const URL_TAG: &str = "url";
+const PROPERTIES_TAG: &str = "properties";
+
+impl ToXml for DataContents {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(CONTENTS_TAG))
+            .map_err(to_xml_write_error(CONTENTS_TAG))?;
+
+        if let Some(attachment) = &self.attachment {
+            attachment.write_xml_named_element(writer, ATTACHMENT_TAG)?;
+        }
+
+        if let Some(url) = &self.url {
+            write_simple_tag(writer, URL_TAG, url)?;
+        }
+
+        if let Some(properties) = &self.properties {
+            properties.write_xml_element(writer)?;
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(CONTENTS_TAG))?;
+
+        Ok(())
+    }
+}
+
+impl FromXml for DataContents {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let mut attachment: Option<AttachedText> = None;
+        let mut url: Option<String> = None;
+        let mut properties: Option<Properties> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(CONTENTS_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == ATTACHMENT_TAG => {
+                    attachment = Some(AttachedText::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == URL_TAG => {
+                    url = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == PROPERTIES_TAG => {
+                    properties = Some(Properties::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)
+                }
+                // lax validation of any elements from a different schema
+                reader::XmlEvent::StartElement { name, .. } => {
+                    read_lax_validation_tag(event_reader, &name)?
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        Ok(Self {
+            attachment,
+            url,
+            properties,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct GraphicsCollection {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    collection: Option<Vec<Graphic>>,
+}
+
+impl From<models::component_data::GraphicsCollection> for GraphicsCollection {
+    fn from(other: models::component_data::GraphicsCollection) -> Self {
+        Self {
+            description: other.description,
+            collection: convert_optional_vec(other.collection),
+        }
+    }
+}
+
+impl From<GraphicsCollection> for models::component_data::GraphicsCollection {
+    fn from(other: GraphicsCollection) -> Self {
+        Self {
+            description: other.description,
+            collection: convert_optional_vec(other.collection),
+        }
+    }
+}
+
+const COLLECTION_TAG: &str = "collection";
+const GRAPHIC_TAG: &str = "graphic";
+
+impl ToXml for GraphicsCollection {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(GRAPHICS_TAG))
+            .map_err(to_xml_write_error(GRAPHICS_TAG))?;
+
+        if let Some(description) = &self.description {
+            write_simple_tag(writer, DESCRIPTION_TAG, description)?;
+        }
+
+        if let Some(collection) = &self.collection {
+            writer
+                .write(XmlEvent::start_element(COLLECTION_TAG))
+                .map_err(to_xml_write_error(COLLECTION_TAG))?;
+
+            for graphic in collection {
+                graphic.write_xml_element(writer)?;
+            }
+
+            writer
+                .write(XmlEvent::end_element())
+                .map_err(to_xml_write_error(COLLECTION_TAG))?;
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(GRAPHICS_TAG))?;
+
+        Ok(())
+    }
+}
+
+impl FromXml for GraphicsCollection {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let mut description: Option<String> = None;
+        let mut collection: Option<Vec<Graphic>> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(GRAPHICS_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == DESCRIPTION_TAG =>
+                {
+                    description = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == COLLECTION_TAG =>
+                {
+                    collection = Some(read_list_tag(event_reader, &name, GRAPHIC_TAG)?)
+                }
+                // lax validation of any elements from a different schema
+                reader::XmlEvent::StartElement { name, .. } => {
+                    read_lax_validation_tag(event_reader, &name)?
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        Ok(Self {
+            description,
+            collection,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Graphic {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    image: Option<AttachedText>,
+}
+
+impl From<models::component_data::Graphic> for Graphic {
+    fn from(other: models::component_data::Graphic) -> Self {
+        Self {
+            name: other.name,
+            image: convert_optional(other.image),
+        }
+    }
+}
+
+impl From<Graphic> for models::component_data::Graphic {
+    fn from(other: Graphic) -> Self {
+        Self {
+            name: other.name,
+            image: convert_optional(other.image),
+        }
+    }
+}
+
+const IMAGE_TAG: &str = "image";
+
+impl ToXml for Graphic {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(GRAPHIC_TAG))
+            .map_err(to_xml_write_error(GRAPHIC_TAG))?;
+
+        if let Some(name) = &self.name {
+            write_simple_tag(writer, NAME_TAG, name)?;
+        }
+
+        if let Some(image) = &self.image {
+            image.write_xml_named_element(writer, IMAGE_TAG)?;
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(GRAPHIC_TAG))?;
+
+        Ok(())
+    }
+}
+
+impl FromXml for Graphic {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let mut name: Option<String> = None;
+        let mut image: Option<AttachedText> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(GRAPHIC_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement { name: tag, .. } if tag.local_name == NAME_TAG => {
+                    name = Some(read_simple_tag(event_reader, &tag)?)
+                }
+                reader::XmlEvent::StartElement {
+                    name: tag,
+                    attributes,
+                    ..
+                } if tag.local_name == IMAGE_TAG => {
+                    image = Some(AttachedText::read_xml_element(
+                        event_reader,
+                        &tag,
+                        &attributes,
+                    )?)
+                }
+                // lax validation of any elements from a different schema
+                reader::XmlEvent::StartElement { name: tag, .. } => {
+                    read_lax_validation_tag(event_reader, &tag)?
+                }
+                reader::XmlEvent::EndElement { name: tag } if &tag == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        Ok(Self { name, image })
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use super::*;
+    use crate::{
+        specs::v1_5::{
+            attached_text::test::{corresponding_attached_text, example_attached_text},
+            property::test::{corresponding_properties, example_properties},
+        },
+        xml::test::{read_element_from_string, write_named_element_to_string},
+    };
+
+    pub(crate) fn example_component_data() -> ComponentData {
+        ComponentData {
+            bom_ref: Some("bom ref".to_string()),
+            data_type: "data type".to_string(),
+            name: Some("name".to_string()),
+            contents: Some(DataContents {
+                attachment: Some(example_attached_text()),
+                url: Some("url".to_string()),
+                properties: Some(example_properties()),
+            }),
+            classification: Some("classification".to_string()),
+            sensitive_data: Some(vec!["sensitive data".to_string()]),
+            graphics: Some(example_graphics()),
+            description: Some("description".to_string()),
+        }
+    }
+
+    pub(crate) fn corresponding_component_data() -> models::component_data::ComponentData {
+        models::component_data::ComponentData {
+            bom_ref: Some("bom ref".to_string()),
+            data_type: models::component_data::ComponentDataType::UnknownComponentDataType(
+                "data type".to_string(),
+            ),
+            name: Some("name".to_string()),
+            contents: Some(models::component_data::DataContents {
+                attachment: Some(corresponding_attached_text()),
+                url: Some(Uri("url".to_string())),
+                properties: Some(corresponding_properties()),
+            }),
+            classification: Some("classification".to_string()),
+            sensitive_data: Some(vec!["sensitive data".to_string()]),
+            graphics: Some(corresponding_graphics()),
+            description: Some("description".to_string()),
+        }
+    }
+
+    pub(crate) fn example_graphics() -> GraphicsCollection {
+        GraphicsCollection {
+            description: Some("description".to_string()),
+            collection: Some(vec![Graphic {
+                name: Some("name".to_string()),
+                image: Some(example_attached_text()),
+            }]),
+        }
+    }
+
+    pub(crate) fn corresponding_graphics() -> models::component_data::GraphicsCollection {
+        models::component_data::GraphicsCollection {
+            description: Some("description".to_string()),
+            collection: Some(vec![models::component_data::Graphic {
+                name: Some("name".to_string()),
+                image: Some(corresponding_attached_text()),
+            }]),
+        }
+    }
+
+    #[test]
+    fn it_should_write_xml_full() {
+        let xml_output = write_named_element_to_string(example_component_data(), "data");
+        insta::assert_snapshot!(xml_output);
+    }
+
+    #[test]
+    fn it_should_read_xml_full() {
+        let input = r#"
+<data bom-ref="bom ref">
+  <type>data type</type>
+  <name>name</name>
+  <contents>
+    <attachment content-type="content type" encoding="encoding">content</attachment>
+    <url>url</url>
+    <properties>
+      <property name="name">value</property>
+    </properties>
+  </contents>
+  <classification>classification</classification>
+  <sensitiveData>sensitive data</sensitiveData>
+  <graphics>
+    <description>description</description>
+    <collection>
+      <graphic>
+        <name>name</name>
+        <image content-type="content type" encoding="encoding">content</image>
+      </graphic>
+    </collection>
+  </graphics>
+  <description>description</description>
+</data>
+"#;
+        let actual: ComponentData = read_element_from_string(input);
+        assert_eq!(actual, example_component_data());
+    }
+
+    #[test]
+    fn it_should_convert_to_the_model() {
+        let actual: models::component_data::ComponentData = example_component_data().into();
+        assert_eq!(actual, corresponding_component_data());
+
+        let actual: ComponentData = corresponding_component_data().into();
+        assert_eq!(actual, example_component_data());
+    }
+}
@@ -0,0 +1,2578 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use crate::{
+    errors::XmlReadError,
+    external_models::{date_time::DateTime, normalized_string::NormalizedString},
+    models,
+    specs::v1_5::{
+        attached_text::AttachedText,
+        component::Components,
+        dependency::Dependency,
+        external_reference::ExternalReference,
+        property::{Properties, Property},
+        service::Services,
+    },
+    utilities::{convert_optional, convert_optional_vec, convert_vec},
+    xml::{
+        optional_attribute, read_date_time_tag, read_lax_validation_tag, read_list_tag,
+        read_simple_tag, to_xml_read_error, to_xml_write_error, unexpected_element_error,
+        write_list_tag, write_simple_list_tag, write_simple_tag, FromXml, ToInnerXml, ToXml,
+    },
+};
+use serde::{Deserialize, Serialize};
+use xml::{reader, writer::XmlEvent};
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(transparent)]
+pub(crate) struct Formulation(Vec<Formula>);
+
+impl From<models::formulation::Formulation> for Formulation {
+    fn from(other: models::formulation::Formulation) -> Self {
+        Formulation(convert_vec(other.0))
+    }
+}
+
+impl From<Formulation> for models::formulation::Formulation {
+    fn from(other: Formulation) -> Self {
+        models::formulation::Formulation(convert_vec(other.0))
+    }
+}
+
+const FORMULATION_TAG: &str = "formulation";
+const FORMULA_TAG: &str = "formula";
+
+impl ToXml for Formulation {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        write_list_tag(writer, FORMULATION_TAG, &self.0)
+    }
+}
+
+impl FromXml for Formulation {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        read_list_tag(event_reader, element_name, FORMULA_TAG).map(Formulation)
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Formula {
+    #[serde(rename = "bom-ref", skip_serializing_if = "Option::is_none")]
+    bom_ref: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    components: Option<Components>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    services: Option<Services>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    workflows: Option<Vec<Workflow>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    properties: Option<Properties>,
+}
+
+impl From<models::formulation::Formula> for Formula {
+    fn from(other: models::formulation::Formula) -> Self {
+        Self {
+            bom_ref: other.bom_ref,
+            components: convert_optional(other.components),
+            services: convert_optional(other.services),
+            workflows: convert_optional_vec(other.workflows),
+            properties: convert_optional(other.properties),
+        }
+    }
+}
+
+impl From<Formula> for models::formulation::Formula {
+    fn from(other: Formula) -> Self {
+        Self {
+            bom_ref: other.bom_ref,
+            components: convert_optional(other.components),
+            services: convert_optional(other.services),
+            workflows: convert_optional_vec(other.workflows),
+            properties: convert_optional(other.properties),
+        }
+    }
+}
+
+const BOM_REF_ATTR: &str = "bom-ref";
+const COMPONENTS_TAG: &str = "components";
+const SERVICES_TAG: &str = "services";
+const WORKFLOWS_TAG: &str = "workflows";
+const WORKFLOW_TAG: &str = "workflow";
+const PROPERTIES_TAG: &str = "properties";
+
+impl ToXml for Formula {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        let mut start_tag = XmlEvent::start_element(FORMULA_TAG);
+
+        if let Some(bom_ref) = &self.bom_ref {
+            start_tag = start_tag.attr(BOM_REF_ATTR, bom_ref);
+        }
+
+        writer
+            .write(start_tag)
+            .map_err(to_xml_write_error(FORMULA_TAG))?;
+
+        if let Some(components) = &self.components {
+            components.write_xml_element(writer)?;
+        }
+
+        if let Some(services) = &self.services {
+            services.write_xml_element(writer)?;
+        }
+
+        if let Some(workflows) = &self.workflows {
+            write_list_tag(writer, WORKFLOWS_TAG, workflows)?;
+        }
+
+        if let Some(properties) = &self.properties {
+            properties.write_xml_element(writer)?;
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(FORMULA_TAG))?;
+
+        Ok(())
+    }
+}
+
+impl FromXml for Formula {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let bom_ref = optional_attribute(attributes, BOM_REF_ATTR);
+        let mut components: Option<Components> = None;
+        let mut services: Option<Services> = None;
+        let mut workflows: Option<Vec<Workflow>> = None;
+        let mut properties: Option<Properties> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(FORMULA_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == COMPONENTS_TAG => {
+                    components = Some(Components::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == SERVICES_TAG => {
+                    services = Some(Services::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == WORKFLOWS_TAG => {
+                    workflows = Some(read_list_tag(event_reader, &name, WORKFLOW_TAG)?)
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == PROPERTIES_TAG => {
+                    properties = Some(Properties::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)
+                }
+                // lax validation of any elements from a different schema
+                reader::XmlEvent::StartElement { name, .. } => {
+                    read_lax_validation_tag(event_reader, &name)?
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        Ok(Self {
+            bom_ref,
+            components,
+            services,
+            workflows,
+            properties,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Workflow {
+    #[serde(rename = "bom-ref")]
+    bom_ref: String,
+    uid: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    resource_references: Option<Vec<ResourceReference>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tasks: Option<Vec<Task>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    task_dependencies: Option<Vec<Dependency>>,
+    task_types: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    trigger: Option<Trigger>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    steps: Option<Vec<Step>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    inputs: Option<Vec<Input>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    outputs: Option<Vec<Output>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    time_start: Option<DateTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    time_end: Option<DateTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    properties: Option<Properties>,
+}
+
+impl From<models::formulation::Workflow> for Workflow {
+    fn from(other: models::formulation::Workflow) -> Self {
+        Self {
+            bom_ref: other.bom_ref,
+            uid: other.uid,
+            name: other.name.map(|name| name.to_string()),
+            description: other.description,
+            resource_references: convert_optional_vec(other.resource_references),
+            tasks: convert_optional_vec(other.tasks),
+            task_dependencies: convert_optional_vec(other.task_dependencies),
+            task_types: other.task_types.iter().map(ToString::to_string).collect(),
+            trigger: convert_optional(other.trigger),
+            steps: convert_optional_vec(other.steps),
+            inputs: convert_optional_vec(other.inputs),
+            outputs: convert_optional_vec(other.outputs),
+            time_start: other.time_start,
+            time_end: other.time_end,
+            properties: convert_optional(other.properties),
+        }
+    }
+}
+
+impl From<Workflow> for models::formulation::Workflow {
+    fn from(other: Workflow) -> Self {
+        Self {
+            bom_ref: other.bom_ref,
+            uid: other.uid,
+            name: other.name.map(NormalizedString::new_unchecked),
+            description: other.description,
+            resource_references: convert_optional_vec(other.resource_references),
+            tasks: convert_optional_vec(other.tasks),
+            task_dependencies: convert_optional_vec(other.task_dependencies),
+            task_types: other
+                .task_types
+                .into_iter()
+                .map(models::formulation::TaskType::new_unchecked)
+                .collect(),
+            trigger: convert_optional(other.trigger),
+            steps: convert_optional_vec(other.steps),
+            inputs: convert_optional_vec(other.inputs),
+            outputs: convert_optional_vec(other.outputs),
+            time_start: other.time_start,
+            time_end: other.time_end,
+            properties: convert_optional(other.properties),
+        }
+    }
+}
+
+const UID_TAG: &str = "uid";
+const NAME_TAG: &str = "name";
+const DESCRIPTION_TAG: &str = "description";
+const RESOURCE_REFERENCES_TAG: &str = "resourceReferences";
+const RESOURCE_REFERENCE_TAG: &str = "resourceReference";
+const TASKS_TAG: &str = "tasks";
+const TASK_TAG: &str = "task";
+const TASK_DEPENDENCIES_TAG: &str = "taskDependencies";
+const DEPENDENCY_TAG: &str = "dependency";
+const TASK_TYPES_TAG: &str = "taskTypes";
+const TASK_TYPE_TAG: &str = "taskType";
+const TRIGGER_TAG: &str = "trigger";
+const STEPS_TAG: &str = "steps";
+const STEP_TAG: &str = "step";
+const INPUTS_TAG: &str = "inputs";
+const INPUT_TAG: &str = "input";
+const OUTPUTS_TAG: &str = "outputs";
+const OUTPUT_TAG: &str = "output";
+const TIME_START_TAG: &str = "timeStart";
+const TIME_END_TAG: &str = "timeEnd";
+
+impl ToXml for Workflow {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(WORKFLOW_TAG).attr(BOM_REF_ATTR, &self.bom_ref))
+            .map_err(to_xml_write_error(WORKFLOW_TAG))?;
+
+        write_simple_tag(writer, UID_TAG, &self.uid)?;
+
+        if let Some(name) = &self.name {
+            write_simple_tag(writer, NAME_TAG, name)?;
+        }
+
+        if let Some(description) = &self.description {
+            write_simple_tag(writer, DESCRIPTION_TAG, description)?;
+        }
+
+        if let Some(resource_references) = &self.resource_references {
+            write_resource_references(writer, resource_references)?;
+        }
+
+        if let Some(tasks) = &self.tasks {
+            write_list_tag(writer, TASKS_TAG, tasks)?;
+        }
+
+        if let Some(task_dependencies) = &self.task_dependencies {
+            write_list_tag(writer, TASK_DEPENDENCIES_TAG, task_dependencies)?;
+        }
+
+        write_simple_list_tag(writer, TASK_TYPES_TAG, TASK_TYPE_TAG, &self.task_types)?;
+
+        if let Some(trigger) = &self.trigger {
+            trigger.write_xml_element(writer)?;
+        }
+
+        if let Some(steps) = &self.steps {
+            write_list_tag(writer, STEPS_TAG, steps)?;
+        }
+
+        if let Some(inputs) = &self.inputs {
+            write_list_tag(writer, INPUTS_TAG, inputs)?;
+        }
+
+        if let Some(outputs) = &self.outputs {
+            write_list_tag(writer, OUTPUTS_TAG, outputs)?;
+        }
+
+        if let Some(time_start) = &self.time_start {
+            write_simple_tag(writer, TIME_START_TAG, &time_start.to_string())?;
+        }
+
+        if let Some(time_end) = &self.time_end {
+            write_simple_tag(writer, TIME_END_TAG, &time_end.to_string())?;
+        }
+
+        if let Some(properties) = &self.properties {
+            properties.write_xml_element(writer)?;
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(WORKFLOW_TAG))?;
+
+        Ok(())
+    }
+}
+
+impl FromXml for Workflow {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let bom_ref = optional_attribute(attributes, BOM_REF_ATTR);
+        let mut uid: Option<String> = None;
+        let mut workflow_name: Option<String> = None;
+        let mut description: Option<String> = None;
+        let mut resource_references: Option<Vec<ResourceReference>> = None;
+        let mut tasks: Option<Vec<Task>> = None;
+        let mut task_dependencies: Option<Vec<Dependency>> = None;
+        let mut task_types: Option<Vec<String>> = None;
+        let mut trigger: Option<Trigger> = None;
+        let mut steps: Option<Vec<Step>> = None;
+        let mut inputs: Option<Vec<Input>> = None;
+        let mut outputs: Option<Vec<Output>> = None;
+        let mut time_start: Option<DateTime> = None;
+        let mut time_end: Option<DateTime> = None;
+        let mut properties: Option<Properties> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(WORKFLOW_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == UID_TAG => {
+                    uid = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == NAME_TAG => {
+                    workflow_name = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == DESCRIPTION_TAG =>
+                {
+                    description = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == RESOURCE_REFERENCES_TAG =>
+                {
+                    resource_references =
+                        Some(read_list_tag(event_reader, &name, RESOURCE_REFERENCE_TAG)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == TASKS_TAG => {
+                    tasks = Some(read_list_tag(event_reader, &name, TASK_TAG)?)
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == TASK_DEPENDENCIES_TAG =>
+                {
+                    task_dependencies = Some(read_list_tag(event_reader, &name, DEPENDENCY_TAG)?)
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == TASK_TYPES_TAG =>
+                {
+                    task_types = Some(read_list_tag(event_reader, &name, TASK_TYPE_TAG)?)
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == TRIGGER_TAG => {
+                    trigger = Some(Trigger::read_xml_element(event_reader, &name, &attributes)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == STEPS_TAG => {
+                    steps = Some(read_list_tag(event_reader, &name, STEP_TAG)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == INPUTS_TAG => {
+                    inputs = Some(read_list_tag(event_reader, &name, INPUT_TAG)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == OUTPUTS_TAG => {
+                    outputs = Some(read_list_tag(event_reader, &name, OUTPUT_TAG)?)
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == TIME_START_TAG =>
+                {
+                    time_start = Some(read_date_time_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == TIME_END_TAG => {
+                    time_end = Some(read_date_time_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == PROPERTIES_TAG => {
+                    properties = Some(Properties::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)
+                }
+                // lax validation of any elements from a different schema
+                reader::XmlEvent::StartElement { name, .. } => {
+                    read_lax_validation_tag(event_reader, &name)?
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        let bom_ref = bom_ref.ok_or_else(|| XmlReadError::RequiredDataMissing {
+            required_field: BOM_REF_ATTR.to_string(),
+            element: element_name.local_name.to_string(),
+        })?;
+
+        let uid = uid.ok_or_else(|| XmlReadError::RequiredDataMissing {
+            required_field: UID_TAG.to_string(),
+            element: element_name.local_name.to_string(),
+        })?;
+
+        let task_types = task_types.ok_or_else(|| XmlReadError::RequiredDataMissing {
+            required_field: TASK_TYPES_TAG.to_string(),
+            element: element_name.local_name.to_string(),
+        })?;
+
+        Ok(Self {
+            bom_ref,
+            uid,
+            name: workflow_name,
+            description,
+            resource_references,
+            tasks,
+            task_dependencies,
+            task_types,
+            trigger,
+            steps,
+            inputs,
+            outputs,
+            time_start,
+            time_end,
+            properties,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Task {
+    #[serde(rename = "bom-ref")]
+    bom_ref: String,
+    uid: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    resource_references: Option<Vec<ResourceReference>>,
+    task_types: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    trigger: Option<Trigger>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    steps: Option<Vec<Step>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    inputs: Option<Vec<Input>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    outputs: Option<Vec<Output>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    time_start: Option<DateTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    time_end: Option<DateTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    properties: Option<Properties>,
+}
+
+impl From<models::formulation::Task> for Task {
+    fn from(other: models::formulation::Task) -> Self {
+        Self {
+            bom_ref: other.bom_ref,
+            uid: other.uid,
+            name: other.name.map(|name| name.to_string()),
+            description: other.description,
+            resource_references: convert_optional_vec(other.resource_references),
+            task_types: other.task_types.iter().map(ToString::to_string).collect(),
+            trigger: convert_optional(other.trigger),
+            steps: convert_optional_vec(other.steps),
+            inputs: convert_optional_vec(other.inputs),
+            outputs: convert_optional_vec(other.outputs),
+            time_start: other.time_start,
+            time_end: other.time_end,
+            properties: convert_optional(other.properties),
+        }
+    }
+}
+
+impl From<Task> for models::formulation::Task {
+    fn from(other: Task) -> Self {
+        Self {
+            bom_ref: other.bom_ref,
+            uid: other.uid,
+            name: other.name.map(NormalizedString::new_unchecked),
+            description: other.description,
+            resource_references: convert_optional_vec(other.resource_references),
+            task_types: other
+                .task_types
+                .into_iter()
+                .map(models::formulation::TaskType::new_unchecked)
+                .collect(),
+            trigger: convert_optional(other.trigger),
+            steps: convert_optional_vec(other.steps),
+            inputs: convert_optional_vec(other.inputs),
+            outputs: convert_optional_vec(other.outputs),
+            time_start: other.time_start,
+            time_end: other.time_end,
+            properties: convert_optional(other.properties),
+        }
+    }
+}
+
+impl ToXml for Task {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(TASK_TAG).attr(BOM_REF_ATTR, &self.bom_ref))
+            .map_err(to_xml_write_error(TASK_TAG))?;
+
+        write_simple_tag(writer, UID_TAG, &self.uid)?;
+
+        if let Some(name) = &self.name {
+            write_simple_tag(writer, NAME_TAG, name)?;
+        }
+
+        if let Some(description) = &self.description {
+            write_simple_tag(writer, DESCRIPTION_TAG, description)?;
+        }
+
+        if let Some(resource_references) = &self.resource_references {
+            write_resource_references(writer, resource_references)?;
+        }
+
+        write_simple_list_tag(writer, TASK_TYPES_TAG, TASK_TYPE_TAG, &self.task_types)?;
+
+        if let Some(trigger) = &self.trigger {
+            trigger.write_xml_element(writer)?;
+        }
+
+        if let Some(steps) = &self.steps {
+            write_list_tag(writer, STEPS_TAG, steps)?;
+        }
+
+        if let Some(inputs) = &self.inputs {
+            write_list_tag(writer, INPUTS_TAG, inputs)?;
+        }
+
+        if let Some(outputs) = &self.outputs {
+            write_list_tag(writer, OUTPUTS_TAG, outputs)?;
+        }
+
+        if let Some(time_start) = &self.time_start {
+            write_simple_tag(writer, TIME_START_TAG, &time_start.to_string())?;
+        }
+
+        if let Some(time_end) = &self.time_end {
+            write_simple_tag(writer, TIME_END_TAG, &time_end.to_string())?;
+        }
+
+        if let Some(properties) = &self.properties {
+            properties.write_xml_element(writer)?;
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(TASK_TAG))?;
+
+        Ok(())
+    }
+}
+
+impl FromXml for Task {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let bom_ref = optional_attribute(attributes, BOM_REF_ATTR);
+        let mut uid: Option<String> = None;
+        let mut task_name: Option<String> = None;
+        let mut description: Option<String> = None;
+        let mut resource_references: Option<Vec<ResourceReference>> = None;
+        let mut task_types: Option<Vec<String>> = None;
+        let mut trigger: Option<Trigger> = None;
+        let mut steps: Option<Vec<Step>> = None;
+        let mut inputs: Option<Vec<Input>> = None;
+        let mut outputs: Option<Vec<Output>> = None;
+        let mut time_start: Option<DateTime> = None;
+        let mut time_end: Option<DateTime> = None;
+        let mut properties: Option<Properties> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader.next().map_err(to_xml_read_error(TASK_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == UID_TAG => {
+                    uid = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == NAME_TAG => {
+                    task_name = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == DESCRIPTION_TAG =>
+                {
+                    description = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == RESOURCE_REFERENCES_TAG =>
+                {
+                    resource_references =
+                        Some(read_list_tag(event_reader, &name, RESOURCE_REFERENCE_TAG)?)
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == TASK_TYPES_TAG =>
+                {
+                    task_types = Some(read_list_tag(event_reader, &name, TASK_TYPE_TAG)?)
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == TRIGGER_TAG => {
+                    trigger = Some(Trigger::read_xml_element(event_reader, &name, &attributes)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == STEPS_TAG => {
+                    steps = Some(read_list_tag(event_reader, &name, STEP_TAG)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == INPUTS_TAG => {
+                    inputs = Some(read_list_tag(event_reader, &name, INPUT_TAG)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == OUTPUTS_TAG => {
+                    outputs = Some(read_list_tag(event_reader, &name, OUTPUT_TAG)?)
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == TIME_START_TAG =>
+                {
+                    time_start = Some(read_date_time_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == TIME_END_TAG => {
+                    time_end = Some(read_date_time_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == PROPERTIES_TAG => {
+                    properties = Some(Properties::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)
+                }
+                // lax validation of any elements from a different schema
+                reader::XmlEvent::StartElement { name, .. } => {
+                    read_lax_validation_tag(event_reader, &name)?
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        let bom_ref = bom_ref.ok_or_else(|| XmlReadError::RequiredDataMissing {
+            required_field: BOM_REF_ATTR.to_string(),
+            element: element_name.local_name.to_string(),
+        })?;
+
+        let uid = uid.ok_or_else(|| XmlReadError::RequiredDataMissing {
+            required_field: UID_TAG.to_string(),
+            element: element_name.local_name.to_string(),
+        })?;
+
+        let task_types = task_types.ok_or_else(|| XmlReadError::RequiredDataMissing {
+            required_field: TASK_TYPES_TAG.to_string(),
+            element: element_name.local_name.to_string(),
+        })?;
+
+        Ok(Self {
+            bom_ref,
+            uid,
+            name: task_name,
+            description,
+            resource_references,
+            task_types,
+            trigger,
+            steps,
+            inputs,
+            outputs,
+            time_start,
+            time_end,
+            properties,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Step {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    commands: Option<Vec<Command>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    properties: Option<Properties>,
+}
+
+impl From<models::formulation::Step> for Step {
+    fn from(other: models::formulation::Step) -> Self {
+        Self {
+            name: other.name.map(|name| name.to_string()),
+            description: other.description,
+            commands: convert_optional_vec(other.commands),
+            properties: convert_optional(other.properties),
+        }
+    }
+}
+
+impl From<Step> for models::formulation::Step {
+    fn from(other: Step) -> Self {
+        Self {
+            name: other.name.map(NormalizedString::new_unchecked),
+            description: other.description,
+            commands: convert_optional_vec(other.commands),
+            properties: convert_optional(other.properties),
+        }
+    }
+}
+
+const COMMANDS_TAG: &str = "commands";
+const COMMAND_TAG: &str = "command";
+
+impl ToXml for Step {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(STEP_TAG))
+            .map_err(to_xml_write_error(STEP_TAG))?;
+
+        if let Some(name) = &self.name {
+            write_simple_tag(writer, NAME_TAG, name)?;
+        }
+
+        if let Some(description) = &self.description {
+            write_simple_tag(writer, DESCRIPTION_TAG, description)?;
+        }
+
+        if let Some(commands) = &self.commands {
+            write_list_tag(writer, COMMANDS_TAG, commands)?;
+        }
+
+        if let Some(properties) = &self.properties {
+            properties.write_xml_element(writer)?;
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(STEP_TAG))?;
+
+        Ok(())
+    }
+}
+
+impl FromXml for Step {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let mut step_name: Option<String> = None;
+        let mut description: Option<String> = None;
+        let mut commands: Option<Vec<Command>> = None;
+        let mut properties: Option<Properties> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader.next().map_err(to_xml_read_error(STEP_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == NAME_TAG => {
+                    step_name = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == DESCRIPTION_TAG =>
+                {
+                    description = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == COMMANDS_TAG => {
+                    commands = Some(read_list_tag(event_reader, &name, COMMAND_TAG)?)
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == PROPERTIES_TAG => {
+                    properties = Some(Properties::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)
+                }
+                // lax validation of any elements from a different schema
+                reader::XmlEvent::StartElement { name, .. } => {
+                    read_lax_validation_tag(event_reader, &name)?
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        Ok(Self {
+            name: step_name,
+            description,
+            commands,
+            properties,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Command {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    executed: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    properties: Option<Properties>,
+}
+
+impl From<models::formulation::Command> for Command {
+    fn from(other: models::formulation::Command) -> Self {
+        Self {
+            executed: other.executed,
+            properties: convert_optional(other.properties),
+        }
+    }
+}
+
+impl From<Command> for models::formulation::Command {
+    fn from(other: Command) -> Self {
+        Self {
+            executed: other.executed,
+            properties: convert_optional(other.properties),
+        }
+    }
+}
+
+const EXECUTED_TAG: &str = "executed";
+
+impl ToXml for Command {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(COMMAND_TAG))
+            .map_err(to_xml_write_error(COMMAND_TAG))?;
+
+        if let Some(executed) = &self.executed {
+            write_simple_tag(writer, EXECUTED_TAG, executed)?;
+        }
+
+        if let Some(properties) = &self.properties {
+            properties.write_xml_element(writer)?;
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(COMMAND_TAG))?;
+
+        Ok(())
+    }
+}
+
+impl FromXml for Command {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let mut executed: Option<String> = None;
+        let mut properties: Option<Properties> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(COMMAND_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == EXECUTED_TAG => {
+                    executed = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == PROPERTIES_TAG => {
+                    properties = Some(Properties::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)
+                }
+                // lax validation of any elements from a different schema
+                reader::XmlEvent::StartElement { name, .. } => {
+                    read_lax_validation_tag(event_reader, &name)?
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        Ok(Self {
+            executed,
+            properties,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Trigger {
+    #[serde(rename = "bom-ref")]
+    bom_ref: String,
+    uid: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    resource_references: Option<Vec<ResourceReference>>,
+    #[serde(rename = "type")]
+    trigger_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    conditions: Option<Vec<Condition>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    time_activated: Option<DateTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    inputs: Option<Vec<Input>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    outputs: Option<Vec<Output>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    properties: Option<Properties>,
+}
+
+impl From<models::formulation::Trigger> for Trigger {
+    fn from(other: models::formulation::Trigger) -> Self {
+        Self {
+            bom_ref: other.bom_ref,
+            uid: other.uid,
+            name: other.name.map(|name| name.to_string()),
+            description: other.description,
+            resource_references: convert_optional_vec(other.resource_references),
+            trigger_type: other.trigger_type.to_string(),
+            conditions: convert_optional_vec(other.conditions),
+            time_activated: other.time_activated,
+            inputs: convert_optional_vec(other.inputs),
+            outputs: convert_optional_vec(other.outputs),
+            properties: convert_optional(other.properties),
+        }
+    }
+}
+
+impl From<Trigger> for models::formulation::Trigger {
+    fn from(other: Trigger) -> Self {
+        Self {
+            bom_ref: other.bom_ref,
+            uid: other.uid,
+            name: other.name.map(NormalizedString::new_unchecked),
+            description: other.description,
+            resource_references: convert_optional_vec(other.resource_references),
+            trigger_type: models::formulation::TriggerType::new_unchecked(other.trigger_type),
+            conditions: convert_optional_vec(other.conditions),
+            time_activated: other.time_activated,
+            inputs: convert_optional_vec(other.inputs),
+            outputs: convert_optional_vec(other.outputs),
+            properties: convert_optional(other.properties),
+        }
+    }
+}
+
+const TYPE_TAG: &str = "type";
+const CONDITIONS_TAG: &str = "conditions";
+const CONDITION_TAG: &str = "condition";
+const TIME_ACTIVATED_TAG: &str = "timeActivated";
+
+impl ToXml for Trigger {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(TRIGGER_TAG).attr(BOM_REF_ATTR, &self.bom_ref))
+            .map_err(to_xml_write_error(TRIGGER_TAG))?;
+
+        write_simple_tag(writer, UID_TAG, &self.uid)?;
+
+        if let Some(name) = &self.name {
+            write_simple_tag(writer, NAME_TAG, name)?;
+        }
+
+        if let Some(description) = &self.description {
+            write_simple_tag(writer, DESCRIPTION_TAG, description)?;
+        }
+
+        if let Some(resource_references) = &self.resource_references {
+            write_resource_references(writer, resource_references)?;
+        }
+
+        write_simple_tag(writer, TYPE_TAG, &self.trigger_type)?;
+
+        if let Some(conditions) = &self.conditions {
+            write_list_tag(writer, CONDITIONS_TAG, conditions)?;
+        }
+
+        if let Some(time_activated) = &self.time_activated {
+            write_simple_tag(writer, TIME_ACTIVATED_TAG, &time_activated.to_string())?;
+        }
+
+        if let Some(inputs) = &self.inputs {
+            write_list_tag(writer, INPUTS_TAG, inputs)?;
+        }
+
+        if let Some(outputs) = &self.outputs {
+            write_list_tag(writer, OUTPUTS_TAG, outputs)?;
+        }
+
+        if let Some(properties) = &self.properties {
+            properties.write_xml_element(writer)?;
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(TRIGGER_TAG))?;
+
+        Ok(())
+    }
+}
+
+impl FromXml for Trigger {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let bom_ref = optional_attribute(attributes, BOM_REF_ATTR);
+        let mut uid: Option<String> = None;
+        let mut trigger_name: Option<String> = None;
+        let mut description: Option<String> = None;
+        let mut resource_references: Option<Vec<ResourceReference>> = None;
+        let mut trigger_type: Option<String> = None;
+        let mut conditions: Option<Vec<Condition>> = None;
+        let mut time_activated: Option<DateTime> = None;
+        let mut inputs: Option<Vec<Input>> = None;
+        let mut outputs: Option<Vec<Output>> = None;
+        let mut properties: Option<Properties> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(TRIGGER_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == UID_TAG => {
+                    uid = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == NAME_TAG => {
+                    trigger_name = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == DESCRIPTION_TAG =>
+                {
+                    description = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == RESOURCE_REFERENCES_TAG =>
+                {
+                    resource_references =
+                        Some(read_list_tag(event_reader, &name, RESOURCE_REFERENCE_TAG)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == TYPE_TAG => {
+                    trigger_type = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == CONDITIONS_TAG =>
+                {
+                    conditions = Some(read_list_tag(event_reader, &name, CONDITION_TAG)?)
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == TIME_ACTIVATED_TAG =>
+                {
+                    time_activated = Some(read_date_time_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == INPUTS_TAG => {
+                    inputs = Some(read_list_tag(event_reader, &name, INPUT_TAG)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == OUTPUTS_TAG => {
+                    outputs = Some(read_list_tag(event_reader, &name, OUTPUT_TAG)?)
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == PROPERTIES_TAG => {
+                    properties = Some(Properties::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)
+                }
+                // lax validation of any elements from a different schema
+                reader::XmlEvent::StartElement { name, .. } => {
+                    read_lax_validation_tag(event_reader, &name)?
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        let bom_ref = bom_ref.ok_or_else(|| XmlReadError::RequiredDataMissing {
+            required_field: BOM_REF_ATTR.to_string(),
+            element: element_name.local_name.to_string(),
+        })?;
+
+        let uid = uid.ok_or_else(|| XmlReadError::RequiredDataMissing {
+            required_field: UID_TAG.to_string(),
+            element: element_name.local_name.to_string(),
+        })?;
+
+        let trigger_type = trigger_type.ok_or_else(|| XmlReadError::RequiredDataMissing {
+            required_field: TYPE_TAG.to_string(),
+            element: element_name.local_name.to_string(),
+        })?;
+
+        Ok(Self {
+            bom_ref,
+            uid,
+            name: trigger_name,
+            description,
+            resource_references,
+            trigger_type,
+            conditions,
+            time_activated,
+            inputs,
+            outputs,
+            properties,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Condition {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expression: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    properties: Option<Properties>,
+}
+
+impl From<models::formulation::Condition> for Condition {
+    fn from(other: models::formulation::Condition) -> Self {
+        Self {
+            description: other.description,
+            expression: other.expression,
+            properties: convert_optional(other.properties),
+        }
+    }
+}
+
+impl From<Condition> for models::formulation::Condition {
+    fn from(other: Condition) -> Self {
+        Self {
+            description: other.description,
+            expression: other.expression,
+            properties: convert_optional(other.properties),
+        }
+    }
+}
+
+const EXPRESSION_TAG: &str = "expression";
+
+impl ToXml for Condition {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(CONDITION_TAG))
+            .map_err(to_xml_write_error(CONDITION_TAG))?;
+
+        if let Some(description) = &self.description {
+            write_simple_tag(writer, DESCRIPTION_TAG, description)?;
+        }
+
+        if let Some(expression) = &self.expression {
+            write_simple_tag(writer, EXPRESSION_TAG, expression)?;
+        }
+
+        if let Some(properties) = &self.properties {
+            properties.write_xml_element(writer)?;
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(CONDITION_TAG))?;
+
+        Ok(())
+    }
+}
+
+impl FromXml for Condition {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let mut description: Option<String> = None;
+        let mut expression: Option<String> = None;
+        let mut properties: Option<Properties> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(CONDITION_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == DESCRIPTION_TAG =>
+                {
+                    description = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == EXPRESSION_TAG =>
+                {
+                    expression = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == PROPERTIES_TAG => {
+                    properties = Some(Properties::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)
+                }
+                // lax validation of any elements from a different schema
+                reader::XmlEvent::StartElement { name, .. } => {
+                    read_lax_validation_tag(event_reader, &name)?
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        Ok(Self {
+            description,
+            expression,
+            properties,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(untagged)]
+pub(crate) enum ResourceReference {
+    Reference {
+        #[serde(rename = "ref")]
+        reference: String,
+    },
+    ExternalReference {
+        #[serde(rename = "externalReference")]
+        external_reference: ExternalReference,
+    },
+}
+
+impl From<models::formulation::ResourceReference> for ResourceReference {
+    fn from(other: models::formulation::ResourceReference) -> Self {
+        match other {
+            models::formulation::ResourceReference::Reference(reference) => {
+                Self::Reference { reference }
+            }
+            models::formulation::ResourceReference::ExternalReference(external_reference) => {
+                Self::ExternalReference {
+                    external_reference: external_reference.into(),
+                }
+            }
+        }
+    }
+}
+
+impl From<ResourceReference> for models::formulation::ResourceReference {
+    fn from(other: ResourceReference) -> Self {
+        match other {
+            ResourceReference::Reference { reference } => Self::Reference(reference),
+            ResourceReference::ExternalReference { external_reference } => {
+                Self::ExternalReference(external_reference.into())
+            }
+        }
+    }
+}
+
+const REF_TAG: &str = "ref";
+const EXTERNAL_REFERENCE_TAG: &str = "externalReference";
+
+fn write_resource_references<W: std::io::Write>(
+    writer: &mut xml::EventWriter<W>,
+    resource_references: &[ResourceReference],
+) -> Result<(), crate::errors::XmlWriteError> {
+    writer
+        .write(XmlEvent::start_element(RESOURCE_REFERENCES_TAG))
+        .map_err(to_xml_write_error(RESOURCE_REFERENCES_TAG))?;
+
+    for resource_reference in resource_references {
+        resource_reference.write_xml_named_element(writer, RESOURCE_REFERENCE_TAG)?;
+    }
+
+    writer
+        .write(XmlEvent::end_element())
+        .map_err(to_xml_write_error(RESOURCE_REFERENCES_TAG))?;
+
+    Ok(())
+}
+
+impl ToInnerXml for ResourceReference {
+    fn write_xml_named_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+        tag: &str,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(tag))
+            .map_err(to_xml_write_error(tag))?;
+
+        match self {
+            ResourceReference::Reference { reference } => {
+                write_simple_tag(writer, REF_TAG, reference)?
+            }
+            ResourceReference::ExternalReference { external_reference } => {
+                external_reference.write_xml_named_element(writer, EXTERNAL_REFERENCE_TAG)?
+            }
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(tag))?;
+
+        Ok(())
+    }
+}
+
+impl FromXml for ResourceReference {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let mut resource_reference: Option<ResourceReference> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(&element_name.local_name))?;
+            match next_element {
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == REF_TAG => {
+                    resource_reference = Some(ResourceReference::Reference {
+                        reference: read_simple_tag(event_reader, &name)?,
+                    })
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == EXTERNAL_REFERENCE_TAG => {
+                    resource_reference = Some(ResourceReference::ExternalReference {
+                        external_reference: ExternalReference::read_xml_element(
+                            event_reader,
+                            &name,
+                            &attributes,
+                        )?,
+                    })
+                }
+                // lax validation of any elements from a different schema
+                reader::XmlEvent::StartElement { name, .. } => {
+                    read_lax_validation_tag(event_reader, &name)?
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        resource_reference.ok_or_else(|| XmlReadError::RequiredDataMissing {
+            required_field: format!("{REF_TAG} or {EXTERNAL_REFERENCE_TAG}"),
+            element: element_name.local_name.to_string(),
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Input {
+    #[serde(flatten)]
+    data: InputData,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source: Option<ResourceReference>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    target: Option<ResourceReference>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    properties: Option<Properties>,
+}
+
+impl From<models::formulation::Input> for Input {
+    fn from(other: models::formulation::Input) -> Self {
+        Self {
+            data: other.data.into(),
+            source: convert_optional(other.source),
+            target: convert_optional(other.target),
+            properties: convert_optional(other.properties),
+        }
+    }
+}
+
+impl From<Input> for models::formulation::Input {
+    fn from(other: Input) -> Self {
+        Self {
+            data: other.data.into(),
+            source: convert_optional(other.source),
+            target: convert_optional(other.target),
+            properties: convert_optional(other.properties),
+        }
+    }
+}
+
+const RESOURCE_TAG: &str = "resource";
+const PARAMETERS_TAG: &str = "parameters";
+const PARAMETER_TAG: &str = "parameter";
+const ENVIRONMENT_VARS_TAG: &str = "environmentVars";
+const DATA_TAG: &str = "data";
+const SOURCE_TAG: &str = "source";
+const TARGET_TAG: &str = "target";
+
+impl ToXml for Input {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(INPUT_TAG))
+            .map_err(to_xml_write_error(INPUT_TAG))?;
+
+        match &self.data {
+            InputData::Resource(resource) => {
+                resource.write_xml_named_element(writer, RESOURCE_TAG)?
+            }
+            InputData::Parameters(parameters) => {
+                write_list_tag(writer, PARAMETERS_TAG, parameters)?
+            }
+            InputData::EnvironmentVars(environment_vars) => {
+                write_environment_vars(writer, environment_vars)?
+            }
+            InputData::Data(data) => data.write_xml_named_element(writer, DATA_TAG)?,
+        }
+
+        if let Some(source) = &self.source {
+            source.write_xml_named_element(writer, SOURCE_TAG)?;
+        }
+
+        if let Some(target) = &self.target {
+            target.write_xml_named_element(writer, TARGET_TAG)?;
+        }
+
+        if let Some(properties) = &self.properties {
+            properties.write_xml_element(writer)?;
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(INPUT_TAG))?;
+
+        Ok(())
+    }
+}
+
+impl FromXml for Input {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let mut data: Option<InputData> = None;
+        let mut source: Option<ResourceReference> = None;
+        let mut target: Option<ResourceReference> = None;
+        let mut properties: Option<Properties> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader.next().map_err(to_xml_read_error(INPUT_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == RESOURCE_TAG => {
+                    data = Some(InputData::Resource(ResourceReference::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?))
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == PARAMETERS_TAG =>
+                {
+                    data = Some(InputData::Parameters(read_list_tag(
+                        event_reader,
+                        &name,
+                        PARAMETER_TAG,
+                    )?))
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == ENVIRONMENT_VARS_TAG =>
+                {
+                    data = Some(InputData::EnvironmentVars(read_environment_vars(
+                        event_reader,
+                        &name,
+                    )?))
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == DATA_TAG => {
+                    data = Some(InputData::Data(AttachedText::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?))
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == SOURCE_TAG => {
+                    source = Some(ResourceReference::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == TARGET_TAG => {
+                    target = Some(ResourceReference::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == PROPERTIES_TAG => {
+                    properties = Some(Properties::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)
+                }
+                // lax validation of any elements from a different schema
+                reader::XmlEvent::StartElement { name, .. } => {
+                    read_lax_validation_tag(event_reader, &name)?
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        let data = data.ok_or_else(|| XmlReadError::RequiredDataMissing {
+            required_field: format!(
+                "{RESOURCE_TAG}, {PARAMETERS_TAG}, {ENVIRONMENT_VARS_TAG} or {DATA_TAG}"
+            ),
+            element: element_name.local_name.to_string(),
+        })?;
+
+        Ok(Self {
+            data,
+            source,
+            target,
+            properties,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) enum InputData {
+    Resource(ResourceReference),
+    Parameters(Vec<Parameter>),
+    EnvironmentVars(Vec<EnvironmentVar>),
+    Data(AttachedText),
+}
+
+impl From<models::formulation::InputData> for InputData {
+    fn from(other: models::formulation::InputData) -> Self {
+        match other {
+            models::formulation::InputData::Resource(resource) => Self::Resource(resource.into()),
+            models::formulation::InputData::Parameters(parameters) => {
+                Self::Parameters(convert_vec(parameters))
+            }
+            models::formulation::InputData::EnvironmentVars(environment_vars) => {
+                Self::EnvironmentVars(convert_vec(environment_vars))
+            }
+            models::formulation::InputData::Data(data) => Self::Data(data.into()),
+        }
+    }
+}
+
+impl From<InputData> for models::formulation::InputData {
+    fn from(other: InputData) -> Self {
+        match other {
+            InputData::Resource(resource) => Self::Resource(resource.into()),
+            InputData::Parameters(parameters) => Self::Parameters(convert_vec(parameters)),
+            InputData::EnvironmentVars(environment_vars) => {
+                Self::EnvironmentVars(convert_vec(environment_vars))
+            }
+            InputData::Data(data) => Self::Data(data.into()),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Parameter {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    data_type: Option<String>,
+}
+
+impl From<models::formulation::Parameter> for Parameter {
+    fn from(other: models::formulation::Parameter) -> Self {
+        Self {
+            name: other.name,
+            value: other.value,
+            data_type: other.data_type,
+        }
+    }
+}
+
+impl From<Parameter> for models::formulation::Parameter {
+    fn from(other: Parameter) -> Self {
+        Self {
+            name: other.name,
+            value: other.value,
+            data_type: other.data_type,
+        }
+    }
+}
+
+const VALUE_TAG: &str = "value";
+const DATA_TYPE_TAG: &str = "dataType";
+
+impl ToXml for Parameter {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(PARAMETER_TAG))
+            .map_err(to_xml_write_error(PARAMETER_TAG))?;
+
+        for (tag, value) in [
+            (NAME_TAG, &self.name),
+            (VALUE_TAG, &self.value),
+            (DATA_TYPE_TAG, &self.data_type),
+        ] {
+            if let Some(value) = value {
+                write_simple_tag(writer, tag, value)?;
+            }
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(PARAMETER_TAG))?;
+
+        Ok(())
+    }
+}
+
+impl FromXml for Parameter {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let mut parameter_name: Option<String> = None;
+        let mut value: Option<String> = None;
+        let mut data_type: Option<String> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(PARAMETER_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == NAME_TAG => {
+                    parameter_name = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == VALUE_TAG => {
+                    value = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == DATA_TYPE_TAG => {
+                    data_type = Some(read_simple_tag(event_reader, &name)?)
+                }
+                // lax validation of any elements from a different schema
+                reader::XmlEvent::StartElement { name, .. } => {
+                    read_lax_validation_tag(event_reader, &name)?
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        Ok(Self {
+            name: parameter_name,
+            value,
+            data_type,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(untagged)]
+pub(crate) enum EnvironmentVar {
+    Property(Property),
+    Value(String),
+}
+
+impl From<models::formulation::EnvironmentVar> for EnvironmentVar {
+    fn from(other: models::formulation::EnvironmentVar) -> Self {
+        match other {
+            models::formulation::EnvironmentVar::Property(property) => {
+                Self::Property(property.into())
+            }
+            models::formulation::EnvironmentVar::Value(value) => Self::Value(value),
+        }
+    }
+}
+
+impl From<EnvironmentVar> for models::formulation::EnvironmentVar {
+    fn from(other: EnvironmentVar) -> Self {
+        match other {
+            EnvironmentVar::Property(property) => Self::Property(property.into()),
+            EnvironmentVar::Value(value) => Self::Value(value),
+        }
+    }
+}
+
+const PROPERTY_TAG: &str = "property";
+
+fn write_environment_vars<W: std::io::Write>(
+    writer: &mut xml::EventWriter<W>,
+    environment_vars: &[EnvironmentVar],
+) -> Result<(), crate::errors::XmlWriteError> {
+    writer
+        .write(XmlEvent::start_element(ENVIRONMENT_VARS_TAG))
+        .map_err(to_xml_write_error(ENVIRONMENT_VARS_TAG))?;
+
+    for environment_var in environment_vars {
+        match environment_var {
+            EnvironmentVar::Property(property) => property.write_xml_element(writer)?,
+            EnvironmentVar::Value(value) => write_simple_tag(writer, VALUE_TAG, value)?,
+        }
+    }
+
+    writer
+        .write(XmlEvent::end_element())
+        .map_err(to_xml_write_error(ENVIRONMENT_VARS_TAG))?;
+
+    Ok(())
+}
+
+fn read_environment_vars<R: std::io::Read>(
+    event_reader: &mut xml::EventReader<R>,
+    element_name: &xml::name::OwnedName,
+) -> Result<Vec<EnvironmentVar>, XmlReadError> {
+    let mut environment_vars = Vec::new();
+
+    let mut got_end_tag = false;
+    while !got_end_tag {
+        let next_element = event_reader
+            .next()
+            .map_err(to_xml_read_error(ENVIRONMENT_VARS_TAG))?;
+        match next_element {
+            reader::XmlEvent::StartElement {
+                name, attributes, ..
+            } if name.local_name == PROPERTY_TAG => {
+                environment_vars.push(EnvironmentVar::Property(Property::read_xml_element(
+                    event_reader,
+                    &name,
+                    &attributes,
+                )?))
+            }
+            reader::XmlEvent::StartElement { name, .. } if name.local_name == VALUE_TAG => {
+                environment_vars.push(EnvironmentVar::Value(read_simple_tag(event_reader, &name)?))
+            }
+            reader::XmlEvent::EndElement { name } if &name == element_name => {
+                got_end_tag = true;
+            }
+            unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+        }
+    }
+
+    Ok(environment_vars)
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Output {
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    output_type: Option<String>,
+    #[serde(flatten)]
+    data: OutputData,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source: Option<ResourceReference>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    target: Option<ResourceReference>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    properties: Option<Properties>,
+}
+
+impl From<models::formulation::Output> for Output {
+    fn from(other: models::formulation::Output) -> Self {
+        Self {
+            output_type: other.output_type.map(|output_type| output_type.to_string()),
+            data: other.data.into(),
+            source: convert_optional(other.source),
+            target: convert_optional(other.target),
+            properties: convert_optional(other.properties),
+        }
+    }
+}
+
+impl From<Output> for models::formulation::Output {
+    fn from(other: Output) -> Self {
+        Self {
+            output_type: other
+                .output_type
+                .map(models::formulation::OutputType::new_unchecked),
+            data: other.data.into(),
+            source: convert_optional(other.source),
+            target: convert_optional(other.target),
+            properties: convert_optional(other.properties),
+        }
+    }
+}
+
+impl ToXml for Output {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(OUTPUT_TAG))
+            .map_err(to_xml_write_error(OUTPUT_TAG))?;
+
+        if let Some(output_type) = &self.output_type {
+            write_simple_tag(writer, TYPE_TAG, output_type)?;
+        }
+
+        match &self.data {
+            OutputData::Resource(resource) => {
+                resource.write_xml_named_element(writer, RESOURCE_TAG)?
+            }
+            OutputData::EnvironmentVars(environment_vars) => {
+                write_environment_vars(writer, environment_vars)?
+            }
+            OutputData::Data(data) => data.write_xml_named_element(writer, DATA_TAG)?,
+        }
+
+        if let Some(source) = &self.source {
+            source.write_xml_named_element(writer, SOURCE_TAG)?;
+        }
+
+        if let Some(target) = &self.target {
+            target.write_xml_named_element(writer, TARGET_TAG)?;
+        }
+
+        if let Some(properties) = &self.properties {
+            properties.write_xml_element(writer)?;
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(OUTPUT_TAG))?;
+
+        Ok(())
+    }
+}
+
+impl FromXml for Output {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let mut output_type: Option<String> = None;
+        let mut data: Option<OutputData> = None;
+        let mut source: Option<ResourceReference> = None;
+        let mut target: Option<ResourceReference> = None;
+        let mut properties: Option<Properties> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader.next().map_err(to_xml_read_error(OUTPUT_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == TYPE_TAG => {
+                    output_type = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == RESOURCE_TAG => {
+                    data = Some(OutputData::Resource(ResourceReference::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?))
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == ENVIRONMENT_VARS_TAG =>
+                {
+                    data = Some(OutputData::EnvironmentVars(read_environment_vars(
+                        event_reader,
+                        &name,
+                    )?))
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == DATA_TAG => {
+                    data = Some(OutputData::Data(AttachedText::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?))
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == SOURCE_TAG => {
+                    source = Some(ResourceReference::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == TARGET_TAG => {
+                    target = Some(ResourceReference::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == PROPERTIES_TAG => {
+                    properties = Some(Properties::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)
+                }
+                // lax validation of any elements from a different schema
+                reader::XmlEvent::StartElement { name, .. } => {
+                    read_lax_validation_tag(event_reader, &name)?
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        let data = data.ok_or_else(|| XmlReadError::RequiredDataMissing {
+            required_field: format!("{RESOURCE_TAG}, {ENVIRONMENT_VARS_TAG} or {DATA_TAG}"),
+            element: element_name.local_name.to_string(),
+        })?;
+
+        Ok(Self {
+            output_type,
+            data,
+            source,
+            target,
+            properties,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) enum OutputData {
+    Resource(ResourceReference),
+    EnvironmentVars(Vec<EnvironmentVar>),
+    Data(AttachedText),
+}
+
+impl From<models::formulation::OutputData> for OutputData {
+    fn from(other: models::formulation::OutputData) -> Self {
+        match other {
+            models::formulation::OutputData::Resource(resource) => Self::Resource(resource.into()),
+            models::formulation::OutputData::EnvironmentVars(environment_vars) => {
+                Self::EnvironmentVars(convert_vec(environment_vars))
+            }
+            models::formulation::OutputData::Data(data) => Self::Data(data.into()),
+        }
+    }
+}
+
+impl From<OutputData> for models::formulation::OutputData {
+    fn from(other: OutputData) -> Self {
+        match other {
+            OutputData::Resource(resource) => Self::Resource(resource.into()),
+            OutputData::EnvironmentVars(environment_vars) => {
+                Self::EnvironmentVars(convert_vec(environment_vars))
+            }
+            OutputData::Data(data) => Self::Data(data.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use super::*;
+    use crate::{
+        external_models::uri::Uri,
+        specs::v1_5::{
+            attached_text::test::{corresponding_attached_text, example_attached_text},
+            metadata::test::example_timestamp,
+            property::test::{corresponding_properties, example_properties},
+        },
+        xml::test::{read_element_from_string, write_element_to_string},
+    };
+
+    pub(crate) fn example_formulation() -> Formulation {
+        Formulation(vec![Formula {
+            bom_ref: Some("formula".to_string()),
+            components: None,
+            services: None,
+            workflows: Some(vec![Workflow {
+                bom_ref: "workflow".to_string(),
+                uid: "workflow uid".to_string(),
+                name: Some("name".to_string()),
+                description: Some("description".to_string()),
+                resource_references: Some(vec![ResourceReference::Reference {
+                    reference: "component".to_string(),
+                }]),
+                tasks: Some(vec![Task {
+                    bom_ref: "task".to_string(),
+                    uid: "task uid".to_string(),
+                    name: None,
+                    description: None,
+                    resource_references: None,
+                    task_types: vec!["build".to_string()],
+                    trigger: None,
+                    steps: Some(vec![Step {
+                        name: Some("name".to_string()),
+                        description: Some("description".to_string()),
+                        commands: Some(vec![Command {
+                            executed: Some("cargo build".to_string()),
+                            properties: Some(example_properties()),
+                        }]),
+                        properties: Some(example_properties()),
+                    }]),
+                    inputs: Some(vec![Input {
+                        data: InputData::EnvironmentVars(vec![
+                            EnvironmentVar::Property(
+                                models::property::Property {
+                                    name: "name".to_string(),
+                                    value: NormalizedString::new_unchecked("value".to_string()),
+                                }
+                                .into(),
+                            ),
+                            EnvironmentVar::Value("value".to_string()),
+                        ]),
+                        source: None,
+                        target: None,
+                        properties: None,
+                    }]),
+                    outputs: Some(vec![Output {
+                        output_type: Some("artifact".to_string()),
+                        data: OutputData::Resource(ResourceReference::ExternalReference {
+                            external_reference: example_external_reference(),
+                        }),
+                        source: None,
+                        target: None,
+                        properties: None,
+                    }]),
+                    time_start: None,
+                    time_end: None,
+                    properties: None,
+                }]),
+                task_dependencies: Some(vec![models::dependency::Dependency {
+                    dependency_ref: "task".to_string(),
+                    dependencies: vec![],
+                }
+                .into()]),
+                task_types: vec!["build".to_string(), "test".to_string()],
+                trigger: Some(Trigger {
+                    bom_ref: "trigger".to_string(),
+                    uid: "trigger uid".to_string(),
+                    name: Some("name".to_string()),
+                    description: Some("description".to_string()),
+                    resource_references: None,
+                    trigger_type: "webhook".to_string(),
+                    conditions: Some(vec![Condition {
+                        description: Some("description".to_string()),
+                        expression: Some("expression".to_string()),
+                        properties: Some(example_properties()),
+                    }]),
+                    time_activated: Some(example_timestamp()),
+                    inputs: None,
+                    outputs: None,
+                    properties: Some(example_properties()),
+                }),
+                steps: None,
+                inputs: Some(vec![Input {
+                    data: InputData::Parameters(vec![Parameter {
+                        name: Some("name".to_string()),
+                        value: Some("value".to_string()),
+                        data_type: Some("data type".to_string()),
+                    }]),
+                    source: Some(ResourceReference::Reference {
+                        reference: "source".to_string(),
+                    }),
+                    target: Some(ResourceReference::Reference {
+                        reference: "target".to_string(),
+                    }),
+                    properties: Some(example_properties()),
+                }]),
+                outputs: Some(vec![Output {
+                    output_type: Some("log".to_string()),
+                    data: OutputData::Data(example_attached_text()),
+                    source: None,
+                    target: None,
+                    properties: Some(example_properties()),
+                }]),
+                time_start: Some(example_timestamp()),
+                time_end: Some(example_timestamp()),
+                properties: Some(example_properties()),
+            }]),
+            properties: Some(example_properties()),
+        }])
+    }
+
+    pub(crate) fn corresponding_formulation() -> models::formulation::Formulation {
+        models::formulation::Formulation(vec![models::formulation::Formula {
+            bom_ref: Some("formula".to_string()),
+            components: None,
+            services: None,
+            workflows: Some(vec![models::formulation::Workflow {
+                bom_ref: "workflow".to_string(),
+                uid: "workflow uid".to_string(),
+                name: Some(NormalizedString::new_unchecked("name".to_string())),
+                description: Some("description".to_string()),
+                resource_references: Some(vec![models::formulation::ResourceReference::Reference(
+                    "component".to_string(),
+                )]),
+                tasks: Some(vec![models::formulation::Task {
+                    bom_ref: "task".to_string(),
+                    uid: "task uid".to_string(),
+                    name: None,
+                    description: None,
+                    resource_references: None,
+                    task_types: vec![models::formulation::TaskType::Build],
+                    trigger: None,
+                    steps: Some(vec![models::formulation::Step {
+                        name: Some(NormalizedString::new_unchecked("name".to_string())),
+                        description: Some("description".to_string()),
+                        commands: Some(vec![models::formulation::Command {
+                            executed: Some("cargo build".to_string()),
+                            properties: Some(corresponding_properties()),
+                        }]),
+                        properties: Some(corresponding_properties()),
+                    }]),
+                    inputs: Some(vec![models::formulation::Input {
+                        data: models::formulation::InputData::EnvironmentVars(vec![
+                            models::formulation::EnvironmentVar::Property(
+                                models::property::Property {
+                                    name: "name".to_string(),
+                                    value: NormalizedString::new_unchecked("value".to_string()),
+                                },
+                            ),
+                            models::formulation::EnvironmentVar::Value("value".to_string()),
+                        ]),
+                        source: None,
+                        target: None,
+                        properties: None,
+                    }]),
+                    outputs: Some(vec![models::formulation::Output {
+                        output_type: Some(models::formulation::OutputType::Artifact),
+                        data: models::formulation::OutputData::Resource(
+                            models::formulation::ResourceReference::ExternalReference(
+                                corresponding_external_reference(),
+                            ),
+                        ),
+                        source: None,
+                        target: None,
+                        properties: None,
+                    }]),
+                    time_start: None,
+                    time_end: None,
+                    properties: None,
+                }]),
+                task_dependencies: Some(vec![models::dependency::Dependency {
+                    dependency_ref: "task".to_string(),
+                    dependencies: vec![],
+                }]),
+                task_types: vec![
+                    models::formulation::TaskType::Build,
+                    models::formulation::TaskType::Test,
+                ],
+                trigger: Some(models::formulation::Trigger {
+                    bom_ref: "trigger".to_string(),
+                    uid: "trigger uid".to_string(),
+                    name: Some(NormalizedString::new_unchecked("name".to_string())),
+                    description: Some("description".to_string()),
+                    resource_references: None,
+                    trigger_type: models::formulation::TriggerType::Webhook,
+                    conditions: Some(vec![models::formulation::Condition {
+                        description: Some("description".to_string()),
+                        expression: Some("expression".to_string()),
+                        properties: Some(corresponding_properties()),
+                    }]),
+                    time_activated: Some(example_timestamp()),
+                    inputs: None,
+                    outputs: None,
+                    properties: Some(corresponding_properties()),
+                }),
+                steps: None,
+                inputs: Some(vec![models::formulation::Input {
+                    data: models::formulation::InputData::Parameters(vec![
+                        models::formulation::Parameter {
+                            name: Some("name".to_string()),
+                            value: Some("value".to_string()),
+                            data_type: Some("data type".to_string()),
+                        },
+                    ]),
+                    source: Some(models::formulation::ResourceReference::Reference(
+                        "source".to_string(),
+                    )),
+                    target: Some(models::formulation::ResourceReference::Reference(
+                        "target".to_string(),
+                    )),
+                    properties: Some(corresponding_properties()),
+                }]),
+                outputs: Some(vec![models::formulation::Output {
+                    output_type: Some(models::formulation::OutputType::Log),
+                    data: models::formulation::OutputData::Data(corresponding_attached_text()),
+                    source: None,
+                    target: None,
+                    properties: Some(corresponding_properties()),
+                }]),
+                time_start: Some(example_timestamp()),
+                time_end: Some(example_timestamp()),
+                properties: Some(corresponding_properties()),
+            }]),
+            properties: Some(corresponding_properties()),
+        }])
+    }
+
+    fn example_external_reference() -> ExternalReference {
+        corresponding_external_reference().into()
+    }
+
+    fn corresponding_external_reference() -> models::external_reference::ExternalReference {
+        models::external_reference::ExternalReference {
+            external_reference_type:
+                models::external_reference::ExternalReferenceType::Distribution,
+            url: Uri("https://example.com/artifact.tar.gz".to_string()),
+            comment: None,
+            hashes: None,
+        }
+    }
+
+    #[test]
+    fn it_should_write_json_full() {
+        insta::assert_json_snapshot!(example_formulation());
+    }
+
+    #[test]
+    fn it_should_read_json_full() {
+        let json = serde_json::to_string(&example_formulation()).expect("Failed to write JSON");
+        let actual: Formulation = serde_json::from_str(&json).expect("Failed to read JSON");
+        assert_eq!(actual, example_formulation());
+    }
+
+    #[test]
+    fn it_should_write_xml_full() {
+        let xml_output = write_element_to_string(example_formulation());
+        insta::assert_snapshot!(xml_output);
+    }
+
+    #[test]
+    fn it_should_read_xml_full() {
+        let input = r#"
+<formulation>
+  <formula bom-ref="formula">
+    <workflows>
+      <workflow bom-ref="workflow">
+        <uid>workflow uid</uid>
+        <name>name</name>
+        <description>description</description>
+        <resourceReferences>
+          <resourceReference>
+            <ref>component</ref>
+          </resourceReference>
+        </resourceReferences>
+        <tasks>
+          <task bom-ref="task">
+            <uid>task uid</uid>
+            <taskTypes>
+              <taskType>build</taskType>
+            </taskTypes>
+            <steps>
+              <step>
+                <name>name</name>
+                <description>description</description>
+                <commands>
+                  <command>
+                    <executed>cargo build</executed>
+                    <properties>
+                      <property name="name">value</property>
+                    </properties>
+                  </command>
+                </commands>
+                <properties>
+                  <property name="name">value</property>
+                </properties>
+              </step>
+            </steps>
+            <inputs>
+              <input>
+                <environmentVars>
+                  <property name="name">value</property>
+                  <value>value</value>
+                </environmentVars>
+              </input>
+            </inputs>
+            <outputs>
+              <output>
+                <type>artifact</type>
+                <resource>
+                  <externalReference type="distribution">
+                    <url>https://example.com/artifact.tar.gz</url>
+                  </externalReference>
+                </resource>
+              </output>
+            </outputs>
+          </task>
+        </tasks>
+        <taskDependencies>
+          <dependency ref="task" />
+        </taskDependencies>
+        <taskTypes>
+          <taskType>build</taskType>
+          <taskType>test</taskType>
+        </taskTypes>
+        <trigger bom-ref="trigger">
+          <uid>trigger uid</uid>
+          <name>name</name>
+          <description>description</description>
+          <type>webhook</type>
+          <conditions>
+            <condition>
+              <description>description</description>
+              <expression>expression</expression>
+              <properties>
+                <property name="name">value</property>
+              </properties>
+            </condition>
+          </conditions>
+          <timeActivated>1969-06-28T01:20:00-04:00</timeActivated>
+          <properties>
+            <property name="name">value</property>
+          </properties>
+        </trigger>
+        <inputs>
+          <input>
+            <parameters>
+              <parameter>
+                <name>name</name>
+                <value>value</value>
+                <dataType>data type</dataType>
+              </parameter>
+            </parameters>
+            <source>
+              <ref>source</ref>
+            </source>
+            <target>
+              <ref>target</ref>
+            </target>
+            <properties>
+              <property name="name">value</property>
+            </properties>
+          </input>
+        </inputs>
+        <outputs>
+          <output>
+            <type>log</type>
+            <data content-type="content type" encoding="encoding">content</data>
+            <properties>
+              <property name="name">value</property>
+            </properties>
+          </output>
+        </outputs>
+        <timeStart>1969-06-28T01:20:00-04:00</timeStart>
+        <timeEnd>1969-06-28T01:20:00-04:00</timeEnd>
+        <properties>
+          <property name="name">value</property>
+        </properties>
+      </workflow>
+    </workflows>
+    <properties>
+      <property name="name">value</property>
+    </properties>
+  </formula>
+</formulation>
+"#;
+        let actual: Formulation = read_element_from_string(input);
+        assert_eq!(actual, example_formulation());
+    }
+
+    #[test]
+    fn it_should_convert_to_the_model() {
+        let actual: models::formulation::Formulation = example_formulation().into();
+        assert_eq!(actual, corresponding_formulation());
+
+        let actual: Formulation = corresponding_formulation().into();
+        assert_eq!(actual, example_formulation());
+    }
+}
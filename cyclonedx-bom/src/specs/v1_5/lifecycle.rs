@@ -0,0 +1,282 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use crate::{
+    errors::XmlReadError,
+    external_models::normalized_string::NormalizedString,
+    models,
+    utilities::convert_vec,
+    xml::{
+        read_lax_validation_tag, read_list_tag, read_simple_tag, to_xml_read_error,
+        to_xml_write_error, unexpected_element_error, write_simple_tag, FromXml, ToXml,
+    },
+};
+use serde::{Deserialize, Serialize};
+use xml::{reader, writer::XmlEvent};
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(transparent)]
+pub(crate) struct Lifecycles(Vec<Lifecycle>);
+
+impl From<models::lifecycle::Lifecycles> for Lifecycles {
+    fn from(other: models::lifecycle::Lifecycles) -> Self {
+        Lifecycles(convert_vec(other.0))
+    }
+}
+
+impl From<Lifecycles> for models::lifecycle::Lifecycles {
+    fn from(other: Lifecycles) -> Self {
+        models::lifecycle::Lifecycles(convert_vec(other.0))
+    }
+}
+
+const LIFECYCLES_TAG: &str = "lifecycles";
+const LIFECYCLE_TAG: &str = "lifecycle";
+
+impl ToXml for Lifecycles {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(LIFECYCLES_TAG))
+            .map_err(to_xml_write_error(LIFECYCLES_TAG))?;
+
+        for lifecycle in &self.0 {
+            lifecycle.write_xml_element(writer)?;
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(LIFECYCLES_TAG))?;
+        Ok(())
+    }
+}
+
+impl FromXml for Lifecycles {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        read_list_tag(event_reader, element_name, LIFECYCLE_TAG).map(Lifecycles)
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(untagged)]
+pub(crate) enum Lifecycle {
+    Phase {
+        phase: String,
+    },
+    Description {
+        name: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+    },
+}
+
+impl From<models::lifecycle::Lifecycle> for Lifecycle {
+    fn from(other: models::lifecycle::Lifecycle) -> Self {
+        match other {
+            models::lifecycle::Lifecycle::Phase(phase) => Self::Phase {
+                phase: phase.to_string(),
+            },
+            models::lifecycle::Lifecycle::Description { name, description } => Self::Description {
+                name: name.to_string(),
+                description: description.map(|d| d.to_string()),
+            },
+        }
+    }
+}
+
+impl From<Lifecycle> for models::lifecycle::Lifecycle {
+    fn from(other: Lifecycle) -> Self {
+        match other {
+            Lifecycle::Phase { phase } => {
+                Self::Phase(models::lifecycle::Phase::new_unchecked(phase))
+            }
+            Lifecycle::Description { name, description } => Self::Description {
+                name: NormalizedString::new_unchecked(name),
+                description: description.map(NormalizedString::new_unchecked),
+            },
+        }
+    }
+}
+
+const PHASE_TAG: &str = "phase";
+const NAME_TAG: &str = "name";
+const DESCRIPTION_TAG: &str = "description";
+
+impl ToXml for Lifecycle {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(LIFECYCLE_TAG))
+            .map_err(to_xml_write_error(LIFECYCLE_TAG))?;
+
+        match self {
+            Lifecycle::Phase { phase } => write_simple_tag(writer, PHASE_TAG, phase)?,
+            Lifecycle::Description { name, description } => {
+                write_simple_tag(writer, NAME_TAG, name)?;
+
+                if let Some(description) = description {
+                    write_simple_tag(writer, DESCRIPTION_TAG, description)?;
+                }
+            }
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(LIFECYCLE_TAG))?;
+        Ok(())
+    }
+}
+
+impl FromXml for Lifecycle {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let mut phase: Option<String> = None;
+        let mut name: Option<String> = None;
+        let mut description: Option<String> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(LIFECYCLE_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement { name: tag, .. } if tag.local_name == PHASE_TAG => {
+                    phase = Some(read_simple_tag(event_reader, &tag)?)
+                }
+                reader::XmlEvent::StartElement { name: tag, .. } if tag.local_name == NAME_TAG => {
+                    name = Some(read_simple_tag(event_reader, &tag)?)
+                }
+                reader::XmlEvent::StartElement { name: tag, .. }
+                    if tag.local_name == DESCRIPTION_TAG =>
+                {
+                    description = Some(read_simple_tag(event_reader, &tag)?)
+                }
+                // lax validation of any elements from a different schema
+                reader::XmlEvent::StartElement { name: tag, .. } => {
+                    read_lax_validation_tag(event_reader, &tag)?
+                }
+                reader::XmlEvent::EndElement { name: tag } if &tag == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        match (phase, name) {
+            (Some(phase), _) => Ok(Self::Phase { phase }),
+            (None, Some(name)) => Ok(Self::Description { name, description }),
+            (None, None) => Err(XmlReadError::RequiredDataMissing {
+                required_field: PHASE_TAG.to_string(),
+                element: element_name.local_name.to_string(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use super::*;
+    use crate::xml::test::{read_element_from_string, write_element_to_string};
+
+    pub(crate) fn example_lifecycles() -> Lifecycles {
+        Lifecycles(vec![
+            Lifecycle::Phase {
+                phase: "build".to_string(),
+            },
+            Lifecycle::Description {
+                name: "platform-integration-testing".to_string(),
+                description: Some("Integration testing on the platform".to_string()),
+            },
+        ])
+    }
+
+    pub(crate) fn corresponding_lifecycles() -> models::lifecycle::Lifecycles {
+        models::lifecycle::Lifecycles(vec![
+            models::lifecycle::Lifecycle::Phase(models::lifecycle::Phase::Build),
+            models::lifecycle::Lifecycle::Description {
+                name: NormalizedString::new_unchecked("platform-integration-testing".to_string()),
+                description: Some(NormalizedString::new_unchecked(
+                    "Integration testing on the platform".to_string(),
+                )),
+            },
+        ])
+    }
+
+    #[test]
+    fn it_should_serialize_to_json() {
+        insta::assert_json_snapshot!(example_lifecycles());
+    }
+
+    #[test]
+    fn it_should_deserialize_from_json() {
+        let actual: Lifecycles = serde_json::from_str(
+            r#"[{"phase": "build"}, {"name": "platform-integration-testing", "description": "Integration testing on the platform"}]"#,
+        )
+        .expect("Failed to read the lifecycles");
+        assert_eq!(actual, example_lifecycles());
+    }
+
+    #[test]
+    fn it_should_write_xml_full() {
+        let xml_output = write_element_to_string(example_lifecycles());
+        insta::assert_snapshot!(xml_output);
+    }
+
+    #[test]
+    fn it_should_read_xml_full() {
+        let input = r#"
+<lifecycles>
+  <lifecycle>
+    <phase>build</phase>
+  </lifecycle>
+  <lifecycle>
+    <name>platform-integration-testing</name>
+    <description>Integration testing on the platform</description>
+  </lifecycle>
+</lifecycles>
+"#;
+        let actual: Lifecycles = read_element_from_string(input);
+        assert_eq!(actual, example_lifecycles());
+    }
+
+    #[test]
+    fn it_should_convert_to_the_model() {
+        let actual: models::lifecycle::Lifecycles = example_lifecycles().into();
+        assert_eq!(actual, corresponding_lifecycles());
+
+        let actual: Lifecycles = corresponding_lifecycles().into();
+        assert_eq!(actual, example_lifecycles());
+    }
+}
@@ -0,0 +1,34 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+// the modules that did not change since version 1.4 of the specification
+pub(crate) use super::v1_4::{
+    attached_text, code, composition, dependency, external_reference, hash, license, organization,
+    property, release_notes, signature, vulnerability,
+};
+
+pub(crate) mod annotation;
+pub(crate) mod bom;
+pub(crate) mod component;
+pub(crate) mod component_data;
+pub(crate) mod formulation;
+pub(crate) mod lifecycle;
+pub(crate) mod metadata;
+pub(crate) mod model_card;
+pub(crate) mod service;
+pub(crate) mod tool;
@@ -0,0 +1,1610 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use crate::{
+    errors::XmlReadError,
+    models,
+    specs::v1_5::{
+        component_data::{ComponentData, GraphicsCollection},
+        property::Properties,
+    },
+    utilities::{convert_optional, convert_optional_vec},
+    xml::{
+        optional_attribute, read_lax_validation_tag, read_list_tag, read_simple_tag,
+        to_xml_read_error, to_xml_write_error, unexpected_element_error, write_list_tag,
+        write_simple_list_tag, write_simple_tag, FromXml, ToInnerXml, ToXml,
+    },
+};
+use serde::{Deserialize, Serialize};
+use xml::{reader, writer::XmlEvent};
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ModelCard {
+    #[serde(rename = "bom-ref", skip_serializing_if = "Option::is_none")]
+    bom_ref: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    model_parameters: Option<ModelParameters>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    quantitative_analysis: Option<QuantitativeAnalysis>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    considerations: Option<Considerations>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    properties: Option<Properties>,
+}
+
+impl From<models::model_card::ModelCard> for ModelCard {
+    fn from(other: models::model_card::ModelCard) -> Self {
+        Self {
+            bom_ref: other.bom_ref,
+            model_parameters: convert_optional(other.model_parameters),
+            quantitative_analysis: convert_optional(other.quantitative_analysis),
+            considerations: convert_optional(other.considerations),
+            properties: convert_optional(other.properties),
+        }
+    }
+}
+
+impl From<ModelCard> for models::model_card::ModelCard {
+    fn from(other: ModelCard) -> Self {
+        Self {
+            bom_ref: other.bom_ref,
+            model_parameters: convert_optional(other.model_parameters),
+            quantitative_analysis: convert_optional(other.quantitative_analysis),
+            considerations: convert_optional(other.considerations),
+            properties: convert_optional(other.properties),
+        }
+    }
+}
+
+const MODEL_CARD_TAG: &str = "modelCard";
+const BOM_REF_ATTR: &str = "bom-ref";
+const MODEL_PARAMETERS_TAG: &str = "modelParameters";
+const QUANTITATIVE_ANALYSIS_TAG: &str = "quantitativeAnalysis";
+const CONSIDERATIONS_TAG: &str = "considerations";
+const PROPERTIES_TAG: &str = "properties";
+
+impl ToXml for ModelCard {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        let mut start_tag = XmlEvent::start_element(MODEL_CARD_TAG);
+
+        if let Some(bom_ref) = &self.bom_ref {
+            start_tag = start_tag.attr(BOM_REF_ATTR, bom_ref);
+        }
+
+        writer
+            .write(start_tag)
+            .map_err(to_xml_write_error(MODEL_CARD_TAG))?;
+
+        if let Some(model_parameters) = &self.model_parameters {
+            model_parameters.write_xml_element(writer)?;
+        }
+
+        if let Some(quantitative_analysis) = &self.quantitative_analysis {
+            quantitative_analysis.write_xml_element(writer)?;
+        }
+
+        if let Some(considerations) = &self.considerations {
+            considerations.write_xml_element(writer)?;
+        }
+
+        if let Some(properties) = &self.properties {
+            properties.write_xml_element(writer)?;
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(MODEL_CARD_TAG))?;
+
+        Ok(())
+    }
+}
+
+impl FromXml for ModelCard {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let bom_ref = optional_attribute(attributes, BOM_REF_ATTR);
+        let mut model_parameters: Option<ModelParameters> = None;
+        let mut quantitative_analysis: Option<QuantitativeAnalysis> = None;
+        let mut considerations: Option<Considerations> = None;
+        let mut properties: Option<Properties> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(MODEL_CARD_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == MODEL_PARAMETERS_TAG => {
+                    model_parameters = Some(ModelParameters::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == QUANTITATIVE_ANALYSIS_TAG => {
+                    quantitative_analysis = Some(QuantitativeAnalysis::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == CONSIDERATIONS_TAG => {
+                    considerations = Some(Considerations::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == PROPERTIES_TAG => {
+                    properties = Some(Properties::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)
+                }
+                // lax validation of any elements from a different schema
+                reader::XmlEvent::StartElement { name, .. } => {
+                    read_lax_validation_tag(event_reader, &name)?
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        Ok(Self {
+            bom_ref,
+            model_parameters,
+            quantitative_analysis,
+            considerations,
+            properties,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ModelParameters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    approach: Option<Approach>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    task: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    architecture_family: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    model_architecture: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    datasets: Option<Vec<Dataset>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    inputs: Option<Vec<MLParameter>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    outputs: Option<Vec<MLParameter>>,
+}
+
+impl From<models::model_card::ModelParameters> for ModelParameters {
+    fn from(other: models::model_card::ModelParameters) -> Self {
+        Self {
+            approach: other.approach.map(|approach| Approach {
+                approach_type: Some(approach.to_string()),
+            }),
+            task: other.task,
+            architecture_family: other.architecture_family,
+            model_architecture: other.model_architecture,
+            datasets: convert_optional_vec(other.datasets),
+            inputs: convert_optional_vec(other.inputs),
+            outputs: convert_optional_vec(other.outputs),
+        }
+    }
+}
+
+impl From<ModelParameters> for models::model_card::ModelParameters {
+    fn from(other: ModelParameters) -> Self {
+        Self {
+            approach: other
+                .approach
+                .and_then(|approach| approach.approach_type)
+                .map(models::model_card::ApproachType::new_unchecked),
+            task: other.task,
+            architecture_family: other.architecture_family,
+            model_architecture: other.model_architecture,
+            datasets: convert_optional_vec(other.datasets),
+            inputs: convert_optional_vec(other.inputs),
+            outputs: convert_optional_vec(other.outputs),
+        }
+    }
+}
+
+const APPROACH_TAG: &str = "approach";
+const TYPE_TAG: &str = "type";
+const TASK_TAG: &str = "task";
+const ARCHITECTURE_FAMILY_TAG: &str = "architectureFamily";
+const MODEL_ARCHITECTURE_TAG: &str = "modelArchitecture";
+const DATASETS_TAG: &str = "datasets";
+const DATASET_TAG: &str = "dataset";
+const REF_TAG: &str = "ref";
+const INPUTS_TAG: &str = "inputs";
+const INPUT_TAG: &str = "input";
+const OUTPUTS_TAG: &str = "outputs";
+const OUTPUT_TAG: &str = "output";
+
+impl ToXml for ModelParameters {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(MODEL_PARAMETERS_TAG))
+            .map_err(to_xml_write_error(MODEL_PARAMETERS_TAG))?;
+
+        if let Some(approach) = &self.approach {
+            writer
+                .write(XmlEvent::start_element(APPROACH_TAG))
+                .map_err(to_xml_write_error(APPROACH_TAG))?;
+
+            if let Some(approach_type) = &approach.approach_type {
+                write_simple_tag(writer, TYPE_TAG, approach_type)?;
+            }
+
+            writer
+                .write(XmlEvent::end_element())
+                .map_err(to_xml_write_error(APPROACH_TAG))?;
+        }
+
+        if let Some(task) = &self.task {
+            write_simple_tag(writer, TASK_TAG, task)?;
+        }
+
+        if let Some(architecture_family) = &self.architecture_family {
+            write_simple_tag(writer, ARCHITECTURE_FAMILY_TAG, architecture_family)?;
+        }
+
+        if let Some(model_architecture) = &self.model_architecture {
+            write_simple_tag(writer, MODEL_ARCHITECTURE_TAG, model_architecture)?;
+        }
+
+        if let Some(datasets) = &self.datasets {
+            write_list_tag(writer, DATASETS_TAG, datasets)?;
+        }
+
+        if let Some(inputs) = &self.inputs {
+            writer
+                .write(XmlEvent::start_element(INPUTS_TAG))
+                .map_err(to_xml_write_error(INPUTS_TAG))?;
+
+            for input in inputs {
+                input.write_xml_named_element(writer, INPUT_TAG)?;
+            }
+
+            writer
+                .write(XmlEvent::end_element())
+                .map_err(to_xml_write_error(INPUTS_TAG))?;
+        }
+
+        if let Some(outputs) = &self.outputs {
+            writer
+                .write(XmlEvent::start_element(OUTPUTS_TAG))
+                .map_err(to_xml_write_error(OUTPUTS_TAG))?;
+
+            for output in outputs {
+                output.write_xml_named_element(writer, OUTPUT_TAG)?;
+            }
+
+            writer
+                .write(XmlEvent::end_element())
+                .map_err(to_xml_write_error(OUTPUTS_TAG))?;
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(MODEL_PARAMETERS_TAG))?;
+
+        Ok(())
+    }
+}
+
+impl FromXml for ModelParameters {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let mut approach: Option<Approach> = None;
+        let mut task: Option<String> = None;
+        let mut architecture_family: Option<String> = None;
+        let mut model_architecture: Option<String> = None;
+        let mut datasets: Option<Vec<Dataset>> = None;
+        let mut inputs: Option<Vec<MLParameter>> = None;
+        let mut outputs: Option<Vec<MLParameter>> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(MODEL_PARAMETERS_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == APPROACH_TAG => {
+                    approach = Some(Approach::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == TASK_TAG => {
+                    task = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == ARCHITECTURE_FAMILY_TAG =>
+                {
+                    architecture_family = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == MODEL_ARCHITECTURE_TAG =>
+                {
+                    model_architecture = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == DATASETS_TAG => {
+                    datasets = Some(read_datasets(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == INPUTS_TAG => {
+                    inputs = Some(read_list_tag(event_reader, &name, INPUT_TAG)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == OUTPUTS_TAG => {
+                    outputs = Some(read_list_tag(event_reader, &name, OUTPUT_TAG)?)
+                }
+                // lax validation of any elements from a different schema
+                reader::XmlEvent::StartElement { name, .. } => {
+                    read_lax_validation_tag(event_reader, &name)?
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        Ok(Self {
+            approach,
+            task,
+            architecture_family,
+            model_architecture,
+            datasets,
+            inputs,
+            outputs,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+pub(crate) struct Approach {
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    approach_type: Option<String>,
+}
+
+impl FromXml for Approach {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let mut approach_type: Option<String> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(APPROACH_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == TYPE_TAG => {
+                    approach_type = Some(read_simple_tag(event_reader, &name)?)
+                }
+                // lax validation of any elements from a different schema
+                reader::XmlEvent::StartElement { name, .. } => {
+                    read_lax_validation_tag(event_reader, &name)?
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        Ok(Self { approach_type })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(untagged)]
+pub(crate) enum Dataset {
+    Component(Box<ComponentData>),
+    Reference {
+        #[serde(rename = "ref")]
+        reference: String,
+    },
+}
+
+impl From<models::model_card::Dataset> for Dataset {
+    fn from(other: models::model_card::Dataset) -> Self {
+        match other {
+            models::model_card::Dataset::Component(data) => {
+                Self::Component(Box::new((*data).into()))
+            }
+            models::model_card::Dataset::Reference(reference) => Self::Reference { reference },
+        }
+    }
+}
+
+impl From<Dataset> for models::model_card::Dataset {
+    fn from(other: Dataset) -> Self {
+        match other {
+            Dataset::Component(data) => Self::Component(Box::new((*data).into())),
+            Dataset::Reference { reference } => Self::Reference(reference),
+        }
+    }
+}
+
+impl ToXml for Dataset {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        match self {
+            Dataset::Component(data) => data.write_xml_named_element(writer, DATASET_TAG),
+            Dataset::Reference { reference } => write_simple_tag(writer, REF_TAG, reference),
+        }
+    }
+}
+
+fn read_datasets<R: std::io::Read>(
+    event_reader: &mut xml::EventReader<R>,
+    element_name: &xml::name::OwnedName,
+) -> Result<Vec<Dataset>, XmlReadError> {
+    let mut datasets = Vec::new();
+
+    let mut got_end_tag = false;
+    while !got_end_tag {
+        let next_element = event_reader
+            .next()
+            .map_err(to_xml_read_error(DATASETS_TAG))?;
+        match next_element {
+            reader::XmlEvent::StartElement {
+                name, attributes, ..
+            } if name.local_name == DATASET_TAG => datasets.push(Dataset::Component(Box::new(
+                ComponentData::read_xml_element(event_reader, &name, &attributes)?,
+            ))),
+            reader::XmlEvent::StartElement { name, .. } if name.local_name == REF_TAG => datasets
+                .push(Dataset::Reference {
+                    reference: read_simple_tag(event_reader, &name)?,
+                }),
+            reader::XmlEvent::EndElement { name } if &name == element_name => {
+                got_end_tag = true;
+            }
+            unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+        }
+    }
+
+    Ok(datasets)
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+pub(crate) struct MLParameter {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    format: Option<String>,
+}
+
+impl From<models::model_card::MLParameter> for MLParameter {
+    fn from(other: models::model_card::MLParameter) -> Self {
+        Self {
+            format: other.format,
+        }
+    }
+}
+
+impl From<MLParameter> for models::model_card::MLParameter {
+    fn from(other: MLParameter) -> Self {
+        Self {
+            format: other.format,
+        }
+    }
+}
+
+const FORMAT_TAG: &str = "format";
+
+impl ToInnerXml for MLParameter {
+    fn write_xml_named_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+        tag: &str,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(tag))
+            .map_err(to_xml_write_error(tag))?;
+
+        if let Some(format) = &self.format {
+            write_simple_tag(writer, FORMAT_TAG, format)?;
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(tag))?;
+
+        Ok(())
+    }
+}
+
+impl FromXml for MLParameter {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let mut format: Option<String> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(&element_name.local_name))?;
+            match next_element {
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == FORMAT_TAG => {
+                    format = Some(read_simple_tag(event_reader, &name)?)
+                }
+                // lax validation of any elements from a different schema
+                reader::XmlEvent::StartElement { name, .. } => {
+                    read_lax_validation_tag(event_reader, &name)?
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        Ok(Self { format })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct QuantitativeAnalysis {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    performance_metrics: Option<Vec<PerformanceMetric>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    graphics: Option<GraphicsCollection>,
+}
+
+impl From<models::model_card::QuantitativeAnalysis> for QuantitativeAnalysis {
+    fn from(other: models::model_card::QuantitativeAnalysis) -> Self {
+        Self {
+            performance_metrics: convert_optional_vec(other.performance_metrics),
+            graphics: convert_optional(other.graphics),
+        }
+    }
+}
+
+impl From<QuantitativeAnalysis> for models::model_card::QuantitativeAnalysis {
+    fn from(other: QuantitativeAnalysis) -> Self {
+        Self {
+            performance_metrics: convert_optional_vec(other.performance_metrics),
+            graphics: convert_optional(other.graphics),
+        }
+    }
+}
+
+const PERFORMANCE_METRICS_TAG: &str = "performanceMetrics";
+const PERFORMANCE_METRIC_TAG: &str = "performanceMetric";
+const GRAPHICS_TAG: &str = "graphics";
+
+impl ToXml for QuantitativeAnalysis {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(QUANTITATIVE_ANALYSIS_TAG))
+            .map_err(to_xml_write_error(QUANTITATIVE_ANALYSIS_TAG))?;
+
+        if let Some(performance_metrics) = &self.performance_metrics {
+            write_list_tag(writer, PERFORMANCE_METRICS_TAG, performance_metrics)?;
+        }
+
+        if let Some(graphics) = &self.graphics {
+            graphics.write_xml_element(writer)?;
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(QUANTITATIVE_ANALYSIS_TAG))?;
+
+        Ok(())
+    }
+}
+
+impl FromXml for QuantitativeAnalysis {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let mut performance_metrics: Option<Vec<PerformanceMetric>> = None;
+        let mut graphics: Option<GraphicsCollection> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(QUANTITATIVE_ANALYSIS_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == PERFORMANCE_METRICS_TAG =>
+                {
+                    performance_metrics =
+                        Some(read_list_tag(event_reader, &name, PERFORMANCE_METRIC_TAG)?)
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == GRAPHICS_TAG => {
+                    graphics = Some(GraphicsCollection::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)
+                }
+                // lax validation of any elements from a different schema
+                reader::XmlEvent::StartElement { name, .. } => {
+                    read_lax_validation_tag(event_reader, &name)?
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        Ok(Self {
+            performance_metrics,
+            graphics,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct PerformanceMetric {
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    metric_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    slice: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    confidence_interval: Option<ConfidenceInterval>,
+}
+
+impl From<models::model_card::PerformanceMetric> for PerformanceMetric {
+    fn from(other: models::model_card::PerformanceMetric) -> Self {
+        Self {
+            metric_type: other.metric_type,
+            value: other.value,
+            slice: other.slice,
+            confidence_interval: convert_optional(other.confidence_interval),
+        }
+    }
+}
+
+impl From<PerformanceMetric> for models::model_card::PerformanceMetric {
+    fn from(other: PerformanceMetric) -> Self {
+        Self {
+            metric_type: other.metric_type,
+            value: other.value,
+            slice: other.slice,
+            confidence_interval: convert_optional(other.confidence_interval),
+        }
+    }
+}
+
+const VALUE_TAG: &str = "value";
+const SLICE_TAG: &str = "slice";
+const CONFIDENCE_INTERVAL_TAG: &str = "confidenceInterval";
+const LOWER_BOUND_TAG: &str = "lowerBound";
+const UPPER_BOUND_TAG: &str = "upperBound";
+
+impl ToXml for PerformanceMetric {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(PERFORMANCE_METRIC_TAG))
+            .map_err(to_xml_write_error(PERFORMANCE_METRIC_TAG))?;
+
+        if let Some(metric_type) = &self.metric_type {
+            write_simple_tag(writer, TYPE_TAG, metric_type)?;
+        }
+
+        if let Some(value) = &self.value {
+            write_simple_tag(writer, VALUE_TAG, value)?;
+        }
+
+        if let Some(slice) = &self.slice {
+            write_simple_tag(writer, SLICE_TAG, slice)?;
+        }
+
+        if let Some(confidence_interval) = &self.confidence_interval {
+            writer
+                .write(XmlEvent::start_element(CONFIDENCE_INTERVAL_TAG))
+                .map_err(to_xml_write_error(CONFIDENCE_INTERVAL_TAG))?;
+
+            if let Some(lower_bound) = &confidence_interval.lower_bound {
+                write_simple_tag(writer, LOWER_BOUND_TAG, lower_bound)?;
+            }
+
+            if let Some(upper_bound) = &confidence_interval.upper_bound {
+                write_simple_tag(writer, UPPER_BOUND_TAG, upper_bound)?;
+            }
+
+            writer
+                .write(XmlEvent::end_element())
+                .map_err(to_xml_write_error(CONFIDENCE_INTERVAL_TAG))?;
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(PERFORMANCE_METRIC_TAG))?;
+
+        Ok(())
+    }
+}
+
+impl FromXml for PerformanceMetric {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let mut metric_type: Option<String> = None;
+        let mut value: Option<String> = None;
+        let mut slice: Option<String> = None;
+        let mut confidence_interval: Option<ConfidenceInterval> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(PERFORMANCE_METRIC_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == TYPE_TAG => {
+                    metric_type = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == VALUE_TAG => {
+                    value = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == SLICE_TAG => {
+                    slice = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == CONFIDENCE_INTERVAL_TAG => {
+                    confidence_interval = Some(ConfidenceInterval::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)
+                }
+                // lax validation of any elements from a different schema
+                reader::XmlEvent::StartElement { name, .. } => {
+                    read_lax_validation_tag(event_reader, &name)?
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        Ok(Self {
+            metric_type,
+            value,
+            slice,
+            confidence_interval,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ConfidenceInterval {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    lower_bound: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    upper_bound: Option<String>,
+}
+
+impl From<models::model_card::ConfidenceInterval> for ConfidenceInterval {
+    fn from(other: models::model_card::ConfidenceInterval) -> Self {
+        Self {
+            lower_bound: other.lower_bound,
+            upper_bound: other.upper_bound,
+        }
+    }
+}
+
+impl From<ConfidenceInterval> for models::model_card::ConfidenceInterval {
+    fn from(other: ConfidenceInterval) -> Self {
+        Self {
+            lower_bound: other.lower_bound,
+            upper_bound: other.upper_bound,
+        }
+    }
+}
+
+impl FromXml for ConfidenceInterval {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let mut lower_bound: Option<String> = None;
+        let mut upper_bound: Option<String> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(CONFIDENCE_INTERVAL_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == LOWER_BOUND_TAG =>
+                {
+                    lower_bound = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == UPPER_BOUND_TAG =>
+                {
+                    upper_bound = Some(read_simple_tag(event_reader, &name)?)
+                }
+                // lax validation of any elements from a different schema
+                reader::XmlEvent::StartElement { name, .. } => {
+                    read_lax_validation_tag(event_reader, &name)?
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        Ok(Self {
+            lower_bound,
+            upper_bound,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Considerations {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    users: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    use_cases: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    technical_limitations: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    performance_tradeoffs: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ethical_considerations: Option<Vec<EthicalConsideration>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fairness_assessments: Option<Vec<FairnessAssessment>>,
+}
+
+impl From<models::model_card::Considerations> for Considerations {
+    fn from(other: models::model_card::Considerations) -> Self {
+        Self {
+            users: other.users,
+            use_cases: other.use_cases,
+            technical_limitations: other.technical_limitations,
+            performance_tradeoffs: other.performance_tradeoffs,
+            ethical_considerations: convert_optional_vec(other.ethical_considerations),
+            fairness_assessments: convert_optional_vec(other.fairness_assessments),
+        }
+    }
+}
+
+impl From<Considerations> for models::model_card::Considerations {
+    fn from(other: Considerations) -> Self {
+        Self {
+            users: other.users,
+            use_cases: other.use_cases,
+            technical_limitations: other.technical_limitations,
+            performance_tradeoffs: other.performance_tradeoffs,
+            ethical_considerations: convert_optional_vec(other.ethical_considerations),
+            fairness_assessments: convert_optional_vec(other.fairness_assessments),
+        }
+    }
+}
+
+const USERS_TAG: &str = "users";
+const USER_TAG: &str = "user";
+const USE_CASES_TAG: &str = "useCases";
+const USE_CASE_TAG: &str = "useCase";
+const TECHNICAL_LIMITATIONS_TAG: &str = "technicalLimitations";
+const TECHNICAL_LIMITATION_TAG: &str = "technicalLimitation";
+const PERFORMANCE_TRADEOFFS_TAG: &str = "performanceTradeoffs";
+const PERFORMANCE_TRADEOFF_TAG: &str = "performanceTradeoff";
+const ETHICAL_CONSIDERATIONS_TAG: &str = "ethicalConsiderations";
+const ETHICAL_CONSIDERATION_TAG: &str = "ethicalConsideration";
+const FAIRNESS_ASSESSMENTS_TAG: &str = "fairnessAssessments";
+const FAIRNESS_ASSESSMENT_TAG: &str = "fairnessAssessment";
+
+impl ToXml for Considerations {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(CONSIDERATIONS_TAG))
+            .map_err(to_xml_write_error(CONSIDERATIONS_TAG))?;
+
+        for (tag, item_tag, items) in [
+            (USERS_TAG, USER_TAG, &self.users),
+            (USE_CASES_TAG, USE_CASE_TAG, &self.use_cases),
+            (
+                TECHNICAL_LIMITATIONS_TAG,
+                TECHNICAL_LIMITATION_TAG,
+                &self.technical_limitations,
+            ),
+            (
+                PERFORMANCE_TRADEOFFS_TAG,
+                PERFORMANCE_TRADEOFF_TAG,
+                &self.performance_tradeoffs,
+            ),
+        ] {
+            if let Some(items) = items {
+                write_simple_list_tag(writer, tag, item_tag, items)?;
+            }
+        }
+
+        if let Some(ethical_considerations) = &self.ethical_considerations {
+            write_list_tag(writer, ETHICAL_CONSIDERATIONS_TAG, ethical_considerations)?;
+        }
+
+        if let Some(fairness_assessments) = &self.fairness_assessments {
+            write_list_tag(writer, FAIRNESS_ASSESSMENTS_TAG, fairness_assessments)?;
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(CONSIDERATIONS_TAG))?;
+
+        Ok(())
+    }
+}
+
+impl FromXml for Considerations {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let mut users: Option<Vec<String>> = None;
+        let mut use_cases: Option<Vec<String>> = None;
+        let mut technical_limitations: Option<Vec<String>> = None;
+        let mut performance_tradeoffs: Option<Vec<String>> = None;
+        let mut ethical_considerations: Option<Vec<EthicalConsideration>> = None;
+        let mut fairness_assessments: Option<Vec<FairnessAssessment>> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(CONSIDERATIONS_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == USERS_TAG => {
+                    users = Some(read_list_tag(event_reader, &name, USER_TAG)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == USE_CASES_TAG => {
+                    use_cases = Some(read_list_tag(event_reader, &name, USE_CASE_TAG)?)
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == TECHNICAL_LIMITATIONS_TAG =>
+                {
+                    technical_limitations = Some(read_list_tag(
+                        event_reader,
+                        &name,
+                        TECHNICAL_LIMITATION_TAG,
+                    )?)
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == PERFORMANCE_TRADEOFFS_TAG =>
+                {
+                    performance_tradeoffs = Some(read_list_tag(
+                        event_reader,
+                        &name,
+                        PERFORMANCE_TRADEOFF_TAG,
+                    )?)
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == ETHICAL_CONSIDERATIONS_TAG =>
+                {
+                    ethical_considerations = Some(read_list_tag(
+                        event_reader,
+                        &name,
+                        ETHICAL_CONSIDERATION_TAG,
+                    )?)
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == FAIRNESS_ASSESSMENTS_TAG =>
+                {
+                    fairness_assessments =
+                        Some(read_list_tag(event_reader, &name, FAIRNESS_ASSESSMENT_TAG)?)
+                }
+                // lax validation of any elements from a different schema
+                reader::XmlEvent::StartElement { name, .. } => {
+                    read_lax_validation_tag(event_reader, &name)?
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        Ok(Self {
+            users,
+            use_cases,
+            technical_limitations,
+            performance_tradeoffs,
+            ethical_considerations,
+            fairness_assessments,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct EthicalConsideration {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mitigation_strategy: Option<String>,
+}
+
+impl From<models::model_card::EthicalConsideration> for EthicalConsideration {
+    fn from(other: models::model_card::EthicalConsideration) -> Self {
+        Self {
+            name: other.name,
+            mitigation_strategy: other.mitigation_strategy,
+        }
+    }
+}
+
+impl From<EthicalConsideration> for models::model_card::EthicalConsideration {
+    fn from(other: EthicalConsideration) -> Self {
+        Self {
+            name: other.name,
+            mitigation_strategy: other.mitigation_strategy,
+        }
+    }
+}
+
+const NAME_TAG: &str = "name";
+const MITIGATION_STRATEGY_TAG: &str = "mitigationStrategy";
+
+impl ToXml for EthicalConsideration {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(ETHICAL_CONSIDERATION_TAG))
+            .map_err(to_xml_write_error(ETHICAL_CONSIDERATION_TAG))?;
+
+        if let Some(name) = &self.name {
+            write_simple_tag(writer, NAME_TAG, name)?;
+        }
+
+        if let Some(mitigation_strategy) = &self.mitigation_strategy {
+            write_simple_tag(writer, MITIGATION_STRATEGY_TAG, mitigation_strategy)?;
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(ETHICAL_CONSIDERATION_TAG))?;
+
+        Ok(())
+    }
+}
+
+impl FromXml for EthicalConsideration {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let mut name: Option<String> = None;
+        let mut mitigation_strategy: Option<String> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(ETHICAL_CONSIDERATION_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement { name: tag, .. } if tag.local_name == NAME_TAG => {
+                    name = Some(read_simple_tag(event_reader, &tag)?)
+                }
+                reader::XmlEvent::StartElement { name: tag, .. }
+                    if tag.local_name == MITIGATION_STRATEGY_TAG =>
+                {
+                    mitigation_strategy = Some(read_simple_tag(event_reader, &tag)?)
+                }
+                // lax validation of any elements from a different schema
+                reader::XmlEvent::StartElement { name: tag, .. } => {
+                    read_lax_validation_tag(event_reader, &tag)?
+                }
+                reader::XmlEvent::EndElement { name: tag } if &tag == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        Ok(Self {
+            name,
+            mitigation_strategy,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct FairnessAssessment {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group_at_risk: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    benefits: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    harms: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mitigation_strategy: Option<String>,
+}
+
+impl From<models::model_card::FairnessAssessment> for FairnessAssessment {
+    fn from(other: models::model_card::FairnessAssessment) -> Self {
+        Self {
+            group_at_risk: other.group_at_risk,
+            benefits: other.benefits,
+            harms: other.harms,
+            mitigation_strategy: other.mitigation_strategy,
+        }
+    }
+}
+
+impl From<FairnessAssessment> for models::model_card::FairnessAssessment {
+    fn from(other: FairnessAssessment) -> Self {
+        Self {
+            group_at_risk: other.group_at_risk,
+            benefits: other.benefits,
+            harms: other.harms,
+            mitigation_strategy: other.mitigation_strategy,
+        }
+    }
+}
+
+const GROUP_AT_RISK_TAG: &str = "groupAtRisk";
+const BENEFITS_TAG: &str = "benefits";
+const HARMS_TAG: &str = "harms";
+
+impl ToXml for FairnessAssessment {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(FAIRNESS_ASSESSMENT_TAG))
+            .map_err(to_xml_write_error(FAIRNESS_ASSESSMENT_TAG))?;
+
+        for (tag, value) in [
+            (GROUP_AT_RISK_TAG, &self.group_at_risk),
+            (BENEFITS_TAG, &self.benefits),
+            (HARMS_TAG, &self.harms),
+            (MITIGATION_STRATEGY_TAG, &self.mitigation_strategy),
+        ] {
+            if let Some(value) = value {
+                write_simple_tag(writer, tag, value)?;
+            }
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(FAIRNESS_ASSESSMENT_TAG))?;
+
+        Ok(())
+    }
+}
+
+impl FromXml for FairnessAssessment {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let mut group_at_risk: Option<String> = None;
+        let mut benefits: Option<String> = None;
+        let mut harms: Option<String> = None;
+        let mut mitigation_strategy: Option<String> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(FAIRNESS_ASSESSMENT_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == GROUP_AT_RISK_TAG =>
+                {
+                    group_at_risk = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == BENEFITS_TAG => {
+                    benefits = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == HARMS_TAG => {
+                    harms = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == MITIGATION_STRATEGY_TAG =>
+                {
+                    mitigation_strategy = Some(read_simple_tag(event_reader, &name)?)
+                }
+                // lax validation of any elements from a different schema
+                reader::XmlEvent::StartElement { name, .. } => {
+                    read_lax_validation_tag(event_reader, &name)?
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        Ok(Self {
+            group_at_risk,
+            benefits,
+            harms,
+            mitigation_strategy,
+        })
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use super::*;
+    use crate::{
+        specs::v1_5::{
+            component_data::test::{
+                corresponding_component_data, corresponding_graphics, example_component_data,
+                example_graphics,
+            },
+            property::test::{corresponding_properties, example_properties},
+        },
+        xml::test::{read_element_from_string, write_element_to_string},
+    };
+
+    pub(crate) fn example_model_card() -> ModelCard {
+        ModelCard {
+            bom_ref: Some("model card".to_string()),
+            model_parameters: Some(ModelParameters {
+                approach: Some(Approach {
+                    approach_type: Some("supervised".to_string()),
+                }),
+                task: Some("task".to_string()),
+                architecture_family: Some("architecture family".to_string()),
+                model_architecture: Some("model architecture".to_string()),
+                datasets: Some(vec![
+                    Dataset::Component(Box::new(example_component_data())),
+                    Dataset::Reference {
+                        reference: "dataset".to_string(),
+                    },
+                ]),
+                inputs: Some(vec![MLParameter {
+                    format: Some("input format".to_string()),
+                }]),
+                outputs: Some(vec![MLParameter {
+                    format: Some("output format".to_string()),
+                }]),
+            }),
+            quantitative_analysis: Some(QuantitativeAnalysis {
+                performance_metrics: Some(vec![PerformanceMetric {
+                    metric_type: Some("metric type".to_string()),
+                    value: Some("value".to_string()),
+                    slice: Some("slice".to_string()),
+                    confidence_interval: Some(ConfidenceInterval {
+                        lower_bound: Some("lower bound".to_string()),
+                        upper_bound: Some("upper bound".to_string()),
+                    }),
+                }]),
+                graphics: Some(example_graphics()),
+            }),
+            considerations: Some(Considerations {
+                users: Some(vec!["user".to_string()]),
+                use_cases: Some(vec!["use case".to_string()]),
+                technical_limitations: Some(vec!["technical limitation".to_string()]),
+                performance_tradeoffs: Some(vec!["performance tradeoff".to_string()]),
+                ethical_considerations: Some(vec![EthicalConsideration {
+                    name: Some("name".to_string()),
+                    mitigation_strategy: Some("mitigation strategy".to_string()),
+                }]),
+                fairness_assessments: Some(vec![FairnessAssessment {
+                    group_at_risk: Some("group at risk".to_string()),
+                    benefits: Some("benefits".to_string()),
+                    harms: Some("harms".to_string()),
+                    mitigation_strategy: Some("mitigation strategy".to_string()),
+                }]),
+            }),
+            properties: Some(example_properties()),
+        }
+    }
+
+    pub(crate) fn corresponding_model_card() -> models::model_card::ModelCard {
+        models::model_card::ModelCard {
+            bom_ref: Some("model card".to_string()),
+            model_parameters: Some(models::model_card::ModelParameters {
+                approach: Some(models::model_card::ApproachType::Supervised),
+                task: Some("task".to_string()),
+                architecture_family: Some("architecture family".to_string()),
+                model_architecture: Some("model architecture".to_string()),
+                datasets: Some(vec![
+                    models::model_card::Dataset::Component(
+                        Box::new(corresponding_component_data()),
+                    ),
+                    models::model_card::Dataset::Reference("dataset".to_string()),
+                ]),
+                inputs: Some(vec![models::model_card::MLParameter {
+                    format: Some("input format".to_string()),
+                }]),
+                outputs: Some(vec![models::model_card::MLParameter {
+                    format: Some("output format".to_string()),
+                }]),
+            }),
+            quantitative_analysis: Some(models::model_card::QuantitativeAnalysis {
+                performance_metrics: Some(vec![models::model_card::PerformanceMetric {
+                    metric_type: Some("metric type".to_string()),
+                    value: Some("value".to_string()),
+                    slice: Some("slice".to_string()),
+                    confidence_interval: Some(models::model_card::ConfidenceInterval {
+                        lower_bound: Some("lower bound".to_string()),
+                        upper_bound: Some("upper bound".to_string()),
+                    }),
+                }]),
+                graphics: Some(corresponding_graphics()),
+            }),
+            considerations: Some(models::model_card::Considerations {
+                users: Some(vec!["user".to_string()]),
+                use_cases: Some(vec!["use case".to_string()]),
+                technical_limitations: Some(vec!["technical limitation".to_string()]),
+                performance_tradeoffs: Some(vec!["performance tradeoff".to_string()]),
+                ethical_considerations: Some(vec![models::model_card::EthicalConsideration {
+                    name: Some("name".to_string()),
+                    mitigation_strategy: Some("mitigation strategy".to_string()),
+                }]),
+                fairness_assessments: Some(vec![models::model_card::FairnessAssessment {
+                    group_at_risk: Some("group at risk".to_string()),
+                    benefits: Some("benefits".to_string()),
+                    harms: Some("harms".to_string()),
+                    mitigation_strategy: Some("mitigation strategy".to_string()),
+                }]),
+            }),
+            properties: Some(corresponding_properties()),
+        }
+    }
+
+    #[test]
+    fn it_should_write_json_full() {
+        insta::assert_json_snapshot!(example_model_card());
+    }
+
+    #[test]
+    fn it_should_read_json_with_a_dataset_reference() {
+        let input = r#"{
+  "modelParameters": {
+    "datasets": [
+      { "ref": "dataset" },
+      { "type": "dataset", "name": "training data" }
+    ]
+  }
+}"#;
+        let actual: ModelCard = serde_json::from_str(input).expect("Failed to read the JSON");
+        let datasets = actual
+            .model_parameters
+            .and_then(|parameters| parameters.datasets)
+            .expect("Expected datasets");
+
+        assert!(matches!(&datasets[0], Dataset::Reference { reference } if reference == "dataset"));
+        assert!(matches!(&datasets[1], Dataset::Component(_)));
+    }
+
+    #[test]
+    fn it_should_write_xml_full() {
+        let xml_output = write_element_to_string(example_model_card());
+        insta::assert_snapshot!(xml_output);
+    }
+
+    #[test]
+    fn it_should_read_xml_full() {
+        let input = r#"
+<modelCard bom-ref="model card">
+  <modelParameters>
+    <approach>
+      <type>supervised</type>
+    </approach>
+    <task>task</task>
+    <architectureFamily>architecture family</architectureFamily>
+    <modelArchitecture>model architecture</modelArchitecture>
+    <datasets>
+      <dataset bom-ref="bom ref">
+        <type>data type</type>
+        <name>name</name>
+        <contents>
+          <attachment content-type="content type" encoding="encoding">content</attachment>
+          <url>url</url>
+          <properties>
+            <property name="name">value</property>
+          </properties>
+        </contents>
+        <classification>classification</classification>
+        <sensitiveData>sensitive data</sensitiveData>
+        <graphics>
+          <description>description</description>
+          <collection>
+            <graphic>
+              <name>name</name>
+              <image content-type="content type" encoding="encoding">content</image>
+            </graphic>
+          </collection>
+        </graphics>
+        <description>description</description>
+      </dataset>
+      <ref>dataset</ref>
+    </datasets>
+    <inputs>
+      <input>
+        <format>input format</format>
+      </input>
+    </inputs>
+    <outputs>
+      <output>
+        <format>output format</format>
+      </output>
+    </outputs>
+  </modelParameters>
+  <quantitativeAnalysis>
+    <performanceMetrics>
+      <performanceMetric>
+        <type>metric type</type>
+        <value>value</value>
+        <slice>slice</slice>
+        <confidenceInterval>
+          <lowerBound>lower bound</lowerBound>
+          <upperBound>upper bound</upperBound>
+        </confidenceInterval>
+      </performanceMetric>
+    </performanceMetrics>
+    <graphics>
+      <description>description</description>
+      <collection>
+        <graphic>
+          <name>name</name>
+          <image content-type="content type" encoding="encoding">content</image>
+        </graphic>
+      </collection>
+    </graphics>
+  </quantitativeAnalysis>
+  <considerations>
+    <users>
+      <user>user</user>
+    </users>
+    <useCases>
+      <useCase>use case</useCase>
+    </useCases>
+    <technicalLimitations>
+      <technicalLimitation>technical limitation</technicalLimitation>
+    </technicalLimitations>
+    <performanceTradeoffs>
+      <performanceTradeoff>performance tradeoff</performanceTradeoff>
+    </performanceTradeoffs>
+    <ethicalConsiderations>
+      <ethicalConsideration>
+        <name>name</name>
+        <mitigationStrategy>mitigation strategy</mitigationStrategy>
+      </ethicalConsideration>
+    </ethicalConsiderations>
+    <fairnessAssessments>
+      <fairnessAssessment>
+        <groupAtRisk>group at risk</groupAtRisk>
+        <benefits>benefits</benefits>
+        <harms>harms</harms>
+        <mitigationStrategy>mitigation strategy</mitigationStrategy>
+      </fairnessAssessment>
+    </fairnessAssessments>
+  </considerations>
+  <properties>
+    <property name="name">value</property>
+  </properties>
+</modelCard>
+"#;
+        let actual: ModelCard = read_element_from_string(input);
+        assert_eq!(actual, example_model_card());
+    }
+
+    #[test]
+    fn it_should_convert_to_the_model() {
+        let actual: models::model_card::ModelCard = example_model_card().into();
+        assert_eq!(actual, corresponding_model_card());
+
+        let actual: ModelCard = corresponding_model_card().into();
+        assert_eq!(actual, example_model_card());
+    }
+}
@@ -0,0 +1,306 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! The `cargo:` properties that describe Rust packages and how cargo built them, and the
+//! `rustc:` properties of the flags passed to rustc
+//!
+//! The [CycloneDX property taxonomy](https://github.com/CycloneDX/cyclonedx-property-taxonomy)
+//! has not registered a `cdx:` namespace for Cargo, so the properties are not named `cdx:*`. The
+//! names are written and read through [`CargoProperty`], with typed accessors on [`Component`]
+//! for the values that are lists or flags.
+//!
+//! ```
+//! use cyclonedx_bom::models::component::{Classification, Component};
+//! use cyclonedx_bom::taxonomy::CargoProperty;
+//!
+//! let mut component = Component::new(Classification::Library, "tacos", "1.0.0", None);
+//! component.set_cargo_features(&["default", "salsa"]);
+//! component.set_cargo_property(CargoProperty::EmbeddedBy, "src/main.rs");
+//!
+//! assert_eq!(component.cargo_features(), vec!["default", "salsa"]);
+//! assert_eq!(
+//!     component.cargo_property(CargoProperty::EmbeddedBy),
+//!     Some("src/main.rs")
+//! );
+//! ```
+
+use crate::models::component::Component;
+use crate::models::property::Properties;
+use std::fmt;
+use std::str::FromStr;
+
+/// A property that describes a Rust package or how cargo built it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CargoProperty {
+    /// Comma-separated features a package was resolved or installed with
+    Features,
+    /// Name of the feature set of the feature matrix an SBOM was generated for
+    FeatureSet,
+    /// Comma-separated features a binary target requires
+    RequiredFeatures,
+    /// Source file that embeds a file with `include_bytes!` or `include_str!`
+    EmbeddedBy,
+    /// License expression of a package as determined from its manifest and license files
+    EffectiveLicense,
+    /// Origin of the effective license, `declared` or `license-files`
+    EffectiveLicenseSource,
+    /// Commit a published package was packaged from
+    ProvenanceCommit,
+    /// Directory of a published package in its repository
+    ProvenancePath,
+    /// `true` if a package was published with uncommitted changes
+    ProvenanceDirty,
+    /// Name of the profile the binaries are built with
+    Profile,
+    /// `opt-level` of the profile
+    ProfileOptLevel,
+    /// `lto` of the profile
+    ProfileLto,
+    /// `panic` strategy of the profile
+    ProfilePanic,
+    /// `overflow-checks` of the profile
+    ProfileOverflowChecks,
+    /// `debug-assertions` of the profile
+    ProfileDebugAssertions,
+    /// `strip` setting of the profile
+    ProfileStrip,
+    /// `-C target-feature` flag passed to rustc
+    TargetFeature,
+    /// `-C relocation-model` flag passed to rustc
+    RelocationModel,
+    /// `-Z relro-level` flag passed to rustc
+    RelroLevel,
+    /// `-C link-arg` flag passed to rustc that enables or disables RELRO, immediate binding or PIE
+    LinkArg,
+    /// Source an installed package was installed from
+    Source,
+    /// Comma-separated binaries of an installed package
+    Bins,
+    /// Target triple an installed package was built for
+    Target,
+    /// Version of rustc an installed package was built with
+    Rustc,
+}
+
+impl CargoProperty {
+    pub const ALL: &'static [CargoProperty] = &[
+        CargoProperty::Features,
+        CargoProperty::FeatureSet,
+        CargoProperty::RequiredFeatures,
+        CargoProperty::EmbeddedBy,
+        CargoProperty::EffectiveLicense,
+        CargoProperty::EffectiveLicenseSource,
+        CargoProperty::ProvenanceCommit,
+        CargoProperty::ProvenancePath,
+        CargoProperty::ProvenanceDirty,
+        CargoProperty::Profile,
+        CargoProperty::ProfileOptLevel,
+        CargoProperty::ProfileLto,
+        CargoProperty::ProfilePanic,
+        CargoProperty::ProfileOverflowChecks,
+        CargoProperty::ProfileDebugAssertions,
+        CargoProperty::ProfileStrip,
+        CargoProperty::TargetFeature,
+        CargoProperty::RelocationModel,
+        CargoProperty::RelroLevel,
+        CargoProperty::LinkArg,
+        CargoProperty::Source,
+        CargoProperty::Bins,
+        CargoProperty::Target,
+        CargoProperty::Rustc,
+    ];
+
+    /// The name of the property, starting with `cargo:`, or `rustc:` for the flags passed to rustc
+    pub fn name(&self) -> &'static str {
+        match self {
+            CargoProperty::Features => "cargo:features",
+            CargoProperty::FeatureSet => "cargo:feature-set",
+            CargoProperty::RequiredFeatures => "cargo:required-features",
+            CargoProperty::EmbeddedBy => "cargo:embedded-by",
+            CargoProperty::EffectiveLicense => "cargo:effective-license",
+            CargoProperty::EffectiveLicenseSource => "cargo:effective-license:source",
+            CargoProperty::ProvenanceCommit => "cargo:provenance:commit",
+            CargoProperty::ProvenancePath => "cargo:provenance:path",
+            CargoProperty::ProvenanceDirty => "cargo:provenance:dirty",
+            CargoProperty::Profile => "cargo:profile",
+            CargoProperty::ProfileOptLevel => "cargo:profile:opt-level",
+            CargoProperty::ProfileLto => "cargo:profile:lto",
+            CargoProperty::ProfilePanic => "cargo:profile:panic",
+            CargoProperty::ProfileOverflowChecks => "cargo:profile:overflow-checks",
+            CargoProperty::ProfileDebugAssertions => "cargo:profile:debug-assertions",
+            CargoProperty::ProfileStrip => "cargo:profile:strip",
+            CargoProperty::TargetFeature => "rustc:target-feature",
+            CargoProperty::RelocationModel => "rustc:relocation-model",
+            CargoProperty::RelroLevel => "rustc:relro-level",
+            CargoProperty::LinkArg => "rustc:link-arg",
+            CargoProperty::Source => "cargo:source",
+            CargoProperty::Bins => "cargo:bins",
+            CargoProperty::Target => "cargo:target",
+            CargoProperty::Rustc => "cargo:rustc",
+        }
+    }
+}
+
+impl fmt::Display for CargoProperty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.name().fmt(f)
+    }
+}
+
+impl FromStr for CargoProperty {
+    type Err = String;
+
+    /// Parses the name of a property
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        CargoProperty::ALL
+            .iter()
+            .copied()
+            .find(|property| property.name() == s)
+            .ok_or_else(|| format!("Unknown cargo property: {}", s))
+    }
+}
+
+impl Properties {
+    /// The value of `property`
+    pub fn cargo(&self, property: CargoProperty) -> Option<&str> {
+        self.get(property.name())
+    }
+
+    /// Sets the value of `property`
+    pub fn upsert_cargo(&mut self, property: CargoProperty, value: &str) {
+        self.upsert(property.name(), value);
+    }
+}
+
+impl Component {
+    /// The value of `property`
+    pub fn cargo_property(&self, property: CargoProperty) -> Option<&str> {
+        self.properties
+            .as_ref()
+            .and_then(|properties| properties.cargo(property))
+    }
+
+    /// Sets the value of `property`, see [`Properties::upsert_cargo`]
+    pub fn set_cargo_property(&mut self, property: CargoProperty, value: &str) {
+        self.properties
+            .get_or_insert_with(|| Properties(Vec::new()))
+            .upsert_cargo(property, value);
+    }
+
+    /// The features the package was resolved or installed with
+    pub fn cargo_features(&self) -> Vec<&str> {
+        self.cargo_list(CargoProperty::Features)
+    }
+
+    pub fn set_cargo_features<S: AsRef<str>>(&mut self, features: &[S]) {
+        self.set_cargo_list(CargoProperty::Features, features);
+    }
+
+    /// The features a binary target requires
+    pub fn cargo_required_features(&self) -> Vec<&str> {
+        self.cargo_list(CargoProperty::RequiredFeatures)
+    }
+
+    pub fn set_cargo_required_features<S: AsRef<str>>(&mut self, features: &[S]) {
+        self.set_cargo_list(CargoProperty::RequiredFeatures, features);
+    }
+
+    /// The binaries of an installed package
+    pub fn cargo_bins(&self) -> Vec<&str> {
+        self.cargo_list(CargoProperty::Bins)
+    }
+
+    pub fn set_cargo_bins<S: AsRef<str>>(&mut self, bins: &[S]) {
+        self.set_cargo_list(CargoProperty::Bins, bins);
+    }
+
+    /// Whether the package was published with uncommitted changes
+    pub fn cargo_provenance_dirty(&self) -> bool {
+        self.cargo_property(CargoProperty::ProvenanceDirty) == Some("true")
+    }
+
+    /// Records that the package was published with uncommitted changes, or removes the property
+    /// if it was not, as the property is only written for dirty packages
+    pub fn set_cargo_provenance_dirty(&mut self, dirty: bool) {
+        if dirty {
+            self.set_cargo_property(CargoProperty::ProvenanceDirty, "true");
+        } else if let Some(properties) = &mut self.properties {
+            properties.remove(CargoProperty::ProvenanceDirty.name());
+        }
+    }
+
+    fn cargo_list(&self, property: CargoProperty) -> Vec<&str> {
+        self.cargo_property(property)
+            .map(|value| value.split(',').filter(|item| !item.is_empty()).collect())
+            .unwrap_or_default()
+    }
+
+    fn set_cargo_list<S: AsRef<str>>(&mut self, property: CargoProperty, items: &[S]) {
+        let value = items
+            .iter()
+            .map(AsRef::as_ref)
+            .collect::<Vec<_>>()
+            .join(",");
+        self.set_cargo_property(property, &value);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::models::component::Classification;
+    use crate::models::property::Property;
+
+    #[test]
+    fn it_should_keep_the_cargo_and_rustc_names() {
+        for property in CargoProperty::ALL {
+            assert!(property.name().starts_with("cargo:") || property.name().starts_with("rustc:"));
+            assert_eq!(property.name().parse(), Ok(*property));
+        }
+        assert_eq!(CargoProperty::Features.name(), "cargo:features");
+        assert_eq!(CargoProperty::LinkArg.name(), "rustc:link-arg");
+        assert!("cdx:cargo:features".parse::<CargoProperty>().is_err());
+        assert!("cargo-cyclonedx:commit".parse::<CargoProperty>().is_err());
+    }
+
+    #[test]
+    fn it_should_read_and_replace_properties() {
+        let mut component = Component::new(Classification::Library, "tacos", "1.0.0", None);
+        component.properties = Some(Properties(vec![
+            Property::new("cargo:features", "default,salsa"),
+            Property::new("cargo:provenance:dirty", "true"),
+        ]));
+
+        assert_eq!(component.cargo_features(), vec!["default", "salsa"]);
+        assert!(component.cargo_provenance_dirty());
+
+        component.set_cargo_features(&["guacamole"]);
+        component.set_cargo_provenance_dirty(false);
+        component.set_cargo_required_features::<&str>(&[]);
+
+        assert_eq!(
+            component.properties,
+            Some(Properties(vec![
+                Property::new("cargo:features", "guacamole"),
+                Property::new("cargo:required-features", ""),
+            ]))
+        );
+        assert!(component.cargo_required_features().is_empty());
+        assert!(!component.cargo_provenance_dirty());
+    }
+}
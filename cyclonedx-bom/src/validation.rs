@@ -16,9 +16,44 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+use serde::Serialize;
+
 pub trait Validate {
+    /// Validate, reporting only the failure reasons that are errors
     fn validate(&self) -> Result<ValidationResult, ValidationError> {
-        self.validate_with_context(ValidationContext::default())
+        self.validate_with_options(ValidationOptions::default())
+    }
+
+    /// Validate, reporting the failure reasons of at least the minimum severity of `options`
+    ///
+    /// ```
+    /// use cyclonedx_bom::prelude::*;
+    /// use cyclonedx_bom::validation::{Severity, ValidationOptions};
+    ///
+    /// let options = ValidationOptions {
+    ///     minimum_severity: Severity::Warning,
+    /// };
+    /// let validation_result = Bom::default()
+    ///     .validate_with_options(options)
+    ///     .expect("Failed to validate BOM");
+    ///
+    /// // a BOM without a timestamp is valid, but not recommended
+    /// assert!(validation_result.passed());
+    /// assert_eq!(validation_result.reasons()[0].severity, Severity::Warning);
+    /// ```
+    fn validate_with_options(
+        &self,
+        options: ValidationOptions,
+    ) -> Result<ValidationResult, ValidationError> {
+        let result = self.validate_with_context(ValidationContext::default())?;
+
+        Ok(ValidationResult::from_reasons(
+            result
+                .into_reasons()
+                .into_iter()
+                .filter(|reason| reason.severity >= options.minimum_severity)
+                .collect(),
+        ))
     }
 
     fn validate_with_context(
@@ -49,6 +84,198 @@ impl ValidationContext {
 
         self.extend_context(component)
     }
+
+    /// The [JSON Pointer](https://www.rfc-editor.org/rfc/rfc6901) of the invalid value in a JSON
+    /// document, relative to the validated value
+    ///
+    /// ```
+    /// use cyclonedx_bom::prelude::*;
+    /// use cyclonedx_bom::validation::{Severity, ValidationOptions};
+    ///
+    /// let validation_result = Bom::default()
+    ///     .validate_with_options(ValidationOptions {
+    ///         minimum_severity: Severity::Warning,
+    ///     })
+    ///     .expect("Failed to validate BOM");
+    ///
+    /// let context = &validation_result.reasons()[0].context;
+    /// assert_eq!(context.to_json_pointer(), "/metadata/timestamp");
+    /// assert_eq!(context.to_xpath(), "/bom/metadata/timestamp");
+    /// ```
+    pub fn to_json_pointer(&self) -> String {
+        let mut pointer = String::new();
+
+        for component in &self.0 {
+            let token = match component {
+                ValidationPathComponent::Struct {
+                    struct_name,
+                    field_name,
+                } => json_field_name(struct_name, field_name),
+                ValidationPathComponent::Array { index } => Some(index.to_string()),
+                ValidationPathComponent::EnumVariant { variant_name } => {
+                    Some(variant_key(variant_name))
+                }
+            };
+
+            if let Some(token) = token {
+                pointer.push('/');
+                pointer.push_str(&token.replace('~', "~0").replace('/', "~1"));
+            }
+        }
+
+        pointer
+    }
+
+    /// The XPath of the invalid value in an XML document, which is absolute if the validated
+    /// value is a [`Bom`](crate::models::bom::Bom) and relative to it otherwise
+    ///
+    /// The items of a list of licenses are matched with `*`, as licenses and expressions are
+    /// different elements of the same list.
+    pub fn to_xpath(&self) -> String {
+        let mut steps: Vec<String> = vec![];
+        let mut item_name = "*".to_string();
+
+        for component in &self.0 {
+            match component {
+                ValidationPathComponent::Struct {
+                    struct_name,
+                    field_name,
+                } => {
+                    steps.extend(xml_field_name(struct_name, field_name));
+                    item_name = xml_item_name(struct_name, field_name);
+                }
+                ValidationPathComponent::Array { index } => {
+                    steps.push(format!("{}[{}]", item_name, index + 1));
+                    item_name = "*".to_string();
+                }
+                ValidationPathComponent::EnumVariant { variant_name } => {
+                    // the choice between a license and an expression is the item element itself
+                    if variant_name != "License" && variant_name != "Expression" {
+                        steps.push(variant_key(variant_name));
+                    }
+                }
+            }
+        }
+
+        match self.0.first() {
+            Some(ValidationPathComponent::Struct { struct_name, .. }) if struct_name == "Bom" => {
+                std::iter::once("/bom".to_string())
+                    .chain(steps)
+                    .collect::<Vec<_>>()
+                    .join("/")
+            }
+            _ => steps.join("/"),
+        }
+    }
+}
+
+/// The key of a field in JSON documents, or `None` if its content is part of its parent
+fn json_field_name(struct_name: &str, field_name: &str) -> Option<String> {
+    match (struct_name, field_name) {
+        ("License", "license_identifier") => None,
+        ("Dependency", "dependency_ref") => Some("ref".to_string()),
+        ("Dependency", "dependencies") => Some("dependsOn".to_string()),
+        (_, "bom_ref") => Some("bom-ref".to_string()),
+        (_, "mime_type") => Some("mime-type".to_string()),
+        (_, field_name) if is_type_field(struct_name, field_name) => Some("type".to_string()),
+        (_, field_name) => Some(camel_case(field_name)),
+    }
+}
+
+/// The element or `@`attribute of a field in XML documents, or `None` if its content is part of
+/// its parent
+fn xml_field_name(struct_name: &str, field_name: &str) -> Option<String> {
+    match (struct_name, field_name) {
+        ("License", "license_identifier")
+        | ("Dependency", "dependencies")
+        | ("Hash", "content")
+        | ("Property", "value")
+        | ("AttachedText", "content")
+        | ("DataClassification", "classification") => None,
+        ("Bom", "serial_number") => Some("@serialNumber".to_string()),
+        ("Dependency", "dependency_ref") => Some("@ref".to_string()),
+        ("Hash", "alg") => Some("@alg".to_string()),
+        ("Property", "name") => Some("@name".to_string()),
+        ("AttachedText", "content_type") => Some("@content-type".to_string()),
+        ("AttachedText", "encoding") => Some("@encoding".to_string()),
+        ("DataClassification", "flow") => Some("@flow".to_string()),
+        (_, "bom_ref") => Some("@bom-ref".to_string()),
+        (_, "mime_type") => Some("@mime-type".to_string()),
+        ("Component", "component_type")
+        | ("ExternalReference", "external_reference_type")
+        | ("Issue", "issue_type")
+        | ("Patch", "patch_type") => Some("@type".to_string()),
+        (_, field_name) if is_type_field(struct_name, field_name) => Some("type".to_string()),
+        (_, field_name) => Some(camel_case(field_name)),
+    }
+}
+
+/// The element of the items of a list in XML documents
+fn xml_item_name(struct_name: &str, field_name: &str) -> String {
+    let item_name = match (struct_name, field_name) {
+        (_, "licenses") => "*",
+        ("Service", "data") => "classification",
+        (_, "external_references") => "reference",
+        (_, "ancestors") | (_, "descendants") | (_, "variants") => "component",
+        (_, "affects") => "target",
+        (_, "resolves") => "issue",
+        (_, "assemblies") => "assembly",
+        (_, "collection") => "graphic",
+        (_, "formulation") => "formula",
+        (_, "dependencies") => "dependency",
+        (_, "properties") => "property",
+        (_, "vulnerabilities") => "vulnerability",
+        (_, "patches") => "patch",
+        (_, "aliases") => "alias",
+        (_, field_name) => {
+            return camel_case(field_name.strip_suffix('s').unwrap_or(field_name));
+        }
+    };
+
+    item_name.to_string()
+}
+
+/// Whether the field is the `type` of its struct, which is a keyword in Rust
+fn is_type_field(struct_name: &str, field_name: &str) -> bool {
+    matches!(
+        (struct_name, field_name),
+        ("Component", "component_type")
+            | ("ComponentData", "data_type")
+            | ("ExternalReference", "external_reference_type")
+            | ("Issue", "issue_type")
+            | ("Output", "output_type")
+            | ("Patch", "patch_type")
+            | ("ReleaseNotes", "release_type")
+            | ("Trigger", "trigger_type")
+    )
+}
+
+/// The key of an enum variant that is a choice between keys, e.g. `SpdxId` of a license
+fn variant_key(variant_name: &str) -> String {
+    match variant_name {
+        "SpdxId" => "id".to_string(),
+        variant_name => {
+            let mut chars = variant_name.chars();
+            chars
+                .next()
+                .map(|first| first.to_lowercase().chain(chars).collect())
+                .unwrap_or_default()
+        }
+    }
+}
+
+fn camel_case(field_name: &str) -> String {
+    let mut words = field_name.split('_');
+    let first = words.next().unwrap_or_default().to_string();
+
+    words.fold(first, |mut name, word| {
+        let mut chars = word.chars();
+        if let Some(initial) = chars.next() {
+            name.extend(initial.to_uppercase());
+            name.extend(chars);
+        }
+        name
+    })
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -65,48 +292,326 @@ pub enum ValidationPathComponent {
     },
 }
 
-#[derive(Debug, PartialEq, Eq)]
+/// Options of [`Validate::validate_with_options`]
+///
+/// * `minimum_severity` - Severity of the least severe failure reasons to report, which defaults
+///   to [`Severity::Error`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ValidationOptions {
+    pub minimum_severity: Severity,
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum ValidationResult {
+    #[default]
     Passed,
-    Failed { reasons: Vec<FailureReason> },
+    /// Passed, with failure reasons that are only warnings or information
+    PassedWithWarnings {
+        reasons: Vec<FailureReason>,
+    },
+    Failed {
+        reasons: Vec<FailureReason>,
+    },
 }
 
 impl ValidationResult {
+    /// The result of the failure reasons, which failed if any of them is an error
+    pub fn from_reasons(reasons: Vec<FailureReason>) -> Self {
+        if reasons.is_empty() {
+            Self::Passed
+        } else if reasons
+            .iter()
+            .any(|reason| reason.severity == Severity::Error)
+        {
+            Self::Failed { reasons }
+        } else {
+            Self::PassedWithWarnings { reasons }
+        }
+    }
+
+    /// Whether no failure reason is an error
+    pub fn passed(&self) -> bool {
+        !matches!(self, Self::Failed { .. })
+    }
+
+    pub fn reasons(&self) -> &[FailureReason] {
+        match self {
+            Self::Passed => &[],
+            Self::PassedWithWarnings { reasons } | Self::Failed { reasons } => reasons,
+        }
+    }
+
+    pub fn into_reasons(self) -> Vec<FailureReason> {
+        match self {
+            Self::Passed => Vec::new(),
+            Self::PassedWithWarnings { reasons } | Self::Failed { reasons } => reasons,
+        }
+    }
+
     pub fn merge(self, other: Self) -> Self {
         match (self, other) {
-            (Self::Passed, Self::Passed) => Self::Passed,
-            (Self::Passed, Self::Failed { reasons }) => Self::Failed { reasons },
-            (Self::Failed { reasons }, Self::Passed) => Self::Failed { reasons },
-            (
-                Self::Failed {
-                    reasons: mut left_reasons,
-                },
-                Self::Failed {
-                    reasons: mut right_reasons,
-                },
-            ) => {
-                left_reasons.append(&mut right_reasons);
-                Self::Failed {
-                    reasons: left_reasons,
-                }
+            (Self::Passed, other) => other,
+            (result, Self::Passed) => result,
+            (left, right) => {
+                let mut reasons = left.into_reasons();
+                reasons.extend(right.into_reasons());
+                Self::from_reasons(reasons)
             }
         }
     }
 }
 
-impl Default for ValidationResult {
-    fn default() -> Self {
-        Self::Passed
-    }
-}
-
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct FailureReason {
     pub message: String,
     pub context: ValidationContext,
+    pub severity: Severity,
+}
+
+impl FailureReason {
+    /// Construct a `FailureReason` of the given severity
+    pub fn new(message: impl Into<String>, context: ValidationContext, severity: Severity) -> Self {
+        Self {
+            message: message.into(),
+            context,
+            severity,
+        }
+    }
+
+    /// Construct a `FailureReason` that fails the validation
+    /// ```
+    /// use cyclonedx_bom::validation::{FailureReason, Severity, ValidationContext};
+    ///
+    /// let reason = FailureReason::error("Unknown classification", ValidationContext::default());
+    /// assert_eq!(reason.severity, Severity::Error);
+    /// ```
+    pub fn error(message: impl Into<String>, context: ValidationContext) -> Self {
+        Self::new(message, context, Severity::Error)
+    }
+
+    /// Construct a `FailureReason` for missing content that is recommended
+    pub fn warning(message: impl Into<String>, context: ValidationContext) -> Self {
+        Self::new(message, context, Severity::Warning)
+    }
+
+    /// Construct a `FailureReason` for content that could be more complete
+    pub fn info(message: impl Into<String>, context: ValidationContext) -> Self {
+        Self::new(message, context, Severity::Info)
+    }
 }
+
+/// How severe a failure reason is, where only errors fail the validation
+///
+/// * `Info` - Content that could be more complete
+/// * `Warning` - Missing content that is optional, but recommended
+/// * `Error` - Content that does not conform to the specification
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Info,
+    Warning,
+    #[default]
+    Error,
+}
+
+impl Severity {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Severity::Info => "info",
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        }
+    }
+}
+
+impl std::str::FromStr for Severity {
+    type Err = SeverityError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        [Severity::Info, Severity::Warning, Severity::Error]
+            .iter()
+            .find(|severity| severity.as_str() == s)
+            .copied()
+            .ok_or_else(|| SeverityError::UnknownSeverity(s.to_string()))
+    }
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum SeverityError {
+    #[error("Expected info, warning or error, got `{0}`")]
+    UnknownSeverity(String),
+}
+
+/// A report of a validation result that serializes to JSON, so that CI tooling can consume it
+///
+/// * `passed` - Whether no failure reason is an error
+/// * `reasons` - The failure reasons, with the location of the invalid value in JSON and XML
+///   documents
+///
+/// ```
+/// use cyclonedx_bom::prelude::*;
+/// use cyclonedx_bom::validation::ValidationReport;
+///
+/// let validation_result = Bom::default().validate().expect("Failed to validate BOM");
+/// let report = ValidationReport::from(&validation_result);
+///
+/// assert_eq!(
+///     serde_json::to_string(&report).expect("Failed to serialize report"),
+///     r#"{"passed":true,"reasons":[]}"#
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidationReport {
+    pub passed: bool,
+    pub reasons: Vec<ReportedFailure>,
+}
+
+impl From<&ValidationResult> for ValidationReport {
+    fn from(result: &ValidationResult) -> Self {
+        Self {
+            passed: result.passed(),
+            reasons: result.reasons().iter().map(ReportedFailure::from).collect(),
+        }
+    }
+}
+
+/// A failure reason of a [`ValidationReport`]
+///
+/// * `json_pointer` - See [`ValidationContext::to_json_pointer`]
+/// * `xpath` - See [`ValidationContext::to_xpath`]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportedFailure {
+    pub severity: Severity,
+    pub message: String,
+    pub json_pointer: String,
+    pub xpath: String,
+}
+
+impl From<&FailureReason> for ReportedFailure {
+    fn from(reason: &FailureReason) -> Self {
+        Self {
+            severity: reason.severity,
+            message: reason.message.clone(),
+            json_pointer: reason.context.to_json_pointer(),
+            xpath: reason.context.to_xpath(),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, thiserror::Error)]
 pub enum ValidationError {
     #[error("Failed to compile regular expression: {0}")]
     InvalidRegularExpressionError(#[from] regex::Error),
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn field(struct_name: &str, field_name: &str) -> ValidationPathComponent {
+        ValidationPathComponent::Struct {
+            struct_name: struct_name.to_string(),
+            field_name: field_name.to_string(),
+        }
+    }
+
+    fn variant(variant_name: &str) -> ValidationPathComponent {
+        ValidationPathComponent::EnumVariant {
+            variant_name: variant_name.to_string(),
+        }
+    }
+
+    #[test]
+    fn it_should_locate_values_in_json_and_xml_documents() {
+        let license_id = ValidationContext(vec![
+            field("Bom", "components"),
+            ValidationPathComponent::Array { index: 0 },
+            field("Component", "licenses"),
+            ValidationPathComponent::Array { index: 1 },
+            variant("License"),
+            field("License", "license_identifier"),
+            variant("SpdxId"),
+        ]);
+        assert_eq!(
+            license_id.to_json_pointer(),
+            "/components/0/licenses/1/license/id"
+        );
+        assert_eq!(
+            license_id.to_xpath(),
+            "/bom/components/component[1]/licenses/*[2]/id"
+        );
+
+        let dependency = ValidationContext(vec![
+            field("Bom", "dependencies"),
+            ValidationPathComponent::Array { index: 2 },
+            field("Dependency", "dependencies"),
+            ValidationPathComponent::Array { index: 0 },
+        ]);
+        assert_eq!(dependency.to_json_pointer(), "/dependencies/2/dependsOn/0");
+        assert_eq!(
+            dependency.to_xpath(),
+            "/bom/dependencies/dependency[3]/dependency[1]"
+        );
+
+        let reference_type = ValidationContext(vec![
+            field("Component", "external_references"),
+            ValidationPathComponent::Array { index: 0 },
+            field("ExternalReference", "external_reference_type"),
+        ]);
+        assert_eq!(
+            reference_type.to_json_pointer(),
+            "/externalReferences/0/type"
+        );
+        assert_eq!(
+            reference_type.to_xpath(),
+            "externalReferences/reference[1]/@type"
+        );
+
+        assert_eq!(ValidationContext::default().to_json_pointer(), "");
+        assert_eq!(ValidationContext::default().to_xpath(), "");
+    }
+
+    #[test]
+    fn it_should_serialize_a_report_of_the_failure_reasons() {
+        let result = ValidationResult::from_reasons(vec![FailureReason::warning(
+            "Missing timestamp",
+            ValidationContext(vec![
+                field("Bom", "metadata"),
+                field("Metadata", "timestamp"),
+            ]),
+        )]);
+
+        assert_eq!(
+            serde_json::to_value(ValidationReport::from(&result)).expect("Failed to serialize"),
+            serde_json::json!({
+                "passed": true,
+                "reasons": [{
+                    "severity": "warning",
+                    "message": "Missing timestamp",
+                    "jsonPointer": "/metadata/timestamp",
+                    "xpath": "/bom/metadata/timestamp",
+                }],
+            })
+        );
+    }
+
+    #[test]
+    fn it_should_parse_severities() {
+        assert_eq!("warning".parse(), Ok(Severity::Warning));
+        assert_eq!(Severity::Info.to_string(), "info");
+        assert_eq!(
+            "fatal".parse::<Severity>(),
+            Err(SeverityError::UnknownSeverity("fatal".to_string()))
+        );
+    }
+}
@@ -0,0 +1,991 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use xml::{reader, EmitterConfig, EventReader, EventWriter, ParserConfig};
+
+use crate::format::DocumentFormat;
+use crate::models::bom::{Bom, SpecVersion};
+use crate::models::component::{Classification, Component};
+use crate::models::organization::OrganizationalEntity;
+use crate::models::service::Service;
+use crate::models::tool::Tools;
+use crate::validation::{Validate, ValidationContext, ValidationPathComponent, ValidationResult};
+
+const XML_SCHEMA_INSTANCE_NAMESPACE: &str = "http://www.w3.org/2001/XMLSchema-instance";
+
+/// A non-fatal issue found while parsing a BOM, which was read in spite of it
+///
+/// Content is located by a JSON pointer, e.g. `/components/0/name`, in JSON documents and by
+/// the path of the element or attribute, e.g. `/bom/components/component/@type`, in XML
+/// documents. Unknown values are located by the path of the field in the model, e.g.
+/// `components[0].component_type`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseWarning {
+    /// A value the specification does not define, e.g. an unknown component type, which is kept
+    /// as written
+    UnknownValue { path: String, message: String },
+
+    /// Content that is not part of the parsed BOM, e.g. an extension from another schema
+    DroppedContent { path: String },
+
+    /// A value that the parsed BOM holds differently than it was written
+    ChangedValue {
+        path: String,
+        from: String,
+        to: String,
+    },
+
+    /// A document in a newer version of the specification than is supported, which was read as
+    /// the newest supported version
+    UnsupportedVersion {
+        found: String,
+        parsed_as: SpecVersion,
+    },
+
+    /// Content of a document in a newer version of the specification that the parsed BOM does
+    /// not hold, kept as written
+    Extension { path: String, content: String },
+}
+
+impl fmt::Display for ParseWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseWarning::UnknownValue { path, message } => write!(f, "{} at {}", message, path),
+            ParseWarning::DroppedContent { path } => {
+                write!(f, "Dropped {}, which is not part of the parsed BOM", path)
+            }
+            ParseWarning::ChangedValue { path, from, to } => {
+                write!(f, "Changed {} from `{}` to `{}`", path, from, to)
+            }
+            ParseWarning::UnsupportedVersion { found, parsed_as } => write!(
+                f,
+                "Read the document in the unsupported specification version {} as version {}",
+                found,
+                parsed_as.as_str()
+            ),
+            ParseWarning::Extension { path, content } => {
+                write!(f, "Kept {} as an extension: {}", path, content)
+            }
+        }
+    }
+}
+
+/// Reports the values of `bom` that are kept as `Unknown*` variants
+pub(crate) fn unknown_values(bom: &Bom) -> Vec<ParseWarning> {
+    match bom.validate() {
+        Ok(ValidationResult::Failed { reasons }) => reasons
+            .into_iter()
+            // the validation of every `Unknown*` variant fails with a message like `Unknown scope`
+            .filter(|reason| reason.message.starts_with("Unknown "))
+            .map(|reason| ParseWarning::UnknownValue {
+                path: context_path(&reason.context),
+                message: reason.message,
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn context_path(context: &ValidationContext) -> String {
+    let mut path = String::new();
+    for component in &context.0 {
+        match component {
+            ValidationPathComponent::Struct { field_name, .. } => {
+                if !path.is_empty() {
+                    path.push('.');
+                }
+                path.push_str(field_name);
+            }
+            ValidationPathComponent::Array { index } => path.push_str(&format!("[{}]", index)),
+            ValidationPathComponent::EnumVariant { .. } => (),
+        }
+    }
+    path
+}
+
+/// Compares the `input` JSON document with the document the parsed BOM is written as
+///
+/// Fields that only the `parsed` document has, e.g. `null` for missing optional fields, are not
+/// reported.
+pub(crate) fn json_warnings(
+    input: &serde_json::Value,
+    parsed: &serde_json::Value,
+) -> Vec<ParseWarning> {
+    let mut warnings = Vec::new();
+    compare_json(input, parsed, "", &mut warnings);
+    warnings
+}
+
+fn compare_json(
+    input: &serde_json::Value,
+    parsed: &serde_json::Value,
+    path: &str,
+    warnings: &mut Vec<ParseWarning>,
+) {
+    use serde_json::Value;
+
+    match (input, parsed) {
+        (Value::Object(input), Value::Object(parsed)) => {
+            for (key, value) in input {
+                // the schema reference is part of the document rather than of the BOM
+                if path.is_empty() && key == "$schema" {
+                    continue;
+                }
+
+                let child = format!("{}/{}", path, key.replace('~', "~0").replace('/', "~1"));
+                match parsed.get(key) {
+                    Some(parsed) => compare_json(value, parsed, &child, warnings),
+                    None => warnings.push(ParseWarning::DroppedContent { path: child }),
+                }
+            }
+        }
+        (Value::Array(input), Value::Array(parsed)) => {
+            for (index, value) in input.iter().enumerate() {
+                let child = format!("{}/{}", path, index);
+                match parsed.get(index) {
+                    Some(parsed) => compare_json(value, parsed, &child, warnings),
+                    None => warnings.push(ParseWarning::DroppedContent { path: child }),
+                }
+            }
+        }
+        (input, parsed) if input != parsed => warnings.push(ParseWarning::ChangedValue {
+            path: path.to_string(),
+            from: input.to_string(),
+            to: parsed.to_string(),
+        }),
+        _ => (),
+    }
+}
+
+/// Turns the warnings of a document in the newer specification version `found` into those of a
+/// document read as version `parsed_as`
+///
+/// The dropped content is kept as an extension, with the `content` of its path as written.
+pub(crate) fn newer_version_warnings(
+    warnings: Vec<ParseWarning>,
+    found: &str,
+    parsed_as: SpecVersion,
+    content: impl Fn(&str) -> String,
+) -> Vec<ParseWarning> {
+    let unsupported_version = ParseWarning::UnsupportedVersion {
+        found: found.to_string(),
+        parsed_as,
+    };
+    std::iter::once(unsupported_version)
+        .chain(warnings.into_iter().map(|warning| match warning {
+            ParseWarning::DroppedContent { path } => ParseWarning::Extension {
+                content: content(&path),
+                path,
+            },
+            warning => warning,
+        }))
+        .collect()
+}
+
+/// The elements at `path` in the XML `document` as written, or the values of the attributes if
+/// `path` ends with an attribute, e.g. `/bom/components/component/@type`
+pub(crate) fn xml_fragment(document: &[u8], path: &str) -> String {
+    let (element_path, attribute) = match path.rsplit_once("/@") {
+        Some((element_path, attribute)) => (element_path, Some(attribute)),
+        None => (path, None),
+    };
+
+    let config = ParserConfig::default().trim_whitespace(true);
+    let event_reader = EventReader::new_with_config(document, config);
+    let emitter_config = EmitterConfig::default().write_document_declaration(false);
+
+    let mut fragments = Vec::new();
+    let mut current = Vec::new();
+    let mut writer: Option<EventWriter<Vec<u8>>> = None;
+    let mut depth = 0;
+    // the document was already read successfully, so an error cannot occur here
+    for event in event_reader.into_iter().map_while(Result::ok) {
+        if let reader::XmlEvent::StartElement {
+            name, attributes, ..
+        } = &event
+        {
+            current.push(name.local_name.clone());
+            let is_match = writer.is_none() && format!("/{}", current.join("/")) == element_path;
+            match attribute {
+                Some(attribute) if is_match => fragments.extend(
+                    attributes
+                        .iter()
+                        .filter(|value| value.name.local_name == attribute)
+                        .map(|value| value.value.clone()),
+                ),
+                None if is_match => {
+                    writer = Some(emitter_config.clone().create_writer(Vec::new()));
+                }
+                _ => (),
+            }
+        }
+
+        if let Some(fragment_writer) = writer.as_mut() {
+            if let Some(writer_event) = event.as_writer_event() {
+                // the read events are well-formed, so they can be written again
+                let _ = fragment_writer.write(writer_event);
+            }
+            match event {
+                reader::XmlEvent::StartElement { .. } => depth += 1,
+                reader::XmlEvent::EndElement { .. } => depth -= 1,
+                _ => (),
+            }
+            if depth == 0 {
+                if let Some(fragment_writer) = writer.take() {
+                    fragments.push(String::from_utf8_lossy(&fragment_writer.into_inner()).into());
+                }
+            }
+        }
+
+        if let reader::XmlEvent::EndElement { .. } = event {
+            current.pop();
+        }
+    }
+    fragments.join("")
+}
+
+/// Compares the elements, attributes and texts of the `input` XML document with those of the
+/// document the parsed BOM is written as
+///
+/// Elements are compared by their path, so the order of repeated elements does not matter as
+/// long as each path holds the same texts. Only the outermost dropped element is reported.
+pub(crate) fn xml_warnings(input: &[u8], parsed: &[u8]) -> Vec<ParseWarning> {
+    let input = xml_contents(input);
+    let parsed = xml_contents(parsed);
+
+    let mut warnings = Vec::new();
+    let mut dropped: Vec<String> = Vec::new();
+    for (path, input_texts) in input {
+        match parsed.get(&path) {
+            None if dropped
+                .iter()
+                .any(|parent| path.starts_with(&format!("{}/", parent))) => {}
+            None => {
+                dropped.push(path.clone());
+                warnings.push(ParseWarning::DroppedContent { path });
+            }
+            Some(parsed_texts) if parsed_texts.len() == input_texts.len() => {
+                for (from, to) in input_texts.into_iter().zip(parsed_texts) {
+                    if &from != to {
+                        warnings.push(ParseWarning::ChangedValue {
+                            path: path.clone(),
+                            from,
+                            to: to.clone(),
+                        });
+                    }
+                }
+            }
+            Some(_) => (),
+        }
+    }
+    warnings
+}
+
+/// The texts of every element and attribute in `document`, by path
+fn xml_contents(document: &[u8]) -> BTreeMap<String, Vec<String>> {
+    let config = ParserConfig::default().trim_whitespace(true);
+    let event_reader = EventReader::new_with_config(document, config);
+
+    let mut contents: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    let mut path = Vec::new();
+    // the document was already read successfully, so an error cannot occur here
+    for event in event_reader.into_iter().map_while(Result::ok) {
+        match event {
+            reader::XmlEvent::StartElement {
+                name, attributes, ..
+            } => {
+                path.push(name.local_name);
+                let element = format!("/{}", path.join("/"));
+                contents.entry(element.clone()).or_default();
+                for attribute in attributes {
+                    if attribute.name.namespace.as_deref() == Some(XML_SCHEMA_INSTANCE_NAMESPACE) {
+                        continue;
+                    }
+                    contents
+                        .entry(format!("{}/@{}", element, attribute.name.local_name))
+                        .or_default()
+                        .push(attribute.value);
+                }
+            }
+            reader::XmlEvent::Characters(text) | reader::XmlEvent::CData(text) => {
+                contents
+                    .entry(format!("/{}", path.join("/")))
+                    .or_default()
+                    .push(text);
+            }
+            reader::XmlEvent::EndElement { .. } => {
+                path.pop();
+            }
+            _ => (),
+        }
+    }
+    contents
+}
+
+/// Content of a BOM that a version of the specification cannot represent, which is dropped
+/// when the BOM is written in that version
+///
+/// Content is located by the path of the field in the model, e.g.
+/// `metadata.tools.components[0].purl`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DowngradeWarning {
+    /// A field that is set, but not defined by the version of the specification
+    UnsupportedField {
+        path: String,
+        spec_version: SpecVersion,
+    },
+    /// A field that the version of the specification defines, but not in documents of the
+    /// format, e.g. the signatures of XML documents
+    UnsupportedInFormat {
+        path: String,
+        spec_version: SpecVersion,
+        format: DocumentFormat,
+    },
+}
+
+impl fmt::Display for DowngradeWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DowngradeWarning::UnsupportedField { path, spec_version } => write!(
+                f,
+                "Dropped {}, which version {} of the specification does not support",
+                path, spec_version
+            ),
+            DowngradeWarning::UnsupportedInFormat {
+                path,
+                spec_version,
+                format,
+            } => write!(
+                f,
+                "Dropped {}, which version {} of the specification does not support in {} documents",
+                path, spec_version, format
+            ),
+        }
+    }
+}
+
+/// Reports the content of `bom` that is dropped when it is written in `spec_version`
+pub(crate) fn downgrade_warnings(bom: &Bom, spec_version: SpecVersion) -> Vec<DowngradeWarning> {
+    let mut fields = tool_fields(bom, spec_version);
+    match spec_version {
+        SpecVersion::V1_3 => {
+            fields.extend(v1_4_fields(bom));
+            fields.extend(v1_5_fields(bom));
+        }
+        SpecVersion::V1_4 => fields.extend(v1_5_fields(bom)),
+        SpecVersion::V1_5 => {}
+    }
+
+    fields
+        .into_iter()
+        .map(|path| DowngradeWarning::UnsupportedField { path, spec_version })
+        .collect()
+}
+
+/// Reports the content of `bom` that is dropped when it is written as a document of `format` in
+/// `spec_version`
+pub(crate) fn format_downgrade_warnings(
+    bom: &Bom,
+    spec_version: SpecVersion,
+    format: DocumentFormat,
+) -> Vec<DowngradeWarning> {
+    let mut warnings = downgrade_warnings(bom, spec_version);
+    // version 1.3 has no signatures at all, which the warnings above already report
+    if format == DocumentFormat::Xml && spec_version != SpecVersion::V1_3 {
+        // XML documents are signed with XML Signature instead
+        warnings.extend(
+            v1_4_fields(bom)
+                .into_iter()
+                .filter(|path| path == "signature" || path.ends_with(".signature"))
+                .map(|path| DowngradeWarning::UnsupportedInFormat {
+                    path,
+                    spec_version,
+                    format,
+                }),
+        );
+    }
+    warnings
+}
+
+/// The fields of the tools that a legacy tool has no place for, as the versions before 1.5 write
+/// tools as the legacy list, see `Tools::into_list`
+fn tool_fields(bom: &Bom, spec_version: SpecVersion) -> Vec<String> {
+    if spec_version == SpecVersion::V1_5 {
+        return Vec::new();
+    }
+
+    // the external references of tools were added in version 1.4
+    let has_external_references = spec_version != SpecVersion::V1_3;
+
+    let mut fields = Vec::new();
+    match bom
+        .metadata
+        .as_ref()
+        .and_then(|metadata| metadata.tools.as_ref())
+    {
+        Some(Tools::List(tools)) => {
+            for (index, tool) in tools.iter().enumerate() {
+                if tool.external_references.is_some() && !has_external_references {
+                    fields.push(format!("metadata.tools[{}].external_references", index));
+                }
+            }
+        }
+        Some(Tools::Object {
+            components,
+            services,
+        }) => {
+            let components = components.iter().flat_map(|components| &components.0);
+            for (index, component) in components.enumerate() {
+                let path = format!("metadata.tools.components[{}]", index);
+                fields.extend(tool_component_fields(
+                    component,
+                    &path,
+                    has_external_references,
+                ));
+            }
+            let services = services.iter().flat_map(|services| &services.0);
+            for (index, service) in services.enumerate() {
+                let path = format!("metadata.tools.services[{}]", index);
+                fields.extend(tool_service_fields(service, &path, has_external_references));
+            }
+        }
+        None => {}
+    }
+    fields
+}
+
+/// The fields that were added in version 1.4: the vulnerabilities, the signatures and the
+/// release notes of components and services
+fn v1_4_fields(bom: &Bom) -> Vec<String> {
+    let mut fields: Vec<String> = [
+        ("vulnerabilities", bom.vulnerabilities.is_some()),
+        ("signature", bom.signature.is_some()),
+    ]
+    .iter()
+    .filter(|(_, set)| *set)
+    .map(|(field, _)| field.to_string())
+    .collect();
+
+    if let Some(component) = bom
+        .metadata
+        .as_ref()
+        .and_then(|metadata| metadata.component.as_ref())
+    {
+        component_v1_4_fields(component, "metadata.component", &mut fields);
+    }
+    for (index, component) in bom.components.iter().flat_map(|c| &c.0).enumerate() {
+        component_v1_4_fields(component, &format!("components[{}]", index), &mut fields);
+    }
+    for (index, service) in bom.services.iter().flat_map(|s| &s.0).enumerate() {
+        service_v1_4_fields(service, &format!("services[{}]", index), &mut fields);
+    }
+    fields
+}
+
+fn component_v1_4_fields(component: &Component, path: &str, fields: &mut Vec<String>) {
+    fields.extend(set_fields(
+        &[
+            ("release_notes", component.release_notes.is_some()),
+            ("signature", component.signature.is_some()),
+        ],
+        path,
+    ));
+    let components = component.components.iter().flat_map(|c| &c.0);
+    for (index, component) in components.enumerate() {
+        let path = format!("{}.components[{}]", path, index);
+        component_v1_4_fields(component, &path, fields);
+    }
+}
+
+fn service_v1_4_fields(service: &Service, path: &str, fields: &mut Vec<String>) {
+    fields.extend(set_fields(
+        &[
+            ("release_notes", service.release_notes.is_some()),
+            ("signature", service.signature.is_some()),
+        ],
+        path,
+    ));
+    let services = service.services.iter().flat_map(|s| &s.0);
+    for (index, service) in services.enumerate() {
+        let path = format!("{}.services[{}]", path, index);
+        service_v1_4_fields(service, &path, fields);
+    }
+}
+
+/// The fields that were added in version 1.5: the annotations, the formulation, the lifecycles
+/// of the metadata, the model cards, data and new types of components, and the trust zones of
+/// services
+fn v1_5_fields(bom: &Bom) -> Vec<String> {
+    let mut fields: Vec<String> = [
+        (
+            "metadata.lifecycles",
+            bom.metadata
+                .as_ref()
+                .map_or(false, |metadata| metadata.lifecycles.is_some()),
+        ),
+        ("annotations", bom.annotations.is_some()),
+        ("formulation", bom.formulation.is_some()),
+    ]
+    .iter()
+    .filter(|(_, set)| *set)
+    .map(|(field, _)| field.to_string())
+    .collect();
+
+    if let Some(component) = bom
+        .metadata
+        .as_ref()
+        .and_then(|metadata| metadata.component.as_ref())
+    {
+        component_v1_5_fields(component, "metadata.component", &mut fields);
+    }
+    for (index, component) in bom.components.iter().flat_map(|c| &c.0).enumerate() {
+        component_v1_5_fields(component, &format!("components[{}]", index), &mut fields);
+    }
+    for (index, service) in bom.services.iter().flat_map(|s| &s.0).enumerate() {
+        service_v1_5_fields(service, &format!("services[{}]", index), &mut fields);
+    }
+    fields
+}
+
+fn component_v1_5_fields(component: &Component, path: &str, fields: &mut Vec<String>) {
+    let new_type = matches!(
+        component.component_type,
+        Classification::Platform
+            | Classification::DeviceDriver
+            | Classification::MachineLearningModel
+            | Classification::Data
+    );
+    fields.extend(set_fields(
+        &[
+            ("component_type", new_type),
+            ("model_card", component.model_card.is_some()),
+            ("data", component.data.is_some()),
+        ],
+        path,
+    ));
+    let components = component.components.iter().flat_map(|c| &c.0);
+    for (index, component) in components.enumerate() {
+        let path = format!("{}.components[{}]", path, index);
+        component_v1_5_fields(component, &path, fields);
+    }
+}
+
+fn service_v1_5_fields(service: &Service, path: &str, fields: &mut Vec<String>) {
+    fields.extend(set_fields(
+        &[("trust_zone", service.trust_zone.is_some())],
+        path,
+    ));
+    let services = service.services.iter().flat_map(|s| &s.0);
+    for (index, service) in services.enumerate() {
+        let path = format!("{}.services[{}]", path, index);
+        service_v1_5_fields(service, &path, fields);
+    }
+}
+
+/// The fields of a tool component that a legacy tool has no place for
+fn tool_component_fields(
+    component: &Component,
+    path: &str,
+    has_external_references: bool,
+) -> Vec<String> {
+    let fields = [
+        (
+            "component_type",
+            component.component_type != Classification::Application,
+        ),
+        ("mime_type", component.mime_type.is_some()),
+        ("bom_ref", component.bom_ref.is_some()),
+        ("author", component.author.is_some()),
+        ("publisher", component.publisher.is_some()),
+        ("group", component.group.is_some()),
+        ("description", component.description.is_some()),
+        ("scope", component.scope.is_some()),
+        ("licenses", component.licenses.is_some()),
+        ("copyright", component.copyright.is_some()),
+        ("cpe", component.cpe.is_some()),
+        ("purl", component.purl.is_some()),
+        ("swid", component.swid.is_some()),
+        ("modified", component.modified.is_some()),
+        ("pedigree", component.pedigree.is_some()),
+        (
+            "external_references",
+            component.external_references.is_some() && !has_external_references,
+        ),
+        ("properties", component.properties.is_some()),
+        ("components", component.components.is_some()),
+        ("evidence", component.evidence.is_some()),
+        ("release_notes", component.release_notes.is_some()),
+        ("signature", component.signature.is_some()),
+        ("model_card", component.model_card.is_some()),
+        ("data", component.data.is_some()),
+    ];
+
+    let mut dropped = set_fields(&fields, path);
+    dropped.extend(vendor_fields(
+        component.supplier.as_ref(),
+        &format!("{}.supplier", path),
+    ));
+    dropped
+}
+
+/// The fields of a tool service that a legacy tool has no place for
+fn tool_service_fields(
+    service: &Service,
+    path: &str,
+    has_external_references: bool,
+) -> Vec<String> {
+    let fields = [
+        ("bom_ref", service.bom_ref.is_some()),
+        ("group", service.group.is_some()),
+        ("description", service.description.is_some()),
+        ("endpoints", service.endpoints.is_some()),
+        ("authenticated", service.authenticated.is_some()),
+        ("x_trust_boundary", service.x_trust_boundary.is_some()),
+        ("trust_zone", service.trust_zone.is_some()),
+        ("data", service.data.is_some()),
+        ("licenses", service.licenses.is_some()),
+        (
+            "external_references",
+            service.external_references.is_some() && !has_external_references,
+        ),
+        ("properties", service.properties.is_some()),
+        ("services", service.services.is_some()),
+        ("release_notes", service.release_notes.is_some()),
+        ("signature", service.signature.is_some()),
+    ];
+
+    let mut dropped = set_fields(&fields, path);
+    dropped.extend(vendor_fields(
+        service.provider.as_ref(),
+        &format!("{}.provider", path),
+    ));
+    dropped
+}
+
+/// Only the name of an organization is kept, as the vendor of the tool
+fn vendor_fields(organization: Option<&OrganizationalEntity>, path: &str) -> Vec<String> {
+    match organization {
+        Some(organization) => set_fields(
+            &[
+                ("url", organization.url.is_some()),
+                ("contact", organization.contact.is_some()),
+            ],
+            path,
+        ),
+        None => Vec::new(),
+    }
+}
+
+fn set_fields(fields: &[(&str, bool)], path: &str) -> Vec<String> {
+    fields
+        .iter()
+        .filter(|(_, set)| *set)
+        .map(|(field, _)| format!("{}.{}", path, field))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_report_dropped_and_changed_json_content() {
+        let input = json!({
+            "$schema": "http://cyclonedx.org/schema/bom-1.3.schema.json",
+            "bomFormat": "CycloneDX",
+            "components": [{ "name": "tacos", "x-vendor/note": "spicy", "version": 1 }]
+        });
+        let parsed = json!({
+            "bomFormat": "CycloneDX",
+            "version": null,
+            "components": [{ "name": "tacos", "version": "1" }]
+        });
+
+        assert_eq!(
+            json_warnings(&input, &parsed),
+            vec![
+                ParseWarning::ChangedValue {
+                    path: "/components/0/version".to_string(),
+                    from: "1".to_string(),
+                    to: r#""1""#.to_string()
+                },
+                ParseWarning::DroppedContent {
+                    path: "/components/0/x-vendor~1note".to_string()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_report_dropped_xml_extensions() {
+        let input = br#"<?xml version="1.0" encoding="UTF-8"?>
+<bom xmlns="http://cyclonedx.org/schema/bom/1.3" xmlns:ext="https://example.com/ext" version="1">
+  <components>
+    <component type="library"><name>tacos</name><ext:spiciness><ext:scoville>5000</ext:scoville></ext:spiciness></component>
+  </components>
+</bom>"#;
+        let parsed = br#"<?xml version="1.0" encoding="UTF-8"?>
+<bom xmlns="http://cyclonedx.org/schema/bom/1.3" version="1">
+  <components>
+    <component type="library"><name>tacos</name></component>
+  </components>
+</bom>"#;
+
+        assert_eq!(
+            xml_warnings(input, parsed),
+            vec![ParseWarning::DroppedContent {
+                path: "/bom/components/component/spiciness".to_string()
+            }]
+        );
+    }
+
+    #[test]
+    fn it_should_keep_the_fragments_of_xml_paths() {
+        let document = br#"<?xml version="1.0" encoding="UTF-8"?>
+<bom xmlns="http://cyclonedx.org/schema/bom/1.6" version="1">
+  <components>
+    <component type="library" isExternal="true"><name>tacos</name></component>
+    <component type="library" isExternal="false"><name>burritos</name></component>
+  </components>
+</bom>"#;
+
+        assert_eq!(
+            xml_fragment(document, "/bom/components/component/@isExternal"),
+            "truefalse"
+        );
+        assert_eq!(
+            xml_fragment(document, "/bom/components/component/name"),
+            r#"<name xmlns="http://cyclonedx.org/schema/bom/1.6">tacos</name><name xmlns="http://cyclonedx.org/schema/bom/1.6">burritos</name>"#
+        );
+        assert_eq!(xml_fragment(document, "/bom/metadata"), "");
+    }
+
+    #[test]
+    fn it_should_report_the_tool_fields_dropped_in_the_legacy_list() {
+        use crate::models::component::Components;
+        use crate::models::metadata::Metadata;
+        use crate::models::tool::Tool;
+
+        let tool = Component {
+            bom_ref: Some("cargo-cyclonedx".to_string()),
+            supplier: Some(OrganizationalEntity {
+                name: Some(crate::prelude::NormalizedString::new("CycloneDX")),
+                url: Some(vec![crate::prelude::Uri(
+                    "https://cyclonedx.org".to_string(),
+                )]),
+                contact: None,
+            }),
+            ..Component::new(
+                Classification::Application,
+                "cargo-cyclonedx",
+                "1.0.0",
+                None,
+            )
+        };
+        let bom = Bom {
+            metadata: Some(Metadata {
+                tools: Some(Tools::Object {
+                    components: Some(Components(vec![tool])),
+                    services: None,
+                }),
+                ..Metadata::default()
+            }),
+            ..Bom::default()
+        };
+
+        assert_eq!(
+            downgrade_warnings(&bom, SpecVersion::V1_3),
+            vec![
+                DowngradeWarning::UnsupportedField {
+                    path: "metadata.tools.components[0].bom_ref".to_string(),
+                    spec_version: SpecVersion::V1_3,
+                },
+                DowngradeWarning::UnsupportedField {
+                    path: "metadata.tools.components[0].supplier.url".to_string(),
+                    spec_version: SpecVersion::V1_3,
+                },
+            ]
+        );
+
+        let bom = Bom {
+            metadata: Some(Metadata {
+                tools: Some(Tools::List(vec![Tool::new(
+                    "CycloneDX",
+                    "cargo-cyclonedx",
+                    "1.0.0",
+                )])),
+                ..Metadata::default()
+            }),
+            ..Bom::default()
+        };
+        assert_eq!(downgrade_warnings(&bom, SpecVersion::V1_3), Vec::new());
+
+        let tool = Tool::new("CycloneDX", "cargo-cyclonedx", "1.0.0").with_external_references(
+            crate::models::external_reference::ExternalReferences(vec![
+                crate::models::external_reference::ExternalReference::new(
+                    crate::models::external_reference::ExternalReferenceType::Vcs,
+                    crate::prelude::Uri(
+                        "https://github.com/CycloneDX/cyclonedx-rust-cargo".to_string(),
+                    ),
+                ),
+            ]),
+        );
+        let bom = Bom {
+            metadata: Some(Metadata {
+                tools: Some(Tools::List(vec![tool])),
+                ..Metadata::default()
+            }),
+            ..Bom::default()
+        };
+        assert_eq!(
+            downgrade_warnings(&bom, SpecVersion::V1_3),
+            vec![DowngradeWarning::UnsupportedField {
+                path: "metadata.tools[0].external_references".to_string(),
+                spec_version: SpecVersion::V1_3,
+            }]
+        );
+    }
+
+    #[test]
+    fn it_should_report_the_fields_added_in_version_1_4() {
+        use crate::models::component::Components;
+        use crate::models::release_notes::ReleaseNotes;
+        use crate::models::signature::{Algorithm, Signature};
+        use crate::models::tool::Tool;
+        use crate::models::vulnerability::{Vulnerabilities, Vulnerability};
+
+        let component = Component::new(Classification::Library, "tacos", "1.0.0", None)
+            .with_components(Components(vec![Component::new(
+                Classification::Library,
+                "salsa",
+                "1.0.0",
+                None,
+            )
+            .with_release_notes(ReleaseNotes::new("patch"))]));
+        let tool = Tool::new("CycloneDX", "cargo-cyclonedx", "1.0.0").with_external_references(
+            crate::models::external_reference::ExternalReferences(vec![]),
+        );
+        let bom = Bom {
+            metadata: Some(crate::models::metadata::Metadata {
+                tools: Some(Tools::List(vec![tool])),
+                ..crate::models::metadata::Metadata::default()
+            }),
+            components: Some(Components(vec![component])),
+            vulnerabilities: Some(Vulnerabilities(vec![Vulnerability::new(Some(
+                "RUSTSEC-2021-0078",
+            ))])),
+            signature: Some(Signature::new(Algorithm::ES256, "c2lnbmF0dXJl")),
+            ..Bom::default()
+        };
+
+        let unsupported = |path: &str| DowngradeWarning::UnsupportedField {
+            path: path.to_string(),
+            spec_version: SpecVersion::V1_3,
+        };
+        assert_eq!(
+            downgrade_warnings(&bom, SpecVersion::V1_3),
+            vec![
+                unsupported("metadata.tools[0].external_references"),
+                unsupported("vulnerabilities"),
+                unsupported("signature"),
+                unsupported("components[0].components[0].release_notes"),
+            ]
+        );
+        assert_eq!(downgrade_warnings(&bom, SpecVersion::V1_4), Vec::new());
+        assert_eq!(
+            format_downgrade_warnings(&bom, SpecVersion::V1_4, DocumentFormat::Xml),
+            vec![DowngradeWarning::UnsupportedInFormat {
+                path: "signature".to_string(),
+                spec_version: SpecVersion::V1_4,
+                format: DocumentFormat::Xml,
+            }]
+        );
+        assert_eq!(
+            format_downgrade_warnings(&bom, SpecVersion::V1_3, DocumentFormat::Xml),
+            downgrade_warnings(&bom, SpecVersion::V1_3)
+        );
+    }
+
+    #[test]
+    fn it_should_report_the_fields_added_in_version_1_5() {
+        use crate::external_models::date_time::DateTime;
+        use crate::external_models::normalized_string::NormalizedString;
+        use crate::models::annotation::{Annotation, Annotations, Annotator};
+        use crate::models::component::Components;
+        use crate::models::lifecycle::{Lifecycle, Lifecycles, Phase};
+        use crate::models::metadata::Metadata;
+        use crate::models::model_card::ModelCard;
+        use crate::models::organization::OrganizationalContact;
+        use crate::models::service::Services;
+
+        let tool = Component::new(
+            Classification::Application,
+            "cargo-cyclonedx",
+            "1.0.0",
+            None,
+        );
+        let model = Component::new(
+            Classification::MachineLearningModel,
+            "tacos-classifier",
+            "1.0.0",
+            None,
+        )
+        .with_model_card(ModelCard::new());
+        let bom = Bom {
+            metadata: Some(Metadata {
+                lifecycles: Some(Lifecycles(vec![Lifecycle::Phase(Phase::Build)])),
+                tools: Some(Tools::Object {
+                    components: Some(Components(vec![tool])),
+                    services: None,
+                }),
+                ..Metadata::default()
+            }),
+            components: Some(Components(vec![model])),
+            services: Some(Services(vec![Service::new("tacos-api", None)
+                .with_services(Services(vec![Service::new("tacos-db", None)
+                    .with_trust_zone(NormalizedString::new("backend"))]))])),
+            annotations: Some(Annotations(vec![Annotation::new(
+                vec!["tacos-classifier".to_string()],
+                Annotator::Individual(OrganizationalContact::new("Jane Doe", None)),
+                DateTime::now().expect("Failed to get the current time"),
+                "Trained on tacos",
+            )])),
+            ..Bom::default()
+        };
+
+        let unsupported = |path: &str| DowngradeWarning::UnsupportedField {
+            path: path.to_string(),
+            spec_version: SpecVersion::V1_4,
+        };
+        assert_eq!(
+            downgrade_warnings(&bom, SpecVersion::V1_4),
+            vec![
+                unsupported("metadata.lifecycles"),
+                unsupported("annotations"),
+                unsupported("components[0].component_type"),
+                unsupported("components[0].model_card"),
+                unsupported("services[0].services[0].trust_zone"),
+            ]
+        );
+        assert_eq!(downgrade_warnings(&bom, SpecVersion::V1_5), Vec::new());
+    }
+}
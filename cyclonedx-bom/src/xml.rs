@@ -1,4 +1,5 @@
 use crate::errors::{XmlReadError, XmlWriteError};
+use crate::external_models::date_time::DateTime;
 use std::io::{Read, Write};
 use xml::{
     attribute::OwnedAttribute,
@@ -9,13 +10,6 @@ use xml::{
     EventReader,
 };
 
-pub(crate) trait ToXmlDocument {
-    fn write_xml_document<W: Write>(
-        &self,
-        writer: &mut EventWriter<W>,
-    ) -> Result<(), XmlWriteError>;
-}
-
 pub(crate) trait ToXml {
     fn write_xml_element<W: Write>(&self, writer: &mut EventWriter<W>)
         -> Result<(), XmlWriteError>;
@@ -64,6 +58,47 @@ pub(crate) fn to_xml_write_error(
     |error| XmlWriteError::XmlElementWriteError { error, element }
 }
 
+/// Write a tag that is of the form `<tag><item/>...</tag>`
+pub(crate) fn write_list_tag<W: Write, X: ToXml>(
+    writer: &mut EventWriter<W>,
+    tag: &str,
+    items: &[X],
+) -> Result<(), XmlWriteError> {
+    writer
+        .write(writer::XmlEvent::start_element(tag))
+        .map_err(to_xml_write_error(tag))?;
+
+    for item in items {
+        item.write_xml_element(writer)?;
+    }
+
+    writer
+        .write(writer::XmlEvent::end_element())
+        .map_err(to_xml_write_error(tag))?;
+    Ok(())
+}
+
+/// Write a tag that is of the form `<tag><item>content</item>...</tag>`
+pub(crate) fn write_simple_list_tag<W: Write>(
+    writer: &mut EventWriter<W>,
+    tag: &str,
+    item_tag: &str,
+    items: &[String],
+) -> Result<(), XmlWriteError> {
+    writer
+        .write(writer::XmlEvent::start_element(tag))
+        .map_err(to_xml_write_error(tag))?;
+
+    for item in items {
+        write_simple_tag(writer, item_tag, item)?;
+    }
+
+    writer
+        .write(writer::XmlEvent::end_element())
+        .map_err(to_xml_write_error(tag))?;
+    Ok(())
+}
+
 pub(crate) trait FromXmlDocument {
     fn read_xml_document<R: Read>(event_reader: &mut EventReader<R>) -> Result<Self, XmlReadError>
     where
@@ -208,6 +243,44 @@ impl FromXmlType for u32 {
     }
 }
 
+impl FromXmlType for f64 {
+    fn xml_type_display() -> String {
+        "xs:decimal".to_string()
+    }
+
+    fn from_xml_value(
+        element: impl ToString,
+        value: impl AsRef<str>,
+    ) -> Result<Self, XmlReadError> {
+        let value = value.as_ref();
+        let value: f64 = value.parse().map_err(|_| XmlReadError::InvalidParseError {
+            value: value.to_string(),
+            data_type: Self::xml_type_display(),
+            element: element.to_string(),
+        })?;
+
+        Ok(value)
+    }
+}
+
+impl FromXmlType for DateTime {
+    fn xml_type_display() -> String {
+        "xs:dateTime".to_string()
+    }
+
+    fn from_xml_value(
+        element: impl ToString,
+        value: impl AsRef<str>,
+    ) -> Result<Self, XmlReadError> {
+        let value = value.as_ref();
+        value.parse().map_err(|_| XmlReadError::InvalidParseError {
+            value: value.to_string(),
+            data_type: Self::xml_type_display(),
+            element: element.to_string(),
+        })
+    }
+}
+
 pub(crate) fn read_simple_tag<R: Read>(
     event_reader: &mut EventReader<R>,
     element: &OwnedName,
@@ -234,6 +307,14 @@ pub(crate) fn read_boolean_tag<R: Read>(
         .and_then(|modified| bool::from_xml_value(element, modified))
 }
 
+pub(crate) fn read_date_time_tag<R: Read>(
+    event_reader: &mut EventReader<R>,
+    element: &OwnedName,
+) -> Result<DateTime, XmlReadError> {
+    read_simple_tag(event_reader, element)
+        .and_then(|timestamp| DateTime::from_xml_value(element, timestamp))
+}
+
 impl FromXml for String {
     fn read_xml_element<R: Read>(
         event_reader: &mut EventReader<R>,
@@ -390,7 +471,7 @@ pub(crate) mod test {
         let end_document = event_reader.next().expect("Expected to end the document");
 
         match end_document {
-            reader::XmlEvent::EndDocument { .. } => (),
+            reader::XmlEvent::EndDocument => (),
             other => panic!("Expected to end a document, but got {:?}", other),
         }
 
@@ -400,7 +481,6 @@ pub(crate) mod test {
     pub(crate) fn read_element_from_string<X: FromXml>(string: impl AsRef<str>) -> X {
         let mut event_reader =
             EventReader::new_with_config(string.as_ref().as_bytes(), parser_config());
-        let output: X;
 
         let start_document = event_reader.next().expect("Expected to start the document");
 
@@ -412,19 +492,17 @@ pub(crate) mod test {
         let initial_event = event_reader
             .next()
             .expect("Failed to read from the XML input");
-        match initial_event {
+        let output: X = match initial_event {
             reader::XmlEvent::StartElement {
                 name, attributes, ..
-            } => {
-                output = X::read_xml_element(&mut event_reader, &name, &attributes)
-                    .expect("Failed to read the element from the string")
-            }
+            } => X::read_xml_element(&mut event_reader, &name, &attributes)
+                .expect("Failed to read the element from the string"),
             other => panic!("Expected to start an element, but got {:?}", other),
-        }
+        };
         let end_document = event_reader.next().expect("Expected to end the document");
 
         match end_document {
-            reader::XmlEvent::EndDocument { .. } => (),
+            reader::XmlEvent::EndDocument => (),
             other => panic!("Expected to end a document, but got {:?}", other),
         }
 
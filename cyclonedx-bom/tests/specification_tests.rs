@@ -4,10 +4,10 @@ use cyclonedx_bom::validation::{Validate, ValidationResult};
 #[test]
 fn it_should_parse_all_of_the_valid_xml_specifications() {
     insta::glob!("data/1.3/valid*.xml", |path| {
-        let file = std::fs::File::open(path).expect(&format!("Failed to read file: {path:?}"));
-        let bom = Bom::parse_from_xml_v1_3(file).expect(&format!(
-            "Failed to parse the document as an SBOM: {path:?}"
-        ));
+        let file =
+            std::fs::File::open(path).unwrap_or_else(|_| panic!("Failed to read file: {:?}", path));
+        let bom = Bom::parse_from_xml_v1_3(file)
+            .unwrap_or_else(|_| panic!("Failed to parse the document as an SBOM: {:?}", path));
 
         let validation_result = bom.validate().expect("Failed to validate BOM");
         assert_eq!(
@@ -18,7 +18,7 @@ fn it_should_parse_all_of_the_valid_xml_specifications() {
 
         let mut output = Vec::new();
         bom.output_as_xml_v1_3(&mut output)
-            .expect(&format!("Failed to output the file: {path:?}"));
+            .unwrap_or_else(|_| panic!("Failed to output the file: {:?}", path));
         let bom_output = String::from_utf8_lossy(&output).to_string();
 
         insta::assert_snapshot!(bom_output);
@@ -28,10 +28,10 @@ fn it_should_parse_all_of_the_valid_xml_specifications() {
 #[test]
 fn it_should_parse_all_of_the_valid_json_specifications() {
     insta::glob!("data/1.3/valid*.json", |path| {
-        let file = std::fs::File::open(path).expect(&format!("Failed to read file: {path:?}"));
-        let bom = Bom::parse_from_json_v1_3(file).expect(&format!(
-            "Failed to parse the document as an SBOM: {path:?}"
-        ));
+        let file =
+            std::fs::File::open(path).unwrap_or_else(|_| panic!("Failed to read file: {:?}", path));
+        let bom = Bom::parse_from_json_v1_3(file)
+            .unwrap_or_else(|_| panic!("Failed to parse the document as an SBOM: {:?}", path));
 
         let validation_result = bom.validate().expect("Failed to validate BOM");
         assert_eq!(
@@ -42,7 +42,7 @@ fn it_should_parse_all_of_the_valid_json_specifications() {
 
         let mut output = Vec::new();
         bom.output_as_json_v1_3(&mut output)
-            .expect(&format!("Failed to output the file: {path:?}"));
+            .unwrap_or_else(|_| panic!("Failed to output the file: {:?}", path));
         let bom_output = String::from_utf8_lossy(&output).to_string();
 
         insta::assert_snapshot!(bom_output);
@@ -52,7 +52,8 @@ fn it_should_parse_all_of_the_valid_json_specifications() {
 #[test]
 fn it_should_fail_to_parse_all_of_the_invalid_xml_specifications() {
     insta::glob!("data/1.3/invalid*.xml", |path| {
-        let file = std::fs::File::open(path).expect(&format!("Failed to read file: {path:?}"));
+        let file =
+            std::fs::File::open(path).unwrap_or_else(|_| panic!("Failed to read file: {:?}", path));
         if let Ok(bom) = Bom::parse_from_xml_v1_3(file) {
             let validation_result = bom.validate().expect("Failed to validate BOM");
             assert_ne!(
@@ -67,7 +68,8 @@ fn it_should_fail_to_parse_all_of_the_invalid_xml_specifications() {
 #[test]
 fn it_should_fail_to_parse_all_of_the_invalid_json_specifications() {
     insta::glob!("data/1.3/invalid*.json", |path| {
-        let file = std::fs::File::open(path).expect(&format!("Failed to read file: {path:?}"));
+        let file =
+            std::fs::File::open(path).unwrap_or_else(|_| panic!("Failed to read file: {:?}", path));
         if let Ok(bom) = Bom::parse_from_json_v1_3(file) {
             let validation_result = bom.validate().expect("Failed to validate BOM");
             assert_ne!(
@@ -78,3 +80,1069 @@ fn it_should_fail_to_parse_all_of_the_invalid_json_specifications() {
         }
     });
 }
+
+#[test]
+fn it_should_warn_about_the_content_of_the_valid_specifications_that_is_not_parsed() {
+    let mut paths: Vec<_> = std::fs::read_dir("tests/data/1.3")
+        .expect("Failed to list the specifications")
+        .map(|entry| entry.expect("Failed to list the specifications").path())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .map_or(false, |name| name.starts_with("valid"))
+        })
+        .collect();
+    paths.sort();
+
+    let mut warnings = String::new();
+    for path in paths {
+        let file = std::fs::File::open(&path)
+            .unwrap_or_else(|_| panic!("Failed to read file: {:?}", path));
+        let parsed = if path
+            .extension()
+            .map_or(false, |extension| extension == "xml")
+        {
+            Bom::parse_from_xml_v1_3_with_warnings(file)
+                .map(|(_, warnings)| warnings)
+                .ok()
+        } else {
+            Bom::parse_from_json_v1_3_with_warnings(file)
+                .map(|(_, warnings)| warnings)
+                .ok()
+        }
+        .unwrap_or_else(|| panic!("Failed to parse the document as an SBOM: {:?}", path));
+
+        for warning in parsed {
+            warnings.push_str(&format!(
+                "{}: {}\n",
+                path.file_name().unwrap_or_default().to_string_lossy(),
+                warning
+            ));
+        }
+    }
+
+    insta::assert_snapshot!(warnings);
+}
+
+mod v1_4 {
+    use cyclonedx_bom::models::bom::Bom;
+    use cyclonedx_bom::validation::{Validate, ValidationResult};
+
+    #[test]
+    fn it_should_parse_all_of_the_valid_xml_specifications() {
+        insta::glob!("data/1.4/valid*.xml", |path| {
+            let file = std::fs::File::open(path)
+                .unwrap_or_else(|_| panic!("Failed to read file: {:?}", path));
+            let bom = Bom::parse_from_xml_v1_4(file)
+                .unwrap_or_else(|_| panic!("Failed to parse the document as an SBOM: {:?}", path));
+
+            let validation_result = bom.validate().expect("Failed to validate BOM");
+            assert_eq!(
+                validation_result,
+                ValidationResult::Passed,
+                "{path:?} unexpectedly failed validation"
+            );
+
+            let mut output = Vec::new();
+            bom.output_as_xml_v1_4(&mut output)
+                .unwrap_or_else(|_| panic!("Failed to output the file: {:?}", path));
+            let bom_output = String::from_utf8_lossy(&output).to_string();
+
+            insta::assert_snapshot!(bom_output);
+        });
+    }
+
+    #[test]
+    fn it_should_parse_all_of_the_valid_json_specifications() {
+        insta::glob!("data/1.4/valid*.json", |path| {
+            let file = std::fs::File::open(path)
+                .unwrap_or_else(|_| panic!("Failed to read file: {:?}", path));
+            let bom = Bom::parse_from_json_v1_4(file)
+                .unwrap_or_else(|_| panic!("Failed to parse the document as an SBOM: {:?}", path));
+
+            let validation_result = bom.validate().expect("Failed to validate BOM");
+            assert_eq!(
+                validation_result,
+                ValidationResult::Passed,
+                "{path:?} unexpectedly failed validation"
+            );
+
+            let mut output = Vec::new();
+            bom.output_as_json_v1_4(&mut output)
+                .unwrap_or_else(|_| panic!("Failed to output the file: {:?}", path));
+            let bom_output = String::from_utf8_lossy(&output).to_string();
+
+            insta::assert_snapshot!(bom_output);
+        });
+    }
+
+    #[test]
+    fn it_should_fail_to_parse_all_of_the_invalid_xml_specifications() {
+        insta::glob!("data/1.4/invalid*.xml", |path| {
+            let file = std::fs::File::open(path)
+                .unwrap_or_else(|_| panic!("Failed to read file: {:?}", path));
+            if let Ok(bom) = Bom::parse_from_xml_v1_4(file) {
+                let validation_result = bom.validate().expect("Failed to validate BOM");
+                assert_ne!(
+                    validation_result,
+                    ValidationResult::Passed,
+                    "{path:?} unexpectedly passed validation"
+                );
+            }
+        });
+    }
+
+    #[test]
+    fn it_should_fail_to_parse_all_of_the_invalid_json_specifications() {
+        insta::glob!("data/1.4/invalid*.json", |path| {
+            let file = std::fs::File::open(path)
+                .unwrap_or_else(|_| panic!("Failed to read file: {:?}", path));
+            if let Ok(bom) = Bom::parse_from_json_v1_4(file) {
+                let validation_result = bom.validate().expect("Failed to validate BOM");
+                assert_ne!(
+                    validation_result,
+                    ValidationResult::Passed,
+                    "{path:?} unexpectedly passed validation"
+                );
+            }
+        });
+    }
+
+    #[test]
+    fn it_should_warn_about_the_content_of_the_valid_specifications_that_is_not_parsed() {
+        let mut paths: Vec<_> = std::fs::read_dir("tests/data/1.4")
+            .expect("Failed to list the specifications")
+            .map(|entry| entry.expect("Failed to list the specifications").path())
+            .filter(|path| {
+                path.file_name()
+                    .and_then(|name| name.to_str())
+                    .map_or(false, |name| name.starts_with("valid"))
+            })
+            .collect();
+        paths.sort();
+
+        let mut warnings = String::new();
+        for path in paths {
+            let file = std::fs::File::open(&path)
+                .unwrap_or_else(|_| panic!("Failed to read file: {:?}", path));
+            let parsed = if path
+                .extension()
+                .map_or(false, |extension| extension == "xml")
+            {
+                Bom::parse_from_xml_v1_4_with_warnings(file)
+                    .map(|(_, warnings)| warnings)
+                    .ok()
+            } else {
+                Bom::parse_from_json_v1_4_with_warnings(file)
+                    .map(|(_, warnings)| warnings)
+                    .ok()
+            }
+            .unwrap_or_else(|| panic!("Failed to parse the document as an SBOM: {:?}", path));
+
+            for warning in parsed {
+                warnings.push_str(&format!(
+                    "{}: {}\n",
+                    path.file_name().unwrap_or_default().to_string_lossy(),
+                    warning
+                ));
+            }
+        }
+
+        insta::assert_snapshot!(warnings);
+    }
+}
+
+mod v1_5 {
+    use cyclonedx_bom::models::bom::Bom;
+    use cyclonedx_bom::validation::{Validate, ValidationResult};
+
+    #[test]
+    fn it_should_parse_all_of_the_valid_xml_specifications() {
+        insta::glob!("data/1.5/valid*.xml", |path| {
+            let file = std::fs::File::open(path)
+                .unwrap_or_else(|_| panic!("Failed to read file: {:?}", path));
+            let bom = Bom::parse_from_xml_v1_5(file)
+                .unwrap_or_else(|_| panic!("Failed to parse the document as an SBOM: {:?}", path));
+
+            let validation_result = bom.validate().expect("Failed to validate BOM");
+            assert_eq!(
+                validation_result,
+                ValidationResult::Passed,
+                "{path:?} unexpectedly failed validation"
+            );
+
+            let mut output = Vec::new();
+            bom.output_as_xml_v1_5(&mut output)
+                .unwrap_or_else(|_| panic!("Failed to output the file: {:?}", path));
+            let bom_output = String::from_utf8_lossy(&output).to_string();
+
+            insta::assert_snapshot!(bom_output);
+        });
+    }
+
+    #[test]
+    fn it_should_parse_all_of_the_valid_json_specifications() {
+        insta::glob!("data/1.5/valid*.json", |path| {
+            let file = std::fs::File::open(path)
+                .unwrap_or_else(|_| panic!("Failed to read file: {:?}", path));
+            let bom = Bom::parse_from_json_v1_5(file)
+                .unwrap_or_else(|_| panic!("Failed to parse the document as an SBOM: {:?}", path));
+
+            let validation_result = bom.validate().expect("Failed to validate BOM");
+            assert_eq!(
+                validation_result,
+                ValidationResult::Passed,
+                "{path:?} unexpectedly failed validation"
+            );
+
+            let mut output = Vec::new();
+            bom.output_as_json_v1_5(&mut output)
+                .unwrap_or_else(|_| panic!("Failed to output the file: {:?}", path));
+            let bom_output = String::from_utf8_lossy(&output).to_string();
+
+            insta::assert_snapshot!(bom_output);
+        });
+    }
+
+    #[test]
+    fn it_should_fail_to_parse_all_of_the_invalid_xml_specifications() {
+        insta::glob!("data/1.5/invalid*.xml", |path| {
+            let file = std::fs::File::open(path)
+                .unwrap_or_else(|_| panic!("Failed to read file: {:?}", path));
+            if let Ok(bom) = Bom::parse_from_xml_v1_5(file) {
+                let validation_result = bom.validate().expect("Failed to validate BOM");
+                assert_ne!(
+                    validation_result,
+                    ValidationResult::Passed,
+                    "{path:?} unexpectedly passed validation"
+                );
+            }
+        });
+    }
+
+    #[test]
+    fn it_should_fail_to_parse_all_of_the_invalid_json_specifications() {
+        insta::glob!("data/1.5/invalid*.json", |path| {
+            let file = std::fs::File::open(path)
+                .unwrap_or_else(|_| panic!("Failed to read file: {:?}", path));
+            if let Ok(bom) = Bom::parse_from_json_v1_5(file) {
+                let validation_result = bom.validate().expect("Failed to validate BOM");
+                assert_ne!(
+                    validation_result,
+                    ValidationResult::Passed,
+                    "{path:?} unexpectedly passed validation"
+                );
+            }
+        });
+    }
+
+    #[test]
+    fn it_should_warn_about_the_content_of_the_valid_specifications_that_is_not_parsed() {
+        let mut paths: Vec<_> = std::fs::read_dir("tests/data/1.5")
+            .expect("Failed to list the specifications")
+            .map(|entry| entry.expect("Failed to list the specifications").path())
+            .filter(|path| {
+                path.file_name()
+                    .and_then(|name| name.to_str())
+                    .map_or(false, |name| name.starts_with("valid"))
+            })
+            .collect();
+        paths.sort();
+
+        let mut warnings = String::new();
+        for path in paths {
+            let file = std::fs::File::open(&path)
+                .unwrap_or_else(|_| panic!("Failed to read file: {:?}", path));
+            let parsed = if path
+                .extension()
+                .map_or(false, |extension| extension == "xml")
+            {
+                Bom::parse_from_xml_v1_5_with_warnings(file)
+                    .map(|(_, warnings)| warnings)
+                    .ok()
+            } else {
+                Bom::parse_from_json_v1_5_with_warnings(file)
+                    .map(|(_, warnings)| warnings)
+                    .ok()
+            }
+            .unwrap_or_else(|| panic!("Failed to parse the document as an SBOM: {:?}", path));
+
+            for warning in parsed {
+                warnings.push_str(&format!(
+                    "{}: {}\n",
+                    path.file_name().unwrap_or_default().to_string_lossy(),
+                    warning
+                ));
+            }
+        }
+
+        insta::assert_snapshot!(warnings);
+    }
+}
+
+#[test]
+fn it_should_detect_the_format_and_version_of_the_specifications() {
+    use cyclonedx_bom::compat::compatibility;
+    use cyclonedx_bom::format::DocumentFormat;
+
+    for glob in ["data/1.3/valid*", "data/1.4/valid*", "data/1.5/valid*"] {
+        insta::glob!(glob, |path| {
+            let input =
+                std::fs::read(path).unwrap_or_else(|_| panic!("Failed to read file: {:?}", path));
+            let (bom, detected) = Bom::parse(&input)
+                .unwrap_or_else(|_| panic!("Failed to parse the document as an SBOM: {:?}", path));
+
+            let directory = path
+                .parent()
+                .and_then(|directory| directory.file_name())
+                .and_then(|name| name.to_str());
+            assert_eq!(
+                Some(detected.spec_version.as_str()),
+                directory,
+                "{path:?} has the wrong version"
+            );
+            let format = match path.extension().and_then(|extension| extension.to_str()) {
+                Some("xml") => DocumentFormat::Xml,
+                _ => DocumentFormat::Json,
+            };
+            assert_eq!(detected.format, format, "{path:?} has the wrong format");
+            assert!(!detected.gzip);
+
+            assert_eq!(
+                compatibility(&bom, detected.format).check(detected.spec_version),
+                Ok(()),
+                "{path:?} cannot be represented in the version it was read in"
+            );
+        });
+    }
+}
+
+#[test]
+fn it_should_read_back_the_valid_specifications_it_writes() {
+    use cyclonedx_bom::format::DocumentFormat;
+
+    for glob in ["data/1.3/valid*", "data/1.4/valid*", "data/1.5/valid*"] {
+        insta::glob!(glob, |path| {
+            let input =
+                std::fs::read(path).unwrap_or_else(|_| panic!("Failed to read file: {:?}", path));
+            let (bom, detected) = Bom::parse(&input)
+                .unwrap_or_else(|_| panic!("Failed to parse the document as an SBOM: {:?}", path));
+            let (expected, _) = Bom::parse(&input)
+                .unwrap_or_else(|_| panic!("Failed to parse the document as an SBOM: {:?}", path));
+
+            let mut output = Vec::new();
+            match detected.format {
+                DocumentFormat::Xml => bom
+                    .output_as_xml(&mut output, detected.spec_version)
+                    .unwrap_or_else(|_| panic!("Failed to output the file: {:?}", path)),
+                _ => bom
+                    .output_as_json(&mut output, detected.spec_version)
+                    .unwrap_or_else(|_| panic!("Failed to output the file: {:?}", path)),
+            }
+
+            let (actual, round_tripped) = Bom::parse(&output)
+                .unwrap_or_else(|_| panic!("Failed to parse the output of {:?}", path));
+            assert_eq!(round_tripped, detected, "{path:?} changed format");
+            assert_eq!(
+                actual, expected,
+                "{path:?} changed when written and read back"
+            );
+        });
+    }
+}
+
+#[test]
+fn it_should_write_the_valid_xml_specifications_in_schema_order() {
+    for glob in [
+        "data/1.3/valid*.xml",
+        "data/1.4/valid*.xml",
+        "data/1.5/valid*.xml",
+    ] {
+        insta::glob!(glob, |path| {
+            let input =
+                std::fs::read(path).unwrap_or_else(|_| panic!("Failed to read file: {:?}", path));
+            let (bom, detected) = Bom::parse(&input)
+                .unwrap_or_else(|_| panic!("Failed to parse the document as an SBOM: {:?}", path));
+
+            let mut output = Vec::new();
+            bom.output_as_xml(&mut output, detected.spec_version)
+                .unwrap_or_else(|_| panic!("Failed to output the file: {:?}", path));
+
+            let namespace = format!(
+                "http://cyclonedx.org/schema/bom/{}",
+                detected.spec_version.as_str()
+            );
+            let input = read_elements(&input, &namespace);
+            let output = read_elements(&output, &namespace);
+            // the specifications themselves are valid, so they check the sequences below
+            assert_schema_order(&input, None, &format!("{:?} (input): ", path));
+            assert_schema_order(&output, None, &format!("{:?}: ", path));
+            assert_same_attribute_order(&input, &output, &format!("{:?}: ", path));
+        });
+    }
+}
+
+const XML_SIGNATURE_NAMESPACE: &str = "http://www.w3.org/2000/09/xmldsig#";
+
+/// The child elements of the complex types in `bom-1.3.xsd`, `bom-1.4.xsd` and `bom-1.5.xsd`, in
+/// the order of their `xs:sequence`
+///
+/// Later versions of the schema only add elements to the sequences of earlier ones, so the
+/// sequences list the elements of all versions. Elements are keyed by their name, or by
+/// `parent/name` where the same name is used for different types. Elements that are not listed
+/// have no child elements. `Signature` is the enveloped `ds:Signature` of XML Signature.
+const SCHEMA_SEQUENCES: &[(&str, &[&str])] = &[
+    (
+        "bom",
+        &[
+            "metadata",
+            "components",
+            "services",
+            "externalReferences",
+            "dependencies",
+            "compositions",
+            "properties",
+            "vulnerabilities",
+            "annotations",
+            "formulation",
+            "Signature",
+        ],
+    ),
+    (
+        "metadata",
+        &[
+            "timestamp",
+            "lifecycles",
+            "tools",
+            "authors",
+            "component",
+            "manufacture",
+            "supplier",
+            "licenses",
+            "properties",
+        ],
+    ),
+    ("lifecycles", &["lifecycle"]),
+    ("lifecycle", &["phase", "name", "description"]),
+    ("tools", &["tool", "components", "services"]),
+    (
+        "tool",
+        &["vendor", "name", "version", "hashes", "externalReferences"],
+    ),
+    ("authors", &["author"]),
+    ("authors/author", &["name", "email", "phone"]),
+    ("manufacture", &["name", "url", "contact"]),
+    ("supplier", &["name", "url", "contact"]),
+    ("provider", &["name", "url", "contact"]),
+    ("contact", &["name", "email", "phone"]),
+    ("components", &["component"]),
+    (
+        "component",
+        &[
+            "supplier",
+            "author",
+            "publisher",
+            "group",
+            "name",
+            "version",
+            "description",
+            "scope",
+            "hashes",
+            "licenses",
+            "copyright",
+            "cpe",
+            "purl",
+            "swid",
+            "modified",
+            "pedigree",
+            "externalReferences",
+            "properties",
+            "components",
+            "evidence",
+            "releaseNotes",
+            "modelCard",
+            "data",
+            "Signature",
+        ],
+    ),
+    ("hashes", &["hash"]),
+    ("licenses", &["license", "expression"]),
+    (
+        "license",
+        &["id", "name", "text", "url", "licensing", "properties"],
+    ),
+    (
+        "licensing",
+        &[
+            "altIds",
+            "licensor",
+            "licensee",
+            "purchaser",
+            "purchaseOrder",
+            "licenseTypes",
+            "lastRenewal",
+            "expiration",
+        ],
+    ),
+    ("altIds", &["altId"]),
+    ("licensor", &["organization", "individual"]),
+    ("licensee", &["organization", "individual"]),
+    ("purchaser", &["organization", "individual"]),
+    ("licenseTypes", &["licenseType"]),
+    ("swid", &["text", "url"]),
+    (
+        "pedigree",
+        &[
+            "ancestors",
+            "descendants",
+            "variants",
+            "commits",
+            "patches",
+            "notes",
+        ],
+    ),
+    ("ancestors", &["component"]),
+    ("descendants", &["component"]),
+    ("variants", &["component"]),
+    ("commits", &["commit"]),
+    ("commit", &["uid", "url", "author", "committer", "message"]),
+    ("commit/author", &["timestamp", "name", "email"]),
+    ("committer", &["timestamp", "name", "email"]),
+    ("patches", &["patch"]),
+    ("patch", &["diff", "resolves"]),
+    ("diff", &["text", "url"]),
+    ("resolves", &["issue"]),
+    (
+        "issue",
+        &["id", "name", "description", "source", "references"],
+    ),
+    ("source", &["name", "url"]),
+    ("references", &["url"]),
+    ("externalReferences", &["reference"]),
+    ("reference", &["url", "comment", "hashes"]),
+    ("properties", &["property"]),
+    (
+        "evidence",
+        &[
+            "identity",
+            "occurrences",
+            "callstack",
+            "licenses",
+            "copyright",
+        ],
+    ),
+    ("evidence/copyright", &["text"]),
+    ("identity", &["field", "confidence", "methods", "tools"]),
+    ("methods", &["method"]),
+    ("method", &["technique", "confidence", "value"]),
+    ("occurrences", &["occurrence"]),
+    ("occurrence", &["location"]),
+    ("callstack", &["frames"]),
+    ("frames", &["frame"]),
+    (
+        "frame",
+        &[
+            "package",
+            "module",
+            "function",
+            "parameters",
+            "line",
+            "column",
+            "fullFilename",
+        ],
+    ),
+    (
+        "releaseNotes",
+        &[
+            "type",
+            "title",
+            "featuredImage",
+            "socialImage",
+            "description",
+            "timestamp",
+            "aliases",
+            "tags",
+            "resolves",
+            "notes",
+            "properties",
+        ],
+    ),
+    ("aliases", &["alias"]),
+    ("tags", &["tag"]),
+    ("notes", &["note"]),
+    ("note", &["locale", "text"]),
+    (
+        "modelCard",
+        &[
+            "modelParameters",
+            "quantitativeAnalysis",
+            "considerations",
+            "properties",
+        ],
+    ),
+    (
+        "modelParameters",
+        &[
+            "approach",
+            "task",
+            "architectureFamily",
+            "modelArchitecture",
+            "datasets",
+            "inputs",
+            "outputs",
+        ],
+    ),
+    ("approach", &["type"]),
+    ("datasets", &["dataset", "ref"]),
+    ("quantitativeAnalysis", &["performanceMetrics", "graphics"]),
+    ("performanceMetrics", &["performanceMetric"]),
+    (
+        "performanceMetric",
+        &["type", "value", "slice", "confidenceInterval"],
+    ),
+    ("confidenceInterval", &["lowerBound", "upperBound"]),
+    (
+        "considerations",
+        &[
+            "users",
+            "useCases",
+            "technicalLimitations",
+            "performanceTradeoffs",
+            "ethicalConsiderations",
+            "fairnessAssessments",
+        ],
+    ),
+    ("users", &["user"]),
+    ("useCases", &["useCase"]),
+    ("technicalLimitations", &["technicalLimitation"]),
+    ("performanceTradeoffs", &["performanceTradeoff"]),
+    ("ethicalConsiderations", &["ethicalConsideration"]),
+    ("ethicalConsideration", &["name", "mitigationStrategy"]),
+    ("fairnessAssessments", &["fairnessAssessment"]),
+    (
+        "fairnessAssessment",
+        &["groupAtRisk", "benefits", "harms", "mitigationStrategy"],
+    ),
+    (
+        "component/data",
+        &[
+            "type",
+            "name",
+            "contents",
+            "classification",
+            "sensitiveData",
+            "graphics",
+            "description",
+            "governance",
+        ],
+    ),
+    ("contents", &["attachment", "url", "properties"]),
+    ("graphics", &["description", "collection"]),
+    ("collection", &["graphic"]),
+    ("graphic", &["name", "image"]),
+    ("governance", &["custodians", "stewards", "owners"]),
+    ("custodians", &["custodian"]),
+    ("stewards", &["steward"]),
+    ("owners", &["owner"]),
+    ("custodian", &["organization", "contact"]),
+    ("steward", &["organization", "contact"]),
+    ("owner", &["organization", "contact"]),
+    ("services", &["service"]),
+    (
+        "service",
+        &[
+            "provider",
+            "group",
+            "name",
+            "version",
+            "description",
+            "endpoints",
+            "authenticated",
+            "x-trust-boundary",
+            "trustZone",
+            "data",
+            "licenses",
+            "externalReferences",
+            "properties",
+            "services",
+            "releaseNotes",
+            "Signature",
+        ],
+    ),
+    ("endpoints", &["endpoint"]),
+    ("service/data", &["classification"]),
+    ("dependencies", &["dependency"]),
+    ("dependency", &["dependency"]),
+    ("compositions", &["composition"]),
+    (
+        "composition",
+        &[
+            "aggregate",
+            "assemblies",
+            "dependencies",
+            "vulnerabilities",
+            "Signature",
+        ],
+    ),
+    ("assemblies", &["assembly"]),
+    ("vulnerabilities", &["vulnerability"]),
+    (
+        "vulnerability",
+        &[
+            "id",
+            "source",
+            "references",
+            "ratings",
+            "cwes",
+            "description",
+            "detail",
+            "recommendation",
+            "workaround",
+            "proofOfConcept",
+            "advisories",
+            "created",
+            "published",
+            "updated",
+            "rejected",
+            "credits",
+            "tools",
+            "analysis",
+            "affects",
+            "properties",
+        ],
+    ),
+    ("vulnerability/references", &["reference"]),
+    ("references/reference", &["id", "source"]),
+    ("ratings", &["rating"]),
+    (
+        "rating",
+        &[
+            "source",
+            "score",
+            "severity",
+            "method",
+            "vector",
+            "justification",
+        ],
+    ),
+    ("cwes", &["cwe"]),
+    (
+        "proofOfConcept",
+        &["reproductionSteps", "environment", "supportingMaterial"],
+    ),
+    ("supportingMaterial", &["attachment"]),
+    ("advisories", &["advisory"]),
+    ("advisory", &["title", "url"]),
+    ("credits", &["organizations", "individuals"]),
+    ("organizations", &["organization"]),
+    ("organization", &["name", "url", "contact"]),
+    ("individuals", &["individual"]),
+    ("individual", &["name", "email", "phone"]),
+    (
+        "analysis",
+        &[
+            "state",
+            "justification",
+            "responses",
+            "detail",
+            "firstIssued",
+            "lastUpdated",
+        ],
+    ),
+    ("responses", &["response"]),
+    ("affects", &["target"]),
+    ("target", &["ref", "versions"]),
+    ("versions", &["version"]),
+    ("versions/version", &["version", "range", "status"]),
+    ("annotations", &["annotation"]),
+    (
+        "annotation",
+        &["subjects", "annotator", "timestamp", "text", "Signature"],
+    ),
+    ("subjects", &["subject"]),
+    (
+        "annotator",
+        &["organization", "individual", "component", "service"],
+    ),
+    ("formulation", &["formula"]),
+    (
+        "formula",
+        &["components", "services", "workflows", "properties"],
+    ),
+    ("workflows", &["workflow"]),
+    (
+        "workflow",
+        &[
+            "uid",
+            "name",
+            "description",
+            "resourceReferences",
+            "tasks",
+            "taskDependencies",
+            "taskTypes",
+            "trigger",
+            "steps",
+            "inputs",
+            "outputs",
+            "timeStart",
+            "timeEnd",
+            "workspaces",
+            "runtimeTopology",
+            "properties",
+        ],
+    ),
+    ("tasks", &["task"]),
+    (
+        "task",
+        &[
+            "uid",
+            "name",
+            "description",
+            "resourceReferences",
+            "taskTypes",
+            "trigger",
+            "steps",
+            "inputs",
+            "outputs",
+            "timeStart",
+            "timeEnd",
+            "workspaces",
+            "runtimeTopology",
+            "properties",
+        ],
+    ),
+    ("resourceReferences", &["resourceReference"]),
+    ("resourceReference", &["ref", "externalReference"]),
+    ("externalReference", &["url", "comment", "hashes"]),
+    ("taskDependencies", &["dependency"]),
+    ("taskTypes", &["taskType"]),
+    (
+        "trigger",
+        &[
+            "uid",
+            "name",
+            "description",
+            "resourceReferences",
+            "type",
+            "event",
+            "conditions",
+            "timeActivated",
+            "inputs",
+            "outputs",
+            "properties",
+        ],
+    ),
+    (
+        "event",
+        &[
+            "uid",
+            "description",
+            "timeReceived",
+            "data",
+            "source",
+            "target",
+            "properties",
+        ],
+    ),
+    ("event/source", &["ref", "externalReference"]),
+    ("event/target", &["ref", "externalReference"]),
+    ("conditions", &["condition"]),
+    ("condition", &["description", "expression", "properties"]),
+    ("steps", &["step"]),
+    ("step", &["name", "description", "commands", "properties"]),
+    ("commands", &["command"]),
+    ("command", &["executed", "properties"]),
+    ("inputs", &["input"]),
+    (
+        "input",
+        &[
+            "resource",
+            "parameters",
+            "environmentVars",
+            "data",
+            "source",
+            "target",
+            "properties",
+            "format",
+        ],
+    ),
+    ("outputs", &["output"]),
+    (
+        "output",
+        &[
+            "type",
+            "resource",
+            "environmentVars",
+            "data",
+            "source",
+            "target",
+            "properties",
+            "format",
+        ],
+    ),
+    ("resource", &["ref", "externalReference"]),
+    ("input/source", &["ref", "externalReference"]),
+    ("input/target", &["ref", "externalReference"]),
+    ("output/source", &["ref", "externalReference"]),
+    ("output/target", &["ref", "externalReference"]),
+    ("parameters", &["parameter"]),
+    ("parameter", &["name", "value", "dataType"]),
+    ("environmentVars", &["property", "value"]),
+    ("workspaces", &["workspace"]),
+    (
+        "workspace",
+        &[
+            "uid",
+            "name",
+            "aliases",
+            "description",
+            "resourceReferences",
+            "accessMode",
+            "mountPath",
+            "managedDataType",
+            "volumeRequest",
+            "volume",
+            "properties",
+        ],
+    ),
+    (
+        "volume",
+        &[
+            "uid",
+            "name",
+            "mode",
+            "path",
+            "sizeAllocated",
+            "persistent",
+            "remote",
+            "properties",
+        ],
+    ),
+    ("runtimeTopology", &["dependency"]),
+];
+
+fn schema_sequence(parent: Option<&str>, name: &str) -> &'static [&'static str] {
+    let qualified = parent.map(|parent| format!("{}/{}", parent, name));
+    SCHEMA_SEQUENCES
+        .iter()
+        .find(|(key, _)| Some(*key) == qualified.as_deref())
+        .or_else(|| SCHEMA_SEQUENCES.iter().find(|(key, _)| *key == name))
+        .map_or(&[], |(_, sequence)| sequence)
+}
+
+/// An element of the CycloneDX namespace with the names of its attributes
+#[derive(Debug)]
+struct Element {
+    name: String,
+    attributes: Vec<String>,
+    children: Vec<Element>,
+}
+
+/// Reads the elements of the CycloneDX `namespace`, skipping those from other schemas except for
+/// the XML signature, which is read as a `Signature` element without its children
+fn read_elements(document: &[u8], namespace: &str) -> Element {
+    let mut stack: Vec<Element> = Vec::new();
+    let mut skipped_depth = 0;
+    let mut root = None;
+    for event in xml::EventReader::new(document) {
+        match event.expect("Failed to read the document") {
+            xml::reader::XmlEvent::StartElement {
+                name, attributes, ..
+            } => {
+                if skipped_depth == 0
+                    && name.namespace.as_deref() == Some(XML_SIGNATURE_NAMESPACE)
+                    && name.local_name == "Signature"
+                {
+                    if let Some(parent) = stack.last_mut() {
+                        parent.children.push(Element {
+                            name: name.local_name.clone(),
+                            attributes: Vec::new(),
+                            children: Vec::new(),
+                        });
+                    }
+                }
+                if skipped_depth > 0 || name.namespace.as_deref() != Some(namespace) {
+                    skipped_depth += 1;
+                    continue;
+                }
+                stack.push(Element {
+                    name: name.local_name,
+                    attributes: attributes
+                        .into_iter()
+                        .filter(|attribute| attribute.name.namespace.is_none())
+                        .map(|attribute| attribute.name.local_name)
+                        .collect(),
+                    children: Vec::new(),
+                });
+            }
+            xml::reader::XmlEvent::EndElement { .. } => {
+                if skipped_depth > 0 {
+                    skipped_depth -= 1;
+                    continue;
+                }
+                let element = stack.pop().expect("Unbalanced document");
+                match stack.last_mut() {
+                    Some(parent) => parent.children.push(element),
+                    None => root = Some(element),
+                }
+            }
+            _ => (),
+        }
+    }
+    root.expect("Missing root element")
+}
+
+fn assert_schema_order(element: &Element, parent: Option<&str>, path: &str) {
+    let path = format!("{}/{}", path, element.name);
+    let sequence = schema_sequence(parent, &element.name);
+
+    let mut previous = 0;
+    for child in &element.children {
+        let position = sequence
+            .iter()
+            .position(|name| *name == child.name)
+            .unwrap_or_else(|| panic!("{} is not allowed in {}", child.name, path));
+        assert!(
+            position >= previous,
+            "{} has to be written before {} in {}",
+            child.name,
+            sequence[previous],
+            path
+        );
+        previous = position;
+
+        assert_schema_order(child, Some(&element.name), &path);
+    }
+}
+
+/// Compares the attribute order of the elements both documents have
+fn assert_same_attribute_order(input: &Element, output: &Element, path: &str) {
+    let path = format!("{}/{}", path, output.name);
+    let input_attributes: Vec<_> = input
+        .attributes
+        .iter()
+        .filter(|attribute| output.attributes.contains(attribute))
+        .collect();
+    let output_attributes: Vec<_> = output.attributes.iter().collect();
+    assert_eq!(
+        input_attributes, output_attributes,
+        "Attributes are reordered in {}",
+        path
+    );
+
+    // the XML signature of a document is not kept when it is read
+    let input_children: Vec<_> = input
+        .children
+        .iter()
+        .filter(|child| child.name != "Signature")
+        .collect();
+    assert_eq!(
+        input_children.len(),
+        output.children.len(),
+        "Elements are dropped in {}",
+        path
+    );
+    for (input, output) in input_children.into_iter().zip(&output.children) {
+        assert_same_attribute_order(input, output, &path);
+    }
+}